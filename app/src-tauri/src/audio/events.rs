@@ -12,11 +12,15 @@ pub enum AudioEvent {
         voice_pack: String,
     },
 
-    /// Alert fired: speak the alert text
+    /// Alert fired: play the custom sound, or speak the alert text
     Alert {
         text: String,
-        /// Optional custom sound file path (relative to sounds dir)
+        /// Optional custom sound (sound name or absolute path)
         custom_sound: Option<String>,
+        /// Volume for the custom sound (None = global volume)
+        volume: Option<u8>,
+        /// Speak `text` even when a custom sound is set
+        speak: bool,
     },
 
     /// Speak arbitrary text
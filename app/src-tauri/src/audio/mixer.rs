@@ -0,0 +1,135 @@
+//! Shared output mixer for alert and countdown sounds
+//!
+//! Every sound plays through one output stream owned by a dedicated thread.
+//! When sounds overlap they are scaled down together so their combined volume
+//! stays at full scale instead of clipping, and scaled back up as they finish.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::Duration;
+
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
+use tracing::warn;
+
+/// How often finished sounds are pruned while any are playing
+const PRUNE_INTERVAL: Duration = Duration::from_millis(50);
+
+struct PlayRequest {
+    path: PathBuf,
+    /// Requested volume (0.0-1.0) before overlap scaling
+    volume: f32,
+}
+
+/// A playing sound
+struct Voice {
+    sink: Sink,
+    volume: f32,
+}
+
+/// Handle to the mixer thread
+pub struct Mixer {
+    tx: Sender<PlayRequest>,
+}
+
+impl Default for Mixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Mixer {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::channel();
+        if let Err(e) = std::thread::Builder::new()
+            .name("audio-mixer".to_string())
+            .spawn(move || run(rx))
+        {
+            warn!(error = %e, "Failed to start audio mixer");
+        }
+        Self { tx }
+    }
+
+    /// Play a sound file at `volume` (0-100) alongside anything already playing
+    pub fn play(&self, path: PathBuf, volume: u8) {
+        let _ = self.tx.send(PlayRequest {
+            path,
+            volume: volume.min(100) as f32 / 100.0,
+        });
+    }
+}
+
+/// Scale applied to every voice so the combined volume doesn't exceed 1.0
+fn headroom(volumes: impl Iterator<Item = f32>) -> f32 {
+    let total: f32 = volumes.sum();
+    if total > 1.0 { 1.0 / total } else { 1.0 }
+}
+
+fn rebalance(voices: &[Voice]) {
+    let scale = headroom(voices.iter().map(|v| v.volume));
+    for voice in voices {
+        voice.sink.set_volume(voice.volume * scale);
+    }
+}
+
+fn run(rx: Receiver<PlayRequest>) {
+    // Opened on first use; retried on later sounds if no device was available
+    let mut output: Option<(OutputStream, OutputStreamHandle)> = None;
+    let mut voices: Vec<Voice> = Vec::new();
+
+    loop {
+        // Sleep until a request arrives unless voices need pruning
+        let request = if voices.is_empty() {
+            match rx.recv() {
+                Ok(request) => Some(request),
+                Err(_) => return,
+            }
+        } else {
+            match rx.recv_timeout(PRUNE_INTERVAL) {
+                Ok(request) => Some(request),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        };
+
+        let playing = voices.len();
+        voices.retain(|v| !v.sink.empty());
+        let mut changed = voices.len() != playing;
+
+        if let Some(request) = request {
+            if output.is_none() {
+                output = OutputStream::try_default().ok();
+            }
+            if let Some((_, handle)) = &output
+                && let Some((sink, source)) = open(handle, &request)
+            {
+                voices.push(Voice {
+                    sink,
+                    volume: request.volume,
+                });
+                // Set volumes before the new sound starts
+                rebalance(&voices);
+                changed = false;
+                if let Some(voice) = voices.last() {
+                    voice.sink.append(source);
+                }
+            }
+        }
+
+        if changed {
+            rebalance(&voices);
+        }
+    }
+}
+
+/// Decode a sound file and create an empty sink for it
+fn open(
+    handle: &OutputStreamHandle,
+    request: &PlayRequest,
+) -> Option<(Sink, Decoder<BufReader<File>>)> {
+    let file = File::open(&request.path).ok()?;
+    let source = Decoder::new(BufReader::new(file)).ok()?;
+    let sink = Sink::try_new(handle).ok()?;
+    Some((sink, source))
+}
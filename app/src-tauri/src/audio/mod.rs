@@ -4,7 +4,10 @@
 //! with optional support for custom sound files.
 
 mod events;
+mod mixer;
 mod service;
+mod tts;
 
 pub use events::AudioEvent;
 pub use service::{AudioSender, AudioService, create_audio_channel};
+pub use tts::TtsEngine;
@@ -1,9 +1,10 @@
 //! Audio playback service using TTS and optional custom sounds
 //!
 //! Runs in a background task, receiving AudioEvents via channel.
-//! TTS is only available on Windows/macOS - Linux requires speech-dispatcher.
+//! Speech goes through [`TtsEngine`] with the voice and rate from settings;
+//! sound files go through the shared [`Mixer`].
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use tokio::sync::{RwLock, mpsc};
@@ -11,6 +12,8 @@ use tokio::sync::{RwLock, mpsc};
 use baras_types::AudioSettings;
 
 use super::events::AudioEvent;
+use super::mixer::Mixer;
+use super::tts::TtsEngine;
 
 /// Audio service that handles TTS and sound playback
 pub struct AudioService {
@@ -26,9 +29,11 @@ pub struct AudioService {
     /// Path to bundled sounds directory (fallback)
     bundled_sounds_dir: PathBuf,
 
-    /// TTS engine (None if initialization failed or unavailable on platform)
-    #[cfg(not(target_os = "linux"))]
-    tts: Option<tts::Tts>,
+    /// Text-to-speech for countdown fallbacks and spoken alerts
+    tts: TtsEngine,
+
+    /// Output for sound files, so overlapping sounds don't clip
+    mixer: Mixer,
 }
 
 impl AudioService {
@@ -39,25 +44,13 @@ impl AudioService {
         user_sounds_dir: PathBuf,
         bundled_sounds_dir: PathBuf,
     ) -> Self {
-        #[cfg(not(target_os = "linux"))]
-        let tts = {
-            // Try to initialize TTS, gracefully handle failure
-            match tts::Tts::default() {
-                Ok(mut engine) => {
-                    let _ = engine.set_rate(engine.normal_rate());
-                    Some(engine)
-                }
-                Err(_) => None,
-            }
-        };
-
         Self {
             event_rx,
             settings,
             user_sounds_dir,
             bundled_sounds_dir,
-            #[cfg(not(target_os = "linux"))]
-            tts,
+            tts: TtsEngine::new(),
+            mixer: Mixer::new(),
         }
     }
 
@@ -67,6 +60,11 @@ impl AudioService {
             // Read settings and extract what we need, then drop the guard
             let (enabled, countdown_enabled, alerts_enabled, volume) = {
                 let settings = self.settings.read().await;
+                self.tts.configure(
+                    settings.tts_voice.as_deref(),
+                    settings.tts_rate,
+                    settings.volume,
+                );
                 (
                     settings.enabled,
                     settings.countdown_enabled,
@@ -92,12 +90,18 @@ impl AudioService {
                     }
                 }
 
-                AudioEvent::Alert { text, custom_sound } => {
+                AudioEvent::Alert {
+                    text,
+                    custom_sound,
+                    volume: sound_volume,
+                    speak,
+                } => {
                     if alerts_enabled {
-                        if let Some(sound_file) = custom_sound {
-                            self.play_custom_sound(sound_file, volume);
-                        } else {
-                            self.speak(text);
+                        match custom_sound {
+                            Some(sound_file) if !speak => {
+                                self.play_custom_sound(sound_file, sound_volume.unwrap_or(volume))
+                            }
+                            _ => self.speak(text),
                         }
                     }
                 }
@@ -109,95 +113,38 @@ impl AudioService {
         }
     }
 
-    /// Speak text using TTS (no-op on Linux)
-    #[cfg(not(target_os = "linux"))]
+    /// Speak text using TTS
     fn speak(&mut self, text: &str) {
-        if let Some(ref mut tts) = self.tts {
-            let _ = tts.speak(text, false);
-        }
-    }
-
-    #[cfg(target_os = "linux")]
-    fn speak(&mut self, text: &str) {
-        use std::process::Command;
-        let text = text.to_string();
-        std::thread::spawn(move || {
-            let _ = Command::new("espeak").arg(&text).output();
-        });
+        self.tts.speak(text);
     }
 
     /// Play a countdown number using a voice pack (returns false if not found)
     fn play_countdown_voice(&self, voice: &str, seconds: u8, volume: u8) -> bool {
-        let filename = format!("{}.mp3", seconds);
-        let user_path = self.user_sounds_dir.join(voice).join(&filename);
-        let bundled_path = self.bundled_sounds_dir.join(voice).join(&filename);
-
-        let path = if user_path.exists() {
-            user_path
-        } else if bundled_path.exists() {
-            bundled_path
-        } else {
+        let Some(path) = self.resolve_sound(&format!("{}/{}.mp3", voice, seconds)) else {
             return false;
         };
-
-        let vol = volume;
-        std::thread::spawn(move || {
-            use rodio::{Decoder, OutputStream, Sink};
-            use std::fs::File;
-            use std::io::BufReader;
-
-            let Ok((_stream, stream_handle)) = OutputStream::try_default() else {
-                return;
-            };
-            let Ok(file) = File::open(&path) else { return };
-            let Ok(source) = Decoder::new(BufReader::new(file)) else {
-                return;
-            };
-            let Ok(sink) = Sink::try_new(&stream_handle) else {
-                return;
-            };
-
-            sink.set_volume(vol as f32 / 100.0);
-            sink.append(source);
-            sink.sleep_until_end();
-        });
+        self.mixer.play(path, volume);
         true
     }
 
     /// Play a custom sound file
-    fn play_custom_sound(&self, filename: &str, volume: u8) {
-        let user_path = self.user_sounds_dir.join(filename);
-        let bundled_path = self.bundled_sounds_dir.join(filename);
-
-        let path = if user_path.exists() {
-            user_path
-        } else if bundled_path.exists() {
-            bundled_path
-        } else {
-            return;
-        };
-
-        let vol = volume;
-        std::thread::spawn(move || {
-            use rodio::{Decoder, OutputStream, Sink};
-            use std::fs::File;
-            use std::io::BufReader;
-
-            let Ok((_stream, stream_handle)) = OutputStream::try_default() else {
-                return;
-            };
-            let Ok(file) = File::open(&path) else { return };
-            let Ok(source) = Decoder::new(BufReader::new(file)) else {
-                return;
-            };
-            let Ok(sink) = Sink::try_new(&stream_handle) else {
-                return;
-            };
+    fn play_custom_sound(&self, sound: &str, volume: u8) {
+        if let Some(path) = self.resolve_sound(sound) {
+            self.mixer.play(path, volume);
+        }
+    }
 
-            sink.set_volume(vol as f32 / 100.0);
-            sink.append(source);
-            sink.sleep_until_end();
-        });
+    /// Locate a sound: absolute paths are used as-is, names are looked up in
+    /// the user sounds directory first, then the bundled one
+    fn resolve_sound(&self, sound: &str) -> Option<PathBuf> {
+        let path = Path::new(sound);
+        if path.is_absolute() {
+            return path.exists().then(|| path.to_path_buf());
+        }
+        [&self.user_sounds_dir, &self.bundled_sounds_dir]
+            .into_iter()
+            .map(|dir| dir.join(path))
+            .find(|candidate| candidate.exists())
     }
 }
 
@@ -0,0 +1,197 @@
+//! Text-to-speech engine for alert callouts
+//!
+//! Windows and macOS speak through the platform voices via the `tts` crate.
+//! Linux has no in-process engine here, so speech goes through
+//! speech-dispatcher (`spd-say`) when it is installed and falls back to
+//! `espeak` otherwise.
+
+/// Slowest and fastest rate accepted from `AudioSettings::tts_rate` (percent)
+const MIN_RATE: u8 = 50;
+const MAX_RATE: u8 = 200;
+
+/// espeak's default speed in words per minute
+#[cfg(target_os = "linux")]
+const ESPEAK_NORMAL_WPM: u32 = 175;
+
+/// Speech program used on Linux
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LinuxSpeech {
+    SpeechDispatcher,
+    Espeak,
+}
+
+/// Platform text-to-speech with voice, rate and volume applied
+pub struct TtsEngine {
+    /// Platform engine (None if initialization failed)
+    #[cfg(not(target_os = "linux"))]
+    tts: Option<tts::Tts>,
+
+    #[cfg(target_os = "linux")]
+    speech: LinuxSpeech,
+
+    /// Settings last applied, so unchanged settings cost nothing per alert
+    voice: Option<String>,
+    rate: u8,
+    volume: u8,
+}
+
+impl Default for TtsEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TtsEngine {
+    pub fn new() -> Self {
+        #[cfg(not(target_os = "linux"))]
+        let tts = tts::Tts::default().ok();
+
+        #[cfg(target_os = "linux")]
+        let speech = if std::process::Command::new("spd-say")
+            .arg("--version")
+            .output()
+            .is_ok()
+        {
+            LinuxSpeech::SpeechDispatcher
+        } else {
+            LinuxSpeech::Espeak
+        };
+
+        let mut engine = Self {
+            #[cfg(not(target_os = "linux"))]
+            tts,
+            #[cfg(target_os = "linux")]
+            speech,
+            voice: None,
+            rate: 100,
+            volume: 100,
+        };
+        engine.apply();
+        engine
+    }
+
+    /// Update voice, rate (percent of normal) and volume (0-100)
+    pub fn configure(&mut self, voice: Option<&str>, rate: u8, volume: u8) {
+        let rate = rate.clamp(MIN_RATE, MAX_RATE);
+        let volume = volume.min(100);
+        if self.voice.as_deref() == voice && self.rate == rate && self.volume == volume {
+            return;
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        if self.voice.is_some() && voice.is_none() {
+            // The platform engine has no "default voice" setter; start over
+            self.tts = tts::Tts::default().ok();
+        }
+
+        self.voice = voice.map(str::to_string);
+        self.rate = rate;
+        self.volume = volume;
+        self.apply();
+    }
+
+    /// Push the current settings to the platform engine
+    #[cfg(not(target_os = "linux"))]
+    fn apply(&mut self) {
+        let Some(tts) = self.tts.as_mut() else {
+            return;
+        };
+
+        if let Some(name) = &self.voice
+            && let Ok(voices) = tts.voices()
+            && let Some(voice) = voices.iter().find(|v| &v.name() == name)
+        {
+            let _ = tts.set_voice(voice);
+        }
+
+        let rate = tts.normal_rate() * self.rate as f32 / 100.0;
+        let _ = tts.set_rate(rate.clamp(tts.min_rate(), tts.max_rate()));
+
+        let (min, max) = (tts.min_volume(), tts.max_volume());
+        let _ = tts.set_volume(min + (max - min) * self.volume as f32 / 100.0);
+    }
+
+    /// Settings are passed per utterance on Linux
+    #[cfg(target_os = "linux")]
+    fn apply(&mut self) {}
+
+    /// Speak `text` verbatim, queued after anything already speaking
+    #[cfg(not(target_os = "linux"))]
+    pub fn speak(&mut self, text: &str) {
+        if let Some(ref mut tts) = self.tts {
+            let _ = tts.speak(text, false);
+        }
+    }
+
+    /// Speak `text` verbatim through the speech program
+    #[cfg(target_os = "linux")]
+    pub fn speak(&mut self, text: &str) {
+        use std::process::Command;
+
+        let mut command = match self.speech {
+            LinuxSpeech::SpeechDispatcher => {
+                let mut c = Command::new("spd-say");
+                // Rate and volume are -100..100 around the voice's default
+                c.arg("-r").arg((self.rate as i32 - 100).to_string());
+                c.arg("-i").arg((self.volume as i32 * 2 - 100).to_string());
+                if let Some(voice) = &self.voice {
+                    c.arg("-y").arg(voice);
+                }
+                c
+            }
+            LinuxSpeech::Espeak => {
+                let mut c = Command::new("espeak");
+                let wpm = ESPEAK_NORMAL_WPM * self.rate as u32 / 100;
+                c.arg("-s").arg(wpm.to_string());
+                // Amplitude is 0-200 with 100 as the default
+                c.arg("-a").arg(self.volume.to_string());
+                if let Some(voice) = &self.voice {
+                    c.arg("-v").arg(voice);
+                }
+                c
+            }
+        };
+        // Alert text may start with '-'; keep it from being read as an option
+        command.arg("--").arg(text);
+
+        std::thread::spawn(move || {
+            let _ = command.output();
+        });
+    }
+
+    /// Names of the installed voices (empty when they can't be listed)
+    #[cfg(not(target_os = "linux"))]
+    pub fn voices(&self) -> Vec<String> {
+        self.tts
+            .as_ref()
+            .and_then(|tts| tts.voices().ok())
+            .map(|voices| voices.iter().map(|v| v.name()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Names of the installed voices (empty when they can't be listed)
+    #[cfg(target_os = "linux")]
+    pub fn voices(&self) -> Vec<String> {
+        use std::process::Command;
+
+        // spd-say lists "NAME LANGUAGE VARIANT", espeak "Pty Language Age/Gender VoiceName ..."
+        let (program, arg, column) = match self.speech {
+            LinuxSpeech::SpeechDispatcher => ("spd-say", "-L", 0),
+            LinuxSpeech::Espeak => ("espeak", "--voices", 3),
+        };
+        let Ok(output) = Command::new(program).arg(arg).output() else {
+            return Vec::new();
+        };
+
+        let mut voices: Vec<String> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.split_whitespace().nth(column))
+            .map(str::to_string)
+            .collect();
+        voices.sort();
+        voices.dedup();
+        voices
+    }
+}
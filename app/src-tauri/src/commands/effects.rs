@@ -16,6 +16,7 @@ use tauri::{AppHandle, Manager, State};
 use baras_core::dsl::{AudioConfig, Trigger};
 use baras_core::effects::{
     AlertTrigger, DefinitionConfig, DisplayTarget, EFFECTS_DSL_VERSION, EffectDefinition,
+    EffectSeverity,
 };
 use baras_types::AbilitySelector;
 
@@ -35,6 +36,9 @@ pub struct EffectListItem {
     pub display_text: Option<String>,
     /// Whether this effect has a user override (vs bundled-only)
     pub is_user_override: bool,
+    /// Template this effect inherits unset settings from
+    #[serde(default)]
+    pub extends: Option<String>,
 
     // Effect data
     pub enabled: bool,
@@ -49,6 +53,8 @@ pub struct EffectListItem {
 
     // Display routing
     pub display_target: DisplayTarget,
+    #[serde(default)]
+    pub severity: EffectSeverity,
     pub icon_ability_id: Option<u64>,
     pub show_icon: bool,
     pub display_source: bool,
@@ -80,6 +86,7 @@ impl EffectListItem {
             name: def.name.clone(),
             display_text: def.display_text.clone(),
             is_user_override,
+            extends: def.extends.clone(),
             enabled: def.enabled,
             trigger: def.trigger.clone(),
             ignore_effect_removed: def.ignore_effect_removed,
@@ -90,6 +97,7 @@ impl EffectListItem {
             color: def.color,
             show_at_secs: def.show_at_secs,
             display_target: def.display_target,
+            severity: def.severity,
             icon_ability_id: def.icon_ability_id,
             show_icon: def.show_icon,
             display_source: def.display_source,
@@ -111,6 +119,7 @@ impl EffectListItem {
             name: self.name.clone(),
             display_text: self.display_text.clone(),
             enabled: self.enabled,
+            extends: self.extends.clone(),
             trigger: self.trigger.clone(),
             ignore_effect_removed: self.ignore_effect_removed,
             refresh_abilities: self.refresh_abilities.clone(),
@@ -127,6 +136,7 @@ impl EffectListItem {
             alert_on: self.alert_on,
             audio: self.audio.clone(),
             display_target: self.display_target,
+            severity: self.severity,
             icon_ability_id: self.icon_ability_id,
             is_affected_by_alacrity: self.is_affected_by_alacrity,
             cooldown_ready_secs: self.cooldown_ready_secs,
@@ -217,8 +227,16 @@ fn load_user_effects_file() -> Option<(u32, Vec<EffectDefinition>)> {
 fn save_user_effects(effects: &[EffectDefinition]) -> Result<(), String> {
     let path = get_user_effects_path().ok_or("Cannot determine user effects path")?;
 
+    // Templates are hand-written; keep them when the editor rewrites the file
+    let templates = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| toml::from_str::<DefinitionConfig>(&contents).ok())
+        .map(|config| config.templates)
+        .unwrap_or_default();
+
     let config = DefinitionConfig {
         version: EFFECTS_DSL_VERSION,
+        templates,
         effects: effects.to_vec(),
     };
 
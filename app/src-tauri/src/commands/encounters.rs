@@ -10,14 +10,18 @@
 
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use tauri::{AppHandle, Manager, State};
 
 use baras_core::boss::{
-    AreaType, BossEncounterDefinition, BossTimerDefinition, BossWithPath, ChallengeDefinition,
-    CounterDefinition, EntityDefinition, PhaseDefinition, find_custom_file, load_area_config,
+    AreaIndex, AreaType, BossEncounterDefinition, BossTimerDefinition, BossWithPath,
+    ChallengeDefinition, ChangedItems, CounterDefinition, EntityDefinition, PhaseDefinition,
+    build_area_index, clone_timers, find_custom_file, load_area_config, load_boss_notes,
     load_bosses_from_file, load_bosses_with_custom, load_bosses_with_paths, merge_boss_definition,
-    save_bosses_to_file,
+    replace_npc_id, save_bosses_to_file, shift_phase_timer_durations, user_notes_dir,
 };
+use baras_core::context::{AreaCoverageReport, IdDictionaryEntry};
+use baras_core::game_data::{GAME_IDS_FILENAME, GameIdDatabase, GameIdEntry, GameIdKind};
 use baras_core::timers::{TimerPreferences, boss_timer_key};
 
 use crate::service::ServiceHandle;
@@ -337,6 +341,74 @@ fn save_item_to_custom_file(
     save_bosses_to_file(&bosses, custom_path)
 }
 
+/// Save bulk-edited items of one boss. Bundled files get the changed items in
+/// their custom overlay; user files are rewritten whole.
+fn save_changed_items(
+    app_handle: &AppHandle,
+    bosses: &[BossWithPath],
+    file_path: &Path,
+    boss_id: &str,
+    items: Vec<EncounterItem>,
+) -> Result<(), String> {
+    if let Some(custom_path) = get_custom_path_if_bundled(file_path, app_handle) {
+        for item in &items {
+            save_item_to_custom_file(&custom_path, boss_id, item)?;
+        }
+        Ok(())
+    } else {
+        let file_bosses: Vec<_> = bosses
+            .iter()
+            .filter(|b| b.file_path == file_path)
+            .map(|b| b.boss.clone())
+            .collect();
+        save_bosses_to_file(&file_bosses, file_path)
+    }
+}
+
+/// Collect the items named in `changed` from a (bulk-edited) boss.
+fn changed_encounter_items(
+    boss: &BossEncounterDefinition,
+    changed: &ChangedItems,
+) -> Vec<EncounterItem> {
+    let mut items = Vec::with_capacity(changed.len());
+    items.extend(
+        boss.entities
+            .iter()
+            .filter(|e| changed.entities.contains(&e.name))
+            .cloned()
+            .map(EncounterItem::Entity),
+    );
+    items.extend(
+        boss.timers
+            .iter()
+            .filter(|t| changed.timers.contains(&t.id))
+            .cloned()
+            .map(EncounterItem::Timer),
+    );
+    items.extend(
+        boss.phases
+            .iter()
+            .filter(|p| changed.phases.contains(&p.id))
+            .cloned()
+            .map(EncounterItem::Phase),
+    );
+    items.extend(
+        boss.counters
+            .iter()
+            .filter(|c| changed.counters.contains(&c.id))
+            .cloned()
+            .map(EncounterItem::Counter),
+    );
+    items.extend(
+        boss.challenges
+            .iter()
+            .filter(|c| changed.challenges.contains(&c.id))
+            .cloned()
+            .map(EncounterItem::Challenge),
+    );
+    items
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Timer Preferences
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -393,6 +465,9 @@ pub async fn fetch_area_bosses(file_path: String) -> Result<Vec<BossWithPathResp
                 if let Some(ref v) = p.audio_file {
                     timer.audio.file = Some(v.clone());
                 }
+                if let Some(v) = p.speak_alert {
+                    timer.audio.speak_alert = v;
+                }
             }
         }
     }
@@ -519,6 +594,7 @@ pub async fn update_encounter_item(
         prefs.update_color(&key, t.color);
         prefs.update_audio_enabled(&key, t.audio.enabled);
         prefs.update_audio_file(&key, t.audio.file.clone());
+        prefs.update_speak_alert(&key, t.audio.speak_alert);
         save_timer_preferences(&prefs)?;
 
         // Update live session
@@ -653,6 +729,234 @@ pub async fn delete_encounter_item(
     Ok(())
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// Bulk Edit Commands
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Add `delta_secs` to every timer limited to a phase. Returns the number of timers changed.
+#[tauri::command]
+pub async fn shift_phase_timers(
+    app_handle: AppHandle,
+    service: State<'_, ServiceHandle>,
+    boss_id: String,
+    file_path: String,
+    phase_id: String,
+    delta_secs: f32,
+) -> Result<usize, String> {
+    let file_path_buf = PathBuf::from(&file_path);
+    let mut bosses = load_all_bosses(&app_handle)?;
+    let boss_with_path = bosses
+        .iter_mut()
+        .find(|b| b.boss.id == boss_id && b.file_path == file_path_buf)
+        .ok_or_else(|| format!("Boss '{}' not found", boss_id))?;
+
+    let changed = ChangedItems {
+        timers: shift_phase_timer_durations(&mut boss_with_path.boss, &phase_id, delta_secs),
+        ..Default::default()
+    };
+    if changed.is_empty() {
+        return Ok(0);
+    }
+    let items = changed_encounter_items(&boss_with_path.boss, &changed);
+    save_changed_items(&app_handle, &bosses, &file_path_buf, &boss_id, items)?;
+
+    let _ = service.reload_timer_definitions().await;
+    Ok(changed.len())
+}
+
+/// Request to copy timers between bosses/difficulties
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneTimersRequest {
+    pub source_boss_id: String,
+    pub source_file_path: String,
+    pub target_boss_id: String,
+    pub target_file_path: String,
+    /// Only copy timers of this difficulty (None = all)
+    #[serde(default)]
+    pub from_difficulty: Option<String>,
+    /// Restrict the copies to this difficulty (None = keep difficulties)
+    #[serde(default)]
+    pub to_difficulty: Option<String>,
+}
+
+/// Copy timers from one boss/difficulty to another. Returns the number of timers created.
+#[tauri::command]
+pub async fn clone_boss_timers(
+    app_handle: AppHandle,
+    service: State<'_, ServiceHandle>,
+    request: CloneTimersRequest,
+) -> Result<usize, String> {
+    let source_path = PathBuf::from(&request.source_file_path);
+    let target_path = PathBuf::from(&request.target_file_path);
+    let mut bosses = load_all_bosses(&app_handle)?;
+
+    let source = bosses
+        .iter()
+        .find(|b| b.boss.id == request.source_boss_id && b.file_path == source_path)
+        .map(|b| b.boss.clone())
+        .ok_or_else(|| format!("Boss '{}' not found", request.source_boss_id))?;
+    let target = bosses
+        .iter_mut()
+        .find(|b| b.boss.id == request.target_boss_id && b.file_path == target_path)
+        .ok_or_else(|| format!("Boss '{}' not found", request.target_boss_id))?;
+
+    let cloned = clone_timers(
+        &source,
+        &mut target.boss,
+        request.from_difficulty.as_deref(),
+        request.to_difficulty.as_deref(),
+    );
+    if cloned.is_empty() {
+        return Ok(0);
+    }
+    let count = cloned.len();
+    let items = cloned.into_iter().map(EncounterItem::Timer).collect();
+    save_changed_items(
+        &app_handle,
+        &bosses,
+        &target_path,
+        &request.target_boss_id,
+        items,
+    )?;
+
+    let _ = service.reload_timer_definitions().await;
+    Ok(count)
+}
+
+/// Replace an NPC ID in a boss's entity roster, triggers and challenges.
+/// Returns the number of items changed.
+#[tauri::command]
+pub async fn replace_npc_id_in_boss(
+    app_handle: AppHandle,
+    service: State<'_, ServiceHandle>,
+    boss_id: String,
+    file_path: String,
+    old_id: i64,
+    new_id: i64,
+) -> Result<usize, String> {
+    let file_path_buf = PathBuf::from(&file_path);
+    let mut bosses = load_all_bosses(&app_handle)?;
+    let boss_with_path = bosses
+        .iter_mut()
+        .find(|b| b.boss.id == boss_id && b.file_path == file_path_buf)
+        .ok_or_else(|| format!("Boss '{}' not found", boss_id))?;
+
+    let changed = replace_npc_id(&mut boss_with_path.boss, old_id, new_id);
+    if changed.is_empty() {
+        return Ok(0);
+    }
+    let items = changed_encounter_items(&boss_with_path.boss, &changed);
+    save_changed_items(&app_handle, &bosses, &file_path_buf, &boss_id, items)?;
+
+    let _ = service.reload_timer_definitions().await;
+    Ok(changed.len())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Game ID Search
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Maximum number of search results returned when no limit is given
+const DEFAULT_GAME_ID_RESULTS: usize = 50;
+
+/// Lazy-loaded game ID database (bundled IDs, known bosses and ability names)
+static GAME_ID_DATABASE: OnceLock<GameIdDatabase> = OnceLock::new();
+
+fn load_game_id_database(app_handle: &AppHandle) -> GameIdDatabase {
+    let resource_or_dev = |resource: &str, dev: &str| {
+        app_handle
+            .path()
+            .resolve(resource, tauri::path::BaseDirectory::Resource)
+            .ok()
+            .filter(|p| p.exists())
+            .unwrap_or_else(|| {
+                PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                    .ancestors()
+                    .nth(2)
+                    .map(|p| p.to_path_buf())
+                    .unwrap_or_else(|| PathBuf::from("."))
+                    .join(dev)
+            })
+    };
+
+    let mut db = GameIdDatabase::new();
+    let ids_path = resource_or_dev(
+        &format!("definitions/{GAME_IDS_FILENAME}"),
+        &format!("core/definitions/{GAME_IDS_FILENAME}"),
+    );
+    match std::fs::read_to_string(&ids_path) {
+        Ok(csv) => db.add_csv(&csv),
+        Err(e) => {
+            tracing::warn!(path = %ids_path.display(), error = %e, "Failed to load game ID database")
+        }
+    }
+    db.add_known_bosses();
+    if let Ok(csv) = std::fs::read_to_string(resource_or_dev("icons", "icons").join("icons.csv")) {
+        db.add_icon_names(&csv);
+    }
+    debug!(ids = db.len(), "Loaded game ID database");
+    db
+}
+
+/// Search NPC, ability and effect IDs by name or ID prefix, for editor pick-lists.
+#[tauri::command]
+pub async fn search_game_ids(
+    app_handle: AppHandle,
+    query: String,
+    kind: Option<GameIdKind>,
+    limit: Option<usize>,
+) -> Result<Vec<GameIdEntry>, String> {
+    tokio::task::spawn_blocking(move || {
+        GAME_ID_DATABASE
+            .get_or_init(|| load_game_id_database(&app_handle))
+            .search(&query, kind, limit.unwrap_or(DEFAULT_GAME_ID_RESULTS))
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Search the local ID dictionary (IDs seen in the user's own logs) by any
+/// name an ID was logged under, or by ID prefix.
+#[tauri::command]
+pub async fn search_id_dictionary(
+    service: State<'_, ServiceHandle>,
+    query: String,
+    kind: Option<GameIdKind>,
+    limit: Option<usize>,
+) -> Result<Vec<IdDictionaryEntry>, String> {
+    let limit = limit.unwrap_or(DEFAULT_GAME_ID_RESULTS);
+    Ok(service
+        .shared
+        .id_dictionary
+        .with(|dictionary| dictionary.search(&query, kind, limit)))
+}
+
+/// Every name an ID was logged under, with first-seen dates and areas.
+#[tauri::command]
+pub async fn get_id_history(
+    service: State<'_, ServiceHandle>,
+    kind: GameIdKind,
+    id: i64,
+) -> Result<Option<IdDictionaryEntry>, String> {
+    Ok(service
+        .shared
+        .id_dictionary
+        .with(|dictionary| dictionary.lookup(kind, id)))
+}
+
+/// IDs the game renamed, most recent first (name selectors on the old name
+/// keep matching them).
+#[tauri::command]
+pub async fn get_renamed_ids(
+    service: State<'_, ServiceHandle>,
+) -> Result<Vec<IdDictionaryEntry>, String> {
+    Ok(service
+        .shared
+        .id_dictionary
+        .with(|dictionary| dictionary.renamed()))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Area Index & Creation Commands
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -693,6 +997,27 @@ pub async fn get_area_index(app_handle: AppHandle) -> Result<Vec<AreaListItem>,
     Ok(areas)
 }
 
+/// Check the operations in the user's log history against the bundled and
+/// user definitions, so operations without definitions can be contributed.
+#[tauri::command]
+pub async fn verify_area_coverage(
+    app_handle: AppHandle,
+    service: State<'_, ServiceHandle>,
+) -> Result<AreaCoverageReport, String> {
+    let mut index = AreaIndex::new();
+    for dir in [
+        get_bundled_encounters_dir(&app_handle),
+        get_user_encounters_dir(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|d| d.exists())
+    {
+        index.extend(build_area_index(&dir)?);
+    }
+    service.area_coverage(index).await
+}
+
 fn scan_areas_recursive(
     dir: &Path,
     user_dir: Option<&Path>,
@@ -905,3 +1230,108 @@ pub async fn create_boss(
     let _ = service.reload_timer_definitions().await;
     Ok(boss)
 }
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Boss Notes
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Load the user's notes for a boss (empty if none).
+#[tauri::command]
+pub async fn get_boss_notes(boss_id: String) -> Result<String, String> {
+    let dir = user_notes_dir().ok_or("Could not determine config directory")?;
+    load_boss_notes(&dir, &boss_id).map_err(|e| format!("Failed to read notes: {}", e))
+}
+
+/// Save the user's notes for a boss. Blank notes delete the file.
+#[tauri::command]
+pub async fn save_boss_notes(boss_id: String, text: String) -> Result<(), String> {
+    let dir = user_notes_dir().ok_or("Could not determine config directory")?;
+    baras_core::boss::save_boss_notes(&dir, &boss_id, &text)
+        .map_err(|e| format!("Failed to save notes: {}", e))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// StarParse Import
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// User file for imported bosses that match no existing definition
+const STARPARSE_IMPORT_FILE: &str = "starparse_import.toml";
+
+/// Outcome of a StarParse timer import
+#[derive(Debug, Clone, Serialize)]
+pub struct StarParseImportSummary {
+    /// Number of timers imported
+    pub timers: usize,
+    /// Existing bosses the timers were added to
+    pub merged_bosses: Vec<String>,
+    /// Bosses with no existing definition, saved to `file_path`
+    pub unmatched_bosses: Vec<String>,
+    pub file_path: Option<String>,
+    /// Timers or settings that could not be converted
+    pub warnings: Vec<String>,
+}
+
+/// Import a StarParse timer export (XML). Timers of bosses that already have a
+/// definition (matched by name) are added to it; the rest go to a standalone
+/// user file until they are given an area.
+#[tauri::command]
+pub async fn import_starparse_timers(
+    app_handle: AppHandle,
+    service: State<'_, ServiceHandle>,
+    path: String,
+) -> Result<StarParseImportSummary, String> {
+    let xml =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let import = baras_core::boss::import_starparse_timers(&xml)?;
+    let mut summary = StarParseImportSummary {
+        timers: import.timer_count(),
+        merged_bosses: Vec::new(),
+        unmatched_bosses: Vec::new(),
+        file_path: None,
+        warnings: import.warnings,
+    };
+
+    let mut bosses = load_all_bosses(&app_handle)?;
+    let mut unmatched = Vec::new();
+    for imported in import.bosses {
+        let Some(idx) = bosses
+            .iter()
+            .position(|b| b.boss.name.eq_ignore_ascii_case(&imported.name))
+        else {
+            unmatched.push(imported);
+            continue;
+        };
+        let file_path = bosses[idx].file_path.clone();
+        let boss_id = bosses[idx].boss.id.clone();
+        let items = imported
+            .timers
+            .iter()
+            .cloned()
+            .map(EncounterItem::Timer)
+            .collect();
+        summary.merged_bosses.push(imported.name.clone());
+        merge_boss_definition(&mut bosses[idx].boss, imported);
+        save_changed_items(&app_handle, &bosses, &file_path, &boss_id, items)?;
+    }
+
+    if !unmatched.is_empty() {
+        let file_path = ensure_user_dir()?.join(STARPARSE_IMPORT_FILE);
+        let mut existing = if file_path.exists() {
+            load_bosses_from_file(&file_path)?
+        } else {
+            Vec::new()
+        };
+        for imported in unmatched {
+            summary.unmatched_bosses.push(imported.name.clone());
+            match existing.iter_mut().find(|b| b.id == imported.id) {
+                Some(boss) => merge_boss_definition(boss, imported),
+                None => existing.push(imported),
+            }
+        }
+        save_bosses_to_file(&existing, &file_path)?;
+        summary.file_path = Some(file_path.to_string_lossy().to_string());
+    }
+
+    let _ = service.reload_timer_definitions().await;
+    Ok(summary)
+}
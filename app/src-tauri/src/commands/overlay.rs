@@ -8,6 +8,7 @@ use tauri::State;
 use crate::overlay::{MetricType, OverlayCommand, OverlayManager, OverlayType, SharedOverlayState};
 use crate::service::ServiceHandle;
 use baras_core::context::OverlaySettings;
+use baras_types::RaidSlotAssignment;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Response Types
@@ -39,6 +40,16 @@ pub struct OverlayStatusResponse {
     pub cooldowns_enabled: bool,
     pub dot_tracker_running: bool,
     pub dot_tracker_enabled: bool,
+    pub tank_swap_running: bool,
+    pub tank_swap_enabled: bool,
+    pub raid_cooldowns_running: bool,
+    pub raid_cooldowns_enabled: bool,
+    pub death_recap_running: bool,
+    pub death_recap_enabled: bool,
+    pub boss_notes_running: bool,
+    pub boss_notes_enabled: bool,
+    pub alert_banner_running: bool,
+    pub alert_banner_enabled: bool,
     pub overlays_visible: bool,
     pub move_mode: bool,
     pub rearrange_mode: bool,
@@ -124,6 +135,11 @@ pub async fn get_overlay_status(
         effects_b_running,
         cooldowns_running,
         dot_tracker_running,
+        tank_swap_running,
+        raid_cooldowns_running,
+        death_recap_running,
+        boss_notes_running,
+        alert_banner_running,
         move_mode,
         rearrange_mode,
     ) = {
@@ -141,6 +157,11 @@ pub async fn get_overlay_status(
             s.is_running(OverlayType::EffectsB),
             s.is_running(OverlayType::Cooldowns),
             s.is_running(OverlayType::DotTracker),
+            s.is_running(OverlayType::TankSwap),
+            s.is_running(OverlayType::RaidCooldowns),
+            s.is_running(OverlayType::DeathRecap),
+            s.is_running(OverlayType::BossNotes),
+            s.is_running(OverlayType::AlertBanner),
             s.move_mode,
             s.rearrange_mode,
         )
@@ -165,6 +186,11 @@ pub async fn get_overlay_status(
     let effects_b_enabled = config.overlay_settings.is_enabled("effects_b");
     let cooldowns_enabled = config.overlay_settings.is_enabled("cooldowns");
     let dot_tracker_enabled = config.overlay_settings.is_enabled("dot_tracker");
+    let tank_swap_enabled = config.overlay_settings.is_enabled("tank_swap");
+    let raid_cooldowns_enabled = config.overlay_settings.is_enabled("raid_cooldowns");
+    let death_recap_enabled = config.overlay_settings.is_enabled("death_recap");
+    let boss_notes_enabled = config.overlay_settings.is_enabled("boss_notes");
+    let alert_banner_enabled = config.overlay_settings.is_enabled("alert_banner");
 
     Ok(OverlayStatusResponse {
         running: running_metric_types,
@@ -191,6 +217,16 @@ pub async fn get_overlay_status(
         cooldowns_enabled,
         dot_tracker_running,
         dot_tracker_enabled,
+        tank_swap_running,
+        tank_swap_enabled,
+        raid_cooldowns_running,
+        raid_cooldowns_enabled,
+        death_recap_running,
+        death_recap_enabled,
+        boss_notes_running,
+        boss_notes_enabled,
+        alert_banner_running,
+        alert_banner_enabled,
         overlays_visible: config.overlay_settings.overlays_visible,
         move_mode,
         rearrange_mode,
@@ -244,6 +280,14 @@ pub async fn clear_raid_registry(service: State<'_, ServiceHandle>) -> Result<()
     Ok(())
 }
 
+/// Every raid frame slot with the player in it (for rearranging from the keyboard)
+#[tauri::command]
+pub async fn get_raid_slots(
+    service: State<'_, ServiceHandle>,
+) -> Result<Vec<RaidSlotAssignment>, String> {
+    Ok(service.raid_slots())
+}
+
 /// Swap two slots in the raid frame registry
 #[tauri::command]
 pub async fn swap_raid_slots(
@@ -2,37 +2,57 @@
 //!
 //! Provides SQL-based queries over encounter data using DataFusion.
 
+use std::path::PathBuf;
+
+use baras_core::BarasError;
+use baras_core::context::{AssignmentCheck, RaidPlan, load_raid_plan, raid_plan_path};
 use baras_core::query::{
-    AbilityBreakdown, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow, DataTab,
-    EffectChartData, EffectWindow, EncounterTimeline, EntityBreakdown, PlayerDeath,
-    RaidOverviewRow, TimeRange, TimeSeriesPoint,
+    AbilityBreakdown, BigHit, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow,
+    DataTab, EffectChartData, EffectWindow, EncounterTimeline, EntityBreakdown, ExportFormat,
+    ExportTable, PlayerDeath, PullComparison, RaidOverviewRow, SessionAggregate, TimeRange,
+    TimeSeriesPoint,
 };
 use tauri::State;
 
 use crate::service::ServiceHandle;
 
+/// Encounter argument for command timing logs
+fn encounter_label(encounter_key: &Option<String>) -> &str {
+    encounter_key.as_deref().unwrap_or("live")
+}
+
 /// Query ability breakdown for an encounter and data tab.
-/// Pass encounter_idx for historical, or None for live encounter.
+/// Pass encounter_key for historical, or None for live encounter.
 #[tauri::command]
 pub async fn query_breakdown(
     handle: State<'_, ServiceHandle>,
     tab: DataTab,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     entity_name: Option<String>,
     time_range: Option<TimeRange>,
     entity_types: Option<Vec<String>>,
     breakdown_mode: Option<BreakdownMode>,
     duration_secs: Option<f32>,
-) -> Result<Vec<AbilityBreakdown>, String> {
+) -> Result<Vec<AbilityBreakdown>, BarasError> {
+    let args = format!(
+        "tab={tab:?} encounter={} entity={entity_name:?}",
+        encounter_label(&encounter_key)
+    );
     handle
-        .query_breakdown(
-            tab,
-            encounter_idx,
-            entity_name,
-            time_range,
-            entity_types,
-            breakdown_mode,
-            duration_secs,
+        .shared
+        .command_stats
+        .time(
+            "query_breakdown",
+            args,
+            handle.query_breakdown(
+                tab,
+                encounter_key,
+                entity_name,
+                time_range,
+                entity_types,
+                breakdown_mode,
+                duration_secs,
+            ),
         )
         .await
 }
@@ -42,11 +62,19 @@ pub async fn query_breakdown(
 pub async fn query_entity_breakdown(
     handle: State<'_, ServiceHandle>,
     tab: DataTab,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     time_range: Option<TimeRange>,
-) -> Result<Vec<EntityBreakdown>, String> {
+    breakdown_mode: Option<BreakdownMode>,
+) -> Result<Vec<EntityBreakdown>, BarasError> {
+    let args = format!("tab={tab:?} encounter={}", encounter_label(&encounter_key));
     handle
-        .query_entity_breakdown(tab, encounter_idx, time_range)
+        .shared
+        .command_stats
+        .time(
+            "query_entity_breakdown",
+            args,
+            handle.query_entity_breakdown(tab, encounter_key, time_range, breakdown_mode),
+        )
         .await
 }
 
@@ -54,12 +82,19 @@ pub async fn query_entity_breakdown(
 #[tauri::command]
 pub async fn query_raid_overview(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     time_range: Option<TimeRange>,
     duration_secs: Option<f32>,
-) -> Result<Vec<RaidOverviewRow>, String> {
+) -> Result<Vec<RaidOverviewRow>, BarasError> {
+    let args = format!("encounter={}", encounter_label(&encounter_key));
     handle
-        .query_raid_overview(encounter_idx, time_range, duration_secs)
+        .shared
+        .command_stats
+        .time(
+            "query_raid_overview",
+            args,
+            handle.query_raid_overview(encounter_key, time_range, duration_secs),
+        )
         .await
 }
 
@@ -67,42 +102,78 @@ pub async fn query_raid_overview(
 #[tauri::command]
 pub async fn query_dps_over_time(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     bucket_ms: i64,
     source_name: Option<String>,
     time_range: Option<TimeRange>,
-) -> Result<Vec<TimeSeriesPoint>, String> {
+) -> Result<Vec<TimeSeriesPoint>, BarasError> {
+    let args = format!(
+        "encounter={} bucket_ms={bucket_ms} source={source_name:?}",
+        encounter_label(&encounter_key)
+    );
     handle
-        .query_dps_over_time(encounter_idx, bucket_ms, source_name, time_range)
+        .shared
+        .command_stats
+        .time(
+            "query_dps_over_time",
+            args,
+            handle.query_dps_over_time(encounter_key, bucket_ms, source_name, time_range),
+        )
         .await
 }
 
 /// List available encounter parquet files.
 #[tauri::command]
-pub async fn list_encounter_files(handle: State<'_, ServiceHandle>) -> Result<Vec<u32>, String> {
-    handle.list_encounter_files().await
+pub async fn list_encounter_files(
+    handle: State<'_, ServiceHandle>,
+) -> Result<Vec<String>, BarasError> {
+    let args = String::new();
+    handle
+        .shared
+        .command_stats
+        .time("list_encounter_files", args, handle.list_encounter_files())
+        .await
 }
 
 /// Get encounter timeline with phase segments.
 #[tauri::command]
 pub async fn query_encounter_timeline(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
-) -> Result<EncounterTimeline, String> {
-    handle.query_encounter_timeline(encounter_idx).await
+    encounter_key: Option<String>,
+) -> Result<EncounterTimeline, BarasError> {
+    let args = format!("encounter={}", encounter_label(&encounter_key));
+    handle
+        .shared
+        .command_stats
+        .time(
+            "query_encounter_timeline",
+            args,
+            handle.query_encounter_timeline(encounter_key),
+        )
+        .await
 }
 
 /// Query HPS over time with specified bucket size.
 #[tauri::command]
 pub async fn query_hps_over_time(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     bucket_ms: i64,
     source_name: Option<String>,
     time_range: Option<TimeRange>,
-) -> Result<Vec<TimeSeriesPoint>, String> {
+) -> Result<Vec<TimeSeriesPoint>, BarasError> {
+    let args = format!(
+        "encounter={} bucket_ms={bucket_ms} source={source_name:?}",
+        encounter_label(&encounter_key)
+    );
     handle
-        .query_hps_over_time(encounter_idx, bucket_ms, source_name, time_range)
+        .shared
+        .command_stats
+        .time(
+            "query_hps_over_time",
+            args,
+            handle.query_hps_over_time(encounter_key, bucket_ms, source_name, time_range),
+        )
         .await
 }
 
@@ -110,13 +181,49 @@ pub async fn query_hps_over_time(
 #[tauri::command]
 pub async fn query_dtps_over_time(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     bucket_ms: i64,
     target_name: Option<String>,
     time_range: Option<TimeRange>,
-) -> Result<Vec<TimeSeriesPoint>, String> {
+) -> Result<Vec<TimeSeriesPoint>, BarasError> {
+    let args = format!(
+        "encounter={} bucket_ms={bucket_ms} target={target_name:?}",
+        encounter_label(&encounter_key)
+    );
     handle
-        .query_dtps_over_time(encounter_idx, bucket_ms, target_name, time_range)
+        .shared
+        .command_stats
+        .time(
+            "query_dtps_over_time",
+            args,
+            handle.query_dtps_over_time(encounter_key, bucket_ms, target_name, time_range),
+        )
+        .await
+}
+
+/// Query hits of at least `min_amount` dealt (or taken) by an entity, for
+/// annotating spikes on the DPS/DTPS charts.
+#[tauri::command]
+pub async fn query_big_hits(
+    handle: State<'_, ServiceHandle>,
+    encounter_key: Option<String>,
+    min_amount: f64,
+    entity_name: Option<String>,
+    taken: bool,
+    time_range: Option<TimeRange>,
+) -> Result<Vec<BigHit>, BarasError> {
+    let args = format!(
+        "encounter={} min={min_amount} entity={entity_name:?} taken={taken}",
+        encounter_label(&encounter_key)
+    );
+    handle
+        .shared
+        .command_stats
+        .time(
+            "query_big_hits",
+            args,
+            handle.query_big_hits(encounter_key, min_amount, entity_name, taken, time_range),
+        )
         .await
 }
 
@@ -124,13 +231,23 @@ pub async fn query_dtps_over_time(
 #[tauri::command]
 pub async fn query_effect_uptime(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     target_name: Option<String>,
     time_range: Option<TimeRange>,
     duration_secs: f32,
-) -> Result<Vec<EffectChartData>, String> {
+) -> Result<Vec<EffectChartData>, BarasError> {
+    let args = format!(
+        "encounter={} target={target_name:?}",
+        encounter_label(&encounter_key)
+    );
     handle
-        .query_effect_uptime(encounter_idx, target_name, time_range, duration_secs)
+        .shared
+        .command_stats
+        .time(
+            "query_effect_uptime",
+            args,
+            handle.query_effect_uptime(encounter_key, target_name, time_range, duration_secs),
+        )
         .await
 }
 
@@ -138,19 +255,29 @@ pub async fn query_effect_uptime(
 #[tauri::command]
 pub async fn query_effect_windows(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     effect_id: i64,
     target_name: Option<String>,
     time_range: Option<TimeRange>,
     duration_secs: f32,
-) -> Result<Vec<EffectWindow>, String> {
+) -> Result<Vec<EffectWindow>, BarasError> {
+    let args = format!(
+        "encounter={} effect_id={effect_id} target={target_name:?}",
+        encounter_label(&encounter_key)
+    );
     handle
-        .query_effect_windows(
-            encounter_idx,
-            effect_id,
-            target_name,
-            time_range,
-            duration_secs,
+        .shared
+        .command_stats
+        .time(
+            "query_effect_windows",
+            args,
+            handle.query_effect_windows(
+                encounter_key,
+                effect_id,
+                target_name,
+                time_range,
+                duration_secs,
+            ),
         )
         .await
 }
@@ -159,7 +286,7 @@ pub async fn query_effect_windows(
 #[tauri::command]
 pub async fn query_combat_log(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     offset: u64,
     limit: u64,
     source_filter: Option<String>,
@@ -167,17 +294,27 @@ pub async fn query_combat_log(
     search_filter: Option<String>,
     time_range: Option<TimeRange>,
     event_filters: Option<CombatLogFilters>,
-) -> Result<Vec<CombatLogRow>, String> {
+) -> Result<Vec<CombatLogRow>, BarasError> {
+    let args = format!(
+        "encounter={} offset={offset} limit={limit}",
+        encounter_label(&encounter_key)
+    );
     handle
-        .query_combat_log(
-            encounter_idx,
-            offset,
-            limit,
-            source_filter,
-            target_filter,
-            search_filter,
-            time_range,
-            event_filters,
+        .shared
+        .command_stats
+        .time(
+            "query_combat_log",
+            args,
+            handle.query_combat_log(
+                encounter_key,
+                offset,
+                limit,
+                source_filter,
+                target_filter,
+                search_filter,
+                time_range,
+                event_filters,
+            ),
         )
         .await
 }
@@ -186,21 +323,28 @@ pub async fn query_combat_log(
 #[tauri::command]
 pub async fn query_combat_log_count(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     source_filter: Option<String>,
     target_filter: Option<String>,
     search_filter: Option<String>,
     time_range: Option<TimeRange>,
     event_filters: Option<CombatLogFilters>,
-) -> Result<u64, String> {
+) -> Result<u64, BarasError> {
+    let args = format!("encounter={}", encounter_label(&encounter_key));
     handle
-        .query_combat_log_count(
-            encounter_idx,
-            source_filter,
-            target_filter,
-            search_filter,
-            time_range,
-            event_filters,
+        .shared
+        .command_stats
+        .time(
+            "query_combat_log_count",
+            args,
+            handle.query_combat_log_count(
+                encounter_key,
+                source_filter,
+                target_filter,
+                search_filter,
+                time_range,
+                event_filters,
+            ),
         )
         .await
 }
@@ -209,21 +353,31 @@ pub async fn query_combat_log_count(
 #[tauri::command]
 pub async fn query_combat_log_find(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<String>,
     find_text: String,
     source_filter: Option<String>,
     target_filter: Option<String>,
     time_range: Option<TimeRange>,
     event_filters: Option<CombatLogFilters>,
-) -> Result<Vec<CombatLogFindMatch>, String> {
+) -> Result<Vec<CombatLogFindMatch>, BarasError> {
+    let args = format!(
+        "encounter={} find={find_text:?}",
+        encounter_label(&encounter_key)
+    );
     handle
-        .query_combat_log_find(
-            encounter_idx,
-            find_text,
-            source_filter,
-            target_filter,
-            time_range,
-            event_filters,
+        .shared
+        .command_stats
+        .time(
+            "query_combat_log_find",
+            args,
+            handle.query_combat_log_find(
+                encounter_key,
+                find_text,
+                source_filter,
+                target_filter,
+                time_range,
+                event_filters,
+            ),
         )
         .await
 }
@@ -232,25 +386,171 @@ pub async fn query_combat_log_find(
 #[tauri::command]
 pub async fn query_source_names(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
-) -> Result<Vec<String>, String> {
-    handle.query_source_names(encounter_idx).await
+    encounter_key: Option<String>,
+) -> Result<Vec<String>, BarasError> {
+    let args = format!("encounter={}", encounter_label(&encounter_key));
+    handle
+        .shared
+        .command_stats
+        .time(
+            "query_source_names",
+            args,
+            handle.query_source_names(encounter_key),
+        )
+        .await
 }
 
 /// Get distinct target names for combat log filter dropdown.
 #[tauri::command]
 pub async fn query_target_names(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
-) -> Result<Vec<String>, String> {
-    handle.query_target_names(encounter_idx).await
+    encounter_key: Option<String>,
+) -> Result<Vec<String>, BarasError> {
+    let args = format!("encounter={}", encounter_label(&encounter_key));
+    handle
+        .shared
+        .command_stats
+        .time(
+            "query_target_names",
+            args,
+            handle.query_target_names(encounter_key),
+        )
+        .await
 }
 
 /// Query player deaths in an encounter.
 #[tauri::command]
 pub async fn query_player_deaths(
     handle: State<'_, ServiceHandle>,
-    encounter_idx: Option<u32>,
-) -> Result<Vec<PlayerDeath>, String> {
-    handle.query_player_deaths(encounter_idx).await
+    encounter_key: Option<String>,
+) -> Result<Vec<PlayerDeath>, BarasError> {
+    let args = format!("encounter={}", encounter_label(&encounter_key));
+    handle
+        .shared
+        .command_stats
+        .time(
+            "query_player_deaths",
+            args,
+            handle.query_player_deaths(encounter_key),
+        )
+        .await
+}
+
+/// Compare two pulls of the same boss (per-player DPS, phase durations, boss ability hits).
+#[tauri::command]
+pub async fn compare_pulls(
+    handle: State<'_, ServiceHandle>,
+    encounter_key_a: String,
+    encounter_key_b: String,
+) -> Result<PullComparison, BarasError> {
+    let args = format!("a={encounter_key_a} b={encounter_key_b}");
+    handle
+        .shared
+        .command_stats
+        .time(
+            "compare_pulls",
+            args,
+            handle.compare_pulls(encounter_key_a, encounter_key_b),
+        )
+        .await
+}
+
+/// Per-player averages, best pull, consistency and deaths across several pulls.
+#[tauri::command]
+pub async fn query_session_aggregate(
+    handle: State<'_, ServiceHandle>,
+    encounter_keys: Vec<String>,
+) -> Result<SessionAggregate, BarasError> {
+    let args = format!("pulls={}", encounter_keys.len());
+    handle
+        .shared
+        .command_stats
+        .time(
+            "query_session_aggregate",
+            args,
+            handle.query_session_aggregate(encounter_keys),
+        )
+        .await
+}
+
+/// Load the raid plan (healer assignments per boss).
+#[tauri::command]
+pub async fn get_raid_plan() -> Result<RaidPlan, BarasError> {
+    match raid_plan_path() {
+        Some(path) => Ok(load_raid_plan(&path)?),
+        None => Ok(RaidPlan::default()),
+    }
+}
+
+/// Save the raid plan.
+#[tauri::command]
+pub async fn save_raid_plan(plan: RaidPlan) -> Result<(), BarasError> {
+    let path = raid_plan_path()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "no config directory"))?;
+    Ok(baras_core::context::save_raid_plan(&path, &plan)?)
+}
+
+/// Share of each assigned healer's healing that landed on their assigned targets.
+#[tauri::command]
+pub async fn check_healer_assignments(
+    handle: State<'_, ServiceHandle>,
+    encounter_key: String,
+    boss_name: String,
+) -> Result<Vec<AssignmentCheck>, BarasError> {
+    let args = format!("encounter={encounter_key} boss={boss_name}");
+    handle
+        .shared
+        .command_stats
+        .time(
+            "check_healer_assignments",
+            args,
+            handle.check_healer_assignments(encounter_key, boss_name),
+        )
+        .await
+}
+
+/// Write a Data Explorer table (breakdown, raid overview, combat log or
+/// timeline) to a CSV or JSON file. Returns the number of rows written.
+#[tauri::command]
+pub async fn export_breakdown(
+    handle: State<'_, ServiceHandle>,
+    encounter_key: Option<String>,
+    table: ExportTable,
+    format: ExportFormat,
+    path: PathBuf,
+) -> Result<usize, BarasError> {
+    let args = format!(
+        "encounter={} format={format:?} path={}",
+        encounter_label(&encounter_key),
+        path.display()
+    );
+    handle
+        .shared
+        .command_stats
+        .time(
+            "export_breakdown",
+            args,
+            handle.export_breakdown(encounter_key, table, format, path),
+        )
+        .await
+}
+
+/// Open a save dialog for a table export, returns the chosen path or None.
+#[tauri::command]
+pub async fn pick_export_path(
+    app: tauri::AppHandle,
+    format: ExportFormat,
+    file_name: String,
+) -> Result<Option<String>, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let file = app
+        .dialog()
+        .file()
+        .set_title("Export Table")
+        .set_file_name(format!("{file_name}.{}", format.extension()))
+        .add_filter(format.label(), &[format.extension()])
+        .blocking_save_file();
+
+    Ok(file.map(|f| f.to_string()))
 }
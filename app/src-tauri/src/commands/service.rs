@@ -3,14 +3,22 @@
 //! Commands for log files, tailing, configuration, session info, and profiles.
 
 use std::path::PathBuf;
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Manager, State};
 
 use baras_core::EncounterSummary;
 use baras_core::PlayerMetrics;
-use baras_core::context::{AppConfig, AppConfigExt, OverlayAppearanceConfig};
+use baras_core::context::{
+    AppConfig, AppConfigExt, IgnoreList, IgnoredId, OverlayAppearanceConfig,
+};
+use baras_core::storage::{DEMO_LOG_ARCHIVE, EncounterHistoryStore};
+use baras_types::{
+    CounterRule, CounterValue, CoverageReport, HistoricalEncounter, HistoryFilter, SetupHealth,
+    TimelineEvent,
+};
 
 use crate::overlay::{MetricType, OverlayCommand, OverlayType, SharedOverlayState};
-use crate::service::{LogFileInfo, ServiceHandle, SessionInfo};
+use crate::service::{LogFileInfo, MemoryStats, ServiceHandle, SessionInfo};
+use crate::state::{CommandTimings, OverlayUpdateCounts, RecentAlert};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Log File Commands
@@ -18,12 +26,21 @@ use crate::service::{LogFileInfo, ServiceHandle, SessionInfo};
 
 #[tauri::command]
 pub async fn get_log_files(handle: State<'_, ServiceHandle>) -> Result<Vec<LogFileInfo>, String> {
-    Ok(handle.log_files().await)
+    let files = handle
+        .shared
+        .command_stats
+        .time("get_log_files", String::new(), handle.log_files())
+        .await;
+    Ok(files)
 }
 
 #[tauri::command]
 pub async fn refresh_log_index(handle: State<'_, ServiceHandle>) -> Result<(), String> {
-    handle.refresh_index().await
+    handle
+        .shared
+        .command_stats
+        .time("refresh_log_index", String::new(), handle.refresh_index())
+        .await
 }
 
 #[tauri::command]
@@ -41,6 +58,12 @@ pub async fn get_log_file_count(handle: State<'_, ServiceHandle>) -> Result<usiz
     Ok(handle.log_file_count().await)
 }
 
+/// Startup diagnostics for the log directory and combat logging
+#[tauri::command]
+pub async fn get_setup_health(handle: State<'_, ServiceHandle>) -> Result<SetupHealth, String> {
+    Ok(handle.setup_health().await)
+}
+
 #[tauri::command]
 pub async fn cleanup_logs(
     handle: State<'_, ServiceHandle>,
@@ -62,7 +85,12 @@ pub async fn refresh_file_sizes(handle: State<'_, ServiceHandle>) -> Result<(),
 
 #[tauri::command]
 pub async fn start_tailing(path: PathBuf, handle: State<'_, ServiceHandle>) -> Result<(), String> {
-    handle.start_tailing(path).await
+    let args = format!("path={}", path.display());
+    handle
+        .shared
+        .command_stats
+        .time("start_tailing", args, handle.start_tailing(path))
+        .await
 }
 
 #[tauri::command]
@@ -94,7 +122,16 @@ pub async fn open_historical_file(
     path: PathBuf,
     handle: State<'_, ServiceHandle>,
 ) -> Result<(), String> {
-    handle.open_historical_file(path).await
+    let args = format!("path={}", path.display());
+    handle
+        .shared
+        .command_stats
+        .time(
+            "open_historical_file",
+            args,
+            handle.open_historical_file(path),
+        )
+        .await
 }
 
 #[tauri::command]
@@ -106,6 +143,31 @@ pub async fn resume_live_tailing(handle: State<'_, ServiceHandle>) -> Result<(),
 pub fn is_live_tailing(handle: State<'_, ServiceHandle>) -> Result<bool, String> {
     Ok(handle.is_live_tailing())
 }
+
+/// Open the bundled sample log so every feature can be explored without a log
+#[tauri::command]
+pub async fn load_demo(app: AppHandle, handle: State<'_, ServiceHandle>) -> Result<(), String> {
+    // In release: bundled resources. In dev: fall back to source directory
+    let demo_dir = app
+        .path()
+        .resolve("definitions/demo", tauri::path::BaseDirectory::Resource)
+        .ok()
+        .filter(|p| p.exists())
+        .unwrap_or_else(|| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .ancestors()
+                .nth(2)
+                .map(|p| p.to_path_buf())
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("core/definitions/demo")
+        });
+    handle.load_demo(demo_dir.join(DEMO_LOG_ARCHIVE)).await
+}
+
+#[tauri::command]
+pub fn is_demo_mode(handle: State<'_, ServiceHandle>) -> Result<bool, String> {
+    Ok(handle.is_demo())
+}
 #[tauri::command]
 pub async fn pick_audio_file(app: tauri::AppHandle) -> Result<Option<String>, String> {
     use tauri_plugin_dialog::DialogExt;
@@ -119,6 +181,14 @@ pub async fn pick_audio_file(app: tauri::AppHandle) -> Result<Option<String>, St
     Ok(file.map(|f| f.to_string()))
 }
 
+/// Text-to-speech voices installed on this system
+#[tauri::command]
+pub async fn list_tts_voices() -> Result<Vec<String>, String> {
+    tokio::task::spawn_blocking(|| crate::audio::TtsEngine::new().voices())
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn pick_log_directory(app: tauri::AppHandle) -> Result<Option<String>, String> {
     use tauri_plugin_dialog::DialogExt;
@@ -160,6 +230,31 @@ pub async fn update_config(
     handle.update_config(config).await
 }
 
+/// Ignore an ability/effect ID (e.g. from the combat log viewer)
+#[tauri::command]
+pub async fn add_ignored_id(
+    id: i64,
+    name: String,
+    handle: State<'_, ServiceHandle>,
+) -> Result<(), String> {
+    if !IgnoreList::can_ignore(id) {
+        return Err(format!("ID {} cannot be ignored", id));
+    }
+    let mut config = handle.config().await;
+    if config.ignored_ids.iter().any(|entry| entry.id == id) {
+        return Ok(());
+    }
+    config.ignored_ids.push(IgnoredId { id, name });
+    handle.update_config(config).await
+}
+
+#[tauri::command]
+pub async fn remove_ignored_id(id: i64, handle: State<'_, ServiceHandle>) -> Result<(), String> {
+    let mut config = handle.config().await;
+    config.ignored_ids.retain(|entry| entry.id != id);
+    handle.update_config(config).await
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Session Commands
 // ─────────────────────────────────────────────────────────────────────────────
@@ -168,21 +263,163 @@ pub async fn update_config(
 pub async fn get_current_metrics(
     handle: State<'_, ServiceHandle>,
 ) -> Result<Option<Vec<PlayerMetrics>>, String> {
-    Ok(handle.current_combat_data().await.map(|d| d.metrics))
+    let data = handle
+        .shared
+        .command_stats
+        .time(
+            "get_current_metrics",
+            String::new(),
+            handle.current_combat_data(),
+        )
+        .await;
+    Ok(data.map(|d| d.metrics))
 }
 
 #[tauri::command]
 pub async fn get_session_info(
     handle: State<'_, ServiceHandle>,
 ) -> Result<Option<SessionInfo>, String> {
-    Ok(handle.session_info().await)
+    let info = handle
+        .shared
+        .command_stats
+        .time("get_session_info", String::new(), handle.session_info())
+        .await;
+    Ok(info)
 }
 
 #[tauri::command]
 pub async fn get_encounter_history(
     handle: State<'_, ServiceHandle>,
 ) -> Result<Vec<EncounterSummary>, String> {
-    Ok(handle.encounter_history().await)
+    let history = handle
+        .shared
+        .command_stats
+        .time(
+            "get_encounter_history",
+            String::new(),
+            handle.encounter_history(),
+        )
+        .await;
+    Ok(history)
+}
+
+/// Encounters of all previously parsed logs matching `filter`, newest first
+#[tauri::command]
+pub async fn get_historical_encounters(
+    filter: HistoryFilter,
+    handle: State<'_, ServiceHandle>,
+) -> Result<Vec<HistoricalEncounter>, String> {
+    let args = format!("{:?}", filter);
+    let query =
+        tokio::task::spawn_blocking(move || EncounterHistoryStore::open_default()?.query(&filter));
+    handle
+        .shared
+        .command_stats
+        .time("get_historical_encounters", args, query)
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Current values of the persistent counters, in rule order
+#[tauri::command]
+pub async fn get_counters(handle: State<'_, ServiceHandle>) -> Result<Vec<CounterValue>, String> {
+    let counters = handle.shared.counters.lock().map_err(|e| e.to_string())?;
+    Ok(counters.values())
+}
+
+#[tauri::command]
+pub async fn get_counter_rules(
+    handle: State<'_, ServiceHandle>,
+) -> Result<Vec<CounterRule>, String> {
+    let counters = handle.shared.counters.lock().map_err(|e| e.to_string())?;
+    Ok(counters.rules().to_vec())
+}
+
+/// Replace the counter rules. Counts of removed rules are dropped.
+#[tauri::command]
+pub async fn save_counter_rules(
+    rules: Vec<CounterRule>,
+    handle: State<'_, ServiceHandle>,
+) -> Result<(), String> {
+    let mut counters = handle.shared.counters.lock().map_err(|e| e.to_string())?;
+    counters.set_rules(rules).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn reset_counter(id: String, handle: State<'_, ServiceHandle>) -> Result<(), String> {
+    let mut counters = handle.shared.counters.lock().map_err(|e| e.to_string())?;
+    counters.reset(&id).map_err(|e| e.to_string())
+}
+
+/// Write the raw log lines of one encounter to `dest` as a standalone log file
+#[tauri::command]
+pub async fn export_encounter_raw(
+    encounter_idx: usize,
+    dest: PathBuf,
+    handle: State<'_, ServiceHandle>,
+) -> Result<u64, String> {
+    handle
+        .shared
+        .command_stats
+        .time(
+            "export_encounter_raw",
+            format!("encounter={}", encounter_idx),
+            handle.export_encounter_raw(encounter_idx, dest),
+        )
+        .await
+}
+
+/// Combat starts/ends, area changes, player deaths and phase changes of the session
+#[tauri::command]
+pub async fn get_session_timeline(
+    handle: State<'_, ServiceHandle>,
+) -> Result<Vec<TimelineEvent>, String> {
+    handle
+        .shared
+        .command_stats
+        .time(
+            "get_session_timeline",
+            String::new(),
+            handle.session_timeline(),
+        )
+        .await
+}
+
+#[tauri::command]
+pub async fn get_memory_stats(handle: State<'_, ServiceHandle>) -> Result<MemoryStats, String> {
+    Ok(handle.memory_stats().await)
+}
+
+#[tauri::command]
+pub async fn get_overlay_update_stats(
+    handle: State<'_, ServiceHandle>,
+) -> Result<OverlayUpdateCounts, String> {
+    Ok(handle.shared.overlay_updates.counts())
+}
+
+#[tauri::command]
+pub async fn get_command_timings(
+    handle: State<'_, ServiceHandle>,
+) -> Result<CommandTimings, String> {
+    Ok(handle.shared.command_stats.timings())
+}
+
+/// Most recent alerts (newest first), so users can check whether a timer fired
+#[tauri::command]
+pub async fn get_recent_alerts(
+    handle: State<'_, ServiceHandle>,
+    limit: Option<usize>,
+) -> Result<Vec<RecentAlert>, String> {
+    Ok(handle.shared.alert_history.recent(limit.unwrap_or(20)))
+}
+
+/// The definition coverage report exactly as it would be sent, for the preview
+#[tauri::command]
+pub async fn get_coverage_report(
+    handle: State<'_, ServiceHandle>,
+) -> Result<CoverageReport, String> {
+    Ok(handle.shared.coverage.report())
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -252,6 +489,45 @@ pub async fn load_profile(
     Ok(())
 }
 
+/// Bind an overlay profile to a character (loaded when their log is tailed)
+#[tauri::command]
+pub async fn set_character_profile(
+    character_name: String,
+    profile_name: Option<String>,
+    handle: State<'_, ServiceHandle>,
+) -> Result<(), String> {
+    let mut config = handle.config().await;
+    if let Some(profile) = &profile_name
+        && config.is_profile_name_available(profile)
+    {
+        return Err("Profile not found".to_string());
+    }
+    config
+        .character_settings
+        .entry(character_name)
+        .or_default()
+        .profile_name = profile_name;
+    handle.update_config(config).await
+}
+
+/// Give a character its own alert audio (starting from the global settings),
+/// or return it to the global settings
+#[tauri::command]
+pub async fn set_character_audio(
+    character_name: String,
+    enabled: bool,
+    handle: State<'_, ServiceHandle>,
+) -> Result<(), String> {
+    let mut config = handle.config().await;
+    let audio = enabled.then(|| config.audio.clone());
+    config
+        .character_settings
+        .entry(character_name)
+        .or_default()
+        .audio = audio;
+    handle.update_config(config).await
+}
+
 #[tauri::command]
 pub async fn delete_profile(name: String, handle: State<'_, ServiceHandle>) -> Result<(), String> {
     let mut config = handle.config().await;
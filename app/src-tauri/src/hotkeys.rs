@@ -1,11 +1,13 @@
 //! Global hotkey registration
 //!
-//! Registers global keyboard shortcuts for overlay visibility, move mode, and rearrange mode.
+//! Registers global keyboard shortcuts for overlay visibility, move mode, rearrange mode,
+//! keyboard mode, and boss notes paging.
 //! Supported on Windows, macOS, and Linux (X11 only - Wayland does not support global hotkeys
 //! due to its security model).
 
 use crate::overlay::{OverlayCommand, OverlayManager, OverlayType, SharedOverlayState};
 use crate::service::ServiceHandle;
+use baras_overlay::OverlayKey;
 use tracing::{error, info, warn};
 
 /// Check if running on Wayland (Linux only)
@@ -118,6 +120,60 @@ pub fn spawn_register_hotkeys(
                 warn!(hotkey = %key_str, "Invalid rearrange mode hotkey format");
             }
         }
+
+        // Register toggle keyboard mode hotkey
+        if let Some(ref key_str) = hotkeys.toggle_keyboard_mode {
+            if let Ok(shortcut) = key_str.parse::<Shortcut>() {
+                let state = overlay_state.clone();
+
+                if let Err(e) =
+                    global_shortcut.on_shortcut(shortcut, move |_app, _shortcut, event| {
+                        if event.state == tauri_plugin_global_shortcut::ShortcutState::Pressed {
+                            let state = state.clone();
+                            tauri::async_runtime::spawn(async move {
+                                toggle_keyboard_mode_hotkey(state).await;
+                            });
+                        }
+                    })
+                {
+                    error!(error = %e, hotkey = %key_str, "Failed to register keyboard mode hotkey");
+                } else {
+                    info!(hotkey = %key_str, "Registered keyboard mode hotkey");
+                }
+            } else {
+                warn!(hotkey = %key_str, "Invalid keyboard mode hotkey format");
+            }
+        }
+
+        // Register boss notes paging hotkeys
+        for (key_str, page_key) in [
+            (&hotkeys.notes_next_page, OverlayKey::Right),
+            (&hotkeys.notes_prev_page, OverlayKey::Left),
+        ] {
+            let Some(key_str) = key_str else {
+                continue;
+            };
+            if let Ok(shortcut) = key_str.parse::<Shortcut>() {
+                let state = overlay_state.clone();
+
+                if let Err(e) =
+                    global_shortcut.on_shortcut(shortcut, move |_app, _shortcut, event| {
+                        if event.state == tauri_plugin_global_shortcut::ShortcutState::Pressed {
+                            let state = state.clone();
+                            tauri::async_runtime::spawn(async move {
+                                notes_page_hotkey(state, page_key).await;
+                            });
+                        }
+                    })
+                {
+                    error!(error = %e, hotkey = %key_str, "Failed to register notes paging hotkey");
+                } else {
+                    info!(hotkey = %key_str, "Registered notes paging hotkey");
+                }
+            } else {
+                warn!(hotkey = %key_str, "Invalid notes paging hotkey format");
+            }
+        }
     });
 }
 
@@ -143,7 +199,7 @@ async fn toggle_visibility_hotkey(
 
 /// Hotkey handler: Toggle move mode
 async fn toggle_move_mode_hotkey(overlay_state: SharedOverlayState, service: ServiceHandle) {
-    let (txs, new_mode, was_rearranging) = {
+    let (txs, new_mode, was_rearranging, was_keyboard) = {
         let mut state = match overlay_state.lock() {
             Ok(s) => s,
             Err(_) => return,
@@ -155,12 +211,14 @@ async fn toggle_move_mode_hotkey(overlay_state: SharedOverlayState, service: Ser
 
         let new_mode = !state.move_mode;
         let was_rearranging = state.rearrange_mode;
+        let was_keyboard = state.keyboard_mode;
         state.set_move_mode(new_mode);
         if new_mode {
             state.rearrange_mode = false;
+            state.keyboard_mode = false;
         }
         let txs: Vec<_> = state.all_txs().into_iter().cloned().collect();
-        (txs, new_mode, was_rearranging)
+        (txs, new_mode, was_rearranging, was_keyboard)
     };
 
     // Update shared state flag if rearrange was disabled
@@ -169,10 +227,44 @@ async fn toggle_move_mode_hotkey(overlay_state: SharedOverlayState, service: Ser
     }
 
     for tx in txs {
+        if was_keyboard && new_mode {
+            let _ = tx.send(OverlayCommand::SetKeyboardMode(false)).await;
+        }
         let _ = tx.send(OverlayCommand::SetMoveMode(new_mode)).await;
     }
 }
 
+/// Hotkey handler: Toggle keyboard mode (arrow keys, Enter and Escape in clicked overlays)
+async fn toggle_keyboard_mode_hotkey(overlay_state: SharedOverlayState) {
+    let (txs, new_mode, was_moving) = {
+        let mut state = match overlay_state.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+
+        if !state.overlays_visible || state.running_overlays().is_empty() {
+            return;
+        }
+
+        let new_mode = !state.keyboard_mode;
+        let was_moving = state.move_mode;
+        state.set_keyboard_mode(new_mode);
+        if new_mode {
+            state.move_mode = false;
+        }
+        let txs: Vec<_> = state.all_txs().into_iter().cloned().collect();
+        (txs, new_mode, was_moving)
+    };
+
+    for tx in txs {
+        // Move mode and keyboard mode both change click-through, so only one is on
+        if was_moving && new_mode {
+            let _ = tx.send(OverlayCommand::SetMoveMode(false)).await;
+        }
+        let _ = tx.send(OverlayCommand::SetKeyboardMode(new_mode)).await;
+    }
+}
+
 /// Hotkey handler: Toggle rearrange mode (raid frames)
 async fn toggle_rearrange_mode_hotkey(overlay_state: SharedOverlayState, service: ServiceHandle) {
     let (raid_tx, new_mode) = {
@@ -198,3 +290,18 @@ async fn toggle_rearrange_mode_hotkey(overlay_state: SharedOverlayState, service
         let _ = tx.send(OverlayCommand::SetRearrangeMode(new_mode)).await;
     }
 }
+
+/// Hotkey handler: Flip the boss notes overlay to the next or previous page
+async fn notes_page_hotkey(overlay_state: SharedOverlayState, key: OverlayKey) {
+    let notes_tx = {
+        let state = match overlay_state.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        state.get_tx(OverlayType::BossNotes).cloned()
+    };
+
+    if let Some(tx) = notes_tx {
+        let _ = tx.send(OverlayCommand::Key(key)).await;
+    }
+}
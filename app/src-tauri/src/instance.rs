@@ -0,0 +1,65 @@
+//! Single-instance guard
+//!
+//! Running two BARAS instances at once corrupts the config file (both save on
+//! exit) and tails the same log twice. The single-instance plugin holds a
+//! per-user lock; when a second launch is attempted, the plugin forwards its
+//! arguments to this (primary) instance over IPC and the second process exits.
+//!
+//! The primary instance treats the handoff as a "show main window" request,
+//! and switches to safe mode if the second launch passed `--safe-mode`.
+
+use tauri::{AppHandle, Manager, Runtime};
+
+/// Register the single-instance plugin.
+///
+/// Must be the first plugin on the builder so duplicate launches are caught
+/// before any other plugin (updater, global shortcuts) initializes.
+#[cfg(desktop)]
+pub fn plugin<R: Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    tauri_plugin_single_instance::init(|app, args, cwd| {
+        handle_takeover(app, args, cwd);
+    })
+}
+
+/// Handle a launch request forwarded from a second instance
+#[cfg(desktop)]
+fn handle_takeover<R: Runtime>(app: &AppHandle<R>, args: Vec<String>, cwd: String) {
+    tracing::info!(?args, cwd = %cwd, "Second instance launch redirected to running instance");
+
+    show_main_window(app);
+
+    // `--safe-mode` on a second launch can't restart us, so switch in place
+    if args.iter().skip(1).any(|arg| arg == crate::safe_mode::FLAG) {
+        enter_safe_mode(app);
+    }
+}
+
+#[cfg(desktop)]
+fn enter_safe_mode<R: Runtime>(app: &AppHandle<R>) {
+    let (Some(state), Some(handle)) = (
+        app.try_state::<crate::overlay::SharedOverlayState>(),
+        app.try_state::<crate::service::ServiceHandle>(),
+    ) else {
+        return;
+    };
+    let state = state.inner().clone();
+    let handle = handle.inner().clone();
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        crate::safe_mode::enter(&app, &state, &handle).await;
+    });
+}
+
+/// Show, restore and focus the main window (it may be hidden in the tray)
+pub fn show_main_window<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+    // The user is back; resume live polling without waiting for log lines
+    if let Some(handle) = app.try_state::<crate::service::ServiceHandle>() {
+        handle.shared.idle.wake();
+    }
+}
@@ -8,14 +8,19 @@
 //! - `overlay/` - Overlay management (OverlayManager, spawn, state)
 //! - `router` - Routes service updates to overlay threads
 //! - `hotkeys` - Global hotkey registration (not supported on Wayland)
+//! - `instance` - Single-instance guard and second-launch handoff
+//! - `safe_mode` - `--safe-mode` recovery startup (no overlays, hotkeys or custom appearance)
 
 mod audio;
 mod commands;
 mod hotkeys;
+mod instance;
 mod logging;
 pub mod overlay;
 mod router;
+mod safe_mode;
 pub mod service;
+mod shutdown;
 pub mod state;
 mod tray;
 #[cfg(desktop)]
@@ -25,10 +30,11 @@ use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 
 use audio::create_audio_channel;
+use baras_core::context::AppConfigExt;
 use overlay::{OverlayManager, OverlayState, SharedOverlayState};
 use router::spawn_overlay_router;
 use service::{CombatService, OverlayUpdate, ServiceHandle};
-use tauri::Manager;
+use tauri::{Emitter, Manager};
 
 /// Auto-show all enabled overlays on startup (if overlays_visible is true)
 fn spawn_auto_show_overlays(overlay_state: SharedOverlayState, service_handle: ServiceHandle) {
@@ -48,6 +54,58 @@ fn spawn_auto_show_overlays(overlay_state: SharedOverlayState, service_handle: S
     });
 }
 
+/// How often the connected monitors are checked for a layout change when the
+/// platform offers no display change events
+const MONITOR_LAYOUT_POLL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Safety-net recheck interval alongside display change events (a change seen
+/// while move mode was on is picked up here)
+const MONITOR_LAYOUT_RECHECK: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Delay after a display change event before reading the monitors, so the
+/// several events of one hotplug settle into a single update
+const DISPLAY_CHANGE_SETTLE: std::time::Duration = std::time::Duration::from_millis(1500);
+
+/// Follow monitors being plugged in or removed (e.g. docking a laptop): apply
+/// the overlay positions of the new layout and tell the frontend, which
+/// prompts to reposition overlays stranded on a removed monitor
+fn spawn_monitor_layout_watch(
+    app_handle: tauri::AppHandle,
+    overlay_state: SharedOverlayState,
+    service_handle: ServiceHandle,
+) {
+    let (change_tx, mut change_rx) = mpsc::unbounded_channel::<()>();
+    let subscribed = baras_overlay::watch_display_changes(Box::new(move || {
+        let _ = change_tx.send(());
+    }));
+    let recheck = if subscribed {
+        MONITOR_LAYOUT_RECHECK
+    } else {
+        tracing::info!("No display change events on this platform, polling monitors");
+        MONITOR_LAYOUT_POLL
+    };
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match OverlayManager::apply_monitor_layout(&overlay_state, &service_handle).await {
+                Ok(Some(change)) => {
+                    let _ = app_handle.emit("monitors-changed", change);
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!(error = %e, "Failed to apply monitor layout positions"),
+            }
+
+            tokio::select! {
+                Some(()) = change_rx.recv() => {
+                    tokio::time::sleep(DISPLAY_CHANGE_SETTLE).await;
+                    while change_rx.try_recv().is_ok() {}
+                }
+                _ = tokio::time::sleep(recheck) => {}
+            }
+        }
+    });
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Initialize logging FIRST - guard must outlive app for buffered log flushing
@@ -59,16 +117,9 @@ pub fn run() {
     let mut builder = tauri::Builder::default();
 
     // Single instance plugin - must be registered FIRST to catch duplicate launches early
-    #[cfg(not(any(target_os = "android", target_os = "ios")))]
+    #[cfg(desktop)]
     {
-        builder = builder.plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
-            // Focus existing window when second instance attempts to launch
-            if let Some(window) = app.get_webview_window("main") {
-                let _ = window.show();
-                let _ = window.unminimize();
-                let _ = window.set_focus();
-            }
-        }));
+        builder = builder.plugin(instance::plugin());
     }
 
     builder
@@ -86,8 +137,10 @@ pub fn run() {
                 // Create channel for audio events
                 let (audio_tx, audio_rx) = create_audio_channel();
 
-                // Clear old parquet data from previous sessions
-                if let Err(e) = baras_core::storage::clear_data_dir() {
+                // Clear old parquet data from previous sessions (except one that
+                // can be resumed from its parse checkpoint)
+                let keep = service::checkpointed_session_id();
+                if let Err(e) = baras_core::storage::clear_data_dir_except(keep.as_deref()) {
                     tracing::error!(error = %e, "Failed to clear data directory");
                 }
 
@@ -107,15 +160,32 @@ pub fn run() {
                     handle.shared.clone(),
                 );
 
-                // Auto-show enabled overlays on startup
-                spawn_auto_show_overlays(overlay_state.clone(), handle.clone());
+                if safe_mode::requested() {
+                    // Recovery startup: skip overlay auto-show and hotkey registration
+                    tracing::warn!("Starting in safe mode");
+                    tauri::async_runtime::block_on(async {
+                        let mut config = handle.config().await;
+                        config.enter_safe_mode();
+                        handle.shared.apply_config(config).await;
+                    });
+                } else {
+                    // Switch overlay positions with the monitor layout
+                    spawn_monitor_layout_watch(
+                        app.handle().clone(),
+                        overlay_state.clone(),
+                        handle.clone(),
+                    );
 
-                // Register global hotkeys (not supported on Wayland)
-                hotkeys::spawn_register_hotkeys(
-                    app.handle().clone(),
-                    overlay_state.clone(),
-                    handle,
-                );
+                    // Auto-show enabled overlays on startup
+                    spawn_auto_show_overlays(overlay_state.clone(), handle.clone());
+
+                    // Register global hotkeys (not supported on Wayland)
+                    hotkeys::spawn_register_hotkeys(
+                        app.handle().clone(),
+                        overlay_state.clone(),
+                        handle,
+                    );
+                }
 
                 // Set up system tray
                 let _ = tray::setup_tray(app.handle());
@@ -164,6 +234,7 @@ pub fn run() {
             commands::refresh_overlay_settings,
             commands::preview_overlay_settings,
             commands::clear_raid_registry,
+            commands::get_raid_slots,
             commands::swap_raid_slots,
             commands::remove_raid_slot,
             // Service commands
@@ -174,6 +245,7 @@ pub fn run() {
             commands::restart_watcher,
             commands::get_log_directory_size,
             commands::get_log_file_count,
+            commands::get_setup_health,
             commands::cleanup_logs,
             commands::refresh_file_sizes,
             commands::get_tailing_status,
@@ -181,14 +253,31 @@ pub fn run() {
             commands::get_current_metrics,
             commands::get_config,
             commands::update_config,
+            commands::add_ignored_id,
+            commands::remove_ignored_id,
             commands::get_active_file,
             commands::get_session_info,
             commands::get_encounter_history,
+            commands::get_historical_encounters,
+            commands::get_counters,
+            commands::get_counter_rules,
+            commands::save_counter_rules,
+            commands::reset_counter,
+            commands::get_session_timeline,
+            commands::export_encounter_raw,
+            commands::get_memory_stats,
+            commands::get_overlay_update_stats,
+            commands::get_command_timings,
+            commands::get_recent_alerts,
+            commands::get_coverage_report,
             // File browser commands
             commands::open_historical_file,
             commands::resume_live_tailing,
             commands::is_live_tailing,
+            commands::load_demo,
+            commands::is_demo_mode,
             commands::pick_audio_file,
+            commands::list_tts_voices,
             commands::pick_log_directory,
             // Profile commands
             commands::get_profile_names,
@@ -196,15 +285,28 @@ pub fn run() {
             commands::save_profile,
             commands::load_profile,
             commands::delete_profile,
+            commands::set_character_profile,
+            commands::set_character_audio,
             commands::rename_profile,
             // Encounter editor commands
             commands::get_area_index,
+            commands::verify_area_coverage,
             commands::fetch_area_bosses,
             commands::create_area,
             commands::create_boss,
             commands::create_encounter_item,
             commands::update_encounter_item,
             commands::delete_encounter_item,
+            commands::shift_phase_timers,
+            commands::clone_boss_timers,
+            commands::replace_npc_id_in_boss,
+            commands::search_game_ids,
+            commands::search_id_dictionary,
+            commands::get_id_history,
+            commands::get_renamed_ids,
+            commands::get_boss_notes,
+            commands::save_boss_notes,
+            commands::import_starparse_timers,
             // Effect editor commands
             commands::get_effect_definitions,
             commands::update_effect_definition,
@@ -221,6 +323,7 @@ pub fn run() {
             commands::query_dps_over_time,
             commands::query_hps_over_time,
             commands::query_dtps_over_time,
+            commands::query_big_hits,
             commands::query_effect_uptime,
             commands::query_effect_windows,
             commands::query_combat_log,
@@ -230,6 +333,13 @@ pub fn run() {
             commands::query_target_names,
             commands::query_player_deaths,
             commands::query_encounter_timeline,
+            commands::compare_pulls,
+            commands::query_session_aggregate,
+            commands::get_raid_plan,
+            commands::save_raid_plan,
+            commands::check_healer_assignments,
+            commands::export_breakdown,
+            commands::pick_export_path,
             commands::list_encounter_files,
             // Updater
             #[cfg(desktop)]
@@ -240,6 +350,15 @@ pub fn run() {
             commands::get_changelog,
             commands::mark_changelog_viewed,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            // Hold the exit until the live session and config are flushed
+            if let tauri::RunEvent::ExitRequested { api, .. } = event
+                && !shutdown::is_complete()
+            {
+                api.prevent_exit();
+                tauri::async_runtime::spawn(shutdown::run_and_exit(app.clone()));
+            }
+        });
 }
@@ -8,13 +8,17 @@ use baras_overlay::{
     CooldownConfig, DotTrackerConfig, EffectsABConfig, EffectsLayout, OverlayConfigUpdate,
     OverlayData, RaidGridLayout, RaidOverlayConfig,
 };
+use serde::Serialize;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use super::metrics::create_entries_for_type;
 use super::spawn::{
-    create_alerts_overlay, create_boss_health_overlay, create_challenges_overlay,
-    create_cooldowns_overlay, create_dot_tracker_overlay, create_effects_a_overlay,
-    create_effects_b_overlay, create_metric_overlay, create_personal_overlay, create_raid_overlay,
+    create_alert_banner_overlay, create_alerts_overlay, create_boss_health_overlay,
+    create_boss_notes_overlay, create_challenges_overlay, create_cooldowns_overlay,
+    create_death_recap_overlay, create_dot_tracker_overlay, create_effects_a_overlay,
+    create_effects_b_overlay, create_metric_overlay, create_personal_overlay,
+    create_raid_cooldowns_overlay, create_raid_overlay, create_tank_swap_overlay,
     create_timers_a_overlay, create_timers_b_overlay,
 };
 use super::state::{OverlayCommand, OverlayHandle, PositionEvent};
@@ -22,6 +26,18 @@ use super::types::{MetricType, OverlayType};
 use super::{SharedOverlayState, get_appearance_for_type};
 use crate::service::{CombatData, ServiceHandle};
 
+/// A monitor layout change, sent to the frontend as `monitors-changed`
+#[derive(Debug, Clone, Serialize)]
+pub struct MonitorsChanged {
+    /// Number of connected monitors
+    pub monitors: usize,
+    /// Whether the saved positions of the new layout were applied
+    pub layout_switched: bool,
+    /// Config keys of running overlays whose monitor is gone (moved to the
+    /// primary monitor until repositioned)
+    pub stranded: Vec<String>,
+}
+
 /// Result of a spawn operation
 pub struct SpawnResult {
     pub handle: OverlayHandle,
@@ -106,6 +122,30 @@ impl OverlayManager {
                 let dot_config = settings.dot_tracker.clone();
                 create_dot_tracker_overlay(position, dot_config, settings.dot_tracker_opacity)?
             }
+            OverlayType::TankSwap => {
+                let tank_swap_config = settings.tank_swap.clone();
+                create_tank_swap_overlay(position, tank_swap_config, settings.tank_swap_opacity)?
+            }
+            OverlayType::RaidCooldowns => {
+                let cooldown_config = settings.raid_cooldowns.clone();
+                create_raid_cooldowns_overlay(
+                    position,
+                    cooldown_config,
+                    settings.raid_cooldowns_opacity,
+                )?
+            }
+            OverlayType::DeathRecap => {
+                let recap_config = settings.death_recap.clone();
+                create_death_recap_overlay(position, recap_config, settings.death_recap_opacity)?
+            }
+            OverlayType::BossNotes => {
+                let notes_config = settings.boss_notes.clone();
+                create_boss_notes_overlay(position, notes_config, settings.boss_notes_opacity)?
+            }
+            OverlayType::AlertBanner => {
+                let banner_config = settings.alert_banner.clone();
+                create_alert_banner_overlay(position, banner_config, settings.alert_banner_opacity)?
+            }
         };
 
         Ok(SpawnResult {
@@ -180,7 +220,12 @@ impl OverlayManager {
             | OverlayType::EffectsA
             | OverlayType::EffectsB
             | OverlayType::Cooldowns
-            | OverlayType::DotTracker => {
+            | OverlayType::DotTracker
+            | OverlayType::TankSwap
+            | OverlayType::RaidCooldowns
+            | OverlayType::DeathRecap
+            | OverlayType::BossNotes
+            | OverlayType::AlertBanner => {
                 // These get data via separate update channels (bridge)
             }
         }
@@ -193,6 +238,16 @@ impl OverlayManager {
         }
     }
 
+    /// Sync keyboard mode state with overlay.
+    pub async fn sync_keyboard_mode(
+        tx: &tokio::sync::mpsc::Sender<OverlayCommand>,
+        keyboard_mode: bool,
+    ) {
+        if keyboard_mode {
+            let _ = tx.send(OverlayCommand::SetKeyboardMode(true)).await;
+        }
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Position Persistence
     // ─────────────────────────────────────────────────────────────────────────
@@ -353,6 +408,26 @@ impl OverlayManager {
                 };
                 OverlayConfigUpdate::DotTracker(dot_config, settings.dot_tracker_opacity)
             }
+            OverlayType::TankSwap => OverlayConfigUpdate::TankSwap(
+                settings.tank_swap.clone(),
+                settings.tank_swap_opacity,
+            ),
+            OverlayType::RaidCooldowns => OverlayConfigUpdate::RaidCooldowns(
+                settings.raid_cooldowns.clone(),
+                settings.raid_cooldowns_opacity,
+            ),
+            OverlayType::DeathRecap => OverlayConfigUpdate::DeathRecap(
+                settings.death_recap.clone(),
+                settings.death_recap_opacity,
+            ),
+            OverlayType::BossNotes => OverlayConfigUpdate::BossNotes(
+                settings.boss_notes.clone(),
+                settings.boss_notes_opacity,
+            ),
+            OverlayType::AlertBanner => OverlayConfigUpdate::AlertBanner(
+                settings.alert_banner.clone(),
+                settings.alert_banner_opacity,
+            ),
         }
     }
 
@@ -377,9 +452,14 @@ impl OverlayManager {
             return Ok(true);
         }
 
+        // Raid frames wait for a group
+        if kind == OverlayType::Raid && Self::raid_hidden_solo(&config.overlay_settings, service) {
+            return Ok(true);
+        }
+
         // Check if already running, spawn, and insert - all under lock to prevent race conditions
         // from rapid toggle clicks spawning duplicate overlays
-        let (tx, needs_monitor_save, current_move_mode) = {
+        let (tx, needs_monitor_save, current_move_mode, current_keyboard_mode) = {
             let mut s = state.lock().map_err(|e| e.to_string())?;
             if s.is_running(kind) {
                 return Ok(true);
@@ -389,12 +469,14 @@ impl OverlayManager {
             let tx = result.handle.tx.clone();
             let needs_monitor_save = result.needs_monitor_save;
             let mode = s.move_mode;
+            let keyboard_mode = s.keyboard_mode;
             s.insert(result.handle);
-            (tx, needs_monitor_save, mode)
+            (tx, needs_monitor_save, mode, keyboard_mode)
         };
 
-        // Sync move mode
+        // Sync move and keyboard modes
         Self::sync_move_mode(&tx, current_move_mode).await;
+        Self::sync_keyboard_mode(&tx, current_keyboard_mode).await;
 
         // Send initial data from cache if available (regardless of tailing state)
         let combat_data = service.current_combat_data().await;
@@ -482,6 +564,11 @@ impl OverlayManager {
                 "effects_b" => OverlayType::EffectsB,
                 "cooldowns" => OverlayType::Cooldowns,
                 "dot_tracker" => OverlayType::DotTracker,
+                "tank_swap" => OverlayType::TankSwap,
+                "raid_cooldowns" => OverlayType::RaidCooldowns,
+                "death_recap" => OverlayType::DeathRecap,
+                "boss_notes" => OverlayType::BossNotes,
+                "alert_banner" => OverlayType::AlertBanner,
                 _ => {
                     if let Some(mt) = MetricType::from_config_key(key) {
                         OverlayType::Metric(mt)
@@ -491,6 +578,12 @@ impl OverlayManager {
                 }
             };
 
+            if kind == OverlayType::Raid
+                && Self::raid_hidden_solo(&config.overlay_settings, service)
+            {
+                continue;
+            }
+
             // Check if running, spawn, and insert - all under lock to prevent race conditions
             let spawn_result = {
                 let mut s = state.lock().map_err(|e| e.to_string())?;
@@ -622,6 +715,11 @@ impl OverlayManager {
                 "effects_b" => OverlayType::EffectsB,
                 "cooldowns" => OverlayType::Cooldowns,
                 "dot_tracker" => OverlayType::DotTracker,
+                "tank_swap" => OverlayType::TankSwap,
+                "raid_cooldowns" => OverlayType::RaidCooldowns,
+                "death_recap" => OverlayType::DeathRecap,
+                "boss_notes" => OverlayType::BossNotes,
+                "alert_banner" => OverlayType::AlertBanner,
                 _ => {
                     if let Some(mt) = MetricType::from_config_key(key) {
                         OverlayType::Metric(mt)
@@ -631,6 +729,12 @@ impl OverlayManager {
                 }
             };
 
+            if kind == OverlayType::Raid
+                && Self::raid_hidden_solo(&config.overlay_settings, service)
+            {
+                continue;
+            }
+
             // Check if running, spawn, and insert
             let spawn_result = {
                 let mut s = state.lock().map_err(|e| e.to_string())?;
@@ -655,25 +759,158 @@ impl OverlayManager {
         Ok(())
     }
 
+    /// React to monitors being plugged in, removed or rearranged.
+    ///
+    /// Switches to the saved overlay positions of the new monitor layout (see
+    /// [`OverlaySettings::switch_monitor_layout`]), respawning running overlays
+    /// there. Otherwise running overlays are moved to their saved positions
+    /// re-resolved against the connected monitors, so overlays of a removed
+    /// monitor land on the primary one instead of phantom coordinates.
+    /// Returns None if the layout did not change.
+    pub async fn apply_monitor_layout(
+        state: &SharedOverlayState,
+        service: &ServiceHandle,
+    ) -> Result<Option<MonitorsChanged>, String> {
+        // Positions are only saved when move mode is locked again
+        if state.lock().map_err(|e| e.to_string())?.move_mode {
+            return Ok(None);
+        }
+
+        let monitors = tokio::task::spawn_blocking(baras_overlay::get_all_monitors)
+            .await
+            .map_err(|e| e.to_string())?;
+        if monitors.is_empty() {
+            return Ok(None);
+        }
+        let fingerprint = baras_overlay::monitor_layout_fingerprint(&monitors);
+
+        let mut config = service.config().await;
+        // Safe mode keeps overlays at their default positions
+        if config.safe_mode_stash.is_some()
+            || config.overlay_settings.active_monitor_layout.as_deref()
+                == Some(fingerprint.as_str())
+        {
+            return Ok(None);
+        }
+        let layout_switched = config.overlay_settings.switch_monitor_layout(&fingerprint);
+        let settings = config.overlay_settings.clone();
+        service.update_config(config).await?;
+        tracing::info!(layout = %fingerprint, layout_switched, "Monitor layout changed");
+
+        let running: Vec<_> = {
+            let s = state.lock().map_err(|e| e.to_string())?;
+            s.all_overlays()
+                .into_iter()
+                .map(|(kind, tx)| (kind, tx.clone()))
+                .collect()
+        };
+
+        let mut stranded = Vec::new();
+        for (kind, tx) in &running {
+            let pos = settings.get_position(kind.config_key());
+            let monitor = baras_overlay::find_monitor_by_id(&monitors, pos.monitor_id.as_deref());
+            if pos
+                .monitor_id
+                .as_ref()
+                .is_some_and(|id| monitor.is_some_and(|m| m.id != *id))
+            {
+                stranded.push(kind.config_key().to_string());
+            }
+            if !layout_switched && let Some(monitor) = monitor {
+                let (x, y) = monitor.to_absolute(pos.x, pos.y);
+                let _ = tx.send(OverlayCommand::SetPosition(x, y)).await;
+            }
+        }
+        if layout_switched && !running.is_empty() {
+            Self::temporary_hide_all(state, service).await?;
+            Self::temporary_show_all(state, service).await?;
+        }
+
+        Ok(Some(MonitorsChanged {
+            monitors: monitors.len(),
+            layout_switched,
+            stranded,
+        }))
+    }
+
+    /// Whether the raid overlay stays hidden because the local player is not
+    /// grouped and `show_when_solo` is off.
+    fn raid_hidden_solo(settings: &OverlaySettings, service: &ServiceHandle) -> bool {
+        !settings.raid_overlay.show_when_solo && !service.shared.grouped.load(Ordering::SeqCst)
+    }
+
+    /// Show or hide the raid overlay after the group status changed (does NOT
+    /// modify config). Only applies while `show_when_solo` is off.
+    pub async fn sync_raid_group_visibility(
+        state: &SharedOverlayState,
+        service: &ServiceHandle,
+    ) -> Result<(), String> {
+        let config = service.config().await;
+        let settings = &config.overlay_settings;
+        if settings.raid_overlay.show_when_solo {
+            return Ok(());
+        }
+
+        let enabled = settings.enabled.get("raid").copied().unwrap_or(false);
+        let should_show = enabled
+            && settings.overlays_visible
+            && !service
+                .shared
+                .conversation_hiding_active
+                .load(Ordering::SeqCst)
+            && !Self::raid_hidden_solo(settings, service);
+
+        if should_show {
+            let (tx, move_mode) = {
+                let mut s = state.lock().map_err(|e| e.to_string())?;
+                if s.is_running(OverlayType::Raid) {
+                    return Ok(());
+                }
+                let result = Self::spawn(OverlayType::Raid, settings)?;
+                let tx = result.handle.tx.clone();
+                s.insert(result.handle);
+                (tx, s.move_mode)
+            };
+            Self::sync_move_mode(&tx, move_mode).await;
+            service.set_overlay_active("raid", true);
+            service.refresh_raid_frames().await;
+        } else {
+            let handle = {
+                let mut s = state.lock().map_err(|e| e.to_string())?;
+                s.rearrange_mode = false;
+                s.remove(OverlayType::Raid)
+            };
+            if let Some(h) = handle {
+                service.set_rearrange_mode(false);
+                Self::shutdown_no_position(h).await;
+                service.set_overlay_active("raid", false);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Toggle move mode for all overlays.
     /// Returns the new move mode state.
     pub async fn toggle_move_mode(
         state: &SharedOverlayState,
         service: &ServiceHandle,
     ) -> Result<bool, String> {
-        let (txs, new_mode, raid_tx, was_rearranging) = {
+        let (txs, new_mode, raid_tx, was_rearranging, was_keyboard) = {
             let mut s = state.lock().map_err(|e| e.to_string())?;
             if !s.any_running() {
                 return Err("No overlays running".to_string());
             }
             s.move_mode = !s.move_mode;
             let was_rearranging = s.rearrange_mode;
+            let was_keyboard = s.keyboard_mode;
             if s.move_mode {
                 s.rearrange_mode = false;
+                s.keyboard_mode = false;
             }
             let txs: Vec<_> = s.all_txs().into_iter().cloned().collect();
             let raid_tx = s.get_raid_tx().cloned();
-            (txs, s.move_mode, raid_tx, was_rearranging)
+            (txs, s.move_mode, raid_tx, was_rearranging, was_keyboard)
         };
 
         // Turn off rearrange mode first if entering move mode
@@ -684,6 +921,13 @@ impl OverlayManager {
             }
         }
 
+        // Likewise keyboard mode, which also changes click-through
+        if was_keyboard && new_mode {
+            for tx in &txs {
+                let _ = tx.send(OverlayCommand::SetKeyboardMode(false)).await;
+            }
+        }
+
         // Broadcast move mode to all overlays
         for tx in &txs {
             let _ = tx.send(OverlayCommand::SetMoveMode(new_mode)).await;
@@ -782,9 +1026,10 @@ impl OverlayManager {
             was_running
         };
 
-        // Only respawn raid if global visibility is on
+        // Only respawn raid if global visibility is on (and grouped, unless shown solo)
         let raid_respawned = if globally_visible
             && (raid_was_running || raid_enabled)
+            && !Self::raid_hidden_solo(settings, service)
             && let Ok(result) = Self::spawn(OverlayType::Raid, settings)
             && let Ok(mut s) = state.lock()
         {
@@ -836,6 +1081,11 @@ impl OverlayManager {
             OverlayType::EffectsB,
             OverlayType::Cooldowns,
             OverlayType::DotTracker,
+            OverlayType::TankSwap,
+            OverlayType::RaidCooldowns,
+            OverlayType::DeathRecap,
+            OverlayType::BossNotes,
+            OverlayType::AlertBanner,
         ];
         for mt in MetricType::all() {
             types.push(OverlayType::Metric(*mt));
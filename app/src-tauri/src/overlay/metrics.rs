@@ -3,28 +3,42 @@
 //! Functions for converting player metrics into overlay entries.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use baras_core::PlayerMetrics;
 use baras_overlay::{Color, MetricEntry};
 
 use super::types::MetricType;
 
+/// Whether the TPS meter shows threat on the current target only
+static TPS_CURRENT_TARGET: AtomicBool = AtomicBool::new(false);
+
+/// Scope the TPS meter to the local player's target (or the boss) instead of
+/// all threat generated. Applies from the next data update.
+pub fn set_tps_current_target(enabled: bool) {
+    TPS_CURRENT_TARGET.store(enabled, Ordering::Relaxed);
+}
+
 /// Blue color for shielding portion of split bars
 fn shield_blue() -> Color {
     Color::from_rgba8(70, 130, 180, 255) // Steel blue
 }
 
 /// Extracted metric values for overlay rendering
-struct MetricValues {
+struct MetricValues<'a> {
     rate: i64,
     total: i64,
     split_rate: Option<i64>,
     split_total: Option<i64>,
     split_color: Option<Color>,
+    /// Reflected share of `rate` (damage meters only)
+    reflect_rate: i64,
+    /// Abilities listed when the entry is expanded (damage or healing meters only)
+    top_abilities: &'a [(String, i64)],
 }
 
 /// Extracts metric values from PlayerMetrics based on overlay type
-fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues {
+fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues<'_> {
     match overlay_type {
         MetricType::Dps => MetricValues {
             rate: m.dps,
@@ -32,6 +46,8 @@ fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues {
             split_rate: None,
             split_total: None,
             split_color: None,
+            reflect_rate: m.reflect_dps,
+            top_abilities: &m.top_damage_abilities,
         },
         MetricType::EDps => MetricValues {
             rate: m.edps,
@@ -39,6 +55,8 @@ fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues {
             split_rate: Some(m.bossdps),
             split_total: Some(m.total_damage_boss),
             split_color: None, // Uses default lighter color for adds
+            reflect_rate: m.reflect_dps,
+            top_abilities: &m.top_damage_abilities,
         },
         MetricType::BossDps => MetricValues {
             rate: m.bossdps,
@@ -46,6 +64,8 @@ fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues {
             split_rate: None,
             split_total: None,
             split_color: None,
+            reflect_rate: 0,
+            top_abilities: &m.top_damage_abilities,
         },
         MetricType::Hps => MetricValues {
             rate: m.hps,
@@ -53,6 +73,8 @@ fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues {
             split_rate: Some(m.ehps),
             split_total: Some(m.total_healing_effective),
             split_color: None, // Uses default lighter color for overheal
+            reflect_rate: 0,
+            top_abilities: &m.top_healing_abilities,
         },
         MetricType::EHps => MetricValues {
             // ehps/total now include shielding, split shows healing vs shields
@@ -61,6 +83,17 @@ fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues {
             split_rate: Some(m.ehps - m.abs), // Healing only (exclude shields)
             split_total: Some(m.total_healing_effective - m.total_shielding),
             split_color: Some(shield_blue()), // Blue for shield portion
+            reflect_rate: 0,
+            top_abilities: &m.top_healing_abilities,
+        },
+        MetricType::Tps if TPS_CURRENT_TARGET.load(Ordering::Relaxed) => MetricValues {
+            rate: m.target_tps,
+            total: m.target_total_threat,
+            split_rate: None,
+            split_total: None,
+            split_color: None,
+            reflect_rate: 0,
+            top_abilities: &[],
         },
         MetricType::Tps => MetricValues {
             rate: m.tps,
@@ -68,6 +101,8 @@ fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues {
             split_rate: None,
             split_total: None,
             split_color: None,
+            reflect_rate: 0,
+            top_abilities: &[],
         },
         MetricType::Dtps => MetricValues {
             rate: m.edtps,
@@ -75,6 +110,8 @@ fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues {
             split_rate: None,
             split_total: None,
             split_color: None,
+            reflect_rate: 0,
+            top_abilities: &[],
         },
         MetricType::Abs => MetricValues {
             rate: m.abs,
@@ -82,6 +119,18 @@ fn extract_values(m: &PlayerMetrics, overlay_type: MetricType) -> MetricValues {
             split_rate: None,
             split_total: None,
             split_color: None,
+            reflect_rate: 0,
+            top_abilities: &[],
+        },
+        MetricType::Dispels => MetricValues {
+            // Counts, not rates: rank by dispels performed
+            rate: m.dispels as i64,
+            total: m.dispels as i64,
+            split_rate: None,
+            split_total: None,
+            split_color: None,
+            reflect_rate: 0,
+            top_abilities: &[],
         },
     }
 }
@@ -97,30 +146,30 @@ pub fn create_entries_for_type(
 ) -> Vec<MetricEntry> {
     let mut values: Vec<_> = metrics
         .iter()
-        .map(|m| {
-            let v = extract_values(m, overlay_type);
-            let class_icon = m.class_icon.clone();
-            (m.name.clone(), v, class_icon)
-        })
+        .map(|m| (m, extract_values(m, overlay_type)))
         .collect();
 
     // Sort by rate value descending (highest first)
     values.sort_by(|a, b| b.1.rate.cmp(&a.1.rate));
 
-    let max_value = values.iter().map(|(_, v, _)| v.rate).max().unwrap_or(1);
+    let max_value = values.iter().map(|(_, v)| v.rate).max().unwrap_or(1);
 
     values
         .into_iter()
-        .map(|(name, v, class_icon)| {
-            let mut entry = MetricEntry::new(&name, v.rate, max_value).with_total(v.total);
+        .map(|(m, v)| {
+            let mut entry = MetricEntry::new(m.name.as_str(), v.rate, max_value)
+                .with_total(v.total)
+                .with_deaths(m.deaths)
+                .with_reflect(v.reflect_rate)
+                .with_top_abilities(v.top_abilities.to_vec());
             if let (Some(sr), Some(st)) = (v.split_rate, v.split_total) {
                 entry = entry.with_split(sr, st);
                 if let Some(color) = v.split_color {
                     entry = entry.with_split_color(color);
                 }
             }
-            if let Some(icon) = class_icon {
-                entry = entry.with_icon(icon);
+            if let Some(icon) = &m.class_icon {
+                entry = entry.with_icon(icon.clone());
             }
             entry
         })
@@ -6,12 +6,14 @@
 //!
 //! - `types` - Core type definitions (`MetricType`, `OverlayType`)
 //! - `state` - Runtime state management (`OverlayState`, `OverlayCommand`, `OverlayHandle`)
+//! - `snapshot` - Lock-free data handoff to overlay threads (`DataSlot`, `OverlaySlots`)
 //! - `spawn` - Overlay creation and spawning functions
 //! - `manager` - High-level overlay lifecycle operations
 //! - `metrics` - Metric entry creation helpers
 
 mod manager;
 mod metrics;
+mod snapshot;
 mod spawn;
 mod state;
 mod types;
@@ -35,16 +37,19 @@ pub use types::{MetricType, OverlayType};
 // State management
 pub use state::{OverlayCommand, OverlayHandle, OverlayState, PositionEvent};
 
+// Data handoff
+pub use snapshot::{DataSlot, OverlaySlots};
+
 // Spawn functions
 pub use spawn::{
     create_boss_health_overlay, create_metric_overlay, create_personal_overlay, create_raid_overlay,
 };
 
 // Manager
-pub use manager::OverlayManager;
+pub use manager::{MonitorsChanged, OverlayManager};
 
 // Metrics helpers
-pub use metrics::{create_all_entries, create_entries_for_type};
+pub use metrics::{create_all_entries, create_entries_for_type, set_tps_current_target};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Appearance Helper
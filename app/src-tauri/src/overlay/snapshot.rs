@@ -0,0 +1,96 @@
+//! Lock-free overlay data handoff
+//!
+//! Data updates (metrics, raid frames, timers, ...) are snapshots: only the
+//! newest one matters. Instead of queueing them on the overlay's command
+//! channel, the router publishes into a per-overlay [`DataSlot`] and the
+//! overlay thread takes whatever is there on its next tick.
+//!
+//! - Publishing never waits on a busy render thread (it just replaces the slot)
+//! - Taking never waits on the service (it swaps the slot with `None`)
+//! - The router finds slots through [`OverlaySlots`], so routing an update
+//!   doesn't touch the `SharedOverlayState` mutex
+//!
+//! Alerts are events rather than snapshots and stay on the command channel.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arc_swap::{ArcSwap, ArcSwapOption};
+use baras_overlay::OverlayData;
+
+use super::types::OverlayType;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Data Slot
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Latest-value handoff between the router and one overlay thread
+#[derive(Default)]
+pub struct DataSlot {
+    latest: ArcSwapOption<OverlayData>,
+}
+
+impl DataSlot {
+    /// Replace the pending snapshot (an unread older one is discarded)
+    pub fn publish(&self, data: OverlayData) {
+        self.latest.store(Some(Arc::new(data)));
+    }
+
+    /// Take the pending snapshot, if any
+    pub fn take(&self) -> Option<OverlayData> {
+        self.latest
+            .swap(None)
+            .map(|data| Arc::try_unwrap(data).unwrap_or_else(|shared| (*shared).clone()))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Slot Registry
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Data slots of all running overlays, readable without locking.
+///
+/// Kept in sync by `OverlayState` as overlays are inserted and removed; cloning
+/// shares the same registry.
+#[derive(Clone, Default)]
+pub struct OverlaySlots {
+    slots: Arc<ArcSwap<HashMap<OverlayType, Arc<DataSlot>>>>,
+}
+
+impl OverlaySlots {
+    /// Publish a snapshot to an overlay. Returns false if it isn't running.
+    pub fn publish(&self, kind: OverlayType, data: OverlayData) -> bool {
+        match self.slots.load().get(&kind) {
+            Some(slot) => {
+                slot.publish(data);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether an overlay is currently registered
+    pub fn contains(&self, kind: OverlayType) -> bool {
+        self.slots.load().contains_key(&kind)
+    }
+
+    pub(super) fn insert(&self, kind: OverlayType, slot: Arc<DataSlot>) {
+        self.slots.rcu(|current| {
+            let mut next = HashMap::clone(current);
+            next.insert(kind, slot.clone());
+            next
+        });
+    }
+
+    pub(super) fn remove(&self, kind: OverlayType) {
+        self.slots.rcu(|current| {
+            let mut next = HashMap::clone(current);
+            next.remove(&kind);
+            next
+        });
+    }
+
+    pub(super) fn clear(&self) {
+        self.slots.store(Arc::new(HashMap::new()));
+    }
+}
@@ -15,6 +15,7 @@
 //! To handle this, overlays are created INSIDE the spawned thread via a factory
 //! function, not passed as pre-created objects.
 
+use std::sync::Arc;
 use std::thread::{self, JoinHandle};
 use tokio::sync::mpsc::{self, Sender};
 
@@ -58,20 +59,23 @@ unsafe impl<T> Send for SendPtr<T> {}
 unsafe impl<T> Sync for SendPtr<T> {}
 
 use baras_core::context::{
-    AlertsOverlayConfig, BossHealthConfig, ChallengeOverlayConfig, OverlayAppearanceConfig,
-    OverlayPositionConfig, PersonalOverlayConfig, TimerOverlayConfig,
+    AlertBannerConfig, AlertsOverlayConfig, BossHealthConfig, BossNotesConfig,
+    ChallengeOverlayConfig, DeathRecapConfig, OverlayAppearanceConfig, OverlayPositionConfig,
+    PersonalOverlayConfig, RaidCooldownConfig, TankSwapConfig, TimerOverlayConfig,
 };
 use baras_overlay::{
-    AlertsOverlay, BossHealthOverlay, ChallengeOverlay, CooldownConfig, CooldownOverlay,
-    DotTrackerConfig, DotTrackerOverlay, EffectsABConfig, EffectsABOverlay, MetricOverlay, Overlay,
-    OverlayConfig, PersonalOverlay, RaidGridLayout, RaidOverlay, RaidOverlayConfig,
-    RaidRegistryAction, TimerOverlay,
+    AlertBannerOverlay, AlertsOverlay, BossHealthOverlay, BossNotesOverlay, ChallengeOverlay,
+    CooldownConfig, CooldownOverlay, DeathRecapOverlay, DotTrackerConfig, DotTrackerOverlay,
+    EffectsABConfig, EffectsABOverlay, MetricOverlay, Overlay, OverlayConfig, PersonalOverlay,
+    RaidCooldownsOverlay, RaidGridLayout, RaidOverlay, RaidOverlayConfig, RaidRegistryAction,
+    TankSwapOverlay, TimerOverlay, overlay_frame_interval,
 };
 use baras_types::{
     CooldownTrackerConfig, DotTrackerConfig as TypesDotTrackerConfig,
     EffectsAConfig as TypesEffectsAConfig, EffectsBConfig as TypesEffectsBConfig,
 };
 
+use super::snapshot::DataSlot;
 use super::state::{OverlayCommand, OverlayHandle, PositionEvent};
 use super::types::{MetricType, OverlayType};
 
@@ -79,6 +83,9 @@ use super::types::{MetricType, OverlayType};
 // Generic Spawn Function
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Command channel, data slot and thread handle of a freshly spawned overlay
+type SpawnedOverlay = (Sender<OverlayCommand>, Arc<DataSlot>, JoinHandle<()>);
+
 /// Spawn an overlay using a factory function that creates it inside the thread.
 ///
 /// This is critical for Windows where HWND must be created and used on the same thread.
@@ -98,12 +105,14 @@ pub fn spawn_overlay_with_factory<O, F>(
     create_overlay: F,
     kind: OverlayType,
     registry_action_tx: Option<std::sync::mpsc::Sender<RaidRegistryAction>>,
-) -> Result<(Sender<OverlayCommand>, JoinHandle<()>), String>
+) -> Result<SpawnedOverlay, String>
 where
     O: Overlay,
     F: FnOnce() -> Result<O, String> + Send + 'static,
 {
     let (tx, mut rx) = mpsc::channel::<OverlayCommand>(32);
+    let data = Arc::new(DataSlot::default());
+    let thread_data = data.clone();
 
     // Use a oneshot channel to get creation result back from spawned thread
     let (confirm_tx, confirm_rx) = std::sync::mpsc::channel::<Result<(), String>>();
@@ -137,6 +146,15 @@ where
                         overlay.set_rearrange_mode(enabled);
                         needs_render = true;
                     }
+                    OverlayCommand::SetKeyboardMode(enabled) => {
+                        overlay.set_keyboard_mode(enabled);
+                        needs_render = true;
+                    }
+                    OverlayCommand::Key(key) => {
+                        if overlay.handle_key(key) {
+                            needs_render = true;
+                        }
+                    }
                     OverlayCommand::UpdateData(data) => {
                         if overlay.update_data(data) {
                             needs_render = true;
@@ -150,6 +168,10 @@ where
                         overlay.frame_mut().window_mut().set_position(x, y);
                         needs_render = true;
                     }
+                    OverlayCommand::SetWarnings(warnings) => {
+                        overlay.frame_mut().set_warnings(warnings);
+                        needs_render = true;
+                    }
                     OverlayCommand::GetPosition(response_tx) => {
                         let pos = overlay.position();
                         let current_monitor = overlay.frame().window().current_monitor();
@@ -171,11 +193,23 @@ where
                 }
             }
 
+            // Pick up the latest data snapshot from the router
+            if let Some(data) = thread_data.take()
+                && overlay.update_data(data)
+            {
+                needs_render = true;
+            }
+
             // Poll window events (returns false if window should close)
             if !overlay.poll_events() {
                 break;
             }
 
+            // Hand keys pressed in keyboard mode to the overlay
+            if overlay.route_keys() {
+                needs_render = true;
+            }
+
             // Forward any pending registry actions to the service
             if let Some(ref tx) = registry_action_tx {
                 for action in overlay.take_pending_registry_actions() {
@@ -213,16 +247,21 @@ where
             }
 
             // Sleep longer when locked (no interaction), shorter when interactive
-            // 100ms = 10 polls/sec when locked (smooth countdowns, visual-change detection skips redundant renders)
+            // Locked: the configured overlay FPS (10 by default; smooth countdowns,
+            // visual-change detection skips redundant renders)
             // 16ms = 60 FPS when interactive (for responsive dragging)
-            let sleep_ms = if is_interactive { 16 } else { 100 };
-            thread::sleep(std::time::Duration::from_millis(sleep_ms));
+            let sleep = if is_interactive {
+                std::time::Duration::from_millis(16)
+            } else {
+                overlay_frame_interval()
+            };
+            thread::sleep(sleep);
         }
     });
 
     // Wait for confirmation from the spawned thread
     match confirm_rx.recv() {
-        Ok(Ok(())) => Ok((tx, handle)),
+        Ok(Ok(())) => Ok((tx, data, handle)),
         Ok(Err(e)) => Err(e),
         Err(_) => Err("Overlay thread exited before confirming creation".to_string()),
     }
@@ -242,12 +281,14 @@ pub fn spawn_overlay_with_factory<O, F>(
     create_overlay: F,
     kind: OverlayType,
     registry_action_tx: Option<std::sync::mpsc::Sender<RaidRegistryAction>>,
-) -> Result<(Sender<OverlayCommand>, JoinHandle<()>), String>
+) -> Result<SpawnedOverlay, String>
 where
     O: Overlay,
     F: FnOnce() -> Result<O, String> + Send + 'static,
 {
     let (tx, mut rx) = mpsc::channel::<OverlayCommand>(32);
+    let data = Arc::new(DataSlot::default());
+    let thread_data = data.clone();
 
     // Use a oneshot channel to get creation result back from spawned thread
     let (confirm_tx, confirm_rx) = std::sync::mpsc::channel::<Result<(), String>>();
@@ -294,6 +335,22 @@ where
                         });
                         needs_render = true;
                     }
+                    OverlayCommand::SetKeyboardMode(enabled) => {
+                        dispatch::Queue::main().exec_sync(move || {
+                            let overlay = unsafe { &mut *overlay_ptr.get() };
+                            overlay.set_keyboard_mode(enabled);
+                        });
+                        needs_render = true;
+                    }
+                    OverlayCommand::Key(key) => {
+                        let handled = dispatch::Queue::main().exec_sync(move || {
+                            let overlay = unsafe { &mut *overlay_ptr.get() };
+                            overlay.handle_key(key)
+                        });
+                        if handled {
+                            needs_render = true;
+                        }
+                    }
                     OverlayCommand::UpdateData(data) => {
                         let updated = dispatch::Queue::main().exec_sync(move || {
                             let overlay = unsafe { &mut *overlay_ptr.get() };
@@ -317,6 +374,13 @@ where
                         });
                         needs_render = true;
                     }
+                    OverlayCommand::SetWarnings(warnings) => {
+                        dispatch::Queue::main().exec_sync(move || {
+                            let overlay = unsafe { &mut *overlay_ptr.get() };
+                            overlay.frame_mut().set_warnings(warnings);
+                        });
+                        needs_render = true;
+                    }
                     OverlayCommand::GetPosition(response_tx) => {
                         let event = dispatch::Queue::main().exec_sync(move || {
                             let overlay = unsafe { &*overlay_ptr.get() };
@@ -348,6 +412,17 @@ where
                 }
             }
 
+            // Pick up the latest data snapshot from the router
+            if let Some(data) = thread_data.take() {
+                let updated = dispatch::Queue::main().exec_sync(move || {
+                    let overlay = unsafe { &mut *overlay_ptr.get() };
+                    overlay.update_data(data)
+                });
+                if updated {
+                    needs_render = true;
+                }
+            }
+
             // Poll window events on main thread (returns false if window should close)
             let should_continue = dispatch::Queue::main().exec_sync(move || {
                 let overlay = unsafe { &mut *overlay_ptr.get() };
@@ -358,6 +433,15 @@ where
                 break;
             }
 
+            // Hand keys pressed in keyboard mode to the overlay
+            let keys_handled = dispatch::Queue::main().exec_sync(move || {
+                let overlay = unsafe { &mut *overlay_ptr.get() };
+                overlay.route_keys()
+            });
+            if keys_handled {
+                needs_render = true;
+            }
+
             // Forward any pending registry actions to the service
             if let Some(ref tx) = registry_action_tx {
                 let actions = dispatch::Queue::main().exec_sync(move || {
@@ -418,10 +502,14 @@ where
             }
 
             // Sleep on background thread (doesn't block main thread)
-            // 100ms = 10 polls/sec when locked
+            // Locked: the configured overlay FPS (10 by default)
             // 16ms = 60 FPS when interactive
-            let sleep_ms = if is_interactive { 16 } else { 100 };
-            thread::sleep(std::time::Duration::from_millis(sleep_ms));
+            let sleep = if is_interactive {
+                std::time::Duration::from_millis(16)
+            } else {
+                overlay_frame_interval()
+            };
+            thread::sleep(sleep);
         }
 
         // Clean up overlay on main thread
@@ -432,7 +520,7 @@ where
 
     // Wait for confirmation from the spawned thread
     match confirm_rx.recv() {
-        Ok(Ok(())) => Ok((tx, handle)),
+        Ok(Ok(())) => Ok((tx, data, handle)),
         Ok(Err(e)) => Err(e),
         Err(_) => Err("Overlay thread exited before confirming creation".to_string()),
     }
@@ -491,10 +579,11 @@ pub fn create_metric_overlay(
         .map_err(|e| format!("Failed to create {} overlay: {}", title, e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -533,10 +622,11 @@ pub fn create_personal_overlay(
             .map_err(|e| format!("Failed to create personal overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -573,10 +663,11 @@ pub fn create_raid_overlay(
             .map_err(|e| format!("Failed to create raid overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, Some(registry_tx))?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, Some(registry_tx))?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: Some(registry_rx),
@@ -606,10 +697,11 @@ pub fn create_boss_health_overlay(
             .map_err(|e| format!("Failed to create boss health overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -639,10 +731,11 @@ pub fn create_timers_a_overlay(
             .map_err(|e| format!("Failed to create Timers A overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -672,10 +765,11 @@ pub fn create_timers_b_overlay(
             .map_err(|e| format!("Failed to create Timers B overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -705,10 +799,11 @@ pub fn create_challenges_overlay(
             .map_err(|e| format!("Failed to create challenges overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -738,10 +833,11 @@ pub fn create_alerts_overlay(
             .map_err(|e| format!("Failed to create alerts overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -789,10 +885,11 @@ pub fn create_effects_a_overlay(
             .map_err(|e| format!("Failed to create Effects A overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -840,10 +937,11 @@ pub fn create_effects_b_overlay(
             .map_err(|e| format!("Failed to create Effects B overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -884,10 +982,11 @@ pub fn create_cooldowns_overlay(
             .map_err(|e| format!("Failed to create cooldowns overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -928,10 +1027,181 @@ pub fn create_dot_tracker_overlay(
             .map_err(|e| format!("Failed to create DOT tracker overlay: {}", e))
     };
 
-    let (tx, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+
+    Ok(OverlayHandle {
+        tx,
+        data,
+        handle,
+        kind,
+        registry_action_rx: None,
+    })
+}
+
+/// Create and spawn the tank swap overlay
+pub fn create_tank_swap_overlay(
+    position: OverlayPositionConfig,
+    tank_swap_config: TankSwapConfig,
+    background_alpha: u8,
+) -> Result<OverlayHandle, String> {
+    let config = OverlayConfig {
+        x: position.x,
+        y: position.y,
+        width: position.width,
+        height: position.height,
+        namespace: "baras-tank-swap".to_string(),
+        click_through: true,
+        target_monitor_id: position.monitor_id.clone(),
+    };
+
+    let kind = OverlayType::TankSwap;
+
+    let factory = move || {
+        TankSwapOverlay::new(config, tank_swap_config, background_alpha)
+            .map_err(|e| format!("Failed to create tank swap overlay: {}", e))
+    };
+
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+
+    Ok(OverlayHandle {
+        tx,
+        data,
+        handle,
+        kind,
+        registry_action_rx: None,
+    })
+}
+
+/// Create and spawn the raid cooldown overlay
+pub fn create_raid_cooldowns_overlay(
+    position: OverlayPositionConfig,
+    cooldown_config: RaidCooldownConfig,
+    background_alpha: u8,
+) -> Result<OverlayHandle, String> {
+    let config = OverlayConfig {
+        x: position.x,
+        y: position.y,
+        width: position.width,
+        height: position.height,
+        namespace: "baras-raid-cooldowns".to_string(),
+        click_through: true,
+        target_monitor_id: position.monitor_id.clone(),
+    };
+
+    let kind = OverlayType::RaidCooldowns;
+
+    let factory = move || {
+        RaidCooldownsOverlay::new(config, cooldown_config, background_alpha)
+            .map_err(|e| format!("Failed to create raid cooldown overlay: {}", e))
+    };
+
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+
+    Ok(OverlayHandle {
+        tx,
+        data,
+        handle,
+        kind,
+        registry_action_rx: None,
+    })
+}
+
+/// Create and spawn the death recap overlay
+pub fn create_death_recap_overlay(
+    position: OverlayPositionConfig,
+    recap_config: DeathRecapConfig,
+    background_alpha: u8,
+) -> Result<OverlayHandle, String> {
+    let config = OverlayConfig {
+        x: position.x,
+        y: position.y,
+        width: position.width,
+        height: position.height,
+        namespace: "baras-death-recap".to_string(),
+        click_through: true,
+        target_monitor_id: position.monitor_id.clone(),
+    };
+
+    let kind = OverlayType::DeathRecap;
+
+    let factory = move || {
+        DeathRecapOverlay::new(config, recap_config, background_alpha)
+            .map_err(|e| format!("Failed to create death recap overlay: {}", e))
+    };
+
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+
+    Ok(OverlayHandle {
+        tx,
+        data,
+        handle,
+        kind,
+        registry_action_rx: None,
+    })
+}
+
+/// Create and spawn the boss notes overlay
+pub fn create_boss_notes_overlay(
+    position: OverlayPositionConfig,
+    notes_config: BossNotesConfig,
+    background_alpha: u8,
+) -> Result<OverlayHandle, String> {
+    let config = OverlayConfig {
+        x: position.x,
+        y: position.y,
+        width: position.width,
+        height: position.height,
+        namespace: "baras-boss-notes".to_string(),
+        click_through: true,
+        target_monitor_id: position.monitor_id.clone(),
+    };
+
+    let kind = OverlayType::BossNotes;
+
+    let factory = move || {
+        BossNotesOverlay::new(config, notes_config, background_alpha)
+            .map_err(|e| format!("Failed to create boss notes overlay: {}", e))
+    };
+
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
+
+    Ok(OverlayHandle {
+        tx,
+        data,
+        handle,
+        kind,
+        registry_action_rx: None,
+    })
+}
+
+/// Create and spawn the alert banner overlay
+pub fn create_alert_banner_overlay(
+    position: OverlayPositionConfig,
+    banner_config: AlertBannerConfig,
+    background_alpha: u8,
+) -> Result<OverlayHandle, String> {
+    let config = OverlayConfig {
+        x: position.x,
+        y: position.y,
+        width: position.width,
+        height: position.height,
+        namespace: "baras-alert-banner".to_string(),
+        click_through: true,
+        target_monitor_id: position.monitor_id.clone(),
+    };
+
+    let kind = OverlayType::AlertBanner;
+
+    let factory = move || {
+        AlertBannerOverlay::new(config, banner_config, background_alpha)
+            .map_err(|e| format!("Failed to create alert banner overlay: {}", e))
+    };
+
+    let (tx, data, handle) = spawn_overlay_with_factory(factory, kind, None)?;
 
     Ok(OverlayHandle {
         tx,
+        data,
         handle,
         kind,
         registry_action_rx: None,
@@ -3,11 +3,13 @@
 //! Types for managing overlay runtime state, commands, and thread handles.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::thread::JoinHandle;
 use tokio::sync::mpsc::Sender;
 
-use baras_overlay::{OverlayConfigUpdate, OverlayData};
+use baras_overlay::{OverlayConfigUpdate, OverlayData, OverlayKey};
 
+use super::snapshot::{DataSlot, OverlaySlots};
 use super::types::{MetricType, OverlayType};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -18,14 +20,21 @@ use super::types::{MetricType, OverlayType};
 pub enum OverlayCommand {
     /// Toggle move/resize mode (global, all overlays)
     SetMoveMode(bool),
-    /// Toggle rearrange mode (raid overlay only - click-to-swap frames)
+    /// Toggle rearrange mode (raid overlay only - click or drag to swap frames)
     SetRearrangeMode(bool),
-    /// Update overlay data (metrics or personal stats)
+    /// Toggle keyboard mode (global - clicked overlays take arrow/enter/escape keys)
+    SetKeyboardMode(bool),
+    /// Deliver a key to the overlay as if pressed in keyboard mode (hotkeys)
+    Key(OverlayKey),
+    /// Update overlay data. Routine updates go through the overlay's `DataSlot`;
+    /// this is for one-off data (initial fill on spawn, alerts).
     UpdateData(OverlayData),
     /// Update overlay configuration
     UpdateConfig(OverlayConfigUpdate),
     /// Set overlay position (x, y in screen coordinates)
     SetPosition(i32, i32),
+    /// Warning badge for degraded subsystems as (subsystem, problem); empty clears it
+    SetWarnings(Vec<(String, String)>),
     /// Request current position via oneshot channel
     GetPosition(tokio::sync::oneshot::Sender<PositionEvent>),
     /// Shutdown the overlay
@@ -57,6 +66,8 @@ pub struct PositionEvent {
 /// Handle to a running overlay instance
 pub struct OverlayHandle {
     pub tx: Sender<OverlayCommand>,
+    /// Latest data snapshot, read by the overlay thread each tick
+    pub data: Arc<DataSlot>,
     pub handle: JoinHandle<()>,
     pub kind: OverlayType,
     /// Optional receiver for registry actions from the raid overlay
@@ -74,10 +85,14 @@ pub struct OverlayState {
     pub overlays: HashMap<OverlayType, OverlayHandle>,
     /// Global move mode state
     pub move_mode: bool,
-    /// Raid rearrange mode state (click-to-swap frames)
+    /// Raid rearrange mode state (click or drag to swap frames)
     pub rearrange_mode: bool,
+    /// Global keyboard mode state (overlays take keyboard focus when clicked)
+    pub keyboard_mode: bool,
     /// Whether overlays are currently visible (mirrors config)
     pub overlays_visible: bool,
+    /// Lock-free view of the running overlays' data slots (for the router)
+    pub slots: OverlaySlots,
 }
 
 impl Default for OverlayState {
@@ -86,7 +101,9 @@ impl Default for OverlayState {
             overlays: HashMap::new(),
             move_mode: false,
             rearrange_mode: false,
+            keyboard_mode: false,
             overlays_visible: true,
+            slots: OverlaySlots::default(),
         }
     }
 }
@@ -215,16 +232,19 @@ impl OverlayState {
 
     /// Insert an overlay handle
     pub fn insert(&mut self, handle: OverlayHandle) {
+        self.slots.insert(handle.kind, handle.data.clone());
         self.overlays.insert(handle.kind, handle);
     }
 
     /// Remove an overlay by kind
     pub fn remove(&mut self, kind: OverlayType) -> Option<OverlayHandle> {
+        self.slots.remove(kind);
         self.overlays.remove(&kind)
     }
 
     /// Drain all overlays
     pub fn drain(&mut self) -> Vec<OverlayHandle> {
+        self.slots.clear();
         self.overlays.drain().map(|(_, h)| h).collect()
     }
 
@@ -249,4 +269,10 @@ impl OverlayState {
         self.rearrange_mode = enabled;
         // Note: Actual broadcast to overlay must be done by caller with async context
     }
+
+    /// Set keyboard mode for all overlays
+    pub fn set_keyboard_mode(&mut self, enabled: bool) {
+        self.keyboard_mode = enabled;
+        // Note: Actual broadcast to overlays must be done by caller with async context
+    }
 }
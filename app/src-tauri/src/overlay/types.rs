@@ -20,6 +20,7 @@ pub enum MetricType {
     Abs,
     Dtps,
     Tps,
+    Dispels,
 }
 
 impl MetricType {
@@ -34,6 +35,7 @@ impl MetricType {
             MetricType::Tps => "Threat",
             MetricType::Dtps => "Damage Taken",
             MetricType::Abs => "Shielding Given",
+            MetricType::Dispels => "Dispels",
         }
     }
 
@@ -48,6 +50,7 @@ impl MetricType {
             MetricType::Tps => "baras-tps",
             MetricType::Dtps => "baras-dtps",
             MetricType::Abs => "baras-abs",
+            MetricType::Dispels => "baras-dispels",
         }
     }
 
@@ -62,6 +65,7 @@ impl MetricType {
             MetricType::Tps => (50, 510),
             MetricType::Dtps => (350, 50),
             MetricType::Abs => (350, 280),
+            MetricType::Dispels => (350, 510),
         }
     }
 
@@ -76,6 +80,7 @@ impl MetricType {
             MetricType::Abs,
             MetricType::Dtps,
             MetricType::Tps,
+            MetricType::Dispels,
         ]
     }
 
@@ -90,6 +95,7 @@ impl MetricType {
             MetricType::Tps => "tps",
             MetricType::Dtps => "dtps",
             MetricType::Abs => "abs",
+            MetricType::Dispels => "dispels",
         }
     }
 
@@ -103,6 +109,7 @@ impl MetricType {
             "tps" => Some(MetricType::Tps),
             "dtps" => Some(MetricType::Dtps),
             "abs" => Some(MetricType::Abs),
+            "dispels" => Some(MetricType::Dispels),
             _ => None,
         }
     }
@@ -146,6 +153,16 @@ pub enum OverlayType {
     Cooldowns,
     /// DOTs on enemy targets
     DotTracker,
+    /// Tank-swap debuff stacks
+    TankSwap,
+    /// Defensive and raid utility cooldowns of every player
+    RaidCooldowns,
+    /// Events leading up to a player's death
+    DeathRecap,
+    /// User notes for the current boss
+    BossNotes,
+    /// Center-screen banner for high priority alerts
+    AlertBanner,
 }
 
 impl OverlayType {
@@ -164,6 +181,11 @@ impl OverlayType {
             OverlayType::EffectsB => "effects_b",
             OverlayType::Cooldowns => "cooldowns",
             OverlayType::DotTracker => "dot_tracker",
+            OverlayType::TankSwap => "tank_swap",
+            OverlayType::RaidCooldowns => "raid_cooldowns",
+            OverlayType::DeathRecap => "death_recap",
+            OverlayType::BossNotes => "boss_notes",
+            OverlayType::AlertBanner => "alert_banner",
         }
     }
 
@@ -182,6 +204,11 @@ impl OverlayType {
             OverlayType::EffectsB => "baras-effects-b".to_string(),
             OverlayType::Cooldowns => "baras-cooldowns".to_string(),
             OverlayType::DotTracker => "baras-dot-tracker".to_string(),
+            OverlayType::TankSwap => "baras-tank-swap".to_string(),
+            OverlayType::RaidCooldowns => "baras-raid-cooldowns".to_string(),
+            OverlayType::DeathRecap => "baras-death-recap".to_string(),
+            OverlayType::BossNotes => "baras-boss-notes".to_string(),
+            OverlayType::AlertBanner => "baras-alert-banner".to_string(),
         }
     }
 
@@ -200,6 +227,11 @@ impl OverlayType {
             OverlayType::EffectsB => (350, 280),
             OverlayType::Cooldowns => (50, 500),
             OverlayType::DotTracker => (50, 650),
+            OverlayType::TankSwap => (650, 850),
+            OverlayType::RaidCooldowns => (950, 700),
+            OverlayType::DeathRecap => (950, 550),
+            OverlayType::BossNotes => (1250, 50),
+            OverlayType::AlertBanner => (710, 300),
         }
     }
 }
@@ -8,16 +8,19 @@ use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
 use crate::overlay::{
-    MetricType, OverlayCommand, OverlayManager, OverlayType, SharedOverlayState, create_all_entries,
+    MetricType, OverlayCommand, OverlayManager, OverlaySlots, OverlayType, SharedOverlayState,
+    create_all_entries,
 };
 use crate::service::{OverlayUpdate, ServiceHandle};
-use crate::state::SharedState;
+use crate::state::{SharedState, Subsystem};
 use baras_overlay::{OverlayData, RaidRegistryAction};
 use tokio::sync::mpsc;
 
 /// Spawn the overlay update router task.
 ///
 /// Routes service updates to overlay threads. Uses select! to avoid polling.
+/// Queued updates are drained in batches and coalesced, so a burst (heavy AoE)
+/// only routes the newest snapshot of each overlay.
 pub fn spawn_overlay_router(
     mut rx: mpsc::Receiver<OverlayUpdate>,
     overlay_state: SharedOverlayState,
@@ -52,25 +55,38 @@ pub fn spawn_overlay_router(
         }
     });
 
+    // Data slots are shared with OverlayState, which keeps them in sync as
+    // overlays start and stop; the router only ever reads them
+    let slots = overlay_state
+        .lock()
+        .map(|s| s.slots.clone())
+        .unwrap_or_default();
+
     // Main router loop - no timeout needed, uses select!
     tauri::async_runtime::spawn(async move {
         loop {
             tokio::select! {
                 // Wait for overlay updates
                 update = rx.recv() => {
-                    match update {
-                        Some(update) => {
-                            process_overlay_update(
-                                &overlay_state,
-                                &service_handle,
-                                &shared,
-                                update,
-                            ).await;
-                        }
-                        None => {
-                            // Channel closed
-                            break;
-                        }
+                    let Some(update) = update else {
+                        // Channel closed
+                        break;
+                    };
+
+                    // Take everything queued behind it so a burst is routed in one pass
+                    let mut batch = vec![update];
+                    while let Ok(update) = rx.try_recv() {
+                        batch.push(update);
+                    }
+
+                    for update in coalesce_updates(batch, &shared) {
+                        process_overlay_update(
+                            &overlay_state,
+                            &slots,
+                            &service_handle,
+                            &shared,
+                            update,
+                        ).await;
                     }
                 }
                 // Wait for registry actions
@@ -84,6 +100,45 @@ pub fn spawn_overlay_router(
     });
 }
 
+/// Drop snapshot updates superseded by a later update of the same kind.
+///
+/// Other updates (combat end, clears, alerts, ...) are kept in order and act as
+/// barriers: a snapshot is never skipped in favour of one queued after a barrier.
+fn coalesce_updates(batch: Vec<OverlayUpdate>, shared: &SharedState) -> Vec<OverlayUpdate> {
+    if batch.len() < 2 {
+        return batch;
+    }
+
+    let mut kept = Vec::with_capacity(batch.len());
+    let mut seen = Vec::new();
+    let mut skipped = 0;
+
+    // Walk newest first so the first snapshot seen of each kind is the latest
+    for update in batch.into_iter().rev() {
+        if !update.is_snapshot() {
+            seen.clear();
+            kept.push(update);
+            continue;
+        }
+        let kind = std::mem::discriminant(&update);
+        if seen.contains(&kind) {
+            skipped += 1;
+        } else {
+            seen.push(kind);
+            kept.push(update);
+        }
+    }
+    kept.reverse();
+
+    if skipped > 0 {
+        shared
+            .overlay_updates
+            .coalesced
+            .fetch_add(skipped, Ordering::Relaxed);
+    }
+    kept
+}
+
 /// Process a registry action from the raid overlay
 async fn process_registry_action(service_handle: &ServiceHandle, action: RaidRegistryAction) {
     match action {
@@ -97,8 +152,12 @@ async fn process_registry_action(service_handle: &ServiceHandle, action: RaidReg
 }
 
 /// Process a single overlay update
+///
+/// Data snapshots are published to the overlays' data slots, which never
+/// blocks on a busy render thread or on the overlay state mutex.
 async fn process_overlay_update(
     overlay_state: &SharedOverlayState,
+    slots: &OverlaySlots,
     service_handle: &ServiceHandle,
     shared: &Arc<SharedState>,
     update: OverlayUpdate,
@@ -108,144 +167,72 @@ async fn process_overlay_update(
             // Create entries for all metric overlay types
             let all_entries = create_all_entries(&data.metrics);
 
-            // Get running metric overlays and their channels
-            let (metric_txs, personal_tx): (Vec<_>, _) = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-
-                let metric_txs = MetricType::all()
-                    .iter()
-                    .filter_map(|&overlay_type| {
-                        let kind = OverlayType::Metric(overlay_type);
-                        state.get_tx(kind).cloned().map(|tx| (overlay_type, tx))
-                    })
-                    .collect();
-
-                let personal_tx = state.get_personal_tx().cloned();
-
-                (metric_txs, personal_tx)
-            };
-
-            // Send entries to each running metric overlay
-            for (overlay_type, tx) in metric_txs {
-                if let Some(entries) = all_entries.get(&overlay_type) {
-                    let _ = tx
-                        .send(OverlayCommand::UpdateData(OverlayData::Metrics(
-                            entries.clone(),
-                        )))
-                        .await;
-                }
+            // Publish entries to each running metric overlay
+            for (overlay_type, entries) in all_entries {
+                slots.publish(
+                    OverlayType::Metric(overlay_type),
+                    OverlayData::Metrics(entries),
+                );
             }
 
-            // Send personal stats to personal overlay
-            if let Some(tx) = personal_tx
+            // Publish personal stats to personal overlay
+            if slots.contains(OverlayType::Personal)
                 && let Some(stats) = data.to_personal_stats()
             {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::Personal(stats)))
-                    .await;
+                slots.publish(OverlayType::Personal, OverlayData::Personal(stats));
             }
 
-            // Send challenges data to challenges overlay
-            let challenges_tx = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-                state.get_challenges_tx().cloned()
-            };
-
-            if let Some(tx) = challenges_tx
-                && let Some(challenges) = data.challenges
-            {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::Challenges(
-                        challenges,
-                    )))
-                    .await;
+            // Publish challenges data to challenges overlay
+            if let Some(challenges) = data.challenges {
+                slots.publish(OverlayType::Challenges, OverlayData::Challenges(challenges));
             }
         }
         OverlayUpdate::EffectsUpdated(raid_data) => {
-            // Send raid frame data to raid overlay
-            let raid_tx = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-                state.get_raid_tx().cloned()
-            };
-
-            if let Some(tx) = raid_tx {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::Raid(raid_data)))
-                    .await;
-            }
+            slots.publish(OverlayType::Raid, OverlayData::Raid(raid_data));
         }
         OverlayUpdate::BossHealthUpdated(boss_data) => {
-            // Send boss health data to boss health overlay
-            let boss_tx = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-                state.get_boss_health_tx().cloned()
-            };
-
-            if let Some(tx) = boss_tx {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::BossHealth(
-                        boss_data,
-                    )))
-                    .await;
-            }
+            slots.publish(OverlayType::BossHealth, OverlayData::BossHealth(boss_data));
         }
         OverlayUpdate::TimersAUpdated(timer_data) => {
-            // Send timer A data to Timers A overlay
-            let timer_tx = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-                state.get_timers_a_tx().cloned()
-            };
-
-            if let Some(tx) = timer_tx {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::TimersA(timer_data)))
-                    .await;
-            }
+            slots.publish(OverlayType::TimersA, OverlayData::TimersA(timer_data));
         }
         OverlayUpdate::TimersBUpdated(timer_data) => {
-            // Send timer B data to Timers B overlay
-            let timer_tx = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-                state.get_timers_b_tx().cloned()
-            };
-
-            if let Some(tx) = timer_tx {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::TimersB(timer_data)))
-                    .await;
-            }
+            slots.publish(OverlayType::TimersB, OverlayData::TimersB(timer_data));
         }
         OverlayUpdate::AlertsFired(fired_alerts) => {
-            // Convert FiredAlert to AlertEntry and send to alerts overlay
-            use baras_overlay::AlertEntry;
+            // Alerts accumulate in the overlay, so every batch must arrive:
+            // they go through the command channel instead of a data slot
+            use baras_core::timers::AlertPriority;
+            use baras_overlay::{AlertBannerData, AlertEntry};
             use std::time::Instant;
 
-            let alerts_tx = {
+            let (alerts_tx, banner_tx) = {
                 let state = match overlay_state.lock() {
                     Ok(s) => s,
                     Err(_) => return,
                 };
-                state.get_tx(OverlayType::Alerts).cloned()
+                (
+                    state.get_tx(OverlayType::Alerts).cloned(),
+                    state.get_tx(OverlayType::AlertBanner).cloned(),
+                )
             };
 
+            // The banner only shows the newest high priority alert of the batch
+            if let Some(tx) = banner_tx
+                && let Some(alert) = fired_alerts
+                    .iter()
+                    .rev()
+                    .find(|a| a.priority == AlertPriority::High)
+            {
+                let banner = AlertBannerData::new(
+                    alert.text.clone(),
+                    alert.color.unwrap_or([255, 255, 255, 255]),
+                );
+                let _ = tx
+                    .send(OverlayCommand::UpdateData(OverlayData::AlertBanner(banner)))
+                    .await;
+            }
+
             if let Some(tx) = alerts_tx {
                 let entries: Vec<AlertEntry> = fired_alerts
                     .into_iter()
@@ -265,193 +252,120 @@ async fn process_overlay_update(
             }
         }
         OverlayUpdate::EffectsAUpdated(effects_data) => {
-            let tx = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-                state.get_effects_a_tx().cloned()
-            };
-
-            if let Some(tx) = tx {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::EffectsA(
-                        effects_data,
-                    )))
-                    .await;
-            }
+            slots.publish(OverlayType::EffectsA, OverlayData::EffectsA(effects_data));
         }
         OverlayUpdate::EffectsBUpdated(effects_data) => {
-            let tx = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-                state.get_effects_b_tx().cloned()
-            };
-
-            if let Some(tx) = tx {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::EffectsB(
-                        effects_data,
-                    )))
-                    .await;
-            }
+            slots.publish(OverlayType::EffectsB, OverlayData::EffectsB(effects_data));
         }
         OverlayUpdate::CooldownsUpdated(cooldowns_data) => {
-            let tx = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-                state.get_cooldowns_tx().cloned()
-            };
-
-            if let Some(tx) = tx {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::Cooldowns(
-                        cooldowns_data,
-                    )))
-                    .await;
-            }
+            slots.publish(
+                OverlayType::Cooldowns,
+                OverlayData::Cooldowns(cooldowns_data),
+            );
         }
         OverlayUpdate::DotTrackerUpdated(dot_data) => {
-            let tx = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-                state.get_dot_tracker_tx().cloned()
-            };
-
-            if let Some(tx) = tx {
-                let _ = tx
-                    .send(OverlayCommand::UpdateData(OverlayData::DotTracker(
-                        dot_data,
-                    )))
-                    .await;
-            }
+            slots.publish(OverlayType::DotTracker, OverlayData::DotTracker(dot_data));
+        }
+        OverlayUpdate::TankSwapUpdated(tank_data) => {
+            slots.publish(OverlayType::TankSwap, OverlayData::TankSwap(tank_data));
+        }
+        OverlayUpdate::RaidCooldownsUpdated(cooldown_data) => {
+            slots.publish(
+                OverlayType::RaidCooldowns,
+                OverlayData::RaidCooldowns(cooldown_data),
+            );
+        }
+        OverlayUpdate::DeathRecapUpdated(recap_data) => {
+            slots.publish(OverlayType::DeathRecap, OverlayData::DeathRecap(recap_data));
+        }
+        OverlayUpdate::BossNotesUpdated(notes_data) => {
+            slots.publish(OverlayType::BossNotes, OverlayData::BossNotes(notes_data));
         }
         OverlayUpdate::CombatStarted => {
             // Could show overlay or clear entries
         }
         OverlayUpdate::CombatEnded => {
-            // Clear boss health, timer, and challenges overlays when combat ends
-            let channels: Vec<_> = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-
-                let mut channels = Vec::new();
-
-                // Boss health overlay
-                if let Some(tx) = state.get_boss_health_tx() {
-                    channels.push((tx.clone(), OverlayData::BossHealth(Default::default())));
-                }
-
-                // Timers A overlay
-                if let Some(tx) = state.get_timers_a_tx() {
-                    channels.push((tx.clone(), OverlayData::TimersA(Default::default())));
-                }
-
-                // Timers B overlay
-                if let Some(tx) = state.get_timers_b_tx() {
-                    channels.push((tx.clone(), OverlayData::TimersB(Default::default())));
-                }
-
-                // Challenges overlay
-                if let Some(tx) = state.get_challenges_tx() {
-                    channels.push((tx.clone(), OverlayData::Challenges(Default::default())));
-                }
-
-                channels
-            };
-
-            for (tx, data) in channels {
-                let _ = tx.send(OverlayCommand::UpdateData(data)).await;
-            }
+            // Clear timer and challenges overlays when combat ends. Boss health
+            // lingers through short gaps and is cleared by the service.
+            slots.publish(
+                OverlayType::TimersA,
+                OverlayData::TimersA(Default::default()),
+            );
+            slots.publish(
+                OverlayType::TimersB,
+                OverlayData::TimersB(Default::default()),
+            );
+            slots.publish(
+                OverlayType::Challenges,
+                OverlayData::Challenges(Default::default()),
+            );
         }
         OverlayUpdate::ClearAllData => {
             // Clear all overlay data when switching files
-            // Collect channels while holding lock, then release before awaiting
             use baras_overlay::RaidFrameData;
 
-            let channels: Vec<_> = {
-                let state = match overlay_state.lock() {
-                    Ok(s) => s,
-                    Err(_) => return,
-                };
-
-                let mut channels = Vec::new();
-
-                // Collect metric overlay channels
-                for metric_type in MetricType::all() {
-                    if let Some(tx) = state.get_tx(OverlayType::Metric(*metric_type)) {
-                        channels.push((tx.clone(), OverlayData::Metrics(vec![])));
-                    }
-                }
-
-                // Personal overlay
-                if let Some(tx) = state.get_personal_tx() {
-                    channels.push((tx.clone(), OverlayData::Personal(Default::default())));
-                }
-
-                // Raid overlay
-                if let Some(tx) = state.get_raid_tx() {
-                    channels.push((
-                        tx.clone(),
-                        OverlayData::Raid(RaidFrameData { frames: vec![] }),
-                    ));
-                }
-
-                // Boss health overlay
-                if let Some(tx) = state.get_boss_health_tx() {
-                    channels.push((tx.clone(), OverlayData::BossHealth(Default::default())));
-                }
-
-                // Timers A overlay
-                if let Some(tx) = state.get_timers_a_tx() {
-                    channels.push((tx.clone(), OverlayData::TimersA(Default::default())));
-                }
-
-                // Timers B overlay
-                if let Some(tx) = state.get_timers_b_tx() {
-                    channels.push((tx.clone(), OverlayData::TimersB(Default::default())));
-                }
-
-                // Challenges overlay
-                if let Some(tx) = state.get_challenges_tx() {
-                    channels.push((tx.clone(), OverlayData::Challenges(Default::default())));
-                }
-
-                // Effects A overlay
-                if let Some(tx) = state.get_effects_a_tx() {
-                    channels.push((tx.clone(), OverlayData::EffectsA(Default::default())));
-                }
-
-                // Effects B overlay
-                if let Some(tx) = state.get_effects_b_tx() {
-                    channels.push((tx.clone(), OverlayData::EffectsB(Default::default())));
-                }
-
-                // Cooldowns overlay
-                if let Some(tx) = state.get_cooldowns_tx() {
-                    channels.push((tx.clone(), OverlayData::Cooldowns(Default::default())));
-                }
-
-                // DOT tracker overlay
-                if let Some(tx) = state.get_dot_tracker_tx() {
-                    channels.push((tx.clone(), OverlayData::DotTracker(Default::default())));
-                }
-
-                channels
-            }; // Lock released here
-
-            // Now send to all channels (outside lock scope)
-            for (tx, data) in channels {
-                let _ = tx.send(OverlayCommand::UpdateData(data)).await;
+            for metric_type in MetricType::all() {
+                slots.publish(
+                    OverlayType::Metric(*metric_type),
+                    OverlayData::Metrics(vec![]),
+                );
             }
+            slots.publish(
+                OverlayType::Personal,
+                OverlayData::Personal(Default::default()),
+            );
+            slots.publish(
+                OverlayType::Raid,
+                OverlayData::Raid(RaidFrameData { frames: vec![] }),
+            );
+            slots.publish(
+                OverlayType::BossHealth,
+                OverlayData::BossHealth(Default::default()),
+            );
+            slots.publish(
+                OverlayType::TimersA,
+                OverlayData::TimersA(Default::default()),
+            );
+            slots.publish(
+                OverlayType::TimersB,
+                OverlayData::TimersB(Default::default()),
+            );
+            slots.publish(
+                OverlayType::Challenges,
+                OverlayData::Challenges(Default::default()),
+            );
+            slots.publish(
+                OverlayType::EffectsA,
+                OverlayData::EffectsA(Default::default()),
+            );
+            slots.publish(
+                OverlayType::EffectsB,
+                OverlayData::EffectsB(Default::default()),
+            );
+            slots.publish(
+                OverlayType::Cooldowns,
+                OverlayData::Cooldowns(Default::default()),
+            );
+            slots.publish(
+                OverlayType::DotTracker,
+                OverlayData::DotTracker(Default::default()),
+            );
+            slots.publish(
+                OverlayType::TankSwap,
+                OverlayData::TankSwap(Default::default()),
+            );
+            slots.publish(
+                OverlayType::RaidCooldowns,
+                OverlayData::RaidCooldowns(Default::default()),
+            );
+            slots.publish(
+                OverlayType::DeathRecap,
+                OverlayData::DeathRecap(Default::default()),
+            );
+            slots.publish(
+                OverlayType::BossNotes,
+                OverlayData::BossNotes(Default::default()),
+            );
         }
         OverlayUpdate::ConversationStarted => {
             // Check if auto-hide during conversations is enabled
@@ -500,5 +414,74 @@ async fn process_overlay_update(
                 }
             }
         }
+        OverlayUpdate::GroupChanged(_) => {
+            if let Err(e) =
+                OverlayManager::sync_raid_group_visibility(overlay_state, service_handle).await
+            {
+                tracing::warn!(error = %e, "Failed to update raid frames for group change");
+            }
+        }
+        OverlayUpdate::SettingsChanged => {
+            if let Err(e) = OverlayManager::refresh_settings(overlay_state, service_handle).await {
+                tracing::warn!(error = %e, "Failed to refresh overlays after config reload");
+            }
+        }
+        OverlayUpdate::HealthChanged(status) => {
+            match &status.problem {
+                Some(problem) => {
+                    tracing::warn!(subsystem = ?status.subsystem, %problem, "Subsystem degraded")
+                }
+                None => tracing::info!(subsystem = ?status.subsystem, "Subsystem recovered"),
+            }
+
+            // Resend every overlay its full badge list, not just the change
+            let problems = shared.health.problems();
+            let targets: Vec<_> = match overlay_state.lock() {
+                Ok(state) => state
+                    .all_overlays()
+                    .into_iter()
+                    .map(|(kind, tx)| {
+                        let warnings: Vec<(String, String)> = problems
+                            .iter()
+                            .filter(|(subsystem, _)| subsystem_affects(*subsystem, kind))
+                            .map(|(subsystem, problem)| {
+                                (subsystem.label().to_string(), problem.clone())
+                            })
+                            .collect();
+                        (tx.clone(), warnings)
+                    })
+                    .collect(),
+                Err(_) => return,
+            };
+            for (tx, warnings) in targets {
+                let _ = tx.send(OverlayCommand::SetWarnings(warnings)).await;
+            }
+        }
+    }
+}
+
+/// Whether an overlay shows data fed by `subsystem`
+fn subsystem_affects(subsystem: Subsystem, kind: OverlayType) -> bool {
+    match subsystem {
+        Subsystem::Tailing => true,
+        Subsystem::EffectTracker => matches!(
+            kind,
+            OverlayType::Raid
+                | OverlayType::EffectsA
+                | OverlayType::EffectsB
+                | OverlayType::Cooldowns
+                | OverlayType::DotTracker
+                | OverlayType::TankSwap
+                | OverlayType::RaidCooldowns
+        ),
+        Subsystem::Definitions => matches!(
+            kind,
+            OverlayType::BossHealth
+                | OverlayType::TimersA
+                | OverlayType::TimersB
+                | OverlayType::Challenges
+                | OverlayType::Alerts
+                | OverlayType::AlertBanner
+        ),
     }
 }
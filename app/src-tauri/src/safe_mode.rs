@@ -0,0 +1,57 @@
+//! Safe mode
+//!
+//! Recovery path for configs that crash or hang overlay spawning (e.g. overlay
+//! positions on a monitor that no longer exists). Safe mode disables every
+//! overlay, resets overlay positions and appearance to defaults, and leaves
+//! global hotkeys unregistered.
+//!
+//! Entered either by launching with `--safe-mode` or from the tray menu, and
+//! lasts until the app exits. The overrides live in the in-memory config only:
+//! saves write the original overlay settings in their place (see
+//! `AppConfigExt::enter_safe_mode`), so the saved layout is there again on the
+//! next normal start. For the same reason, config updates that would change
+//! overlay settings are rejected while safe mode lasts.
+
+use baras_core::context::AppConfigExt;
+use tauri::{AppHandle, Emitter, Runtime};
+
+use crate::overlay::{OverlayManager, SharedOverlayState};
+use crate::service::ServiceHandle;
+
+/// Command-line flag that starts the app in safe mode
+pub const FLAG: &str = "--safe-mode";
+
+/// Whether the current process was launched with `--safe-mode`
+pub fn requested() -> bool {
+    std::env::args().skip(1).any(|arg| arg == FLAG)
+}
+
+/// Switch a running app into safe mode (tray action)
+pub async fn enter<R: Runtime>(
+    app: &AppHandle<R>,
+    overlay_state: &SharedOverlayState,
+    service: &ServiceHandle,
+) {
+    tracing::warn!("Entering safe mode: disabling overlays, hotkeys and custom appearance");
+
+    unregister_hotkeys(app);
+
+    let mut config = service.config().await;
+    config.enter_safe_mode();
+    service.shared.apply_config(config).await;
+
+    // Stops every running overlay, since none are enabled anymore
+    if let Err(e) = OverlayManager::refresh_settings(overlay_state, service).await {
+        tracing::error!(error = %e, "Failed to stop overlays for safe mode");
+    }
+
+    let _ = app.emit("settings-changed", ());
+}
+
+fn unregister_hotkeys<R: Runtime>(app: &AppHandle<R>) {
+    use tauri_plugin_global_shortcut::GlobalShortcutExt;
+
+    if let Err(e) = app.global_shortcut().unregister_all() {
+        tracing::error!(error = %e, "Failed to unregister global hotkeys");
+    }
+}
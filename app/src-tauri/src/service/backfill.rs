@@ -0,0 +1,150 @@
+//! Background backfill for logs parsed newest-first
+//!
+//! Large files are first parsed only from the area of their final combat, so the
+//! latest encounters show up quickly. A second parse worker then runs over the
+//! whole file in the background; its summaries replace the history (keeping any
+//! encounters finished live since) and the signals it journaled for the skipped
+//! part of the file are prepended to the session journal.
+
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+
+use baras_core::context::ParsingSession;
+use baras_core::storage::{BACKFILL_JOURNAL_FILENAME, JOURNAL_FILENAME, prepend_journal};
+use baras_types::{SESSION_UPDATED_EVENT, SessionEvent};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use super::{
+    ParseWorkerOutput, checkpoint, decode_worker_output, player_discipline_info,
+    record_encounter_history,
+};
+use crate::state::SharedState;
+
+/// Files at least this large are parsed newest-first
+pub(super) const NEWEST_FIRST_MIN_BYTES: u64 = 64 * 1024 * 1024;
+
+/// A running backfill parse
+pub(super) struct Backfill {
+    worker: Arc<Mutex<Child>>,
+    task: tokio::task::JoinHandle<()>,
+    shared: Arc<SharedState>,
+}
+
+impl Backfill {
+    /// Spawn `cmd` (a parse worker over the whole file) and merge its output into
+    /// `session` once it finishes
+    pub fn start(
+        mut cmd: Command,
+        session: Arc<RwLock<ParsingSession>>,
+        encounters_dir: PathBuf,
+        shared: Arc<SharedState>,
+        app_handle: AppHandle,
+    ) -> std::io::Result<Self> {
+        let mut child = cmd.stdout(Stdio::piped()).spawn()?;
+        let stdout = child.stdout.take();
+        let worker = Arc::new(Mutex::new(child));
+        shared.parse_backfill_pending.store(true, Ordering::SeqCst);
+
+        let task = tokio::spawn({
+            let worker = worker.clone();
+            let shared = shared.clone();
+            async move {
+                let timer = std::time::Instant::now();
+                let result = tokio::task::spawn_blocking(move || wait_for_output(stdout, &worker))
+                    .await
+                    .map_err(|e| e.to_string())
+                    .and_then(|output| output);
+
+                match result {
+                    Ok(output) => {
+                        merge(&session, output, &encounters_dir).await;
+                        shared.id_dictionary.merge_session(&encounters_dir);
+                        shared.parse_backfill_pending.store(false, Ordering::SeqCst);
+                        info!(
+                            elapsed_ms = timer.elapsed().as_millis() as u64,
+                            "Backfill parse completed"
+                        );
+                        let _ = app_handle.emit(SESSION_UPDATED_EVENT, SessionEvent::FileLoaded);
+                        checkpoint::save_active_session(&shared).await;
+                        record_encounter_history(&shared).await;
+                    }
+                    Err(e) => {
+                        shared.parse_backfill_pending.store(false, Ordering::SeqCst);
+                        warn!(error = %e, "Backfill parse failed, history only covers the latest area");
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            worker,
+            task,
+            shared,
+        })
+    }
+
+    /// Stop the task and kill the worker (e.g. when another file is opened)
+    pub async fn cancel(self) {
+        self.task.abort();
+        let _ = self.task.await;
+        let mut worker = self.worker.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = worker.kill();
+        let _ = worker.wait();
+        self.shared
+            .parse_backfill_pending
+            .store(false, Ordering::SeqCst);
+    }
+}
+
+/// Read the worker's JSON output and wait for it to exit
+fn wait_for_output(
+    stdout: Option<std::process::ChildStdout>,
+    worker: &Mutex<Child>,
+) -> Result<ParseWorkerOutput, String> {
+    let mut json = Vec::new();
+    if let Some(mut stdout) = stdout {
+        stdout
+            .read_to_end(&mut json)
+            .map_err(|e| format!("Failed to read worker output: {}", e))?;
+    }
+    let status = worker
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .wait()
+        .map_err(|e| e.to_string())?;
+    if !status.success() {
+        return Err(format!("Worker exited with {}", status));
+    }
+    decode_worker_output(json)
+}
+
+async fn merge(
+    session: &RwLock<ParsingSession>,
+    output: ParseWorkerOutput,
+    encounters_dir: &std::path::Path,
+) {
+    let mut session = session.write().await;
+    if let Some(cache) = &mut session.session_cache {
+        cache.encounter_history.merge_backfill(output.encounters);
+        // Players only seen before the latest area
+        for disc in &output.player_disciplines {
+            cache
+                .player_disciplines
+                .entry(disc.entity_id)
+                .or_insert_with(|| player_discipline_info(disc));
+        }
+    }
+
+    // Live journal flushes happen under the session lock, which is held here
+    if let Err(e) = prepend_journal(
+        &encounters_dir.join(BACKFILL_JOURNAL_FILENAME),
+        &encounters_dir.join(JOURNAL_FILENAME),
+    ) {
+        warn!(error = %e, "Failed to merge backfill signal journal");
+    }
+}
@@ -0,0 +1,316 @@
+//! Parse checkpoints for resuming the active log after a restart
+//!
+//! After the initial parse and after every combat, the service records where the
+//! last finished encounter ends in the log, together with the session state the
+//! parse worker would otherwise rebuild (encounter summaries, player, area,
+//! disciplines). When the same file is opened again and its bytes up to that
+//! point are unchanged, the checkpoint is restored and only the rest of the file
+//! is parsed. The session's parquet files are kept alongside for the explorer.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+
+use baras_core::context::{ParsingSession, resolve};
+use tracing::{debug, warn};
+
+use super::{ParseWorkerOutput, WorkerAreaInfo, WorkerPlayerDiscipline, WorkerPlayerInfo};
+use crate::state::SharedState;
+
+/// Bytes hashed at the start of the file and before the checkpoint position
+const FINGERPRINT_LEN: u64 = 4096;
+
+/// A resumable position in one log file
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(super) struct ParseCheckpoint {
+    /// Log file name, also the name of the session's data directory
+    pub session_id: String,
+    /// Hash of the first [`FINGERPRINT_LEN`] bytes of the file
+    prefix_hash: u64,
+    /// Hash of the [`FINGERPRINT_LEN`] bytes before `output.end_pos`
+    tail_hash: u64,
+    /// Session state at `output.end_pos`, in the parse worker's format
+    pub output: ParseWorkerOutput,
+}
+
+fn checkpoint_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("parse_checkpoint.json"))
+}
+
+fn read_stored() -> Option<ParseCheckpoint> {
+    let content = std::fs::read_to_string(checkpoint_path()?).ok()?;
+    serde_json::from_str(&content)
+        .inspect_err(|e| warn!(error = %e, "Ignoring unreadable parse checkpoint"))
+        .ok()
+}
+
+/// Session whose data directory must survive the startup cleanup
+pub fn stored_session_id() -> Option<String> {
+    read_stored().map(|c| c.session_id)
+}
+
+impl ParseCheckpoint {
+    /// Stored checkpoint for `log_path`, if the file still starts with the
+    /// checkpointed bytes and the session's parquet files are still on disk
+    pub fn load(log_path: &Path, session_id: &str) -> Option<Self> {
+        let checkpoint = read_stored()?.matching(log_path, session_id)?;
+
+        let data_dir = baras_core::storage::data_dir().ok()?.join(session_id);
+        if !data_dir.is_dir() {
+            debug!(session_id, "Parse checkpoint has no data directory");
+            return None;
+        }
+        Some(checkpoint)
+    }
+
+    /// The checkpoint, if it was taken for `session_id` and `log_path` still
+    /// has the checkpointed bytes up to `end_pos`
+    fn matching(self, log_path: &Path, session_id: &str) -> Option<Self> {
+        if self.session_id != session_id {
+            return None;
+        }
+        let (prefix_hash, tail_hash) = fingerprint(log_path, self.output.end_pos).ok()?;
+        if prefix_hash != self.prefix_hash || tail_hash != self.tail_hash {
+            debug!(session_id, "Log file changed since parse checkpoint");
+            return None;
+        }
+        Some(self)
+    }
+
+    /// Checkpoint at the end of the session's last finished encounter
+    pub fn capture(session: &ParsingSession) -> Option<Self> {
+        let log_path = session.active_file.as_ref()?;
+        let session_id = log_path.file_name()?.to_str()?.to_string();
+        let cache = session.session_cache.as_ref()?;
+        let encounters = cache.encounter_history.summaries();
+        let (_, end_pos) = encounters.last()?.log_range?;
+        let (prefix_hash, tail_hash) = fingerprint(log_path, end_pos).ok()?;
+
+        let output = ParseWorkerOutput {
+            start_pos: 0,
+            end_pos,
+            // The resumed parse dates its lines on from the last encounter
+            last_timestamp: encounters.last()?.end_time.clone(),
+            event_count: 0,
+            encounter_count: encounters.len(),
+            encounters: encounters.to_vec(),
+            player: WorkerPlayerInfo {
+                name: resolve(cache.player.name).to_string(),
+                class_name: cache.player.class_name.clone(),
+                discipline_name: cache.player.discipline_name.clone(),
+                entity_id: cache.player.id,
+            },
+            area: WorkerAreaInfo {
+                area_name: cache.current_area.area_name.clone(),
+                area_id: cache.current_area.area_id,
+                difficulty_id: cache.current_area.difficulty_id,
+                difficulty_name: cache.current_area.difficulty_name.clone(),
+            },
+            player_disciplines: cache
+                .player_disciplines
+                .values()
+                .map(|p| WorkerPlayerDiscipline {
+                    entity_id: p.id,
+                    name: resolve(p.name).to_string(),
+                    class_id: p.class_id,
+                    class_name: p.class_name.clone(),
+                    discipline_id: p.discipline_id,
+                    discipline_name: p.discipline_name.clone(),
+                })
+                .collect(),
+            elapsed_ms: 0,
+        };
+
+        Some(Self {
+            session_id,
+            prefix_hash,
+            tail_hash,
+            output,
+        })
+    }
+
+    /// Replace the stored checkpoint (written to a temp file, then renamed)
+    pub fn save(&self) {
+        let Some(path) = checkpoint_path() else {
+            return;
+        };
+        let tmp = path.with_extension("json.tmp");
+        let result = serde_json::to_vec(self)
+            .map_err(std::io::Error::other)
+            .and_then(|json| std::fs::write(&tmp, json))
+            .and_then(|()| std::fs::rename(&tmp, &path));
+        if let Err(e) = result {
+            warn!(error = %e, "Failed to save parse checkpoint");
+        }
+    }
+}
+
+/// Capture and store a checkpoint of the active session
+pub(super) async fn save_active_session(shared: &SharedState) {
+    // The history is incomplete until a backfill parse has merged in
+    if shared.parse_backfill_pending.load(Ordering::SeqCst) {
+        return;
+    }
+    let checkpoint = {
+        let session_guard = shared.session.read().await;
+        let Some(session) = session_guard.as_ref() else {
+            return;
+        };
+        let session = session.read().await;
+        ParseCheckpoint::capture(&session)
+    };
+    if let Some(checkpoint) = checkpoint {
+        let _ = tokio::task::spawn_blocking(move || checkpoint.save()).await;
+    }
+}
+
+/// Hashes of the start of the file and of the bytes before `end_pos`
+fn fingerprint(log_path: &Path, end_pos: u64) -> std::io::Result<(u64, u64)> {
+    let mut file = File::open(log_path)?;
+    if file.metadata()?.len() < end_pos {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+
+    let mut prefix = vec![0; FINGERPRINT_LEN.min(end_pos) as usize];
+    file.read_exact(&mut prefix)?;
+
+    let tail_start = end_pos.saturating_sub(FINGERPRINT_LEN);
+    let mut tail = vec![0; (end_pos - tail_start) as usize];
+    file.seek(SeekFrom::Start(tail_start))?;
+    file.read_exact(&mut tail)?;
+
+    Ok((fnv1a(&prefix), fnv1a(&tail)))
+}
+
+/// FNV-1a: stable across builds, unlike std's hasher
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    const SESSION_ID: &str = "combat_2025-01-01_20_00_00_000000.txt";
+
+    /// A log longer than both fingerprint windows
+    fn write_log(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("baras-checkpoint-{}-{}", std::process::id(), name));
+        let lines: String = (0..500)
+            .map(|i| {
+                format!(
+                    "[20:00:{:02}.000] [@Player] [] [Event {{836045448945472}}]\n",
+                    i % 60
+                )
+            })
+            .collect();
+        std::fs::write(&path, lines).unwrap();
+        path
+    }
+
+    fn checkpoint_at(log_path: &Path, end_pos: u64) -> ParseCheckpoint {
+        let (prefix_hash, tail_hash) = fingerprint(log_path, end_pos).unwrap();
+        ParseCheckpoint {
+            session_id: SESSION_ID.to_string(),
+            prefix_hash,
+            tail_hash,
+            output: ParseWorkerOutput {
+                start_pos: 0,
+                end_pos,
+                last_timestamp: None,
+                event_count: 0,
+                encounter_count: 0,
+                encounters: Vec::new(),
+                player: WorkerPlayerInfo {
+                    name: String::new(),
+                    class_name: String::new(),
+                    discipline_name: String::new(),
+                    entity_id: 0,
+                },
+                area: WorkerAreaInfo {
+                    area_name: String::new(),
+                    area_id: 0,
+                    difficulty_id: 0,
+                    difficulty_name: String::new(),
+                },
+                player_disciplines: Vec::new(),
+                elapsed_ms: 0,
+            },
+        }
+    }
+
+    fn overwrite_byte(path: &Path, pos: u64) {
+        let mut file = std::fs::OpenOptions::new().write(true).open(path).unwrap();
+        file.seek(SeekFrom::Start(pos)).unwrap();
+        file.write_all(b"#").unwrap();
+    }
+
+    #[test]
+    fn test_appended_log_resumes() {
+        let path = write_log("appended");
+        let end_pos = std::fs::metadata(&path).unwrap().len();
+        let checkpoint = checkpoint_at(&path, end_pos);
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        file.write_all(b"[20:10:00.000] [@Player] [] [Event {836045448945472}]\n")
+            .unwrap();
+
+        let resumed = checkpoint.matching(&path, SESSION_ID).unwrap();
+        assert_eq!(resumed.output.end_pos, end_pos);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_changed_log_is_rejected() {
+        let path = write_log("changed");
+        let end_pos = std::fs::metadata(&path).unwrap().len();
+        assert!(end_pos > FINGERPRINT_LEN * 2);
+
+        // Start of the file rewritten
+        let checkpoint = checkpoint_at(&path, end_pos);
+        overwrite_byte(&path, 10);
+        assert!(checkpoint.matching(&path, SESSION_ID).is_none());
+
+        // Bytes just before the checkpoint rewritten
+        let checkpoint = checkpoint_at(&path, end_pos);
+        overwrite_byte(&path, end_pos - 2);
+        assert!(checkpoint.matching(&path, SESSION_ID).is_none());
+
+        // Truncated below the checkpoint
+        let checkpoint = checkpoint_at(&path, end_pos);
+        let file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+        file.set_len(end_pos - 100).unwrap();
+        assert!(checkpoint.matching(&path, SESSION_ID).is_none());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_other_session_is_rejected() {
+        let path = write_log("session");
+        let end_pos = std::fs::metadata(&path).unwrap().len();
+        let checkpoint = checkpoint_at(&path, end_pos);
+
+        assert!(
+            checkpoint
+                .matching(&path, "combat_2025-01-02_20_00_00_000000.txt")
+                .is_none()
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_fnv1a_is_stable() {
+        // Stored in checkpoints, so must never change between builds
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
@@ -2,22 +2,28 @@
 //!
 //! Provides async methods for Tauri commands to interact with the background service.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use tokio::sync::mpsc;
 
-use baras_core::EncounterSummary;
-use baras_core::context::{AppConfig, AppConfigExt, resolve};
+use baras_core::boss::AreaIndex;
+use baras_core::context::{
+    AppConfig, AppConfigExt, AreaCoverageReport, AreaHistory, AssignmentCheck, IgnoreList,
+    SetupHealth, check_setup_health, interner_stats, load_raid_plan, raid_plan_path, resolve,
+};
 use baras_core::encounter::EncounterState;
 use baras_core::game_data::Discipline;
 use baras_core::query::{
-    AbilityBreakdown, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow, DataTab,
-    EffectChartData, EffectWindow, EncounterTimeline, EntityBreakdown, PlayerDeath,
-    RaidOverviewRow, TimeRange, TimeSeriesPoint,
+    AbilityBreakdown, BigHit, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow,
+    DataTab, EffectChartData, EffectWindow, EncounterTimeline, EntityBreakdown, ExportFormat,
+    ExportTable, PlayerDeath, PullComparison, QueryContext, QueryError, RaidOverviewRow,
+    SessionAggregate, TimeRange, TimeSeriesPoint, export_query_results,
 };
+use baras_core::{BarasError, EncounterSummary};
+use baras_types::{RaidSlotAssignment, TimelineEvent};
 
-use super::{CombatData, LogFileInfo, ServiceCommand, SessionInfo};
+use super::{CombatData, LogFileInfo, MemoryStats, ServiceCommand, SessionInfo};
 use crate::state::SharedState;
 
 /// Handle to communicate with the combat service and query state
@@ -48,6 +54,20 @@ impl ServiceHandle {
             .map_err(|e| e.to_string())
     }
 
+    /// Flush the live session and config and stop the service.
+    /// Returns once the service has finished (or immediately if it already stopped).
+    pub async fn shutdown(&self) {
+        let (done_tx, done_rx) = tokio::sync::oneshot::channel();
+        if self
+            .cmd_tx
+            .send(ServiceCommand::Shutdown(done_tx))
+            .await
+            .is_ok()
+        {
+            let _ = done_rx.await;
+        }
+    }
+
     /// Check if currently tailing a file
     pub async fn is_tailing(&self) -> bool {
         self.shared.session.read().await.is_some()
@@ -119,6 +139,42 @@ impl ServiceHandle {
         index.len()
     }
 
+    /// Check the log directory and today's combat logging output
+    pub async fn setup_health(&self) -> SetupHealth {
+        let log_directory = self.shared.config.read().await.log_directory.clone();
+        let index = self.shared.directory_index.read().await;
+        let today = chrono::Local::now().date_naive();
+        check_setup_health(Path::new(&log_directory), &index, today)
+    }
+
+    /// Scan the indexed logs for operations entered and check them against
+    /// the areas that have boss definitions.
+    pub async fn area_coverage(&self, area_index: AreaIndex) -> Result<AreaCoverageReport, String> {
+        let logs: Vec<_> = self
+            .shared
+            .directory_index
+            .read()
+            .await
+            .entries()
+            .into_iter()
+            .filter(|e| !e.is_empty)
+            .map(|e| (e.path.clone(), e.created_at))
+            .collect();
+
+        tokio::task::spawn_blocking(move || {
+            let mut history = AreaHistory::new();
+            for (path, created_at) in logs {
+                match std::fs::read(&path) {
+                    Ok(bytes) => history.record_log(&bytes, created_at),
+                    Err(e) => tracing::warn!(path = %path.display(), error = %e, "Skipping unreadable log"),
+                }
+            }
+            history.coverage_report(&area_index)
+        })
+        .await
+        .map_err(|e| e.to_string())
+    }
+
     /// Clean up log files based on provided settings. Returns (empty_deleted, old_deleted).
     pub async fn cleanup_logs(
         &self,
@@ -145,49 +201,34 @@ impl ServiceHandle {
     }
 
     /// Update the configuration
-    pub async fn update_config(&self, config: AppConfig) -> Result<(), String> {
-        let old_config = self.shared.config.read().await.clone();
-        let old_dir = old_config.log_directory.clone();
-        let new_dir = config.log_directory.clone();
-
-        // Check if grid size changed
-        let old_slots = old_config.overlay_settings.raid_overlay.grid_columns
-            * old_config.overlay_settings.raid_overlay.grid_rows;
-        let new_slots = config.overlay_settings.raid_overlay.grid_columns
-            * config.overlay_settings.raid_overlay.grid_rows;
-
-        let alacrity_changed = old_config.alacrity_percent != config.alacrity_percent;
-        let latency_changed = old_config.latency_ms != config.latency_ms;
-        let new_alacrity = config.alacrity_percent;
-        let new_latency = config.latency_ms;
-
-        *self.shared.config.write().await = config.clone();
-        if let Err(e) = config.save() {
-            tracing::error!(error = %e, "Failed to save configuration");
+    pub async fn update_config(&self, mut config: AppConfig) -> Result<(), String> {
+        let current = self.shared.config.read().await;
+        if current.edits_safe_mode_overlays(&config) {
+            return Err(
+                "Overlay settings can't be changed in safe mode. Restart BARAS normally to edit them."
+                    .to_string(),
+            );
         }
+        let live_server_changed = current.live_server != config.live_server;
+        // The frontend's copy doesn't carry the safe mode stash
+        if config.safe_mode_stash.is_none() {
+            config.safe_mode_stash = current.safe_mode_stash.clone();
+        }
+        drop(current);
 
-        // Update raid registry max slots if grid size changed
-        if new_slots != old_slots {
-            self.shared.raid_registry.lock().unwrap_or_else(|p| p.into_inner()).set_max_slots(new_slots);
+        let dir_changed = self.shared.apply_config(config.clone()).await;
+        if let Err(e) = config.save() {
+            tracing::error!(error = %e, "Failed to save configuration");
         }
 
-        // Update effect tracker alacrity/latency if changed
-        if alacrity_changed || latency_changed {
-            if let Some(session) = self.shared.session.read().await.as_ref() {
-                let session = session.read().await;
-                if let Some(tracker) = session.effect_tracker() {
-                    let mut tracker = tracker.lock().unwrap_or_else(|p| p.into_inner());
-                    if alacrity_changed {
-                        tracker.set_alacrity(new_alacrity);
-                    }
-                    if latency_changed {
-                        tracker.set_latency(new_latency);
-                    }
-                }
-            }
+        if live_server_changed {
+            self.cmd_tx
+                .send(ServiceCommand::LiveServerSettingsChanged)
+                .await
+                .map_err(|e| e.to_string())?;
         }
 
-        if old_dir != new_dir {
+        if dir_changed {
             self.cmd_tx
                 .send(ServiceCommand::DirectoryChanged)
                 .await
@@ -200,6 +241,35 @@ impl ServiceHandle {
     // Session Data
     // ─────────────────────────────────────────────────────────────────────────
 
+    /// Estimate memory held by each subsystem
+    pub async fn memory_stats(&self) -> MemoryStats {
+        let (interned_strings, interner_bytes) = interner_stats();
+        let query_context_bytes = self.shared.query_context.estimated_memory_bytes().await;
+
+        let mut session_cache_bytes = 0;
+        let mut encounter_buffer_bytes = 0;
+        let mut encounter_buffer_rows = 0;
+        if let Some(session) = self.shared.session.read().await.as_ref() {
+            let session = session.read().await;
+            if let Some(cache) = session.session_cache.as_ref() {
+                session_cache_bytes = cache.estimated_heap_bytes();
+            }
+            if let Some(writer) = session.encounter_writer() {
+                encounter_buffer_bytes = writer.estimated_heap_bytes();
+                encounter_buffer_rows = writer.len();
+            }
+        }
+
+        MemoryStats {
+            interned_strings,
+            interner_bytes,
+            session_cache_bytes,
+            query_context_bytes,
+            encounter_buffer_bytes,
+            encounter_buffer_rows,
+        }
+    }
+
     /// Get current session info
     pub async fn session_info(&self) -> Option<SessionInfo> {
         let session_guard = self.shared.session.read().await;
@@ -300,10 +370,65 @@ impl ServiceHandle {
 
     /// Get current combat data (unified for all overlays)
     pub async fn current_combat_data(&self) -> Option<CombatData> {
-        super::calculate_combat_data(&self.shared).await
+        super::calculate_combat_data(&self.shared, &mut Default::default()).await
     }
 
     /// Get encounter history for the current log file
+    /// Timeline markers of the current session, read from its signal journal
+    pub async fn session_timeline(&self) -> Result<Vec<TimelineEvent>, String> {
+        let journal_path = {
+            let session_guard = self.shared.session.read().await;
+            let Some(session) = session_guard.as_ref() else {
+                return Ok(Vec::new());
+            };
+            let session = session.read().await;
+            let Some(dir) = session.encounters_dir() else {
+                return Ok(Vec::new());
+            };
+            dir.join(baras_core::storage::JOURNAL_FILENAME)
+        };
+        if !journal_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        tokio::task::spawn_blocking(move || baras_core::storage::read_timeline(&journal_path))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| e.to_string())
+    }
+
+    /// Copy the raw log lines of encounter `encounter_idx` (index into the encounter
+    /// history) to `dest`, returning the number of bytes written
+    pub async fn export_encounter_raw(
+        &self,
+        encounter_idx: usize,
+        dest: PathBuf,
+    ) -> Result<u64, String> {
+        let (log_path, range) = {
+            let session_guard = self.shared.session.read().await;
+            let session = session_guard.as_ref().ok_or("No active session")?;
+            let session = session.read().await;
+            let log_path = session.active_file.clone().ok_or("No log file loaded")?;
+            let cache = session.session_cache.as_ref().ok_or("No active session")?;
+            let summary = cache
+                .encounter_history
+                .summaries()
+                .get(encounter_idx)
+                .ok_or_else(|| format!("Encounter {} not found", encounter_idx))?;
+            let range = summary
+                .log_range
+                .ok_or("Encounter has no recorded log position")?;
+            (log_path, range)
+        };
+
+        tokio::task::spawn_blocking(move || {
+            baras_core::combat_log::export_log_segment(&log_path, range, &dest)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     pub async fn encounter_history(&self) -> Vec<EncounterSummary> {
         let session_guard = self.shared.session.read().await;
         let Some(session) = session_guard.as_ref() else {
@@ -323,19 +448,40 @@ impl ServiceHandle {
 
     /// Swap two slots in the raid registry
     pub async fn swap_raid_slots(&self, slot_a: u8, slot_b: u8) {
-        self.shared.raid_registry.lock().unwrap_or_else(|p| p.into_inner()).swap_slots(slot_a, slot_b);
+        self.shared
+            .raid_registry
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .swap_slots(slot_a, slot_b);
         self.refresh_raid_frames().await;
     }
 
     /// Remove a slot from the raid registry
     pub async fn remove_raid_slot(&self, slot: u8) {
-        self.shared.raid_registry.lock().unwrap_or_else(|p| p.into_inner()).remove_slot(slot);
+        self.shared
+            .raid_registry
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .remove_slot(slot);
         self.refresh_raid_frames().await;
     }
 
+    /// Every raid frame slot with the player in it
+    pub fn raid_slots(&self) -> Vec<RaidSlotAssignment> {
+        self.shared
+            .raid_registry
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .assignments()
+    }
+
     /// Clear all raid registry slots
     pub async fn clear_raid_registry(&self) {
-        self.shared.raid_registry.lock().unwrap_or_else(|p| p.into_inner()).clear();
+        self.shared
+            .raid_registry
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .clear();
         self.refresh_raid_frames().await;
     }
 
@@ -389,40 +535,60 @@ impl ServiceHandle {
         self.shared.is_live_tailing.load(Ordering::SeqCst)
     }
 
+    /// Extract the bundled demo log and open it in demo mode
+    pub async fn load_demo(&self, archive: PathBuf) -> Result<(), String> {
+        let path = tokio::task::spawn_blocking(move || {
+            let dir = baras_core::storage::demo_dir()?;
+            baras_core::storage::extract_demo_log(&archive, &dir)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Failed to extract demo log: {}", e))?;
+        self.cmd_tx
+            .send(ServiceCommand::OpenDemo(path))
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Check if the demo log is open
+    pub fn is_demo(&self) -> bool {
+        self.shared.is_demo.load(Ordering::SeqCst)
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Query Operations
     // ─────────────────────────────────────────────────────────────────────────
 
     /// Query ability breakdown for a specific encounter and data tab.
-    /// If encounter_idx is None, queries the live encounter buffer.
+    /// If encounter_key is None, queries the live encounter buffer.
     pub async fn query_breakdown(
         &self,
         tab: DataTab,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         entity_name: Option<String>,
         time_range: Option<TimeRange>,
         entity_types: Option<Vec<String>>,
         breakdown_mode: Option<BreakdownMode>,
         duration_secs: Option<f32>,
-    ) -> Result<Vec<AbilityBreakdown>, String> {
+    ) -> Result<Vec<AbilityBreakdown>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
+        if let Some(key) = encounter_key {
             // Query historical parquet
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
             // Query live buffer
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -443,31 +609,33 @@ impl ServiceHandle {
                 duration_secs,
             )
             .await
+            .map_err(BarasError::from)
     }
 
     /// Query breakdown by entity for a specific encounter and data tab.
     pub async fn query_entity_breakdown(
         &self,
         tab: DataTab,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         time_range: Option<TimeRange>,
-    ) -> Result<Vec<EntityBreakdown>, String> {
+        breakdown_mode: Option<BreakdownMode>,
+    ) -> Result<Vec<EntityBreakdown>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -476,19 +644,20 @@ impl ServiceHandle {
             .query()
             .await
             .query()
-            .breakdown_by_entity(tab, time_range.as_ref())
+            .breakdown_by_entity(tab, time_range.as_ref(), breakdown_mode.as_ref())
             .await
+            .map_err(BarasError::from)
     }
 
     /// Query raid overview - aggregated stats per player.
     pub async fn query_raid_overview(
         &self,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         time_range: Option<TimeRange>,
         duration_secs: Option<f32>,
-    ) -> Result<Vec<RaidOverviewRow>, String> {
+    ) -> Result<Vec<RaidOverviewRow>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
         // Build discipline map - source depends on live vs historical query
@@ -497,7 +666,7 @@ impl ServiceHandle {
             (String, String, String, String),
         > = std::collections::HashMap::new();
 
-        if let Some(idx) = encounter_idx {
+        if let Some(key) = encounter_key {
             // Historical query: get discipline info from that encounter's summary
             // This captures the discipline each player had AT THAT TIME
             if let Some(cache) = session.session_cache.as_ref() {
@@ -505,7 +674,7 @@ impl ServiceHandle {
                     .encounter_history
                     .summaries()
                     .iter()
-                    .find(|s| s.encounter_id == idx as u64)
+                    .find(|s| s.encounter_key == key)
                 {
                     for pm in &summary.player_metrics {
                         if let (Some(class_icon), Some(role_icon)) = (&pm.class_icon, &pm.role_icon)
@@ -513,10 +682,10 @@ impl ServiceHandle {
                             player_discipline_map.insert(
                                 pm.name.clone(),
                                 (
-                                    pm.class_name.clone().unwrap_or_default(),
+                                    pm.class_name.as_deref().unwrap_or_default().to_string(),
                                     pm.discipline_name.clone().unwrap_or_default(),
-                                    class_icon.clone(),
-                                    role_icon.clone(),
+                                    class_icon.to_string(),
+                                    role_icon.to_string(),
                                 ),
                             );
                         }
@@ -524,10 +693,12 @@ impl ServiceHandle {
                 }
             }
 
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
@@ -546,10 +717,8 @@ impl ServiceHandle {
                 }
             }
 
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -580,27 +749,27 @@ impl ServiceHandle {
     /// Query DPS over time for a specific encounter.
     pub async fn query_dps_over_time(
         &self,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         bucket_ms: i64,
         source_name: Option<String>,
         time_range: Option<TimeRange>,
-    ) -> Result<Vec<TimeSeriesPoint>, String> {
+    ) -> Result<Vec<TimeSeriesPoint>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -611,52 +780,54 @@ impl ServiceHandle {
             .query()
             .dps_over_time(bucket_ms, source_name.as_deref(), time_range.as_ref())
             .await
+            .map_err(BarasError::from)
     }
 
-    /// Get list of available encounter parquet files.
-    pub async fn list_encounter_files(&self) -> Result<Vec<u32>, String> {
+    /// Get keys of available encounter parquet files (chronological order).
+    pub async fn list_encounter_files(&self) -> Result<Vec<String>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        let dir = session.encounters_dir().ok_or("No encounters directory")?;
+        let dir = session
+            .encounters_dir()
+            .ok_or(QueryError::NoEncountersDir)?;
 
-        let mut indices = Vec::new();
+        let mut keys = Vec::new();
         if let Ok(entries) = std::fs::read_dir(dir) {
             for entry in entries.flatten() {
                 if let Some(name) = entry.file_name().to_str()
-                    && name.ends_with(".parquet")
-                    && let Ok(idx) = name.trim_end_matches(".parquet").parse::<u32>()
+                    && let Some(key) = name.strip_suffix(".parquet")
                 {
-                    indices.push(idx);
+                    keys.push(key.to_string());
                 }
             }
         }
-        indices.sort();
-        Ok(indices)
+        keys.sort();
+        Ok(keys)
     }
 
     /// Query encounter timeline with phase segments.
     pub async fn query_encounter_timeline(
         &self,
-        encounter_idx: Option<u32>,
-    ) -> Result<EncounterTimeline, String> {
+        encounter_key: Option<String>,
+    ) -> Result<EncounterTimeline, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -667,32 +838,33 @@ impl ServiceHandle {
             .query()
             .encounter_timeline()
             .await
+            .map_err(BarasError::from)
     }
 
     /// Query HPS over time for a specific encounter.
     pub async fn query_hps_over_time(
         &self,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         bucket_ms: i64,
         source_name: Option<String>,
         time_range: Option<TimeRange>,
-    ) -> Result<Vec<TimeSeriesPoint>, String> {
+    ) -> Result<Vec<TimeSeriesPoint>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -703,32 +875,33 @@ impl ServiceHandle {
             .query()
             .hps_over_time(bucket_ms, source_name.as_deref(), time_range.as_ref())
             .await
+            .map_err(BarasError::from)
     }
 
     /// Query DTPS over time for a specific encounter.
     pub async fn query_dtps_over_time(
         &self,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         bucket_ms: i64,
         target_name: Option<String>,
         time_range: Option<TimeRange>,
-    ) -> Result<Vec<TimeSeriesPoint>, String> {
+    ) -> Result<Vec<TimeSeriesPoint>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -739,32 +912,76 @@ impl ServiceHandle {
             .query()
             .dtps_over_time(bucket_ms, target_name.as_deref(), time_range.as_ref())
             .await
+            .map_err(BarasError::from)
+    }
+
+    /// Query hits of at least `min_amount` for chart annotations.
+    /// `taken` selects hits taken by `entity_name` instead of dealt by it.
+    pub async fn query_big_hits(
+        &self,
+        encounter_key: Option<String>,
+        min_amount: f64,
+        entity_name: Option<String>,
+        taken: bool,
+        time_range: Option<TimeRange>,
+    ) -> Result<Vec<BigHit>, BarasError> {
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
+        let session = session.read().await;
+
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
+            if !path.exists() {
+                return Err(QueryError::EncounterNotFound { path }.into());
+            }
+            self.shared.query_context.register_parquet(&path).await?;
+        } else {
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
+            self.shared.query_context.register_batch(batch).await?;
+        }
+
+        let ctx = self.shared.query_context.query().await;
+        let query = ctx.query();
+        let hits = if taken {
+            query
+                .big_hits_taken(min_amount, entity_name.as_deref(), time_range.as_ref())
+                .await
+        } else {
+            query
+                .big_hits_dealt(min_amount, entity_name.as_deref(), time_range.as_ref())
+                .await
+        };
+        hits.map_err(BarasError::from)
     }
 
     /// Query effect uptime statistics for the charts panel.
     pub async fn query_effect_uptime(
         &self,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         target_name: Option<String>,
         time_range: Option<TimeRange>,
         duration_secs: f32,
-    ) -> Result<Vec<EffectChartData>, String> {
+    ) -> Result<Vec<EffectChartData>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -775,33 +992,34 @@ impl ServiceHandle {
             .query()
             .query_effect_uptime(target_name.as_deref(), time_range.as_ref(), duration_secs)
             .await
+            .map_err(BarasError::from)
     }
 
     /// Query individual time windows for a specific effect.
     pub async fn query_effect_windows(
         &self,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         effect_id: i64,
         target_name: Option<String>,
         time_range: Option<TimeRange>,
         duration_secs: f32,
-    ) -> Result<Vec<EffectWindow>, String> {
+    ) -> Result<Vec<EffectWindow>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -817,12 +1035,13 @@ impl ServiceHandle {
                 duration_secs,
             )
             .await
+            .map_err(BarasError::from)
     }
 
     /// Query combat log rows with pagination for virtual scrolling.
     pub async fn query_combat_log(
         &self,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         offset: u64,
         limit: u64,
         source_filter: Option<String>,
@@ -830,23 +1049,23 @@ impl ServiceHandle {
         search_filter: Option<String>,
         time_range: Option<TimeRange>,
         event_filters: Option<CombatLogFilters>,
-    ) -> Result<Vec<CombatLogRow>, String> {
+    ) -> Result<Vec<CombatLogRow>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -865,34 +1084,35 @@ impl ServiceHandle {
                 event_filters.as_ref(),
             )
             .await
+            .map_err(BarasError::from)
     }
 
     /// Get total count of combat log rows for pagination.
     pub async fn query_combat_log_count(
         &self,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         source_filter: Option<String>,
         target_filter: Option<String>,
         search_filter: Option<String>,
         time_range: Option<TimeRange>,
         event_filters: Option<CombatLogFilters>,
-    ) -> Result<u64, String> {
+    ) -> Result<u64, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -909,38 +1129,40 @@ impl ServiceHandle {
                 event_filters.as_ref(),
             )
             .await
+            .map_err(BarasError::from)
     }
 
     /// Find matching rows in combat log (returns position and row_idx).
     pub async fn query_combat_log_find(
         &self,
-        encounter_idx: Option<u32>,
+        encounter_key: Option<String>,
         find_text: String,
         source_filter: Option<String>,
         target_filter: Option<String>,
         time_range: Option<TimeRange>,
         event_filters: Option<CombatLogFilters>,
-    ) -> Result<Vec<CombatLogFindMatch>, String> {
+    ) -> Result<Vec<CombatLogFindMatch>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
-        let result = self.shared
+        let result = self
+            .shared
             .query_context
             .query()
             .await
@@ -957,30 +1179,30 @@ impl ServiceHandle {
         if let Err(ref e) = result {
             tracing::error!("query_combat_log_find failed: {}", e);
         }
-        result
+        result.map_err(BarasError::from)
     }
 
     /// Get distinct source names for combat log filter dropdown.
     pub async fn query_source_names(
         &self,
-        encounter_idx: Option<u32>,
-    ) -> Result<Vec<String>, String> {
+        encounter_key: Option<String>,
+    ) -> Result<Vec<String>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -991,29 +1213,30 @@ impl ServiceHandle {
             .query()
             .query_source_names()
             .await
+            .map_err(BarasError::from)
     }
 
     /// Get distinct target names for combat log filter dropdown.
     pub async fn query_target_names(
         &self,
-        encounter_idx: Option<u32>,
-    ) -> Result<Vec<String>, String> {
+        encounter_key: Option<String>,
+    ) -> Result<Vec<String>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -1024,29 +1247,30 @@ impl ServiceHandle {
             .query()
             .query_target_names()
             .await
+            .map_err(BarasError::from)
     }
 
     /// Query player deaths in an encounter.
     pub async fn query_player_deaths(
         &self,
-        encounter_idx: Option<u32>,
-    ) -> Result<Vec<PlayerDeath>, String> {
+        encounter_key: Option<String>,
+    ) -> Result<Vec<PlayerDeath>, BarasError> {
         let session_guard = self.shared.session.read().await;
-        let session = session_guard.as_ref().ok_or("No active session")?;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
         let session = session.read().await;
 
-        if let Some(idx) = encounter_idx {
-            let dir = session.encounters_dir().ok_or("No encounters directory")?;
-            let path = dir.join(baras_core::storage::encounter_filename(idx));
+        if let Some(key) = encounter_key {
+            let dir = session
+                .encounters_dir()
+                .ok_or(QueryError::NoEncountersDir)?;
+            let path = dir.join(baras_core::storage::encounter_filename(&key));
             if !path.exists() {
-                return Err(format!("Encounter file not found: {:?}", path));
+                return Err(QueryError::EncounterNotFound { path }.into());
             }
             self.shared.query_context.register_parquet(&path).await?;
         } else {
-            let writer = session
-                .encounter_writer()
-                .ok_or("No live encounter buffer")?;
-            let batch = writer.to_record_batch().ok_or("Live buffer is empty")?;
+            let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+            let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
             self.shared.query_context.register_batch(batch).await?;
         }
 
@@ -1057,6 +1281,231 @@ impl ServiceHandle {
             .query()
             .query_player_deaths()
             .await
+            .map_err(BarasError::from)
+    }
+
+    /// Compare two pulls (historical encounters) of the same boss.
+    pub async fn compare_pulls(
+        &self,
+        encounter_key_a: String,
+        encounter_key_b: String,
+    ) -> Result<PullComparison, BarasError> {
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
+        let session = session.read().await;
+
+        let dir = session
+            .encounters_dir()
+            .ok_or(QueryError::NoEncountersDir)?;
+        let path_a = dir.join(baras_core::storage::encounter_filename(&encounter_key_a));
+        let path_b = dir.join(baras_core::storage::encounter_filename(&encounter_key_b));
+        for path in [&path_a, &path_b] {
+            if !path.exists() {
+                return Err(QueryError::EncounterNotFound { path: path.clone() }.into());
+            }
+        }
+
+        // Separate context: registering both files on the shared one would race
+        // with the explorer's own queries for the selected encounter
+        let ignore_list = IgnoreList::from_config(&*self.shared.config.read().await);
+        QueryContext::with_ignore_list(ignore_list)
+            .compare_pulls(&path_a, &path_b)
+            .await
+            .map_err(BarasError::from)
+    }
+
+    /// Per-player statistics across several pulls (historical encounters).
+    pub async fn query_session_aggregate(
+        &self,
+        encounter_keys: Vec<String>,
+    ) -> Result<SessionAggregate, BarasError> {
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
+        let session = session.read().await;
+
+        let dir = session
+            .encounters_dir()
+            .ok_or(QueryError::NoEncountersDir)?;
+        let mut paths = Vec::with_capacity(encounter_keys.len());
+        for key in &encounter_keys {
+            let path = dir.join(baras_core::storage::encounter_filename(key));
+            if !path.exists() {
+                return Err(QueryError::EncounterNotFound { path }.into());
+            }
+            paths.push(path);
+        }
+
+        // Separate context, as for pull comparison
+        let ignore_list = IgnoreList::from_config(&*self.shared.config.read().await);
+        QueryContext::with_ignore_list(ignore_list)
+            .aggregate_pulls(&paths)
+            .await
+            .map_err(BarasError::from)
+    }
+
+    /// Check the raid plan's healer assignments for a boss against a historical encounter.
+    pub async fn check_healer_assignments(
+        &self,
+        encounter_key: String,
+        boss_name: String,
+    ) -> Result<Vec<AssignmentCheck>, BarasError> {
+        let plan = match raid_plan_path() {
+            Some(path) => load_raid_plan(&path)?,
+            None => Default::default(),
+        };
+        let assignments = plan.assignments_for(&boss_name);
+        if assignments.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let session_guard = self.shared.session.read().await;
+        let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
+        let session = session.read().await;
+
+        let dir = session
+            .encounters_dir()
+            .ok_or(QueryError::NoEncountersDir)?;
+        let path = dir.join(baras_core::storage::encounter_filename(&encounter_key));
+        if !path.exists() {
+            return Err(QueryError::EncounterNotFound { path }.into());
+        }
+        self.shared.query_context.register_parquet(&path).await?;
+
+        self.shared
+            .query_context
+            .query()
+            .await
+            .query()
+            .check_healer_assignments(assignments)
+            .await
+            .map_err(BarasError::from)
+    }
+
+    /// Write a Data Explorer table to `path` as CSV or JSON, returning the number
+    /// of rows written. A combat log export without a limit is streamed to the
+    /// file instead of being loaded page by page.
+    pub async fn export_breakdown(
+        &self,
+        encounter_key: Option<String>,
+        table: ExportTable,
+        format: ExportFormat,
+        path: PathBuf,
+    ) -> Result<usize, BarasError> {
+        match table {
+            ExportTable::Breakdown {
+                tab,
+                entity_name,
+                time_range,
+                entity_types,
+                breakdown_mode,
+                duration_secs,
+            } => {
+                let rows = self
+                    .query_breakdown(
+                        tab,
+                        encounter_key,
+                        entity_name,
+                        time_range,
+                        entity_types,
+                        breakdown_mode,
+                        duration_secs,
+                    )
+                    .await?;
+                Ok(export_query_results(&rows, format, &path)?)
+            }
+            ExportTable::EntityBreakdown {
+                tab,
+                time_range,
+                breakdown_mode,
+            } => {
+                let rows = self
+                    .query_entity_breakdown(tab, encounter_key, time_range, breakdown_mode)
+                    .await?;
+                Ok(export_query_results(&rows, format, &path)?)
+            }
+            ExportTable::RaidOverview {
+                time_range,
+                duration_secs,
+            } => {
+                let rows = self
+                    .query_raid_overview(encounter_key, time_range, duration_secs)
+                    .await?;
+                Ok(export_query_results(&rows, format, &path)?)
+            }
+            ExportTable::CombatLog {
+                offset,
+                limit: Some(limit),
+                source_filter,
+                target_filter,
+                search_filter,
+                time_range,
+                event_filters,
+            } => {
+                let rows = self
+                    .query_combat_log(
+                        encounter_key,
+                        offset,
+                        limit,
+                        source_filter,
+                        target_filter,
+                        search_filter,
+                        time_range,
+                        event_filters,
+                    )
+                    .await?;
+                Ok(export_query_results(&rows, format, &path)?)
+            }
+            ExportTable::CombatLog {
+                offset,
+                limit: None,
+                source_filter,
+                target_filter,
+                search_filter,
+                time_range,
+                event_filters,
+            } => {
+                let session_guard = self.shared.session.read().await;
+                let session = session_guard.as_ref().ok_or(QueryError::NoSession)?;
+                let session = session.read().await;
+
+                if let Some(key) = encounter_key {
+                    let dir = session
+                        .encounters_dir()
+                        .ok_or(QueryError::NoEncountersDir)?;
+                    let parquet = dir.join(baras_core::storage::encounter_filename(&key));
+                    if !parquet.exists() {
+                        return Err(QueryError::EncounterNotFound { path: parquet }.into());
+                    }
+                    self.shared.query_context.register_parquet(&parquet).await?;
+                } else {
+                    let writer = session.encounter_writer().ok_or(QueryError::NoLiveData)?;
+                    let batch = writer.to_record_batch().ok_or(QueryError::NoLiveData)?;
+                    self.shared.query_context.register_batch(batch).await?;
+                }
+
+                self.shared
+                    .query_context
+                    .query()
+                    .await
+                    .query()
+                    .export_combat_log(
+                        offset,
+                        source_filter.as_deref(),
+                        target_filter.as_deref(),
+                        search_filter.as_deref(),
+                        time_range.as_ref(),
+                        event_filters.as_ref(),
+                        format,
+                        &path,
+                    )
+                    .await
+                    .map_err(BarasError::from)
+            }
+            ExportTable::Timeline => {
+                let timeline = self.query_encounter_timeline(encounter_key).await?;
+                Ok(export_query_results(&timeline.phases, format, &path)?)
+            }
+        }
     }
 
     // ─────────────────────────────────────────────────────────────────────────
@@ -1094,6 +1543,22 @@ impl ServiceHandle {
                 .shared
                 .dot_tracker_overlay_active
                 .store(active, Ordering::SeqCst),
+            "tank_swap" => self
+                .shared
+                .tank_swap_overlay_active
+                .store(active, Ordering::SeqCst),
+            "raid_cooldowns" => self
+                .shared
+                .raid_cooldowns_overlay_active
+                .store(active, Ordering::SeqCst),
+            "death_recap" => self
+                .shared
+                .death_recap_overlay_active
+                .store(active, Ordering::SeqCst),
+            "boss_notes" => self
+                .shared
+                .boss_notes_overlay_active
+                .store(active, Ordering::SeqCst),
             _ => {}
         }
     }
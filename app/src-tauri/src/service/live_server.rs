@@ -0,0 +1,352 @@
+//! Live data WebSocket server for stream overlays
+//!
+//! Streams combat metrics, timers and boss health as JSON to local WebSocket
+//! clients, so OBS browser sources can draw their own overlays instead of
+//! capturing the native windows. Messages are only built while a client is
+//! connected.
+//!
+//! Every message is `{"type": ..., "data": ...}` where type is one of
+//! `combat`, `timers`, `boss_health`, `combat_started`, `combat_ended` or
+//! `clear` (the last three carry no data).
+//!
+//! Binding to 127.0.0.1 keeps other machines out, but any web page open in
+//! the user's browser could still connect. Handshakes from browser pages are
+//! only accepted from local files (OBS browser sources) and localhost.
+
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+
+use baras_core::OverlayHealthEntry;
+use baras_core::encounter::metrics::PlayerMetrics;
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::{StatusCode, header};
+use tracing::{debug, info, warn};
+
+use super::OverlayUpdate;
+
+/// Messages buffered per client before a slow client starts skipping
+const FEED_CAPACITY: usize = 64;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Messages
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+enum LiveMessage<'a> {
+    Combat(LiveCombat<'a>),
+    Timers(LiveTimers<'a>),
+    BossHealth(LiveBossHealth<'a>),
+    CombatStarted,
+    CombatEnded,
+    /// Everything was reset (log file switched)
+    Clear,
+}
+
+#[derive(Serialize)]
+struct LiveCombat<'a> {
+    encounter_name: Option<&'a str>,
+    difficulty: Option<&'a str>,
+    encounter_time_secs: u64,
+    current_phase: Option<&'a str>,
+    phase_time_secs: f32,
+    /// Entity ID of the local player within `players`
+    player_entity_id: i64,
+    players: &'a [PlayerMetrics],
+}
+
+#[derive(Serialize)]
+struct LiveTimers<'a> {
+    /// Timer overlay the timers belong to ("a" or "b")
+    group: &'static str,
+    timers: Vec<LiveTimer<'a>>,
+}
+
+#[derive(Serialize)]
+struct LiveTimer<'a> {
+    name: &'a str,
+    remaining_secs: f32,
+    total_secs: f32,
+    color: [u8; 4],
+}
+
+#[derive(Serialize)]
+struct LiveBossHealth<'a> {
+    bosses: &'a [OverlayHealthEntry],
+    /// Seconds behind the best recorded kill at this HP (negative = ahead)
+    pace_secs: Option<f32>,
+}
+
+impl<'a> LiveMessage<'a> {
+    /// The message streamed for an overlay update, if it has one
+    fn from_update(update: &'a OverlayUpdate) -> Option<Self> {
+        let message = match update {
+            OverlayUpdate::DataUpdated(data) => LiveMessage::Combat(LiveCombat {
+                encounter_name: data.encounter_name.as_deref(),
+                difficulty: data.difficulty.as_deref(),
+                encounter_time_secs: data.encounter_time_secs,
+                current_phase: data.current_phase.as_deref(),
+                phase_time_secs: data.phase_time_secs,
+                player_entity_id: data.player_entity_id,
+                players: &data.metrics,
+            }),
+            OverlayUpdate::TimersAUpdated(data) | OverlayUpdate::TimersBUpdated(data) => {
+                let group = if matches!(update, OverlayUpdate::TimersAUpdated(_)) {
+                    "a"
+                } else {
+                    "b"
+                };
+                LiveMessage::Timers(LiveTimers {
+                    group,
+                    timers: data
+                        .entries
+                        .iter()
+                        .map(|t| LiveTimer {
+                            name: &t.name,
+                            remaining_secs: t.remaining_secs,
+                            total_secs: t.total_secs,
+                            color: t.color,
+                        })
+                        .collect(),
+                })
+            }
+            OverlayUpdate::BossHealthUpdated(data) => LiveMessage::BossHealth(LiveBossHealth {
+                bosses: &data.entries,
+                pace_secs: data.pace_secs,
+            }),
+            OverlayUpdate::CombatStarted => LiveMessage::CombatStarted,
+            OverlayUpdate::CombatEnded => LiveMessage::CombatEnded,
+            OverlayUpdate::ClearAllData => LiveMessage::Clear,
+            _ => return None,
+        };
+        Some(message)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Feed
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Fan-out of serialized messages to every connected client.
+///
+/// Lives as long as the service; servers come and go with the settings.
+#[derive(Clone)]
+pub struct LiveFeed {
+    tx: broadcast::Sender<Arc<str>>,
+}
+
+impl Default for LiveFeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiveFeed {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(FEED_CAPACITY);
+        Self { tx }
+    }
+
+    /// Whether any client is connected
+    pub fn has_clients(&self) -> bool {
+        self.tx.receiver_count() > 0
+    }
+
+    /// Stream an overlay update to connected clients (no-op without clients)
+    pub fn publish(&self, update: &OverlayUpdate) {
+        if !self.has_clients() {
+            return;
+        }
+        let Some(message) = LiveMessage::from_update(update) else {
+            return;
+        };
+        match serde_json::to_string(&message) {
+            Ok(json) => {
+                let _ = self.tx.send(json.into());
+            }
+            Err(e) => warn!(error = %e, "Failed to serialize live data message"),
+        }
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<Arc<str>> {
+        self.tx.subscribe()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Server
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A running server, bound to 127.0.0.1 only
+pub struct LiveServer {
+    port: u16,
+    handle: JoinHandle<()>,
+}
+
+impl LiveServer {
+    /// Bind the port and start accepting clients
+    pub async fn start(port: u16, feed: LiveFeed) -> std::io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port)).await?;
+        let handle = tokio::spawn(accept_clients(listener, feed));
+        info!(port, "Live data server listening");
+        Ok(Self { port, handle })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Close the listener and disconnect all clients
+    pub fn stop(self) {
+        self.handle.abort();
+        info!(port = self.port, "Live data server stopped");
+    }
+}
+
+/// Accept loop. Clients live in the JoinSet, so aborting this task drops them all.
+async fn accept_clients(listener: TcpListener, feed: LiveFeed) {
+    let mut clients = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, addr)) => {
+                    debug!(%addr, "Live data client connected");
+                    clients.spawn(serve_client(stream, feed.subscribe()));
+                }
+                Err(e) => warn!(error = %e, "Live data server failed to accept a client"),
+            },
+            Some(_) = clients.join_next() => {}
+        }
+    }
+}
+
+/// Whether a handshake with this `Origin` header may connect.
+///
+/// Browsers send the origin of the page opening the socket; native clients
+/// usually send none. Local files report `null` or `file://`.
+fn origin_allowed(origin: Option<&str>) -> bool {
+    let Some(origin) = origin else {
+        return true;
+    };
+    if origin == "null" || origin.starts_with("file://") {
+        return true;
+    }
+    let Some(rest) = origin
+        .strip_prefix("http://")
+        .or_else(|| origin.strip_prefix("https://"))
+    else {
+        return false;
+    };
+    let host = match rest.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+        _ => rest,
+    };
+    matches!(host, "localhost" | "127.0.0.1" | "[::1]")
+}
+
+/// Reject handshakes from web pages on other sites
+fn check_origin(request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+    // A non-UTF-8 origin is never one we allow
+    let origin = request
+        .headers()
+        .get(header::ORIGIN)
+        .map(|value| value.to_str().unwrap_or_default());
+    if origin_allowed(origin) {
+        return Ok(response);
+    }
+    debug!(?origin, "Live data client rejected: origin not allowed");
+    let mut rejection = ErrorResponse::new(Some("Origin not allowed".to_string()));
+    *rejection.status_mut() = StatusCode::FORBIDDEN;
+    Err(rejection)
+}
+
+/// Forward feed messages to one client until either side closes
+async fn serve_client(stream: TcpStream, mut rx: broadcast::Receiver<Arc<str>>) {
+    let ws = match tokio_tungstenite::accept_hdr_async(stream, check_origin).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            debug!(error = %e, "Live data handshake failed");
+            return;
+        }
+    };
+    let (mut sink, mut source) = ws.split();
+
+    loop {
+        tokio::select! {
+            message = rx.recv() => match message {
+                Ok(json) => {
+                    if sink.send(Message::Text(json.to_string())).await.is_err() {
+                        break;
+                    }
+                }
+                // Slow client: skip to the newest messages
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            // Clients only listen; reading keeps pings answered and notices closes
+            incoming = source.next() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    debug!("Live data client disconnected");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::CombatData;
+
+    #[test]
+    fn test_origin_allowed() {
+        assert!(origin_allowed(None));
+        assert!(origin_allowed(Some("null")));
+        assert!(origin_allowed(Some("file://")));
+        assert!(origin_allowed(Some("http://localhost:8080")));
+        assert!(origin_allowed(Some("http://127.0.0.1")));
+        assert!(!origin_allowed(Some("https://example.com")));
+        assert!(!origin_allowed(Some("http://localhost.example.com")));
+        assert!(!origin_allowed(Some("")));
+    }
+
+    #[test]
+    fn test_combat_message_shape() {
+        let update = OverlayUpdate::DataUpdated(CombatData {
+            metrics: Vec::new(),
+            player_entity_id: 7,
+            encounter_time_secs: 95,
+            encounter_count: 1,
+            class_discipline: None,
+            encounter_name: Some("Dread Master Bestia Pull 1".into()),
+            difficulty: Some("NiM 8".into()),
+            challenges: None,
+            current_phase: None,
+            phase_time_secs: 0.0,
+            next_phase_eta: None,
+            time_to_enrage_secs: None,
+            counters: Vec::new(),
+        });
+        let message = LiveMessage::from_update(&update).unwrap();
+        let json = serde_json::to_value(&message).unwrap();
+
+        assert_eq!(json["type"], "combat");
+        assert_eq!(json["data"]["encounter_name"], "Dread Master Bestia Pull 1");
+        assert_eq!(json["data"]["difficulty"], "NiM 8");
+        assert_eq!(json["data"]["encounter_time_secs"], 95);
+        assert_eq!(json["data"]["player_entity_id"], 7);
+        assert_eq!(json["data"]["players"], serde_json::json!([]));
+
+        let started = LiveMessage::from_update(&OverlayUpdate::CombatStarted).unwrap();
+        assert_eq!(
+            serde_json::to_value(&started).unwrap(),
+            serde_json::json!({ "type": "combat_started" })
+        );
+    }
+}
@@ -4,33 +4,48 @@
 //! - SharedState: Arc-wrapped state readable by Tauri commands (in crate::state)
 //! - ServiceHandle: For sending commands + accessing shared state
 //! - CombatService: Background task that processes commands and updates shared state
+mod backfill;
+mod checkpoint;
 mod directory;
 mod handler;
+mod live_server;
 
-use crate::state::SharedState;
+use crate::state::{KillPaceStore, OverlayUpdateStats, SharedState, Subsystem, SubsystemStatus};
 pub use crate::state::{RaidSlotRegistry, RegisteredPlayer};
 use baras_core::directory_watcher;
+use baras_types::{SESSION_UPDATED_EVENT, SessionEvent};
+pub use checkpoint::stored_session_id as checkpointed_session_id;
 pub use handler::*;
-use std::path::PathBuf;
+use live_server::{LiveFeed, LiveServer};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use tauri::{AppHandle, Emitter, Manager};
 use tokio::sync::{RwLock, mpsc};
 
-use baras_core::context::{AppConfig, AppConfigExt, DirectoryIndex, ParsingSession, resolve};
-use baras_core::directory_watcher::DirectoryWatcher;
-use baras_core::encounter::{EncounterState, PhaseType};
+use baras_core::context::{
+    AppConfig, AppConfigExt, DEEP_IDLE_POLL, DirectoryIndex, IgnoreList, ParsingSession, resolve,
+};
+use baras_core::directory_watcher::{ConfigWatcher, DirectoryWatcher};
+use baras_core::effects::{BuffCoverageReport, BuffCoverageTracker};
+use baras_core::encounter::metrics::EntityMetrics;
 use baras_core::encounter::summary::classify_encounter;
+use baras_core::encounter::{EncounterState, PhaseEta, PhaseType};
 use baras_core::game_data::{Discipline, Role};
-use baras_core::timers::FiredAlert;
+use baras_core::storage::{EncounterHistoryStore, JOURNAL_FILENAME, SignalJournal, to_historical};
+use baras_core::timers::{AlertPriority, FiredAlert};
 use baras_core::{
     ActiveEffect, BossEncounterDefinition, DefinitionConfig, DefinitionSet, DisplayTarget,
-    EFFECTS_DSL_VERSION, EntityType, GameSignal, PlayerMetrics, Reader, SignalHandler,
+    EFFECTS_DSL_VERSION, EntityType, GameSignal, PlayerMetrics, Reader, SessionClock,
+    SignalHandler,
 };
 use baras_overlay::{
-    BossHealthData, ChallengeData, ChallengeEntry, Color, CooldownData, CooldownEntry, DotEntry,
-    DotTarget, DotTrackerData, EffectABEntry, EffectsABData, PersonalStats, PlayerContribution,
-    PlayerRole, RaidEffect, RaidFrame, RaidFrameData, TimerData, TimerEntry,
+    BossHealthData, BossHealthLinger, BossNotesData, ChallengeData, ChallengeEntry, Color,
+    CooldownData, CooldownEntry, DeathRecapData, DeathRecapEvent, DotEntry, DotTarget,
+    DotTrackerData, EffectABEntry, EffectsABData, PersonalStats, PlayerContribution, PlayerRole,
+    RaidCooldownCell, RaidCooldownRow, RaidCooldownsData, RaidEffect, RaidFrame, RaidFrameData,
+    TankSwapData, TankSwapEntry, TimerData, TimerEntry,
 };
 
 use crate::audio::{AudioEvent, AudioSender, AudioService};
@@ -43,7 +58,7 @@ use tracing::{debug, error, info, warn};
 use baras_core::encounter::summary::EncounterSummary;
 
 /// Player info from parse worker subprocess.
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct WorkerPlayerInfo {
     name: String,
     class_name: String,
@@ -52,7 +67,7 @@ struct WorkerPlayerInfo {
 }
 
 /// Area info from parse worker subprocess.
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct WorkerAreaInfo {
     area_name: String,
     area_id: i64,
@@ -61,7 +76,7 @@ struct WorkerAreaInfo {
 }
 
 /// Player discipline entry from parse worker subprocess.
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct WorkerPlayerDiscipline {
     entity_id: i64,
     name: String,
@@ -72,9 +87,15 @@ struct WorkerPlayerDiscipline {
 }
 
 /// Output from the parse worker subprocess (matches parse-worker JSON output).
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct ParseWorkerOutput {
+    /// Where the worker started parsing (non-zero when only recent encounters were parsed)
+    #[serde(default)]
+    start_pos: u64,
     end_pos: u64,
+    /// Timestamp of the last parsed event (absent from older checkpoints)
+    #[serde(default)]
+    last_timestamp: Option<String>,
     event_count: usize,
     encounter_count: usize,
     encounters: Vec<EncounterSummary>,
@@ -93,12 +114,14 @@ async fn fallback_streaming_parse(
     let timer = std::time::Instant::now();
     let mut session_guard = session.write().await;
     let session_date = session_guard.game_session_date.unwrap_or_default();
-    let result = reader.read_log_file_streaming(session_date, |event| {
+    let mut clock = SessionClock::new(session_date);
+    let result = reader.read_log_file_streaming(session_date, &mut clock, |event| {
         session_guard.process_event(event);
     });
 
     if let Ok((end_pos, event_count)) = result {
         session_guard.current_byte = Some(end_pos);
+        session_guard.session_clock = Some(clock);
 
         // Enable live parquet writing so Data Explorer can query encounters
         // Start from encounter 0 since fallback doesn't write parquet files
@@ -115,6 +138,230 @@ async fn fallback_streaming_parse(
     }
 }
 
+/// Move the live session's definition coverage into the store and send a
+/// report if the user opted in and one is due
+async fn record_coverage(shared: &Arc<SharedState>) {
+    let timer_mgr = {
+        let session_guard = shared.session.read().await;
+        let Some(session) = session_guard.as_ref() else {
+            return;
+        };
+        session.read().await.timer_manager()
+    };
+    let Some(timer_mgr) = timer_mgr else {
+        return;
+    };
+    let coverage = timer_mgr
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take_coverage();
+    shared.coverage.add(coverage);
+
+    let settings = shared.config.read().await.telemetry.clone();
+    let shared = shared.clone();
+    tokio::spawn(async move { shared.coverage.send_if_due(&settings).await });
+}
+
+/// Keep the last pull's HP timeline if it was the fastest kill of its boss
+async fn record_kill_pace(shared: &Arc<SharedState>) {
+    let session_guard = shared.session.read().await;
+    let Some(session) = session_guard.as_ref() else {
+        return;
+    };
+    let session = session.read().await;
+    let Some(enc) = session
+        .session_cache
+        .as_ref()
+        .and_then(|cache| cache.last_combat_encounter())
+    else {
+        return;
+    };
+    let Some(def) = enc.active_boss_definition() else {
+        return;
+    };
+    if !baras_core::encounter::summary::determine_success(enc) {
+        return;
+    }
+    let key = KillPaceStore::key(&def.id, enc.difficulty);
+    if shared.kill_pace.record(key.clone(), &enc.hp_timeline) {
+        info!(boss = %key, "Recorded new best kill pace");
+    }
+}
+
+/// Save the session's finished encounters to the persistent encounter history
+async fn record_encounter_history(shared: &Arc<SharedState>) {
+    let (log_file, encounters) = {
+        let session_guard = shared.session.read().await;
+        let Some(session) = session_guard.as_ref() else {
+            return;
+        };
+        let session = session.read().await;
+        let (Some(log_file), Some(cache)) = (
+            session
+                .active_file
+                .as_ref()
+                .and_then(|p| p.file_name())
+                .and_then(|f| f.to_str())
+                .map(str::to_string),
+            session.session_cache.as_ref(),
+        ) else {
+            return;
+        };
+        let encounters: Vec<_> = cache
+            .encounter_history
+            .summaries()
+            .iter()
+            .map(|summary| to_historical(summary, &log_file, cache.player.id))
+            .collect();
+        (log_file, encounters)
+    };
+
+    let result = tokio::task::spawn_blocking(move || {
+        EncounterHistoryStore::open_default()?.record(&log_file, &encounters)
+    })
+    .await;
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => warn!(error = %e, "Failed to save encounter history"),
+        Err(e) => warn!(error = %e, "Encounter history task failed"),
+    }
+}
+
+/// Count the last finished pull against the persistent counter rules
+async fn record_counters(shared: &Arc<SharedState>) {
+    let summary = {
+        let session_guard = shared.session.read().await;
+        let Some(session) = session_guard.as_ref() else {
+            return;
+        };
+        let session = session.read().await;
+        let Some(summary) = session
+            .session_cache
+            .as_ref()
+            .and_then(|cache| cache.encounter_history.summaries().last().cloned())
+        else {
+            return;
+        };
+        summary
+    };
+
+    let mut counters = shared.counters.lock().unwrap_or_else(|p| p.into_inner());
+    match counters.record(&summary) {
+        Ok(true) => info!(encounter = %summary.encounter_key, "Updated persistent counters"),
+        Ok(false) => {}
+        Err(e) => warn!(error = %e, "Failed to save persistent counters"),
+    }
+}
+
+/// Average the last boss pull's hits into the learned per-timer damage
+async fn record_incoming_damage(shared: &Arc<SharedState>) {
+    let session_guard = shared.session.read().await;
+    let Some(session) = session_guard.as_ref() else {
+        return;
+    };
+    let session = session.read().await;
+    let Some(enc) = session
+        .session_cache
+        .as_ref()
+        .and_then(|cache| cache.last_combat_encounter())
+    else {
+        return;
+    };
+    let Some(def) = enc.active_boss_definition() else {
+        return;
+    };
+    let key = KillPaceStore::key(&def.id, enc.difficulty);
+    shared
+        .incoming_damage
+        .record(key, enc.timer_hit_estimates());
+}
+
+/// Seed a fresh session with the state produced by the parse worker (or restored
+/// from a parse checkpoint, which stores the same data).
+fn import_parse_output(session: &mut ParsingSession, output: &ParseWorkerOutput) {
+    session.current_byte = Some(output.end_pos);
+    // Lines after `end_pos` are dated on from the last parsed event
+    session.session_clock = output
+        .last_timestamp
+        .as_deref()
+        .and_then(|ts| chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%dT%H:%M:%S%.f").ok())
+        .map(SessionClock::new);
+
+    // Import encounter summaries and session metadata from subprocess
+    if let Some(cache) = &mut session.session_cache {
+        for summary in &output.encounters {
+            cache.encounter_history.add(summary.clone());
+        }
+
+        // Import player info
+        cache.player.name = baras_core::context::intern(&output.player.name);
+        cache.player.id = output.player.entity_id;
+        cache.player.class_name = output.player.class_name.clone();
+        cache.player.discipline_name = output.player.discipline_name.clone();
+        cache.player_initialized = true;
+
+        // Import area info
+        debug!(
+            area_id = output.area.area_id,
+            area_name = %output.area.area_name,
+            difficulty_id = output.area.difficulty_id,
+            "Importing area"
+        );
+        cache.current_area.area_name = output.area.area_name.clone();
+        cache.current_area.area_id = output.area.area_id;
+        cache.current_area.difficulty_id = output.area.difficulty_id;
+        cache.current_area.difficulty_name = output.area.difficulty_name.clone();
+
+        // Sync next_encounter_id to continue from where subprocess left off
+        // (fixes off-by-one bug where live encounters would have IDs that
+        // collide with subprocess parquet files)
+        cache.set_next_encounter_id(output.encounter_count as u64);
+
+        // Create fresh encounter with correct area context
+        // (the initial encounter was created before we had area info from subprocess)
+        cache.push_new_encounter();
+
+        // Import player disciplines from subprocess
+        for disc in &output.player_disciplines {
+            cache
+                .player_disciplines
+                .insert(disc.entity_id, player_discipline_info(disc));
+        }
+    }
+}
+
+fn player_discipline_info(
+    disc: &WorkerPlayerDiscipline,
+) -> baras_core::encounter::entity_info::PlayerInfo {
+    baras_core::encounter::entity_info::PlayerInfo {
+        id: disc.entity_id,
+        name: baras_core::context::intern(&disc.name),
+        class_id: disc.class_id,
+        class_name: disc.class_name.clone(),
+        discipline_id: disc.discipline_id,
+        discipline_name: disc.discipline_name.clone(),
+        is_dead: false,
+        death_time: None,
+        current_target_id: 0,
+        last_seen_at: None,
+    }
+}
+
+/// Decode the JSON the parse worker prints on success
+fn decode_worker_output(stdout: Vec<u8>) -> Result<ParseWorkerOutput, String> {
+    String::from_utf8(stdout)
+        .map_err(|e| format!("Invalid UTF-8: {}", e))
+        .and_then(|result| {
+            serde_json::from_str::<ParseWorkerOutput>(&result).map_err(|e| {
+                format!(
+                    "JSON parse error: {} (input: {})",
+                    e,
+                    &result[..result.len().min(500)]
+                )
+            })
+        })
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Service Commands
 // ─────────────────────────────────────────────────────────────────────────────
@@ -125,7 +372,9 @@ pub enum ServiceCommand {
     StopTailing,
     RefreshIndex,
     StartWatcher,
-    Shutdown,
+    /// Finalize the live session, save config and stop the service loop.
+    /// Replies once everything has been flushed.
+    Shutdown(tokio::sync::oneshot::Sender<()>),
     FileDetected(PathBuf),
     /// File was modified - re-check character data for files missing it
     FileModified(PathBuf),
@@ -137,10 +386,18 @@ pub enum ServiceCommand {
     ReloadEffectDefinitions,
     /// Open a historical file (pauses live tailing)
     OpenHistoricalFile(PathBuf),
+    /// Open the extracted demo log (pauses live tailing)
+    OpenDemo(PathBuf),
     /// Resume live tailing (switch to newest file)
     ResumeLiveTailing,
     /// Trigger immediate raid frame data refresh (after registry changes)
     RefreshRaidFrames,
+    /// Config file was edited outside the app - reload and merge it
+    ConfigFileChanged,
+    /// Tail reader stopped making progress while the log keeps growing
+    TailStalled,
+    /// Live data server was enabled, disabled or moved to another port
+    LiveServerSettingsChanged,
 }
 
 /// Updates sent to the overlay system
@@ -168,12 +425,107 @@ pub enum OverlayUpdate {
     CooldownsUpdated(CooldownData),
     /// DOTs on enemy targets
     DotTrackerUpdated(DotTrackerData),
+    /// Tank-swap debuff stacks
+    TankSwapUpdated(TankSwapData),
+    /// Defensive and raid utility cooldowns of every player
+    RaidCooldownsUpdated(RaidCooldownsData),
+    /// Events leading up to a player's death
+    DeathRecapUpdated(DeathRecapData),
+    /// User notes for the boss that was just detected
+    BossNotesUpdated(BossNotesData),
     /// Clear all overlay data (sent when switching files)
     ClearAllData,
     /// Local player entered conversation - temporarily hide overlays
     ConversationStarted,
     /// Local player exited conversation - restore overlays if we hid them
     ConversationEnded,
+    /// Config was reloaded from disk - re-apply settings to running overlays
+    SettingsChanged,
+    /// Local player joined (true) or left (false) a group - show/hide raid frames
+    GroupChanged(bool),
+    /// A background subsystem became degraded or recovered - update warning badges
+    HealthChanged(SubsystemStatus),
+}
+
+impl OverlayUpdate {
+    /// Whether this update is a full snapshot of its overlay's data, so an
+    /// older queued update of the same kind can be skipped
+    pub fn is_snapshot(&self) -> bool {
+        matches!(
+            self,
+            OverlayUpdate::DataUpdated(_)
+                | OverlayUpdate::EffectsUpdated(_)
+                | OverlayUpdate::BossHealthUpdated(_)
+                | OverlayUpdate::TimersAUpdated(_)
+                | OverlayUpdate::TimersBUpdated(_)
+                | OverlayUpdate::EffectsAUpdated(_)
+                | OverlayUpdate::EffectsBUpdated(_)
+                | OverlayUpdate::CooldownsUpdated(_)
+                | OverlayUpdate::DotTrackerUpdated(_)
+                | OverlayUpdate::TankSwapUpdated(_)
+                | OverlayUpdate::RaidCooldownsUpdated(_)
+                | OverlayUpdate::DeathRecapUpdated(_)
+                | OverlayUpdate::BossNotesUpdated(_)
+        )
+    }
+}
+
+/// Sending half of the overlay update channel.
+///
+/// Never waits for the router: when the channel is full the update is dropped
+/// and counted in [`OverlayUpdateStats`]. Updates are also streamed to live
+/// data server clients, if any are connected.
+#[derive(Clone)]
+pub struct OverlayUpdateSender {
+    tx: mpsc::Sender<OverlayUpdate>,
+    stats: Arc<OverlayUpdateStats>,
+    live: LiveFeed,
+}
+
+impl OverlayUpdateSender {
+    pub fn new(
+        tx: mpsc::Sender<OverlayUpdate>,
+        stats: Arc<OverlayUpdateStats>,
+        live: LiveFeed,
+    ) -> Self {
+        Self { tx, stats, live }
+    }
+
+    /// Whether a live data server client wants updates even without overlays
+    pub fn has_live_clients(&self) -> bool {
+        self.live.has_clients()
+    }
+
+    /// Queue an update for the router. Returns false if it was dropped.
+    pub fn try_send(&self, update: OverlayUpdate) -> bool {
+        self.live.publish(&update);
+        match self.tx.try_send(update) {
+            Ok(()) => {
+                self.stats.sent.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+            Err(mpsc::error::TrySendError::Full(update)) => {
+                self.stats.dropped.fetch_add(1, Ordering::Relaxed);
+                if !update.is_snapshot() {
+                    warn!("Overlay update channel full, dropped a non-snapshot update");
+                }
+                false
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => false,
+        }
+    }
+
+    /// Record a subsystem's status and tell the router if it changed
+    pub fn report_health(
+        &self,
+        shared: &SharedState,
+        subsystem: Subsystem,
+        problem: Option<String>,
+    ) {
+        if let Some(status) = shared.health.report(subsystem, problem) {
+            self.try_send(OverlayUpdate::HealthChanged(status));
+        }
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -188,15 +540,6 @@ pub enum MetricsTrigger {
     InitialLoad,
 }
 
-/// Events to notify frontend of session state changes
-#[derive(Debug, Clone, Copy)]
-pub enum SessionEvent {
-    CombatStarted,
-    CombatEnded,
-    AreaChanged,
-    PlayerInitialized,
-}
-
 /// Signal handler that tracks combat state and triggers metrics updates
 struct CombatSignalHandler {
     shared: Arc<SharedState>,
@@ -204,9 +547,20 @@ struct CombatSignalHandler {
     /// Channel for frontend session updates (event-driven, not polled)
     session_event_tx: std::sync::mpsc::Sender<SessionEvent>,
     /// Channel for overlay updates (to clear overlays on combat end)
-    overlay_tx: mpsc::Sender<OverlayUpdate>,
+    overlay_tx: OverlayUpdateSender,
     /// Local player entity ID (set on first DisciplineChanged)
     local_player_id: Option<i64>,
+    /// Last discipline of the local player checked for a starter profile
+    local_discipline_id: Option<i64>,
+    /// Other players seen exchanging effects with the local player or loading
+    /// into the area since it was entered
+    group_members: std::collections::HashSet<i64>,
+    /// Raid buffs and stim on the local player, reported around each pull
+    buff_coverage: BuffCoverageTracker,
+    /// Coverage when the current pull started
+    pre_pull_coverage: Option<BuffCoverageReport>,
+    /// For buff coverage toasts
+    app_handle: AppHandle,
 }
 
 impl CombatSignalHandler {
@@ -214,7 +568,8 @@ impl CombatSignalHandler {
         shared: Arc<SharedState>,
         trigger_tx: mpsc::Sender<MetricsTrigger>,
         session_event_tx: std::sync::mpsc::Sender<SessionEvent>,
-        overlay_tx: mpsc::Sender<OverlayUpdate>,
+        overlay_tx: OverlayUpdateSender,
+        app_handle: AppHandle,
     ) -> Self {
         Self {
             shared,
@@ -222,6 +577,151 @@ impl CombatSignalHandler {
             session_event_tx,
             overlay_tx,
             local_player_id: None,
+            local_discipline_id: None,
+            group_members: std::collections::HashSet::new(),
+            buff_coverage: BuffCoverageTracker::new(),
+            pre_pull_coverage: None,
+            app_handle,
+        }
+    }
+
+    /// Note another player as a group member, notifying overlays when the
+    /// local player goes from solo to grouped
+    fn add_group_member(&mut self, entity_id: i64) {
+        if self.local_player_id.is_none_or(|id| id == entity_id) {
+            return;
+        }
+        if self.group_members.insert(entity_id) && self.group_members.len() == 1 {
+            self.set_grouped(true);
+        }
+    }
+
+    fn set_grouped(&self, grouped: bool) {
+        if self.shared.grouped.swap(grouped, Ordering::SeqCst) != grouped {
+            let _ = self
+                .overlay_tx
+                .try_send(OverlayUpdate::GroupChanged(grouped));
+        }
+    }
+
+    /// The first time a discipline is played live, add a starter profile for
+    /// its role and offer it to the user
+    fn offer_role_profile(&mut self, discipline_id: i64) {
+        if self.local_discipline_id.replace(discipline_id) == Some(discipline_id)
+            || !self.shared.is_live_tailing.load(Ordering::SeqCst)
+        {
+            return;
+        }
+        let Some(discipline) = Discipline::from_guid(discipline_id) else {
+            return;
+        };
+
+        let shared = self.shared.clone();
+        let app_handle = self.app_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            let mut config = shared.config.read().await.clone();
+            if config.seen_disciplines.contains(&discipline_id) {
+                return;
+            }
+            config.seen_disciplines.push(discipline_id);
+            let created = config.create_role_profile(discipline.role());
+
+            shared.apply_config(config.clone()).await;
+            if let Err(e) = config.save() {
+                warn!(error = %e, "Failed to save configuration");
+            }
+
+            match created {
+                Ok(profile) => {
+                    info!(discipline = discipline.name(), profile = %profile, "Created starter profile");
+                    let _ = app_handle.emit(
+                        "role-profile-offered",
+                        format!(
+                            "First time playing {}: created profile \"{}\", load it from Settings",
+                            discipline.name(),
+                            profile
+                        ),
+                    );
+                    let _ = app_handle.emit("settings-changed", ());
+                }
+                Err(reason) => debug!(reason, "No starter profile created"),
+            }
+        });
+    }
+
+    /// Send the hits and heals a player took before dying to the death recap overlay
+    fn send_death_recap(
+        &self,
+        encounter: &baras_core::encounter::CombatEncounter,
+        entity_id: i64,
+        entity_name: &str,
+        timestamp: chrono::NaiveDateTime,
+    ) {
+        if !self
+            .shared
+            .death_recap_overlay_active
+            .load(Ordering::SeqCst)
+        {
+            return;
+        }
+        let recent = encounter.recent_events.window(entity_id, timestamp);
+        if recent.is_empty() {
+            return;
+        }
+
+        let events = recent
+            .iter()
+            .map(|e| DeathRecapEvent {
+                secs_before_death: (timestamp - e.timestamp).num_milliseconds() as f32 / 1000.0,
+                source: resolve(e.source_name).to_string(),
+                ability: resolve(e.ability_name).to_string(),
+                amount: e.amount,
+                absorbed: e.absorbed,
+                is_heal: e.is_heal,
+                is_crit: e.is_crit,
+            })
+            .collect();
+        let _ = self
+            .overlay_tx
+            .try_send(OverlayUpdate::DeathRecapUpdated(DeathRecapData {
+                player_name: entity_name.to_string(),
+                is_local_player: self.local_player_id == Some(entity_id),
+                events,
+            }));
+    }
+
+    /// Show the user's notes for a detected boss on the boss notes overlay
+    fn send_boss_notes(&self, definition_id: &str, boss_name: &str) {
+        if !self.shared.boss_notes_overlay_active.load(Ordering::SeqCst) {
+            return;
+        }
+        let Some(dir) = baras_core::boss::user_notes_dir() else {
+            return;
+        };
+        let text = match baras_core::boss::load_boss_notes(&dir, definition_id) {
+            Ok(text) => text,
+            Err(e) => {
+                warn!(definition_id, error = %e, "Failed to read boss notes");
+                return;
+            }
+        };
+        let _ = self
+            .overlay_tx
+            .try_send(OverlayUpdate::BossNotesUpdated(BossNotesData {
+                boss_name: boss_name.to_string(),
+                pages: baras_core::boss::split_note_pages(&text),
+            }));
+    }
+
+    /// Toast missing raid buffs while grouped (solo players never have them all)
+    fn report_buff_coverage(&self, when: &str, report: &BuffCoverageReport) {
+        if !self.shared.grouped.load(Ordering::SeqCst) {
+            return;
+        }
+        if let Some(summary) = report.summary() {
+            let _ = self
+                .app_handle
+                .emit("buff-coverage", format!("{}: {}", when, summary));
         }
     }
 }
@@ -230,16 +730,30 @@ impl SignalHandler for CombatSignalHandler {
     fn handle_signal(
         &mut self,
         signal: &GameSignal,
-        _encounter: Option<&baras_core::encounter::CombatEncounter>,
+        encounter: Option<&baras_core::encounter::CombatEncounter>,
     ) {
+        self.buff_coverage.handle_signal(signal, encounter);
+
         match signal {
             GameSignal::CombatStarted { .. } => {
                 self.shared.in_combat.store(true, Ordering::SeqCst);
+                // No other player seen since entering the area: solo
+                if self.group_members.is_empty() {
+                    self.set_grouped(false);
+                }
+                let coverage = self.buff_coverage.report();
+                self.report_buff_coverage("Pre-pull", &coverage);
+                self.pre_pull_coverage = Some(coverage);
                 let _ = self.trigger_tx.try_send(MetricsTrigger::CombatStarted);
                 let _ = self.session_event_tx.send(SessionEvent::CombatStarted);
             }
             GameSignal::CombatEnded { .. } => {
                 self.shared.in_combat.store(false, Ordering::SeqCst);
+                // Only report buffs lost during the pull
+                let coverage = self.buff_coverage.report();
+                if self.pre_pull_coverage.take().as_ref() != Some(&coverage) {
+                    self.report_buff_coverage("Post-pull", &coverage);
+                }
                 let _ = self.trigger_tx.try_send(MetricsTrigger::CombatEnded);
                 let _ = self.session_event_tx.send(SessionEvent::CombatEnded);
                 // Clear boss health and timer overlays
@@ -251,9 +765,15 @@ impl SignalHandler for CombatSignalHandler {
                 discipline_id,
                 ..
             } => {
-                // First DisciplineChanged is always the local player
+                // First DisciplineChanged is always the local player; later ones
+                // are group members loading into the area
                 if self.local_player_id.is_none() {
                     self.local_player_id = Some(*entity_id);
+                } else {
+                    self.add_group_member(*entity_id);
+                }
+                if self.local_player_id == Some(*entity_id) {
+                    self.offer_role_profile(*discipline_id);
                 }
                 // Update raid registry with discipline info for role icons
                 let mut registry = self.shared.raid_registry.lock().unwrap_or_else(|p| p.into_inner());
@@ -263,7 +783,10 @@ impl SignalHandler for CombatSignalHandler {
             }
             GameSignal::EffectApplied {
                 effect_id,
+                source_id,
+                source_entity_type,
                 target_id,
+                target_entity_type,
                 ..
             } => {
                 // Check for conversation effect on local player
@@ -272,6 +795,17 @@ impl SignalHandler for CombatSignalHandler {
                 {
                     let _ = self.overlay_tx.try_send(OverlayUpdate::ConversationStarted);
                 }
+
+                // Players buffing the local player, or buffed by them, are in the group
+                if *source_entity_type == EntityType::Player
+                    && *target_entity_type == EntityType::Player
+                {
+                    if self.local_player_id == Some(*source_id) {
+                        self.add_group_member(*target_id);
+                    } else if self.local_player_id == Some(*target_id) {
+                        self.add_group_member(*source_id);
+                    }
+                }
             }
             GameSignal::EffectRemoved {
                 effect_id,
@@ -285,10 +819,31 @@ impl SignalHandler for CombatSignalHandler {
                     let _ = self.overlay_tx.try_send(OverlayUpdate::ConversationEnded);
                 }
             }
+            GameSignal::EntityDeath {
+                entity_id,
+                entity_type: EntityType::Player,
+                entity_name,
+                timestamp,
+                ..
+            } => {
+                if let Some(encounter) = encounter {
+                    self.send_death_recap(encounter, *entity_id, entity_name, *timestamp);
+                }
+            }
+            GameSignal::BossEncounterDetected {
+                definition_id,
+                boss_name,
+                ..
+            } => {
+                self.send_boss_notes(definition_id, boss_name);
+            }
             GameSignal::AreaEntered { area_id, .. } => {
                 // Note: Boss definitions are loaded synchronously in process_event via definition_loader
                 let current = self.shared.current_area_id.load(Ordering::SeqCst);
                 if *area_id != current && *area_id != 0 {
+                    // Group members announce themselves again in the new area;
+                    // stay grouped until a pull shows otherwise
+                    self.group_members.clear();
                     self.shared
                         .current_area_id
                         .store(*area_id, Ordering::SeqCst);
@@ -308,7 +863,7 @@ impl SignalHandler for CombatSignalHandler {
 pub struct CombatService {
     app_handle: AppHandle,
     shared: Arc<SharedState>,
-    overlay_tx: mpsc::Sender<OverlayUpdate>,
+    overlay_tx: OverlayUpdateSender,
     audio_tx: AudioSender,
     cmd_rx: mpsc::Receiver<ServiceCommand>,
     cmd_tx: mpsc::Sender<ServiceCommand>,
@@ -316,6 +871,10 @@ pub struct CombatService {
     directory_handle: Option<tokio::task::JoinHandle<()>>,
     metrics_handle: Option<tokio::task::JoinHandle<()>>,
     effects_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Detects a stalled tail reader and requests a restart
+    watchdog_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Full parse of a file whose recent encounters were parsed first
+    backfill: Option<backfill::Backfill>,
     /// Effect definitions loaded at startup for overlay tracking
     definitions: DefinitionSet,
     /// Area index for lazy loading encounter definitions (area_id -> file path)
@@ -324,6 +883,10 @@ pub struct CombatService {
     loaded_area_id: i64,
     /// Icon cache for ability icons (shared with SharedState for overlay data building)
     icon_cache: Option<Arc<baras_overlay::icons::IconCache>>,
+    /// Stream of overlay updates for live data server clients
+    live_feed: LiveFeed,
+    /// Local WebSocket server (None while disabled or if the port was taken)
+    live_server: Option<LiveServer>,
 }
 
 impl CombatService {
@@ -347,6 +910,9 @@ impl CombatService {
         let area_index = Arc::new(Self::build_area_index(&app_handle));
 
         let shared = Arc::new(SharedState::new(config, directory_index));
+        let live_feed = LiveFeed::new();
+        let overlay_tx =
+            OverlayUpdateSender::new(overlay_tx, shared.overlay_updates.clone(), live_feed.clone());
 
         // Spawn the audio service (shares audio settings with config)
         let user_sounds_dir = dirs::config_dir()
@@ -367,12 +933,9 @@ impl CombatService {
                     .unwrap_or_else(|| PathBuf::from("."))
                     .join("core/definitions/sounds")
             });
-        let audio_settings = Arc::new(tokio::sync::RwLock::new(
-            shared.config.blocking_read().audio.clone(),
-        ));
         let audio_service = AudioService::new(
             audio_rx,
-            audio_settings,
+            shared.audio_settings.clone(),
             user_sounds_dir,
             bundled_sounds_dir,
         );
@@ -392,10 +955,14 @@ impl CombatService {
             directory_handle: None,
             metrics_handle: None,
             effects_handle: None,
+            watchdog_handle: None,
+            backfill: None,
             definitions,
             area_index,
             loaded_area_id: 0,
             icon_cache,
+            live_feed,
+            live_server: None,
         };
 
         let handle = ServiceHandle { cmd_tx, shared };
@@ -405,7 +972,7 @@ impl CombatService {
 
     /// Build area index from encounter definition files (lightweight - only reads headers)
     fn build_area_index(app_handle: &AppHandle) -> baras_core::boss::AreaIndex {
-        use baras_core::boss::build_area_index;
+        use baras_core::boss::{build_layered_area_index, user_encounters_dir};
 
         // Bundled definitions: shipped with the app in resources
         let bundled_dir = app_handle
@@ -416,41 +983,21 @@ impl CombatService {
             )
             .ok();
 
-        // Custom definitions: user's config directory
-        let custom_dir =
-            dirs::config_dir().map(|p| p.join("baras").join("definitions").join("encounters"));
-
-        let mut index = baras_core::boss::AreaIndex::new();
-
-        // Build index from bundled directory
-        if let Some(ref path) = bundled_dir
-            && path.exists()
-            && let Ok(area_index) = build_area_index(path)
-        {
-            index.extend(area_index);
-        }
-
-        // Build index from custom directory (can override bundled)
-        if let Some(ref path) = custom_dir
-            && path.exists()
-            && let Ok(area_index) = build_area_index(path)
-        {
-            index.extend(area_index);
-        }
-
-        index
+        // Custom definitions in the user's config directory override bundled areas
+        build_layered_area_index(bundled_dir.as_deref(), user_encounters_dir().as_deref())
     }
 
     /// Load boss definitions for a specific area, merging with custom overlays
     fn load_area_definitions(&self, area_id: i64) -> Option<Vec<BossEncounterDefinition>> {
-        use baras_core::boss::load_bosses_with_custom;
-
-        let entry = self.area_index.get(&area_id)?;
-
-        // User custom directory for overlay files
-        let user_dir = dirs::config_dir().map(|p| p.join("baras").join("encounters"));
-
-        load_bosses_with_custom(&entry.file_path, user_dir.as_deref()).ok()
+        use baras_core::boss::{try_load_indexed_area, user_encounters_dir};
+
+        let loaded =
+            try_load_indexed_area(&self.area_index, area_id, user_encounters_dir().as_deref());
+        let mut bosses = report_definitions_health(&self.shared, &self.overlay_tx, loaded)?;
+        self.shared
+            .id_dictionary
+            .expand_renamed_selectors(&mut bosses);
+        Some(bosses)
     }
 
     /// Get the path to the timer preferences file
@@ -534,6 +1081,7 @@ impl CombatService {
     /// 1. Load bundled definitions from app resources (base layer)
     /// 2. Load user overrides from single file: ~/.config/baras/definitions/effects.toml
     /// 3. User effects with matching IDs replace bundled effects entirely
+    /// 4. Effects with `extends` inherit unset settings from the named template
     ///
     /// Version checking:
     /// - User file must have `version = N` matching EFFECTS_DSL_VERSION
@@ -561,6 +1109,12 @@ impl CombatService {
             Self::load_user_effects(&mut set, &user_path);
         }
 
+        // 3. Apply templates once every file has contributed its templates
+        let unresolved = set.resolve_templates();
+        if !unresolved.is_empty() {
+            warn!(effects = ?unresolved, "Effects extend unknown templates");
+        }
+
         set
     }
 
@@ -587,6 +1141,7 @@ impl CombatService {
                 && let Ok(config) = toml::from_str::<DefinitionConfig>(&contents)
             {
                 let count = config.effects.len();
+                set.add_templates(config.templates, false);
                 set.add_definitions(config.effects, false);
                 debug!(
                     file = ?path.file_name().unwrap_or_default(),
@@ -623,6 +1178,7 @@ impl CombatService {
             return;
         }
 
+        set.add_templates(config.templates, true); // Overwrite bundled
         if !config.effects.is_empty() {
             debug!(count = config.effects.len(), path = ?path, "Loading user effect overrides");
             set.add_definitions(config.effects, true); // Overwrite bundled
@@ -631,7 +1187,10 @@ impl CombatService {
 
     /// Run the service event loop
     pub async fn run(mut self) {
+        self.start_config_watcher();
+        self.start_idle_monitor();
         self.start_watcher().await;
+        self.sync_live_server().await;
 
         loop {
             let Some(cmd) = self.cmd_rx.recv().await else {
@@ -648,8 +1207,9 @@ impl CombatService {
                 ServiceCommand::RefreshIndex => {
                     self.refresh_index().await;
                 }
-                ServiceCommand::Shutdown => {
-                    self.stop_tailing().await;
+                ServiceCommand::Shutdown(done) => {
+                    self.shutdown().await;
+                    let _ = done.send(());
                     break;
                 }
                 ServiceCommand::StartWatcher => {
@@ -676,17 +1236,28 @@ impl CombatService {
                 ServiceCommand::OpenHistoricalFile(path) => {
                     // Pause live tailing and open the historical file
                     self.shared.is_live_tailing.store(false, Ordering::SeqCst);
+                    self.shared.is_demo.store(false, Ordering::SeqCst);
                     let _ = self
                         .app_handle
-                        .emit("session-updated", "TailingModeChanged");
+                        .emit(SESSION_UPDATED_EVENT, SessionEvent::TailingModeChanged);
+                    self.start_tailing(path).await;
+                }
+                ServiceCommand::OpenDemo(path) => {
+                    // Demo mode is historical mode on the bundled log
+                    self.shared.is_live_tailing.store(false, Ordering::SeqCst);
+                    self.shared.is_demo.store(true, Ordering::SeqCst);
+                    let _ = self
+                        .app_handle
+                        .emit(SESSION_UPDATED_EVENT, SessionEvent::TailingModeChanged);
                     self.start_tailing(path).await;
                 }
                 ServiceCommand::ResumeLiveTailing => {
                     // Resume live tailing and switch to newest file
                     self.shared.is_live_tailing.store(true, Ordering::SeqCst);
+                    self.shared.is_demo.store(false, Ordering::SeqCst);
                     let _ = self
                         .app_handle
-                        .emit("session-updated", "TailingModeChanged");
+                        .emit(SESSION_UPDATED_EVENT, SessionEvent::TailingModeChanged);
                     let newest = {
                         let index = self.shared.directory_index.read().await;
                         index.newest_file().map(|f| f.path.clone())
@@ -705,6 +1276,124 @@ impl CombatService {
                         .overlay_tx
                         .try_send(OverlayUpdate::EffectsUpdated(data));
                 }
+                ServiceCommand::ConfigFileChanged => {
+                    self.reload_config_from_disk().await;
+                }
+                ServiceCommand::TailStalled => {
+                    self.restart_tail_reader().await;
+                }
+                ServiceCommand::LiveServerSettingsChanged => {
+                    self.sync_live_server().await;
+                }
+            }
+        }
+    }
+
+    /// Drop the polling loops into deep idle once the app sits unused in the tray
+    fn start_idle_monitor(&self) {
+        let shared = self.shared.clone();
+        let app_handle = self.app_handle.clone();
+        tokio::spawn(async move {
+            loop {
+                // Returns as soon as deep idle ends
+                shared.idle.sleep(IDLE_CHECK_INTERVAL).await;
+
+                if shared.in_combat.load(Ordering::Relaxed) {
+                    shared.idle.touch();
+                }
+
+                let minutes = shared.config.read().await.deep_idle_minutes;
+                let timeout = std::time::Duration::from_secs(minutes as u64 * 60);
+                let hidden = app_handle
+                    .get_webview_window("main")
+                    .is_some_and(|w| !w.is_visible().unwrap_or(true));
+                if minutes > 0
+                    && hidden
+                    && shared.idle.idle_for() >= timeout
+                    && shared.idle.enter_deep_idle()
+                {
+                    info!(minutes, "No combat and window hidden, entering deep idle");
+                }
+
+                baras_overlay::set_overlay_idle(shared.idle.is_deep_idle());
+            }
+        });
+    }
+
+    /// Watch the config file for external edits for the lifetime of the service
+    fn start_config_watcher(&self) {
+        let Some(path) = AppConfig::config_path() else {
+            warn!("Could not resolve config file path, live reload disabled");
+            return;
+        };
+
+        let mut watcher = match ConfigWatcher::new(&path) {
+            Ok(w) => w,
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Failed to watch config file, live reload disabled");
+                return;
+            }
+        };
+
+        let cmd_tx = self.cmd_tx.clone();
+        tokio::spawn(async move {
+            while watcher.next_change().await.is_some() {
+                if cmd_tx.send(ServiceCommand::ConfigFileChanged).await.is_err() {
+                    break; // Service shut down
+                }
+            }
+        });
+    }
+
+    /// Reload the config file after an external edit and merge it into the running config
+    async fn reload_config_from_disk(&mut self) {
+        let on_disk = match AppConfig::try_load() {
+            Ok(config) => config,
+            Err(e) => {
+                // Keep running with the in-memory config; the user may still be mid-edit
+                warn!(error = %e, "Ignoring config file change: failed to parse");
+                return;
+            }
+        };
+
+        let mut merged = self.shared.config.read().await.clone();
+        if !merged.merge_external(on_disk) {
+            return;
+        }
+        info!("Config file changed on disk, reloading");
+
+        if self.shared.apply_config(merged).await {
+            self.on_directory_changed().await;
+        }
+        self.sync_live_server().await;
+
+        let _ = self.app_handle.emit("settings-changed", ());
+        let _ = self.overlay_tx.try_send(OverlayUpdate::SettingsChanged);
+    }
+
+    /// Start, stop or move the live data server to match the config
+    async fn sync_live_server(&mut self) {
+        let settings = self.shared.config.read().await.live_server.clone();
+
+        let wanted_port = settings.enabled.then_some(settings.port);
+        if self.live_server.as_ref().map(LiveServer::port) == wanted_port {
+            return;
+        }
+        if let Some(server) = self.live_server.take() {
+            server.stop();
+        }
+        let Some(port) = wanted_port else {
+            return;
+        };
+
+        match LiveServer::start(port, self.live_feed.clone()).await {
+            Ok(server) => self.live_server = Some(server),
+            Err(e) => {
+                error!(port, error = %e, "Failed to start live data server");
+                let _ = self.app_handle.emit(
+                    "recoverable-warning",
+                    format!("Live data server could not use port {}: {}", port, e),
+                );
             }
         }
     }
@@ -778,6 +1467,11 @@ impl CombatService {
 
     /// Handle file modification - re-check character data for files that were missing it
     async fn file_modified(&mut self, path: PathBuf) {
+        // The game is writing again; don't wait for the idle tail poll to notice
+        if self.shared.idle.wake() {
+            debug!("Log file modified, leaving deep idle");
+        }
+
         let updated = {
             let mut index = self.shared.directory_index.write().await;
             // Check if this specific file needs character re-extraction
@@ -793,12 +1487,63 @@ impl CombatService {
             debug!(updated, "Re-read character names from modified files");
             // Notify frontend that file list changed (display names may have updated)
             let _ = self.app_handle.emit("log-files-changed", ());
+
+            // A freshly created log only names its character once the player logs in
+            let is_active = match self.shared.session.read().await.as_ref() {
+                Some(session) => session.read().await.active_file.as_ref() == Some(&path),
+                None => false,
+            };
+            if is_active {
+                self.switch_character(&path).await;
+            }
         }
     }
 
-    async fn file_removed(&mut self, path: PathBuf) {
-        let was_active = {
-            let session_guard = self.shared.session.read().await;
+    /// Apply the per-character settings of the character logging to `path`
+    /// (live tailing only; historical files never switch profiles)
+    async fn switch_character(&mut self, path: &Path) {
+        if !self.shared.is_live_tailing.load(Ordering::SeqCst) {
+            return;
+        }
+        let Some(name) = self
+            .shared
+            .directory_index
+            .read()
+            .await
+            .character_name(path)
+            .map(str::to_string)
+        else {
+            return;
+        };
+
+        let mut config = self.shared.config.read().await.clone();
+        let old_profile = config.active_profile_name.clone();
+        if !config.switch_character(&name) {
+            return;
+        }
+        info!(character = %name, "Active character changed");
+
+        self.shared.apply_config(config.clone()).await;
+        let new_profile = config.active_profile_name.clone();
+        if let Err(e) = config.save() {
+            warn!(error = %e, "Failed to save configuration");
+        }
+
+        if new_profile != old_profile {
+            let _ = self.overlay_tx.try_send(OverlayUpdate::SettingsChanged);
+            if let Some(profile) = new_profile {
+                let _ = self.app_handle.emit(
+                    "character-profile-loaded",
+                    format!("Loaded profile \"{}\" for {}", profile, name),
+                );
+            }
+        }
+        let _ = self.app_handle.emit("settings-changed", ());
+    }
+
+    async fn file_removed(&mut self, path: PathBuf) {
+        let was_active = {
+            let session_guard = self.shared.session.read().await;
             if let Some(session) = session_guard.as_ref() {
                 let s = session.read().await;
                 s.active_file.as_ref().map(|p| p == &path).unwrap_or(false)
@@ -894,14 +1639,27 @@ impl CombatService {
 
         self.directory_handle = Some(handle);
         self.shared.watching.store(true, Ordering::SeqCst);
-        let _ = self.app_handle.emit("session-updated", "WatcherStarted");
+        let _ = self
+            .app_handle
+            .emit(SESSION_UPDATED_EVENT, SessionEvent::WatcherStarted);
     }
 
     async fn start_tailing(&mut self, path: PathBuf) {
         self.stop_tailing().await;
+        self.switch_character(&path).await;
 
-        // Clear old parquet data from previous session
-        if let Err(e) = baras_core::storage::clear_data_dir() {
+        let session_id = path
+            .file_name()
+            .and_then(|f| f.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        // A checkpoint of this file lets us skip re-parsing what was already read
+        let checkpoint = checkpoint::ParseCheckpoint::load(&path, &session_id);
+
+        // Clear old parquet data from previous session (kept when resuming this one)
+        let keep = checkpoint.as_ref().map(|_| session_id.as_str());
+        if let Err(e) = baras_core::storage::clear_data_dir_except(keep) {
             warn!(error = %e, "Failed to clear data directory");
         }
 
@@ -917,6 +1675,12 @@ impl CombatService {
         let (session_event_tx, session_event_rx) = std::sync::mpsc::channel::<SessionEvent>();
 
         let mut session = ParsingSession::new(path.clone(), self.definitions.clone());
+        {
+            let config = self.shared.config.read().await;
+            session.set_cache_compaction(config.compact_session_cache);
+            session.set_ignore_list(IgnoreList::from_config(&config));
+        }
+        session.set_id_dictionary(self.shared.id_dictionary.shared());
 
         // Load timer preferences into the session's timer manager (Live mode only)
         if let Some(prefs_path) = Self::timer_preferences_path() {
@@ -931,13 +1695,18 @@ impl CombatService {
 
         // Set up sync definition loader for AreaEntered events (fixes race condition)
         let area_index = self.area_index.clone();
-        let user_encounters_dir =
-            dirs::config_dir().map(|p| p.join("baras").join("definitions").join("encounters"));
+        let user_encounters_dir = baras_core::boss::user_encounters_dir();
+        let shared = self.shared.clone();
+        let overlay_tx = self.overlay_tx.clone();
         let loader: baras_core::context::DefinitionLoader = Box::new(move |area_id: i64| {
-            use baras_core::boss::load_bosses_with_custom;
-            area_index.get(&area_id).and_then(|entry| {
-                load_bosses_with_custom(&entry.file_path, user_encounters_dir.as_deref()).ok()
-            })
+            let loaded = baras_core::boss::try_load_indexed_area(
+                &area_index,
+                area_id,
+                user_encounters_dir.as_deref(),
+            );
+            let mut bosses = report_definitions_health(&shared, &overlay_tx, loaded)?;
+            shared.id_dictionary.expand_renamed_selectors(&mut bosses);
+            Some(bosses)
         });
         session.set_definition_loader(std::sync::Arc::new(loader));
 
@@ -951,6 +1720,7 @@ impl CombatService {
             trigger_tx.clone(),
             session_event_tx,
             self.overlay_tx.clone(),
+            self.app_handle.clone(),
         );
         session.add_signal_handler(Box::new(handler));
 
@@ -969,7 +1739,7 @@ impl CombatService {
                     Err(_) => break,     // Task cancelled
                 };
                 // Emit event to frontend - they can fetch fresh data
-                let _ = app_handle.emit("session-updated", format!("{:?}", event));
+                let _ = app_handle.emit(SESSION_UPDATED_EVENT, event);
             }
         });
 
@@ -984,190 +1754,35 @@ impl CombatService {
             .emit("active-file-changed", path.to_string_lossy().to_string());
 
         // Create reader for live tailing (after subprocess parse)
-        let reader = Reader::from(path.clone(), session.clone());
+        let reader =
+            Reader::from(path.clone(), session.clone()).with_idle(self.shared.idle.clone());
 
-        // Parse historical file in subprocess to avoid memory fragmentation
         let timer = std::time::Instant::now();
-        let session_id = path
-            .file_name()
-            .and_then(|f| f.to_str())
-            .unwrap_or("unknown")
-            .to_string();
 
         // Get encounters output directory
         let encounters_dir = baras_core::storage::encounters_dir(&session_id)
             .unwrap_or_else(|_| PathBuf::from("/tmp/baras-encounters"));
 
-        // Get boss definitions directory for phase detection
-        let definitions_dir = self
-            .app_handle
-            .path()
-            .resolve(
-                "definitions/encounters",
-                tauri::path::BaseDirectory::Resource,
-            )
-            .ok();
-
-        // Spawn parse worker subprocess
-        // Check multiple locations: bundled sidecar (with target triple), next to exe, fallback to PATH
-        let worker_path = std::env::current_exe()
-            .ok()
-            .and_then(|exe| {
-                let dir = exe.parent()?;
-                // Try sidecar name with target triple first (Tauri bundle format), then plain name
-                let candidates = [
-                    dir.join(format!(
-                        "baras-parse-worker-{}-unknown-linux-gnu",
-                        std::env::consts::ARCH
-                    )),
-                    dir.join("baras-parse-worker"),
-                ];
-                candidates.into_iter().find(|p| p.exists())
-            })
-            .unwrap_or_else(|| PathBuf::from("baras-parse-worker"));
-
-        debug!(worker_path = ?worker_path, "Using parse worker");
-
-        let mut cmd = std::process::Command::new(&worker_path);
-        cmd.arg(&path).arg(&session_id).arg(&encounters_dir);
-
-        // Pass definitions directory if available
-        if let Some(ref def_dir) = definitions_dir {
-            cmd.arg(def_dir);
-            debug!(definitions_path = ?def_dir, "Using definitions directory");
-        }
-
-        // Pass log path so subprocess writes to same log file
-        if let Some(log_path) = dirs::config_dir().map(|p| p.join("baras").join("baras.log")) {
-            cmd.env("BARAS_LOG_PATH", &log_path);
+        // Journal live signals after the historical ones the parse worker records
+        // (the fallback parse runs through this session and records them itself)
+        let journal_path = encounters_dir.join(JOURNAL_FILENAME);
+        let journal = if checkpoint.is_some() {
+            Ok(SignalJournal::append(journal_path))
+        } else {
+            SignalJournal::create(journal_path)
+        };
+        match journal {
+            Ok(journal) => session.write().await.add_signal_handler(Box::new(journal)),
+            Err(e) => warn!(error = %e, "Failed to create signal journal"),
         }
 
-        let output = cmd.output();
-
-        match output {
-            Ok(output) if output.status.success() => {
-                // Parse JSON result from subprocess
-                let json_result = String::from_utf8(output.stdout)
-                    .map_err(|e| format!("Invalid UTF-8: {}", e))
-                    .and_then(|result| {
-                        serde_json::from_str::<ParseWorkerOutput>(&result).map_err(|e| {
-                            format!(
-                                "JSON parse error: {} (input: {})",
-                                e,
-                                &result[..result.len().min(500)]
-                            )
-                        })
-                    });
-
-                match json_result {
-                    Ok(parse_result) => {
-                        let mut session_guard = session.write().await;
-                        session_guard.current_byte = Some(parse_result.end_pos);
-
-                        // Import encounter summaries and session metadata from subprocess
-                        if let Some(cache) = &mut session_guard.session_cache {
-                            for summary in parse_result.encounters {
-                                cache.encounter_history.add(summary);
-                            }
-
-                            // Import player info
-                            cache.player.name =
-                                baras_core::context::intern(&parse_result.player.name);
-                            cache.player.id = parse_result.player.entity_id;
-                            cache.player.class_name = parse_result.player.class_name.clone();
-                            cache.player.discipline_name =
-                                parse_result.player.discipline_name.clone();
-                            cache.player_initialized = true;
-
-                            // Import area info
-                            debug!(
-                                area_id = parse_result.area.area_id,
-                                area_name = %parse_result.area.area_name,
-                                difficulty_id = parse_result.area.difficulty_id,
-                                "Importing area from subprocess"
-                            );
-                            cache.current_area.area_name = parse_result.area.area_name.clone();
-                            cache.current_area.area_id = parse_result.area.area_id;
-                            cache.current_area.difficulty_id = parse_result.area.difficulty_id;
-                            cache.current_area.difficulty_name =
-                                parse_result.area.difficulty_name.clone();
-
-                            // Sync next_encounter_id to continue from where subprocess left off
-                            // (fixes off-by-one bug where live encounters would have IDs that
-                            // collide with subprocess parquet files)
-                            cache.set_next_encounter_id(parse_result.encounter_count as u64);
-
-                            // Create fresh encounter with correct area context
-                            // (the initial encounter was created before we had area info from subprocess)
-                            cache.push_new_encounter();
-
-                            // Import player disciplines from subprocess
-                            for disc in &parse_result.player_disciplines {
-                                use baras_core::encounter::entity_info::PlayerInfo;
-                                cache.player_disciplines.insert(
-                                    disc.entity_id,
-                                    PlayerInfo {
-                                        id: disc.entity_id,
-                                        name: baras_core::context::intern(&disc.name),
-                                        class_id: disc.class_id,
-                                        class_name: disc.class_name.clone(),
-                                        discipline_id: disc.discipline_id,
-                                        discipline_name: disc.discipline_name.clone(),
-                                        is_dead: false,
-                                        death_time: None,
-                                        current_target_id: 0,
-                                        last_seen_at: None,
-                                    },
-                                );
-                            }
-                        }
-
-                        // Enable live parquet writing (continues from where subprocess left off)
-                        session_guard.enable_live_parquet(
-                            encounters_dir.clone(),
-                            parse_result.encounter_count as u32,
-                        );
-
-                        // Load boss definitions for initial area (before releasing lock)
-                        if parse_result.area.area_id != 0 {
-                            if let Some(bosses) = self.load_area_definitions(parse_result.area.area_id) {
-                                session_guard.load_boss_definitions(bosses);
-                            }
-                        }
-
-                        session_guard.finalize_session();
-                        session_guard.sync_timer_context();
-                        drop(session_guard);
-
-                        info!(
-                            event_count = parse_result.event_count,
-                            encounter_count = parse_result.encounter_count,
-                            elapsed_ms = parse_result.elapsed_ms,
-                            "Subprocess parse completed"
-                        );
-
-                        // Notify frontend to refresh session info
-                        let _ = self.app_handle.emit("session-updated", "FileLoaded");
-                    }
-                    Err(e) => {
-                        error!(error = %e, "Subprocess output parse failed");
-                        fallback_streaming_parse(&reader, &session, encounters_dir.clone()).await;
-                    }
-                }
-            }
-            Ok(output) => {
-                error!(
-                    stderr = %String::from_utf8_lossy(&output.stderr),
-                    "Subprocess failed"
-                );
-                // Fallback to streaming parse in main process
-                fallback_streaming_parse(&reader, &session, encounters_dir.clone()).await;
-            }
-            Err(e) => {
-                error!(error = %e, "Failed to spawn subprocess");
-                // Fallback to streaming parse in main process
-                fallback_streaming_parse(&reader, &session, encounters_dir.clone()).await;
-            }
+        if let Some(checkpoint) = checkpoint {
+            self.resume_from_checkpoint(&reader, &session, checkpoint, &encounters_dir)
+                .await;
+        } else {
+            // Parse historical file in subprocess to avoid memory fragmentation
+            self.run_parse_worker(&path, &session_id, &reader, &session, &encounters_dir)
+                .await;
         }
 
         info!(
@@ -1190,19 +1805,25 @@ impl CombatService {
         }
 
         // Spawn the tail task to watch for new lines
-        let tail_handle = tokio::spawn(async move {
-            let _ = reader.tail_log_file().await;
-        });
+        let tail_handle = spawn_tail_reader(reader, self.shared.clone(), self.overlay_tx.clone());
+
+        // Watch for the reader falling behind the file (restarted via TailStalled)
+        let watchdog_handle =
+            spawn_tail_watchdog(path.clone(), session.clone(), self.cmd_tx.clone());
 
         // Spawn signal-driven metrics task
         let shared = self.shared.clone();
         let overlay_tx = self.overlay_tx.clone();
         let metrics_handle = tokio::spawn(async move {
+            let mut buffers = CombatDataBuffers::default();
             loop {
                 // Check for triggers with timeout to allow task cancellation
-                let trigger =
-                    tokio::time::timeout(std::time::Duration::from_millis(100), trigger_rx.recv())
-                        .await;
+                let poll = if shared.idle.is_deep_idle() {
+                    DEEP_IDLE_POLL
+                } else {
+                    std::time::Duration::from_millis(100)
+                };
+                let trigger = tokio::time::timeout(poll, trigger_rx.recv()).await;
 
                 let trigger = match trigger {
                     Ok(Some(t)) => t,
@@ -1210,20 +1831,46 @@ impl CombatService {
                     Err(_) => continue, // Timeout - check again
                 };
 
+                // Count the pull first so the personal overlay shows the new totals
+                if matches!(trigger, MetricsTrigger::CombatEnded)
+                    && shared.is_live_tailing.load(Ordering::SeqCst)
+                {
+                    record_counters(&shared).await;
+                }
+
                 // Calculate and send unified combat data
-                if let Some(data) = calculate_combat_data(&shared).await
+                if let Some(data) = calculate_combat_data(&shared, &mut buffers).await
                     && !data.metrics.is_empty()
                 {
                     let _ = overlay_tx.try_send(OverlayUpdate::DataUpdated(data));
                 }
 
+                if matches!(trigger, MetricsTrigger::CombatEnded) {
+                    record_coverage(&shared).await;
+                    record_kill_pace(&shared).await;
+                    record_incoming_damage(&shared).await;
+                    shared.id_dictionary.save_if_dirty();
+                }
+
+                // Checkpoint after each finished combat so a restart can resume here
+                if matches!(
+                    trigger,
+                    MetricsTrigger::CombatEnded | MetricsTrigger::InitialLoad
+                ) {
+                    checkpoint::save_active_session(&shared).await;
+                    record_encounter_history(&shared).await;
+                }
+
                 // For CombatStarted, start polling during combat
                 if matches!(trigger, MetricsTrigger::CombatStarted) {
                     // Poll during active combat
                     while shared.in_combat.load(Ordering::SeqCst) {
-                        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+                        let interval_ms =
+                            shared.config.read().await.performance.metrics_interval_ms;
+                        tokio::time::sleep(std::time::Duration::from_millis(interval_ms as u64))
+                            .await;
 
-                        if let Some(data) = calculate_combat_data(&shared).await
+                        if let Some(data) = calculate_combat_data(&shared, &mut buffers).await
                             && !data.metrics.is_empty()
                         {
                             let _ = overlay_tx.try_send(OverlayUpdate::DataUpdated(data));
@@ -1234,7 +1881,7 @@ impl CombatService {
         });
 
         // Spawn effects + boss health + audio sampling task (polls continuously)
-        // Uses adaptive sleep: fast when active, slow (500ms) when idle
+        // Uses adaptive sleep: the configured interval when active, slow (500ms) when idle
         let shared = self.shared.clone();
         let overlay_tx = self.overlay_tx.clone();
         let audio_tx = self.audio_tx.clone();
@@ -1242,6 +1889,7 @@ impl CombatService {
         let effects_handle = tokio::spawn(async move {
             // Track previous state to avoid redundant updates
             let mut last_raid_effect_count: usize = 0;
+            let mut last_raid_hp: Vec<f32> = Vec::new();
             let _last_effects_count: usize = 0;
 
             // Track previous state for new overlays to avoid redundant updates
@@ -1249,6 +1897,9 @@ impl CombatService {
             let mut last_effects_b_count: usize = 0;
             let mut last_cooldowns_count: usize = 0;
             let mut last_dot_tracker_count: usize = 0;
+            let mut last_tank_swap = TankSwapData::default();
+            let mut last_raid_cooldowns = RaidCooldownsData::default();
+            let mut boss_linger = BossHealthLinger::default();
 
             loop {
                 // Check which overlays are active to determine sleep interval
@@ -1259,9 +1910,23 @@ impl CombatService {
                 let effects_b_active = shared.effects_b_overlay_active.load(Ordering::Relaxed);
                 let cooldowns_active = shared.cooldowns_overlay_active.load(Ordering::Relaxed);
                 let dot_tracker_active = shared.dot_tracker_overlay_active.load(Ordering::Relaxed);
+                let tank_swap_active = shared.tank_swap_overlay_active.load(Ordering::Relaxed);
+                let raid_cooldowns_active =
+                    shared.raid_cooldowns_overlay_active.load(Ordering::Relaxed);
+                // Stream clients get boss health and timers without the overlays open
+                let live_clients = overlay_tx.has_live_clients();
                 let in_combat = shared.in_combat.load(Ordering::Relaxed);
                 let is_live = shared.is_live_tailing.load(Ordering::SeqCst);
 
+                // A panic while the tracker was locked leaves it usable but
+                // possibly half-updated; flag it on the effect overlays
+                if is_live {
+                    let problem = effect_tracker_poisoned(&shared)
+                        .await
+                        .then(|| "Recovered from an error, effects may be stale".to_string());
+                    overlay_tx.report_health(&shared, Subsystem::EffectTracker, problem);
+                }
+
                 // Determine if any work needs to be done
                 let any_overlay_active = raid_active
                     || boss_active
@@ -1269,17 +1934,24 @@ impl CombatService {
                     || effects_a_active
                     || effects_b_active
                     || cooldowns_active
-                    || dot_tracker_active;
+                    || dot_tracker_active
+                    || tank_swap_active
+                    || raid_cooldowns_active
+                    || live_clients;
                 let needs_audio = is_live && (in_combat || raid_active);
 
-                // Adaptive sleep: fast when active, slow when idle
-                // 30ms matches tail polling for consistent ~60ms max latency
+                // Adaptive sleep: the configured interval when active, slow when idle
+                // The default 30ms matches tail polling for consistent ~60ms max latency
                 let sleep_ms = if any_overlay_active || needs_audio {
-                    30
+                    shared.config.read().await.performance.effects_interval_ms as u64
                 } else {
                     500
                 };
-                tokio::time::sleep(std::time::Duration::from_millis(sleep_ms)).await;
+                // Deep idle waits for new log lines instead
+                shared
+                    .idle
+                    .sleep(std::time::Duration::from_millis(sleep_ms))
+                    .await;
 
                 // Skip processing if nothing needs updating
                 if !any_overlay_active && !needs_audio {
@@ -1291,19 +1963,28 @@ impl CombatService {
                     let rearranging = shared.rearrange_mode.load(Ordering::Relaxed);
                     if let Some(data) = build_raid_frame_data(&shared, rearranging, icon_cache.as_ref()).await {
                         let effect_count: usize = data.frames.iter().map(|f| f.effects.len()).sum();
-                        // Always send in rearrange mode, otherwise only when effects exist/changed
-                        if rearranging || effect_count > 0 || last_raid_effect_count > 0 {
+                        let raid_hp: Vec<f32> = data.frames.iter().map(|f| f.hp_percent).collect();
+                        let hp_changed = raid_hp != last_raid_hp;
+                        // Always send in rearrange mode, otherwise only when effects or HP exist/changed
+                        if rearranging
+                            || effect_count > 0
+                            || last_raid_effect_count > 0
+                            || hp_changed
+                        {
                             let _ = overlay_tx.try_send(OverlayUpdate::EffectsUpdated(data));
                         }
                         last_raid_effect_count = effect_count;
+                        last_raid_hp = raid_hp;
                     } else if rearranging {
                         // In rearrange mode, send empty data to keep overlay rendering
                         let _ = overlay_tx.try_send(OverlayUpdate::EffectsUpdated(
                             baras_overlay::RaidFrameData { frames: vec![] },
                         ));
                         last_raid_effect_count = 0;
+                        last_raid_hp.clear();
                     } else {
                         last_raid_effect_count = 0;
+                        last_raid_hp.clear();
                     }
                 }
                 // Effects A: only send if there are effects or effects just cleared
@@ -1374,6 +2055,25 @@ impl CombatService {
                     }
                 }
 
+                // Tank swap: stacks change rarely, so only send when they do
+                if tank_swap_active {
+                    let data = build_tank_swap_data(&shared).await.unwrap_or_default();
+                    if data != last_tank_swap {
+                        let _ = overlay_tx.try_send(OverlayUpdate::TankSwapUpdated(data.clone()));
+                        last_tank_swap = data;
+                    }
+                }
+
+                // Raid cooldowns: countdowns are whole seconds, so this sends about once a second
+                if raid_cooldowns_active {
+                    let data = build_raid_cooldowns_data(&shared).await.unwrap_or_default();
+                    if data != last_raid_cooldowns {
+                        let _ =
+                            overlay_tx.try_send(OverlayUpdate::RaidCooldownsUpdated(data.clone()));
+                        last_raid_cooldowns = data;
+                    }
+                }
+
                 // Effect audio: process in live mode
                 if shared.is_live_tailing.load(Ordering::SeqCst) {
                     let effect_audio = process_effect_audio(&shared).await;
@@ -1388,74 +2088,339 @@ impl CombatService {
                         let _ = audio_tx.try_send(AudioEvent::Alert {
                             text: alert.name,
                             custom_sound: alert.file,
+                            volume: alert.volume,
+                            speak: false,
                         });
                     }
                     // Send text alerts to overlay
                     if !effect_audio.text_alerts.is_empty() {
+                        shared.alert_history.record(&effect_audio.text_alerts);
                         let _ = overlay_tx
                             .try_send(OverlayUpdate::AlertsFired(effect_audio.text_alerts));
                     }
                 }
 
-                // Boss health: only poll when in combat
-                if boss_active
-                    && in_combat
-                    && let Some(data) = build_boss_health_data(&shared).await
-                {
-                    let _ = overlay_tx.try_send(OverlayUpdate::BossHealthUpdated(data));
-                }
+                // Boss health: only poll when in combat. The linger clears the
+                // overlay once combat has been over for a while.
+                if boss_active || live_clients {
+                    let data = if in_combat {
+                        build_boss_health_data(&shared).await
+                    } else {
+                        None
+                    };
+                    let linger_secs = shared
+                        .config
+                        .read()
+                        .await
+                        .overlay_settings
+                        .boss_health
+                        .linger_secs;
+                    let linger =
+                        std::time::Duration::try_from_secs_f32(linger_secs).unwrap_or_default();
+                    let now = std::time::Instant::now();
+                    if let Some(data) = boss_linger.update(data, linger, now) {
+                        let _ = overlay_tx.try_send(OverlayUpdate::BossHealthUpdated(data));
+                    }
+                }
+
+                // Timers + Audio: always poll when in live mode (alerts can fire at combat end)
+                if shared.is_live_tailing.load(Ordering::SeqCst) {
+                    // Process timer audio and get timer data (returns (TimersA data, TimersB data, countdowns, alerts))
+                    if let Some((timers_a, timers_b, countdowns, alerts)) =
+                        build_timer_data_with_audio(&shared, icon_cache.as_ref()).await
+                    {
+                        // Send timer overlay data (only when in combat)
+                        if in_combat && (timer_active || live_clients) {
+                            let _ = overlay_tx.try_send(OverlayUpdate::TimersAUpdated(timers_a));
+                            let _ = overlay_tx.try_send(OverlayUpdate::TimersBUpdated(timers_b));
+                        }
+
+                        // Send countdown audio events (only when in combat)
+                        if in_combat {
+                            for (name, seconds, voice_pack) in countdowns {
+                                let _ = audio_tx.try_send(AudioEvent::Countdown {
+                                    timer_name: name,
+                                    seconds,
+                                    voice_pack,
+                                });
+                            }
+                        }
+
+                        // Send alerts to overlay (before audio consumes them)
+                        if !alerts.is_empty() {
+                            shared.alert_history.record(&alerts);
+                            let _ = overlay_tx.try_send(OverlayUpdate::AlertsFired(alerts.clone()));
+                        }
+
+                        // Send alert audio events (only if audio_enabled for that alert)
+                        for alert in alerts {
+                            if alert.audio_enabled {
+                                let _ = audio_tx.try_send(AudioEvent::Alert {
+                                    text: alert.text,
+                                    custom_sound: alert.audio_file,
+                                    volume: alert.audio_volume,
+                                    speak: alert.speak,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        self.tail_handle = Some(tail_handle);
+        self.watchdog_handle = Some(watchdog_handle);
+        self.metrics_handle = Some(metrics_handle);
+        self.effects_handle = Some(effects_handle);
+    }
+
+    /// Parse the whole file in the parse worker subprocess and import its results
+    /// (falls back to a streaming parse in this process if the worker fails)
+    /// Parse worker invocation for a log file (arguments and environment)
+    fn parse_worker_command(
+        &self,
+        path: &Path,
+        session_id: &str,
+        encounters_dir: &Path,
+        ignore_list: &IgnoreList,
+    ) -> std::process::Command {
+        // Get boss definitions directory for phase detection
+        let definitions_dir = self
+            .app_handle
+            .path()
+            .resolve(
+                "definitions/encounters",
+                tauri::path::BaseDirectory::Resource,
+            )
+            .ok();
+
+        // Spawn parse worker subprocess
+        // Check multiple locations: bundled sidecar (with target triple), next to exe, fallback to PATH
+        let worker_path = std::env::current_exe()
+            .ok()
+            .and_then(|exe| {
+                let dir = exe.parent()?;
+                // Try sidecar name with target triple first (Tauri bundle format), then plain name
+                let candidates = [
+                    dir.join(format!(
+                        "baras-parse-worker-{}-unknown-linux-gnu",
+                        std::env::consts::ARCH
+                    )),
+                    dir.join("baras-parse-worker"),
+                ];
+                candidates.into_iter().find(|p| p.exists())
+            })
+            .unwrap_or_else(|| PathBuf::from("baras-parse-worker"));
+
+        debug!(worker_path = ?worker_path, "Using parse worker");
+
+        let mut cmd = std::process::Command::new(&worker_path);
+        cmd.arg(path).arg(session_id).arg(encounters_dir);
+
+        // Pass definitions directory if available
+        if let Some(ref def_dir) = definitions_dir {
+            cmd.arg(def_dir);
+            debug!(definitions_path = ?def_dir, "Using definitions directory");
+        }
+
+        // Pass log path so subprocess writes to same log file
+        if let Some(log_path) = dirs::config_dir().map(|p| p.join("baras").join("baras.log")) {
+            cmd.env("BARAS_LOG_PATH", &log_path);
+        }
+
+        if !ignore_list.is_empty() {
+            let ids: Vec<String> = ignore_list.ids().map(|id| id.to_string()).collect();
+            cmd.env("BARAS_IGNORED_IDS", ids.join(","));
+        }
+
+        cmd
+    }
+
+    async fn run_parse_worker(
+        &mut self,
+        path: &Path,
+        session_id: &str,
+        reader: &Reader,
+        session: &Arc<RwLock<ParsingSession>>,
+        encounters_dir: &Path,
+    ) {
+        let ignore_list = IgnoreList::from_config(&*self.shared.config.read().await);
+        let mut cmd = self.parse_worker_command(path, session_id, encounters_dir, &ignore_list);
+
+        // Large files: parse the most recent encounters first, backfill the rest later
+        let newest_first = std::fs::metadata(path)
+            .is_ok_and(|m| m.len() >= backfill::NEWEST_FIRST_MIN_BYTES);
+        if newest_first {
+            cmd.env("BARAS_PARSE_RECENT", "1");
+        }
+
+        let output = cmd.output();
 
-                // Timers + Audio: always poll when in live mode (alerts can fire at combat end)
-                if shared.is_live_tailing.load(Ordering::SeqCst) {
-                    // Process timer audio and get timer data (returns (TimersA data, TimersB data, countdowns, alerts))
-                    if let Some((timers_a, timers_b, countdowns, alerts)) =
-                        build_timer_data_with_audio(&shared).await
-                    {
-                        // Send timer overlay data (only when in combat)
-                        if in_combat && timer_active {
-                            let _ = overlay_tx.try_send(OverlayUpdate::TimersAUpdated(timers_a));
-                            let _ = overlay_tx.try_send(OverlayUpdate::TimersBUpdated(timers_b));
-                        }
+        match output {
+            Ok(output) if output.status.success() => {
+                // Parse JSON result from subprocess
+                let json_result = decode_worker_output(output.stdout);
 
-                        // Send countdown audio events (only when in combat)
-                        if in_combat {
-                            for (name, seconds, voice_pack) in countdowns {
-                                let _ = audio_tx.try_send(AudioEvent::Countdown {
-                                    timer_name: name,
-                                    seconds,
-                                    voice_pack,
-                                });
+                match json_result {
+                    Ok(parse_result) => {
+                        self.shared.id_dictionary.merge_session(encounters_dir);
+
+                        let mut session_guard = session.write().await;
+                        import_parse_output(&mut session_guard, &parse_result);
+
+                        // Enable live parquet writing (continues from where subprocess left off)
+                        session_guard.enable_live_parquet(
+                            encounters_dir.to_path_buf(),
+                            parse_result.encounter_count as u32,
+                        );
+
+                        // Load boss definitions for initial area (before releasing lock)
+                        if parse_result.area.area_id != 0 {
+                            if let Some(bosses) = self.load_area_definitions(parse_result.area.area_id) {
+                                session_guard.load_boss_definitions(bosses);
                             }
                         }
 
-                        // Send alerts to overlay (before audio consumes them)
-                        if !alerts.is_empty() {
-                            let _ = overlay_tx.try_send(OverlayUpdate::AlertsFired(alerts.clone()));
-                        }
+                        session_guard.finalize_session();
+                        session_guard.sync_timer_context();
+                        drop(session_guard);
 
-                        // Send alert audio events (only if audio_enabled for that alert)
-                        for alert in alerts {
-                            if alert.audio_enabled {
-                                let _ = audio_tx.try_send(AudioEvent::Alert {
-                                    text: alert.text,
-                                    custom_sound: alert.audio_file,
-                                });
-                            }
+                        info!(
+                            event_count = parse_result.event_count,
+                            encounter_count = parse_result.encounter_count,
+                            start_pos = parse_result.start_pos,
+                            elapsed_ms = parse_result.elapsed_ms,
+                            "Subprocess parse completed"
+                        );
+
+                        // Notify frontend to refresh session info
+                        let _ = self
+                            .app_handle
+                            .emit(SESSION_UPDATED_EVENT, SessionEvent::FileLoaded);
+
+                        if parse_result.start_pos > 0 {
+                            self.start_backfill(
+                                path,
+                                session_id,
+                                session,
+                                encounters_dir,
+                                &ignore_list,
+                                parse_result.start_pos,
+                            );
                         }
                     }
+                    Err(e) => {
+                        error!(error = %e, "Subprocess output parse failed");
+                        fallback_streaming_parse(reader, session, encounters_dir.to_path_buf()).await;
+                    }
                 }
             }
-        });
+            Ok(output) => {
+                error!(
+                    stderr = %String::from_utf8_lossy(&output.stderr),
+                    "Subprocess failed"
+                );
+                // Fallback to streaming parse in main process
+                fallback_streaming_parse(reader, session, encounters_dir.to_path_buf()).await;
+            }
+            Err(e) => {
+                error!(error = %e, "Failed to spawn subprocess");
+                // Fallback to streaming parse in main process
+                fallback_streaming_parse(reader, session, encounters_dir.to_path_buf()).await;
+            }
+        }
+    }
 
-        self.tail_handle = Some(tail_handle);
-        self.metrics_handle = Some(metrics_handle);
-        self.effects_handle = Some(effects_handle);
+    /// Parse the whole file in the background after its recent encounters were
+    /// parsed from `start_pos`
+    fn start_backfill(
+        &mut self,
+        path: &Path,
+        session_id: &str,
+        session: &Arc<RwLock<ParsingSession>>,
+        encounters_dir: &Path,
+        ignore_list: &IgnoreList,
+        start_pos: u64,
+    ) {
+        let mut cmd = self.parse_worker_command(path, session_id, encounters_dir, ignore_list);
+        cmd.env("BARAS_PARSE_BACKFILL", start_pos.to_string());
+        match backfill::Backfill::start(
+            cmd,
+            session.clone(),
+            encounters_dir.to_path_buf(),
+            self.shared.clone(),
+            self.app_handle.clone(),
+        ) {
+            Ok(backfill) => self.backfill = Some(backfill),
+            Err(e) => warn!(error = %e, "Failed to spawn backfill parse worker"),
+        }
+    }
+
+    /// Restore a parse checkpoint, then parse only the lines written after it
+    async fn resume_from_checkpoint(
+        &mut self,
+        reader: &Reader,
+        session: &Arc<RwLock<ParsingSession>>,
+        checkpoint: checkpoint::ParseCheckpoint,
+        encounters_dir: &Path,
+    ) {
+        let output = checkpoint.output;
+        let mut session_guard = session.write().await;
+        import_parse_output(&mut session_guard, &output);
+
+        // Encounters after the checkpoint are written next to the restored ones
+        session_guard.enable_live_parquet(encounters_dir.to_path_buf(), output.encounter_count as u32);
+
+        if output.area.area_id != 0
+            && let Some(bosses) = self.load_area_definitions(output.area.area_id)
+        {
+            session_guard.load_boss_definitions(bosses);
+        }
+
+        let session_date = session_guard.game_session_date.unwrap_or_default();
+        let mut clock = session_guard
+            .session_clock
+            .unwrap_or_else(|| SessionClock::new(session_date));
+        let result = reader.read_log_file_streaming_from(
+            output.end_pos,
+            session_date,
+            &mut clock,
+            |event| {
+                session_guard.process_event(event);
+            },
+        );
+        match result {
+            Ok((end_pos, event_count)) => {
+                session_guard.current_byte = Some(end_pos);
+                session_guard.session_clock = Some(clock);
+                info!(
+                    resumed_at = output.end_pos,
+                    event_count,
+                    encounter_count = output.encounter_count,
+                    "Resumed from parse checkpoint"
+                );
+            }
+            Err(e) => warn!(error = %e, "Failed to read log after parse checkpoint"),
+        }
+
+        session_guard.finalize_session();
+        session_guard.sync_timer_context();
+        drop(session_guard);
+
+        let _ = self
+            .app_handle
+            .emit(SESSION_UPDATED_EVENT, SessionEvent::FileLoaded);
     }
 
     async fn stop_tailing(&mut self) {
         // Reset combat state
         self.shared.in_combat.store(false, Ordering::SeqCst);
 
+        // Stop a backfill still parsing the previous file
+        if let Some(backfill) = self.backfill.take() {
+            backfill.cancel().await;
+        }
+
         // Cancel effects task
         if let Some(handle) = self.effects_handle.take() {
             handle.abort();
@@ -1468,6 +2433,12 @@ impl CombatService {
             let _ = handle.await;
         }
 
+        // Cancel watchdog before the tail task so it can't request a restart
+        if let Some(handle) = self.watchdog_handle.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
         // Cancel tail task
         if let Some(handle) = self.tail_handle.take() {
             handle.abort();
@@ -1477,6 +2448,61 @@ impl CombatService {
         *self.shared.session.write().await = None;
     }
 
+    /// Persist in-progress state before the app exits: the current encounter
+    /// goes to history and parquet, and the config is saved.
+    async fn shutdown(&mut self) {
+        let session = self.shared.session.read().await.clone();
+
+        // Stop all tasks first so no more events arrive while flushing
+        self.stop_tailing().await;
+        if let Some(server) = self.live_server.take() {
+            server.stop();
+        }
+
+        if let Some(session) = session {
+            session.write().await.finalize_live_session();
+        }
+
+        // Nothing to flush in safe mode: its overrides are runtime only
+        let config = self.shared.config.read().await.clone();
+        if config.safe_mode_stash.is_none()
+            && let Err(e) = config.save()
+        {
+            error!(error = %e, "Failed to save configuration on shutdown");
+        }
+
+        info!("Combat service shut down");
+    }
+
+    /// Replace a stalled tail reader with a fresh one, resuming from the last
+    /// fully processed byte. Session state (encounters, effects) is kept.
+    async fn restart_tail_reader(&mut self) {
+        let Some(session) = self.shared.session.read().await.clone() else {
+            return;
+        };
+        let Some(path) = session.read().await.active_file.clone() else {
+            return;
+        };
+
+        if let Some(handle) = self.tail_handle.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        warn!(path = %path.display(), "Tail reader stalled, restarting");
+        let reader = Reader::from(path, session).with_idle(self.shared.idle.clone());
+        self.tail_handle = Some(spawn_tail_reader(
+            reader,
+            self.shared.clone(),
+            self.overlay_tx.clone(),
+        ));
+
+        let _ = self.app_handle.emit(
+            "recoverable-warning",
+            "Live log reading stalled and was restarted automatically",
+        );
+    }
+
     async fn refresh_index(&mut self) {
         let log_dir = self.shared.config.read().await.log_directory.clone();
         if let Ok(index) = DirectoryIndex::build_index(&PathBuf::from(&log_dir)) {
@@ -1485,8 +2511,142 @@ impl CombatService {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Deep Idle
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// How often the idle monitor checks whether to enter deep idle
+const IDLE_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Tail Reader Watchdog
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// How often the watchdog compares reader progress against the file size
+const TAIL_WATCHDOG_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Consecutive checks without progress (while the file has unread bytes)
+/// before the reader is considered stuck. Two checks tolerate a line the game
+/// is still in the middle of writing.
+const TAIL_STALL_CHECKS: u32 = 2;
+
+fn spawn_tail_reader(
+    reader: Reader,
+    shared: Arc<SharedState>,
+    overlay_tx: OverlayUpdateSender,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        overlay_tx.report_health(&shared, Subsystem::Tailing, None);
+        if let Err(e) = reader.tail_log_file().await {
+            error!(error = %e, "Tail reader stopped");
+            overlay_tx.report_health(
+                &shared,
+                Subsystem::Tailing,
+                Some("Stopped reading the combat log".to_string()),
+            );
+        }
+    })
+}
+
+/// Record whether an area's definitions loaded, passing the definitions on
+fn report_definitions_health(
+    shared: &SharedState,
+    overlay_tx: &OverlayUpdateSender,
+    loaded: Result<Option<Vec<BossEncounterDefinition>>, String>,
+) -> Option<Vec<BossEncounterDefinition>> {
+    match loaded {
+        Ok(bosses) => {
+            overlay_tx.report_health(shared, Subsystem::Definitions, None);
+            bosses
+        }
+        Err(_) => {
+            overlay_tx.report_health(
+                shared,
+                Subsystem::Definitions,
+                Some("Encounter file failed to load".to_string()),
+            );
+            None
+        }
+    }
+}
+
+/// Request a reader restart when the log grows but the session's byte
+/// position doesn't advance (reader exited, or is stuck after a rotation edge case).
+fn spawn_tail_watchdog(
+    path: PathBuf,
+    session: Arc<RwLock<ParsingSession>>,
+    cmd_tx: mpsc::Sender<ServiceCommand>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut last_pos = None;
+        let mut stalled_checks = 0;
+
+        loop {
+            tokio::time::sleep(TAIL_WATCHDOG_INTERVAL).await;
+
+            let Ok(file_len) = tokio::fs::metadata(&path).await.map(|m| m.len()) else {
+                continue;
+            };
+            let pos = session.read().await.current_byte.unwrap_or(0);
+
+            if file_len > pos && last_pos == Some(pos) {
+                stalled_checks += 1;
+            } else {
+                stalled_checks = 0;
+            }
+            last_pos = Some(pos);
+
+            if stalled_checks >= TAIL_STALL_CHECKS {
+                stalled_checks = 0;
+                debug!(file_len, pos, "Tail reader made no progress");
+                if cmd_tx.send(ServiceCommand::TailStalled).await.is_err() {
+                    break; // Service shut down
+                }
+            }
+        }
+    })
+}
+
 /// Calculate unified combat data for all overlays
-async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData> {
+/// A per-tick label that is only re-formatted when its inputs change
+#[derive(Default)]
+struct CachedLabel {
+    inputs: u64,
+    value: Option<Arc<str>>,
+}
+
+impl CachedLabel {
+    fn get(
+        &mut self,
+        inputs: impl Hash,
+        build: impl FnOnce() -> Option<String>,
+    ) -> Option<Arc<str>> {
+        let mut hasher = std::hash::DefaultHasher::new();
+        inputs.hash(&mut hasher);
+        let inputs = hasher.finish();
+        if self.value.is_none() || self.inputs != inputs {
+            self.value = build().map(Arc::from);
+            self.inputs = inputs;
+        }
+        self.value.clone()
+    }
+}
+
+/// Scratch state reused across metrics ticks so a long fight doesn't rebuild
+/// the same vectors and label strings every 250 ms
+#[derive(Default)]
+pub(crate) struct CombatDataBuffers {
+    entity_metrics: Vec<EntityMetrics>,
+    class_discipline: CachedLabel,
+    encounter_name: CachedLabel,
+    difficulty: CachedLabel,
+    current_phase: CachedLabel,
+}
+
+async fn calculate_combat_data(
+    shared: &Arc<SharedState>,
+    buffers: &mut CombatDataBuffers,
+) -> Option<CombatData> {
     let session_guard = shared.session.read().await;
     let session = session_guard.as_ref()?;
     let session = session.read().await;
@@ -1494,18 +2654,30 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
 
     // Get player info for class/discipline and entity ID
     let player_info = &cache.player;
-    let class_discipline =
-        if !player_info.class_name.is_empty() && !player_info.discipline_name.is_empty() {
-            Some(format!(
-                "{} / {}",
-                player_info.class_name, player_info.discipline_name
-            ))
-        } else if !player_info.class_name.is_empty() {
-            Some(player_info.class_name.clone())
-        } else {
-            None
-        };
+    let class_discipline = buffers.class_discipline.get(
+        (&player_info.class_name, &player_info.discipline_name),
+        || {
+            if !player_info.class_name.is_empty() && !player_info.discipline_name.is_empty() {
+                Some(format!(
+                    "{} / {}",
+                    player_info.class_name, player_info.discipline_name
+                ))
+            } else if !player_info.class_name.is_empty() {
+                Some(player_info.class_name.clone())
+            } else {
+                None
+            }
+        },
+    );
     let player_entity_id = player_info.id;
+    let counters: Vec<(String, u64)> = shared
+        .counters
+        .lock()
+        .unwrap_or_else(|p| p.into_inner())
+        .values()
+        .into_iter()
+        .map(|c| (c.label, c.count))
+        .collect();
 
     // Try live encounter first, fall back to historical summary for initial hydration
     if let Some(encounter) = cache.last_combat_encounter() {
@@ -1526,19 +2698,24 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
         // If encounter is finalized (PostCombat), use the name from history to avoid off-by-one
         let encounter_name = if matches!(encounter.state, EncounterState::PostCombat { .. }) {
             // Encounter already finalized - use the display_name from history
-            cache
-                .encounter_history
-                .summaries()
-                .last()
-                .map(|s| s.display_name.clone())
+            let summary = cache.encounter_history.summaries().last();
+            buffers
+                .encounter_name
+                .get(summary.map(|s| &s.display_name), || {
+                    summary.map(|s| s.display_name.clone())
+                })
         } else if let Some(def) = encounter.active_boss_definition() {
             // Definition is active - use definition name with pull count
             let pull_count = cache.encounter_history.peek_pull_count(&def.name);
-            Some(format!("{} - {}", def.name, pull_count))
+            buffers.encounter_name.get((&def.name, pull_count), || {
+                Some(format!("{} - {}", def.name, pull_count))
+            })
         } else if let Some(boss) = boss_info {
             // Hardcoded boss detected (no definition) - use boss name with pull count
             let pull_count = cache.encounter_history.peek_pull_count(boss.boss);
-            Some(format!("{} - {}", boss.boss, pull_count))
+            buffers.encounter_name.get((boss.boss, pull_count), || {
+                Some(format!("{} - {}", boss.boss, pull_count))
+            })
         } else {
             // Trash encounter - use phase type with trash count
             let trash_count = cache.encounter_history.peek_trash_count();
@@ -1549,23 +2726,42 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
                 PhaseType::PvP => "PvP Match",
                 PhaseType::OpenWorld => "Open World",
             };
-            Some(format!("{} {}", label, trash_count))
+            buffers.encounter_name.get((label, trash_count), || {
+                Some(format!("{} {}", label, trash_count))
+            })
         };
 
         // Get difficulty from area info (blank for non-instanced content)
-        let difficulty = if !cache.current_area.difficulty_name.is_empty() {
-            Some(cache.current_area.difficulty_name.clone())
-        } else {
-            None
-        };
+        let difficulty_name = &cache.current_area.difficulty_name;
+        let difficulty = buffers.difficulty.get(difficulty_name, || {
+            (!difficulty_name.is_empty()).then(|| difficulty_name.clone())
+        });
 
         // Calculate metrics for all players (use session-level discipline registry)
-        let entity_metrics = encounter.calculate_entity_metrics(&cache.player_disciplines)?;
-        let metrics: Vec<PlayerMetrics> = entity_metrics
-            .into_iter()
-            .filter(|m| m.entity_type != EntityType::Npc)
-            .map(|m| m.to_player_metrics())
-            .collect();
+        if !encounter
+            .calculate_entity_metrics_into(&cache.player_disciplines, &mut buffers.entity_metrics)
+        {
+            return None;
+        }
+        let mut metrics: Vec<PlayerMetrics> = Vec::with_capacity(buffers.entity_metrics.len());
+        metrics.extend(
+            buffers
+                .entity_metrics
+                .iter()
+                .filter(|m| m.entity_type != EntityType::Npc)
+                .map(|m| m.to_player_metrics()),
+        );
+
+        // Threat on the local player's target (or the boss) for a target-scoped TPS meter
+        if let Some(target_id) = encounter.threat_target(player_entity_id)
+            && let Some(duration_ms) = encounter.duration_ms()
+        {
+            for m in &mut metrics {
+                let threat = encounter.threat_on(m.entity_id, target_id);
+                m.target_total_threat = threat as i64;
+                m.target_tps = (threat * 1000.0 / duration_ms as f64) as i64;
+            }
+        }
 
         // Build challenge data from encounter's tracker (persists with encounter, not boss state)
         let challenges = if encounter.challenge_tracker.is_active() {
@@ -1652,14 +2848,18 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
 
         // Get phase info from encounter's boss state
         // Look up the phase display name from the boss definition
-        let current_phase = encounter.current_phase.as_ref().and_then(|phase_id| {
-            encounter.active_boss_definition().and_then(|def| {
-                def.phases
+        let active_def = encounter.active_boss_definition();
+        let current_phase = buffers.current_phase.get(
+            (&encounter.current_phase, active_def.map(|def| &def.id)),
+            || {
+                let phase_id = encounter.current_phase.as_ref()?;
+                active_def?
+                    .phases
                     .iter()
                     .find(|p| &p.id == phase_id)
                     .map(|p| p.name.clone())
-            })
-        });
+            },
+        );
         let phase_time_secs = encounter
             .phase_started_at
             .map(|start| {
@@ -1667,6 +2867,11 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
                 (now - start).num_milliseconds() as f32 / 1000.0
             })
             .unwrap_or(0.0);
+        let next_phase_eta = encounter.next_phase_eta();
+        let time_to_enrage_secs = active_def.and_then(|def| {
+            let combat_secs = encounter.duration_ms()? as f32 / 1000.0;
+            def.time_to_enrage(combat_secs, encounter.difficulty)
+        });
 
         Some(CombatData {
             metrics,
@@ -1679,13 +2884,16 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
             challenges,
             current_phase,
             phase_time_secs,
+            next_phase_eta,
+            time_to_enrage_secs,
+            counters,
         })
     } else if let Some(summary) = cache.encounter_history.summaries().last() {
         // Fallback to historical summary for initial hydration when no live encounter exists
         let encounter_count = cache.encounter_history.summaries().len();
         let encounter_time_secs = summary.duration_seconds.max(0) as u64;
-        let encounter_name = Some(summary.display_name.clone());
-        let difficulty = summary.difficulty.clone();
+        let encounter_name = Some(Arc::from(summary.display_name.as_str()));
+        let difficulty = summary.difficulty.as_deref().map(Arc::from);
         let metrics = summary.player_metrics.clone();
 
         Some(CombatData {
@@ -1699,12 +2907,27 @@ async fn calculate_combat_data(shared: &Arc<SharedState>) -> Option<CombatData>
             challenges: None,
             current_phase: None,
             phase_time_secs: 0.0,
+            next_phase_eta: None,
+            time_to_enrage_secs: None,
+            counters,
         })
     } else {
         None
     }
 }
 
+/// Whether a panic left the live effect tracker mutex poisoned
+async fn effect_tracker_poisoned(shared: &Arc<SharedState>) -> bool {
+    let session_guard = shared.session.read().await;
+    let Some(session) = session_guard.as_ref() else {
+        return false;
+    };
+    let session = session.read().await;
+    session
+        .effect_tracker()
+        .is_some_and(|tracker| tracker.is_poisoned())
+}
+
 /// Build raid frame data from the effect tracker and registry
 ///
 /// Uses RaidSlotRegistry to maintain stable player positions.
@@ -1747,6 +2970,15 @@ async fn build_raid_frame_data(
         .map(|c| c.player.id)
         .unwrap_or(0);
 
+    // Full HP until a health value has been seen for the player
+    let hp_percent = |entity_id: i64| {
+        session
+            .session_cache
+            .as_ref()
+            .and_then(|c| c.player_hp_percent(entity_id))
+            .unwrap_or(1.0)
+    };
+
     // Process new targets queue - these are entities that JUST received an effect from local player
     // The registry handles duplicate rejection via try_register
     for target in tracker.take_new_targets() {
@@ -1785,8 +3017,8 @@ async fn build_raid_frame_data(
                 .remove(&player.entity_id)
                 .unwrap_or_default();
 
-            // Sort effects by effect_id for stable visual ordering
-            effects.sort_by_key(|e| e.effect_id);
+            // Most severe first, then effect_id for stable visual ordering
+            effects.sort_by_key(|e| (std::cmp::Reverse(e.severity), e.effect_id));
 
             // Map discipline to role (defaults to DPS if unknown)
             let role = player
@@ -1803,7 +3035,7 @@ async fn build_raid_frame_data(
                 slot,
                 player_id: Some(player.entity_id),
                 name: player.name.clone(),
-                hp_percent: 1.0,
+                hp_percent: hp_percent(player.entity_id),
                 role,
                 effects,
                 is_self: player.entity_id == local_player_id,
@@ -1828,7 +3060,14 @@ async fn build_boss_health_data(shared: &Arc<SharedState>) -> Option<BossHealthD
     }
 
     let entries = cache.get_boss_health();
-    Some(BossHealthData { entries })
+    let pace_secs = cache.current_encounter().and_then(|enc| {
+        let def = enc.active_boss_definition()?;
+        let key = KillPaceStore::key(&def.id, enc.difficulty);
+        shared
+            .kill_pace
+            .pace_delta_secs(&key, enc.combat_time_secs, enc.boss_hp_percent()?)
+    });
+    Some(BossHealthData { entries, pace_secs })
 }
 
 /// Build timer data with audio events (countdowns and alerts)
@@ -1838,6 +3077,7 @@ async fn build_boss_health_data(shared: &Arc<SharedState>) -> Option<BossHealthD
 /// Countdowns are (timer_name, seconds, voice_pack)
 async fn build_timer_data_with_audio(
     shared: &Arc<SharedState>,
+    icon_cache: Option<&Arc<baras_overlay::icons::IconCache>>,
 ) -> Option<(TimerData, TimerData, Vec<(String, u8, String)>, Vec<FiredAlert>)> {
     use baras_core::timers::TimerDisplayTarget;
 
@@ -1885,11 +3125,19 @@ async fn build_timer_data_with_audio(
         if remaining <= 0.0 {
             continue;
         }
+        let suggestion = timer.suggested_cooldown.as_ref();
+        let suggested_cooldown_icon = suggestion
+            .and_then(|s| s.icon_ability_id)
+            .zip(icon_cache)
+            .and_then(|(ability_id, cache)| cache.get_icon(ability_id))
+            .map(|data| Arc::new((data.width, data.height, data.rgba)));
         let entry = TimerEntry {
             name: timer.name.clone(),
             remaining_secs: remaining,
             total_secs: timer.duration.as_secs_f32(),
             color: timer.color,
+            suggested_cooldown: suggestion.map(|s| s.ability.clone()),
+            suggested_cooldown_icon,
         };
         match timer.display_target {
             TimerDisplayTarget::TimersA => entries_a.push(entry),
@@ -1898,9 +3146,44 @@ async fn build_timer_data_with_audio(
         }
     }
 
+    // Upcoming HP-triggered phase is annotated on the main timer overlay
+    let encounter = session
+        .session_cache
+        .as_ref()
+        .and_then(|cache| cache.current_encounter());
+    let next_phase = encounter
+        .and_then(|enc| enc.next_phase_eta())
+        .map(|eta| (eta.phase_name, eta.secs));
+
+    // So is the soonest raid-wide hit with damage learned from earlier pulls
+    let incoming_hit = encounter.and_then(|enc| {
+        let def = enc.active_boss_definition()?;
+        let key = KillPaceStore::key(&def.id, enc.difficulty);
+        timer_mgr
+            .active_timers()
+            .into_iter()
+            .filter_map(|timer| {
+                let estimate = shared
+                    .incoming_damage
+                    .estimate(&key, &timer.definition_id)
+                    .filter(|e| e.is_raid_wide())?;
+                let remaining = timer.remaining_secs_realtime();
+                (remaining > 0.0).then(|| (timer.name.clone(), remaining, estimate.per_person))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    });
+
     Some((
-        TimerData { entries: entries_a },
-        TimerData { entries: entries_b },
+        TimerData {
+            entries: entries_a,
+            next_phase,
+            incoming_hit,
+        },
+        TimerData {
+            entries: entries_b,
+            next_phase: None,
+            incoming_hit: None,
+        },
         countdowns,
         alerts,
     ))
@@ -1919,6 +3202,7 @@ struct EffectAudioResult {
 struct EffectAlert {
     name: String,
     file: Option<String>,
+    volume: Option<u8>,
 }
 
 /// Process effect audio (countdowns and alerts)
@@ -1959,6 +3243,9 @@ async fn process_effect_audio(shared: &std::sync::Arc<SharedState>) -> EffectAud
                 timestamp: chrono::Local::now().naive_local(),
                 audio_enabled: false,
                 audio_file: None,
+                audio_volume: None,
+                speak: false,
+                priority: AlertPriority::Normal,
             });
         }
 
@@ -1984,6 +3271,7 @@ async fn process_effect_audio(shared: &std::sync::Arc<SharedState>) -> EffectAud
             alerts.push(EffectAlert {
                 name: effect.display_text.clone(),
                 file: effect.audio_file.clone(),
+                volume: effect.audio_volume,
             });
         }
 
@@ -1992,6 +3280,7 @@ async fn process_effect_audio(shared: &std::sync::Arc<SharedState>) -> EffectAud
             alerts.push(EffectAlert {
                 name: effect.display_text.clone(),
                 file: effect.audio_file.clone(),
+                volume: effect.audio_volume,
             });
         }
     }
@@ -2015,7 +3304,8 @@ fn convert_to_raid_effect(
     // Effects on raid frames are typically HoTs/shields (is_buff defaults to true in RaidEffect::new())
     let mut raid_effect = RaidEffect::new(effect.game_effect_id, effect.name.clone())
         .with_charges(effect.stacks)
-        .with_color_rgba(effect.color);
+        .with_color_rgba(effect.color)
+        .with_severity(effect.severity);
 
     // applied_instant is already lag-compensated (backdated to game event time)
     // Just add duration to get the expiry instant
@@ -2324,6 +3614,69 @@ async fn build_dot_tracker_data(
     Some(DotTrackerData { targets })
 }
 
+/// Build tank swap overlay data from the tank-swap debuff tracker
+async fn build_tank_swap_data(shared: &Arc<SharedState>) -> Option<TankSwapData> {
+    let session_guard = shared.session.read().await;
+    let session = session_guard.as_ref()?;
+    let session = session.read().await;
+
+    let tank_swap_tracker = session.tank_swap_tracker()?;
+    let tracker = tank_swap_tracker.lock().unwrap_or_else(|p| p.into_inner());
+
+    let tanks = tracker
+        .tanks()
+        .iter()
+        .map(|tank| TankSwapEntry {
+            name: resolve(tank.name).to_string(),
+            stacks: tank.stacks,
+        })
+        .collect();
+
+    Some(TankSwapData {
+        tanks,
+        swap_at_stacks: tracker.swap_at_stacks(),
+    })
+}
+
+/// Build raid cooldown overlay data from the raid cooldown tracker
+async fn build_raid_cooldowns_data(shared: &Arc<SharedState>) -> Option<RaidCooldownsData> {
+    let session_guard = shared.session.read().await;
+    let session = session_guard.as_ref()?;
+    let session = session.read().await;
+
+    let cooldown_tracker = session.raid_cooldown_tracker()?;
+    let tracker = cooldown_tracker.lock().unwrap_or_else(|p| p.into_inner());
+    let now = tracker.now()?;
+
+    // One row per player, in the order they first used a cooldown
+    let mut players: Vec<(i64, RaidCooldownRow)> = Vec::new();
+    for cooldown_use in tracker.uses() {
+        let cooldown = cooldown_use.cooldown();
+        let cell = RaidCooldownCell {
+            label: cooldown.label.to_string(),
+            kind: cooldown.kind,
+            remaining_secs: cooldown_use.remaining_secs(now).ceil() as u16,
+        };
+        match players
+            .iter_mut()
+            .find(|(id, _)| *id == cooldown_use.entity_id)
+        {
+            Some((_, row)) => row.cooldowns.push(cell),
+            None => players.push((
+                cooldown_use.entity_id,
+                RaidCooldownRow {
+                    name: resolve(cooldown_use.name).to_string(),
+                    cooldowns: vec![cell],
+                },
+            )),
+        }
+    }
+
+    Some(RaidCooldownsData {
+        players: players.into_iter().map(|(_, row)| row).collect(),
+    })
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // DTOs for Tauri IPC
 // ─────────────────────────────────────────────────────────────────────────────
@@ -2350,17 +3703,23 @@ pub struct CombatData {
     /// Number of encounters in the session
     pub encounter_count: usize,
     /// Player's class and discipline (e.g., "Sorcerer / Corruption")
-    pub class_discipline: Option<String>,
+    pub class_discipline: Option<Arc<str>>,
     /// Current encounter display name (e.g., "Raid Trash 3" or "Dread Master Bestia Pull 1")
-    pub encounter_name: Option<String>,
+    pub encounter_name: Option<Arc<str>>,
     /// Current area difficulty (e.g., "NiM 8") or phase type for non-instanced content
-    pub difficulty: Option<String>,
+    pub difficulty: Option<Arc<str>>,
     /// Challenge metrics for boss encounters (polled with other metrics)
     pub challenges: Option<ChallengeData>,
     /// Current boss phase (if in a defined encounter)
-    pub current_phase: Option<String>,
+    pub current_phase: Option<Arc<str>>,
     /// Time spent in the current phase (seconds)
     pub phase_time_secs: f32,
+    /// Estimated time until the next HP-triggered phase
+    pub next_phase_eta: Option<PhaseEta>,
+    /// Seconds left before the boss's hard enrage
+    pub time_to_enrage_secs: Option<f32>,
+    /// Persistent counters as (label, count)
+    pub counters: Vec<(String, u64)>,
 }
 
 impl CombatData {
@@ -2371,11 +3730,11 @@ impl CombatData {
             .iter()
             .find(|m| m.entity_id == self.player_entity_id)?;
         Some(PersonalStats {
-            encounter_name: self.encounter_name.clone(),
-            difficulty: self.difficulty.clone(),
+            encounter_name: self.encounter_name.as_deref().map(str::to_owned),
+            difficulty: self.difficulty.as_deref().map(str::to_owned),
             encounter_time_secs: self.encounter_time_secs,
             encounter_count: self.encounter_count,
-            class_discipline: self.class_discipline.clone(),
+            class_discipline: self.class_discipline.as_deref().map(str::to_owned),
             apm: player.apm,
             dps: player.dps as i32,
             edps: player.edps as i32,
@@ -2385,6 +3744,7 @@ impl CombatData {
             hps: player.hps as i32,
             ehps: player.ehps as i32,
             total_healing: player.total_healing,
+            abs: player.abs as i32,
             dtps: player.dtps as i32,
             edtps: player.edtps as i32,
             tps: player.tps as i32,
@@ -2392,12 +3752,35 @@ impl CombatData {
             damage_crit_pct: player.damage_crit_pct,
             heal_crit_pct: player.heal_crit_pct,
             effective_heal_pct: player.effective_heal_pct,
-            current_phase: self.current_phase.clone(),
+            current_phase: self.current_phase.as_deref().map(str::to_owned),
             phase_time_secs: self.phase_time_secs,
+            next_phase_eta: self
+                .next_phase_eta
+                .as_ref()
+                .map(|eta| (eta.phase_name.clone(), eta.secs)),
+            time_to_enrage_secs: self.time_to_enrage_secs,
+            counters: self.counters.clone(),
         })
     }
 }
 
+/// Per-subsystem memory estimates in bytes, derived from collection capacities
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MemoryStats {
+    /// Number of strings in the global interner
+    pub interned_strings: usize,
+    /// Global string interner (never freed during a run)
+    pub interner_bytes: usize,
+    /// Live encounters, encounter history and session registries
+    pub session_cache_bytes: usize,
+    /// Data explorer query context (live batch + in-flight queries)
+    pub query_context_bytes: usize,
+    /// Events buffered for the current encounter's parquet file
+    pub encounter_buffer_bytes: usize,
+    /// Number of buffered events
+    pub encounter_buffer_rows: usize,
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct SessionInfo {
     pub player_name: Option<String>,
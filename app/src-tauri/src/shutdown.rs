@@ -0,0 +1,109 @@
+//! Graceful shutdown
+//!
+//! Runs when the app is asked to exit (tray "Quit", or closing the main window
+//! with minimize-to-tray disabled). The exit is held back until the combat
+//! service has finalized the live session (in-progress encounter to history
+//! and parquet), the config is saved, and overlay windows are closed.
+//!
+//! Every step shares one timeout so a hung overlay thread or slow disk can't
+//! keep the process alive.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::overlay::{OverlayManager, SharedOverlayState};
+use crate::service::ServiceHandle;
+
+/// Upper bound on the whole shutdown sequence
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Set once the sequence has run, so the follow-up exit request goes through
+static COMPLETE: AtomicBool = AtomicBool::new(false);
+
+/// Whether the shutdown sequence has already finished
+pub fn is_complete() -> bool {
+    COMPLETE.load(Ordering::SeqCst)
+}
+
+/// Run the shutdown sequence, then exit the app
+pub async fn run_and_exit<R: Runtime>(app: AppHandle<R>) {
+    run_bounded(run(&app), SHUTDOWN_TIMEOUT).await;
+    COMPLETE.store(true, Ordering::SeqCst);
+    app.exit(0);
+}
+
+/// Run `sequence` for at most `timeout`. Returns false if it was cut off.
+async fn run_bounded(sequence: impl Future<Output = ()>, timeout: Duration) -> bool {
+    let finished = tokio::time::timeout(timeout, sequence).await.is_ok();
+    if !finished {
+        tracing::warn!(
+            timeout_secs = timeout.as_secs(),
+            "Shutdown timed out, exiting anyway"
+        );
+    }
+    finished
+}
+
+async fn run<R: Runtime>(app: &AppHandle<R>) {
+    tracing::info!("Shutting down");
+
+    if let Some(service) = app.try_state::<ServiceHandle>() {
+        service.shutdown().await;
+    }
+
+    if let Some(overlay_state) = app.try_state::<SharedOverlayState>() {
+        close_overlays(&overlay_state).await;
+    }
+}
+
+/// Stop every overlay thread so windows are destroyed before the process ends
+async fn close_overlays(state: &SharedOverlayState) {
+    let handles = match state.lock() {
+        Ok(mut s) => s.drain(),
+        Err(e) => {
+            tracing::error!(error = %e, "Overlay state poisoned, skipping overlay shutdown");
+            return;
+        }
+    };
+
+    for handle in handles {
+        OverlayManager::shutdown_no_position(handle).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_sequence_runs_every_step_in_order() {
+        let steps = Mutex::new(Vec::new());
+        let sequence = async {
+            steps.lock().unwrap().push("service");
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            steps.lock().unwrap().push("overlays");
+        };
+
+        assert!(run_bounded(sequence, SHUTDOWN_TIMEOUT).await);
+        assert_eq!(*steps.lock().unwrap(), ["service", "overlays"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_hung_step_is_cut_off_at_timeout() {
+        let started = tokio::time::Instant::now();
+        let steps = Mutex::new(Vec::new());
+        let sequence = async {
+            steps.lock().unwrap().push("service");
+            std::future::pending::<()>().await;
+            steps.lock().unwrap().push("overlays");
+        };
+
+        assert!(!run_bounded(sequence, SHUTDOWN_TIMEOUT).await);
+        assert!(started.elapsed() >= SHUTDOWN_TIMEOUT);
+        assert_eq!(*steps.lock().unwrap(), ["service"]);
+    }
+}
@@ -0,0 +1,65 @@
+//! Recently fired alerts
+//!
+//! Every alert sent to the alerts overlay (timer alerts, audio offset warnings
+//! and effect alerts) is also recorded here, so the UI can show what fired
+//! during the last pull even when the overlay was hidden or the text missed.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use baras_core::timers::FiredAlert;
+
+/// Number of alerts kept in the history
+const ALERT_HISTORY_CAPACITY: usize = 100;
+
+/// One alert as shown in the alert history
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RecentAlert {
+    /// Timer or effect definition ID
+    pub id: String,
+    pub name: String,
+    pub text: String,
+    pub color: Option<[u8; 4]>,
+    /// Game time the alert fired (HH:MM:SS)
+    pub fired_at: String,
+}
+
+impl From<&FiredAlert> for RecentAlert {
+    fn from(alert: &FiredAlert) -> Self {
+        Self {
+            id: alert.id.clone(),
+            name: alert.name.clone(),
+            text: alert.text.clone(),
+            color: alert.color,
+            fired_at: alert.timestamp.format("%H:%M:%S").to_string(),
+        }
+    }
+}
+
+/// Ring buffer of the most recent alerts
+#[derive(Debug, Default)]
+pub struct AlertHistory {
+    alerts: Mutex<VecDeque<RecentAlert>>,
+}
+
+impl AlertHistory {
+    /// Record alerts that were just sent to the overlay
+    pub fn record(&self, fired: &[FiredAlert]) {
+        if fired.is_empty() {
+            return;
+        }
+        let mut alerts = self.alerts.lock().unwrap_or_else(|e| e.into_inner());
+        for alert in fired {
+            if alerts.len() == ALERT_HISTORY_CAPACITY {
+                alerts.pop_back();
+            }
+            alerts.push_front(RecentAlert::from(alert));
+        }
+    }
+
+    /// Up to `limit` most recent alerts, newest first
+    pub fn recent(&self, limit: usize) -> Vec<RecentAlert> {
+        let alerts = self.alerts.lock().unwrap_or_else(|e| e.into_inner());
+        alerts.iter().take(limit).cloned().collect()
+    }
+}
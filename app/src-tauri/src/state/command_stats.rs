@@ -0,0 +1,92 @@
+//! Tauri command timing
+//!
+//! Commands that touch session data (explorer queries, history, opening files)
+//! are wrapped in [`CommandStats::time`]. Every call is logged at debug level
+//! with its duration and a short argument summary; calls slower than
+//! [`SLOW_COMMAND_THRESHOLD`] are logged as warnings and kept in a small ring
+//! buffer that the Diagnostics section of the settings panel displays.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Commands taking longer than this are reported as slow
+pub const SLOW_COMMAND_THRESHOLD: Duration = Duration::from_millis(250);
+
+/// Number of slow commands kept for the diagnostics view
+const RECENT_SLOW_CAPACITY: usize = 20;
+
+/// One command that exceeded [`SLOW_COMMAND_THRESHOLD`]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SlowCommand {
+    pub command: &'static str,
+    /// Short summary of the arguments (encounter, tab, filters, ...)
+    pub args: String,
+    pub duration_ms: u64,
+    /// Local time the command finished (HH:MM:SS)
+    pub finished_at: String,
+}
+
+/// Snapshot of [`CommandStats`] for the frontend
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CommandTimings {
+    pub total: u64,
+    pub slow: u64,
+    pub threshold_ms: u64,
+    /// Most recent slow commands, newest first
+    pub recent_slow: Vec<SlowCommand>,
+}
+
+/// Call counters and recent slow commands
+#[derive(Debug, Default)]
+pub struct CommandStats {
+    total: AtomicU64,
+    slow: AtomicU64,
+    recent_slow: Mutex<VecDeque<SlowCommand>>,
+}
+
+impl CommandStats {
+    /// Run a command future, recording how long it took
+    pub async fn time<F: Future>(&self, command: &'static str, args: String, fut: F) -> F::Output {
+        let start = Instant::now();
+        let output = fut.await;
+        self.record(command, args, start.elapsed());
+        output
+    }
+
+    fn record(&self, command: &'static str, args: String, elapsed: Duration) {
+        self.total.fetch_add(1, Ordering::Relaxed);
+        let duration_ms = elapsed.as_millis() as u64;
+
+        if elapsed < SLOW_COMMAND_THRESHOLD {
+            tracing::debug!(command, duration_ms, args = %args, "Command finished");
+            return;
+        }
+
+        tracing::warn!(command, duration_ms, args = %args, "Slow command");
+        self.slow.fetch_add(1, Ordering::Relaxed);
+        let mut recent = self.recent_slow.lock().unwrap_or_else(|e| e.into_inner());
+        if recent.len() == RECENT_SLOW_CAPACITY {
+            recent.pop_back();
+        }
+        recent.push_front(SlowCommand {
+            command,
+            args,
+            duration_ms,
+            finished_at: chrono::Local::now().format("%H:%M:%S").to_string(),
+        });
+    }
+
+    /// Current counters and recent slow commands
+    pub fn timings(&self) -> CommandTimings {
+        let recent = self.recent_slow.lock().unwrap_or_else(|e| e.into_inner());
+        CommandTimings {
+            total: self.total.load(Ordering::Relaxed),
+            slow: self.slow.load(Ordering::Relaxed),
+            threshold_ms: SLOW_COMMAND_THRESHOLD.as_millis() as u64,
+            recent_slow: recent.iter().cloned().collect(),
+        }
+    }
+}
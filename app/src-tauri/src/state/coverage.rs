@@ -0,0 +1,181 @@
+//! Definition coverage telemetry
+//!
+//! Boss definition pulls and timer starts from live play are accumulated here
+//! and persisted to `coverage.json`, so users can preview the report at any
+//! time. A report is only posted once the user opts in and an endpoint is
+//! configured, at most once per [`SEND_INTERVAL`]; sent counts are then reset.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use baras_core::timers::EncounterCoverage;
+use baras_types::{
+    CoverageReport, EncounterCoverageReport, TelemetrySettings, TimerCoverageReport,
+};
+use chrono::{Local, NaiveDateTime};
+
+/// Minimum time between two reports
+pub const SEND_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+const USER_AGENT: &str = concat!("BARAS v", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct StoredEncounter {
+    pulls: u32,
+    timer_starts: BTreeMap<String, u32>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct StoredCoverage {
+    since: Option<String>,
+    last_sent: Option<NaiveDateTime>,
+    encounters: BTreeMap<String, StoredEncounter>,
+}
+
+/// Coverage counters not yet reported
+#[derive(Debug, Default)]
+pub struct CoverageStore {
+    stored: Mutex<StoredCoverage>,
+}
+
+fn coverage_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("coverage.json"))
+}
+
+impl CoverageStore {
+    /// Counters saved by a previous run (empty if none or unreadable)
+    pub fn load() -> Self {
+        let stored = coverage_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| {
+                serde_json::from_str(&content)
+                    .inspect_err(
+                        |e| tracing::warn!(error = %e, "Ignoring unreadable coverage file"),
+                    )
+                    .ok()
+            })
+            .unwrap_or_default();
+        Self {
+            stored: Mutex::new(stored),
+        }
+    }
+
+    /// Add counters taken from the timer manager and persist them
+    pub fn add(&self, coverage: HashMap<String, EncounterCoverage>) {
+        if coverage.is_empty() {
+            return;
+        }
+        let mut stored = self.stored.lock().unwrap_or_else(|e| e.into_inner());
+        stored
+            .since
+            .get_or_insert_with(|| Local::now().format("%Y-%m-%d").to_string());
+        for (definition_id, counts) in coverage {
+            let encounter = stored.encounters.entry(definition_id).or_default();
+            encounter.pulls += counts.pulls;
+            for (timer_id, starts) in counts.timer_starts {
+                *encounter.timer_starts.entry(timer_id).or_insert(0) += starts;
+            }
+        }
+        save(&stored);
+    }
+
+    /// The report that would be sent now
+    pub fn report(&self) -> CoverageReport {
+        let stored = self.stored.lock().unwrap_or_else(|e| e.into_inner());
+        CoverageReport {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            since: stored.since.clone(),
+            encounters: stored
+                .encounters
+                .iter()
+                .map(|(definition_id, encounter)| EncounterCoverageReport {
+                    definition_id: definition_id.clone(),
+                    pulls: encounter.pulls,
+                    timers: encounter
+                        .timer_starts
+                        .iter()
+                        .map(|(timer_id, starts)| TimerCoverageReport {
+                            timer_id: timer_id.clone(),
+                            starts: *starts,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether there is something to report and the last report is old enough
+    fn is_due(&self) -> bool {
+        let stored = self.stored.lock().unwrap_or_else(|e| e.into_inner());
+        !stored.encounters.is_empty()
+            && stored.last_sent.is_none_or(|sent| {
+                (Local::now().naive_local() - sent)
+                    .to_std()
+                    .unwrap_or_default()
+                    >= SEND_INTERVAL
+            })
+    }
+
+    /// Drop the counters of `report` after it was sent
+    fn mark_sent(&self, report: &CoverageReport) {
+        let mut stored = self.stored.lock().unwrap_or_else(|e| e.into_inner());
+        for sent in &report.encounters {
+            let Some(encounter) = stored.encounters.get_mut(&sent.definition_id) else {
+                continue;
+            };
+            encounter.pulls = encounter.pulls.saturating_sub(sent.pulls);
+            for timer in &sent.timers {
+                if let Some(starts) = encounter.timer_starts.get_mut(&timer.timer_id) {
+                    *starts = starts.saturating_sub(timer.starts);
+                }
+            }
+        }
+        // Encounters pulled while the report was in flight stay for the next one
+        stored.encounters.retain(|_, e| e.pulls > 0);
+        stored.since =
+            (!stored.encounters.is_empty()).then(|| Local::now().format("%Y-%m-%d").to_string());
+        stored.last_sent = Some(Local::now().naive_local());
+        save(&stored);
+    }
+
+    /// Post the report if the user opted in and one is due
+    pub async fn send_if_due(&self, settings: &TelemetrySettings) {
+        if !settings.enabled || settings.endpoint.is_empty() || !self.is_due() {
+            return;
+        }
+        let report = self.report();
+        let Ok(body) = serde_json::to_vec(&report) else {
+            return;
+        };
+        let result = reqwest::Client::new()
+            .post(&settings.endpoint)
+            .header("User-Agent", USER_AGENT)
+            .header("Content-Type", "application/json")
+            .body(body)
+            .timeout(Duration::from_secs(30))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        match result {
+            Ok(_) => {
+                tracing::info!(encounters = report.encounters.len(), "Sent coverage report");
+                self.mark_sent(&report);
+            }
+            Err(e) => tracing::warn!(error = %e, "Failed to send coverage report"),
+        }
+    }
+}
+
+fn save(stored: &StoredCoverage) {
+    let Some(path) = coverage_path() else {
+        return;
+    };
+    let result = serde_json::to_vec(stored)
+        .map_err(std::io::Error::other)
+        .and_then(|json| std::fs::write(&path, json));
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "Failed to save coverage counters");
+    }
+}
@@ -0,0 +1,65 @@
+//! Health of background subsystems
+//!
+//! A subsystem that fails (effect tracker lock poisoned, encounter definitions
+//! not loading, tail reader stopped) reports here. Status changes reach the
+//! overlay router as `OverlayUpdate::HealthChanged`, which puts a warning
+//! badge on the overlays that subsystem feeds.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A background subsystem whose failure degrades overlays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Subsystem {
+    /// Effect tracker behind raid frames and effect/cooldown overlays
+    EffectTracker,
+    /// Encounter definitions of the current area
+    Definitions,
+    /// Reader following the combat log
+    Tailing,
+}
+
+impl Subsystem {
+    /// Name shown in the overlay badge tooltip
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::EffectTracker => "Effects",
+            Self::Definitions => "Definitions",
+            Self::Tailing => "Log",
+        }
+    }
+}
+
+/// A subsystem's new status, `None` meaning healthy again
+#[derive(Debug, Clone)]
+pub struct SubsystemStatus {
+    pub subsystem: Subsystem,
+    pub problem: Option<String>,
+}
+
+/// Current problem of every degraded subsystem
+#[derive(Debug, Default)]
+pub struct SubsystemHealth {
+    problems: Mutex<HashMap<Subsystem, String>>,
+}
+
+impl SubsystemHealth {
+    /// Record a subsystem's status. Returns the status to announce if it
+    /// differs from the one recorded before.
+    pub fn report(&self, subsystem: Subsystem, problem: Option<String>) -> Option<SubsystemStatus> {
+        let mut problems = self.problems.lock().unwrap_or_else(|p| p.into_inner());
+        let changed = match &problem {
+            Some(problem) => problems.insert(subsystem, problem.clone()).as_ref() != Some(problem),
+            None => problems.remove(&subsystem).is_some(),
+        };
+        changed.then_some(SubsystemStatus { subsystem, problem })
+    }
+
+    /// Every degraded subsystem with its problem
+    pub fn problems(&self) -> Vec<(Subsystem, String)> {
+        let problems = self.problems.lock().unwrap_or_else(|p| p.into_inner());
+        let mut problems: Vec<_> = problems.iter().map(|(s, p)| (*s, p.clone())).collect();
+        problems.sort_by_key(|(s, _)| s.label());
+        problems
+    }
+}
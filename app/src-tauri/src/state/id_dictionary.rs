@@ -0,0 +1,80 @@
+//! Local dictionary of game IDs seen in parsed logs
+//!
+//! Live sessions record into the dictionary directly; the parse worker writes
+//! the IDs of the part it parsed next to its encounter files, which are merged
+//! in when it finishes. Saved to `id_dictionary.tsv` after each combat.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use baras_core::boss::BossEncounterDefinition;
+use baras_core::context::{ID_DICTIONARY_FILENAME, IdDictionary, id_dictionary_path};
+
+/// The user's ID dictionary, shared with the parsing session
+#[derive(Debug, Default)]
+pub struct IdDictionaryStore {
+    dictionary: Arc<Mutex<IdDictionary>>,
+}
+
+impl IdDictionaryStore {
+    /// Dictionary saved by a previous run (empty if none or unreadable)
+    pub fn load() -> Self {
+        let dictionary = id_dictionary_path()
+            .map(|path| {
+                IdDictionary::load(&path)
+                    .inspect_err(
+                        |e| tracing::warn!(error = %e, "Ignoring unreadable ID dictionary"),
+                    )
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default();
+        Self {
+            dictionary: Arc::new(Mutex::new(dictionary)),
+        }
+    }
+
+    /// Handle for a parsing session to record into
+    pub fn shared(&self) -> Arc<Mutex<IdDictionary>> {
+        self.dictionary.clone()
+    }
+
+    /// Run `f` with the dictionary locked
+    pub fn with<T>(&self, f: impl FnOnce(&IdDictionary) -> T) -> T {
+        f(&self.dictionary.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Merge the IDs a parse worker wrote to a session's data directory
+    pub fn merge_session(&self, encounters_dir: &Path) {
+        match IdDictionary::load(&encounters_dir.join(ID_DICTIONARY_FILENAME)) {
+            Ok(session) => self
+                .dictionary
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .merge(session),
+            Err(e) => tracing::warn!(error = %e, "Failed to read parse worker ID dictionary"),
+        }
+        self.save_if_dirty();
+    }
+
+    /// Add the IDs behind renamed abilities and effects to name selectors
+    pub fn expand_renamed_selectors(&self, bosses: &mut [BossEncounterDefinition]) {
+        let dictionary = self.dictionary.lock().unwrap_or_else(|e| e.into_inner());
+        for boss in bosses {
+            dictionary.expand_renamed_selectors(boss);
+        }
+    }
+
+    /// Write the dictionary if IDs were added since it was last saved
+    pub fn save_if_dirty(&self) {
+        let Some(path) = id_dictionary_path() else {
+            return;
+        };
+        let mut dictionary = self.dictionary.lock().unwrap_or_else(|e| e.into_inner());
+        if !dictionary.is_dirty() {
+            return;
+        }
+        if let Err(e) = dictionary.save(&path) {
+            tracing::warn!(error = %e, "Failed to save ID dictionary");
+        }
+    }
+}
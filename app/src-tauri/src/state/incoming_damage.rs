@@ -0,0 +1,89 @@
+//! Learned per-cast damage of boss timer abilities
+//!
+//! After each boss pull, the damage players took from the abilities named by
+//! the boss's timers is averaged into `incoming_damage.json`. While the boss
+//! is pulled, the timer overlay uses those averages to warn healers about the
+//! next raid-wide hit.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use baras_core::encounter::HitEstimate;
+
+/// Pulls after which older pulls stop outweighing new ones
+const MAX_PULL_WEIGHT: u32 = 10;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct StoredEstimate {
+    estimate: HitEstimate,
+    pulls: u32,
+}
+
+/// Averaged hit estimates per boss (see `KillPaceStore::key`) and timer ID
+#[derive(Debug, Default)]
+pub struct IncomingDamageStore {
+    bosses: Mutex<BTreeMap<String, BTreeMap<String, StoredEstimate>>>,
+}
+
+fn incoming_damage_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("incoming_damage.json"))
+}
+
+impl IncomingDamageStore {
+    /// Estimates saved by a previous run (empty if none or unreadable)
+    pub fn load() -> Self {
+        let bosses = incoming_damage_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| {
+                serde_json::from_str(&content)
+                    .inspect_err(
+                        |e| tracing::warn!(error = %e, "Ignoring unreadable incoming damage file"),
+                    )
+                    .ok()
+            })
+            .unwrap_or_default();
+        Self {
+            bosses: Mutex::new(bosses),
+        }
+    }
+
+    /// Average one pull's per-timer estimates into the stored ones
+    pub fn record(&self, key: String, estimates: Vec<(String, HitEstimate)>) {
+        if estimates.is_empty() {
+            return;
+        }
+        let mut bosses = self.bosses.lock().unwrap_or_else(|e| e.into_inner());
+        let timers = bosses.entry(key).or_default();
+        for (timer_id, estimate) in estimates {
+            timers
+                .entry(timer_id)
+                .and_modify(|stored| {
+                    stored.estimate = stored
+                        .estimate
+                        .blend(&estimate, stored.pulls.min(MAX_PULL_WEIGHT));
+                    stored.pulls += 1;
+                })
+                .or_insert(StoredEstimate { estimate, pulls: 1 });
+        }
+        save(&bosses);
+    }
+
+    /// Learned estimate for a boss timer
+    pub fn estimate(&self, key: &str, timer_id: &str) -> Option<HitEstimate> {
+        let bosses = self.bosses.lock().unwrap_or_else(|e| e.into_inner());
+        bosses.get(key)?.get(timer_id).map(|stored| stored.estimate)
+    }
+}
+
+fn save(bosses: &BTreeMap<String, BTreeMap<String, StoredEstimate>>) {
+    let Some(path) = incoming_damage_path() else {
+        return;
+    };
+    let result = serde_json::to_vec(bosses)
+        .map_err(std::io::Error::other)
+        .and_then(|json| std::fs::write(&path, json));
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "Failed to save incoming damage estimates");
+    }
+}
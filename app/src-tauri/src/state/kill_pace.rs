@@ -0,0 +1,100 @@
+//! Best-kill HP timelines for kill pace comparison
+//!
+//! The fastest kill of each boss and difficulty is persisted to
+//! `kill_pace.json`. While a boss is pulled, the boss health overlay compares
+//! the combined boss HP against that kill's timeline.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use baras_core::Difficulty;
+use baras_core::encounter::HpTimeline;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct StoredKill {
+    duration_secs: f32,
+    timeline: HpTimeline,
+}
+
+/// Fastest recorded kill per boss and difficulty
+#[derive(Debug, Default)]
+pub struct KillPaceStore {
+    kills: Mutex<BTreeMap<String, StoredKill>>,
+}
+
+fn kill_pace_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("kill_pace.json"))
+}
+
+impl KillPaceStore {
+    /// Kills saved by a previous run (empty if none or unreadable)
+    pub fn load() -> Self {
+        let kills = kill_pace_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| {
+                serde_json::from_str(&content)
+                    .inspect_err(
+                        |e| tracing::warn!(error = %e, "Ignoring unreadable kill pace file"),
+                    )
+                    .ok()
+            })
+            .unwrap_or_default();
+        Self {
+            kills: Mutex::new(kills),
+        }
+    }
+
+    /// Store key for a boss definition at a difficulty (e.g. `bestia:veteran8`)
+    pub fn key(definition_id: &str, difficulty: Option<Difficulty>) -> String {
+        match difficulty {
+            Some(d) => format!("{}:{}{}", definition_id, d.config_key(), d.group_size()),
+            None => definition_id.to_string(),
+        }
+    }
+
+    /// Keep `timeline` if it is a kill faster than the stored one.
+    /// Returns true if it became the new best.
+    pub fn record(&self, key: String, timeline: &HpTimeline) -> bool {
+        let Some(duration_secs) = timeline.duration_secs().filter(|_| timeline.is_kill()) else {
+            return false;
+        };
+        let mut kills = self.kills.lock().unwrap_or_else(|e| e.into_inner());
+        if kills
+            .get(&key)
+            .is_some_and(|best| best.duration_secs <= duration_secs)
+        {
+            return false;
+        }
+        kills.insert(
+            key,
+            StoredKill {
+                duration_secs,
+                timeline: timeline.clone(),
+            },
+        );
+        save(&kills);
+        true
+    }
+
+    /// Seconds the current pull is behind the best kill (negative when ahead)
+    pub fn pace_delta_secs(&self, key: &str, elapsed_secs: f32, hp_percent: f32) -> Option<f32> {
+        let kills = self.kills.lock().unwrap_or_else(|e| e.into_inner());
+        kills
+            .get(key)?
+            .timeline
+            .pace_delta_secs(elapsed_secs, hp_percent)
+    }
+}
+
+fn save(kills: &BTreeMap<String, StoredKill>) {
+    let Some(path) = kill_pace_path() else {
+        return;
+    };
+    let result = serde_json::to_vec(kills)
+        .map_err(std::io::Error::other)
+        .and_then(|json| std::fs::write(&path, json));
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "Failed to save kill pace");
+    }
+}
@@ -3,17 +3,43 @@
 //! This module contains all shared state types used across the Tauri application:
 //! - `SharedState`: Core application state shared between service and commands
 //! - `RaidSlotRegistry`: Persistent player-to-slot assignments for raid frames
+//! - `OverlayUpdateStats`: Counters for the service → overlay router channel
+//! - `CommandStats`: Tauri command durations and recent slow commands
+//! - `AlertHistory`: Recently fired alerts for the alert history view
+//! - `CoverageStore`: Opt-in definition coverage counters
+//! - `KillPaceStore`: Best-kill HP timelines for kill pace comparison
+//! - `IdDictionaryStore`: Game IDs and their names seen in parsed logs
+//! - `IncomingDamageStore`: Learned per-cast damage of boss timer abilities
+//! - `SubsystemHealth`: Degraded subsystems, shown as overlay warning badges
+//! - `CounterStore`: Persistent cross-session kill/wipe counters (from baras-core)
+//! - `IdleState`: Deep idle switch for the polling loops (from baras-core)
 
+mod alert_history;
+mod command_stats;
+mod coverage;
+mod health;
+mod id_dictionary;
+mod incoming_damage;
+mod kill_pace;
 mod raid_registry;
 
+pub use alert_history::{AlertHistory, RecentAlert};
+pub use command_stats::{CommandStats, CommandTimings};
+pub use coverage::CoverageStore;
+pub use health::{Subsystem, SubsystemHealth, SubsystemStatus};
+pub use id_dictionary::IdDictionaryStore;
+pub use incoming_damage::IncomingDamageStore;
+pub use kill_pace::KillPaceStore;
 pub use raid_registry::{RaidSlotRegistry, RegisteredPlayer};
 
-use std::sync::atomic::{AtomicBool, AtomicI64};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
 
-use baras_core::context::{AppConfig, DirectoryIndex, ParsingSession};
+use baras_core::context::{AppConfig, DirectoryIndex, IdleState, IgnoreList, ParsingSession};
 use baras_core::query::QueryContext;
+use baras_core::storage::{CounterStore, counters_path};
+use baras_types::AudioSettings;
 
 /// State shared between the combat service and Tauri commands.
 ///
@@ -26,6 +52,8 @@ use baras_core::query::QueryContext;
 pub struct SharedState {
     /// Application configuration (persisted to disk)
     pub config: RwLock<AppConfig>,
+    /// Audio settings read by the audio service ([`AppConfig::active_audio`])
+    pub audio_settings: Arc<RwLock<AudioSettings>>,
     /// Index of log files in the configured directory
     pub directory_index: RwLock<DirectoryIndex>,
     /// Current parsing session (when tailing a log file)
@@ -36,10 +64,16 @@ pub struct SharedState {
     pub watching: AtomicBool,
     /// Whether we're in live tailing mode (vs viewing historical file)
     pub is_live_tailing: AtomicBool,
+    /// Whether the open file is the bundled demo log
+    pub is_demo: AtomicBool,
     /// Raid frame slot assignments (persists player positions)
     pub raid_registry: Mutex<RaidSlotRegistry>,
     /// Current area ID for lazy loading timers (0 = unknown)
     pub current_area_id: AtomicI64,
+    /// Whether a backfill parse is still adding earlier encounters to the session
+    pub parse_backfill_pending: AtomicBool,
+    /// Deep idle between play sessions (polling loops wait on it)
+    pub idle: Arc<IdleState>,
 
     // ─── Overlay status flags (for skipping work when not needed) ───
     /// Whether raid overlay is currently running
@@ -56,8 +90,18 @@ pub struct SharedState {
     pub cooldowns_overlay_active: AtomicBool,
     /// Whether DOT tracker overlay is currently running
     pub dot_tracker_overlay_active: AtomicBool,
+    /// Whether tank swap overlay is currently running
+    pub tank_swap_overlay_active: AtomicBool,
+    /// Whether raid cooldown overlay is currently running
+    pub raid_cooldowns_overlay_active: AtomicBool,
+    /// Whether death recap overlay is currently running
+    pub death_recap_overlay_active: AtomicBool,
+    /// Whether boss notes overlay is currently running
+    pub boss_notes_overlay_active: AtomicBool,
     /// Whether raid frame rearrange mode is active (bypasses rendering gates)
     pub rearrange_mode: AtomicBool,
+    /// Whether the local player appears to be in a group (raid frames hide while solo)
+    pub grouped: AtomicBool,
 
     // ─── Conversation auto-hide state ───────────────────────────────────────
     /// Whether overlays are temporarily hidden due to conversation
@@ -67,19 +111,61 @@ pub struct SharedState {
 
     /// Shared query context for DataFusion queries (reuses SessionContext)
     pub query_context: QueryContext,
+
+    /// Sent/dropped/coalesced counters for overlay updates
+    pub overlay_updates: Arc<OverlayUpdateStats>,
+
+    /// Tauri command durations (see [`CommandStats`])
+    pub command_stats: CommandStats,
+
+    /// Recently fired alerts (see [`AlertHistory`])
+    pub alert_history: AlertHistory,
+
+    /// Definition coverage counters (see [`CoverageStore`])
+    pub coverage: CoverageStore,
+
+    /// Best boss kills for the boss health pace readout (see [`KillPaceStore`])
+    pub kill_pace: KillPaceStore,
+
+    /// Game IDs seen in parsed logs (see [`IdDictionaryStore`])
+    pub id_dictionary: IdDictionaryStore,
+
+    /// Learned boss hit damage for the incoming hit readout (see [`IncomingDamageStore`])
+    pub incoming_damage: IncomingDamageStore,
+
+    /// Cross-session kill/wipe counters for the personal overlay (see [`CounterStore`])
+    pub counters: Mutex<CounterStore>,
+
+    /// Degraded background subsystems (see [`SubsystemHealth`])
+    pub health: SubsystemHealth,
 }
 
 impl SharedState {
     pub fn new(config: AppConfig, directory_index: DirectoryIndex) -> Self {
+        let ignore_list = IgnoreList::from_config(&config);
+        let audio_settings = Arc::new(RwLock::new(config.active_audio().clone()));
+        baras_overlay::set_overlay_fps(config.performance.overlay_fps);
+        baras_overlay::set_renderer_preference(config.overlay_renderer);
+        baras_overlay::set_frame_style(config.overlay_settings.frame_style);
+        crate::overlay::set_tps_current_target(
+            config
+                .overlay_settings
+                .get_appearance("tps")
+                .current_target_only,
+        );
         Self {
             config: RwLock::new(config),
+            audio_settings,
             directory_index: RwLock::new(directory_index),
             session: RwLock::new(None),
             in_combat: AtomicBool::new(false),
             watching: AtomicBool::new(false),
             is_live_tailing: AtomicBool::new(true), // Start in live tailing mode
-            raid_registry: Mutex::new(RaidSlotRegistry::new(8)), // Default 8 slots (2x4 grid)
+            is_demo: AtomicBool::new(false),
+            raid_registry: Mutex::new(RaidSlotRegistry::load(8)), // Default 8 slots (2x4 grid)
             current_area_id: AtomicI64::new(0),
+            parse_backfill_pending: AtomicBool::new(false),
+            idle: Arc::new(IdleState::default()),
             // Overlay status flags - updated by OverlayManager
             raid_overlay_active: AtomicBool::new(false),
             boss_health_overlay_active: AtomicBool::new(false),
@@ -88,13 +174,107 @@ impl SharedState {
             effects_b_overlay_active: AtomicBool::new(false),
             cooldowns_overlay_active: AtomicBool::new(false),
             dot_tracker_overlay_active: AtomicBool::new(false),
+            tank_swap_overlay_active: AtomicBool::new(false),
+            raid_cooldowns_overlay_active: AtomicBool::new(false),
+            death_recap_overlay_active: AtomicBool::new(false),
+            boss_notes_overlay_active: AtomicBool::new(false),
             rearrange_mode: AtomicBool::new(false),
+            grouped: AtomicBool::new(false),
             // Conversation auto-hide state
             conversation_hiding_active: AtomicBool::new(false),
             overlays_visible_before_conversation: AtomicBool::new(false),
             // Shared query context for DataFusion (reuses SessionContext across queries)
-            query_context: QueryContext::new(),
+            query_context: QueryContext::with_ignore_list(ignore_list),
+            overlay_updates: Arc::new(OverlayUpdateStats::default()),
+            command_stats: CommandStats::default(),
+            alert_history: AlertHistory::default(),
+            coverage: CoverageStore::load(),
+            kill_pace: KillPaceStore::load(),
+            id_dictionary: IdDictionaryStore::load(),
+            incoming_damage: IncomingDamageStore::load(),
+            counters: Mutex::new(load_counters()),
+            health: SubsystemHealth::default(),
+        }
+    }
+
+    /// Replace the in-memory config and propagate the changes that live
+    /// outside of it (raid slot count, effect tracker alacrity/latency,
+    /// session cache compaction, ignore list, audio settings).
+    ///
+    /// Does not persist to disk. Returns `true` if the log directory changed,
+    /// in which case the caller must restart the directory watcher.
+    pub async fn apply_config(&self, config: AppConfig) -> bool {
+        let old_config = self.config.read().await.clone();
+
+        let old_slots = old_config.overlay_settings.raid_overlay.grid_columns
+            * old_config.overlay_settings.raid_overlay.grid_rows;
+        let new_slots =
+            config.overlay_settings.raid_overlay.grid_columns * config.overlay_settings.raid_overlay.grid_rows;
+
+        let alacrity_changed = old_config.alacrity_percent != config.alacrity_percent;
+        let latency_changed = old_config.latency_ms != config.latency_ms;
+        let dir_changed = old_config.log_directory != config.log_directory;
+        let compaction_changed = old_config.compact_session_cache != config.compact_session_cache;
+        let new_compaction = config.compact_session_cache;
+        let new_alacrity = config.alacrity_percent;
+        let new_latency = config.latency_ms;
+        let ignore_list_changed = old_config.ignored_ids != config.ignored_ids;
+        let new_ignore_list = IgnoreList::from_config(&config);
+        let new_audio = config.active_audio().clone();
+
+        baras_overlay::set_overlay_fps(config.performance.overlay_fps);
+        baras_overlay::set_renderer_preference(config.overlay_renderer);
+        baras_overlay::set_frame_style(config.overlay_settings.frame_style);
+        crate::overlay::set_tps_current_target(
+            config
+                .overlay_settings
+                .get_appearance("tps")
+                .current_target_only,
+        );
+        *self.config.write().await = config;
+        *self.audio_settings.write().await = new_audio;
+
+        // Update raid registry max slots if grid size changed
+        if new_slots != old_slots {
+            self.raid_registry
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .set_max_slots(new_slots);
+        }
+
+        // Update effect tracker alacrity/latency if changed
+        if (alacrity_changed || latency_changed)
+            && let Some(session) = self.session.read().await.as_ref()
+        {
+            let session = session.read().await;
+            if let Some(tracker) = session.effect_tracker() {
+                let mut tracker = tracker.lock().unwrap_or_else(|p| p.into_inner());
+                if alacrity_changed {
+                    tracker.set_alacrity(new_alacrity);
+                }
+                if latency_changed {
+                    tracker.set_latency(new_latency);
+                }
+            }
+        }
+
+        if compaction_changed
+            && let Some(session) = self.session.read().await.as_ref()
+        {
+            session.write().await.set_cache_compaction(new_compaction);
+        }
+
+        if ignore_list_changed {
+            if let Some(session) = self.session.read().await.as_ref() {
+                session
+                    .write()
+                    .await
+                    .set_ignore_list(new_ignore_list.clone());
+            }
+            self.query_context.set_ignore_list(new_ignore_list).await;
         }
+
+        dir_changed
     }
 
     /// Execute a function with mutable access to the current session.
@@ -112,3 +292,49 @@ impl SharedState {
         }
     }
 }
+
+/// Load the counter store. An unreadable file leaves the counters in memory
+/// only, so it is not overwritten.
+fn load_counters() -> CounterStore {
+    let Some(path) = counters_path() else {
+        return CounterStore::default();
+    };
+    CounterStore::load(path).unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "Ignoring unreadable counter file");
+        CounterStore::default()
+    })
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Overlay Update Stats
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Counters for the service → overlay router channel
+#[derive(Debug, Default)]
+pub struct OverlayUpdateStats {
+    /// Updates queued for the router
+    pub sent: AtomicU64,
+    /// Updates dropped because the channel was full
+    pub dropped: AtomicU64,
+    /// Snapshot updates skipped by the router because a newer one was queued
+    pub coalesced: AtomicU64,
+}
+
+impl OverlayUpdateStats {
+    /// Current counter values
+    pub fn counts(&self) -> OverlayUpdateCounts {
+        OverlayUpdateCounts {
+            sent: self.sent.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            coalesced: self.coalesced.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Snapshot of [`OverlayUpdateStats`] for the frontend
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct OverlayUpdateCounts {
+    pub sent: u64,
+    pub dropped: u64,
+    pub coalesced: u64,
+}
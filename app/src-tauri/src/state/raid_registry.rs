@@ -2,8 +2,14 @@
 //!
 //! Players are added when they receive an effect from the local player.
 //! Players stay in their assigned slot until explicitly removed by user action.
+//!
+//! Slots the user rearranges players into are remembered by name in
+//! `raid_slot_order.json`, so returning players get their slot back.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
+
+use baras_types::RaidSlotAssignment;
 
 /// Information about a player registered in the raid frame
 #[derive(Debug, Clone)]
@@ -41,6 +47,12 @@ pub struct RaidSlotRegistry {
     /// (DisciplineChanged often fires before player is registered)
     /// Maps entity_id -> (class_id, discipline_id)
     pending_disciplines: HashMap<i64, (i64, i64)>,
+    /// Slots players were rearranged into, by name (persisted)
+    preferred_slots: HashMap<String, u8>,
+}
+
+fn slot_order_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("raid_slot_order.json"))
 }
 
 impl RaidSlotRegistry {
@@ -50,6 +62,25 @@ impl RaidSlotRegistry {
             entity_to_slot: HashMap::new(),
             max_slots,
             pending_disciplines: HashMap::new(),
+            preferred_slots: HashMap::new(),
+        }
+    }
+
+    /// Registry with the slot order saved by a previous run (none if unreadable)
+    pub fn load(max_slots: u8) -> Self {
+        let preferred_slots = slot_order_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| {
+                serde_json::from_str(&content)
+                    .inspect_err(
+                        |e| tracing::warn!(error = %e, "Ignoring unreadable raid slot order"),
+                    )
+                    .ok()
+            })
+            .unwrap_or_default();
+        Self {
+            preferred_slots,
+            ..Self::new(max_slots)
         }
     }
 
@@ -63,8 +94,14 @@ impl RaidSlotRegistry {
             return None;
         }
 
-        // Find first available slot (returns None if all full)
-        let slot = self.find_first_available_slot()?;
+        // Use the slot the player was arranged into if it is free, otherwise
+        // the first available slot (returns None if all full)
+        let slot = self
+            .preferred_slots
+            .get(&name)
+            .copied()
+            .filter(|&s| s < self.max_slots && !self.slots.contains_key(&s))
+            .or_else(|| self.find_first_available_slot())?;
         let mut player = RegisteredPlayer::new(entity_id, name);
 
         // Check for pending discipline info (DisciplineChanged often fires before registration)
@@ -121,15 +158,53 @@ impl RaidSlotRegistry {
             self.entity_to_slot.insert(p.entity_id, slot_a);
             self.slots.insert(slot_a, p);
         }
+
+        self.remember_slots(&[slot_a, slot_b]);
     }
 
     /// Remove player from a specific slot (user-initiated delete)
     pub fn remove_slot(&mut self, slot: u8) {
         if let Some(player) = self.slots.remove(&slot) {
             self.entity_to_slot.remove(&player.entity_id);
+            if self.preferred_slots.remove(&player.name).is_some() {
+                self.save_slot_order();
+            }
         }
     }
 
+    /// Remember the players now in `slots` as the ones arranged there, and save
+    fn remember_slots(&mut self, slots: &[u8]) {
+        for &slot in slots {
+            self.preferred_slots.retain(|_, s| *s != slot);
+            if let Some(player) = self.slots.get(&slot) {
+                self.preferred_slots.insert(player.name.clone(), slot);
+            }
+        }
+        self.save_slot_order();
+    }
+
+    fn save_slot_order(&self) {
+        let Some(path) = slot_order_path() else {
+            return;
+        };
+        let result = serde_json::to_vec(&self.preferred_slots)
+            .map_err(std::io::Error::other)
+            .and_then(|json| std::fs::write(&path, json));
+        if let Err(e) = result {
+            tracing::warn!(error = %e, "Failed to save raid slot order");
+        }
+    }
+
+    /// Every slot in order with the player in it, for keyboard rearranging
+    pub fn assignments(&self) -> Vec<RaidSlotAssignment> {
+        (0..self.max_slots)
+            .map(|slot| RaidSlotAssignment {
+                slot,
+                name: self.slots.get(&slot).map(|p| p.name.clone()),
+            })
+            .collect()
+    }
+
     /// Get the slot for an entity (if registered)
     pub fn get_slot(&self, entity_id: i64) -> Option<u8> {
         self.entity_to_slot.get(&entity_id).copied()
@@ -145,7 +220,7 @@ impl RaidSlotRegistry {
         self.entity_to_slot.contains_key(&entity_id)
     }
 
-    /// Clear all assignments (new session/encounter)
+    /// Clear all assignments (new session/encounter). Remembered slots are kept.
     pub fn clear(&mut self) {
         self.slots.clear();
         self.entity_to_slot.clear();
@@ -24,11 +24,12 @@ pub fn setup_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::err
         true,
         None::<&str>,
     )?;
+    let safe_mode = MenuItem::with_id(app, "safe_mode", "Safe Mode", true, None::<&str>)?;
     let separator = MenuItem::with_id(app, "sep", "─────────────", false, None::<&str>)?;
     let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
 
     // Build menu
-    let menu = Menu::with_items(app, &[&show_hide, &toggle_overlays, &separator, &quit])?;
+    let menu = Menu::with_items(app, &[&show_hide, &toggle_overlays, &safe_mode, &separator, &quit])?;
 
     // Build tray icon
     let _tray = TrayIconBuilder::new()
@@ -50,10 +51,7 @@ pub fn setup_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::err
             } = event
             {
                 // Double-click or single click to show window
-                if let Some(window) = tray.app_handle().get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                }
+                crate::instance::show_main_window(tray.app_handle());
             }
         })
         .build(app)?;
@@ -69,8 +67,7 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, id: &str) {
                 if window.is_visible().unwrap_or(false) {
                     let _ = window.hide();
                 } else {
-                    let _ = window.show();
-                    let _ = window.set_focus();
+                    crate::instance::show_main_window(app);
                 }
             }
         }
@@ -86,8 +83,18 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, id: &str) {
                 toggle_all_overlays(state, handle).await;
             });
         }
+        "safe_mode" => {
+            let state = app.state::<Arc<Mutex<OverlayState>>>().inner().clone();
+            let handle = app.state::<ServiceHandle>().inner().clone();
+            let app = app.clone();
+
+            tauri::async_runtime::spawn(async move {
+                crate::safe_mode::enter(&app, &state, &handle).await;
+            });
+        }
         "quit" => {
-            std::process::exit(0);
+            // Goes through RunEvent::ExitRequested for a graceful shutdown
+            app.exit(0);
         }
         _ => {}
     }
@@ -7,7 +7,12 @@ use serde::Serialize;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::prelude::*;
 
-use crate::types::{AppConfig, OverlayStatus, OverlayType, SessionInfo};
+use crate::types::{
+    AppConfig, AreaCoverageReport, CommandError, CommandTimings, CounterRule, CounterValue,
+    CoverageReport, HistoricalEncounter, HistoryFilter, MemoryStats, OverlayStatus, OverlayType,
+    OverlayUpdateCounts, RaidSlotAssignment, RecentAlert, SessionEvent, SessionInfo, SetupHealth,
+    TimelineEvent,
+};
 use crate::utils::js_set;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -33,6 +38,12 @@ extern "C" {
 // Helper Functions
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Decode the payload of a `session-updated` event
+pub fn session_event(event: &JsValue) -> Option<SessionEvent> {
+    let payload = js_sys::Reflect::get(event, &JsValue::from_str("payload")).ok()?;
+    from_js(payload)
+}
+
 /// Build a JsValue object with a single key-value pair
 fn build_args<T: Serialize + ?Sized>(key: &str, value: &T) -> JsValue {
     let args = serde_wasm_bindgen::to_value(value).unwrap_or(JsValue::NULL);
@@ -77,8 +88,10 @@ async fn try_invoke(cmd: &str, args: JsValue) -> Result<JsValue, String> {
 
     // Await the promise, catching rejections
     JsFuture::from(promise).await.map_err(|e| {
-        // Extract error message from JsValue
-        e.as_string().unwrap_or_else(|| format!("{:?}", e))
+        // Extract error message from JsValue (plain string or structured CommandError)
+        e.as_string()
+            .or_else(|| from_js::<CommandError>(e.clone()).map(|err| err.message))
+            .unwrap_or_else(|| format!("{:?}", e))
     })
 }
 
@@ -98,6 +111,47 @@ pub async fn update_config(config: &AppConfig) -> Result<(), String> {
     Ok(())
 }
 
+/// Bind an overlay profile to a character (`None` removes the binding)
+pub async fn set_character_profile(
+    character_name: &str,
+    profile_name: Option<&str>,
+) -> Result<(), String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "characterName", &JsValue::from_str(character_name));
+    match profile_name {
+        Some(name) => js_set(&obj, "profileName", &JsValue::from_str(name)),
+        None => js_set(&obj, "profileName", &JsValue::NULL),
+    }
+    try_invoke("set_character_profile", obj.into()).await?;
+    Ok(())
+}
+
+/// Give a character its own alert audio, or return it to the global settings
+pub async fn set_character_audio(character_name: &str, enabled: bool) -> Result<(), String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "characterName", &JsValue::from_str(character_name));
+    js_set(&obj, "enabled", &JsValue::from_bool(enabled));
+    try_invoke("set_character_audio", obj.into()).await?;
+    Ok(())
+}
+
+/// Leave an ability/effect ID out of metrics, effect tracking, and queries
+pub async fn add_ignored_id(id: i64, name: &str) -> Result<(), String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "id", &JsValue::from_f64(id as f64));
+    js_set(&obj, "name", &JsValue::from_str(name));
+    try_invoke("add_ignored_id", obj.into()).await?;
+    Ok(())
+}
+
+/// Remove an ID from the ignore list
+pub async fn remove_ignored_id(id: i64) -> Result<(), String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "id", &JsValue::from_f64(id as f64));
+    try_invoke("remove_ignored_id", obj.into()).await?;
+    Ok(())
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Overlay Commands
 // ─────────────────────────────────────────────────────────────────────────────
@@ -191,6 +245,21 @@ pub async fn clear_raid_registry() {
     let _ = invoke("clear_raid_registry", JsValue::NULL).await;
 }
 
+/// Every raid frame slot with the player in it
+pub async fn get_raid_slots() -> Option<Vec<RaidSlotAssignment>> {
+    let result = invoke("get_raid_slots", JsValue::NULL).await;
+    from_js(result)
+}
+
+/// Swap the players in two raid frame slots
+pub async fn swap_raid_slots(slot_a: u8, slot_b: u8) -> Result<(), String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "slotA", &JsValue::from_f64(slot_a as f64));
+    js_set(&obj, "slotB", &JsValue::from_f64(slot_b as f64));
+    try_invoke("swap_raid_slots", obj.into()).await?;
+    Ok(())
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Session Commands
 // ─────────────────────────────────────────────────────────────────────────────
@@ -284,6 +353,18 @@ pub async fn is_live_tailing() -> bool {
     from_js(result).unwrap_or(true)
 }
 
+/// Open the bundled sample log in demo mode
+pub async fn load_demo() -> Result<(), String> {
+    try_invoke("load_demo", JsValue::NULL).await?;
+    Ok(())
+}
+
+/// Check if the demo log is open
+pub async fn is_demo_mode() -> bool {
+    let result = invoke("is_demo_mode", JsValue::NULL).await;
+    from_js(result).unwrap_or(false)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Profile Commands
 // ─────────────────────────────────────────────────────────────────────────────
@@ -352,6 +433,86 @@ pub async fn get_encounter_history()
     from_js(result)
 }
 
+/// Get encounters of previously parsed logs from the persistent history
+pub async fn get_historical_encounters(filter: &HistoryFilter) -> Vec<HistoricalEncounter> {
+    let result = invoke("get_historical_encounters", build_args("filter", filter)).await;
+    from_js(result).unwrap_or_default()
+}
+
+/// Get the persistent counters with their current counts
+pub async fn get_counters() -> Vec<CounterValue> {
+    let result = invoke("get_counters", JsValue::NULL).await;
+    from_js(result).unwrap_or_default()
+}
+
+/// Get the persistent counter rules
+pub async fn get_counter_rules() -> Vec<CounterRule> {
+    let result = invoke("get_counter_rules", JsValue::NULL).await;
+    from_js(result).unwrap_or_default()
+}
+
+/// Replace the persistent counter rules (counts of removed rules are dropped)
+pub async fn save_counter_rules(rules: &[CounterRule]) -> Result<(), String> {
+    try_invoke("save_counter_rules", build_args("rules", rules)).await?;
+    Ok(())
+}
+
+/// Set a persistent counter back to zero
+pub async fn reset_counter(id: &str) -> Result<(), String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "id", &JsValue::from_str(id));
+    try_invoke("reset_counter", obj.into()).await?;
+    Ok(())
+}
+
+/// Get combat, area, death and phase markers of the current session
+pub async fn get_session_timeline() -> Vec<TimelineEvent> {
+    let result = invoke("get_session_timeline", JsValue::NULL).await;
+    from_js(result).unwrap_or_default()
+}
+
+/// Get per-subsystem memory estimates
+pub async fn get_memory_stats() -> Option<MemoryStats> {
+    let result = invoke("get_memory_stats", JsValue::NULL).await;
+    from_js(result)
+}
+
+/// Get overlay update channel counters (sent, dropped, coalesced)
+pub async fn get_overlay_update_stats() -> Option<OverlayUpdateCounts> {
+    let result = invoke("get_overlay_update_stats", JsValue::NULL).await;
+    from_js(result)
+}
+
+/// Get command counters and the most recent slow commands
+pub async fn get_command_timings() -> Option<CommandTimings> {
+    let result = invoke("get_command_timings", JsValue::NULL).await;
+    from_js(result)
+}
+
+/// Get the most recently fired alerts (newest first)
+pub async fn get_recent_alerts(limit: usize) -> Vec<RecentAlert> {
+    let result = invoke("get_recent_alerts", build_args("limit", &limit)).await;
+    from_js(result).unwrap_or_default()
+}
+
+/// Run the log directory and combat logging setup checks
+pub async fn get_setup_health() -> Option<SetupHealth> {
+    let result = invoke("get_setup_health", JsValue::NULL).await;
+    from_js(result)
+}
+
+/// Get the definition coverage report as it would be sent
+pub async fn get_coverage_report() -> Option<CoverageReport> {
+    let result = invoke("get_coverage_report", JsValue::NULL).await;
+    from_js(result)
+}
+
+/// Check the operations in the log history against the boss definitions
+pub async fn verify_area_coverage() -> Result<AreaCoverageReport, String> {
+    let result = try_invoke("verify_area_coverage", JsValue::NULL).await?;
+    from_js(result).ok_or_else(|| "Failed to parse area coverage report".to_string())
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Unified Encounter Item Commands (NEW - replaces type-specific commands)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -463,6 +624,52 @@ pub async fn create_area(area: &NewAreaRequest) -> Result<String, String> {
     from_js(result).ok_or_else(|| "Failed to parse area response".to_string())
 }
 
+use crate::types::StarParseImportSummary;
+
+/// Open a file picker for a StarParse timer export (XML)
+pub async fn pick_starparse_file() -> Option<String> {
+    let filter = js_sys::Object::new();
+    js_set(&filter, "name", &JsValue::from_str("StarParse timers"));
+    js_set(
+        &filter,
+        "extensions",
+        &js_sys::Array::of1(&JsValue::from_str("xml")).into(),
+    );
+    let options = js_sys::Object::new();
+    js_set(
+        &options,
+        "title",
+        &JsValue::from_str("Import StarParse Timers"),
+    );
+    js_set(&options, "filters", &js_sys::Array::of1(&filter).into());
+
+    let result = open_dialog(options.into()).await;
+    result.as_string()
+}
+
+/// Import timers from a StarParse export into the encounter definitions
+pub async fn import_starparse_timers(path: &str) -> Result<StarParseImportSummary, String> {
+    let args = build_args("path", path);
+    let result = try_invoke("import_starparse_timers", args).await?;
+    from_js(result).ok_or_else(|| "Failed to parse import response".to_string())
+}
+
+/// Get the user's notes for a boss (shown on the boss notes overlay)
+pub async fn get_boss_notes(boss_id: &str) -> Result<String, String> {
+    let args = build_args("bossId", boss_id);
+    let result = try_invoke("get_boss_notes", args).await?;
+    from_js(result).ok_or_else(|| "Failed to parse notes response".to_string())
+}
+
+/// Save the user's notes for a boss
+pub async fn save_boss_notes(boss_id: &str, text: &str) -> Result<(), String> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "bossId", &JsValue::from_str(boss_id));
+    js_set(&obj, "text", &JsValue::from_str(text));
+    try_invoke("save_boss_notes", obj.into()).await?;
+    Ok(())
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Effect Editor Commands
 // ─────────────────────────────────────────────────────────────────────────────
@@ -543,6 +750,12 @@ pub async fn pick_audio_file() -> Option<String> {
     from_js(result).unwrap_or(None)
 }
 
+/// Text-to-speech voices installed on this system
+pub async fn list_tts_voices() -> Vec<String> {
+    let result = invoke("list_tts_voices", JsValue::NULL).await;
+    from_js(result).unwrap_or_default()
+}
+
 /// Open a folder picker for the log directory, returns the selected path or None.
 /// This is handled on the Rust side to maintain macOS security-scoped access.
 pub async fn pick_log_directory() -> Option<String> {
@@ -566,19 +779,20 @@ pub async fn install_update() -> Result<(), String> {
 
 // Re-export query types from shared types crate
 pub use baras_types::{
-    AbilityBreakdown, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow, DataTab,
-    EffectChartData, EffectWindow, EncounterTimeline, EntityBreakdown, PhaseSegment, PlayerDeath,
-    RaidOverviewRow, TimeRange, TimeSeriesPoint,
+    AbilityBreakdown, AssignmentCheck, BigHit, BreakdownMode, CombatLogFilters, CombatLogFindMatch,
+    CombatLogRow, DataTab, EffectChartData, EffectWindow, EncounterTimeline, EntityBreakdown,
+    ExportFormat, ExportTable, HealerAssignment, PhaseSegment, PlayerAggregate, PlayerDeath,
+    PullComparison, RaidOverviewRow, RaidPlan, SessionAggregate, TimeRange, TimeSeriesPoint,
 };
 
 /// Query ability breakdown for an encounter and data tab.
-/// Pass encounter_idx for historical, or None for live encounter.
+/// Pass encounter_key for historical, or None for live encounter.
 /// entity_types filters by entity type (e.g., ["Player", "Companion"]).
 /// breakdown_mode controls grouping (by ability, target type, target instance).
 /// duration_secs is used for rate calculation (DPS/HPS/etc.).
 pub async fn query_breakdown(
     tab: DataTab,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     entity_name: Option<&str>,
     time_range: Option<&TimeRange>,
     entity_types: Option<&[&str]>,
@@ -588,10 +802,10 @@ pub async fn query_breakdown(
     let obj = js_sys::Object::new();
     let tab_js = serde_wasm_bindgen::to_value(&tab).unwrap_or(JsValue::NULL);
     js_set(&obj, "tab", &tab_js);
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     if let Some(name) = entity_name {
         js_set(&obj, "entityName", &JsValue::from_str(name));
@@ -626,18 +840,20 @@ pub async fn query_breakdown(
 }
 
 /// Query breakdown by entity for a data tab.
+/// breakdown_mode.group_instances merges same-named NPCs into one row.
 pub async fn query_entity_breakdown(
     tab: DataTab,
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     time_range: Option<&TimeRange>,
+    breakdown_mode: Option<&BreakdownMode>,
 ) -> Option<Vec<EntityBreakdown>> {
     let obj = js_sys::Object::new();
     let tab_js = serde_wasm_bindgen::to_value(&tab).unwrap_or(JsValue::NULL);
     js_set(&obj, "tab", &tab_js);
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     if let Some(tr) = time_range {
         let tr_js = serde_wasm_bindgen::to_value(tr).unwrap_or(JsValue::NULL);
@@ -645,21 +861,27 @@ pub async fn query_entity_breakdown(
     } else {
         js_set(&obj, "timeRange", &JsValue::NULL);
     }
+    if let Some(mode) = breakdown_mode {
+        let mode_js = serde_wasm_bindgen::to_value(mode).unwrap_or(JsValue::NULL);
+        js_set(&obj, "breakdownMode", &mode_js);
+    } else {
+        js_set(&obj, "breakdownMode", &JsValue::NULL);
+    }
     let result = invoke("query_entity_breakdown", obj.into()).await;
     from_js(result)
 }
 
 /// Query raid overview - aggregated stats per player.
 pub async fn query_raid_overview(
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     time_range: Option<&TimeRange>,
     duration_secs: Option<f32>,
 ) -> Option<Vec<RaidOverviewRow>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     if let Some(tr) = time_range {
         let tr_js = serde_wasm_bindgen::to_value(tr).unwrap_or(JsValue::NULL);
@@ -678,16 +900,16 @@ pub async fn query_raid_overview(
 
 /// Query DPS over time with specified bucket size.
 pub async fn query_dps_over_time(
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     bucket_ms: i64,
     source_name: Option<&str>,
     time_range: Option<&TimeRange>,
 ) -> Option<Vec<TimeSeriesPoint>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     js_set(&obj, "bucketMs", &JsValue::from_f64(bucket_ms as f64));
     if let Some(name) = source_name {
@@ -706,12 +928,12 @@ pub async fn query_dps_over_time(
 }
 
 /// Query encounter timeline with phase segments.
-pub async fn query_encounter_timeline(encounter_idx: Option<u32>) -> Option<EncounterTimeline> {
+pub async fn query_encounter_timeline(encounter_key: Option<&str>) -> Option<EncounterTimeline> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     let result = invoke("query_encounter_timeline", obj.into()).await;
     from_js(result)
@@ -719,16 +941,16 @@ pub async fn query_encounter_timeline(encounter_idx: Option<u32>) -> Option<Enco
 
 /// Query HPS over time with specified bucket size.
 pub async fn query_hps_over_time(
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     bucket_ms: i64,
     source_name: Option<&str>,
     time_range: Option<&TimeRange>,
 ) -> Option<Vec<TimeSeriesPoint>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     js_set(&obj, "bucketMs", &JsValue::from_f64(bucket_ms as f64));
     if let Some(name) = source_name {
@@ -748,16 +970,16 @@ pub async fn query_hps_over_time(
 
 /// Query DTPS over time with specified bucket size.
 pub async fn query_dtps_over_time(
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     bucket_ms: i64,
     target_name: Option<&str>,
     time_range: Option<&TimeRange>,
 ) -> Option<Vec<TimeSeriesPoint>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     js_set(&obj, "bucketMs", &JsValue::from_f64(bucket_ms as f64));
     if let Some(name) = target_name {
@@ -775,18 +997,49 @@ pub async fn query_dtps_over_time(
     from_js(result)
 }
 
+/// Query hits of at least `min_amount` dealt (or, with `taken`, taken) by an entity.
+pub async fn query_big_hits(
+    encounter_key: Option<&str>,
+    min_amount: f64,
+    entity_name: Option<&str>,
+    taken: bool,
+    time_range: Option<&TimeRange>,
+) -> Option<Vec<BigHit>> {
+    let obj = js_sys::Object::new();
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
+    } else {
+        js_set(&obj, "encounterKey", &JsValue::NULL);
+    }
+    js_set(&obj, "minAmount", &JsValue::from_f64(min_amount));
+    if let Some(name) = entity_name {
+        js_set(&obj, "entityName", &JsValue::from_str(name));
+    } else {
+        js_set(&obj, "entityName", &JsValue::NULL);
+    }
+    js_set(&obj, "taken", &JsValue::from_bool(taken));
+    if let Some(tr) = time_range {
+        let tr_js = serde_wasm_bindgen::to_value(tr).unwrap_or(JsValue::NULL);
+        js_set(&obj, "timeRange", &tr_js);
+    } else {
+        js_set(&obj, "timeRange", &JsValue::NULL);
+    }
+    let result = invoke("query_big_hits", obj.into()).await;
+    from_js(result)
+}
+
 /// Query effect uptime statistics for charts panel.
 pub async fn query_effect_uptime(
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     target_name: Option<&str>,
     time_range: Option<&TimeRange>,
     duration_secs: f32,
 ) -> Option<Vec<EffectChartData>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     if let Some(name) = target_name {
         js_set(&obj, "targetName", &JsValue::from_str(name));
@@ -810,17 +1063,17 @@ pub async fn query_effect_uptime(
 
 /// Query individual time windows for a specific effect.
 pub async fn query_effect_windows(
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     effect_id: i64,
     target_name: Option<&str>,
     time_range: Option<&TimeRange>,
     duration_secs: f32,
 ) -> Option<Vec<EffectWindow>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     js_set(&obj, "effectId", &JsValue::from_f64(effect_id as f64));
     if let Some(name) = target_name {
@@ -845,7 +1098,7 @@ pub async fn query_effect_windows(
 
 /// Query combat log rows with pagination for virtual scrolling.
 pub async fn query_combat_log(
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     offset: u64,
     limit: u64,
     source_filter: Option<&str>,
@@ -855,10 +1108,10 @@ pub async fn query_combat_log(
     event_filters: Option<&CombatLogFilters>,
 ) -> Option<Vec<CombatLogRow>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     js_set(&obj, "offset", &JsValue::from_f64(offset as f64));
     js_set(&obj, "limit", &JsValue::from_f64(limit as f64));
@@ -895,7 +1148,7 @@ pub async fn query_combat_log(
 
 /// Get total count of combat log rows for pagination.
 pub async fn query_combat_log_count(
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     source_filter: Option<&str>,
     target_filter: Option<&str>,
     search_filter: Option<&str>,
@@ -903,10 +1156,10 @@ pub async fn query_combat_log_count(
     event_filters: Option<&CombatLogFilters>,
 ) -> Option<u64> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     if let Some(s) = source_filter {
         js_set(&obj, "sourceFilter", &JsValue::from_str(s));
@@ -941,7 +1194,7 @@ pub async fn query_combat_log_count(
 
 /// Find matching rows in combat log (returns position and row_idx).
 pub async fn query_combat_log_find(
-    encounter_idx: Option<u32>,
+    encounter_key: Option<&str>,
     find_text: &str,
     source_filter: Option<&str>,
     target_filter: Option<&str>,
@@ -949,10 +1202,10 @@ pub async fn query_combat_log_find(
     event_filters: Option<&CombatLogFilters>,
 ) -> Option<Vec<CombatLogFindMatch>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     js_set(&obj, "findText", &JsValue::from_str(find_text));
     if let Some(s) = source_filter {
@@ -982,41 +1235,125 @@ pub async fn query_combat_log_find(
 }
 
 /// Get distinct source names for combat log filter dropdown.
-pub async fn query_source_names(encounter_idx: Option<u32>) -> Option<Vec<String>> {
+pub async fn query_source_names(encounter_key: Option<&str>) -> Option<Vec<String>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     let result = invoke("query_source_names", obj.into()).await;
     from_js(result)
 }
 
 /// Get distinct target names for combat log filter dropdown.
-pub async fn query_target_names(encounter_idx: Option<u32>) -> Option<Vec<String>> {
+pub async fn query_target_names(encounter_key: Option<&str>) -> Option<Vec<String>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     let result = invoke("query_target_names", obj.into()).await;
     from_js(result)
 }
 
 /// Query player deaths in an encounter.
-pub async fn query_player_deaths(encounter_idx: Option<u32>) -> Option<Vec<PlayerDeath>> {
+pub async fn query_player_deaths(encounter_key: Option<&str>) -> Option<Vec<PlayerDeath>> {
     let obj = js_sys::Object::new();
-    if let Some(idx) = encounter_idx {
-        js_set(&obj, "encounterIdx", &JsValue::from_f64(idx as f64));
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
     } else {
-        js_set(&obj, "encounterIdx", &JsValue::NULL);
+        js_set(&obj, "encounterKey", &JsValue::NULL);
     }
     let result = invoke("query_player_deaths", obj.into()).await;
     from_js(result)
 }
 
+/// Compare two pulls of the same boss (A is the baseline).
+pub async fn compare_pulls(encounter_key_a: &str, encounter_key_b: &str) -> Option<PullComparison> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "encounterKeyA", &JsValue::from_str(encounter_key_a));
+    js_set(&obj, "encounterKeyB", &JsValue::from_str(encounter_key_b));
+    let result = invoke("compare_pulls", obj.into()).await;
+    from_js(result)
+}
+
+/// Per-player statistics across several pulls (e.g. every pull of a boss).
+pub async fn query_session_aggregate(encounter_keys: &[String]) -> Option<SessionAggregate> {
+    let result = invoke(
+        "query_session_aggregate",
+        build_args("encounterKeys", encounter_keys),
+    )
+    .await;
+    from_js(result)
+}
+
+/// Load the raid plan (healer assignments per boss)
+pub async fn get_raid_plan() -> Option<RaidPlan> {
+    let result = invoke("get_raid_plan", JsValue::NULL).await;
+    from_js(result)
+}
+
+/// Save the raid plan
+pub async fn save_raid_plan(plan: &RaidPlan) -> Result<(), String> {
+    try_invoke("save_raid_plan", build_args("plan", plan)).await?;
+    Ok(())
+}
+
+/// Check the raid plan's healer assignments for `boss_name` against an encounter
+pub async fn check_healer_assignments(
+    encounter_key: &str,
+    boss_name: &str,
+) -> Option<Vec<AssignmentCheck>> {
+    let obj = js_sys::Object::new();
+    js_set(&obj, "encounterKey", &JsValue::from_str(encounter_key));
+    js_set(&obj, "bossName", &JsValue::from_str(boss_name));
+    let result = invoke("check_healer_assignments", obj.into()).await;
+    from_js(result)
+}
+
+/// Ask for an export destination, returns the chosen path or None if cancelled.
+pub async fn pick_export_path(format: ExportFormat, file_name: &str) -> Option<String> {
+    let obj = js_sys::Object::new();
+    js_set(
+        &obj,
+        "format",
+        &serde_wasm_bindgen::to_value(&format).unwrap_or(JsValue::NULL),
+    );
+    js_set(&obj, "fileName", &JsValue::from_str(file_name));
+    let result = invoke("pick_export_path", obj.into()).await;
+    from_js(result).unwrap_or(None)
+}
+
+/// Write a Data Explorer table to `path`, returning the number of rows written
+pub async fn export_breakdown(
+    encounter_key: Option<&str>,
+    table: &ExportTable,
+    format: ExportFormat,
+    path: &str,
+) -> Result<usize, String> {
+    let obj = js_sys::Object::new();
+    if let Some(key) = encounter_key {
+        js_set(&obj, "encounterKey", &JsValue::from_str(key));
+    } else {
+        js_set(&obj, "encounterKey", &JsValue::NULL);
+    }
+    js_set(
+        &obj,
+        "table",
+        &serde_wasm_bindgen::to_value(table).unwrap_or(JsValue::NULL),
+    );
+    js_set(
+        &obj,
+        "format",
+        &serde_wasm_bindgen::to_value(&format).unwrap_or(JsValue::NULL),
+    );
+    js_set(&obj, "path", &JsValue::from_str(path));
+    let result = try_invoke("export_breakdown", obj.into()).await?;
+    Ok(from_js(result).unwrap_or(0))
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Changelog Commands
 // ─────────────────────────────────────────────────────────────────────────────
@@ -12,7 +12,7 @@ use crate::components::{
     SettingsPanel, ToastFrame, ToastSeverity, use_toast, use_toast_provider,
 };
 use crate::types::{
-    LogFileInfo, MetricType, OverlaySettings, OverlayStatus, OverlayType, SessionInfo, UpdateInfo,
+    AreaCoverageReport, CharacterSettings, CommandTimings, CoverageReport, IgnoredId, LogFileInfo, MemoryStats, MetricType, MonitorsChanged, OverlayRenderer, OverlaySettings, OverlayStatus, OverlayType, OverlayUpdateCounts, PerformancePreset, PerformanceSettings, RaidSlotAssignment, RecentAlert, SESSION_UPDATED_EVENT, SessionInfo, SetupCheckStatus, SetupHealth, UpdateInfo,
 };
 
 static CSS: Asset = asset!("/assets/styles.css");
@@ -46,6 +46,11 @@ pub fn App() -> Element {
     let mut effects_b_enabled = use_signal(|| false);
     let mut cooldowns_enabled = use_signal(|| false);
     let mut dot_tracker_enabled = use_signal(|| false);
+    let mut tank_swap_enabled = use_signal(|| false);
+    let mut raid_cooldowns_enabled = use_signal(|| false);
+    let mut death_recap_enabled = use_signal(|| false);
+    let mut boss_notes_enabled = use_signal(|| false);
+    let mut alert_banner_enabled = use_signal(|| false);
     let mut overlays_visible = use_signal(|| true);
     let mut move_mode = use_signal(|| false);
     let mut rearrange_mode = use_signal(|| false);
@@ -55,6 +60,7 @@ pub fn App() -> Element {
     let mut active_file = use_signal(String::new);
     let mut is_watching = use_signal(|| false);
     let mut is_live_tailing = use_signal(|| true);
+    let mut is_demo = use_signal(|| false);
     let mut session_info = use_signal(|| None::<SessionInfo>);
 
     // File browser state
@@ -71,7 +77,7 @@ pub fn App() -> Element {
     let mut overlay_settings = use_signal(OverlaySettings::default);
     let selected_overlay_tab = use_signal(|| "dps".to_string());
     let mut show_only_bosses = use_signal(|| false);
-    let explorer_selected_encounter = use_signal(|| None::<u32>);
+    let explorer_selected_encounter = use_signal(|| None::<String>);
     let explorer_view_mode = use_signal(ViewMode::default);
     let explorer_combat_log_state = use_signal(CombatLogState::default);
 
@@ -79,6 +85,9 @@ pub fn App() -> Element {
     let mut hotkey_visibility = use_signal(String::new);
     let mut hotkey_move_mode = use_signal(String::new);
     let mut hotkey_rearrange = use_signal(String::new);
+    let mut hotkey_keyboard_mode = use_signal(String::new);
+    let mut hotkey_notes_next = use_signal(String::new);
+    let mut hotkey_notes_prev = use_signal(String::new);
     let mut hotkey_save_status = use_signal(String::new);
 
     // Log management state
@@ -106,53 +115,133 @@ pub fn App() -> Element {
     let mut audio_volume = use_signal(|| 80u8);
     let mut audio_countdown_enabled = use_signal(|| true);
     let mut audio_alerts_enabled = use_signal(|| true);
+    let mut audio_tts_voice = use_signal(String::new);
+    let mut audio_tts_rate = use_signal(|| 100u8);
+    let mut tts_voices = use_signal(Vec::<String>::new);
 
     // Profile state
     let mut profile_names = use_signal(Vec::<String>::new);
     let mut active_profile = use_signal(|| None::<String>);
 
+    // Per-character profile and audio (keyed by character name)
+    let mut active_character = use_signal(|| None::<String>);
+    let mut character_settings = use_signal(HashMap::<String, CharacterSettings>::new);
+
     // Parsely settings
     let mut parsely_username = use_signal(String::new);
     let mut parsely_password = use_signal(String::new);
     let mut parsely_guild = use_signal(String::new);
     let mut parsely_save_status = use_signal(String::new);
 
+    // Definition coverage reporting (opt-in)
+    let mut telemetry_enabled = use_signal(|| false);
+    let mut telemetry_endpoint = use_signal(String::new);
+    let mut live_server_enabled = use_signal(|| false);
+    let mut live_server_port = use_signal(|| 7878u16);
+    let mut performance = use_signal(PerformanceSettings::default);
+    let mut deep_idle_minutes = use_signal(|| 10u32);
+    let mut overlay_renderer = use_signal(OverlayRenderer::default);
+    let mut coverage_preview = use_signal(|| None::<CoverageReport>);
+    let mut area_coverage = use_signal(|| None::<AreaCoverageReport>);
+    let mut area_coverage_running = use_signal(|| false);
+
+    // Log directory setup checks (run on startup and from settings)
+    let mut setup_health = use_signal(|| None::<SetupHealth>);
+
+    // Ability/effect IDs left out of metrics, effect tracking, and queries
+    let mut ignored_ids = use_signal(Vec::<IgnoredId>::new);
+
+    // Diagnostics (loaded on demand)
+    let mut memory_stats = use_signal(|| None::<MemoryStats>);
+    let mut overlay_update_stats = use_signal(|| None::<OverlayUpdateCounts>);
+    let mut command_timings = use_signal(|| None::<CommandTimings>);
+    let mut recent_alerts = use_signal(|| None::<Vec<RecentAlert>>);
+
     // ─────────────────────────────────────────────────────────────────────────
     // Initial Load
     // ─────────────────────────────────────────────────────────────────────────
 
-    use_future(move || async move {
-        if let Some(config) = api::get_config().await {
-            log_directory.set(config.log_directory.clone());
-            overlay_settings.set(config.overlay_settings);
-            if let Some(v) = config.hotkeys.toggle_visibility {
-                hotkey_visibility.set(v);
-            }
-            if let Some(v) = config.hotkeys.toggle_move_mode {
-                hotkey_move_mode.set(v);
-            }
-            if let Some(v) = config.hotkeys.toggle_rearrange_mode {
-                hotkey_rearrange.set(v);
+    // Bumped when the config file is edited outside the app; re-runs the config load
+    let mut config_revision = use_signal(|| 0u32);
+
+    // Installed text-to-speech voices for the audio voice picker
+    use_effect(move || {
+        spawn(async move {
+            tts_voices.set(api::list_tts_voices().await);
+        });
+    });
+
+    use_effect(move || {
+        let _ = config_revision();
+        spawn(async move {
+            if let Some(config) = api::get_config().await {
+                log_directory.set(config.log_directory.clone());
+                // Audio settings (the active character's own, if any)
+                let audio = config.active_audio();
+                audio_enabled.set(audio.enabled);
+                audio_volume.set(audio.volume);
+                audio_countdown_enabled.set(audio.countdown_enabled);
+                audio_alerts_enabled.set(audio.alerts_enabled);
+                audio_tts_voice.set(audio.tts_voice.clone().unwrap_or_default());
+                audio_tts_rate.set(audio.tts_rate);
+                // Per-character settings
+                active_character.set(config.active_character.clone());
+                character_settings.set(config.character_settings.clone());
+                overlay_settings.set(config.overlay_settings);
+                if let Some(v) = config.hotkeys.toggle_visibility {
+                    hotkey_visibility.set(v);
+                }
+                if let Some(v) = config.hotkeys.toggle_move_mode {
+                    hotkey_move_mode.set(v);
+                }
+                if let Some(v) = config.hotkeys.toggle_rearrange_mode {
+                    hotkey_rearrange.set(v);
+                }
+                if let Some(v) = config.hotkeys.toggle_keyboard_mode {
+                    hotkey_keyboard_mode.set(v);
+                }
+                if let Some(v) = config.hotkeys.notes_next_page {
+                    hotkey_notes_next.set(v);
+                }
+                if let Some(v) = config.hotkeys.notes_prev_page {
+                    hotkey_notes_prev.set(v);
+                }
+                profile_names.set(config.profiles.iter().map(|p| p.name.clone()).collect());
+                active_profile.set(config.active_profile_name);
+                auto_delete_empty.set(config.auto_delete_empty_files);
+                auto_delete_old.set(config.auto_delete_old_files);
+                retention_days.set(config.log_retention_days);
+                hide_small_log_files.set(config.hide_small_log_files);
+                minimize_to_tray.set(config.minimize_to_tray);
+                ignored_ids.set(config.ignored_ids);
+                parsely_username.set(config.parsely.username);
+                parsely_password.set(config.parsely.password);
+                parsely_guild.set(config.parsely.guild);
+                telemetry_enabled.set(config.telemetry.enabled);
+                telemetry_endpoint.set(config.telemetry.endpoint);
+                live_server_enabled.set(config.live_server.enabled);
+                live_server_port.set(config.live_server.port);
+                performance.set(config.performance);
+                deep_idle_minutes.set(config.deep_idle_minutes);
+                overlay_renderer.set(config.overlay_renderer);
+                // UI preferences
+                show_only_bosses.set(config.show_only_bosses);
             }
-            profile_names.set(config.profiles.iter().map(|p| p.name.clone()).collect());
-            active_profile.set(config.active_profile_name);
-            auto_delete_empty.set(config.auto_delete_empty_files);
-            auto_delete_old.set(config.auto_delete_old_files);
-            retention_days.set(config.log_retention_days);
-            hide_small_log_files.set(config.hide_small_log_files);
-            minimize_to_tray.set(config.minimize_to_tray);
-            parsely_username.set(config.parsely.username);
-            parsely_password.set(config.parsely.password);
-            parsely_guild.set(config.parsely.guild);
-            // Audio settings
-            audio_enabled.set(config.audio.enabled);
-            audio_volume.set(config.audio.volume);
-            audio_countdown_enabled.set(config.audio.countdown_enabled);
-            audio_alerts_enabled.set(config.audio.alerts_enabled);
-            // UI preferences
-            show_only_bosses.set(config.show_only_bosses);
+        });
+    });
+
+    // IDs are added from the combat log, so refresh the list whenever settings open
+    use_effect(move || {
+        if general_settings_open() {
+            spawn(async move {
+                if let Some(config) = api::get_config().await {
+                    ignored_ids.set(config.ignored_ids);
+                }
+            });
         }
+    });
 
+    use_future(move || async move {
         app_version.set(api::get_app_version().await);
         log_dir_size.set(api::get_log_directory_size().await);
         log_file_count.set(api::get_log_file_count().await);
@@ -183,6 +272,11 @@ pub fn App() -> Element {
                 &mut effects_b_enabled,
                 &mut cooldowns_enabled,
                 &mut dot_tracker_enabled,
+                &mut tank_swap_enabled,
+                &mut raid_cooldowns_enabled,
+                &mut death_recap_enabled,
+                &mut boss_notes_enabled,
+                &mut alert_banner_enabled,
                 &mut overlays_visible,
                 &mut move_mode,
                 &mut rearrange_mode,
@@ -221,12 +315,22 @@ pub fn App() -> Element {
         closure.forget();
     });
 
+    // Listen for config file edits made outside the app
+    use_future(move || async move {
+        let closure = Closure::new(move |_event: JsValue| {
+            let _ = config_revision.try_write().map(|mut w| *w += 1);
+        });
+        api::tauri_listen("settings-changed", &closure).await;
+        closure.forget();
+    });
+
     // Listen for session updates (event-driven from backend signals)
     use_future(move || async move {
         // Initial fetch on mount
         session_info.set(api::get_session_info().await);
         is_watching.set(api::get_watching_status().await);
         is_live_tailing.set(api::is_live_tailing().await);
+        is_demo.set(api::is_demo_mode().await);
 
         // Listen for updates (no more polling!)
         let closure = Closure::new(move |_event: JsValue| {
@@ -235,12 +339,14 @@ pub fn App() -> Element {
                 let info = api::get_session_info().await;
                 let watching = api::get_watching_status().await;
                 let tailing = api::is_live_tailing().await;
+                let demo = api::is_demo_mode().await;
                 let _ = session_info.try_write().map(|mut w| *w = info);
                 let _ = is_watching.try_write().map(|mut w| *w = watching);
                 let _ = is_live_tailing.try_write().map(|mut w| *w = tailing);
+                let _ = is_demo.try_write().map(|mut w| *w = demo);
             });
         });
-        api::tauri_listen("session-updated", &closure).await;
+        api::tauri_listen(SESSION_UPDATED_EVENT, &closure).await;
         closure.forget();
     });
 
@@ -274,6 +380,103 @@ pub fn App() -> Element {
         closure.forget();
     });
 
+    // Report setup problems (missing log directory, logging disabled) on startup
+    let mut setup_health_toast = use_toast();
+    use_future(move || async move {
+        let Some(health) = api::get_setup_health().await else {
+            return;
+        };
+        for check in health.problems() {
+            let severity = match check.status {
+                SetupCheckStatus::Error => ToastSeverity::Critical,
+                _ => ToastSeverity::Normal,
+            };
+            setup_health_toast.show(format!("{}: {}", check.name, check.message), severity);
+        }
+        setup_health.set(Some(health));
+    });
+
+    // Listen for problems the backend recovered from on its own
+    let mut recoverable_warning_toast = use_toast();
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && let Some(msg) = payload.as_string()
+            {
+                recoverable_warning_toast.show(msg, ToastSeverity::Normal);
+            }
+        });
+        api::tauri_listen("recoverable-warning", &closure).await;
+        closure.forget();
+    });
+
+    // Listen for character profiles loaded automatically
+    let mut character_profile_toast = use_toast();
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && let Some(msg) = payload.as_string()
+            {
+                character_profile_toast.show(msg, ToastSeverity::Normal);
+            }
+        });
+        api::tauri_listen("character-profile-loaded", &closure).await;
+        closure.forget();
+    });
+
+    // Listen for starter profiles created for a newly played discipline
+    let mut role_profile_toast = use_toast();
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && let Some(msg) = payload.as_string()
+            {
+                role_profile_toast.show(msg, ToastSeverity::Normal);
+            }
+        });
+        api::tauri_listen("role-profile-offered", &closure).await;
+        closure.forget();
+    });
+
+    // Listen for raid buffs missing around a pull
+    let mut buff_coverage_toast = use_toast();
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && let Some(msg) = payload.as_string()
+            {
+                buff_coverage_toast.show(msg, ToastSeverity::Normal);
+            }
+        });
+        api::tauri_listen("buff-coverage", &closure).await;
+        closure.forget();
+    });
+
+    // Listen for monitor hotplug: prompt to reposition overlays of a removed monitor
+    let mut monitors_changed_toast = use_toast();
+    use_future(move || async move {
+        let closure = Closure::new(move |event: JsValue| {
+            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                && let Ok(change) = serde_wasm_bindgen::from_value::<MonitorsChanged>(payload)
+                && !change.stranded.is_empty()
+            {
+                let count = change.stranded.len();
+                monitors_changed_toast.show(
+                    format!(
+                        "A monitor was disconnected: {} overlay{} moved to the primary monitor. \
+                         Unlock overlays to reposition {}.",
+                        count,
+                        if count == 1 { "" } else { "s" },
+                        if count == 1 { "it" } else { "them" },
+                    ),
+                    ToastSeverity::Critical,
+                );
+            }
+        });
+        api::tauri_listen("monitors-changed", &closure).await;
+        closure.forget();
+    });
+
     // Check for changelog on startup
     use_future(move || async move {
         if let Some(response) = api::get_changelog().await {
@@ -302,6 +505,11 @@ pub fn App() -> Element {
     let effects_b_on = effects_b_enabled();
     let cooldowns_on = cooldowns_enabled();
     let dot_tracker_on = dot_tracker_enabled();
+    let tank_swap_on = tank_swap_enabled();
+    let raid_cooldowns_on = raid_cooldowns_enabled();
+    let death_recap_on = death_recap_enabled();
+    let boss_notes_on = boss_notes_enabled();
+    let alert_banner_on = alert_banner_enabled();
     let any_enabled = enabled_map.values().any(|&v| v)
         || personal_on
         || raid_on
@@ -313,13 +521,19 @@ pub fn App() -> Element {
         || effects_a_on
         || effects_b_on
         || cooldowns_on
-        || dot_tracker_on;
+        || dot_tracker_on
+        || tank_swap_on
+        || raid_cooldowns_on
+        || death_recap_on
+        || boss_notes_on
+        || alert_banner_on;
     let is_visible = overlays_visible();
     let is_move_mode = move_mode();
     let is_rearrange = rearrange_mode();
     let current_dir = log_directory();
     let watching = is_watching();
     let live_tailing = is_live_tailing();
+    let demo = is_demo();
     let current_file = active_file();
 
     // Session state for the session tab
@@ -446,7 +660,7 @@ pub fn App() -> Element {
                                         else { "Loading file...".to_string() }
                                     })
                                 })
-                                .unwrap_or_else(|| "None".to_string());
+                                .unwrap_or_else(|| if demo { "Demo".to_string() } else { "None".to_string() });
                             let date = current_meta.as_ref().map(|f| f.date.clone()).unwrap_or_default();
                             let is_latest = log_files().first().map(|f| f.path == current_file).unwrap_or(false);
                             rsx! {
@@ -503,7 +717,8 @@ pub fn App() -> Element {
                                                     &mut raid_enabled, &mut boss_health_enabled, &mut timers_enabled,
                                                     &mut timers_b_enabled, &mut challenges_enabled, &mut alerts_enabled,
                                                     &mut effects_a_enabled, &mut effects_b_enabled,
-                                                    &mut cooldowns_enabled, &mut dot_tracker_enabled,
+                                                    &mut cooldowns_enabled, &mut dot_tracker_enabled, &mut tank_swap_enabled,
+                                                    &mut raid_cooldowns_enabled, &mut death_recap_enabled, &mut boss_notes_enabled, &mut alert_banner_enabled,
                                                     &mut overlays_visible, &mut move_mode, &mut rearrange_mode);
                                             }
                                         }
@@ -650,6 +865,22 @@ pub fn App() -> Element {
                                         "settings"
                                     }
                                 }
+                                button {
+                                    class: "btn",
+                                    onclick: move |_| {
+                                        let mut toast = use_toast();
+                                        spawn(async move {
+                                            if let Err(err) = api::load_demo().await {
+                                                toast.show(format!("Failed to load demo: {}", err), ToastSeverity::Normal);
+                                            } else {
+                                                is_live_tailing.set(false);
+                                                is_demo.set(true);
+                                            }
+                                        });
+                                    },
+                                    i { class: "fa-solid fa-flask" }
+                                    " Explore a Demo Encounter"
+                                }
                             }
                         } else if watching {
                             // Live: Log file detected but no character data yet
@@ -681,6 +912,11 @@ pub fn App() -> Element {
                                         i { class: "fa-solid fa-circle-play" }
                                         " Live Session"
                                     }
+                                } else if demo {
+                                    h3 { class: "session-header historical",
+                                        i { class: "fa-solid fa-flask" }
+                                        " Demo Session"
+                                    }
                                 } else {
                                     h3 { class: "session-header historical",
                                         i { class: "fa-solid fa-circle-pause" }
@@ -904,7 +1140,8 @@ pub fn App() -> Element {
                                                                 &mut raid_enabled, &mut boss_health_enabled, &mut timers_enabled,
                                                                 &mut timers_b_enabled, &mut challenges_enabled, &mut alerts_enabled,
                                                                 &mut effects_a_enabled, &mut effects_b_enabled,
-                                                                &mut cooldowns_enabled, &mut dot_tracker_enabled,
+                                                                &mut cooldowns_enabled, &mut dot_tracker_enabled, &mut tank_swap_enabled,
+                                                                &mut raid_cooldowns_enabled, &mut death_recap_enabled, &mut boss_notes_enabled, &mut alert_banner_enabled,
                                                                 &mut overlays_visible, &mut move_mode, &mut rearrange_mode);
                                                         }
                                                     }
@@ -983,6 +1220,9 @@ pub fn App() -> Element {
                                 span { " Clear Frames" }
                             }
                         }
+                        if is_rearrange {
+                            RaidSlotList {}
+                        }
 
                         // General overlays
                         h4 { class: "subsection-title", "General" }
@@ -1018,6 +1258,16 @@ pub fn App() -> Element {
                                 }); },
                                 "Alerts"
                             }
+                            button {
+                                class: if alert_banner_on { "btn btn-overlay btn-active" } else { "btn btn-overlay" },
+                                title: "Flashes high priority timer alerts in large text, e.g. in the middle of the screen",
+                                onclick: move |_| { spawn(async move {
+                                    if api::toggle_overlay(OverlayType::AlertBanner, alert_banner_on).await {
+                                        alert_banner_enabled.set(!alert_banner_on);
+                                    }
+                                }); },
+                                "Alert Banner"
+                            }
                         }
 
                         // Encounter overlays
@@ -1063,6 +1313,46 @@ pub fn App() -> Element {
                                 }); },
                                 "Timers B"
                             }
+                            button {
+                                class: if tank_swap_on { "btn btn-overlay btn-active" } else { "btn btn-overlay" },
+                                title: "Shows tank-swap debuff stacks on both tanks",
+                                onclick: move |_| { spawn(async move {
+                                    if api::toggle_overlay(OverlayType::TankSwap, tank_swap_on).await {
+                                        tank_swap_enabled.set(!tank_swap_on);
+                                    }
+                                }); },
+                                "Tank Swap"
+                            }
+                            button {
+                                class: if raid_cooldowns_on { "btn btn-overlay btn-active" } else { "btn btn-overlay" },
+                                title: "Shows which defensive and raid utility cooldowns each player has ready",
+                                onclick: move |_| { spawn(async move {
+                                    if api::toggle_overlay(OverlayType::RaidCooldowns, raid_cooldowns_on).await {
+                                        raid_cooldowns_enabled.set(!raid_cooldowns_on);
+                                    }
+                                }); },
+                                "Raid Cooldowns"
+                            }
+                            button {
+                                class: if death_recap_on { "btn btn-overlay btn-active" } else { "btn btn-overlay" },
+                                title: "Lists the damage and healing a player took in the 10 seconds before dying",
+                                onclick: move |_| { spawn(async move {
+                                    if api::toggle_overlay(OverlayType::DeathRecap, death_recap_on).await {
+                                        death_recap_enabled.set(!death_recap_on);
+                                    }
+                                }); },
+                                "Death Recap"
+                            }
+                            button {
+                                class: if boss_notes_on { "btn btn-overlay btn-active" } else { "btn btn-overlay" },
+                                title: "Shows your notes for a boss when the fight starts (Settings > Boss Notes)",
+                                onclick: move |_| { spawn(async move {
+                                    if api::toggle_overlay(OverlayType::BossNotes, boss_notes_on).await {
+                                        boss_notes_enabled.set(!boss_notes_on);
+                                    }
+                                }); },
+                                "Boss Notes"
+                            }
                         }
 
                         // Effects overlays
@@ -1269,6 +1559,51 @@ pub fn App() -> Element {
                                         span { "Watching for new log files" }
                                     }
                                 }
+                                if let Some(health) = setup_health() {
+                                    for check in health.checks.iter() {
+                                        {
+                                            let icon = match check.status {
+                                                SetupCheckStatus::Ok => "fa-solid fa-circle-check",
+                                                SetupCheckStatus::Warning => "fa-solid fa-triangle-exclamation",
+                                                SetupCheckStatus::Error => "fa-solid fa-circle-xmark",
+                                            };
+                                            rsx! {
+                                                p { class: "hint hint-subtle",
+                                                    i { class: "{icon}" }
+                                                    " {check.name}: {check.message}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                div { class: "settings-footer",
+                                    button {
+                                        class: "btn",
+                                        onclick: move |_| {
+                                            spawn(async move {
+                                                setup_health.set(api::get_setup_health().await);
+                                            });
+                                        },
+                                        "Check Setup"
+                                    }
+                                    button {
+                                        class: "btn",
+                                        title: "Open a bundled sample raid pull to explore history, the Data Explorer and overlays",
+                                        onclick: move |_| {
+                                            let mut toast = use_toast();
+                                            spawn(async move {
+                                                if let Err(err) = api::load_demo().await {
+                                                    toast.show(format!("Failed to load demo: {}", err), ToastSeverity::Normal);
+                                                } else {
+                                                    is_live_tailing.set(false);
+                                                    is_demo.set(true);
+                                                    general_settings_open.set(false);
+                                                }
+                                            });
+                                        },
+                                        "Load Demo"
+                                    }
+                                }
                             }
 
                             div { class: "settings-section",
@@ -1398,6 +1733,34 @@ pub fn App() -> Element {
                                 p { class: "hint", "When enabled, closing the window hides to system tray instead of quitting." }
                             }
 
+                            div { class: "settings-section",
+                                h4 { "Ignored Abilities & Effects" }
+                                p { class: "hint", "Left out of metrics, effect tracking, and Data Explorer queries. Add IDs from the combat log with Show IDs enabled. Past encounters are recounted when their log is reopened." }
+                                if ignored_ids().is_empty() {
+                                    p { class: "hint hint-subtle", "Nothing ignored." }
+                                }
+                                for entry in ignored_ids().into_iter() {
+                                    div { class: "setting-row", key: "{entry.id}",
+                                        label { "{entry.name} [{entry.id}]" }
+                                        button {
+                                            class: "btn btn-small btn-delete",
+                                            onclick: move |_| {
+                                                let id = entry.id;
+                                                let mut toast = use_toast();
+                                                spawn(async move {
+                                                    if let Err(err) = api::remove_ignored_id(id).await {
+                                                        toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                        return;
+                                                    }
+                                                    ignored_ids.with_mut(|ids| ids.retain(|e| e.id != id));
+                                                });
+                                            },
+                                            "Remove"
+                                        }
+                                    }
+                                }
+                            }
+
                             div { class: "settings-section",
                                 h4 { "Global Hotkeys" }
                                 p { class: "hint", "Click to capture a key combination. Backspace to clear." }
@@ -1431,18 +1794,43 @@ pub fn App() -> Element {
                                             on_change: move |v| hotkey_rearrange.set(v),
                                         }
                                     }
+                                    div { class: "setting-row",
+                                        label { "Keyboard Mode" }
+                                        HotkeyInput {
+                                            value: hotkey_keyboard_mode(),
+                                            on_change: move |v| hotkey_keyboard_mode.set(v),
+                                        }
+                                    }
+                                    div { class: "setting-row",
+                                        label { "Notes Next Page" }
+                                        HotkeyInput {
+                                            value: hotkey_notes_next(),
+                                            on_change: move |v| hotkey_notes_next.set(v),
+                                        }
+                                    }
+                                    div { class: "setting-row",
+                                        label { "Notes Prev Page" }
+                                        HotkeyInput {
+                                            value: hotkey_notes_prev(),
+                                            on_change: move |v| hotkey_notes_prev.set(v),
+                                        }
+                                    }
                                 }
                                 div { class: "settings-footer",
                                     button {
                                         class: "btn btn-save",
                                         onclick: move |_| {
-                                            let v = hotkey_visibility(); let m = hotkey_move_mode(); let r = hotkey_rearrange();
+                                            let v = hotkey_visibility(); let m = hotkey_move_mode(); let r = hotkey_rearrange(); let k = hotkey_keyboard_mode();
+                                            let nn = hotkey_notes_next(); let np = hotkey_notes_prev();
                                             let mut toast = use_toast();
                                             spawn(async move {
                                                 if let Some(mut cfg) = api::get_config().await {
                                                     cfg.hotkeys.toggle_visibility = if v.is_empty() { None } else { Some(v) };
                                                     cfg.hotkeys.toggle_move_mode = if m.is_empty() { None } else { Some(m) };
                                                     cfg.hotkeys.toggle_rearrange_mode = if r.is_empty() { None } else { Some(r) };
+                                                    cfg.hotkeys.toggle_keyboard_mode = if k.is_empty() { None } else { Some(k) };
+                                                    cfg.hotkeys.notes_next_page = if nn.is_empty() { None } else { Some(nn) };
+                                                    cfg.hotkeys.notes_prev_page = if np.is_empty() { None } else { Some(np) };
                                                     if let Err(err) = api::update_config(&cfg).await {
                                                         toast.show(format!("Failed to save hotkeys: {}", err), ToastSeverity::Normal);
                                                     } else {
@@ -1472,7 +1860,7 @@ pub fn App() -> Element {
                                             let mut toast = use_toast();
                                             spawn(async move {
                                                 if let Some(mut cfg) = api::get_config().await {
-                                                    cfg.audio.enabled = checked;
+                                                    cfg.active_audio_mut().enabled = checked;
                                                     if let Err(err) = api::update_config(&cfg).await {
                                                         toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
                                                     }
@@ -1496,7 +1884,7 @@ pub fn App() -> Element {
                                                 let mut toast = use_toast();
                                                 spawn(async move {
                                                     if let Some(mut cfg) = api::get_config().await {
-                                                        cfg.audio.volume = val;
+                                                        cfg.active_audio_mut().volume = val;
                                                         if let Err(err) = api::update_config(&cfg).await {
                                                             toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
                                                         }
@@ -1520,7 +1908,7 @@ pub fn App() -> Element {
                                             let mut toast = use_toast();
                                             spawn(async move {
                                                 if let Some(mut cfg) = api::get_config().await {
-                                                    cfg.audio.countdown_enabled = checked;
+                                                    cfg.active_audio_mut().countdown_enabled = checked;
                                                     if let Err(err) = api::update_config(&cfg).await {
                                                         toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
                                                     }
@@ -1542,7 +1930,7 @@ pub fn App() -> Element {
                                             let mut toast = use_toast();
                                             spawn(async move {
                                                 if let Some(mut cfg) = api::get_config().await {
-                                                    cfg.audio.alerts_enabled = checked;
+                                                    cfg.active_audio_mut().alerts_enabled = checked;
                                                     if let Err(err) = api::update_config(&cfg).await {
                                                         toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
                                                     }
@@ -1552,7 +1940,140 @@ pub fn App() -> Element {
                                     }
                                 }
 
+                                div { class: "setting-row",
+                                    label { "Voice" }
+                                    select {
+                                        value: "{audio_tts_voice()}",
+                                        disabled: !audio_enabled(),
+                                        onchange: move |e| {
+                                            let voice = e.value();
+                                            audio_tts_voice.set(voice.clone());
+                                            let mut toast = use_toast();
+                                            spawn(async move {
+                                                if let Some(mut cfg) = api::get_config().await {
+                                                    cfg.active_audio_mut().tts_voice =
+                                                        if voice.is_empty() { None } else { Some(voice) };
+                                                    if let Err(err) = api::update_config(&cfg).await {
+                                                        toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                    }
+                                                }
+                                            });
+                                        },
+                                        option { value: "", "System default" }
+                                        for voice in tts_voices().iter() {
+                                            option { value: "{voice}", "{voice}" }
+                                        }
+                                        // Keep a saved voice selectable even if it is no longer listed
+                                        if !audio_tts_voice().is_empty() && !tts_voices().contains(&audio_tts_voice()) {
+                                            option { value: "{audio_tts_voice()}", "{audio_tts_voice()} (not found)" }
+                                        }
+                                    }
+                                }
+
+                                div { class: "setting-row",
+                                    label { "Speech Rate" }
+                                    input {
+                                        r#type: "range",
+                                        min: "50",
+                                        max: "200",
+                                        step: "10",
+                                        value: "{audio_tts_rate()}",
+                                        disabled: !audio_enabled(),
+                                        oninput: move |e| {
+                                            if let Ok(val) = e.value().parse::<u8>() {
+                                                audio_tts_rate.set(val);
+                                                let mut toast = use_toast();
+                                                spawn(async move {
+                                                    if let Some(mut cfg) = api::get_config().await {
+                                                        cfg.active_audio_mut().tts_rate = val;
+                                                        if let Err(err) = api::update_config(&cfg).await {
+                                                            toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                        }
+                                    }
+                                    span { class: "value", "{audio_tts_rate()}%" }
+                                }
+
                                 p { class: "hint hint-subtle", "Countdowns speak timer name + seconds (e.g., \"Shield 3... 2... 1...\")" }
+                                p { class: "hint hint-subtle", "Timers with Speak Alert read their alert text aloud. On Linux this needs speech-dispatcher or espeak." }
+                                if let Some(name) = active_character() {
+                                    if character_settings().get(&name).is_some_and(|c| c.audio.is_some()) {
+                                        p { class: "hint hint-subtle", "Editing {name}'s own audio settings." }
+                                    }
+                                }
+                            }
+
+                            div { class: "settings-section",
+                                h4 { "Characters" }
+                                p { class: "hint", "Load an overlay profile automatically when a character's log starts. With own alert audio, the Audio settings above apply to that character only." }
+                                {
+                                    let active = active_character();
+                                    let settings = character_settings();
+                                    let mut names: Vec<String> = settings.keys().cloned().chain(active.clone()).collect();
+                                    names.sort();
+                                    names.dedup();
+                                    rsx! {
+                                        if names.is_empty() {
+                                            p { class: "hint hint-subtle", "No character detected yet. Characters appear once their log is tailed." }
+                                        }
+                                        for name in names.into_iter() {
+                                            {
+                                                let entry = settings.get(&name).cloned().unwrap_or_default();
+                                                let is_active = active.as_deref() == Some(name.as_str());
+                                                let profile_name = name.clone();
+                                                let audio_name = name.clone();
+                                                rsx! {
+                                                    div { class: "setting-row", key: "{name}",
+                                                        label {
+                                                            "{name}"
+                                                            if is_active { " (active)" }
+                                                        }
+                                                        select {
+                                                            value: entry.profile_name.clone().unwrap_or_default(),
+                                                            onchange: move |e| {
+                                                                let value = e.value();
+                                                                let profile = if value.is_empty() { None } else { Some(value) };
+                                                                let name = profile_name.clone();
+                                                                let mut toast = use_toast();
+                                                                spawn(async move {
+                                                                    if let Err(err) = api::set_character_profile(&name, profile.as_deref()).await {
+                                                                        toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                                    }
+                                                                    config_revision += 1;
+                                                                });
+                                                            },
+                                                            option { value: "", "No profile" }
+                                                            for pname in profile_names().iter() {
+                                                                option { value: "{pname}", "{pname}" }
+                                                            }
+                                                        }
+                                                        label {
+                                                            input {
+                                                                r#type: "checkbox",
+                                                                checked: entry.audio.is_some(),
+                                                                onchange: move |e| {
+                                                                    let checked = e.checked();
+                                                                    let name = audio_name.clone();
+                                                                    let mut toast = use_toast();
+                                                                    spawn(async move {
+                                                                        if let Err(err) = api::set_character_audio(&name, checked).await {
+                                                                            toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                                        }
+                                                                        config_revision += 1;
+                                                                    });
+                                                                }
+                                                            }
+                                                            " Own alert audio"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
                             }
 
                             div { class: "settings-section",
@@ -1611,6 +2132,356 @@ pub fn App() -> Element {
                                     span { class: "save-status", "{parsely_save_status}" }
                                 }
                             }
+
+                            div { class: "settings-section",
+                                h4 { "Definition Coverage" }
+                                p { class: "hint", "Share which boss definitions you pulled and which of their timers started, so maintainers know which encounters need definition work. Reports contain definition and timer IDs with counts only: no character names, guilds or log data." }
+                                div { class: "setting-row",
+                                    label { "Share Coverage Reports" }
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: telemetry_enabled(),
+                                        onchange: move |e| {
+                                            let checked = e.checked();
+                                            telemetry_enabled.set(checked);
+                                            let mut toast = use_toast();
+                                            spawn(async move {
+                                                if let Some(mut cfg) = api::get_config().await {
+                                                    cfg.telemetry.enabled = checked;
+                                                    if let Err(err) = api::update_config(&cfg).await {
+                                                        toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                    }
+                                                }
+                                            });
+                                        }
+                                    }
+                                }
+                                div { class: "setting-row",
+                                    label { "Report URL" }
+                                    input {
+                                        r#type: "text",
+                                        placeholder: "Not configured",
+                                        value: telemetry_endpoint,
+                                        oninput: move |e| telemetry_endpoint.set(e.value()),
+                                        onchange: move |_| {
+                                            let endpoint = telemetry_endpoint();
+                                            let mut toast = use_toast();
+                                            spawn(async move {
+                                                if let Some(mut cfg) = api::get_config().await {
+                                                    cfg.telemetry.endpoint = endpoint;
+                                                    if let Err(err) = api::update_config(&cfg).await {
+                                                        toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                    }
+                                                }
+                                            });
+                                        }
+                                    }
+                                }
+                                if let Some(report) = coverage_preview() {
+                                    p { class: "hint hint-subtle", "app_version: {report.app_version}" }
+                                    p { class: "hint hint-subtle", "since: {report.since.clone().unwrap_or_default()}" }
+                                    if report.encounters.is_empty() {
+                                        p { class: "hint hint-subtle", "No boss pulls recorded yet." }
+                                    }
+                                    for encounter in report.encounters.iter() {
+                                        p { class: "hint hint-subtle", "{encounter.definition_id}: {encounter.pulls} pulls" }
+                                        for timer in encounter.timers.iter() {
+                                            p { class: "hint hint-subtle coverage-timer", "{timer.timer_id}: {timer.starts} starts" }
+                                        }
+                                    }
+                                }
+                                div { class: "settings-footer",
+                                    button {
+                                        class: "btn",
+                                        onclick: move |_| {
+                                            spawn(async move {
+                                                coverage_preview.set(api::get_coverage_report().await);
+                                            });
+                                        },
+                                        "Preview Report"
+                                    }
+                                }
+                            }
+
+                            div { class: "settings-section",
+                                h4 { "Stream Overlays" }
+                                p { class: "hint", "Stream combat metrics, timers and boss health as JSON over a local WebSocket, for OBS browser-source overlays. Only programs on this computer can connect; web pages must be local files or served from localhost." }
+                                div { class: "setting-row",
+                                    label { "Enable Live Data Server" }
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: live_server_enabled(),
+                                        onchange: move |e| {
+                                            let checked = e.checked();
+                                            live_server_enabled.set(checked);
+                                            let mut toast = use_toast();
+                                            spawn(async move {
+                                                if let Some(mut cfg) = api::get_config().await {
+                                                    cfg.live_server.enabled = checked;
+                                                    if let Err(err) = api::update_config(&cfg).await {
+                                                        toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                    }
+                                                }
+                                            });
+                                        }
+                                    }
+                                }
+                                div { class: "setting-row",
+                                    label { "Port" }
+                                    input {
+                                        r#type: "number",
+                                        min: "1024",
+                                        max: "65535",
+                                        value: "{live_server_port()}",
+                                        onchange: move |e| {
+                                            if let Ok(port) = e.value().parse::<u16>() {
+                                                let port = port.max(1024);
+                                                live_server_port.set(port);
+                                                let mut toast = use_toast();
+                                                spawn(async move {
+                                                    if let Some(mut cfg) = api::get_config().await {
+                                                        cfg.live_server.port = port;
+                                                        if let Err(err) = api::update_config(&cfg).await {
+                                                            toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                        }
+                                    }
+                                }
+                                p { class: "hint hint-subtle", "ws://127.0.0.1:{live_server_port}" }
+                            }
+
+                            div { class: "settings-section",
+                                h4 { "Performance" }
+                                p { class: "hint", "How often live data refreshes. Use Low on slower machines if overlays cause stutter in game." }
+                                div { class: "setting-row",
+                                    label { "Preset" }
+                                    select {
+                                        value: performance().preset().map(|p| p.label()).unwrap_or("Custom"),
+                                        onchange: move |e| {
+                                            if let Some(preset) = PerformancePreset::ALL
+                                                .into_iter()
+                                                .find(|p| p.label() == e.value())
+                                            {
+                                                performance.set(preset.settings());
+                                                save_performance(preset.settings());
+                                            }
+                                        },
+                                        for preset in PerformancePreset::ALL {
+                                            option { value: preset.label(), "{preset.label()}" }
+                                        }
+                                        if performance().preset().is_none() {
+                                            option { value: "Custom", "Custom" }
+                                        }
+                                    }
+                                }
+                                div { class: "setting-row",
+                                    label { "Metrics Interval (ms)" }
+                                    input {
+                                        r#type: "number",
+                                        min: "50",
+                                        max: "2000",
+                                        value: "{performance().metrics_interval_ms}",
+                                        onchange: move |e| {
+                                            if let Ok(ms) = e.value().parse::<u32>() {
+                                                let mut settings = performance();
+                                                settings.metrics_interval_ms = ms.clamp(50, 2000);
+                                                performance.set(settings);
+                                                save_performance(settings);
+                                            }
+                                        }
+                                    }
+                                }
+                                div { class: "setting-row",
+                                    label { "Effects Interval (ms)" }
+                                    input {
+                                        r#type: "number",
+                                        min: "10",
+                                        max: "1000",
+                                        value: "{performance().effects_interval_ms}",
+                                        onchange: move |e| {
+                                            if let Ok(ms) = e.value().parse::<u32>() {
+                                                let mut settings = performance();
+                                                settings.effects_interval_ms = ms.clamp(10, 1000);
+                                                performance.set(settings);
+                                                save_performance(settings);
+                                            }
+                                        }
+                                    }
+                                }
+                                div { class: "setting-row",
+                                    label { "Overlay FPS" }
+                                    input {
+                                        r#type: "number",
+                                        min: "1",
+                                        max: "60",
+                                        value: "{performance().overlay_fps}",
+                                        onchange: move |e| {
+                                            if let Ok(fps) = e.value().parse::<u32>() {
+                                                let mut settings = performance();
+                                                settings.overlay_fps = fps.clamp(1, 60);
+                                                performance.set(settings);
+                                                save_performance(settings);
+                                            }
+                                        }
+                                    }
+                                }
+                                div { class: "setting-row",
+                                    label { "Deep Idle After (minutes)" }
+                                    input {
+                                        r#type: "number",
+                                        min: "0",
+                                        max: "240",
+                                        value: "{deep_idle_minutes()}",
+                                        onchange: move |e| {
+                                            if let Ok(minutes) = e.value().parse::<u32>() {
+                                                let minutes = minutes.min(240);
+                                                deep_idle_minutes.set(minutes);
+                                                let mut toast = use_toast();
+                                                spawn(async move {
+                                                    if let Some(mut cfg) = api::get_config().await {
+                                                        cfg.deep_idle_minutes = minutes;
+                                                        if let Err(err) = api::update_config(&cfg).await {
+                                                            toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                        }
+                                    }
+                                }
+                                p { class: "hint hint-subtle", "With the window hidden to the tray and no combat for this long, background work pauses until the game writes to the log again. 0 keeps it running." }
+                                div { class: "setting-row",
+                                    label { "Overlay Renderer" }
+                                    select {
+                                        value: overlay_renderer().label(),
+                                        onchange: move |e| {
+                                            if let Some(renderer) = OverlayRenderer::ALL
+                                                .into_iter()
+                                                .find(|r| r.label() == e.value())
+                                            {
+                                                overlay_renderer.set(renderer);
+                                                let mut toast = use_toast();
+                                                spawn(async move {
+                                                    if let Some(mut cfg) = api::get_config().await {
+                                                        cfg.overlay_renderer = renderer;
+                                                        if let Err(err) = api::update_config(&cfg).await {
+                                                            toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                        },
+                                        for renderer in OverlayRenderer::ALL {
+                                            option { value: renderer.label(), "{renderer.label()}" }
+                                        }
+                                    }
+                                }
+                                p { class: "hint hint-subtle", "Automatic currently draws in software. GPU drawing is experimental and falls back to software when no GPU is available. Applies to overlays opened after a change." }
+                            }
+
+                            div { class: "settings-section",
+                                h4 { "Operations Without Definitions" }
+                                p { class: "hint", "Scan your combat logs for operations you run that have no boss definitions yet. Contributing definitions for these helps everyone running them." }
+                                if let Some(report) = area_coverage() {
+                                    {
+                                        let uncovered: Vec<_> = report.uncovered().cloned().collect();
+                                        rsx! {
+                                            p { class: "hint hint-subtle", "{report.areas.len()} operation(s) found in {report.scanned_files} log(s)." }
+                                            if uncovered.is_empty() {
+                                                p { class: "hint hint-subtle", "Every operation you run has definitions." }
+                                            }
+                                            for area in uncovered {
+                                                p { class: "hint hint-subtle",
+                                                    "{area.name} ({area.area_id}): {area.visits} visit(s), {area.difficulties.join(\", \")}, last {area.last_seen}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                div { class: "settings-footer",
+                                    button {
+                                        class: "btn",
+                                        disabled: area_coverage_running(),
+                                        onclick: move |_| {
+                                            area_coverage_running.set(true);
+                                            let mut toast = use_toast();
+                                            spawn(async move {
+                                                match api::verify_area_coverage().await {
+                                                    Ok(report) => area_coverage.set(Some(report)),
+                                                    Err(err) => toast.show(format!("Failed to scan logs: {}", err), ToastSeverity::Normal),
+                                                }
+                                                area_coverage_running.set(false);
+                                            });
+                                        },
+                                        if area_coverage_running() { "Scanning..." } else { "Scan Logs" }
+                                    }
+                                }
+                            }
+
+                            div { class: "settings-section",
+                                h4 { "Diagnostics" }
+                                p { class: "hint", "Estimated memory held by each subsystem. Include these numbers when reporting slowdowns in long sessions." }
+                                if let Some(stats) = memory_stats() {
+                                    {
+                                        let mb = |bytes: usize| bytes as f64 / 1_000_000.0;
+                                        rsx! {
+                                            p { class: "hint hint-subtle", "String interner: {mb(stats.interner_bytes):.1} MB ({stats.interned_strings} strings)" }
+                                            p { class: "hint hint-subtle", "Session cache: {mb(stats.session_cache_bytes):.1} MB" }
+                                            p { class: "hint hint-subtle", "Query context: {mb(stats.query_context_bytes):.1} MB" }
+                                            p { class: "hint hint-subtle", "Encounter buffer: {mb(stats.encounter_buffer_bytes):.1} MB ({stats.encounter_buffer_rows} events)" }
+                                        }
+                                    }
+                                }
+                                if let Some(updates) = overlay_update_stats() {
+                                    p { class: "hint hint-subtle", "Overlay updates: {updates.sent} sent, {updates.dropped} dropped, {updates.coalesced} coalesced" }
+                                }
+                                if let Some(timings) = command_timings() {
+                                    p { class: "hint hint-subtle", "Commands: {timings.total} run, {timings.slow} slower than {timings.threshold_ms} ms" }
+                                    for slow in timings.recent_slow.iter().take(5) {
+                                        p { class: "hint hint-subtle", "{slow.finished_at} {slow.command} took {slow.duration_ms} ms {slow.args}" }
+                                    }
+                                }
+                                div { class: "settings-footer",
+                                    button {
+                                        class: "btn",
+                                        onclick: move |_| {
+                                            spawn(async move {
+                                                memory_stats.set(api::get_memory_stats().await);
+                                                overlay_update_stats.set(api::get_overlay_update_stats().await);
+                                                command_timings.set(api::get_command_timings().await);
+                                            });
+                                        },
+                                        "Measure Memory Usage"
+                                    }
+                                }
+                            }
+
+                            div { class: "settings-section",
+                                h4 { "Recent Alerts" }
+                                p { class: "hint", "Alerts sent to the alerts overlay, newest first. Use this to check whether a timer alert fired." }
+                                if let Some(alerts) = recent_alerts() {
+                                    if alerts.is_empty() {
+                                        p { class: "hint hint-subtle", "No alerts have fired yet." }
+                                    }
+                                    for alert in alerts.iter() {
+                                        p { class: "hint hint-subtle", "{alert.fired_at} {alert.name}: {alert.text}" }
+                                    }
+                                }
+                                div { class: "settings-footer",
+                                    button {
+                                        class: "btn",
+                                        onclick: move |_| {
+                                            spawn(async move {
+                                                recent_alerts.set(Some(api::get_recent_alerts(20).await));
+                                            });
+                                        },
+                                        "Show Recent Alerts"
+                                    }
+                                }
+                            }
                             } // settings-content
                         }
                     }
@@ -1940,10 +2811,104 @@ fn PlayerStatsBar() -> Element {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Raid Slot List
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Raid frame slots in order, with buttons to move players from the keyboard
+#[component]
+fn RaidSlotList() -> Element {
+    let mut slots = use_signal(Vec::<RaidSlotAssignment>::new);
+
+    let reload = move || {
+        spawn(async move {
+            if let Some(list) = api::get_raid_slots().await {
+                slots.set(list);
+            }
+        });
+    };
+
+    // Load on mount
+    use_effect(reload);
+
+    let swap = move |a: u8, b: u8| {
+        let mut toast = use_toast();
+        spawn(async move {
+            match api::swap_raid_slots(a, b).await {
+                Ok(()) => {
+                    if let Some(list) = api::get_raid_slots().await {
+                        slots.set(list);
+                    }
+                }
+                Err(err) => toast.show(
+                    format!("Failed to move raid frame: {}", err),
+                    ToastSeverity::Normal,
+                ),
+            }
+        });
+    };
+
+    let last = slots().len().saturating_sub(1) as u8;
+
+    rsx! {
+        div { class: "raid-slot-list",
+            div { class: "raid-slot-list-header",
+                span { class: "hint", "Drag frames on the overlay, or move them here" }
+                button {
+                    class: "btn btn-small",
+                    title: "Refresh slots",
+                    onclick: move |_| reload(),
+                    i { class: "fa-solid fa-rotate" }
+                }
+            }
+            ol {
+                for (slot, name) in slots().into_iter().map(|e| (e.slot, e.name)) {
+                    li { key: "{slot}", class: "raid-slot-row",
+                        span { class: "raid-slot-number", "{slot + 1}" }
+                        span {
+                            class: if name.is_some() { "raid-slot-name" } else { "raid-slot-name empty" },
+                            {name.clone().unwrap_or_else(|| "Empty".to_string())}
+                        }
+                        button {
+                            class: "btn btn-small",
+                            title: "Move up",
+                            aria_label: "Move slot {slot + 1} up",
+                            disabled: slot == 0,
+                            onclick: move |_| swap(slot, slot.saturating_sub(1)),
+                            i { class: "fa-solid fa-arrow-up" }
+                        }
+                        button {
+                            class: "btn btn-small",
+                            title: "Move down",
+                            aria_label: "Move slot {slot + 1} down",
+                            disabled: slot >= last,
+                            onclick: move |_| swap(slot, slot + 1),
+                            i { class: "fa-solid fa-arrow-down" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Helpers
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Save performance settings; the backend applies them immediately
+fn save_performance(settings: PerformanceSettings) {
+    let mut toast = use_toast();
+    spawn(async move {
+        if let Some(mut cfg) = api::get_config().await {
+            cfg.performance = settings;
+            if let Err(err) = api::update_config(&cfg).await {
+                toast.show(format!("Failed to save settings: {}", err), ToastSeverity::Normal);
+            }
+        }
+    });
+}
+
 #[allow(clippy::too_many_arguments)]
 fn apply_status(
     status: &OverlayStatus,
@@ -1959,6 +2924,11 @@ fn apply_status(
     effects_b_enabled: &mut Signal<bool>,
     cooldowns_enabled: &mut Signal<bool>,
     dot_tracker_enabled: &mut Signal<bool>,
+    tank_swap_enabled: &mut Signal<bool>,
+    raid_cooldowns_enabled: &mut Signal<bool>,
+    death_recap_enabled: &mut Signal<bool>,
+    boss_notes_enabled: &mut Signal<bool>,
+    alert_banner_enabled: &mut Signal<bool>,
     overlays_visible: &mut Signal<bool>,
     move_mode: &mut Signal<bool>,
     rearrange_mode: &mut Signal<bool>,
@@ -1979,6 +2949,11 @@ fn apply_status(
     effects_b_enabled.set(status.effects_b_enabled);
     cooldowns_enabled.set(status.cooldowns_enabled);
     dot_tracker_enabled.set(status.dot_tracker_enabled);
+    tank_swap_enabled.set(status.tank_swap_enabled);
+    raid_cooldowns_enabled.set(status.raid_cooldowns_enabled);
+    death_recap_enabled.set(status.death_recap_enabled);
+    boss_notes_enabled.set(status.boss_notes_enabled);
+    alert_banner_enabled.set(status.alert_banner_enabled);
     overlays_visible.set(status.overlays_visible);
     move_mode.set(status.move_mode);
     rearrange_mode.set(status.rearrange_mode);
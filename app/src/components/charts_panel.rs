@@ -8,7 +8,7 @@ use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local as spawn;
 
-use crate::api::{self, EffectChartData, EffectWindow, TimeRange, TimeSeriesPoint};
+use crate::api::{self, BigHit, EffectChartData, EffectWindow, TimeRange, TimeSeriesPoint};
 use crate::components::ability_icon::AbilityIcon;
 use crate::components::class_icons::get_class_icon;
 use crate::utils::js_set;
@@ -123,6 +123,7 @@ fn build_time_series_option(
     color: &str,
     fill_color: &str,
     effect_windows: &[(i64, EffectWindow, &str)], // (effect_id, window, color)
+    big_hits: &[BigHit],
     y_axis_name: &str,
 ) -> JsValue {
     let obj = js_sys::Object::new();
@@ -335,6 +336,45 @@ fn build_time_series_option(
     js_set(&mark_area, "data", &mark_data);
     js_set(&series, "markArea", &mark_area);
 
+    // Mark points naming the ability behind each big hit, pinned to its burst bucket
+    // Always set markPoint (even if empty) so ECharts clears previous annotations
+    let mark_point = js_sys::Object::new();
+    js_set(&mark_point, "symbol", &JsValue::from_str("triangle"));
+    js_set(&mark_point, "symbolSize", &JsValue::from_f64(8.0));
+    js_set(&mark_point, "symbolRotate", &JsValue::from_f64(180.0));
+    let point_style = js_sys::Object::new();
+    js_set(&point_style, "color", &JsValue::from_str(color));
+    js_set(&mark_point, "itemStyle", &point_style);
+    let point_label = js_sys::Object::new();
+    js_set(&point_label, "show", &JsValue::TRUE);
+    js_set(&point_label, "position", &JsValue::from_str("top"));
+    js_set(&point_label, "formatter", &JsValue::from_str("{b}"));
+    js_set(&point_label, "color", &JsValue::from_str("#e0e0e0"));
+    js_set(&point_label, "fontSize", &JsValue::from_f64(10.0));
+    js_set(&mark_point, "label", &point_label);
+    let point_data = js_sys::Array::new();
+    for hit in big_hits {
+        let bucket_ms = (hit.combat_time_secs.floor() as i64) * bucket_ms;
+        let y = sparse.get(&bucket_ms).copied().unwrap_or(hit.amount);
+        let point = js_sys::Object::new();
+        js_set(
+            &point,
+            "name",
+            &JsValue::from_str(&format!(
+                "{} {}",
+                hit.ability_name,
+                format_amount(hit.amount)
+            )),
+        );
+        let coord = js_sys::Array::new();
+        coord.push(&JsValue::from_f64(hit.combat_time_secs as f64));
+        coord.push(&JsValue::from_f64(y));
+        js_set(&point, "coord", &coord);
+        point_data.push(&point);
+    }
+    js_set(&mark_point, "data", &point_data);
+    js_set(&series, "markPoint", &mark_point);
+
     series_arr.push(&series);
 
     // Series 2: Moving average (thicker line, no fill)
@@ -375,6 +415,17 @@ fn build_time_series_option(
 // Helper Functions
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Compact hit size for chart annotations (e.g. "142.5k")
+fn format_amount(amount: f64) -> String {
+    if amount >= 1_000_000.0 {
+        format!("{:.2}M", amount / 1_000_000.0)
+    } else if amount >= 1_000.0 {
+        format!("{:.1}k", amount / 1_000.0)
+    } else {
+        format!("{:.0}", amount)
+    }
+}
+
 fn format_duration(secs: f32) -> String {
     let total_secs = secs as i32;
     let mins = total_secs / 60;
@@ -392,23 +443,30 @@ fn format_pct(pct: f32) -> String {
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ChartsPanelProps {
-    /// Encounter index (None = live)
-    pub encounter_idx: Option<u32>,
+    /// Encounter key (None = live)
+    pub encounter_key: Option<String>,
     /// Total duration in seconds
     pub duration_secs: f32,
     /// Time range filter
     pub time_range: TimeRange,
 }
 
+/// Default size of hits annotated on the charts
+const DEFAULT_BIG_HIT_THRESHOLD: f64 = 100_000.0;
+
 #[component]
 pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
-    // Mirror time_range prop into a signal for reactivity
+    // Mirror props into signals for reactivity
     let mut time_range_signal = use_signal(|| props.time_range.clone());
+    let mut encounter_key_signal = use_signal(|| props.encounter_key.clone());
 
-    // Update signal when props change (runs on every render with new props)
+    // Update signals when props change (runs on every render with new props)
     if *time_range_signal.read() != props.time_range {
         time_range_signal.set(props.time_range.clone());
     }
+    if *encounter_key_signal.read() != props.encounter_key {
+        encounter_key_signal.set(props.encounter_key.clone());
+    }
 
     // Entity selection (default to none - show aggregated data)
     let mut selected_entity = use_signal(|| None::<String>);
@@ -425,6 +483,11 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
     let mut hps_data = use_signal(Vec::<TimeSeriesPoint>::new);
     let mut dtps_data = use_signal(Vec::<TimeSeriesPoint>::new);
 
+    // Big hits annotated on the DPS (dealt) and DTPS (taken) charts
+    let mut big_hit_threshold = use_signal(|| DEFAULT_BIG_HIT_THRESHOLD);
+    let mut dps_big_hits = use_signal(Vec::<BigHit>::new);
+    let mut dtps_big_hits = use_signal(Vec::<BigHit>::new);
+
     // Effect data
     let mut active_effects = use_signal(Vec::<EffectChartData>::new);
     let mut passive_effects = use_signal(Vec::<EffectChartData>::new);
@@ -450,48 +513,46 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
     ];
 
     // Load entities on mount and auto-select first player (with retry for race conditions)
-    use_effect({
-        let idx = props.encounter_idx;
-        move || {
-            spawn(async move {
-                // Retry up to 3 seconds if data not ready
-                for attempt in 0..10 {
-                    if let Some(data) = api::query_raid_overview(idx, None, None).await {
-                        let players: Vec<_> = data
-                            .into_iter()
-                            .filter(|r| r.entity_type == "Player" || r.entity_type == "Companion")
-                            .collect();
-                        if !players.is_empty() {
-                            // Auto-select first player
-                            if let Some(first) = players.first() {
-                                selected_entity.set(Some(first.name.clone()));
-                            }
-                            // Store class icons lookup
-                            let icons: HashMap<String, String> = players
-                                .iter()
-                                .filter_map(|r| {
-                                    r.class_icon
-                                        .as_ref()
-                                        .map(|icon| (r.name.clone(), icon.clone()))
-                                })
-                                .collect();
-                            class_icons.set(icons);
-                            // Store entity names
-                            entities.set(players.into_iter().map(|r| r.name).collect());
-                            return;
+    use_effect(move || {
+        let key = encounter_key_signal.read().clone();
+        spawn(async move {
+            // Retry up to 3 seconds if data not ready
+            for attempt in 0..10 {
+                if let Some(data) = api::query_raid_overview(key.as_deref(), None, None).await {
+                    let players: Vec<_> = data
+                        .into_iter()
+                        .filter(|r| r.entity_type == "Player" || r.entity_type == "Companion")
+                        .collect();
+                    if !players.is_empty() {
+                        // Auto-select first player
+                        if let Some(first) = players.first() {
+                            selected_entity.set(Some(first.name.clone()));
                         }
-                    }
-                    if attempt < 9 {
-                        gloo_timers::future::TimeoutFuture::new(300).await;
+                        // Store class icons lookup
+                        let icons: HashMap<String, String> = players
+                            .iter()
+                            .filter_map(|r| {
+                                r.class_icon
+                                    .as_ref()
+                                    .map(|icon| (r.name.clone(), icon.clone()))
+                            })
+                            .collect();
+                        class_icons.set(icons);
+                        // Store entity names
+                        entities.set(players.into_iter().map(|r| r.name).collect());
+                        return;
                     }
                 }
-            });
-        }
+                if attempt < 9 {
+                    gloo_timers::future::TimeoutFuture::new(300).await;
+                }
+            }
+        });
     });
 
     // Load time series data when entity or time range changes
     use_effect(move || {
-        let idx = props.encounter_idx;
+        let key = encounter_key_signal.read().clone();
         let tr = time_range_signal.read().clone();
         let entity = selected_entity.read().clone();
 
@@ -506,17 +567,18 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
             };
 
             if let Some(data) =
-                api::query_dps_over_time(idx, bucket_ms, entity.as_deref(), tr_opt).await
+                api::query_dps_over_time(key.as_deref(), bucket_ms, entity.as_deref(), tr_opt).await
             {
                 dps_data.set(data);
             }
             if let Some(data) =
-                api::query_hps_over_time(idx, bucket_ms, entity.as_deref(), tr_opt).await
+                api::query_hps_over_time(key.as_deref(), bucket_ms, entity.as_deref(), tr_opt).await
             {
                 hps_data.set(data);
             }
             if let Some(data) =
-                api::query_dtps_over_time(idx, bucket_ms, entity.as_deref(), tr_opt).await
+                api::query_dtps_over_time(key.as_deref(), bucket_ms, entity.as_deref(), tr_opt)
+                    .await
             {
                 dtps_data.set(data);
             }
@@ -525,9 +587,38 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
         });
     });
 
+    // Load big hits when entity, time range, or threshold changes
+    use_effect(move || {
+        let key = encounter_key_signal.read().clone();
+        let tr = time_range_signal.read().clone();
+        let entity = selected_entity.read().clone();
+        let threshold = *big_hit_threshold.read();
+
+        if threshold <= 0.0 {
+            dps_big_hits.set(Vec::new());
+            dtps_big_hits.set(Vec::new());
+            return;
+        }
+        spawn(async move {
+            let tr_opt = if tr.start == 0.0 && tr.end == 0.0 {
+                None
+            } else {
+                Some(&tr)
+            };
+            let dealt =
+                api::query_big_hits(key.as_deref(), threshold, entity.as_deref(), false, tr_opt)
+                    .await;
+            dps_big_hits.set(dealt.unwrap_or_default());
+            let taken =
+                api::query_big_hits(key.as_deref(), threshold, entity.as_deref(), true, tr_opt)
+                    .await;
+            dtps_big_hits.set(taken.unwrap_or_default());
+        });
+    });
+
     // Load effect uptime data when entity or time range changes
     use_effect(move || {
-        let idx = props.encounter_idx;
+        let key = encounter_key_signal.read().clone();
         let duration = props.duration_secs;
         let tr = time_range_signal.read().clone();
         let entity = selected_entity.read().clone();
@@ -540,7 +631,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
             };
 
             if let Some(data) =
-                api::query_effect_uptime(idx, entity.as_deref(), tr_opt, duration).await
+                api::query_effect_uptime(key.as_deref(), entity.as_deref(), tr_opt, duration).await
             {
                 let (active, passive): (Vec<_>, Vec<_>) =
                     data.into_iter().partition(|e| e.is_active);
@@ -552,7 +643,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
 
     // Load effect windows when selected effects or time range changes
     use_effect(move || {
-        let idx = props.encounter_idx;
+        let key = encounter_key_signal.read().clone();
         let duration = props.duration_secs;
         let tr = time_range_signal.read().clone();
         let effects = selected_effects.read().clone();
@@ -569,9 +660,14 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                 };
                 let mut all_windows = Vec::new();
                 for (eid, color) in effects {
-                    if let Some(windows) =
-                        api::query_effect_windows(idx, eid, entity.as_deref(), tr_opt, duration)
-                            .await
+                    if let Some(windows) = api::query_effect_windows(
+                        key.as_deref(),
+                        eid,
+                        entity.as_deref(),
+                        tr_opt,
+                        duration,
+                    )
+                    .await
                     {
                         for w in windows {
                             all_windows.push((eid, w, color));
@@ -593,6 +689,8 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
         let hps = hps_data.read().clone();
         let dtps = dtps_data.read().clone();
         let windows = effect_windows.read().clone();
+        let dps_hits = dps_big_hits.read().clone();
+        let dtps_hits = dtps_big_hits.read().clone();
 
         // Dispose hidden charts immediately to prevent overlap
         if !show_dps_val {
@@ -619,6 +717,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                     "#e74c3c",
                     "rgba(231, 76, 60, 0.15)",
                     &windows,
+                    &dps_hits,
                     "DPS",
                 );
                 set_chart_option(&chart, &option);
@@ -634,6 +733,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                     "#2ecc71",
                     "rgba(46, 204, 113, 0.15)",
                     &windows,
+                    &[],
                     "HPS",
                 );
                 set_chart_option(&chart, &option);
@@ -649,6 +749,7 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                     "#e67e22",
                     "rgba(230, 126, 34, 0.15)",
                     &windows,
+                    &dtps_hits,
                     "DTPS",
                 );
                 set_chart_option(&chart, &option);
@@ -764,6 +865,26 @@ pub fn ChartsPanel(props: ChartsPanelProps) -> Element {
                         }
                     }
                 }
+                div { class: "sidebar-section",
+                    h4 { "Big Hits" }
+                    div { class: "chart-toggles",
+                        label { title: "Name the ability behind hits at least this large on the DPS and DTPS charts (0 = off)",
+                            input {
+                                r#type: "number",
+                                class: "input-inline",
+                                min: "0",
+                                step: "10",
+                                value: "{*big_hit_threshold.read() / 1000.0}",
+                                onchange: move |e| {
+                                    if let Ok(k) = e.value().parse::<f64>() {
+                                        big_hit_threshold.set(k.max(0.0) * 1000.0);
+                                    }
+                                }
+                            }
+                            span { "k or more" }
+                        }
+                    }
+                }
             }
 
             // Main content area (charts + effects below)
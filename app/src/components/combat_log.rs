@@ -5,8 +5,12 @@
 use dioxus::prelude::*;
 use wasm_bindgen::JsCast;
 
-use crate::api::{self, CombatLogFilters, CombatLogFindMatch, CombatLogRow, TimeRange};
+use crate::api::{
+    self, CombatLogFilters, CombatLogFindMatch, CombatLogRow, ExportTable, TimeRange,
+};
 use crate::components::ability_icon::AbilityIcon;
+use crate::components::table_export::TableExport;
+use crate::components::{ToastSeverity, use_toast};
 
 /// Row height in pixels for virtual scrolling calculations.
 const ROW_HEIGHT: f64 = 24.0;
@@ -46,7 +50,7 @@ const DEFENSE_REFLECTED: i64 = 836045448953649;
 /// Persisted combat log filter/scroll state that survives component unmounts.
 #[derive(Clone, PartialEq)]
 pub struct CombatLogState {
-    pub encounter_idx: Option<u32>,
+    pub encounter_key: Option<String>,
     pub source_filter: Option<String>,
     pub target_filter: Option<String>,
     pub search_text: String,
@@ -61,7 +65,7 @@ pub struct CombatLogState {
 impl Default for CombatLogState {
     fn default() -> Self {
         Self {
-            encounter_idx: None,
+            encounter_key: None,
             source_filter: None,
             target_filter: None,
             search_text: String::new(),
@@ -77,7 +81,7 @@ impl Default for CombatLogState {
 
 #[derive(Props, Clone, PartialEq)]
 pub struct CombatLogProps {
-    pub encounter_idx: u32,
+    pub encounter_key: String,
     pub time_range: TimeRange,
     /// Optional initial search text (e.g., player name from death tracker)
     #[props(default)]
@@ -186,6 +190,40 @@ fn row_class(row: &CombatLogRow, highlighted_row_idx: Option<u64>) -> String {
 }
 
 /// Get CSS class for event type text.
+/// Whether the row applies or removes a buff/debuff (its effect ID is specific to it)
+fn is_effect_row(row: &CombatLogRow) -> bool {
+    matches!(readable_event_type(row), "Effect gained" | "Effect lost")
+}
+
+/// Button adding an ability/effect ID to the global ignore list
+#[component]
+fn IgnoreIdButton(id: i64, name: String, on_ignored: EventHandler<()>) -> Element {
+    rsx! {
+        button {
+            class: "log-ignore-btn",
+            r#type: "button",
+            title: "Ignore {name} in metrics, effect tracking, and queries",
+            onclick: move |e| {
+                e.stop_propagation();
+                let name = name.clone();
+                let mut toast = use_toast();
+                spawn(async move {
+                    match api::add_ignored_id(id, &name).await {
+                        Ok(()) => {
+                            toast.show(format!("Ignoring {} [{}]", name, id), ToastSeverity::Normal);
+                            on_ignored.call(());
+                        }
+                        Err(err) => {
+                            toast.show(format!("Failed to ignore {}: {}", name, err), ToastSeverity::Normal);
+                        }
+                    }
+                });
+            },
+            i { class: "fa-solid fa-eye-slash" }
+        }
+    }
+}
+
 fn event_type_class(row: &CombatLogRow) -> &'static str {
     match row.effect_type_id {
         EFFECT_TYPE_APPLYEFFECT => {
@@ -205,14 +243,14 @@ fn event_type_class(row: &CombatLogRow) -> &'static str {
 pub fn CombatLog(props: CombatLogProps) -> Element {
     // Mirror props into signals for reactivity
     let mut time_range_signal = use_signal(|| props.time_range.clone());
-    let mut encounter_idx_signal = use_signal(|| props.encounter_idx);
+    let mut encounter_key_signal = use_signal(|| props.encounter_key.clone());
 
     // Update signals when props change (runs on every render with new props)
     if *time_range_signal.read() != props.time_range {
         time_range_signal.set(props.time_range.clone());
     }
-    if *encounter_idx_signal.read() != props.encounter_idx {
-        encounter_idx_signal.set(props.encounter_idx);
+    if *encounter_key_signal.read() != props.encounter_key {
+        encounter_key_signal.set(props.encounter_key.clone());
     }
 
     // Determine whether to restore saved state:
@@ -220,7 +258,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
     // - Only restore if encounter matches (filters are encounter-specific)
     let mut state = props.state;
     let should_restore = props.initial_search.is_none()
-        && state.peek().encounter_idx == Some(props.encounter_idx);
+        && state.peek().encounter_key.as_ref() == Some(&props.encounter_key);
 
     // Filter state - restore from saved state or use defaults
     let mut source_filter = use_signal(|| {
@@ -249,6 +287,9 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
     // Show IDs toggle
     let mut show_ids = use_signal(|| false);
 
+    // Bumped after an ID is added to the ignore list so the rows reload
+    let mut ignore_revision = use_signal(|| 0u32);
+
     // Scroll restoration flag - true only on first mount when we have saved scroll
     let mut restoring_scroll = use_signal(|| should_restore && state.peek().scroll_offset > 0.0);
 
@@ -293,12 +334,12 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
 
     // Load source/target names when encounter changes
     use_effect(move || {
-        let idx = *encounter_idx_signal.read();
+        let key = encounter_key_signal.read().clone();
         spawn(async move {
-            if let Some(sources) = api::query_source_names(Some(idx)).await {
+            if let Some(sources) = api::query_source_names(Some(&key)).await {
                 source_names.set(sources);
             }
-            if let Some(targets) = api::query_target_names(Some(idx)).await {
+            if let Some(targets) = api::query_target_names(Some(&key)).await {
                 target_names.set(targets);
             }
         });
@@ -328,7 +369,8 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
 
     // Load data when filters, time range, or encounter change
     use_effect(move || {
-        let idx = *encounter_idx_signal.read();
+        let _ = ignore_revision();
+        let key = encounter_key_signal.read().clone();
         let tr = time_range_signal.read().clone();
         let source = source_filter.read().clone();
         let target = target_filter.read().clone();
@@ -371,7 +413,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
 
             // Get total count
             if let Some(count) = api::query_combat_log_count(
-                Some(idx),
+                Some(&key),
                 source.as_deref(),
                 target.as_deref(),
                 search_opt.as_deref(),
@@ -385,7 +427,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
 
             // Load page at computed offset
             if let Some(data) = api::query_combat_log(
-                Some(idx),
+                Some(&key),
                 load_offset,
                 PAGE_SIZE,
                 source.as_deref(),
@@ -444,7 +486,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
     // Find feature: query backend for all matches when find text changes
     use_effect(move || {
         let find = find_debounce.read().clone();
-        let idx = *encounter_idx_signal.read();
+        let key = encounter_key_signal.read().clone();
         let tr = time_range_signal.read().clone();
         let source = source_filter.read().clone();
         let target = target_filter.read().clone();
@@ -465,7 +507,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
             };
 
             if let Some(matches) = api::query_combat_log_find(
-                Some(idx),
+                Some(&key),
                 &find,
                 source.as_deref(),
                 target.as_deref(),
@@ -500,7 +542,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
     use_drop(move || {
         if let Ok(mut s) = state.try_write() {
             *s = CombatLogState {
-                encounter_idx: Some(*encounter_idx_signal.peek()),
+                encounter_key: Some(encounter_key_signal.peek().clone()),
                 source_filter: source_filter.peek().clone(),
                 target_filter: target_filter.peek().clone(),
                 search_text: search_text.peek().clone(),
@@ -526,7 +568,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
 
     // Load more data when scrolling beyond current buffer
     use_effect(move || {
-        let idx = *encounter_idx_signal.read();
+        let key = encounter_key_signal.read().clone();
         let total = *total_count.read() as usize;
         let scroll = *scroll_top.read();
         let height = *container_height.read();
@@ -560,7 +602,7 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
                 };
 
                 if let Some(data) = api::query_combat_log(
-                    Some(idx),
+                    Some(&key),
                     new_offset,
                     PAGE_SIZE,
                     source.as_deref(),
@@ -600,6 +642,23 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
     let find_match_count = find_matches.read().len();
     let find_idx = *find_current_idx.read();
 
+    // Export the loaded page, or every row matching the filters (streamed)
+    let export_table = |offset: u64, limit: Option<u64>| {
+        let tr = time_range_signal.read().clone();
+        let search = search_debounce.read().clone();
+        ExportTable::CombatLog {
+            offset,
+            limit,
+            source_filter: source_filter.read().clone(),
+            target_filter: target_filter.read().clone(),
+            search_filter: if search.is_empty() { None } else { Some(search) },
+            time_range: if tr.start == 0.0 && tr.end == 0.0 { None } else { Some(tr) },
+            event_filters: build_event_filters(),
+        }
+    };
+    let page_export = export_table(offset as u64, Some(current_rows.len() as u64));
+    let full_export = export_table(0, None);
+
     rsx! {
         div { class: "combat-log-panel",
             // Filter bar - row 1
@@ -737,6 +796,21 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
 
                 // Row count
                 span { class: "log-count", "{total} events" }
+
+                TableExport {
+                    encounter_key: Some(props.encounter_key.clone()),
+                    table: page_export,
+                    file_name: "combat-log-page",
+                    title: "Export the loaded rows",
+                    label: "Page",
+                }
+                TableExport {
+                    encounter_key: Some(props.encounter_key.clone()),
+                    table: full_export,
+                    file_name: "combat-log",
+                    title: "Export every event matching the filters",
+                    label: "All",
+                }
             }
 
             // Filter bar - row 2 (event type checkboxes)
@@ -954,6 +1028,19 @@ pub fn CombatLog(props: CombatLogProps) -> Element {
                                     }
                                     if show_ids_val && row.ability_id != 0 {
                                         span { class: "log-id-suffix", " [{row.ability_id}]" }
+                                        IgnoreIdButton {
+                                            id: row.ability_id,
+                                            name: row.ability_name.clone(),
+                                            on_ignored: move |_| ignore_revision += 1,
+                                        }
+                                    }
+                                    if show_ids_val && is_effect_row(&row) && row.effect_id != row.ability_id {
+                                        span { class: "log-id-suffix", " {row.effect_name} [{row.effect_id}]" }
+                                        IgnoreIdButton {
+                                            id: row.effect_id,
+                                            name: row.effect_name.clone(),
+                                            on_ignored: move |_| ignore_revision += 1,
+                                        }
                                     }
                                 }
                                 div { class: "log-cell log-value", style: "width: {col_value}px; min-width: {col_value}px;",
@@ -0,0 +1,247 @@
+//! Persistent Counter Rules Component
+//!
+//! Edits the cross-session counters shown on the personal overlay (e.g. total
+//! Revan kills) and shows their current counts. Rules are saved straight to the
+//! counter store rather than with the overlay settings.
+
+use dioxus::prelude::*;
+use wasm_bindgen_futures::spawn_local as spawn;
+
+use crate::api;
+use crate::components::{ToastSeverity, use_toast};
+use crate::types::{CounterOutcome, CounterRule, CounterValue};
+
+/// Id for a new counter: the label as a slug, suffixed if already taken
+fn counter_id(label: &str, rules: &[CounterRule]) -> String {
+    let slug: String = label
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    let base = if slug.is_empty() {
+        "counter".to_string()
+    } else {
+        slug
+    };
+    let taken = |id: &str| rules.iter().any(|r| r.id == id);
+    if !taken(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{base}_{n}"))
+        .find(|id| !taken(id))
+        .unwrap_or(base)
+}
+
+/// Trimmed text, with empty meaning "any"
+fn optional(text: String) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn outcome_value(outcome: CounterOutcome) -> &'static str {
+    match outcome {
+        CounterOutcome::Kill => "kill",
+        CounterOutcome::Wipe => "wipe",
+        CounterOutcome::Any => "any",
+    }
+}
+
+#[component]
+pub fn CounterRules() -> Element {
+    let mut rules = use_signal(Vec::<CounterRule>::new);
+    let mut values = use_signal(Vec::<CounterValue>::new);
+    let mut dirty = use_signal(|| false);
+    // Bumped after a save or reset to reload the counts
+    let mut revision = use_signal(|| 0u32);
+
+    use_effect(move || {
+        spawn(async move {
+            rules.set(api::get_counter_rules().await);
+            dirty.set(false);
+        });
+    });
+
+    use_effect(move || {
+        let _ = revision.read();
+        spawn(async move {
+            values.set(api::get_counters().await);
+        });
+    });
+
+    let save = move |_| {
+        let mut updated: Vec<CounterRule> = rules
+            .read()
+            .iter()
+            .filter(|r| !r.label.trim().is_empty())
+            .map(|r| CounterRule {
+                label: r.label.trim().to_string(),
+                boss: r.boss.clone().and_then(optional),
+                difficulty: r.difficulty.clone().and_then(optional),
+                ..r.clone()
+            })
+            .collect();
+        for idx in 0..updated.len() {
+            if updated[idx].id.is_empty() {
+                updated[idx].id = counter_id(&updated[idx].label, &updated);
+            }
+        }
+        let mut toast = use_toast();
+        spawn(async move {
+            match api::save_counter_rules(&updated).await {
+                Ok(()) => {
+                    rules.set(updated);
+                    dirty.set(false);
+                    revision += 1;
+                }
+                Err(err) => {
+                    toast.show(
+                        format!("Failed to save counters: {}", err),
+                        ToastSeverity::Normal,
+                    );
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "counter-rules",
+            p { class: "hint",
+                "Counters keep a running total of boss kills, wipes or pulls across sessions. "
+                "Leave the boss or difficulty empty to count any."
+            }
+            table { class: "overview-table counter-rules-edit",
+                thead {
+                    tr {
+                        th { class: "name-col", "Label" }
+                        th { class: "name-col", "Boss" }
+                        th { class: "name-col", "Difficulty" }
+                        th { "Counts" }
+                        th { class: "num", "Total" }
+                        th {}
+                    }
+                }
+                tbody {
+                    for (idx, rule) in rules.read().iter().cloned().enumerate() {
+                        {
+                            let count = values
+                                .read()
+                                .iter()
+                                .find(|v| !rule.id.is_empty() && v.id == rule.id)
+                                .map(|v| v.count);
+                            let count_text = count.map(|c| c.to_string()).unwrap_or_default();
+                            let boss = rule.boss.clone().unwrap_or_default();
+                            let difficulty = rule.difficulty.clone().unwrap_or_default();
+                            let id = rule.id.clone();
+                            rsx! {
+                                tr { key: "{idx}",
+                                    td {
+                                        input {
+                                            r#type: "text",
+                                            value: "{rule.label}",
+                                            placeholder: "Revan kills",
+                                            oninput: move |e| {
+                                                rules.write()[idx].label = e.value();
+                                                dirty.set(true);
+                                            },
+                                        }
+                                    }
+                                    td {
+                                        input {
+                                            r#type: "text",
+                                            value: "{boss}",
+                                            placeholder: "Any boss",
+                                            oninput: move |e| {
+                                                rules.write()[idx].boss = Some(e.value());
+                                                dirty.set(true);
+                                            },
+                                        }
+                                    }
+                                    td {
+                                        input {
+                                            r#type: "text",
+                                            value: "{difficulty}",
+                                            placeholder: "Any",
+                                            oninput: move |e| {
+                                                rules.write()[idx].difficulty = Some(e.value());
+                                                dirty.set(true);
+                                            },
+                                        }
+                                    }
+                                    td {
+                                        select {
+                                            class: "input-inline",
+                                            onchange: move |e: Event<FormData>| {
+                                                rules.write()[idx].outcome = match e.value().as_str() {
+                                                    "wipe" => CounterOutcome::Wipe,
+                                                    "any" => CounterOutcome::Any,
+                                                    _ => CounterOutcome::Kill,
+                                                };
+                                                dirty.set(true);
+                                            },
+                                            for outcome in CounterOutcome::all() {
+                                                option {
+                                                    value: outcome_value(*outcome),
+                                                    selected: rule.outcome == *outcome,
+                                                    "{outcome.label()}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                    td { class: "num", "{count_text}" }
+                                    td {
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            title: "Reset to zero",
+                                            disabled: count.is_none_or(|c| c == 0),
+                                            onclick: move |_| {
+                                                let id = id.clone();
+                                                let mut toast = use_toast();
+                                                spawn(async move {
+                                                    match api::reset_counter(&id).await {
+                                                        Ok(()) => revision += 1,
+                                                        Err(err) => toast.show(
+                                                            format!("Failed to reset counter: {}", err),
+                                                            ToastSeverity::Normal,
+                                                        ),
+                                                    }
+                                                });
+                                            },
+                                            i { class: "fa-solid fa-rotate-left" }
+                                        }
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            title: "Remove (drops its count on save)",
+                                            onclick: move |_| {
+                                                rules.write().remove(idx);
+                                                dirty.set(true);
+                                            },
+                                            i { class: "fa-solid fa-xmark" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "counter-rules-actions",
+                button {
+                    class: "btn btn-sm",
+                    onclick: move |_| {
+                        rules.write().push(CounterRule::default());
+                        dirty.set(true);
+                    },
+                    "Add Counter"
+                }
+                button {
+                    class: "btn btn-success btn-sm",
+                    disabled: !dirty(),
+                    onclick: save,
+                    "Save Counters"
+                }
+            }
+        }
+    }
+}
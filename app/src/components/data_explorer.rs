@@ -10,7 +10,7 @@ use wasm_bindgen_futures::spawn_local as spawn;
 
 use crate::api::{
     self, AbilityBreakdown, BreakdownMode, DataTab, EncounterTimeline, EntityBreakdown,
-    PlayerDeath, RaidOverviewRow, TimeRange,
+    ExportTable, PlayerDeath, RaidOverviewRow, TimeRange,
 };
 use crate::components::ability_icon::AbilityIcon;
 use crate::components::charts_panel::ChartsPanel;
@@ -18,7 +18,12 @@ use crate::components::class_icons::{get_class_icon, get_role_icon};
 use crate::components::combat_log::{CombatLog, CombatLogState};
 use crate::components::history_panel::EncounterSummary;
 use crate::components::phase_timeline::PhaseTimelineFilter;
+use crate::components::healer_assignments::HealerAssignments;
+use crate::components::pull_aggregate::PullAggregate;
+use crate::components::pull_compare::PullCompare;
+use crate::components::table_export::TableExport;
 use crate::components::{ToastSeverity, use_toast};
+use crate::types::{SESSION_UPDATED_EVENT, SessionEvent};
 use crate::utils::js_set;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -56,6 +61,10 @@ pub enum ViewMode {
     Overview,
     Charts,
     CombatLog,
+    Compare,
+    /// Statistics across every pull of the selected boss
+    Aggregate,
+    Assignments,
     Detailed(DataTab),
 }
 
@@ -85,6 +94,7 @@ struct OverviewTableData {
     total_healing: f64,
     total_hps: f64,
     total_ehps: f64,
+    total_deaths: i64,
 }
 
 impl ViewMode {
@@ -361,13 +371,13 @@ fn group_by_area(
 
 #[derive(Props, Clone, PartialEq)]
 pub struct DataExplorerProps {
-    /// Initial encounter index (None = show selector)
+    /// Initial encounter key (None = show selector)
     #[props(default)]
-    pub encounter_idx: Option<u32>,
+    pub encounter_key: Option<String>,
     /// Shared bosses-only filter signal
     pub show_only_bosses: Signal<bool>,
     /// Persisted selected encounter (survives tab switches)
-    pub selected_encounter: Signal<Option<u32>>,
+    pub selected_encounter: Signal<Option<String>>,
     /// Persisted view mode (survives tab switches)
     pub view_mode: Signal<ViewMode>,
     /// Persisted combat log state (survives tab switches)
@@ -416,7 +426,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
     let mut overview_data = use_signal(Vec::<RaidOverviewRow>::new);
     let mut player_deaths = use_signal(Vec::<PlayerDeath>::new);
     // Track last (encounter, time_range) we fetched overview data for (prevents re-fetch loops)
-    let mut last_overview_fetch = use_signal(|| None::<(Option<u32>, TimeRange)>);
+    let mut last_overview_fetch = use_signal(|| None::<(Option<String>, TimeRange)>);
 
     // Death search text - set when clicking a death to search combat log (source OR target)
     let mut death_search_text = use_signal(|| None::<String>);
@@ -444,6 +454,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
             total_healing: rows.iter().map(|r| r.healing_total).sum(),
             total_hps: rows.iter().map(|r| r.hps).sum(),
             total_ehps: rows.iter().map(|r| r.ehps).sum(),
+            total_deaths: rows.iter().map(|r| r.deaths).sum(),
             rows,
         }
     });
@@ -568,14 +579,12 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
     // Listen for session updates (refresh on combat end, file load)
     use_future(move || async move {
         let closure = Closure::new(move |event: JsValue| {
-            // Extract payload from event object (Tauri events have { payload: "..." } structure)
-            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
-                && let Some(event_type) = payload.as_string()
-                && (event_type.contains("CombatEnded") || event_type.contains("FileLoaded"))
+            if let Some(session_event @ (SessionEvent::CombatEnded | SessionEvent::FileLoaded)) =
+                api::session_event(&event)
             {
-                // Reset selection only on file load (new file invalidates old encounter indices)
+                // Reset selection only on file load (new file invalidates old encounter keys)
                 // Use try_write to handle signal being dropped when component unmounts
-                if event_type.contains("FileLoaded") {
+                if session_event == SessionEvent::FileLoaded {
                     let _ = selected_encounter.try_write().map(|mut w| *w = None);
                 }
                 spawn(async move {
@@ -586,7 +595,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                 });
             }
         });
-        let handle = api::tauri_listen("session-updated", &closure).await;
+        let handle = api::tauri_listen(SESSION_UPDATED_EVENT, &closure).await;
         // Store the unlisten function for cleanup
         if let Ok(func) = handle.dyn_into::<js_sys::Function>() {
             let _ = unlisten_handle.try_write().map(|mut w| *w = Some(func));
@@ -606,7 +615,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
     // Load timeline when encounter changes - prerequisite for all data loading
     // Uses generation counter to discard stale async results on rapid switching
     use_effect(move || {
-        let idx = *selected_encounter.read();
+        let key = selected_encounter.read().clone();
 
         // Dispose charts immediately when encounter changes
         dispose_all_overview_charts();
@@ -629,7 +638,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
         let _ = timeline_state.try_write().map(|mut w| *w = LoadState::Idle);
         let _ = content_state.try_write().map(|mut w| *w = LoadState::Idle);
 
-        let Some(idx) = idx else {
+        let Some(key) = key else {
             return; // No encounter selected
         };
 
@@ -643,7 +652,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                 return; // Stale request, discard
             }
 
-            match api::query_encounter_timeline(Some(idx)).await {
+            match api::query_encounter_timeline(Some(&key)).await {
                 Some(tl) => {
                     // Double-check generation before applying
                     if *load_generation.peek() != generation {
@@ -674,26 +683,26 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
     // Load overview data when timeline is loaded and view_mode/time_range changes
     // Overview data provides class icons for all views + full data for Overview tab
     use_effect(move || {
-        let idx = *selected_encounter.read();
+        let key = selected_encounter.read().clone();
         let mode = *view_mode.read();
         let is_overview = matches!(mode, ViewMode::Overview);
         let tr = time_range();
         let tl_state = timeline_state();
 
         // Only proceed when timeline is loaded
-        if !matches!(tl_state, LoadState::Loaded) || idx.is_none() {
+        if !matches!(tl_state, LoadState::Loaded) || key.is_none() {
             return;
         }
 
         // Check if we've already fetched for this (encounter, time_range) combo
         let last = last_overview_fetch.read().clone();
-        if let Some((last_idx, last_tr)) = last {
-            if last_idx == idx && last_tr == tr {
+        if let Some((last_key, last_tr)) = last {
+            if last_key == key && last_tr == tr {
                 return; // Already fetched for this exact state
             }
             // On non-overview tabs, any loaded data for this encounter is fine (class icons only)
             // But always reload on Overview tab when time_range changes
-            if !is_overview && last_idx == idx {
+            if !is_overview && last_key == key {
                 return;
             }
         }
@@ -722,16 +731,18 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
 
             // Load raid overview - single attempt
             // None typically means no data available (no encounters dir, etc.) - not an error
-            if let Some(data) = api::query_raid_overview(idx, tr_opt.as_ref(), duration).await {
+            if let Some(data) =
+                api::query_raid_overview(key.as_deref(), tr_opt.as_ref(), duration).await
+            {
                 let _ = overview_data.try_write().map(|mut w| *w = data);
                 let _ = last_overview_fetch
                     .try_write()
-                    .map(|mut w| *w = Some((idx, tr)));
+                    .map(|mut w| *w = Some((key.clone(), tr)));
             } else {
                 // No data available - just mark as loaded with empty data
                 let _ = last_overview_fetch
                     .try_write()
-                    .map(|mut w| *w = Some((idx, tr)));
+                    .map(|mut w| *w = Some((key.clone(), tr)));
                 if is_overview {
                     let _ = content_state
                         .try_write()
@@ -742,7 +753,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
 
             // Load player deaths (only needed for Overview tab)
             if is_overview {
-                if let Some(deaths) = api::query_player_deaths(idx).await {
+                if let Some(deaths) = api::query_player_deaths(key.as_deref()).await {
                     let _ = player_deaths.try_write().map(|mut w| *w = deaths);
                 }
                 let _ = content_state
@@ -752,12 +763,24 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
         });
     });
 
+    // Only instance grouping and the reflect filter affect the entity list;
+    // other breakdown toggles must not reload it
+    let entity_mode = use_memo(move || {
+        let mode = breakdown_mode.read();
+        BreakdownMode {
+            group_instances: mode.group_instances,
+            exclude_reflect: mode.exclude_reflect,
+            ..BreakdownMode::ability_only()
+        }
+    });
+
     // Lazy load: Detailed tab data (entities + abilities) for Damage/Healing/etc tabs
     use_effect(move || {
-        let idx = *selected_encounter.read();
+        let key = selected_encounter.read().clone();
         let mode = *view_mode.read();
         let tr = time_range();
         let tl_state = timeline_state();
+        let entity_mode = entity_mode();
 
         // Extract tab if in detailed mode, otherwise exit
         let Some(tab) = mode.tab() else {
@@ -769,7 +792,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
         };
 
         // Only load when timeline is loaded and we have an encounter
-        if !matches!(tl_state, LoadState::Loaded) || idx.is_none() {
+        if !matches!(tl_state, LoadState::Loaded) || key.is_none() {
             return;
         }
 
@@ -786,7 +809,14 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
 
             // Load entity breakdown - single attempt
             // None typically means no data available (no encounters dir, etc.) - show empty state
-            let entity_data = match api::query_entity_breakdown(tab, idx, tr_opt.as_ref()).await {
+            let entity_data = match api::query_entity_breakdown(
+                tab,
+                key.as_deref(),
+                tr_opt.as_ref(),
+                Some(&entity_mode),
+            )
+            .await
+            {
                 Some(data) => data,
                 None => {
                     // No data available - just mark as loaded with empty data
@@ -813,7 +843,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
             let breakdown = *breakdown_mode.read();
             if let Some(data) = api::query_breakdown(
                 tab,
-                idx,
+                key.as_deref(),
                 auto_selected.as_deref(),
                 tr_opt.as_ref(),
                 None, // No entity filter when source is selected
@@ -843,7 +873,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
     use_effect(move || {
         let players_only = *show_players_only.read();
         let breakdown = *breakdown_mode.read();
-        let idx = *selected_encounter.read();
+        let key = selected_encounter.read().clone();
         let view = *view_mode.read();
         let src = selected_source.read().clone();
         let tr = time_range();
@@ -855,7 +885,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
         };
 
         // Skip if no encounter or timeline not loaded
-        if idx.is_none() || !matches!(tl_state, LoadState::Loaded) {
+        if key.is_none() || !matches!(tl_state, LoadState::Loaded) {
             return;
         }
 
@@ -874,7 +904,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
             let duration = timeline.read().as_ref().map(|t| t.duration_secs);
             if let Some(data) = api::query_breakdown(
                 tab,
-                idx,
+                key.as_deref(),
                 src.as_deref(),
                 tr_opt.as_ref(),
                 entity_filter,
@@ -890,7 +920,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
 
     // Filter by source when selected
     let mut on_source_click = move |name: String| {
-        let idx = *selected_encounter.read();
+        let key = selected_encounter.read().clone();
         let mode = *view_mode.read();
         let current = selected_source.read().clone();
         let tr = time_range();
@@ -928,7 +958,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
             let duration = timeline.read().as_ref().map(|t| t.duration_secs);
             if let Some(data) = api::query_breakdown(
                 tab,
-                idx,
+                key.as_deref(),
                 new_source.as_deref(),
                 tr_opt.as_ref(),
                 entity_filter,
@@ -1010,6 +1040,43 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
             .collect::<HashMap<String, String>>()
     });
 
+    // Table exports use the same parameters as the queries behind the tables
+    let export_tables = use_memo(move || {
+        let tr = time_range();
+        let tr_opt = if tr.start == 0.0 && tr.end == 0.0 {
+            None
+        } else {
+            Some(tr)
+        };
+        let full_duration = timeline.read().as_ref().map(|t| t.duration_secs);
+        let overview_duration = tr_opt.map(|r| r.end - r.start).or(full_duration);
+        let mode = *breakdown_mode.read();
+        let src = selected_source.read().clone();
+        let entity_types = (src.is_none() && *show_players_only.read())
+            .then(|| vec!["Player".to_string(), "Companion".to_string()]);
+        let tab = view_mode.read().tab().unwrap_or_default();
+
+        (
+            ExportTable::RaidOverview {
+                time_range: tr_opt,
+                duration_secs: overview_duration,
+            },
+            ExportTable::EntityBreakdown {
+                tab,
+                time_range: tr_opt,
+                breakdown_mode: Some(entity_mode()),
+            },
+            ExportTable::Breakdown {
+                tab,
+                entity_name: src,
+                time_range: tr_opt,
+                entity_types,
+                breakdown_mode: Some(mode),
+                duration_secs: full_duration,
+            },
+        )
+    });
+
     // Group stats for hierarchical display
     #[derive(Clone, Default, PartialEq)]
     struct GroupStats {
@@ -1075,7 +1142,11 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
         let mut groups: BTreeMap<(String, Option<i64>), Vec<AbilityBreakdown>> = BTreeMap::new();
 
         for ability in list {
-            let target = ability.target_name.clone().unwrap_or_default();
+            let mut target = ability.target_name.clone().unwrap_or_default();
+            // Number same-named instances ("Monolith Tentacle #3")
+            if let Some(n) = ability.target_instance.filter(|_| mode.by_target_instance) {
+                target = format!("{target} #{n}");
+            }
             // Use target_log_id for instance grouping (unique per NPC spawn)
             let instance_key = if mode.by_target_instance {
                 ability.target_log_id
@@ -1221,15 +1292,15 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                     if !is_collapsed {
                                         for enc in area_encounters.iter() {
                                             {
-                                                // Use actual encounter_id for parquet file lookup
-                                                let enc_idx = enc.encounter_id as u32;
-                                                let is_selected = *selected_encounter.read() == Some(enc_idx);
+                                                // Encounter key names the parquet file
+                                                let enc_key = enc.encounter_key.clone();
+                                                let is_selected = selected_encounter.read().as_deref() == Some(enc_key.as_str());
                                                 let success_class = if enc.success { "success" } else { "wipe" };
 
                                                 rsx! {
                                                     div {
                                                         class: if is_selected { "sidebar-encounter-item selected" } else { "sidebar-encounter-item" },
-                                                        onclick: move |_| selected_encounter.set(Some(enc_idx)),
+                                                        onclick: move |_| selected_encounter.set(Some(enc_key.clone())),
                                                         div { class: "encounter-main",
                                                             span { class: "encounter-name", "{enc.display_name}" }
                                                             span { class: "result-indicator {success_class}",
@@ -1315,6 +1386,21 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                             onclick: move |_| { death_search_text.set(None); view_mode.set(ViewMode::CombatLog); },
                             "Combat Log"
                         }
+                        button {
+                            class: if matches!(*view_mode.read(), ViewMode::Compare) { "data-tab active" } else { "data-tab" },
+                            onclick: move |_| view_mode.set(ViewMode::Compare),
+                            "Compare"
+                        }
+                        button {
+                            class: if matches!(*view_mode.read(), ViewMode::Aggregate) { "data-tab active" } else { "data-tab" },
+                            onclick: move |_| view_mode.set(ViewMode::Aggregate),
+                            "All Pulls"
+                        }
+                        button {
+                            class: if matches!(*view_mode.read(), ViewMode::Assignments) { "data-tab active" } else { "data-tab" },
+                            onclick: move |_| view_mode.set(ViewMode::Assignments),
+                            "Assignments"
+                        }
                     }
 
                     // Loading/Error state display
@@ -1337,19 +1423,85 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                     // Content area - Overview, Charts, Combat Log, or Detailed view
                     if matches!(*view_mode.read(), ViewMode::CombatLog) {
                         // Combat Log Panel
-                        if let Some(enc_idx) = *selected_encounter.read() {
+                        if let Some(enc_key) = selected_encounter.read().clone() {
                             CombatLog {
-                                encounter_idx: enc_idx,
+                                encounter_key: enc_key,
                                 time_range: time_range(),
                                 initial_search: death_search_text(),
                                 state: props.combat_log_state,
                             }
                         }
+                    } else if matches!(*view_mode.read(), ViewMode::Compare) {
+                        // Pull comparison against another pull of the same boss
+                        if let Some(enc_key) = selected_encounter.read().clone() {
+                            {
+                                let all = encounters.read();
+                                let boss = all
+                                    .iter()
+                                    .find(|e| e.encounter_key == enc_key)
+                                    .map(|e| (e.boss_name.clone(), e.display_name.clone()));
+                                let candidates: Vec<EncounterSummary> = all
+                                    .iter()
+                                    .filter(|e| e.encounter_key != enc_key)
+                                    .filter(|e| match &boss {
+                                        Some((Some(name), _)) => e.boss_name.as_ref() == Some(name),
+                                        Some((None, display)) => &e.display_name == display,
+                                        None => false,
+                                    })
+                                    .cloned()
+                                    .collect();
+                                rsx! {
+                                    PullCompare { encounter_key: enc_key, candidates }
+                                }
+                            }
+                        }
+                    } else if matches!(*view_mode.read(), ViewMode::Aggregate) {
+                        // Statistics across every pull of the same boss
+                        if let Some(enc_key) = selected_encounter.read().clone() {
+                            {
+                                let all = encounters.read();
+                                let boss = all
+                                    .iter()
+                                    .find(|e| e.encounter_key == enc_key)
+                                    .map(|e| (e.boss_name.clone(), e.display_name.clone()));
+                                let pulls: Vec<EncounterSummary> = all
+                                    .iter()
+                                    .filter(|e| match &boss {
+                                        Some((Some(name), _)) => e.boss_name.as_ref() == Some(name),
+                                        Some((None, display)) => &e.display_name == display,
+                                        None => false,
+                                    })
+                                    .cloned()
+                                    .collect();
+                                rsx! {
+                                    PullAggregate { pulls }
+                                }
+                            }
+                        }
+                    } else if matches!(*view_mode.read(), ViewMode::Assignments) {
+                        // Healer assignments from the raid plan, checked against this pull
+                        if let Some(enc_key) = selected_encounter.read().clone() {
+                            {
+                                let boss_name = encounters
+                                    .read()
+                                    .iter()
+                                    .find(|e| e.encounter_key == enc_key)
+                                    .and_then(|e| e.boss_name.clone());
+                                match boss_name {
+                                    Some(boss_name) => rsx! {
+                                        HealerAssignments { encounter_key: enc_key, boss_name }
+                                    },
+                                    None => rsx! {
+                                        p { class: "hint", "Healer assignments are only available for boss encounters" }
+                                    },
+                                }
+                            }
+                        }
                     } else if matches!(*view_mode.read(), ViewMode::Charts) {
                         // Charts Panel
                         if let Some(tl) = timeline.read().as_ref() {
                             ChartsPanel {
-                                encounter_idx: *selected_encounter.read(),
+                                encounter_key: selected_encounter.read().clone(),
                                 duration_secs: tl.duration_secs,
                                 time_range: time_range(),
                             }
@@ -1399,6 +1551,24 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                 }
                             }
 
+                            // Export the overview table or the phase timeline
+                            div { class: "overview-export",
+                                TableExport {
+                                    encounter_key: selected_encounter.read().clone(),
+                                    table: export_tables().0,
+                                    file_name: "raid-overview",
+                                    title: "Export raid overview",
+                                }
+                                if timeline.read().as_ref().is_some_and(|t| !t.phases.is_empty()) {
+                                    TableExport {
+                                        encounter_key: selected_encounter.read().clone(),
+                                        table: ExportTable::Timeline,
+                                        file_name: "phases",
+                                        title: "Export phase timeline",
+                                    }
+                                }
+                            }
+
                             // Overview table - uses memoized data
                             {
                                 let table_data = overview_table_data.read();
@@ -1412,6 +1582,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                                 th { class: "section-header", colspan: "3", "Damage Taken" }
                                                 th { class: "section-header", colspan: "4", "Healing" }
                                                 th { class: "section-header", colspan: "2", "Shielding" }
+                                                th { class: "num", rowspan: "2", "Deaths" }
                                             }
                                             tr { class: "sub-header",
                                                 th {}
@@ -1475,6 +1646,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                                     td { class: "num heal", "{format_number(row.ehps)}" }
                                                     td { class: "num shield", "{format_number(row.shielding_given_total)}" }
                                                     td { class: "num shield", "{format_number(row.sps)}" }
+                                                    td { class: "num", "{row.deaths}" }
                                                 }
                                             }
                                         }
@@ -1494,6 +1666,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                                 td { class: "num heal", "{format_number(table_data.total_ehps)}" }
                                                 td { class: "num shield", "{format_number(table_data.total_shielding)}" }
                                                 td { class: "num shield", "{format_number(table_data.total_sps)}" }
+                                                td { class: "num", "{table_data.total_deaths}" }
                                             }
                                         }
                                     }
@@ -1532,6 +1705,28 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                             "All"
                                         }
                                     }
+                                    // Same-named NPCs are listed per instance unless grouped
+                                    if !*show_players_only.read() {
+                                        label { class: "entity-group-toggle",
+                                            title: "Merge same-named NPCs (e.g. adds) into one row",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: entity_mode().group_instances,
+                                                onchange: move |e| {
+                                                    let mut mode = *breakdown_mode.read();
+                                                    mode.group_instances = e.checked();
+                                                    breakdown_mode.set(mode);
+                                                }
+                                            }
+                                            "Group instances"
+                                        }
+                                    }
+                                    TableExport {
+                                        encounter_key: selected_encounter.read().clone(),
+                                        table: export_tables().1,
+                                        file_name: if current_tab.is_outgoing() { "sources" } else { "targets" },
+                                        title: "Export entity totals",
+                                    }
                                 }
                                 div { class: "entity-list",
                                     // Uses memoized entity_list
@@ -1558,7 +1753,7 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                                                 }
                                                             }
                                                         }
-                                                        "{entity.source_name}"
+                                                        "{entity.display_name()}"
                                                     }
                                                     span { class: "entity-value", "{format_number(entity.total_value)}" }
                                                     span { class: "entity-abilities", "{entity.abilities_used} abilities" }
@@ -1637,9 +1832,31 @@ pub fn DataExplorerPanel(props: DataExplorerProps) -> Element {
                                                         }
                                                     }
                                                 }
+                                                // Reflect filter, damage tabs only (like instance mode)
+                                                if show_instance {
+                                                    label { class: "breakdown-option",
+                                                        title: "Leave out damage reflected back at the attacker",
+                                                        input {
+                                                            r#type: "checkbox",
+                                                            checked: breakdown_mode.read().exclude_reflect,
+                                                            onchange: move |e| {
+                                                                let mut mode = *breakdown_mode.read();
+                                                                mode.exclude_reflect = e.checked();
+                                                                breakdown_mode.set(mode);
+                                                            }
+                                                        }
+                                                        "Exclude reflect"
+                                                    }
+                                                }
                                             }
                                         }
                                     }
+                                    TableExport {
+                                        encounter_key: selected_encounter.read().clone(),
+                                        table: export_tables().2,
+                                        file_name: "abilities",
+                                        title: "Export ability breakdown",
+                                    }
                                 }
                                 // Table with dynamic columns (sortable)
                                 {
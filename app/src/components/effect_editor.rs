@@ -15,7 +15,7 @@ use super::{ToastSeverity, use_toast};
 use crate::api;
 use crate::types::{
     AbilitySelector, AlertTrigger, AudioConfig, DisplayTarget, EffectListItem, EffectSelector,
-    EntityFilter, Trigger,
+    EffectSeverity, EntityFilter, Trigger,
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -166,6 +166,7 @@ fn default_effect(name: String) -> EffectListItem {
         name,
         display_text: None,
         is_user_override: false,
+        extends: None,
         enabled: true,
         trigger: Trigger::EffectApplied {
             effects: vec![],
@@ -179,6 +180,7 @@ fn default_effect(name: String) -> EffectListItem {
         color: Some([80, 200, 80, 255]),
         show_at_secs: 0.0,
         display_target: DisplayTarget::None,
+        severity: EffectSeverity::Low,
         icon_ability_id: None,
         show_icon: true,
         display_source: false,
@@ -775,6 +777,40 @@ fn EffectEditForm(
                                         }
                                     }
                                 }
+
+                                // Severity - only for raid frames
+                                if draft().display_target == DisplayTarget::RaidFrames {
+                                    div { class: "form-row-hz",
+                                        label { class: "flex items-center",
+                                            "Severity"
+                                            span {
+                                                class: "help-icon",
+                                                title: "More severe effects are listed first on raid frames; critical ones are enlarged and flash",
+                                                "?"
+                                            }
+                                        }
+                                        select {
+                                            class: "select-inline",
+                                            value: "{draft().severity.label()}",
+                                            onchange: move |e| {
+                                                let mut d = draft();
+                                                d.severity = match e.value().as_str() {
+                                                    "Medium" => EffectSeverity::Medium,
+                                                    "Critical" => EffectSeverity::Critical,
+                                                    _ => EffectSeverity::Low,
+                                                };
+                                                draft.set(d);
+                                            },
+                                            for severity in EffectSeverity::all() {
+                                                option {
+                                                    value: "{severity.label()}",
+                                                    selected: *severity == draft().severity,
+                                                    "{severity.label()}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
                             }
                         }
 
@@ -1,6 +1,7 @@
 //! Encounter Editor
 //!
-//! Full CRUD for the BossEncounter DSL: timers, phases, counters, challenges, entities.
+//! Full CRUD for the BossEncounter DSL: timers, phases, counters, challenges, entities,
+//! plus per-boss raid notes.
 //! Uses unified BossWithPath type and EncounterItem enum for streamlined data handling.
 
 mod challenges;
@@ -8,6 +9,7 @@ mod conditions;
 mod counters;
 mod entities;
 mod new_forms;
+mod notes;
 mod phases;
 mod tabs;
 mod timers;
@@ -224,6 +226,40 @@ pub fn EncounterEditorPanel() -> Element {
         });
     };
 
+    // Import StarParse timers, then reload the open area to show them
+    let import_starparse = move || {
+        spawn(async move {
+            let Some(path) = api::pick_starparse_file().await else {
+                return;
+            };
+            match api::import_starparse_timers(&path).await {
+                Ok(summary) => {
+                    let mut message = format!(
+                        "Imported {} timers ({} bosses updated",
+                        summary.timers,
+                        summary.merged_bosses.len()
+                    );
+                    if !summary.unmatched_bosses.is_empty() {
+                        message.push_str(&format!(
+                            ", {} unmatched saved to {}",
+                            summary.unmatched_bosses.len(),
+                            summary.file_path.unwrap_or_default()
+                        ));
+                    }
+                    message.push(')');
+                    if !summary.warnings.is_empty() {
+                        message.push_str(&format!(", {} warnings", summary.warnings.len()));
+                    }
+                    status_message.set(Some((message, false)));
+                    if let Some(area) = selected_area() {
+                        load_area_data(area);
+                    }
+                }
+                Err(e) => status_message.set(Some((e, true))),
+            }
+        });
+    };
+
     // Group areas by category (with filtering)
     let grouped_areas = {
         let filter = area_filter().to_lowercase();
@@ -252,10 +288,18 @@ pub fn EncounterEditorPanel() -> Element {
             div { class: "editor-sidebar",
                 div { class: "editor-sidebar-header",
                     span { class: "text-sm text-muted", "Areas" }
-                    button {
-                        class: "btn btn-success btn-sm",
-                        onclick: move |_| show_new_area.set(true),
-                        "+ New"
+                    div { class: "flex items-center gap-xs",
+                        button {
+                            class: "btn btn-sm",
+                            title: "Import timers from a StarParse export",
+                            onclick: move |_| import_starparse(),
+                            "Import"
+                        }
+                        button {
+                            class: "btn btn-success btn-sm",
+                            onclick: move |_| show_new_area.set(true),
+                            "+ New"
+                        }
                     }
                 }
 
@@ -0,0 +1,76 @@
+//! Notes editing tab
+//!
+//! Free-text raid notes for a boss, shown on the boss notes overlay when the
+//! boss is detected. Stored per boss in ~/.config/baras/definitions/notes.
+
+use dioxus::prelude::*;
+
+use crate::api;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Notes Tab
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[component]
+pub fn NotesTab(boss_id: String, on_status: EventHandler<(String, bool)>) -> Element {
+    let mut current_boss = use_signal(|| boss_id.clone());
+    if *current_boss.read() != boss_id {
+        current_boss.set(boss_id.clone());
+    }
+    let mut text = use_signal(String::new);
+    let mut dirty = use_signal(|| false);
+
+    // Load notes whenever the boss changes
+    use_effect(move || {
+        let id = current_boss.read().clone();
+        spawn(async move {
+            match api::get_boss_notes(&id).await {
+                Ok(notes) => {
+                    text.set(notes);
+                    dirty.set(false);
+                }
+                Err(e) => on_status.call((e, true)),
+            }
+        });
+    });
+
+    rsx! {
+        div { class: "notes-tab",
+            div { class: "text-xs text-muted mb-sm",
+                "Shown on the Boss Notes overlay when this boss is detected. "
+                "Lines starting with # are headings, - starts a bullet, and a line with only --- starts a new page."
+            }
+            textarea {
+                class: "notes-editor",
+                rows: "14",
+                placeholder: "# Tanks\n- Swap at 3 stacks\n---\n# Healers\n- ...",
+                value: "{text}",
+                oninput: move |e| {
+                    text.set(e.value());
+                    dirty.set(true);
+                },
+            }
+            div { class: "flex items-center gap-xs mt-sm",
+                button {
+                    class: "btn btn-success btn-sm",
+                    disabled: !dirty(),
+                    onclick: move |_| {
+                        let boss_id = boss_id.clone();
+                        let notes = text();
+                        spawn(async move {
+                            on_status.call(("Saving...".to_string(), false));
+                            match api::save_boss_notes(&boss_id, &notes).await {
+                                Ok(()) => {
+                                    dirty.set(false);
+                                    on_status.call(("Saved".to_string(), false));
+                                }
+                                Err(e) => on_status.call((e, true)),
+                            }
+                        });
+                    },
+                    "Save Notes"
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Tab navigation for boss editing
 //!
-//! Each boss expands to show tabs: [Timers] [Phases] [Counters] [Challenges] [Entities]
+//! Each boss expands to show tabs: [Timers] [Phases] [Counters] [Challenges] [Entities] [Notes]
 //! All encounter data comes from BossWithPath - no additional loading needed.
 
 use dioxus::prelude::*;
@@ -13,6 +13,7 @@ use crate::types::{
 use super::challenges::ChallengesTab;
 use super::counters::CountersTab;
 use super::entities::EntitiesTab;
+use super::notes::NotesTab;
 use super::phases::PhasesTab;
 use super::timers::TimersTab;
 
@@ -24,6 +25,7 @@ pub enum BossTab {
     Counters,
     Challenges,
     Entities,
+    Notes,
 }
 
 impl BossTab {
@@ -34,6 +36,7 @@ impl BossTab {
             Self::Counters => "Counters",
             Self::Challenges => "Challenges",
             Self::Entities => "Entities",
+            Self::Notes => "Notes",
         }
     }
 
@@ -44,6 +47,7 @@ impl BossTab {
             Self::Counters,
             Self::Challenges,
             Self::Entities,
+            Self::Notes,
         ]
     }
 }
@@ -127,6 +131,7 @@ pub fn BossTabs(
                             BossTab::Counters => format!(" ({})", counter_count),
                             BossTab::Challenges => format!(" ({})", challenge_count),
                             BossTab::Entities => format!(" ({})", entity_count),
+                            BossTab::Notes => String::new(),
                         };
 
                         rsx! {
@@ -202,6 +207,12 @@ pub fn BossTabs(
                             on_status: on_status,
                         }
                     },
+                    BossTab::Notes => rsx! {
+                        NotesTab {
+                            boss_id: boss_with_path.boss.id.clone(),
+                            on_status: on_status,
+                        }
+                    },
                 }
             }
         }
@@ -7,7 +7,8 @@ use dioxus::prelude::*;
 
 use crate::api;
 use crate::types::{
-    AudioConfig, BossTimerDefinition, BossWithPath, EncounterItem, TimerDisplayTarget, Trigger,
+    AlertPriority, AudioConfig, BossTimerDefinition, BossWithPath, EncounterItem, GroupSizeRange,
+    TimerDisplayTarget, Trigger,
 };
 use crate::utils::parse_hex_color;
 
@@ -30,6 +31,7 @@ fn default_timer(name: String) -> BossTimerDefinition {
         duration_secs: 30.0,
         is_alert: false,
         alert_text: None,
+        alert_priority: AlertPriority::Normal,
         color: [255, 128, 0, 255], // Orange
         phases: vec![],
         counter_condition: None,
@@ -38,6 +40,7 @@ fn default_timer(name: String) -> BossTimerDefinition {
             "veteran".to_string(),
             "master".to_string(),
         ],
+        group_size: None,
         enabled: true,
         can_be_refreshed: false,
         repeats: 0,
@@ -47,6 +50,7 @@ fn default_timer(name: String) -> BossTimerDefinition {
         show_on_raid_frames: false,
         show_at_secs: 0.0,
         display_target: TimerDisplayTarget::TimersA,
+        suggested_cooldown: None,
         audio: AudioConfig::default(),
     }
 }
@@ -724,6 +728,32 @@ fn TimerEditForm(
                         }
                     }
 
+                    // ─── Suggested Cooldown (only for countdown timers) ──────────
+                    if !draft().is_alert {
+                        div { class: "form-row-hz",
+                            label { "Suggest" }
+                            input {
+                                r#type: "text",
+                                class: "input-inline",
+                                style: "width: 160px;",
+                                placeholder: "e.g. Saber Ward",
+                                value: "{draft().suggested_cooldown.map(|s| s.ability).unwrap_or_default()}",
+                                oninput: move |e| {
+                                    let mut d = draft();
+                                    let ability = e.value();
+                                    // Keep icon and discipline overrides written in TOML
+                                    let mut suggestion = d.suggested_cooldown.take().unwrap_or_default();
+                                    suggestion.ability = ability;
+                                    if !suggestion.ability.is_empty() || !suggestion.disciplines.is_empty() {
+                                        d.suggested_cooldown = Some(suggestion);
+                                    }
+                                    draft.set(d);
+                                }
+                            }
+                            span { class: "text-sm text-secondary", "cooldown shown beside the bar" }
+                        }
+                    }
+
                     // ─── Conditions ──────────────────────────────────────────────
                     span { class: "text-sm font-bold text-secondary", "Conditions" }
 
@@ -740,6 +770,41 @@ fn TimerEditForm(
                         }
                     }
 
+                    div { class: "form-row-hz",
+                        label { "Group Size" }
+                        select {
+                            class: "select",
+                            style: "width: 120px;",
+                            onchange: move |e| {
+                                let mut d = draft();
+                                d.group_size = match e.value().as_str() {
+                                    "8" => Some(GroupSizeRange::exactly(8)),
+                                    "16" => Some(GroupSizeRange::exactly(16)),
+                                    "custom" => d.group_size,
+                                    _ => None,
+                                };
+                                draft.set(d);
+                            },
+                            {
+                                let current = match draft().group_size {
+                                    None => "any",
+                                    Some(r) if r == GroupSizeRange::exactly(8) => "8",
+                                    Some(r) if r == GroupSizeRange::exactly(16) => "16",
+                                    Some(_) => "custom",
+                                };
+                                rsx! {
+                                    option { value: "any", selected: current == "any", "Any" }
+                                    option { value: "8", selected: current == "8", "8 players" }
+                                    option { value: "16", selected: current == "16", "16 players" }
+                                    // Other ranges are written by hand in TOML; keep them as-is
+                                    if current == "custom" {
+                                        option { value: "custom", selected: true, "Custom" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
                     div { class: "form-row-hz",
                         label { "Counter" }
                         CounterConditionEditor {
@@ -771,6 +836,38 @@ fn TimerEditForm(
                                 }
                             }
                         }
+                        div { class: "form-row-hz",
+                            label { "Priority" }
+                            select {
+                                class: "select",
+                                style: "width: 120px;",
+                                title: "High priority alerts also flash in the Alert Banner overlay",
+                                onchange: move |e| {
+                                    let mut d = draft();
+                                    d.alert_priority = match e.value().as_str() {
+                                        "high" => AlertPriority::High,
+                                        _ => AlertPriority::Normal,
+                                    };
+                                    draft.set(d);
+                                },
+                                for priority in AlertPriority::all() {
+                                    {
+                                        let value = match priority {
+                                            AlertPriority::Normal => "normal",
+                                            AlertPriority::High => "high",
+                                        };
+                                        let is_selected = draft().alert_priority == *priority;
+                                        rsx! {
+                                            option {
+                                                value: "{value}",
+                                                selected: is_selected,
+                                                "{priority.label()}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
                     }
 
                     // ─── Audio ────────────────────────────────────────────────────
@@ -831,6 +928,55 @@ fn TimerEditForm(
                         }
                     }
 
+                    div { class: "form-row-hz",
+                        label { "Volume" }
+                        div { class: "flex items-center gap-xs",
+                            input {
+                                r#type: "checkbox",
+                                title: "Play this timer's sound at its own volume instead of the global one",
+                                checked: draft().audio.volume.is_some(),
+                                onchange: move |e| {
+                                    let mut d = draft();
+                                    d.audio.volume = e.checked().then_some(100);
+                                    draft.set(d);
+                                }
+                            }
+                            if let Some(volume) = draft().audio.volume {
+                                input {
+                                    r#type: "range",
+                                    min: "0",
+                                    max: "100",
+                                    style: "width: 100px;",
+                                    value: "{volume}",
+                                    oninput: move |e| {
+                                        if let Ok(val) = e.value().parse::<u8>() {
+                                            let mut d = draft();
+                                            d.audio.volume = Some(val);
+                                            draft.set(d);
+                                        }
+                                    }
+                                }
+                                span { class: "text-sm text-secondary", "{volume}%" }
+                            } else {
+                                span { class: "text-muted", "Global" }
+                            }
+                        }
+                    }
+
+                    div { class: "form-row-hz",
+                        label { "Speak Alert" }
+                        input {
+                            r#type: "checkbox",
+                            title: "Read the alert text aloud instead of playing the sound",
+                            checked: draft().audio.speak_alert,
+                            onchange: move |e| {
+                                let mut d = draft();
+                                d.audio.speak_alert = e.checked();
+                                draft.set(d);
+                            }
+                        }
+                    }
+
                     // Audio timing options (only for countdown timers)
                     if !draft().is_alert {
                         div { class: "form-row-hz",
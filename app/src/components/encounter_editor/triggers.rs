@@ -5,8 +5,8 @@
 use dioxus::prelude::*;
 
 use crate::types::{
-    AbilitySelector, CounterTrigger, EffectSelector, EntityFilter, EntitySelector, PhaseTrigger,
-    TimerTrigger,
+    AbilitySelector, ComparisonOp, CounterTrigger, EffectSelector, EntityFilter, EntitySelector,
+    PhaseTrigger, TimerTrigger,
 };
 
 use super::tabs::EncounterData;
@@ -68,6 +68,12 @@ pub fn EntityFilterDropdown(
     on_change: EventHandler<EntityFilter>,
 ) -> Element {
     let is_selector = matches!(value, EntityFilter::Selector(_));
+    let named_player = match &value {
+        EntityFilter::NamedPlayer(name) => Some(name.clone()),
+        _ => None,
+    };
+    // Not/AllOf/AnyOf are written by hand in TOML; keep them selectable as-is
+    let combinator = value.is_combinator().then(|| value.clone());
     let selectors = if let EntityFilter::Selector(s) = &value {
         s.clone()
     } else {
@@ -87,6 +93,12 @@ pub fn EntityFilterDropdown(
                         let selected = e.value();
                         if selected == "Specific (ID or Name)" {
                             on_change.call(EntityFilter::Selector(vec![]));
+                        } else if selected == "Named Player" {
+                            on_change.call(EntityFilter::NamedPlayer(String::new()));
+                        } else if selected == "Combined" {
+                            if let Some(filter) = &combinator {
+                                on_change.call(filter.clone());
+                            }
                         } else {
                             for opt in options {
                                 if opt.label() == selected {
@@ -108,6 +120,18 @@ pub fn EntityFilterDropdown(
                         selected: is_selector,
                         "Specific (ID or Name)"
                     }
+                    option {
+                        value: "Named Player",
+                        selected: named_player.is_some(),
+                        "Named Player"
+                    }
+                    if value.is_combinator() {
+                        option {
+                            value: "Combined",
+                            selected: true,
+                            "Combined ({value.label()})"
+                        }
+                    }
                 }
             }
             if is_selector {
@@ -117,6 +141,15 @@ pub fn EntityFilterDropdown(
                     on_change: move |sels| on_change.call(EntityFilter::Selector(sels))
                 }
             }
+            if let Some(name) = named_player {
+                input {
+                    class: "input-inline",
+                    style: "width: 160px;",
+                    placeholder: "Character name",
+                    value: "{name}",
+                    onchange: move |e| on_change.call(EntityFilter::NamedPlayer(e.value().trim().to_string()))
+                }
+            }
         }
     }
 }
@@ -231,10 +264,17 @@ fn TriggerNode(
     encounter_data: EncounterData,
     on_change: EventHandler<TimerTrigger>,
     depth: u8,
+    /// Node is an AllOf condition (offers state conditions)
+    #[props(default)]
+    in_all_of: bool,
 ) -> Element {
-    let is_composite = matches!(trigger, TimerTrigger::AnyOf { .. });
+    let is_composite = matches!(
+        trigger,
+        TimerTrigger::AnyOf { .. } | TimerTrigger::AllOf { .. }
+    );
 
     let trigger_for_or = trigger.clone();
+    let trigger_for_and = trigger.clone();
     let indent = format!("padding-left: {}px;", depth as u32 * 12);
 
     rsx! {
@@ -254,6 +294,7 @@ fn TriggerNode(
                     trigger: trigger.clone(),
                     encounter_data: encounter_data,
                     on_change: on_change,
+                    in_all_of: in_all_of,
                 }
             }
 
@@ -269,13 +310,36 @@ fn TriggerNode(
                         },
                         "+ OR"
                     }
+                    button {
+                        class: "btn-compose",
+                        onclick: move |e| {
+                            e.stop_propagation();
+                            on_change.call(TimerTrigger::AllOf {
+                                conditions: vec![trigger_for_and.clone()],
+                                window_secs: 5.0,
+                            });
+                        },
+                        "+ AND"
+                    }
                 }
             }
         }
     }
 }
 
-/// Editor for composite triggers (AnyOf only)
+/// Rebuild a composite trigger with new conditions, keeping its kind
+/// (`window_secs` is `Some` for AllOf)
+fn composite_trigger(conditions: Vec<TimerTrigger>, window_secs: Option<f32>) -> TimerTrigger {
+    match window_secs {
+        Some(window_secs) => TimerTrigger::AllOf {
+            conditions,
+            window_secs,
+        },
+        None => TimerTrigger::AnyOf { conditions },
+    }
+}
+
+/// Editor for composite triggers (AnyOf / AllOf)
 #[component]
 fn CompositeEditor(
     trigger: TimerTrigger,
@@ -283,19 +347,42 @@ fn CompositeEditor(
     on_change: EventHandler<TimerTrigger>,
     depth: u8,
 ) -> Element {
-    let conditions = match &trigger {
-        TimerTrigger::AnyOf { conditions } => conditions.clone(),
+    let (conditions, window_secs) = match &trigger {
+        TimerTrigger::AnyOf { conditions } => (conditions.clone(), None),
+        TimerTrigger::AllOf {
+            conditions,
+            window_secs,
+        } => (conditions.clone(), Some(*window_secs)),
         _ => return rsx! { span { "Invalid composite" } },
     };
 
     let conditions_for_unwrap = conditions.clone();
     let conditions_for_add = conditions.clone();
+    let conditions_for_window = conditions.clone();
     let conditions_len = conditions.len();
 
     rsx! {
         div { class: "composite-trigger",
             div { class: "composite-header",
-                span { class: "composite-label", "ANY OF (OR)" }
+                if let Some(window) = window_secs {
+                    span { class: "composite-label", "ALL OF (AND) within" }
+                    input {
+                        r#type: "number",
+                        step: "0.5",
+                        min: "0",
+                        class: "input-inline",
+                        style: "width: 60px;",
+                        value: "{window}",
+                        oninput: move |e| {
+                            if let Ok(val) = e.value().parse::<f32>() {
+                                on_change.call(composite_trigger(conditions_for_window.clone(), Some(val)));
+                            }
+                        }
+                    }
+                    span { class: "hint", "sec" }
+                } else {
+                    span { class: "composite-label", "ANY OF (OR)" }
+                }
                 if conditions_len == 1 {
                     button {
                         class: "btn-compose",
@@ -314,20 +401,48 @@ fn CompositeEditor(
                     {
                         let conditions_for_update = conditions.clone();
                         let conditions_for_remove = conditions.clone();
-                        let condition_clone = condition.clone();
+                        let conditions_for_negate = conditions.clone();
                         let encounter_data_for_node = encounter_data.clone();
+                        let is_all_of = window_secs.is_some();
+                        // NOT conditions are edited as their inner condition
+                        let (negated, inner) = match condition {
+                            TimerTrigger::Not { condition } => (true, (**condition).clone()),
+                            other => (false, other.clone()),
+                        };
+                        let inner_for_negate = inner.clone();
 
                         rsx! {
                             div { class: "condition-item",
+                                if is_all_of {
+                                    button {
+                                        class: if negated { "btn-compose active" } else { "btn-compose" },
+                                        title: "Condition must NOT occur within the window",
+                                        onclick: move |_| {
+                                            let mut new_conditions = conditions_for_negate.clone();
+                                            new_conditions[idx] = if negated {
+                                                inner_for_negate.clone()
+                                            } else {
+                                                TimerTrigger::Not { condition: Box::new(inner_for_negate.clone()) }
+                                            };
+                                            on_change.call(composite_trigger(new_conditions, window_secs));
+                                        },
+                                        "NOT"
+                                    }
+                                }
                                 TriggerNode {
-                                    trigger: condition_clone,
+                                    trigger: inner,
                                     encounter_data: encounter_data_for_node,
                                     on_change: move |new_cond| {
                                         let mut new_conditions = conditions_for_update.clone();
-                                        new_conditions[idx] = new_cond;
-                                        on_change.call(TimerTrigger::AnyOf { conditions: new_conditions });
+                                        new_conditions[idx] = if negated {
+                                            TimerTrigger::Not { condition: Box::new(new_cond) }
+                                        } else {
+                                            new_cond
+                                        };
+                                        on_change.call(composite_trigger(new_conditions, window_secs));
                                     },
                                     depth: depth + 1,
+                                    in_all_of: is_all_of && !negated,
                                 }
                                 if conditions_len > 1 {
                                     button {
@@ -335,7 +450,7 @@ fn CompositeEditor(
                                         onclick: move |_| {
                                             let mut new_conditions = conditions_for_remove.clone();
                                             new_conditions.remove(idx);
-                                            on_change.call(TimerTrigger::AnyOf { conditions: new_conditions });
+                                            on_change.call(composite_trigger(new_conditions, window_secs));
                                         },
                                         "×"
                                     }
@@ -351,7 +466,7 @@ fn CompositeEditor(
                 onclick: move |_| {
                     let mut new_conditions = conditions_for_add.clone();
                     new_conditions.push(TimerTrigger::CombatStart);
-                    on_change.call(TimerTrigger::AnyOf { conditions: new_conditions });
+                    on_change.call(composite_trigger(new_conditions, window_secs));
                 },
                 "+ Add Condition"
             }
@@ -359,12 +474,37 @@ fn CompositeEditor(
     }
 }
 
+/// Build a cast trigger (`completed` selects CastCompleted over CastStarted)
+fn cast_trigger(
+    completed: bool,
+    abilities: Vec<AbilitySelector>,
+    source: EntityFilter,
+    target: EntityFilter,
+) -> TimerTrigger {
+    if completed {
+        TimerTrigger::CastCompleted {
+            abilities,
+            source,
+            target,
+        }
+    } else {
+        TimerTrigger::CastStarted {
+            abilities,
+            source,
+            target,
+        }
+    }
+}
+
 /// Editor for simple (non-composite) triggers
 #[component]
 pub fn SimpleTriggerEditor(
     trigger: TimerTrigger,
     encounter_data: EncounterData,
     on_change: EventHandler<TimerTrigger>,
+    /// Offer state conditions (only evaluated inside AllOf)
+    #[props(default)]
+    in_all_of: bool,
 ) -> Element {
     let trigger_type = trigger.type_name();
 
@@ -379,6 +519,8 @@ pub fn SimpleTriggerEditor(
                         "combat_start" => TimerTrigger::CombatStart,
                         "combat_end" => TimerTrigger::CombatEnd,
                         "ability_cast" => TimerTrigger::AbilityCast { abilities: vec![], source: EntityFilter::default(), target: EntityFilter::default() },
+                        "cast_started" => cast_trigger(false, vec![], EntityFilter::default(), EntityFilter::default()),
+                        "cast_completed" => cast_trigger(true, vec![], EntityFilter::default(), EntityFilter::default()),
                         "effect_applied" => TimerTrigger::EffectApplied { effects: vec![], source: EntityFilter::default(), target: EntityFilter::default() },
                         "effect_removed" => TimerTrigger::EffectRemoved { effects: vec![], source: EntityFilter::default(), target: EntityFilter::default() },
                         "damage_taken" => TimerTrigger::DamageTaken { abilities: vec![], source: EntityFilter::default(), target: EntityFilter::default() },
@@ -395,6 +537,8 @@ pub fn SimpleTriggerEditor(
                         "time_elapsed" => TimerTrigger::TimeElapsed { secs: 30.0 },
                         "manual" => TimerTrigger::Manual,
                         "never" => TimerTrigger::Never,
+                        "phase_active" => TimerTrigger::PhaseActive { phase_id: String::new() },
+                        "counter_is" => TimerTrigger::CounterIs { counter_id: String::new(), operator: ComparisonOp::Gte, value: 1 },
                         _ => trigger.clone(),
                     };
                     on_change.call(new_trigger);
@@ -402,6 +546,8 @@ pub fn SimpleTriggerEditor(
                 option { value: "combat_start", "Combat Start" }
                 option { value: "combat_end", "Combat End" }
                 option { value: "ability_cast", "Ability Cast" }
+                option { value: "cast_started", "Cast Started" }
+                option { value: "cast_completed", "Cast Completed" }
                 option { value: "effect_applied", "Effect Applied" }
                 option { value: "effect_removed", "Effect Removed" }
                 option { value: "damage_taken", "Damage Taken" }
@@ -418,6 +564,10 @@ pub fn SimpleTriggerEditor(
                 option { value: "time_elapsed", "Time Elapsed" }
                 option { value: "manual", "Manual" }
                 option { value: "never", "Never" }
+                if in_all_of {
+                    option { value: "phase_active", "Phase Active" }
+                    option { value: "counter_is", "Counter Is" }
+                }
             }
 
             // Type-specific fields
@@ -467,6 +617,50 @@ pub fn SimpleTriggerEditor(
                             }
                         }
                     },
+                    TimerTrigger::CastStarted { abilities, source, target }
+                    | TimerTrigger::CastCompleted { abilities, source, target } => {
+                        let completed = matches!(trigger, TimerTrigger::CastCompleted { .. });
+                        let source_for_abilities = source.clone();
+                        let target_for_abilities = target.clone();
+                        let abilities_for_source = abilities.clone();
+                        let target_for_source = target.clone();
+                        let abilities_for_target = abilities.clone();
+                        let source_for_target = source.clone();
+                        rsx! {
+                            AbilitySelectorEditor {
+                                label: "Abilities",
+                                selectors: abilities,
+                                on_change: move |sels| on_change.call(cast_trigger(
+                                    completed,
+                                    sels,
+                                    source_for_abilities.clone(),
+                                    target_for_abilities.clone(),
+                                ))
+                            }
+                            EntityFilterDropdown {
+                                label: "Source",
+                                value: source,
+                                options: EntityFilter::source_options(),
+                                on_change: move |f| on_change.call(cast_trigger(
+                                    completed,
+                                    abilities_for_source.clone(),
+                                    f,
+                                    target_for_source.clone(),
+                                ))
+                            }
+                            EntityFilterDropdown {
+                                label: "Target",
+                                value: target,
+                                options: EntityFilter::target_options(),
+                                on_change: move |f| on_change.call(cast_trigger(
+                                    completed,
+                                    abilities_for_target.clone(),
+                                    source_for_target.clone(),
+                                    f,
+                                ))
+                            }
+                        }
+                    },
                     TimerTrigger::EffectApplied { effects, source, target } => {
                         let source_for_effects = source.clone();
                         let target_for_effects = target.clone();
@@ -744,6 +938,75 @@ pub fn SimpleTriggerEditor(
                             }
                         }
                     },
+                    TimerTrigger::PhaseActive { phase_id } => {
+                        let available_phases = encounter_data.phase_ids();
+                        rsx! {
+                            IdSelector {
+                                label: "Phase",
+                                value: phase_id,
+                                available: available_phases,
+                                on_change: move |id| on_change.call(TimerTrigger::PhaseActive { phase_id: id })
+                            }
+                        }
+                    },
+                    TimerTrigger::CounterIs { counter_id, operator, value } => {
+                        let available_counters = encounter_data.counter_ids();
+                        let counter_id_for_op = counter_id.clone();
+                        let counter_id_for_value = counter_id.clone();
+                        rsx! {
+                            div { class: "flex items-center gap-xs",
+                                IdSelector {
+                                    label: "Counter",
+                                    value: counter_id,
+                                    available: available_counters,
+                                    on_change: move |id| on_change.call(TimerTrigger::CounterIs {
+                                        counter_id: id,
+                                        operator,
+                                        value,
+                                    })
+                                }
+                                select {
+                                    class: "select",
+                                    style: "width: 55px;",
+                                    onchange: move |e| {
+                                        let op = ComparisonOp::all()
+                                            .iter()
+                                            .find(|op| format!("{op:?}") == e.value())
+                                            .copied()
+                                            .unwrap_or_default();
+                                        on_change.call(TimerTrigger::CounterIs {
+                                            counter_id: counter_id_for_op.clone(),
+                                            operator: op,
+                                            value,
+                                        });
+                                    },
+                                    for op in ComparisonOp::all() {
+                                        option {
+                                            value: "{op:?}",
+                                            selected: operator == *op,
+                                            "{op.label()}"
+                                        }
+                                    }
+                                }
+                                input {
+                                    r#type: "number",
+                                    min: "0",
+                                    class: "input-inline",
+                                    style: "width: 70px;",
+                                    value: "{value}",
+                                    oninput: move |e| {
+                                        if let Ok(val) = e.value().parse::<u32>() {
+                                            on_change.call(TimerTrigger::CounterIs {
+                                                counter_id: counter_id_for_value.clone(),
+                                                operator,
+                                                value: val,
+                                            });
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
                     TimerTrigger::TimeElapsed { secs } => rsx! {
                         div { class: "flex items-center gap-xs",
                             label { class: "text-sm text-secondary", "Seconds" }
@@ -0,0 +1,222 @@
+//! Healer Assignments Component
+//!
+//! Edits the raid plan's healer assignments for the selected boss and shows
+//! how much of each healer's healing in the pull went to their assigned targets.
+
+use dioxus::prelude::*;
+use wasm_bindgen_futures::spawn_local as spawn;
+
+use crate::api::{self, AssignmentCheck, HealerAssignment, RaidPlan};
+use crate::components::{ToastSeverity, use_toast};
+
+fn format_number(n: f64) -> String {
+    if n >= 1_000_000.0 {
+        format!("{:.2}M", n / 1_000_000.0)
+    } else if n >= 1_000.0 {
+        format!("{:.2}K", n / 1_000.0)
+    } else {
+        format!("{}", n as i64)
+    }
+}
+
+/// Split a comma-separated target list, dropping empty entries
+fn parse_targets(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Replace the assignments for `boss_name`, reusing an existing key that
+/// differs only in case
+fn set_boss_assignments(plan: &mut RaidPlan, boss_name: &str, rows: Vec<HealerAssignment>) {
+    let key = plan
+        .bosses
+        .keys()
+        .find(|name| name.eq_ignore_ascii_case(boss_name))
+        .cloned()
+        .unwrap_or_else(|| boss_name.to_string());
+    if rows.is_empty() {
+        plan.bosses.remove(&key);
+    } else {
+        plan.bosses.insert(key, rows);
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct HealerAssignmentsProps {
+    /// Selected encounter
+    pub encounter_key: String,
+    /// Boss the assignments belong to
+    pub boss_name: String,
+}
+
+#[component]
+pub fn HealerAssignments(props: HealerAssignmentsProps) -> Element {
+    let mut encounter_key = use_signal(|| props.encounter_key.clone());
+    if *encounter_key.read() != props.encounter_key {
+        encounter_key.set(props.encounter_key.clone());
+    }
+    let mut boss_name = use_signal(|| props.boss_name.clone());
+    if *boss_name.read() != props.boss_name {
+        boss_name.set(props.boss_name.clone());
+    }
+
+    let mut plan = use_signal(RaidPlan::default);
+    // Editable rows for this boss as (healer, comma-separated targets)
+    let mut rows = use_signal(Vec::<(String, String)>::new);
+    let mut checks = use_signal(Vec::<AssignmentCheck>::new);
+    // Bumped after a save to re-run the check
+    let mut revision = use_signal(|| 0u32);
+
+    // Load the plan and this boss's rows
+    use_effect(move || {
+        let boss = boss_name.read().clone();
+        spawn(async move {
+            let loaded = api::get_raid_plan().await.unwrap_or_default();
+            rows.set(
+                loaded
+                    .assignments_for(&boss)
+                    .iter()
+                    .map(|a| (a.healer.clone(), a.targets.join(", ")))
+                    .collect(),
+            );
+            plan.set(loaded);
+        });
+    });
+
+    // Check the saved assignments against the pull
+    use_effect(move || {
+        let key = encounter_key.read().clone();
+        let boss = boss_name.read().clone();
+        let _ = revision.read();
+        spawn(async move {
+            let result = api::check_healer_assignments(&key, &boss)
+                .await
+                .unwrap_or_default();
+            if *encounter_key.peek() == key {
+                checks.set(result);
+            }
+        });
+    });
+
+    let save = move |_| {
+        let boss = boss_name.read().clone();
+        let assignments: Vec<HealerAssignment> = rows
+            .read()
+            .iter()
+            .filter(|(healer, _)| !healer.trim().is_empty())
+            .map(|(healer, targets)| HealerAssignment {
+                healer: healer.trim().to_string(),
+                targets: parse_targets(targets),
+            })
+            .collect();
+        let mut updated = plan.read().clone();
+        set_boss_assignments(&mut updated, &boss, assignments);
+        let mut toast = use_toast();
+        spawn(async move {
+            match api::save_raid_plan(&updated).await {
+                Ok(()) => {
+                    plan.set(updated);
+                    revision += 1;
+                }
+                Err(err) => {
+                    toast.show(
+                        format!("Failed to save raid plan: {}", err),
+                        ToastSeverity::Normal,
+                    );
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "healer-assignments",
+            h4 { "Assignments for {props.boss_name}" }
+            table { class: "overview-table healer-assignments-edit",
+                thead {
+                    tr {
+                        th { class: "name-col", "Healer" }
+                        th { class: "name-col", "Targets (comma separated)" }
+                        th {}
+                    }
+                }
+                tbody {
+                    for (idx, (healer, targets)) in rows.read().iter().cloned().enumerate() {
+                        tr { key: "{idx}",
+                            td {
+                                input {
+                                    r#type: "text",
+                                    value: "{healer}",
+                                    placeholder: "Healer",
+                                    oninput: move |e| rows.write()[idx].0 = e.value(),
+                                }
+                            }
+                            td {
+                                input {
+                                    r#type: "text",
+                                    value: "{targets}",
+                                    placeholder: "Tank or group members",
+                                    oninput: move |e| rows.write()[idx].1 = e.value(),
+                                }
+                            }
+                            td {
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    title: "Remove",
+                                    onclick: move |_| {
+                                        rows.write().remove(idx);
+                                    },
+                                    i { class: "fa-solid fa-xmark" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "healer-assignments-actions",
+                button {
+                    class: "btn btn-sm",
+                    onclick: move |_| rows.write().push((String::new(), String::new())),
+                    "Add Healer"
+                }
+                button { class: "btn btn-success btn-sm", onclick: save, "Save" }
+            }
+
+            if checks.read().is_empty() {
+                p { class: "hint", "No assignments saved for this boss" }
+            } else {
+                h4 { "Healing on Assigned Targets" }
+                table { class: "overview-table healer-assignments-result",
+                    thead {
+                        tr {
+                            th { class: "name-col", "Healer" }
+                            th { class: "name-col", "Targets" }
+                            th { class: "num", "On Assigned" }
+                            th { class: "num", "Assigned" }
+                            th { class: "num", "Total" }
+                        }
+                    }
+                    tbody {
+                        for check in checks.read().iter() {
+                            {
+                                let pct = check.assigned_pct();
+                                let targets = check.targets.join(", ");
+                                rsx! {
+                                    tr {
+                                        td { class: "name-col", "{check.healer}" }
+                                        td { class: "name-col", "{targets}" }
+                                        td { class: "num", "{pct:.1}%" }
+                                        td { class: "num", "{format_number(check.assigned_healing)}" }
+                                        td { class: "num", "{format_number(check.total_healing)}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -11,6 +11,7 @@ use wasm_bindgen_futures::spawn_local as spawn;
 use crate::api;
 use crate::components::class_icons::{get_class_icon, get_role_icon};
 use crate::components::{ToastSeverity, use_toast};
+use crate::types::{SESSION_UPDATED_EVENT, SessionEvent, TimelineEvent, TimelineEventKind};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Data Types (mirrors backend)
@@ -55,6 +56,8 @@ pub struct PlayerMetrics {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncounterSummary {
     pub encounter_id: u64,
+    #[serde(default)]
+    pub encounter_key: String,
     pub display_name: String,
     pub encounter_type: String,
     pub start_time: Option<String>,
@@ -130,6 +133,7 @@ pub fn HistoryPanel(props: HistoryPanelProps) -> Element {
     let mut expanded_id = use_signal(|| None::<u64>);
     let mut collapsed_sections = use_signal(HashSet::<String>::new);
     let mut loading = use_signal(|| true);
+    let mut timeline = use_signal(Vec::<TimelineEvent>::new);
     let mut show_only_bosses = props.show_only_bosses;
 
     // Fetch encounter history
@@ -137,32 +141,35 @@ pub fn HistoryPanel(props: HistoryPanelProps) -> Element {
         if let Some(history) = api::get_encounter_history().await {
             encounters.set(history);
         }
+        timeline.set(api::get_session_timeline().await);
         loading.set(false);
     });
 
     // Listen for session updates (refresh on combat end, file change, etc.)
     use_future(move || async move {
         let closure = Closure::new(move |event: JsValue| {
-            // Extract payload from event object (Tauri events have { payload: "..." } structure)
-            if let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
-                && let Some(event_type) = payload.as_string()
-                && (event_type.contains("CombatEnded")
-                    || event_type.contains("TailingModeChanged")
-                    || event_type.contains("FileLoaded"))
+            if let Some(
+                SessionEvent::CombatEnded
+                | SessionEvent::TailingModeChanged
+                | SessionEvent::FileLoaded,
+            ) = api::session_event(&event)
             {
                 spawn(async move {
                     if let Some(history) = api::get_encounter_history().await {
                         // Use try_write to handle signal being dropped when component unmounts
                         let _ = encounters.try_write().map(|mut w| *w = history);
                     }
+                    let events = api::get_session_timeline().await;
+                    let _ = timeline.try_write().map(|mut w| *w = events);
                 });
             }
         });
-        api::tauri_listen("session-updated", &closure).await;
+        api::tauri_listen(SESSION_UPDATED_EVENT, &closure).await;
         closure.forget();
     });
 
     let history = encounters();
+    let timeline_events = timeline();
     let is_loading = loading();
     let selected = expanded_id();
     let collapsed = collapsed_sections();
@@ -240,6 +247,10 @@ pub fn HistoryPanel(props: HistoryPanelProps) -> Element {
                 }
             }
 
+            if !timeline_events.is_empty() {
+                SessionTimeline { events: timeline_events }
+            }
+
             if is_loading {
                 div { class: "history-loading",
                     i { class: "fa-solid fa-spinner fa-spin" }
@@ -421,6 +432,66 @@ fn sort_metrics(metrics: &mut [PlayerMetrics], column: SortColumn, ascending: bo
     });
 }
 
+/// Session overview: combat spans plus markers for area changes, player deaths and phases
+#[component]
+fn SessionTimeline(events: Vec<TimelineEvent>) -> Element {
+    let span_secs = events.last().map(|e| e.offset_secs).unwrap_or(0.0).max(1.0);
+    let pct = |secs: f64| secs / span_secs * 100.0;
+
+    // Pair each combat start with its end (an open combat runs to the end of the strip)
+    let mut combats = Vec::new();
+    let mut combat_start = None;
+    for event in &events {
+        match event.kind {
+            TimelineEventKind::CombatStarted => combat_start = Some(event.offset_secs),
+            TimelineEventKind::CombatEnded => {
+                if let Some(start) = combat_start.take() {
+                    combats.push((pct(start), pct(event.offset_secs)));
+                }
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = combat_start {
+        combats.push((pct(start), 100.0));
+    }
+
+    let markers: Vec<_> = events
+        .iter()
+        .filter_map(|event| {
+            let class = match event.kind {
+                TimelineEventKind::AreaChanged => "marker-area",
+                TimelineEventKind::PlayerDeath => "marker-death",
+                TimelineEventKind::PhaseChanged => "marker-phase",
+                _ => return None,
+            };
+            Some((
+                class,
+                pct(event.offset_secs),
+                format!("{} {}", event.time, event.label),
+            ))
+        })
+        .collect();
+
+    rsx! {
+        div { class: "session-timeline",
+            for (left, right) in combats {
+                div {
+                    class: "timeline-combat",
+                    style: "left: {left:.2}%; width: {(right - left).max(0.2):.2}%;",
+                }
+            }
+            for (class, left, title) in markers {
+                div {
+                    class: "timeline-marker {class}",
+                    style: "left: {left:.2}%;",
+                    title: "{title}",
+                }
+            }
+        }
+    }
+}
+
 #[component]
 fn EncounterDetail(encounter: EncounterSummary) -> Element {
     let mut sort_column = use_signal(|| SortColumn::Dps);
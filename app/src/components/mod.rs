@@ -7,13 +7,18 @@ pub mod ability_icon;
 pub mod charts_panel;
 pub mod class_icons;
 pub mod combat_log;
+pub mod counter_rules;
 pub mod data_explorer;
 pub mod effect_editor;
 pub mod encounter_editor;
+pub mod healer_assignments;
 pub mod history_panel;
 pub mod hotkey_input;
 pub mod phase_timeline;
+pub mod pull_aggregate;
+pub mod pull_compare;
 pub mod settings_panel;
+pub mod table_export;
 pub mod toast;
 
 pub use combat_log::CombatLogState;
@@ -0,0 +1,148 @@
+//! Pull Aggregate Component
+//!
+//! Statistics across every pull of the selected boss in the session:
+//! per-player average and best DPS, consistency, healing and deaths.
+
+use dioxus::prelude::*;
+use wasm_bindgen_futures::spawn_local as spawn;
+
+use crate::api::{self, SessionAggregate};
+use crate::components::history_panel::EncounterSummary;
+
+fn format_number(n: f64) -> String {
+    if n >= 1_000_000.0 {
+        format!("{:.2}M", n / 1_000_000.0)
+    } else if n >= 1_000.0 {
+        format!("{:.2}K", n / 1_000.0)
+    } else {
+        format!("{}", n as i64)
+    }
+}
+
+fn format_duration(secs: f32) -> String {
+    let secs = secs.round() as u64;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PullAggregateProps {
+    /// Every pull of the selected boss, in session order
+    pub pulls: Vec<EncounterSummary>,
+}
+
+#[component]
+pub fn PullAggregate(props: PullAggregateProps) -> Element {
+    let mut kills_only = use_signal(|| false);
+    let mut aggregate = use_signal(|| None::<SessionAggregate>);
+    let mut loading = use_signal(|| false);
+
+    let mut pulls = use_signal(|| props.pulls.clone());
+    if *pulls.peek() != props.pulls {
+        pulls.set(props.pulls.clone());
+    }
+
+    // Pulls included in the aggregate
+    let included = use_memo(move || {
+        let kills_only = kills_only();
+        pulls
+            .read()
+            .iter()
+            .filter(|p| !kills_only || p.success)
+            .cloned()
+            .collect::<Vec<_>>()
+    });
+
+    use_effect(move || {
+        let keys: Vec<String> = included
+            .read()
+            .iter()
+            .map(|p| p.encounter_key.clone())
+            .collect();
+        if keys.is_empty() {
+            aggregate.set(None);
+            return;
+        }
+        spawn(async move {
+            loading.set(true);
+            let result = api::query_session_aggregate(&keys).await;
+            // Discard results for a selection that changed while loading
+            let current: Vec<String> = included
+                .peek()
+                .iter()
+                .map(|p| p.encounter_key.clone())
+                .collect();
+            if current == keys {
+                aggregate.set(result);
+            }
+            loading.set(false);
+        });
+    });
+
+    let pull_label = move |index: usize| -> String {
+        included
+            .read()
+            .get(index)
+            .map(|p| {
+                let time = p.start_time.clone().unwrap_or_default();
+                format!("#{} {}", index + 1, time)
+            })
+            .unwrap_or_default()
+    };
+
+    rsx! {
+        div { class: "pull-aggregate",
+            div { class: "pull-compare-picker",
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: kills_only(),
+                        onchange: move |e| kills_only.set(e.checked()),
+                    }
+                    " Kills only"
+                }
+                if loading() {
+                    i { class: "fa-solid fa-spinner fa-spin" }
+                }
+            }
+
+            if included.read().is_empty() {
+                p { class: "hint", "No pulls of this boss to aggregate" }
+            }
+
+            if let Some(agg) = aggregate.read().as_ref() {
+                p { class: "pull-compare-duration",
+                    "{agg.pull_count} pulls, {format_duration(agg.total_duration_secs)} in combat"
+                }
+
+                table { class: "overview-table pull-compare-table",
+                    thead {
+                        tr {
+                            th { class: "name-col", "Player" }
+                            th { class: "num", "Pulls" }
+                            th { class: "num", "Avg DPS" }
+                            th { class: "num", "Best DPS" }
+                            th { "Best Pull" }
+                            th { class: "num", title: "100% means the same DPS on every pull", "Consistency" }
+                            th { class: "num", "Avg eHPS" }
+                            th { class: "num", "Deaths" }
+                        }
+                    }
+                    tbody {
+                        for p in agg.players.iter() {
+                            tr {
+                                td { class: "name-col", "{p.name}" }
+                                td { class: "num", "{p.pulls}" }
+                                td { class: "num", "{format_number(p.avg_dps)}" }
+                                td { class: "num", "{format_number(p.best_dps)}" }
+                                td { "{pull_label(p.best_pull)}" }
+                                td { class: "num", "{p.consistency_pct:.0}%" }
+                                td { class: "num", "{format_number(p.avg_ehps)}" }
+                                td { class: "num", "{p.deaths}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
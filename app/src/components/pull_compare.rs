@@ -0,0 +1,224 @@
+//! Pull Compare Component
+//!
+//! Compares the selected encounter against another pull of the same boss:
+//! per-player DPS, phase durations and boss ability hits taken by players.
+
+use dioxus::prelude::*;
+use wasm_bindgen_futures::spawn_local as spawn;
+
+use crate::api::{self, PullComparison};
+use crate::components::history_panel::EncounterSummary;
+
+fn format_number(n: f64) -> String {
+    let sign = if n < 0.0 { "-" } else { "" };
+    let abs = n.abs();
+    if abs >= 1_000_000.0 {
+        format!("{sign}{:.2}M", abs / 1_000_000.0)
+    } else if abs >= 1_000.0 {
+        format!("{sign}{:.2}K", abs / 1_000.0)
+    } else {
+        format!("{sign}{}", abs as i64)
+    }
+}
+
+fn format_delta(delta: f64) -> String {
+    if delta > 0.0 {
+        format!("+{}", format_number(delta))
+    } else {
+        format_number(delta)
+    }
+}
+
+fn format_secs(secs: Option<f32>) -> String {
+    secs.map(|s| format!("{s:.1}s"))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_secs_delta(delta: Option<f64>) -> String {
+    delta
+        .map(|d| format!("{d:+.1}s"))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// CSS class for a change where `higher_is_better` says which sign is good
+fn delta_class(delta: f64, higher_is_better: bool) -> &'static str {
+    if delta.abs() < f64::EPSILON {
+        "num"
+    } else if (delta > 0.0) == higher_is_better {
+        "num delta-better"
+    } else {
+        "num delta-worse"
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PullCompareProps {
+    /// Selected encounter (pull A)
+    pub encounter_key: String,
+    /// Other pulls of the same boss to compare against
+    pub candidates: Vec<EncounterSummary>,
+}
+
+#[component]
+pub fn PullCompare(props: PullCompareProps) -> Element {
+    let mut encounter_a = use_signal(|| props.encounter_key.clone());
+    if *encounter_a.read() != props.encounter_key {
+        encounter_a.set(props.encounter_key.clone());
+    }
+
+    let mut encounter_b = use_signal(|| None::<String>);
+    let mut comparison = use_signal(|| None::<PullComparison>);
+    let mut loading = use_signal(|| false);
+
+    // Reset the pick when the selected encounter changes
+    use_effect(move || {
+        let _ = encounter_a.read();
+        encounter_b.set(None);
+    });
+
+    use_effect(move || {
+        let a = encounter_a.read().clone();
+        let Some(b) = encounter_b.read().clone() else {
+            comparison.set(None);
+            return;
+        };
+        spawn(async move {
+            loading.set(true);
+            let result = api::compare_pulls(&a, &b).await;
+            // Discard results for a pick that changed while loading
+            if *encounter_b.peek() == Some(b) {
+                comparison.set(result);
+            }
+            loading.set(false);
+        });
+    });
+
+    rsx! {
+        div { class: "pull-compare",
+            div { class: "pull-compare-picker",
+                label { "Compare with" }
+                select {
+                    value: encounter_b.read().clone().unwrap_or_default(),
+                    onchange: move |e| {
+                        let value = e.value();
+                        encounter_b.set(if value.is_empty() { None } else { Some(value) });
+                    },
+                    option { value: "", "Select a pull..." }
+                    for enc in props.candidates.iter() {
+                        {
+                            let result = if enc.success { "kill" } else { "wipe" };
+                            let time = enc.start_time.clone().unwrap_or_default();
+                            rsx! {
+                                option {
+                                    value: "{enc.encounter_key}",
+                                    selected: encounter_b.read().as_deref() == Some(enc.encounter_key.as_str()),
+                                    "{enc.display_name} {time} ({result})"
+                                }
+                            }
+                        }
+                    }
+                }
+                if loading() {
+                    i { class: "fa-solid fa-spinner fa-spin" }
+                }
+            }
+
+            if props.candidates.is_empty() {
+                p { class: "hint", "No other pulls of this boss in the session" }
+            }
+
+            if let Some(c) = comparison.read().as_ref() {
+                p { class: "pull-compare-duration",
+                    "Duration {format_secs(Some(c.duration_a))} → {format_secs(Some(c.duration_b))} ({format_secs_delta(Some((c.duration_b - c.duration_a) as f64))})"
+                }
+
+                h4 { "Players" }
+                table { class: "overview-table pull-compare-table",
+                    thead {
+                        tr {
+                            th { class: "name-col", "Player" }
+                            th { class: "num", "DPS A" }
+                            th { class: "num", "DPS B" }
+                            th { class: "num", "Change" }
+                            th { class: "num", "Deaths A" }
+                            th { class: "num", "Deaths B" }
+                        }
+                    }
+                    tbody {
+                        for p in c.players.iter() {
+                            tr {
+                                td { class: "name-col", "{p.name}" }
+                                td { class: "num", "{p.dps_a.map(format_number).unwrap_or_default()}" }
+                                td { class: "num", "{p.dps_b.map(format_number).unwrap_or_default()}" }
+                                td { class: delta_class(p.dps_delta(), true), "{format_delta(p.dps_delta())}" }
+                                td { class: "num", "{p.deaths_a}" }
+                                td { class: delta_class(p.deaths_b as f64 - p.deaths_a as f64, false), "{p.deaths_b}" }
+                            }
+                        }
+                    }
+                }
+
+                if !c.phases.is_empty() {
+                    h4 { "Phases" }
+                    table { class: "overview-table pull-compare-table",
+                        thead {
+                            tr {
+                                th { class: "name-col", "Phase" }
+                                th { class: "num", "A" }
+                                th { class: "num", "B" }
+                                th { class: "num", "Change" }
+                            }
+                        }
+                        tbody {
+                            for phase in c.phases.iter() {
+                                {
+                                    let change = match (phase.duration_a, phase.duration_b) {
+                                        (Some(a), Some(b)) => Some((b - a) as f64),
+                                        _ => None,
+                                    };
+                                    rsx! {
+                                        tr {
+                                            td { class: "name-col", "{phase.phase_name} #{phase.instance}" }
+                                            td { class: "num", "{format_secs(phase.duration_a)}" }
+                                            td { class: "num", "{format_secs(phase.duration_b)}" }
+                                            td {
+                                                class: change.map(|d| delta_class(d, false)).unwrap_or("num"),
+                                                "{format_secs_delta(change)}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !c.mechanics.is_empty() {
+                    h4 { "Boss Abilities on Players" }
+                    table { class: "overview-table pull-compare-table",
+                        thead {
+                            tr {
+                                th { class: "name-col", "Ability" }
+                                th { class: "num", "Hits A" }
+                                th { class: "num", "Hits B" }
+                                th { class: "num", "Damage A" }
+                                th { class: "num", "Damage B" }
+                            }
+                        }
+                        tbody {
+                            for m in c.mechanics.iter() {
+                                tr {
+                                    td { class: "name-col", "{m.ability_name}" }
+                                    td { class: "num", "{m.hits_a}" }
+                                    td { class: delta_class(m.hits_b as f64 - m.hits_a as f64, false), "{m.hits_b}" }
+                                    td { class: "num", "{format_number(m.damage_a)}" }
+                                    td { class: delta_class(m.damage_b - m.damage_a, false), "{format_number(m.damage_b)}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -8,12 +8,15 @@ use gloo_timers::future::TimeoutFuture;
 use std::collections::HashMap;
 
 use crate::api;
+use crate::components::counter_rules::CounterRules;
 use crate::components::{ToastSeverity, use_toast};
 use crate::types::{
-    AlertsOverlayConfig, BossHealthConfig, ChallengeLayout, CooldownTrackerConfig,
-    DotTrackerConfig, EffectsAConfig, EffectsBConfig, MAX_PROFILES, MetricType,
+    AlertBannerConfig, AlertsOverlayConfig, BossHealthConfig, BossNotesConfig, ChallengeLayout,
+    CooldownTrackerConfig, DeathRecapConfig, DotTrackerConfig, EffectsAConfig, EffectsBConfig,
+    MAX_NUMBER_DECIMALS, MAX_PROFILES, MetricType, NumberFormat, NumberStyle,
     OverlayAppearanceConfig, OverlaySettings, PersonalOverlayConfig, PersonalStat,
-    RaidOverlaySettings, TimerOverlayConfig,
+    RaidCooldownConfig, RaidFrameLayout, RaidNameStyle, RaidOverlaySettings, TankSwapConfig,
+    TimerBarDirection, TimerOverlayConfig, TimerTextLayout,
 };
 use crate::utils::{color_to_hex, parse_hex_color};
 
@@ -109,6 +112,18 @@ pub fn SettingsPanel(
                     new_settings.cooldown_tracker_opacity;
                 config.overlay_settings.dot_tracker = new_settings.dot_tracker.clone();
                 config.overlay_settings.dot_tracker_opacity = new_settings.dot_tracker_opacity;
+                config.overlay_settings.tank_swap = new_settings.tank_swap.clone();
+                config.overlay_settings.tank_swap_opacity = new_settings.tank_swap_opacity;
+                config.overlay_settings.raid_cooldowns = new_settings.raid_cooldowns.clone();
+                config.overlay_settings.raid_cooldowns_opacity =
+                    new_settings.raid_cooldowns_opacity;
+                config.overlay_settings.death_recap = new_settings.death_recap.clone();
+                config.overlay_settings.death_recap_opacity = new_settings.death_recap_opacity;
+                config.overlay_settings.boss_notes = new_settings.boss_notes.clone();
+                config.overlay_settings.boss_notes_opacity = new_settings.boss_notes_opacity;
+                config.overlay_settings.alert_banner = new_settings.alert_banner.clone();
+                config.overlay_settings.alert_banner_opacity = new_settings.alert_banner_opacity;
+                config.overlay_settings.frame_style = new_settings.frame_style;
                 config.overlay_settings.positions = existing_positions;
                 config.overlay_settings.enabled = existing_enabled;
 
@@ -333,6 +348,45 @@ pub fn SettingsPanel(
                         TabButton { label: "Personal Stats", tab_key: "personal", selected_tab: selected_tab }
                         TabButton { label: "Raid Frames", tab_key: "raid", selected_tab: selected_tab }
                         TabButton { label: "Alerts", tab_key: "alerts", selected_tab: selected_tab }
+                        TabButton { label: "Alert Banner", tab_key: "alert_banner", selected_tab: selected_tab }
+                    }
+                    details { class: "settings-section collapsible metrics-global",
+                        summary { class: "collapsible-summary",
+                            i { class: "fa-solid fa-window-maximize summary-icon" }
+                            "Window Style"
+                        }
+                        div { class: "collapsible-content",
+                            div { class: "setting-row",
+                                label { "Corner Radius" }
+                                input {
+                                    r#type: "range",
+                                    min: "0",
+                                    max: "16",
+                                    value: "{current_settings.frame_style.corner_radius}",
+                                    oninput: move |e| {
+                                        if let Ok(val) = e.value().parse::<u8>() {
+                                            let mut new_settings = draft_settings();
+                                            new_settings.frame_style.corner_radius = val;
+                                            update_draft(new_settings);
+                                        }
+                                    }
+                                }
+                                span { class: "value", "{current_settings.frame_style.corner_radius}px" }
+                            }
+
+                            div { class: "setting-row",
+                                label { "Drop Shadow" }
+                                input {
+                                    r#type: "checkbox",
+                                    checked: current_settings.frame_style.shadow,
+                                    onchange: move |e: Event<FormData>| {
+                                        let mut new_settings = draft_settings();
+                                        new_settings.frame_style.shadow = e.checked();
+                                        update_draft(new_settings);
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
                 div { class: "tab-group",
@@ -342,6 +396,10 @@ pub fn SettingsPanel(
                         TabButton { label: "Timers A", tab_key: "timers_a", selected_tab: selected_tab }
                         TabButton { label: "Timers B", tab_key: "timers_b", selected_tab: selected_tab }
                         TabButton { label: "Challenges", tab_key: "challenges", selected_tab: selected_tab }
+                        TabButton { label: "Tank Swap", tab_key: "tank_swap", selected_tab: selected_tab }
+                        TabButton { label: "Raid Cooldowns", tab_key: "raid_cooldowns", selected_tab: selected_tab }
+                        TabButton { label: "Death Recap", tab_key: "death_recap", selected_tab: selected_tab }
+                        TabButton { label: "Boss Notes", tab_key: "boss_notes", selected_tab: selected_tab }
                     }
                 }
                 div { class: "tab-group",
@@ -497,6 +555,53 @@ pub fn SettingsPanel(
                                 }
                             }
 
+                            div { class: "setting-row",
+                                label { "Show pace vs. best kill" }
+                                input {
+                                    r#type: "checkbox",
+                                    checked: current_settings.boss_health.show_pace,
+                                    onchange: move |e: Event<FormData>| {
+                                        let mut new_settings = draft_settings();
+                                        new_settings.boss_health.show_pace = e.checked();
+                                        update_draft(new_settings);
+                                    }
+                                }
+                            }
+
+                            div { class: "setting-row",
+                                label { "Show phase markers" }
+                                input {
+                                    r#type: "checkbox",
+                                    title: "Tick marks on the bars where HP-triggered phases start",
+                                    checked: current_settings.boss_health.show_phase_markers,
+                                    onchange: move |e: Event<FormData>| {
+                                        let mut new_settings = draft_settings();
+                                        new_settings.boss_health.show_phase_markers = e.checked();
+                                        update_draft(new_settings);
+                                    }
+                                }
+                            }
+
+                            div { class: "setting-row",
+                                label { "Linger After Combat (sec)" }
+                                input {
+                                    r#type: "number",
+                                    title: "Keep the bars up through short combat gaps, like immune phase transitions",
+                                    min: "0",
+                                    max: "30",
+                                    step: "0.5",
+                                    value: "{current_settings.boss_health.linger_secs}",
+                                    class: "input-inline",
+                                    oninput: move |e: Event<FormData>| {
+                                        if let Ok(val) = e.value().parse::<f32>() {
+                                            let mut new_settings = draft_settings();
+                                            new_settings.boss_health.linger_secs = val.clamp(0.0, 30.0);
+                                            update_draft(new_settings);
+                                        }
+                                    }
+                                }
+                            }
+
                     div { class: "setting-row reset-row",
                         button {
                             class: "btn btn-reset",
@@ -542,6 +647,72 @@ pub fn SettingsPanel(
                         }
                     }
 
+                    div { class: "setting-row",
+                        label { "Bar Direction" }
+                        select {
+                            class: "input-inline",
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.timers_a_overlay.bar_direction = match e.value().as_str() {
+                                    "fill" => TimerBarDirection::Fill,
+                                    _ => TimerBarDirection::Drain,
+                                };
+                                update_draft(new_settings);
+                            },
+                            option { value: "drain", selected: current_settings.timers_a_overlay.bar_direction == TimerBarDirection::Drain, "Drain (full to empty)" }
+                            option { value: "fill", selected: current_settings.timers_a_overlay.bar_direction == TimerBarDirection::Fill, "Fill (empty to full)" }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Text Layout" }
+                        select {
+                            class: "input-inline",
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.timers_a_overlay.text_layout = match e.value().as_str() {
+                                    "centered" => TimerTextLayout::Centered,
+                                    _ => TimerTextLayout::Split,
+                                };
+                                update_draft(new_settings);
+                            },
+                            option { value: "split", selected: current_settings.timers_a_overlay.text_layout == TimerTextLayout::Split, "Name left, time right" }
+                            option { value: "centered", selected: current_settings.timers_a_overlay.text_layout == TimerTextLayout::Centered, "Centered" }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Flash Last (sec)" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            max: "30",
+                            step: "1",
+                            value: "{current_settings.timers_a_overlay.flash_last_secs}",
+                            class: "input-inline",
+                            oninput: move |e: Event<FormData>| {
+                                if let Ok(val) = e.value().parse::<f32>() {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.timers_a_overlay.flash_last_secs = val.clamp(0.0, 30.0);
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Show next raid-wide hit" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current_settings.timers_a_overlay.show_incoming_hit,
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.timers_a_overlay.show_incoming_hit = e.checked();
+                                update_draft(new_settings);
+                            }
+                        }
+                    }
+
                     div { class: "setting-row reset-row",
                         button {
                             class: "btn btn-reset",
@@ -587,6 +758,59 @@ pub fn SettingsPanel(
                         }
                     }
 
+                    div { class: "setting-row",
+                        label { "Bar Direction" }
+                        select {
+                            class: "input-inline",
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.timers_b_overlay.bar_direction = match e.value().as_str() {
+                                    "fill" => TimerBarDirection::Fill,
+                                    _ => TimerBarDirection::Drain,
+                                };
+                                update_draft(new_settings);
+                            },
+                            option { value: "drain", selected: current_settings.timers_b_overlay.bar_direction == TimerBarDirection::Drain, "Drain (full to empty)" }
+                            option { value: "fill", selected: current_settings.timers_b_overlay.bar_direction == TimerBarDirection::Fill, "Fill (empty to full)" }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Text Layout" }
+                        select {
+                            class: "input-inline",
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.timers_b_overlay.text_layout = match e.value().as_str() {
+                                    "centered" => TimerTextLayout::Centered,
+                                    _ => TimerTextLayout::Split,
+                                };
+                                update_draft(new_settings);
+                            },
+                            option { value: "split", selected: current_settings.timers_b_overlay.text_layout == TimerTextLayout::Split, "Name left, time right" }
+                            option { value: "centered", selected: current_settings.timers_b_overlay.text_layout == TimerTextLayout::Centered, "Centered" }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Flash Last (sec)" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            max: "30",
+                            step: "1",
+                            value: "{current_settings.timers_b_overlay.flash_last_secs}",
+                            class: "input-inline",
+                            oninput: move |e: Event<FormData>| {
+                                if let Ok(val) = e.value().parse::<f32>() {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.timers_b_overlay.flash_last_secs = val.clamp(0.0, 30.0);
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
                     div { class: "setting-row reset-row",
                         button {
                             class: "btn btn-reset",
@@ -1061,54 +1285,423 @@ pub fn SettingsPanel(
                     }
 
                     div { class: "setting-row",
-                        label { "Show Effect Names" }
+                        label { "Show Effect Names" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current_settings.dot_tracker.show_effect_names,
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.dot_tracker.show_effect_names = e.checked();
+                                update_draft(new_settings);
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Show Countdown" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current_settings.dot_tracker.show_countdown,
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.dot_tracker.show_countdown = e.checked();
+                                update_draft(new_settings);
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Font Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.dot_tracker.font_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.dot_tracker.font_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row reset-row",
+                        button {
+                            class: "btn btn-reset",
+                            onclick: move |_| {
+                                let mut new_settings = draft_settings();
+                                new_settings.dot_tracker = DotTrackerConfig::default();
+                                new_settings.dot_tracker_opacity = 180;
+                                update_draft(new_settings);
+                            },
+                            i { class: "fa-solid fa-rotate-left" }
+                            span { " Reset to Defaults" }
+                        }
+                    }
+                }
+            } else if tab == "tank_swap" {
+                // Tank Swap Settings
+                div { class: "settings-section",
+                    h4 { "Appearance" }
+
+                    OpacitySlider {
+                        label: "Background Opacity",
+                        value: current_settings.tank_swap_opacity,
+                        on_change: move |val| {
+                            let mut new_settings = draft_settings();
+                            new_settings.tank_swap_opacity = val;
+                            update_draft(new_settings);
+                        },
+                    }
+
+                    div { class: "setting-row",
+                        label { "Stack Bar Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.tank_swap.stack_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.tank_swap.stack_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Font Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.tank_swap.font_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.tank_swap.font_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    h4 { style: "margin-top: 16px;", "Display Options" }
+
+                    div { class: "setting-row",
+                        label { "Flash On Swap" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current_settings.tank_swap.flash_on_swap,
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.tank_swap.flash_on_swap = e.checked();
+                                update_draft(new_settings);
+                            }
+                        }
+                    }
+                    p { class: "hint", "Debuffs and swap stack counts come from each boss's tank_swap definition" }
+
+                    div { class: "setting-row reset-row",
+                        button {
+                            class: "btn btn-reset",
+                            onclick: move |_| {
+                                let mut new_settings = draft_settings();
+                                new_settings.tank_swap = TankSwapConfig::default();
+                                new_settings.tank_swap_opacity = 180;
+                                update_draft(new_settings);
+                            },
+                            i { class: "fa-solid fa-rotate-left" }
+                            span { " Reset to Defaults" }
+                        }
+                    }
+                }
+            } else if tab == "raid_cooldowns" {
+                // Raid Cooldown Settings
+                div { class: "settings-section",
+                    h4 { "Appearance" }
+
+                    OpacitySlider {
+                        label: "Background Opacity",
+                        value: current_settings.raid_cooldowns_opacity,
+                        on_change: move |val| {
+                            let mut new_settings = draft_settings();
+                            new_settings.raid_cooldowns_opacity = val;
+                            update_draft(new_settings);
+                        },
+                    }
+
+                    div { class: "setting-row",
+                        label { "Ready Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.raid_cooldowns.ready_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.raid_cooldowns.ready_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "On Cooldown Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.raid_cooldowns.cooldown_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.raid_cooldowns.cooldown_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Font Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.raid_cooldowns.font_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.raid_cooldowns.font_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    h4 { style: "margin-top: 16px;", "Display Options" }
+
+                    div { class: "setting-row",
+                        label { "Show Defensives" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current_settings.raid_cooldowns.show_defensives,
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.raid_cooldowns.show_defensives = e.checked();
+                                update_draft(new_settings);
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Show Raid Utility" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current_settings.raid_cooldowns.show_raid_utility,
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.raid_cooldowns.show_raid_utility = e.checked();
+                                update_draft(new_settings);
+                            }
+                        }
+                    }
+                    p { class: "hint", "A player's cooldown appears once they have used it. Durations are base values, before utilities and set bonuses." }
+
+                    div { class: "setting-row reset-row",
+                        button {
+                            class: "btn btn-reset",
+                            onclick: move |_| {
+                                let mut new_settings = draft_settings();
+                                new_settings.raid_cooldowns = RaidCooldownConfig::default();
+                                new_settings.raid_cooldowns_opacity = 180;
+                                update_draft(new_settings);
+                            },
+                            i { class: "fa-solid fa-rotate-left" }
+                            span { " Reset to Defaults" }
+                        }
+                    }
+                }
+            } else if tab == "death_recap" {
+                // Death Recap Settings
+                div { class: "settings-section",
+                    h4 { "Appearance" }
+
+                    OpacitySlider {
+                        label: "Background Opacity",
+                        value: current_settings.death_recap_opacity,
+                        on_change: move |val| {
+                            let mut new_settings = draft_settings();
+                            new_settings.death_recap_opacity = val;
+                            update_draft(new_settings);
+                        },
+                    }
+
+                    div { class: "setting-row",
+                        label { "Font Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.death_recap.font_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.death_recap.font_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Damage Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.death_recap.damage_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.death_recap.damage_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Heal Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.death_recap.heal_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.death_recap.heal_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    h4 { style: "margin-top: 16px;", "Display Options" }
+
+                    div { class: "setting-row",
+                        label { "Show Heals" }
                         input {
                             r#type: "checkbox",
-                            checked: current_settings.dot_tracker.show_effect_names,
+                            checked: current_settings.death_recap.show_heals,
                             onchange: move |e: Event<FormData>| {
                                 let mut new_settings = draft_settings();
-                                new_settings.dot_tracker.show_effect_names = e.checked();
+                                new_settings.death_recap.show_heals = e.checked();
                                 update_draft(new_settings);
                             }
                         }
                     }
 
                     div { class: "setting-row",
-                        label { "Show Countdown" }
+                        label { "Local Player Only" }
                         input {
                             r#type: "checkbox",
-                            checked: current_settings.dot_tracker.show_countdown,
+                            checked: current_settings.death_recap.local_player_only,
                             onchange: move |e: Event<FormData>| {
                                 let mut new_settings = draft_settings();
-                                new_settings.dot_tracker.show_countdown = e.checked();
+                                new_settings.death_recap.local_player_only = e.checked();
                                 update_draft(new_settings);
                             }
                         }
                     }
+                    p { class: "hint", "Scroll with Up/Down and dismiss with Escape in keyboard mode" }
+
+                    div { class: "setting-row reset-row",
+                        button {
+                            class: "btn btn-reset",
+                            onclick: move |_| {
+                                let mut new_settings = draft_settings();
+                                new_settings.death_recap = DeathRecapConfig::default();
+                                new_settings.death_recap_opacity = 180;
+                                update_draft(new_settings);
+                            },
+                            i { class: "fa-solid fa-rotate-left" }
+                            span { " Reset to Defaults" }
+                        }
+                    }
+                }
+            } else if tab == "boss_notes" {
+                // Boss Notes Settings
+                div { class: "settings-section",
+                    h4 { "Appearance" }
+
+                    OpacitySlider {
+                        label: "Background Opacity",
+                        value: current_settings.boss_notes_opacity,
+                        on_change: move |val| {
+                            let mut new_settings = draft_settings();
+                            new_settings.boss_notes_opacity = val;
+                            update_draft(new_settings);
+                        },
+                    }
 
                     div { class: "setting-row",
                         label { "Font Color" }
                         input {
                             r#type: "color",
-                            value: "{color_to_hex(&current_settings.dot_tracker.font_color)}",
+                            value: "{color_to_hex(&current_settings.boss_notes.font_color)}",
                             class: "color-picker",
                             oninput: move |e: Event<FormData>| {
                                 if let Some(color) = parse_hex_color(&e.value()) {
                                     let mut new_settings = draft_settings();
-                                    new_settings.dot_tracker.font_color = color;
+                                    new_settings.boss_notes.font_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Heading Color" }
+                        input {
+                            r#type: "color",
+                            value: "{color_to_hex(&current_settings.boss_notes.heading_color)}",
+                            class: "color-picker",
+                            oninput: move |e: Event<FormData>| {
+                                if let Some(color) = parse_hex_color(&e.value()) {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.boss_notes.heading_color = color;
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Font Size" }
+                        input {
+                            r#type: "range",
+                            min: "8",
+                            max: "24",
+                            value: "{current_settings.boss_notes.font_size}",
+                            oninput: move |e| {
+                                if let Ok(val) = e.value().parse::<u8>() {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.boss_notes.font_size = val.clamp(8, 24);
                                     update_draft(new_settings);
                                 }
                             }
                         }
+                        span { class: "value", "{current_settings.boss_notes.font_size}px" }
                     }
+                    p { class: "hint", "Write notes per boss in the Encounter Editor's Notes tab. Page with Left/Right in keyboard mode or the notes paging hotkeys; Escape hides them." }
 
                     div { class: "setting-row reset-row",
                         button {
                             class: "btn btn-reset",
                             onclick: move |_| {
                                 let mut new_settings = draft_settings();
-                                new_settings.dot_tracker = DotTrackerConfig::default();
-                                new_settings.dot_tracker_opacity = 180;
+                                new_settings.boss_notes = BossNotesConfig::default();
+                                new_settings.boss_notes_opacity = 180;
                                 update_draft(new_settings);
                             },
                             i { class: "fa-solid fa-rotate-left" }
@@ -1382,6 +1975,123 @@ pub fn SettingsPanel(
                         " Per-alert color can be set when defining timers with is_alert enabled."
                     }
                 }
+            } else if tab == "alert_banner" {
+                // Alert Banner Settings
+                div { class: "settings-section",
+                    h4 { "Appearance" }
+
+                    OpacitySlider {
+                        label: "Background Opacity",
+                        value: current_settings.alert_banner_opacity,
+                        on_change: move |val| {
+                            let mut new_settings = draft_settings();
+                            new_settings.alert_banner_opacity = val;
+                            update_draft(new_settings);
+                        },
+                    }
+
+                    div { class: "setting-row",
+                        label { "Font Size" }
+                        input {
+                            r#type: "range",
+                            min: "16",
+                            max: "72",
+                            value: "{current_settings.alert_banner.font_size}",
+                            oninput: move |e| {
+                                if let Ok(val) = e.value().parse::<u8>() {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.alert_banner.font_size = val.clamp(16, 72);
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                        span { class: "value", "{current_settings.alert_banner.font_size}px" }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Use Alert Color" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current_settings.alert_banner.use_alert_color,
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.alert_banner.use_alert_color = e.checked();
+                                update_draft(new_settings);
+                            }
+                        }
+                    }
+
+                    if !current_settings.alert_banner.use_alert_color {
+                        div { class: "setting-row",
+                            label { "Font Color" }
+                            input {
+                                r#type: "color",
+                                value: "{color_to_hex(&current_settings.alert_banner.font_color)}",
+                                class: "color-picker",
+                                oninput: move |e: Event<FormData>| {
+                                    if let Some(color) = parse_hex_color(&e.value()) {
+                                        let mut new_settings = draft_settings();
+                                        new_settings.alert_banner.font_color = color;
+                                        update_draft(new_settings);
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    h4 { style: "margin-top: 16px;", "Timing" }
+
+                    div { class: "setting-row",
+                        label { "Display Duration" }
+                        input {
+                            r#type: "range",
+                            min: "1",
+                            max: "10",
+                            step: "0.5",
+                            value: "{current_settings.alert_banner.duration_secs}",
+                            oninput: move |e| {
+                                if let Ok(val) = e.value().parse::<f32>() {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.alert_banner.duration_secs = val.clamp(1.0, 10.0);
+                                    update_draft(new_settings);
+                                }
+                            }
+                        }
+                        span { class: "value", "{current_settings.alert_banner.duration_secs:.1}s" }
+                    }
+
+                    div { class: "setting-row",
+                        label { "Flash" }
+                        input {
+                            r#type: "checkbox",
+                            checked: current_settings.alert_banner.flash,
+                            onchange: move |e: Event<FormData>| {
+                                let mut new_settings = draft_settings();
+                                new_settings.alert_banner.flash = e.checked();
+                                update_draft(new_settings);
+                            }
+                        }
+                    }
+
+                    div { class: "setting-row reset-row",
+                        button {
+                            class: "btn btn-reset",
+                            onclick: move |_| {
+                                let mut new_settings = draft_settings();
+                                new_settings.alert_banner = AlertBannerConfig::default();
+                                new_settings.alert_banner_opacity = 0;
+                                update_draft(new_settings);
+                            },
+                            i { class: "fa-solid fa-rotate-left" }
+                            span { " Reset to Defaults" }
+                        }
+                    }
+
+                    p { class: "text-muted text-sm", style: "margin-top: 12px;",
+                        i { class: "fa-solid fa-info-circle" }
+                        " Only alerts from timers with High priority show in the banner. Set the priority in the timer editor."
+                    }
+                }
             } else if tab == "raid" {
                 // Raid Settings
                 {
@@ -1452,6 +2162,42 @@ pub fn SettingsPanel(
                                 },
                             }
 
+                            div { class: "setting-row",
+                                label { "Frame Layout" }
+                                select {
+                                    class: "input-inline",
+                                    onchange: move |e: Event<FormData>| {
+                                        let mut new_settings = draft_settings();
+                                        new_settings.raid_overlay.frame_layout = match e.value().as_str() {
+                                            "compact" => RaidFrameLayout::Compact,
+                                            _ => RaidFrameLayout::Standard,
+                                        };
+                                        update_draft(new_settings);
+                                    },
+                                    option { value: "standard", selected: current_settings.raid_overlay.frame_layout == RaidFrameLayout::Standard, "Standard" }
+                                    option { value: "compact", selected: current_settings.raid_overlay.frame_layout == RaidFrameLayout::Compact, "Compact" }
+                                }
+                            }
+                            if current_settings.raid_overlay.frame_layout == RaidFrameLayout::Compact {
+                                div { class: "setting-row",
+                                    label { "Names" }
+                                    select {
+                                        class: "input-inline",
+                                        onchange: move |e: Event<FormData>| {
+                                            let mut new_settings = draft_settings();
+                                            new_settings.raid_overlay.compact_name_style = match e.value().as_str() {
+                                                "truncated" => RaidNameStyle::Truncated,
+                                                _ => RaidNameStyle::Initials,
+                                            };
+                                            update_draft(new_settings);
+                                        },
+                                        option { value: "initials", selected: current_settings.raid_overlay.compact_name_style == RaidNameStyle::Initials, "Initials" }
+                                        option { value: "truncated", selected: current_settings.raid_overlay.compact_name_style == RaidNameStyle::Truncated, "First letters" }
+                                    }
+                                }
+                            }
+                            p { class: "hint", "Compact shows a short name with effects as thin bars, for a minimal 16-player footprint" }
+
                             div { class: "setting-row",
                                 label { "Max Effects per Frame" }
                                 input {
@@ -1532,6 +2278,34 @@ pub fn SettingsPanel(
                             }
                             p { class: "hint", "Display ability icons instead of colored squares (requires icon pack)" }
 
+                            div { class: "setting-row",
+                                label { "Show Health Bars" }
+                                input {
+                                    r#type: "checkbox",
+                                    checked: current_settings.raid_overlay.show_health_bars,
+                                    onchange: move |e: Event<FormData>| {
+                                        let mut new_settings = draft_settings();
+                                        new_settings.raid_overlay.show_health_bars = e.checked();
+                                        update_draft(new_settings);
+                                    }
+                                }
+                            }
+                            p { class: "hint", "Fill each frame by the player's HP from the combat log, with missing health shaded red" }
+
+                            div { class: "setting-row",
+                                label { "Show When Solo" }
+                                input {
+                                    r#type: "checkbox",
+                                    checked: current_settings.raid_overlay.show_when_solo,
+                                    onchange: move |e: Event<FormData>| {
+                                        let mut new_settings = draft_settings();
+                                        new_settings.raid_overlay.show_when_solo = e.checked();
+                                        update_draft(new_settings);
+                                    }
+                                }
+                            }
+                            p { class: "hint", "Otherwise raid frames only appear while you are in a group" }
+
                             div { class: "setting-row reset-row",
                                 button {
                                     class: "btn btn-reset",
@@ -1629,6 +2403,9 @@ pub fn SettingsPanel(
                                 }
                             }
 
+                            h4 { "Counters" }
+                            CounterRules {}
+
                             h4 { "Appearance" }
 
                             OpacitySlider {
@@ -1673,6 +2450,36 @@ pub fn SettingsPanel(
                                 }
                             }
 
+                            NumberFormatRow {
+                                label: "Number Format",
+                                value: Some(current_settings.personal_overlay.number_format),
+                                on_change: move |format: Option<NumberFormat>| {
+                                    let mut new_settings = draft_settings();
+                                    new_settings.personal_overlay.number_format = format.unwrap_or_default();
+                                    update_draft(new_settings);
+                                },
+                            }
+
+                            // Per-stat overrides for the visible amount stats
+                            for stat in current_settings.personal_overlay.visible_stats.iter().copied().filter(|s| s.is_amount()) {
+                                NumberFormatRow {
+                                    key: "{stat.label()}",
+                                    label: stat.label(),
+                                    value: current_settings
+                                        .personal_overlay
+                                        .stat_formats
+                                        .iter()
+                                        .find(|f| f.stat == stat)
+                                        .map(|f| f.format),
+                                    inherits: true,
+                                    on_change: move |format: Option<NumberFormat>| {
+                                        let mut new_settings = draft_settings();
+                                        new_settings.personal_overlay.set_stat_format(stat, format);
+                                        update_draft(new_settings);
+                                    },
+                                }
+                            }
+
                             div { class: "setting-row reset-row",
                                 button {
                                     class: "btn btn-reset",
@@ -1731,6 +2538,66 @@ pub fn SettingsPanel(
                                 }
                             }
 
+                            div { class: "setting-row",
+                                label { "Show Deaths" }
+                                input {
+                                    r#type: "checkbox",
+                                    checked: current_appearance.show_deaths,
+                                    onchange: {
+                                        let tab = tab_key.clone();
+                                        move |e: Event<FormData>| {
+                                            let mut new_settings = draft_settings();
+                                            let default = new_settings.default_appearances.get(&tab).cloned().unwrap_or_default();
+                                            let appearance = new_settings.appearances.entry(tab.clone()).or_insert(default);
+                                            appearance.show_deaths = e.checked();
+                                            update_draft(new_settings);
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Reflected damage is only tracked for the damage meters
+                            if tab_key == "dps" || tab_key == "edps" {
+                                div { class: "setting-row",
+                                    label { "Show Reflect" }
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: current_appearance.show_reflect,
+                                        onchange: {
+                                            let tab = tab_key.clone();
+                                            move |e: Event<FormData>| {
+                                                let mut new_settings = draft_settings();
+                                                let default = new_settings.default_appearances.get(&tab).cloned().unwrap_or_default();
+                                                let appearance = new_settings.appearances.entry(tab.clone()).or_insert(default);
+                                                appearance.show_reflect = e.checked();
+                                                update_draft(new_settings);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if tab_key == "tps" {
+                                div { class: "setting-row",
+                                    label { "Current Target Only" }
+                                    input {
+                                        r#type: "checkbox",
+                                        title: "Threat on your target, or the boss when no enemy is targeted",
+                                        checked: current_appearance.current_target_only,
+                                        onchange: {
+                                            let tab = tab_key.clone();
+                                            move |e: Event<FormData>| {
+                                                let mut new_settings = draft_settings();
+                                                let default = new_settings.default_appearances.get(&tab).cloned().unwrap_or_default();
+                                                let appearance = new_settings.appearances.entry(tab.clone()).or_insert(default);
+                                                appearance.current_target_only = e.checked();
+                                                update_draft(new_settings);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
                             div { class: "setting-row",
                                 label { "Show Header" }
                                 input {
@@ -1789,6 +2656,21 @@ pub fn SettingsPanel(
                                 }
                             }
 
+                            NumberFormatRow {
+                                label: "Number Format",
+                                value: Some(current_appearance.number_format),
+                                on_change: {
+                                    let tab = tab_key.clone();
+                                    move |format: Option<NumberFormat>| {
+                                        let mut new_settings = draft_settings();
+                                        let default = new_settings.default_appearances.get(&tab).cloned().unwrap_or_default();
+                                        let appearance = new_settings.appearances.entry(tab.clone()).or_insert(default);
+                                        appearance.number_format = format.unwrap_or_default();
+                                        update_draft(new_settings);
+                                    }
+                                },
+                            }
+
                             div { class: "setting-row",
                                 label { "Bar Color" }
                                 input {
@@ -1891,6 +2773,66 @@ fn TabButton(label: &'static str, tab_key: &'static str, selected_tab: Signal<St
     }
 }
 
+/// Number style and decimals. With `inherits`, a "Default" style clears the
+/// format to `None` so the overlay-wide one applies.
+#[component]
+fn NumberFormatRow(
+    label: &'static str,
+    value: Option<NumberFormat>,
+    #[props(default = false)] inherits: bool,
+    on_change: EventHandler<Option<NumberFormat>>,
+) -> Element {
+    let current = value.unwrap_or_default();
+    rsx! {
+        div { class: "setting-row",
+            label { "{label}" }
+            select {
+                class: "input-inline",
+                onchange: move |e: Event<FormData>| {
+                    let style = match e.value().as_str() {
+                        "compact" => NumberStyle::Compact,
+                        "full" => NumberStyle::Full,
+                        _ => return on_change.call(None),
+                    };
+                    on_change.call(Some(NumberFormat { style, ..current }));
+                },
+                if inherits {
+                    option { value: "default", selected: value.is_none(), "Default" }
+                }
+                option {
+                    value: "compact",
+                    selected: value.is_some() && current.style == NumberStyle::Compact,
+                    "{NumberStyle::Compact.label()}"
+                }
+                option {
+                    value: "full",
+                    selected: value.is_some() && current.style == NumberStyle::Full,
+                    "{NumberStyle::Full.label()}"
+                }
+            }
+            if value.is_some() {
+                input {
+                    r#type: "number",
+                    class: "input-inline",
+                    title: "Decimals",
+                    min: "0",
+                    max: "{MAX_NUMBER_DECIMALS}",
+                    value: "{current.decimals}",
+                    onchange: move |e: Event<FormData>| {
+                        if let Ok(decimals) = e.value().parse::<u8>() {
+                            on_change.call(Some(NumberFormat {
+                                decimals: decimals.min(MAX_NUMBER_DECIMALS),
+                                ..current
+                            }));
+                        }
+                    }
+                }
+                span { class: "value", "decimals" }
+            }
+        }
+    }
+}
+
 #[component]
 fn OpacitySlider(label: &'static str, value: u8, on_change: EventHandler<u8>) -> Element {
     rsx! {
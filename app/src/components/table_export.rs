@@ -0,0 +1,77 @@
+//! Export buttons for Data Explorer tables
+//!
+//! Writes the table (with the filters currently applied) to a CSV or JSON
+//! file picked in a save dialog.
+
+use dioxus::prelude::*;
+
+use crate::api::{self, ExportFormat, ExportTable};
+use crate::components::{ToastSeverity, use_toast};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TableExportProps {
+    /// Encounter the table belongs to (None for live)
+    pub encounter_key: Option<String>,
+    /// Table and query parameters to export
+    pub table: ExportTable,
+    /// Suggested file name, without extension
+    #[props(into)]
+    pub file_name: String,
+    /// Tooltip naming what gets exported
+    #[props(default = "Export table".to_string(), into)]
+    pub title: String,
+    /// Text shown after the icon, for panels with more than one export
+    #[props(default, into)]
+    pub label: String,
+}
+
+#[component]
+pub fn TableExport(props: TableExportProps) -> Element {
+    let mut busy = use_signal(|| false);
+
+    let export = move |format: ExportFormat| {
+        let key = props.encounter_key.clone();
+        let table = props.table.clone();
+        let file_name = props.file_name.clone();
+        let mut toast = use_toast();
+        spawn(async move {
+            let Some(path) = api::pick_export_path(format, &file_name).await else {
+                return;
+            };
+            busy.set(true);
+            match api::export_breakdown(key.as_deref(), &table, format, &path).await {
+                Ok(rows) => {
+                    toast.show(
+                        format!("Exported {} rows to {}", rows, path),
+                        ToastSeverity::Normal,
+                    );
+                }
+                Err(err) => {
+                    toast.show(format!("Export failed: {}", err), ToastSeverity::Normal);
+                }
+            }
+            busy.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "table-export", title: "{props.title}",
+            i { class: "fa-solid fa-file-export" }
+            if !props.label.is_empty() {
+                span { class: "table-export-label", "{props.label}" }
+            }
+            for format in [ExportFormat::Csv, ExportFormat::Json] {
+                button {
+                    class: "btn btn-ghost btn-sm",
+                    r#type: "button",
+                    disabled: busy(),
+                    onclick: {
+                        let export = export.clone();
+                        move |_| export(format)
+                    },
+                    "{format.label()}"
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,8 @@
 //! Contains types used by the Dioxus frontend, including re-exports from
 //! baras-types and frontend-specific types that mirror backend structures.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -13,26 +15,70 @@ pub use baras_types::{
     // Selectors (unified ID-or-Name matching)
     AbilitySelector,
     // Config types
+    AlertBannerConfig,
     AlertsOverlayConfig,
+    // Operations in the log history checked against definitions
+    AreaCoverageReport,
     AppConfig,
     BossHealthConfig,
+    BossNotesConfig,
     ChallengeColumns,
     ChallengeLayout,
+    CharacterSettings,
     Color,
+    // Structured command errors
+    CommandError,
+    // Counter comparisons (conditions, challenges, triggers)
+    ComparisonOp,
     CooldownTrackerConfig,
+    // Persistent cross-session counters
+    CounterOutcome,
+    CounterRule,
+    CounterValue,
+    // Opt-in definition coverage report
+    CoverageReport,
+    DeathRecapConfig,
     DotTrackerConfig,
     EffectSelector,
     EffectsAConfig,
     EffectsBConfig,
     EntityFilter,
     EntitySelector,
+    // Persistent encounter history
+    HistoricalEncounter,
+    HistoryFilter,
+    IgnoredId,
+    MAX_NUMBER_DECIMALS,
     MAX_PROFILES,
+    // Number formatting of overlay stats
+    NumberFormat,
+    NumberStyle,
     OverlayAppearanceConfig,
+    OverlayRenderer,
     OverlaySettings,
+    // Refresh rates (polling intervals and overlay FPS)
+    PerformancePreset,
+    PerformanceSettings,
     PersonalOverlayConfig,
     PersonalStat,
+    RaidCooldownConfig,
+    RaidFrameLayout,
+    RaidNameStyle,
     RaidOverlaySettings,
+    RaidSlotAssignment,
+    // Session events (backend → frontend)
+    SESSION_UPDATED_EVENT,
+    SessionEvent,
+    // Startup log directory diagnostics
+    SetupCheckStatus,
+    SetupHealth,
+    TankSwapConfig,
+    // Session timeline (read from the signal journal)
+    TimelineEvent,
+    TimelineEventKind,
+    TimerBarDirection,
     TimerOverlayConfig,
+    TimerTextLayout,
     // Trigger type (shared across timers, phases, counters)
     Trigger,
 };
@@ -63,6 +109,54 @@ pub struct SessionInfo {
     pub duration_formatted: Option<String>,
 }
 
+/// Per-subsystem memory estimates from the backend (bytes)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemoryStats {
+    pub interned_strings: usize,
+    pub interner_bytes: usize,
+    pub session_cache_bytes: usize,
+    pub query_context_bytes: usize,
+    pub encounter_buffer_bytes: usize,
+    pub encounter_buffer_rows: usize,
+}
+
+/// Overlay update channel counters from the backend
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OverlayUpdateCounts {
+    pub sent: u64,
+    pub dropped: u64,
+    pub coalesced: u64,
+}
+
+/// A backend command that exceeded the slow-command threshold
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlowCommand {
+    pub command: String,
+    pub args: String,
+    pub duration_ms: u64,
+    pub finished_at: String,
+}
+
+/// Backend command counters and recent slow commands (newest first)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommandTimings {
+    pub total: u64,
+    pub slow: u64,
+    pub threshold_ms: u64,
+    pub recent_slow: Vec<SlowCommand>,
+}
+
+/// An alert that fired recently (mirrors the backend alert history entry)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentAlert {
+    pub id: String,
+    pub name: String,
+    pub text: String,
+    #[serde(default)]
+    pub color: Option<[u8; 4]>,
+    pub fired_at: String,
+}
+
 /// Overlay status response from backend
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverlayStatus {
@@ -90,6 +184,16 @@ pub struct OverlayStatus {
     pub cooldowns_enabled: bool,
     pub dot_tracker_running: bool,
     pub dot_tracker_enabled: bool,
+    pub tank_swap_running: bool,
+    pub tank_swap_enabled: bool,
+    pub raid_cooldowns_running: bool,
+    pub raid_cooldowns_enabled: bool,
+    pub death_recap_running: bool,
+    pub death_recap_enabled: bool,
+    pub boss_notes_running: bool,
+    pub boss_notes_enabled: bool,
+    pub alert_banner_running: bool,
+    pub alert_banner_enabled: bool,
     pub overlays_visible: bool,
     pub move_mode: bool,
     pub rearrange_mode: bool,
@@ -114,6 +218,16 @@ pub struct UpdateInfo {
     pub date: Option<String>,
 }
 
+/// Monitor layout change reported by the backend (`monitors-changed` event)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonitorsChanged {
+    pub monitors: usize,
+    /// Whether the saved positions of the new layout were applied
+    pub layout_switched: bool,
+    /// Config keys of shown overlays whose monitor is gone
+    pub stranded: Vec<String>,
+}
+
 /// Changelog response from backend
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChangelogResponse {
@@ -138,6 +252,7 @@ pub enum MetricType {
     Abs,
     Dtps,
     Tps,
+    Dispels,
 }
 
 impl MetricType {
@@ -152,6 +267,7 @@ impl MetricType {
             MetricType::Tps => "Threat",
             MetricType::Dtps => "Damage Taken",
             MetricType::Abs => "Shielding Given",
+            MetricType::Dispels => "Dispels",
         }
     }
 
@@ -166,6 +282,7 @@ impl MetricType {
             MetricType::Tps => "tps",
             MetricType::Dtps => "dtps",
             MetricType::Abs => "abs",
+            MetricType::Dispels => "dispels",
         }
     }
 
@@ -180,6 +297,7 @@ impl MetricType {
             MetricType::Abs,
             MetricType::Dtps,
             MetricType::Tps,
+            MetricType::Dispels,
         ]
     }
 }
@@ -204,6 +322,11 @@ pub enum OverlayType {
     EffectsB,
     Cooldowns,
     DotTracker,
+    TankSwap,
+    RaidCooldowns,
+    DeathRecap,
+    BossNotes,
+    AlertBanner,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -217,10 +340,15 @@ pub struct AudioConfig {
     #[serde(default)]
     pub enabled: bool,
 
-    /// Audio file to play (relative to sounds directory)
+    /// Audio file to play: a sound name (user sounds directory, then bundled)
+    /// or an absolute path
     #[serde(default)]
     pub file: Option<String>,
 
+    /// Volume for this item's sounds (0-100, None = global volume)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<u8>,
+
     /// Seconds before expiration to play audio (0 = on expiration)
     #[serde(default)]
     pub offset: u8,
@@ -232,6 +360,10 @@ pub struct AudioConfig {
     /// Voice pack for countdown (None = default)
     #[serde(default)]
     pub countdown_voice: Option<String>,
+
+    /// Speak the alert text with text-to-speech instead of playing `file`
+    #[serde(default)]
+    pub speak_alert: bool,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -303,6 +435,30 @@ impl TimerDisplayTarget {
     }
 }
 
+/// How urgently a fired alert is shown (mirrors baras_core::timers::AlertPriority)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertPriority {
+    /// Alerts overlay only
+    #[default]
+    Normal,
+    /// Also flashed in the alert banner
+    High,
+}
+
+impl AlertPriority {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::High => "High (banner)",
+        }
+    }
+
+    pub fn all() -> &'static [AlertPriority] {
+        &[Self::Normal, Self::High]
+    }
+}
+
 /// Timer definition (mirrors baras_core::dsl::BossTimerDefinition)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BossTimerDefinition {
@@ -317,6 +473,8 @@ pub struct BossTimerDefinition {
     pub is_alert: bool,
     #[serde(default)]
     pub alert_text: Option<String>,
+    #[serde(default)]
+    pub alert_priority: AlertPriority,
     #[serde(default = "default_timer_color")]
     pub color: [u8; 4],
     #[serde(default)]
@@ -325,6 +483,8 @@ pub struct BossTimerDefinition {
     pub counter_condition: Option<CounterCondition>,
     #[serde(default)]
     pub difficulties: Vec<String>,
+    #[serde(default)]
+    pub group_size: Option<GroupSizeRange>,
     #[serde(default = "default_enabled")]
     pub enabled: bool,
     #[serde(default)]
@@ -343,6 +503,8 @@ pub struct BossTimerDefinition {
     pub show_at_secs: f32,
     #[serde(default)]
     pub display_target: TimerDisplayTarget,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_cooldown: Option<SuggestedCooldown>,
     #[serde(default)]
     pub audio: AudioConfig,
 }
@@ -507,6 +669,30 @@ impl DisplayTarget {
     }
 }
 
+/// Raid frame priority of an effect (mirrors baras_core::effects::EffectSeverity)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectSeverity {
+    #[default]
+    Low,
+    Medium,
+    Critical,
+}
+
+impl EffectSeverity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::Critical => "Critical",
+        }
+    }
+
+    pub fn all() -> &'static [EffectSeverity] {
+        &[Self::Low, Self::Medium, Self::Critical]
+    }
+}
+
 /// When to trigger an alert for this effect
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -542,6 +728,9 @@ pub struct EffectListItem {
     /// Whether this effect has a user override (vs bundled-only)
     #[serde(default)]
     pub is_user_override: bool,
+    /// Template this effect inherits unset settings from
+    #[serde(default)]
+    pub extends: Option<String>,
 
     // Core
     pub enabled: bool,
@@ -569,6 +758,8 @@ pub struct EffectListItem {
     #[serde(default)]
     pub display_target: DisplayTarget,
     #[serde(default)]
+    pub severity: EffectSeverity,
+    #[serde(default)]
     pub icon_ability_id: Option<u64>,
     #[serde(default = "crate::utils::default_true")]
     pub show_icon: bool,
@@ -607,36 +798,6 @@ pub struct EffectListItem {
 // Encounter Editor Types (Phases, Counters, Challenges, Entities)
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Comparison operators for counter conditions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum ComparisonOp {
-    #[default]
-    Eq,
-    Lt,
-    Gt,
-    Lte,
-    Gte,
-    Ne,
-}
-
-impl ComparisonOp {
-    pub fn label(&self) -> &'static str {
-        match self {
-            Self::Eq => "=",
-            Self::Lt => "<",
-            Self::Gt => ">",
-            Self::Lte => "<=",
-            Self::Gte => ">=",
-            Self::Ne => "!=",
-        }
-    }
-
-    pub fn all() -> &'static [ComparisonOp] {
-        &[Self::Eq, Self::Lt, Self::Gt, Self::Lte, Self::Gte, Self::Ne]
-    }
-}
-
 /// Counter condition for timer/phase guards
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CounterCondition {
@@ -646,6 +807,44 @@ pub struct CounterCondition {
     pub value: u32,
 }
 
+/// Group size guard for timers (mirrors baras_core::timers::GroupSizeRange)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupSizeRange {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<u8>,
+}
+
+impl GroupSizeRange {
+    /// Range matching exactly one group size
+    pub fn exactly(size: u8) -> Self {
+        Self {
+            min: Some(size),
+            max: Some(size),
+        }
+    }
+}
+
+/// Cooldown suggestion (mirrors baras_core::timers::CooldownSuggestion)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CooldownSuggestion {
+    pub ability: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_ability_id: Option<u64>,
+}
+
+/// Suggested cooldown for a timer (mirrors baras_core::timers::SuggestedCooldown)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuggestedCooldown {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ability: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_ability_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub disciplines: BTreeMap<String, CooldownSuggestion>,
+}
+
 /// Challenge metric types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -767,3 +966,15 @@ pub struct NewAreaRequest {
 fn default_area_type() -> String {
     "operation".to_string()
 }
+
+/// Outcome of a StarParse timer import
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StarParseImportSummary {
+    pub timers: usize,
+    /// Existing bosses the timers were added to
+    pub merged_bosses: Vec<String>,
+    /// Bosses with no existing definition, saved to `file_path`
+    pub unmatched_bosses: Vec<String>,
+    pub file_path: Option<String>,
+    pub warnings: Vec<String>,
+}
@@ -0,0 +1,106 @@
+//! Pull comparison
+//!
+//! Compares two encounter parquet files of the same boss: per-player DPS,
+//! phase durations and boss ability hits taken by players, with the change
+//! from pull A to pull B.
+
+use std::path::PathBuf;
+
+use baras_core::query::{PullComparison, QueryContext};
+
+use crate::meters::format_number;
+
+/// Options for the `compare` command
+pub struct CompareOptions {
+    pub a: PathBuf,
+    pub b: PathBuf,
+}
+
+pub async fn run(options: CompareOptions) -> Result<(), String> {
+    for path in [&options.a, &options.b] {
+        if !path.is_file() {
+            return Err(format!("{} not found", path.display()));
+        }
+    }
+
+    let context = QueryContext::new();
+    let comparison = context
+        .compare_pulls(&options.a, &options.b)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    print!("{}", format_comparison(&comparison));
+    Ok(())
+}
+
+fn format_secs(secs: Option<f32>) -> String {
+    secs.map(|s| format!("{s:.1}s"))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_comparison(c: &PullComparison) -> String {
+    let mut out = format!(
+        "Duration: {:.1}s -> {:.1}s ({:+.1}s)\n\n",
+        c.duration_a,
+        c.duration_b,
+        c.duration_b - c.duration_a
+    );
+
+    out.push_str(&format!(
+        "{:<24} {:>9} {:>9} {:>9} {:>7}\n",
+        "Player", "DPS A", "DPS B", "Change", "Deaths"
+    ));
+    for p in &c.players {
+        out.push_str(&format!(
+            "{:<24} {:>9} {:>9} {:>9} {:>7}\n",
+            p.name,
+            p.dps_a
+                .map(|d| format_number(d as i64))
+                .unwrap_or_else(|| "-".into()),
+            p.dps_b
+                .map(|d| format_number(d as i64))
+                .unwrap_or_else(|| "-".into()),
+            format_number(p.dps_delta() as i64),
+            format!("{}/{}", p.deaths_a, p.deaths_b),
+        ));
+    }
+
+    if !c.phases.is_empty() {
+        out.push_str(&format!(
+            "\n{:<28} {:>9} {:>9} {:>9}\n",
+            "Phase", "A", "B", "Change"
+        ));
+        for phase in &c.phases {
+            let change = match (phase.duration_a, phase.duration_b) {
+                (Some(a), Some(b)) => format!("{:+.1}s", b - a),
+                _ => "-".to_string(),
+            };
+            out.push_str(&format!(
+                "{:<28} {:>9} {:>9} {:>9}\n",
+                format!("{} #{}", phase.phase_name, phase.instance),
+                format_secs(phase.duration_a),
+                format_secs(phase.duration_b),
+                change,
+            ));
+        }
+    }
+
+    if !c.mechanics.is_empty() {
+        out.push_str(&format!(
+            "\n{:<32} {:>6} {:>6} {:>10} {:>10}\n",
+            "Boss ability", "Hits A", "Hits B", "Damage A", "Damage B"
+        ));
+        for m in &c.mechanics {
+            out.push_str(&format!(
+                "{:<32} {:>6} {:>6} {:>10} {:>10}\n",
+                m.ability_name,
+                m.hits_a,
+                m.hits_b,
+                format_number(m.damage_a as i64),
+                format_number(m.damage_b as i64),
+            ));
+        }
+    }
+
+    out
+}
@@ -0,0 +1,164 @@
+//! Encounter export
+//!
+//! Parses a complete log and writes the encounter summaries the app shows in
+//! its history view, including per-player metrics.
+//! - JSON: one document with every encounter and its nested player metrics
+//! - CSV: one row per player per encounter, encounter columns repeated
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use tokio::sync::RwLock;
+
+use baras_core::EncounterSummary;
+use baras_core::context::parse_file;
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+/// Options for the `export` command
+pub struct ExportOptions {
+    pub log: PathBuf,
+    pub format: ExportFormat,
+    /// Output file; stdout when `None`
+    pub output: Option<PathBuf>,
+}
+
+/// JSON document layout
+#[derive(Serialize)]
+struct ExportDocument<'a> {
+    file: String,
+    encounters: &'a [EncounterSummary],
+}
+
+pub async fn run(options: ExportOptions) -> Result<(), String> {
+    let encounters = parse_encounters(&options.log).await?;
+
+    let mut out: Box<dyn Write> = match &options.output {
+        Some(path) => Box::new(std::io::BufWriter::new(
+            std::fs::File::create(path)
+                .map_err(|e| format!("failed to create {}: {}", path.display(), e))?,
+        )),
+        None => Box::new(std::io::stdout().lock()),
+    };
+
+    match options.format {
+        ExportFormat::Json => {
+            let doc = ExportDocument {
+                file: options.log.display().to_string(),
+                encounters: &encounters,
+            };
+            serde_json::to_writer_pretty(&mut out, &doc).map_err(|e| e.to_string())?;
+            writeln!(out).map_err(|e| e.to_string())?;
+        }
+        ExportFormat::Csv => write_csv(&mut out, &encounters).map_err(|e| e.to_string())?,
+    }
+
+    out.flush().map_err(|e| e.to_string())
+}
+
+/// Parse a whole log and return its encounter summaries
+pub async fn parse_encounters(path: &Path) -> Result<Vec<EncounterSummary>, String> {
+    let session = Arc::new(RwLock::new(crate::open_session(path)));
+    parse_file(session.clone()).await?;
+
+    let mut session = session.write().await;
+    session.finalize_session();
+    Ok(session
+        .session_cache
+        .as_ref()
+        .map(|cache| cache.encounter_history.summaries().to_vec())
+        .unwrap_or_default())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CSV
+// ─────────────────────────────────────────────────────────────────────────────
+
+const CSV_HEADER: &[&str] = &[
+    "encounter_id",
+    "encounter",
+    "area",
+    "difficulty",
+    "boss",
+    "success",
+    "start_time",
+    "duration_seconds",
+    "player",
+    "discipline",
+    "dps",
+    "edps",
+    "bossdps",
+    "total_damage",
+    "hps",
+    "ehps",
+    "total_healing",
+    "dtps",
+    "total_damage_taken",
+    "tps",
+    "abs",
+    "apm",
+];
+
+fn write_csv(out: &mut dyn Write, encounters: &[EncounterSummary]) -> std::io::Result<()> {
+    writeln!(out, "{}", CSV_HEADER.join(","))?;
+
+    for enc in encounters {
+        for m in &enc.player_metrics {
+            let fields = [
+                enc.encounter_id.to_string(),
+                csv_field(&enc.display_name),
+                csv_field(&enc.area_name),
+                csv_field(enc.difficulty.as_deref().unwrap_or_default()),
+                csv_field(enc.boss_name.as_deref().unwrap_or_default()),
+                enc.success.to_string(),
+                csv_field(enc.start_time.as_deref().unwrap_or_default()),
+                enc.duration_seconds.to_string(),
+                csv_field(&m.name),
+                csv_field(m.discipline_name.as_deref().unwrap_or_default()),
+                m.dps.to_string(),
+                m.edps.to_string(),
+                m.bossdps.to_string(),
+                m.total_damage.to_string(),
+                m.hps.to_string(),
+                m.ehps.to_string(),
+                m.total_healing.to_string(),
+                m.dtps.to_string(),
+                m.total_damage_taken.to_string(),
+                m.tps.to_string(),
+                m.abs.to_string(),
+                format!("{:.1}", m.apm),
+            ];
+            writeln!(out, "{}", fields.join(","))?;
+        }
+    }
+    Ok(())
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or newline (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("Dread Master Bestia"), "Dread Master Bestia");
+        assert_eq!(csv_field("Bestia - 1, Veteran"), "\"Bestia - 1, Veteran\"");
+        assert_eq!(csv_field("The \"Boss\""), "\"The \"\"Boss\"\"\"");
+    }
+}
@@ -0,0 +1,44 @@
+//! Game ID extraction
+//!
+//! Collects the named NPC, ability and effect IDs from combat logs into the
+//! CSV database bundled with the app (`core/definitions/game_ids.csv`), which
+//! backs the encounter editor's ID pick-lists. IDs already in the output file
+//! are kept, so logs can be added over several runs.
+
+use std::path::PathBuf;
+
+use baras_core::game_data::GameIdDatabase;
+
+/// Options for the `extract-ids` command
+pub struct ExtractIdsOptions {
+    pub logs: Vec<PathBuf>,
+    pub output: PathBuf,
+}
+
+pub fn run(options: ExtractIdsOptions) -> Result<(), String> {
+    let mut db = GameIdDatabase::new();
+    if options.output.exists() {
+        let existing = std::fs::read_to_string(&options.output)
+            .map_err(|e| format!("failed to read {}: {}", options.output.display(), e))?;
+        db.add_csv(&existing);
+    }
+    let before = db.len();
+
+    for log in &options.logs {
+        let bytes =
+            std::fs::read(log).map_err(|e| format!("failed to read {}: {}", log.display(), e))?;
+        let date = crate::session_date(log).unwrap_or_default();
+        db.record_log(&bytes, date);
+        eprintln!("{}: {} IDs", log.display(), db.len());
+    }
+
+    std::fs::write(&options.output, db.to_csv())
+        .map_err(|e| format!("failed to write {}: {}", options.output.display(), e))?;
+    eprintln!(
+        "Wrote {} IDs ({} new) to {}",
+        db.len(),
+        db.len() - before,
+        options.output.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,293 @@
+//! Command-line interface for BARAS
+//!
+//! Headless access to the core parsing pipeline, for machines without a
+//! display or for scripting:
+//! - `tail`: follow the newest combat log and render live meters in the terminal
+//! - `export`: write encounter summaries and player metrics as JSON or CSV
+//! - `reparse-all`: run the parse-worker over every log in a directory
+//! - `query`: run SQL or canned reports against an encounter parquet file
+//! - `compare`: diff two pulls of the same boss (DPS, phases, boss ability hits)
+//! - `extract-ids`: collect named NPC, ability and effect IDs from logs
+//! - `import-starparse`: convert StarParse timers (XML) to boss definition TOML
+//! - `shell`: interactive prompt for running the commands above
+
+mod compare;
+mod export;
+mod ids;
+mod meters;
+mod query;
+mod reparse;
+mod shell;
+mod starparse;
+mod tail;
+
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, Subcommand};
+
+use chrono::NaiveDateTime;
+
+use baras_core::context::{
+    AppConfig, AppConfigExt, DirectoryIndex, ParsingSession, parse_log_filename,
+};
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// CLI Arguments
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[derive(Parser, Debug)]
+#[command(name = "baras-cli")]
+#[command(about = "Combat log parser for Star Wars: The Old Republic")]
+#[command(version)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Follow the newest combat log and show live DPS/HPS meters
+    Tail {
+        /// Log directory to watch (defaults to the directory in the app config)
+        #[arg(short, long)]
+        dir: Option<PathBuf>,
+
+        /// Tail this file instead of the newest log in the directory
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+
+        /// Meter refresh interval in milliseconds
+        #[arg(long, default_value_t = 500)]
+        refresh_ms: u64,
+
+        /// Number of players to show
+        #[arg(short = 'n', long, default_value_t = 16)]
+        rows: usize,
+    },
+
+    /// Parse a log and export encounter summaries with per-player metrics
+    Export {
+        /// Path to combat log file
+        log: PathBuf,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = export::ExportFormat::Json)]
+        format: export::ExportFormat,
+
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Re-parse every log in a directory into encounter parquet files
+    ReparseAll {
+        /// Log directory (defaults to the directory in the app config)
+        #[arg(short, long)]
+        dir: Option<PathBuf>,
+
+        /// Directory to write parquet output to (one subdirectory per log)
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Maximum number of logs parsed at once (defaults to CPU count)
+        #[arg(short, long)]
+        jobs: Option<usize>,
+
+        /// Path to the baras-parse-worker binary
+        #[arg(long)]
+        worker: Option<PathBuf>,
+
+        /// Boss definitions directory, for phase detection
+        #[arg(long)]
+        definitions: Option<PathBuf>,
+    },
+
+    /// Run SQL against an encounter parquet file (table name: `events`)
+    Query {
+        /// Encounter parquet file (e.g. from `reparse-all`)
+        #[arg(short, long)]
+        encounter: PathBuf,
+
+        /// SQL query to run
+        sql: Option<String>,
+
+        /// Run a canned report instead of SQL
+        #[arg(short, long, value_enum, conflicts_with = "sql")]
+        canned: Option<query::CannedQuery>,
+
+        /// Data tab for the breakdown report
+        #[arg(long, value_enum, default_value_t = query::BreakdownTab::Damage)]
+        tab: query::BreakdownTab,
+
+        /// Restrict the breakdown report to one player
+        #[arg(short, long)]
+        player: Option<String>,
+    },
+
+    /// Compare two pulls of the same boss (encounter parquet files)
+    Compare {
+        /// First pull (the baseline)
+        a: PathBuf,
+
+        /// Second pull
+        b: PathBuf,
+    },
+
+    /// Collect named NPC, ability and effect IDs from logs into a CSV database
+    ExtractIds {
+        /// Combat log files to read
+        #[arg(required = true)]
+        logs: Vec<PathBuf>,
+
+        /// CSV database to write (IDs already in it are kept)
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Convert a StarParse timer export (XML) into boss definition TOML
+    ImportStarparse {
+        /// StarParse timer export
+        xml: PathBuf,
+
+        /// TOML file to write (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Interactive shell with history, completion and aliases
+    Shell,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Entry Point
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[tokio::main]
+async fn main() {
+    let args = Args::parse();
+
+    let result = match args.command {
+        Command::Shell => shell::run().await,
+        command => execute(command).await,
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Run a single (non-interactive) command
+async fn execute(command: Command) -> Result<(), String> {
+    match command {
+        Command::Tail {
+            dir,
+            file,
+            refresh_ms,
+            rows,
+        } => {
+            let options = tail::TailOptions {
+                dir: dir.unwrap_or_else(configured_log_directory),
+                file,
+                refresh: std::time::Duration::from_millis(refresh_ms.max(50)),
+                rows,
+            };
+            tail::run(options).await
+        }
+        Command::Export {
+            log,
+            format,
+            output,
+        } => {
+            export::run(export::ExportOptions {
+                log,
+                format,
+                output,
+            })
+            .await
+        }
+        Command::ReparseAll {
+            dir,
+            output,
+            jobs,
+            worker,
+            definitions,
+        } => {
+            let jobs = jobs.unwrap_or_else(|| {
+                std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1)
+            });
+            reparse::run(reparse::ReparseOptions {
+                dir: dir.unwrap_or_else(configured_log_directory),
+                output,
+                jobs,
+                worker,
+                definitions,
+            })
+            .await
+        }
+        Command::Query {
+            encounter,
+            sql,
+            canned,
+            tab,
+            player,
+        } => {
+            query::run(query::QueryOptions {
+                encounter,
+                sql,
+                canned,
+                tab,
+                player,
+            })
+            .await
+        }
+        Command::Compare { a, b } => compare::run(compare::CompareOptions { a, b }).await,
+        Command::ExtractIds { logs, output } => ids::run(ids::ExtractIdsOptions { logs, output }),
+        Command::ImportStarparse { xml, output } => {
+            starparse::run(starparse::ImportStarParseOptions { xml, output })
+        }
+        Command::Shell => Err("already in the interactive shell".to_string()),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Shared Helpers
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Log directory from the app config (same file the GUI uses)
+fn configured_log_directory() -> PathBuf {
+    PathBuf::from(AppConfig::load().log_directory)
+}
+
+/// Newest combat log in a directory
+fn newest_log(dir: &Path) -> Result<PathBuf, String> {
+    let index = DirectoryIndex::build_index(dir)
+        .map_err(|e| format!("failed to index {}: {}", dir.display(), e))?;
+    index
+        .newest_file()
+        .map(|f| f.path.clone())
+        .ok_or_else(|| format!("no combat logs found in {}", dir.display()))
+}
+
+/// Create a session for parsing a log file (no effect/timer tracking)
+fn open_session(path: &Path) -> ParsingSession {
+    let mut session = ParsingSession::historical();
+    session.active_file = Some(path.to_path_buf());
+    session.game_session_date = session_date(path);
+    session
+}
+
+/// Session date from the log file name, falling back to the file's
+/// modification time for logs that were renamed
+fn session_date(path: &Path) -> Option<NaiveDateTime> {
+    path.file_name()
+        .and_then(|f| f.to_str())
+        .and_then(parse_log_filename)
+        .map(|(_, dt)| dt)
+        .or_else(|| {
+            let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+            Some(chrono::DateTime::<chrono::Local>::from(modified).naive_local())
+        })
+}
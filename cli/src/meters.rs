@@ -0,0 +1,118 @@
+//! Terminal meter table
+//!
+//! Plain-text rendering of per-player metrics for the current encounter.
+
+use std::fmt::Write;
+
+use baras_core::PlayerMetrics;
+use baras_core::combat_log::EntityType;
+use baras_core::encounter::EncounterState;
+use baras_core::state::SessionCache;
+
+/// Snapshot of the most recent encounter for display
+pub struct MeterSnapshot {
+    pub duration_secs: i64,
+    pub in_combat: bool,
+    pub players: Vec<PlayerMetrics>,
+}
+
+impl MeterSnapshot {
+    /// Capture metrics for the last encounter that saw combat
+    pub fn capture(cache: &SessionCache) -> Option<Self> {
+        let encounter = cache.last_combat_encounter()?;
+        let mut players: Vec<PlayerMetrics> = encounter
+            .calculate_entity_metrics(&cache.player_disciplines)?
+            .into_iter()
+            .filter(|m| m.entity_type != EntityType::Npc)
+            .map(|m| m.to_player_metrics())
+            .collect();
+        players.sort_by_key(|m| std::cmp::Reverse(m.dps));
+
+        Some(Self {
+            duration_secs: encounter.duration_seconds().unwrap_or(0),
+            in_combat: encounter.state == EncounterState::InCombat,
+            players,
+        })
+    }
+}
+
+/// Render the meter table, limited to `rows` players
+pub fn render(snapshot: &MeterSnapshot, rows: usize) -> String {
+    let mut out = String::new();
+
+    let status = if snapshot.in_combat {
+        "IN COMBAT"
+    } else {
+        "out of combat"
+    };
+    let _ = writeln!(
+        out,
+        "{} ({}:{:02})",
+        status,
+        snapshot.duration_secs / 60,
+        snapshot.duration_secs % 60
+    );
+    let _ = writeln!(
+        out,
+        "{:<24} {:>9} {:>10} {:>9} {:>10} {:>8}",
+        "Player", "DPS", "Damage", "HPS", "Healing", "DTPS"
+    );
+    let _ = writeln!(out, "{}", "─".repeat(75));
+
+    for m in snapshot.players.iter().take(rows) {
+        let _ = writeln!(
+            out,
+            "{:<24} {:>9} {:>10} {:>9} {:>10} {:>8}",
+            truncate(&m.name, 24),
+            format_number(m.dps),
+            format_number(m.total_damage),
+            format_number(m.hps),
+            format_number(m.total_healing),
+            format_number(m.dtps),
+        );
+    }
+
+    out
+}
+
+/// Compact number formatting (1.2K, 3.4M)
+pub fn format_number(n: i64) -> String {
+    let abs = n.unsigned_abs() as f64;
+    let sign = if n < 0 { "-" } else { "" };
+    if abs >= 1_000_000.0 {
+        format!("{}{:.2}M", sign, abs / 1_000_000.0)
+    } else if abs >= 10_000.0 {
+        format!("{}{:.1}K", sign, abs / 1_000.0)
+    } else {
+        n.to_string()
+    }
+}
+
+fn truncate(name: &str, max: usize) -> String {
+    if name.chars().count() <= max {
+        name.to_string()
+    } else {
+        let mut s: String = name.chars().take(max - 1).collect();
+        s.push('…');
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(950), "950");
+        assert_eq!(format_number(12_345), "12.3K");
+        assert_eq!(format_number(2_500_000), "2.50M");
+        assert_eq!(format_number(-12_345), "-12.3K");
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("Short", 24), "Short");
+        assert_eq!(truncate("Abcdefgh", 5), "Abcd…");
+    }
+}
@@ -0,0 +1,138 @@
+//! Encounter queries
+//!
+//! Runs SQL against a single encounter parquet file through the same
+//! `QueryContext` the app's data explorer uses. The file is registered as the
+//! `events` table. Canned reports cover the common cases without writing SQL.
+
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+
+use baras_core::query::{BreakdownMode, DataTab, QueryContext, format_batches};
+
+use crate::meters::format_number;
+
+/// Canned report types
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CannedQuery {
+    /// Per-player damage, healing, threat and damage taken
+    Overview,
+    /// Per-ability breakdown (optionally for one player)
+    Breakdown,
+}
+
+/// Data tab for breakdown reports
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum BreakdownTab {
+    #[default]
+    Damage,
+    Healing,
+    DamageTaken,
+    HealingTaken,
+}
+
+impl From<BreakdownTab> for DataTab {
+    fn from(tab: BreakdownTab) -> Self {
+        match tab {
+            BreakdownTab::Damage => DataTab::Damage,
+            BreakdownTab::Healing => DataTab::Healing,
+            BreakdownTab::DamageTaken => DataTab::DamageTaken,
+            BreakdownTab::HealingTaken => DataTab::HealingTaken,
+        }
+    }
+}
+
+/// Options for the `query` command
+pub struct QueryOptions {
+    pub encounter: PathBuf,
+    pub sql: Option<String>,
+    pub canned: Option<CannedQuery>,
+    pub tab: BreakdownTab,
+    pub player: Option<String>,
+}
+
+pub async fn run(options: QueryOptions) -> Result<(), String> {
+    if !options.encounter.is_file() {
+        return Err(format!("{} not found", options.encounter.display()));
+    }
+
+    let context = QueryContext::new();
+    context
+        .register_parquet(&options.encounter)
+        .await
+        .map_err(|e| e.to_string())?;
+    let guard = context.query().await;
+    let query = guard.query();
+
+    let output = match (&options.sql, options.canned) {
+        (Some(sql), _) => {
+            let batches = query.execute_sql(sql).await.map_err(|e| e.to_string())?;
+            format_batches(&batches).map_err(|e| e.to_string())? + "\n"
+        }
+        (None, Some(CannedQuery::Overview)) => {
+            let duration = query
+                .encounter_timeline()
+                .await
+                .map_err(|e| e.to_string())?
+                .duration_secs;
+            let rows = query
+                .query_raid_overview(None, Some(duration))
+                .await
+                .map_err(|e| e.to_string())?;
+            let mut out = format!(
+                "{:<24} {:>9} {:>9} {:>9} {:>9} {:>9}\n",
+                "Name", "DPS", "HPS", "EHPS", "DTPS", "TPS"
+            );
+            for row in rows {
+                out.push_str(&format!(
+                    "{:<24} {:>9} {:>9} {:>9} {:>9} {:>9}\n",
+                    row.name,
+                    format_number(row.dps as i64),
+                    format_number(row.hps as i64),
+                    format_number(row.ehps as i64),
+                    format_number(row.dtps as i64),
+                    format_number(row.tps as i64),
+                ));
+            }
+            out
+        }
+        (None, Some(CannedQuery::Breakdown)) => {
+            let duration = query
+                .encounter_timeline()
+                .await
+                .map_err(|e| e.to_string())?
+                .duration_secs;
+            let rows = query
+                .query_breakdown(
+                    options.tab.into(),
+                    options.player.as_deref(),
+                    None,
+                    None,
+                    Some(&BreakdownMode::ability_only()),
+                    Some(duration),
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+            let mut out = format!(
+                "{:<32} {:>10} {:>9} {:>6} {:>7} {:>9}\n",
+                "Ability", "Total", "Per sec", "Hits", "Crit %", "Share %"
+            );
+            for row in rows {
+                out.push_str(&format!(
+                    "{:<32} {:>10} {:>9} {:>6} {:>7.1} {:>9.1}\n",
+                    row.ability_name,
+                    format_number(row.total_value as i64),
+                    format_number(row.dps as i64),
+                    row.hit_count,
+                    row.crit_rate,
+                    row.percent_of_total,
+                ));
+            }
+            out
+        }
+        (None, None) => return Err("provide a SQL query or --canned report".to_string()),
+    };
+
+    print!("{}", output);
+    Ok(())
+}
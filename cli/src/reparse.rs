@@ -0,0 +1,168 @@
+//! Batch re-parse
+//!
+//! Runs the parse-worker subprocess (the same pipeline the app uses when a log
+//! is opened) over every non-empty log in a directory, writing each log's
+//! encounter parquet files to `<output>/<log file stem>/`. Files are parsed in
+//! parallel, bounded by a job limit; progress is reported on stderr.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde::Deserialize;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use baras_core::context::DirectoryIndex;
+
+/// Options for the `reparse-all` command
+pub struct ReparseOptions {
+    pub dir: PathBuf,
+    pub output: PathBuf,
+    pub jobs: usize,
+    /// Explicit parse-worker binary (otherwise found next to this executable or on PATH)
+    pub worker: Option<PathBuf>,
+    /// Bundled boss definitions directory, for phase detection
+    pub definitions: Option<PathBuf>,
+}
+
+/// The subset of parse-worker output used for progress and stats
+#[derive(Deserialize)]
+struct WorkerOutput {
+    event_count: usize,
+    encounter_count: usize,
+    elapsed_ms: u128,
+}
+
+/// Result of parsing one file
+struct FileResult {
+    name: String,
+    outcome: Result<WorkerOutput, String>,
+}
+
+pub async fn run(options: ReparseOptions) -> Result<(), String> {
+    let index = DirectoryIndex::build_index(&options.dir)
+        .map_err(|e| format!("failed to index {}: {}", options.dir.display(), e))?;
+    let files: Vec<PathBuf> = index
+        .entries()
+        .into_iter()
+        .filter(|f| !f.is_empty)
+        .map(|f| f.path.clone())
+        .collect();
+
+    if files.is_empty() {
+        return Err(format!("no combat logs found in {}", options.dir.display()));
+    }
+
+    std::fs::create_dir_all(&options.output)
+        .map_err(|e| format!("failed to create {}: {}", options.output.display(), e))?;
+
+    let worker = options.worker.clone().unwrap_or_else(find_worker);
+    let jobs = options.jobs.max(1);
+    let total = files.len();
+    eprintln!(
+        "Re-parsing {} logs from {} ({} jobs)",
+        total,
+        options.dir.display(),
+        jobs
+    );
+
+    let started = Instant::now();
+    let limit = Arc::new(Semaphore::new(jobs));
+    let mut tasks = JoinSet::new();
+
+    for path in files {
+        let limit = limit.clone();
+        let worker = worker.clone();
+        let output = options.output.clone();
+        let definitions = options.definitions.clone();
+        tasks.spawn(async move {
+            let _permit = limit.acquire_owned().await;
+            parse_one(&worker, &path, &output, definitions.as_deref()).await
+        });
+    }
+
+    let mut done = 0;
+    let mut failed = 0;
+    let mut events = 0;
+    let mut encounters = 0;
+
+    while let Some(joined) = tasks.join_next().await {
+        let result = joined.map_err(|e| e.to_string())?;
+        done += 1;
+        match result.outcome {
+            Ok(out) => {
+                events += out.event_count;
+                encounters += out.encounter_count;
+                eprintln!(
+                    "[{}/{}] {}: {} encounters, {} events ({} ms)",
+                    done, total, result.name, out.encounter_count, out.event_count, out.elapsed_ms
+                );
+            }
+            Err(e) => {
+                failed += 1;
+                eprintln!("[{}/{}] {}: FAILED - {}", done, total, result.name, e);
+            }
+        }
+    }
+
+    eprintln!();
+    eprintln!(
+        "Parsed {} of {} logs in {:.1}s: {} encounters, {} events",
+        total - failed,
+        total,
+        started.elapsed().as_secs_f64(),
+        encounters,
+        events
+    );
+    eprintln!("Output: {}", options.output.display());
+
+    if failed > 0 {
+        return Err(format!("{} logs failed to parse", failed));
+    }
+    Ok(())
+}
+
+/// Run the parse worker on a single file
+async fn parse_one(
+    worker: &Path,
+    path: &Path,
+    output: &Path,
+    definitions: Option<&Path>,
+) -> FileResult {
+    let name = path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+    let session_id = path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+
+    let mut cmd = tokio::process::Command::new(worker);
+    cmd.arg(path).arg(&session_id).arg(output.join(&session_id));
+    if let Some(dir) = definitions {
+        cmd.arg(dir);
+    }
+
+    let outcome = match cmd.output().await {
+        Ok(out) if out.status.success() => serde_json::from_slice::<WorkerOutput>(&out.stdout)
+            .map_err(|e| format!("invalid worker output: {}", e)),
+        Ok(out) => Err(format!("worker exited with {}", out.status)),
+        Err(e) => Err(format!("failed to run {}: {}", worker.display(), e)),
+    };
+
+    FileResult { name, outcome }
+}
+
+/// Locate the parse-worker binary: next to this executable, then PATH
+fn find_worker() -> PathBuf {
+    let exe_name = format!("baras-parse-worker{}", std::env::consts::EXE_SUFFIX);
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.join(&exe_name)))
+        .filter(|p| p.exists())
+        .unwrap_or_else(|| PathBuf::from(exe_name))
+}
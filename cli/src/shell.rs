@@ -0,0 +1,337 @@
+//! Interactive shell
+//!
+//! A readline prompt that runs the regular subcommands without retyping the
+//! binary name. Lines are split with shell quoting rules and parsed by the
+//! same clap definitions as the command line.
+//!
+//! - History persists to `<config dir>/baras/cli_history.txt`
+//! - Tab completes subcommands, aliases and file paths
+//! - Aliases persist to `<config dir>/baras/cli_aliases.toml`
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use clap::{CommandFactory, Parser};
+use rustyline::completion::{Completer, FilenameCompleter, Pair};
+use rustyline::config::Configurer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use serde::{Deserialize, Serialize};
+
+use crate::Args;
+
+const HISTORY_FILE: &str = "cli_history.txt";
+const ALIASES_FILE: &str = "cli_aliases.toml";
+const MAX_HISTORY: usize = 1000;
+
+/// Commands handled by the shell itself rather than clap
+const BUILTINS: &[&str] = &["alias", "unalias", "help", "exit", "quit"];
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Aliases
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// User-defined command aliases (`name = "expansion"`)
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Aliases {
+    #[serde(default)]
+    aliases: BTreeMap<String, String>,
+}
+
+impl Aliases {
+    fn load() -> Self {
+        config_file(ALIASES_FILE)
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let path = config_file(ALIASES_FILE).ok_or("config directory not available")?;
+        let contents = toml::to_string(self).map_err(|e| e.to_string())?;
+        std::fs::write(&path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Replace a leading alias with its expansion (not recursive)
+    fn expand(&self, words: Vec<String>) -> Result<Vec<String>, String> {
+        let Some(expansion) = words.first().and_then(|w| self.aliases.get(w)) else {
+            return Ok(words);
+        };
+        let mut expanded =
+            shlex::split(expansion).ok_or_else(|| format!("invalid alias: {}", expansion))?;
+        expanded.extend(words.into_iter().skip(1));
+        Ok(expanded)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Completion
+// ─────────────────────────────────────────────────────────────────────────────
+
+struct ShellHelper {
+    commands: Vec<String>,
+    files: FilenameCompleter,
+}
+
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let before = &line[..pos];
+
+        // First word: subcommands, builtins and aliases
+        if !before.contains(char::is_whitespace) {
+            let matches = self
+                .commands
+                .iter()
+                .filter(|c| c.starts_with(before))
+                .map(|c| Pair {
+                    display: c.clone(),
+                    replacement: format!("{} ", c),
+                })
+                .collect();
+            return Ok((0, matches));
+        }
+
+        self.files.complete(line, pos, ctx)
+    }
+}
+
+impl Helper for ShellHelper {}
+impl Highlighter for ShellHelper {}
+impl Validator for ShellHelper {}
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl ShellHelper {
+    fn new(aliases: &Aliases) -> Self {
+        let mut commands: Vec<String> = Args::command()
+            .get_subcommands()
+            .map(|c| c.get_name().to_string())
+            .filter(|name| name != "shell")
+            .chain(BUILTINS.iter().map(|b| b.to_string()))
+            .chain(aliases.aliases.keys().cloned())
+            .collect();
+        commands.sort();
+        commands.dedup();
+        Self {
+            commands,
+            files: FilenameCompleter::new(),
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Loop
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn run() -> Result<(), String> {
+    let mut aliases = Aliases::load();
+    let mut editor: Editor<ShellHelper, DefaultHistory> =
+        Editor::new().map_err(|e| e.to_string())?;
+    editor.set_helper(Some(ShellHelper::new(&aliases)));
+    let _ = editor.set_max_history_size(MAX_HISTORY);
+
+    let history = config_file(HISTORY_FILE);
+    if let Some(path) = &history {
+        let _ = editor.load_history(path);
+    }
+
+    println!("BARAS shell - type 'help' for commands, 'exit' to quit");
+
+    loop {
+        let line = match tokio::task::block_in_place(|| editor.readline("baras> ")) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let _ = editor.add_history_entry(trimmed);
+
+        let words = match split_line(trimmed) {
+            Ok(Some(words)) => words,
+            Ok(None) => continue,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                continue;
+            }
+        };
+
+        match words[0].as_str() {
+            "exit" | "quit" => break,
+            "help" => print_help(&aliases),
+            "alias" => {
+                if let Err(e) = define_alias(&mut aliases, trimmed) {
+                    eprintln!("Error: {}", e);
+                }
+                editor.set_helper(Some(ShellHelper::new(&aliases)));
+            }
+            "unalias" => {
+                for name in &words[1..] {
+                    if aliases.aliases.remove(name).is_none() {
+                        eprintln!("No alias named '{}'", name);
+                    }
+                }
+                if let Err(e) = aliases.save() {
+                    eprintln!("Error: {}", e);
+                }
+                editor.set_helper(Some(ShellHelper::new(&aliases)));
+            }
+            _ => run_command(&aliases, words).await,
+        }
+    }
+
+    if let Some(path) = &history {
+        let _ = editor.save_history(path);
+    }
+    Ok(())
+}
+
+/// Split an input line into words. `Ok(None)` when there is nothing to run
+/// (the line is only a `#` comment).
+fn split_line(line: &str) -> Result<Option<Vec<String>>, &'static str> {
+    let words = shlex::split(line).ok_or("unbalanced quotes")?;
+    Ok((!words.is_empty()).then_some(words))
+}
+
+/// Expand aliases, parse with clap and execute
+async fn run_command(aliases: &Aliases, words: Vec<String>) {
+    let words = match aliases.expand(words) {
+        Ok(words) => words,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return;
+        }
+    };
+
+    let argv = std::iter::once("baras-cli".to_string()).chain(words);
+    match Args::try_parse_from(argv) {
+        Ok(args) => {
+            if let Err(e) = crate::execute(args.command).await {
+                eprintln!("Error: {}", e);
+            }
+        }
+        // Includes --help output, which clap reports as an "error"
+        Err(e) => {
+            let _ = e.print();
+        }
+    }
+}
+
+/// `alias` lists aliases; `alias name = expansion` defines one
+fn define_alias(aliases: &mut Aliases, line: &str) -> Result<(), String> {
+    let rest = line.trim_start_matches("alias").trim();
+    if rest.is_empty() {
+        for (name, expansion) in &aliases.aliases {
+            println!("{} = {}", name, expansion);
+        }
+        return Ok(());
+    }
+
+    let (name, expansion) = rest
+        .split_once('=')
+        .ok_or("usage: alias <name> = <command ...>")?;
+    let name = name.trim();
+    let expansion = strip_quotes(expansion.trim());
+    if name.is_empty() || name.contains(char::is_whitespace) || expansion.is_empty() {
+        return Err("usage: alias <name> = <command ...>".to_string());
+    }
+    if BUILTINS.contains(&name) {
+        return Err(format!("'{}' is a shell builtin", name));
+    }
+
+    aliases
+        .aliases
+        .insert(name.to_string(), expansion.into_owned());
+    aliases.save()
+}
+
+fn strip_quotes(s: &str) -> Cow<'_, str> {
+    match s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+    {
+        Some(inner) => Cow::Owned(inner.to_string()),
+        None => Cow::Borrowed(s),
+    }
+}
+
+fn print_help(aliases: &Aliases) {
+    let _ = Args::command().print_help();
+    println!();
+    println!("Shell builtins:");
+    println!("  alias [<name> = <command ...>]  List or define an alias");
+    println!("  unalias <name>...               Remove aliases");
+    println!("  exit | quit                     Leave the shell");
+    if !aliases.aliases.is_empty() {
+        println!();
+        println!("Aliases:");
+        for (name, expansion) in &aliases.aliases {
+            println!("  {:<16} {}", name, expansion);
+        }
+    }
+}
+
+/// Path inside the shared BARAS config directory (created if missing)
+fn config_file(name: &str) -> Option<PathBuf> {
+    let dir = dirs::config_dir()?.join("baras");
+    std::fs::create_dir_all(&dir).ok()?;
+    Some(dir.join(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alias_expansion_appends_arguments() {
+        let mut aliases = Aliases::default();
+        aliases
+            .aliases
+            .insert("ov".to_string(), "query -c overview".to_string());
+
+        let words = vec![
+            "ov".to_string(),
+            "-e".to_string(),
+            "0001.parquet".to_string(),
+        ];
+        assert_eq!(
+            aliases.expand(words).unwrap(),
+            vec!["query", "-c", "overview", "-e", "0001.parquet"]
+        );
+    }
+
+    #[test]
+    fn test_non_alias_passes_through() {
+        let aliases = Aliases::default();
+        let words = vec!["tail".to_string()];
+        assert_eq!(aliases.expand(words).unwrap(), vec!["tail"]);
+    }
+
+    #[test]
+    fn test_split_line_skips_comments() {
+        assert_eq!(split_line("# note"), Ok(None));
+        assert_eq!(
+            split_line("tail --demo # watch"),
+            Ok(Some(vec!["tail".to_string(), "--demo".to_string()]))
+        );
+        assert!(split_line("alias x = \"tail").is_err());
+    }
+}
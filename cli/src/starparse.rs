@@ -0,0 +1,44 @@
+//! StarParse timer import
+//!
+//! Converts a StarParse timer export (XML) into a boss definition TOML file
+//! that can be dropped into the user encounters directory. Timers are grouped
+//! per boss; give each boss an area (or move its timers into an existing
+//! definition) for them to fire.
+
+use std::path::PathBuf;
+
+use baras_core::boss::{BossConfig, import_starparse_timers};
+
+/// Options for the `import-starparse` command
+pub struct ImportStarParseOptions {
+    pub xml: PathBuf,
+    pub output: Option<PathBuf>,
+}
+
+pub fn run(options: ImportStarParseOptions) -> Result<(), String> {
+    let xml = std::fs::read_to_string(&options.xml)
+        .map_err(|e| format!("failed to read {}: {}", options.xml.display(), e))?;
+    let import = import_starparse_timers(&xml)?;
+
+    for warning in &import.warnings {
+        eprintln!("warning: {}", warning);
+    }
+    let timers = import.timer_count();
+    let bosses = import.bosses.len();
+
+    match &options.output {
+        Some(path) => baras_core::boss::save_bosses_to_file(&import.bosses, path)?,
+        None => {
+            let config = BossConfig {
+                area: None,
+                bosses: import.bosses,
+            };
+            let text = toml::to_string(&config)
+                .map_err(|e| format!("failed to serialize definitions: {}", e))?;
+            print!("{}", text);
+        }
+    }
+
+    eprintln!("Imported {} timers for {} bosses", timers, bosses);
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//! Live tail mode
+//!
+//! Follows the newest combat log through the same session pipeline the app
+//! uses and redraws a meter table on a fixed interval. When the game starts a
+//! new log (character switch, relog), tailing moves to the new file.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::RwLock;
+
+use baras_core::context::parse_file;
+use baras_core::directory_watcher::{DirectoryEvent, DirectoryWatcher};
+
+use crate::meters::{self, MeterSnapshot};
+
+/// Options for the `tail` command
+pub struct TailOptions {
+    pub dir: PathBuf,
+    /// Fixed file to tail; disables switching to newer logs
+    pub file: Option<PathBuf>,
+    pub refresh: Duration,
+    pub rows: usize,
+}
+
+/// Why tailing of a single file stopped
+enum Stop {
+    /// A newer log appeared in the directory
+    NewFile(PathBuf),
+    /// User interrupted (Ctrl+C) or the log could not be followed
+    Exit,
+}
+
+pub async fn run(options: TailOptions) -> Result<(), String> {
+    let mut path = match &options.file {
+        Some(file) => file.clone(),
+        None => crate::newest_log(&options.dir)?,
+    };
+
+    // Only watch the directory when following the newest log
+    let mut watcher = match options.file {
+        Some(_) => None,
+        None => Some(
+            DirectoryWatcher::new(&options.dir)
+                .map_err(|e| format!("failed to watch {}: {}", options.dir.display(), e))?,
+        ),
+    };
+
+    loop {
+        match follow(&path, &options, watcher.as_mut()).await? {
+            Stop::NewFile(next) => path = next,
+            Stop::Exit => return Ok(()),
+        }
+    }
+}
+
+/// Parse a log to its current end, then follow it until interrupted or superseded
+async fn follow(
+    path: &Path,
+    options: &TailOptions,
+    mut watcher: Option<&mut DirectoryWatcher>,
+) -> Result<Stop, String> {
+    let session = Arc::new(RwLock::new(crate::open_session(path)));
+
+    // Catch up on everything already in the file
+    let result = parse_file(session.clone()).await?;
+    let mut tail = tokio::spawn(result.reader.tail_log_file());
+
+    let mut ticker = tokio::time::interval(options.refresh);
+    let file_name = path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+
+    let stop = loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                let frame = {
+                    let session = session.read().await;
+                    let snapshot = session.session_cache.as_ref().and_then(MeterSnapshot::capture);
+                    draw(&file_name, snapshot.as_ref(), options.rows)
+                };
+                print!("{}", frame);
+                let _ = std::io::stdout().flush();
+            }
+            event = next_directory_event(&mut watcher) => {
+                if let Some(DirectoryEvent::NewFile(next)) = event {
+                    break Stop::NewFile(next);
+                }
+            }
+            result = &mut tail => {
+                if let Ok(Err(e)) = result {
+                    eprintln!("Stopped tailing {}: {}", file_name, e);
+                }
+                break Stop::Exit;
+            }
+            _ = tokio::signal::ctrl_c() => break Stop::Exit,
+        }
+    };
+
+    tail.abort();
+    Ok(stop)
+}
+
+/// Next watcher event, or pending forever when not watching.
+///
+/// A watcher whose channel has closed is dropped, so the caller keeps tailing
+/// the current file instead of spinning on `None`.
+async fn next_directory_event(
+    watcher: &mut Option<&mut DirectoryWatcher>,
+) -> Option<DirectoryEvent> {
+    let event = match watcher {
+        Some(w) => w.next_event().await,
+        None => std::future::pending().await,
+    };
+    if event.is_none() {
+        eprintln!("Directory watcher stopped; no longer following new log files");
+        *watcher = None;
+    }
+    event
+}
+
+/// Build one full-screen frame (clear + home cursor, then the table)
+fn draw(file_name: &str, snapshot: Option<&MeterSnapshot>, rows: usize) -> String {
+    let mut frame = String::from("\x1b[2J\x1b[H");
+    frame.push_str(&format!("BARAS - {}\n\n", file_name));
+    match snapshot {
+        Some(snapshot) => frame.push_str(&meters::render(snapshot, rows)),
+        None => frame.push_str("Waiting for combat...\n"),
+    }
+    frame
+}
@@ -0,0 +1,204 @@
+//! Hot path benchmarks
+//!
+//! Measures the per-line cost of the live pipeline against the bundled
+//! fixture logs in `test-log-files/fixtures`:
+//! - `parse_line`: line parsing, from decoded strings and from raw file bytes
+//! - `process_event`: event processing into encounters and signals
+//! - `effect_tracking`: `EffectTracker` fed the signals of a pull, with the bundled effect definitions
+//! - `timer_matching`: event processing plus `TimerManager` with the Dread Palace boss timers
+//!
+//! Run with `cargo bench -p baras-core`. Each benchmark processes a whole pull
+//! per iteration, so throughput is reported in log lines.
+
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+
+use chrono::{NaiveDate, NaiveDateTime};
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+
+use baras_core::signal_processor::{EventProcessor, GameSignal, SignalHandler};
+use baras_core::state::SessionCache;
+use baras_core::{
+    BossConfig, BossEncounterDefinition, CombatEvent, DefinitionConfig, DefinitionSet,
+    EffectTracker, LogParser, TimerManager,
+};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Fixtures
+// ─────────────────────────────────────────────────────────────────────────────
+
+const PULL_LOG: &str = "test-log-files/fixtures/bestia_pull.txt";
+const BOSS_CONFIG: &str = "test-log-files/fixtures/config/dread_palace.toml";
+
+fn repo_path(relative: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(relative)
+}
+
+/// Fixed session date so results don't depend on when the bench runs
+fn session_date() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 1, 1)
+        .and_then(|d| d.and_hms_opt(20, 0, 0))
+        .expect("valid date")
+}
+
+/// Fixture contents (lossy UTF-8, like the reader)
+fn load_log(relative: &str) -> String {
+    let bytes = std::fs::read(repo_path(relative)).expect("fixture log not found");
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn parse_events(content: &str) -> Vec<CombatEvent> {
+    let parser = LogParser::new(session_date());
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(n, line)| parser.parse_line(n as u64, line))
+        .collect()
+}
+
+fn collect_signals(events: &[CombatEvent]) -> Vec<GameSignal> {
+    let mut processor = EventProcessor::new();
+    let mut cache = SessionCache::default();
+    events
+        .iter()
+        .flat_map(|event| processor.process_event(event.clone(), &mut cache).0)
+        .collect()
+}
+
+fn load_bosses() -> Vec<BossEncounterDefinition> {
+    let content = std::fs::read_to_string(repo_path(BOSS_CONFIG)).expect("boss config not found");
+    let mut config: BossConfig = toml::from_str(&content).expect("invalid boss config");
+    for boss in &mut config.bosses {
+        boss.build_indexes();
+    }
+    config.bosses
+}
+
+/// Bundled effect definitions (same files the app loads, minus the template)
+fn load_effect_definitions() -> DefinitionSet {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("definitions/effects");
+    let mut set = DefinitionSet::new();
+    for entry in std::fs::read_dir(dir).expect("effect definitions not found").flatten() {
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == "toml")
+            && path.file_name().is_none_or(|n| n != "custom.toml")
+        {
+            let content = std::fs::read_to_string(&path).expect("unreadable definitions");
+            let config: DefinitionConfig = toml::from_str(&content).expect("invalid definitions");
+            set.add_templates(config.templates, false);
+            set.add_definitions(config.effects, false);
+        }
+    }
+    set.resolve_templates();
+    set
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Benchmarks
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn bench_parse_line(c: &mut Criterion) {
+    let content = load_log(PULL_LOG);
+    let parser = LogParser::new(session_date());
+
+    let mut group = c.benchmark_group("parse_line");
+    group.throughput(Throughput::Elements(content.lines().count() as u64));
+    group.bench_function("bestia_pull", |b| {
+        b.iter(|| {
+            for (n, line) in content.lines().enumerate() {
+                black_box(parser.parse_line(n as u64, black_box(line)));
+            }
+        })
+    });
+    let bytes = std::fs::read(repo_path(PULL_LOG)).expect("fixture log not found");
+    group.bench_function("bestia_pull_raw", |b| {
+        b.iter(|| {
+            for (n, line) in bytes.split(|&c| c == b'\n').enumerate() {
+                black_box(parser.parse_raw_line(n as u64, black_box(line)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_process_event(c: &mut Criterion) {
+    let events = parse_events(&load_log(PULL_LOG));
+
+    let mut group = c.benchmark_group("process_event");
+    group.throughput(Throughput::Elements(events.len() as u64));
+    group.bench_function("bestia_pull", |b| {
+        b.iter_batched(
+            || (events.clone(), EventProcessor::new(), SessionCache::default()),
+            |(events, mut processor, mut cache)| {
+                for event in events {
+                    black_box(processor.process_event(event, &mut cache));
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_effect_tracking(c: &mut Criterion) {
+    let events = parse_events(&load_log(PULL_LOG));
+    let signals = collect_signals(&events);
+    let definitions = load_effect_definitions();
+
+    let mut group = c.benchmark_group("effect_tracking");
+    group.throughput(Throughput::Elements(events.len() as u64));
+    group.bench_function("bestia_pull", |b| {
+        b.iter_batched(
+            || {
+                let mut tracker = EffectTracker::new(definitions.clone());
+                tracker.set_live_mode(true);
+                tracker
+            },
+            |mut tracker| {
+                tracker.handle_signals(&signals, None);
+                black_box(tracker.active_effects().count());
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_timer_matching(c: &mut Criterion) {
+    let events = parse_events(&load_log(PULL_LOG));
+    let bosses = load_bosses();
+
+    // Timers read area/boss context from the live encounter, so events are
+    // processed alongside; compare against `process_event` for the timer cost.
+    let mut group = c.benchmark_group("timer_matching");
+    group.throughput(Throughput::Elements(events.len() as u64));
+    group.bench_function("bestia_pull", |b| {
+        b.iter_batched(
+            || {
+                let mut cache = SessionCache::default();
+                cache.load_boss_definitions(bosses.clone());
+                let mut timers = TimerManager::new();
+                timers.load_boss_definitions(bosses.clone());
+                (events.clone(), EventProcessor::new(), cache, timers)
+            },
+            |(events, mut processor, mut cache, mut timers)| {
+                for event in events {
+                    let (signals, _) = processor.process_event(event, &mut cache);
+                    timers.handle_signals(&signals, cache.current_encounter());
+                }
+                black_box(timers.active_timers().len());
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_parse_line,
+    bench_process_event,
+    bench_effect_tracking,
+    bench_timer_matching
+);
+criterion_main!(benches);
@@ -0,0 +1,60 @@
+//! Encounter boundary scanning
+//!
+//! Finds where the most recent content of a log starts without parsing any
+//! lines, so a large file can be parsed newest-first: the tail is parsed for
+//! display right away and the rest of the file is backfilled afterwards.
+
+use memchr::{memchr_iter, memmem, memrchr};
+
+use crate::game_data::{effect_id, effect_type_id};
+
+/// Start of the last area entry before the final combat of the log.
+///
+/// Parsing from there yields the latest encounters with their area, difficulty
+/// and player context intact. Returns `None` if the log has no combat or no
+/// area entry before it.
+pub fn recent_area_start(bytes: &[u8]) -> Option<usize> {
+    let enter_combat = format!("{{{}}}", effect_id::ENTERCOMBAT);
+    let area_entered = format!("{{{}}}", effect_type_id::AREAENTERED);
+
+    let combat = memmem::rfind(bytes, enter_combat.as_bytes())?;
+    let area = memmem::rfind(&bytes[..combat], area_entered.as_bytes())?;
+    Some(memrchr(b'\n', &bytes[..area]).map_or(0, |newline| newline + 1))
+}
+
+/// Number of non-empty lines before `offset`, so a parse starting there numbers
+/// its lines the same way a parse of the whole file does
+pub fn count_lines_before(bytes: &[u8], offset: usize) -> u64 {
+    let mut count = 0;
+    let mut start = 0;
+    for end in memchr_iter(b'\n', &bytes[..offset]) {
+        if end > start {
+            count += 1;
+        }
+        start = end + 1;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: &str = "[20:00:00.000] [@Tank#1|(0,0,0,0)|(1/1)] [] [] [AreaEntered {836045448953664}: Dxun {833571547775792}] (he3001)\r\n";
+    const COMBAT: &str = "[20:00:05.000] [@Tank#1|(0,0,0,0)|(1/1)] [] [] [Event {836045448945472}: EnterCombat {836045448945489}]\r\n";
+    const OTHER: &str = "[20:00:06.000] [@Tank#1|(0,0,0,0)|(1/1)] [] [] [Event {836045448945472}: ExitCombat {836045448945490}]\r\n";
+
+    #[test]
+    fn test_recent_area_start_finds_last_area_before_combat() {
+        let log = [AREA, COMBAT, OTHER, "\n", AREA, COMBAT, OTHER, AREA].concat();
+        let second_area = AREA.len() + COMBAT.len() + OTHER.len() + 1;
+
+        let start = recent_area_start(log.as_bytes()).unwrap();
+        assert_eq!(start, second_area);
+        // The empty line is skipped by the parse worker and not counted
+        assert_eq!(count_lines_before(log.as_bytes(), start), 3);
+
+        assert_eq!(recent_area_start([AREA, OTHER].concat().as_bytes()), None);
+        assert_eq!(recent_area_start(COMBAT.as_bytes()), None);
+    }
+}
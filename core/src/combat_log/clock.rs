@@ -0,0 +1,71 @@
+use chrono::{Days, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+
+use super::CombatEvent;
+
+/// A backwards step at least this large between consecutive lines is the
+/// clock wrapping past midnight
+const ROLLOVER_MIN_STEP: TimeDelta = TimeDelta::hours(12);
+
+/// Wall-clock step when daylight saving time ends
+const DST_STEP: TimeDelta = TimeDelta::hours(1);
+
+/// How far a backwards step may be from `DST_STEP` and still count as the
+/// clocks going back (consecutive lines are rarely exactly on the hour)
+const DST_TOLERANCE: TimeDelta = TimeDelta::minutes(5);
+
+/// Assigns dates to log lines in file order.
+///
+/// Log lines only record a local wall-clock time of day. Starting from the
+/// session date in the file name, the clock advances a day whenever the time
+/// jumps back by 12 hours or more (midnight), and keeps timestamps
+/// increasing across the end of daylight saving time by shifting every later
+/// line forward by the hour the clocks went back. The start of daylight
+/// saving time needs no correction: it only looks like an hour of idling.
+///
+/// Events must be passed in file order, so parallel parses resolve their
+/// events once they have been collected. Readers that pick a session up
+/// part way through (tailing, checkpoint resumes) carry on with the clock
+/// that dated the lines before.
+#[derive(Debug, Clone, Copy)]
+pub struct SessionClock {
+    date: NaiveDate,
+    /// Wall-clock time of the previous line (or the session start)
+    last: NaiveDateTime,
+    /// Correction for wall-clock hours repeated at the end of DST
+    shift: TimeDelta,
+}
+
+impl SessionClock {
+    /// Clock starting at the session date, or after a line that was
+    /// resolved to `session_date` (a DST correction in effect there is lost)
+    pub fn new(session_date: NaiveDateTime) -> Self {
+        Self {
+            date: session_date.date(),
+            last: session_date,
+            shift: TimeDelta::zero(),
+        }
+    }
+
+    /// Full timestamp of the next line, given its time of day
+    pub fn resolve(&mut self, time: NaiveTime) -> NaiveDateTime {
+        let mut wall = self.date.and_time(time);
+        let step = wall - self.last;
+
+        if step <= -ROLLOVER_MIN_STEP {
+            if let Some(next) = self.date.checked_add_days(Days::new(1)) {
+                self.date = next;
+                wall = next.and_time(time);
+            }
+        } else if (step + DST_STEP).abs() <= DST_TOLERANCE {
+            self.shift += DST_STEP;
+        }
+
+        self.last = wall;
+        wall + self.shift
+    }
+
+    /// Replace the event's timestamp with its resolved date and time
+    pub fn apply(&mut self, event: &mut CombatEvent) {
+        event.timestamp = self.resolve(event.timestamp.time());
+    }
+}
@@ -50,6 +50,10 @@ impl Default for Entity {
 #[derive(Debug, Clone)]
 pub struct CombatEvent {
     pub line_number: u64,
+    /// Byte offset of the event's line in the log file (set by the reader)
+    pub byte_offset: u64,
+    /// Byte offset just past the line's newline (set by the reader)
+    pub byte_end: u64,
     pub timestamp: NaiveDateTime,
     pub source_entity: Entity,
     pub target_entity: Entity,
@@ -58,6 +62,21 @@ pub struct CombatEvent {
     pub details: Details,
 }
 
+impl CombatEvent {
+    /// Record the bytes `[start, end)` the event's line occupies in the log file
+    pub fn at_log_range(mut self, start: u64, end: u64) -> Self {
+        self.byte_offset = start;
+        self.byte_end = end;
+        self
+    }
+
+    /// Whether this event's damage was reflected back at the target
+    /// (see [`crate::game_data::is_reflect_damage`])
+    pub fn is_reflect_damage(&self) -> bool {
+        crate::game_data::is_reflect_damage(self.action.action_id, self.details.is_reflect)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Effect {
     pub type_name: IStr,
@@ -56,6 +56,13 @@ pub enum ReaderError {
         source: std::io::Error,
     },
 
+    #[error("failed to write file {path}")]
+    WriteFile {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("session date not initialized before tailing")]
     SessionDateMissing,
 }
@@ -0,0 +1,84 @@
+//! Raw log segment export
+//!
+//! Reads the original lines of one encounter out of a combat log, byte for byte,
+//! by seeking to the byte range stored in its summary. The segment can then be
+//! uploaded or shared as a standalone log file.
+
+use std::fs::File;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use super::ReaderError;
+
+/// Read the bytes `[range.0, range.1)` of a log, as recorded in an encounter
+/// summary's `log_range`.
+///
+/// Fails if the file is shorter than the range (e.g. it was replaced).
+pub fn read_log_segment(log_path: &Path, range: (u64, u64)) -> Result<Vec<u8>, ReaderError> {
+    let (start, end) = range;
+    let mut file = File::open(log_path).map_err(|source| ReaderError::OpenFile {
+        path: log_path.to_path_buf(),
+        source,
+    })?;
+    file.seek(SeekFrom::Start(start))
+        .map_err(|source| ReaderError::Seek {
+            path: log_path.to_path_buf(),
+            source,
+        })?;
+
+    let mut segment = vec![0; end.saturating_sub(start) as usize];
+    file.read_exact(&mut segment)
+        .map_err(|source| ReaderError::ReadFile {
+            path: log_path.to_path_buf(),
+            source,
+        })?;
+    Ok(segment)
+}
+
+/// Copy the log bytes `[range.0, range.1)` into `dest`, returning the number of
+/// bytes written
+pub fn export_log_segment(
+    log_path: &Path,
+    range: (u64, u64),
+    dest: &Path,
+) -> Result<u64, ReaderError> {
+    let segment = read_log_segment(log_path, range)?;
+    let write_err = |source| ReaderError::WriteFile {
+        path: dest.to_path_buf(),
+        source,
+    };
+
+    let out = File::create(dest).map_err(write_err)?;
+    let mut writer = BufWriter::new(out);
+    writer.write_all(&segment).map_err(write_err)?;
+    writer.flush().map_err(write_err)?;
+
+    Ok(segment.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_copies_whole_lines() {
+        let dir = std::env::temp_dir();
+        let log = dir.join(format!("baras-export-test-{}.txt", std::process::id()));
+        let dest = dir.join(format!("baras-export-test-{}-out.txt", std::process::id()));
+        let lines = ["[first]\r\n", "[second]\r\n", "[third]\r\n", "[fourth]\r\n"];
+        std::fs::write(&log, lines.concat()).unwrap();
+
+        let second = lines[0].len() as u64;
+        let fourth = second + (lines[1].len() + lines[2].len()) as u64;
+        let written = export_log_segment(&log, (second, fourth), &dest).unwrap();
+        let exported = std::fs::read_to_string(&dest).unwrap();
+        // A range past the end of the file is an error, not a short read
+        let too_long = read_log_segment(&log, (second, 1_000));
+        std::fs::remove_file(&log).ok();
+        std::fs::remove_file(&dest).ok();
+
+        assert_eq!(exported, "[second]\r\n[third]\r\n");
+        assert_eq!(written, exported.len() as u64);
+        assert!(too_long.is_err());
+    }
+}
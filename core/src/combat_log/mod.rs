@@ -1,9 +1,15 @@
+mod boundaries;
+mod clock;
 mod combat_event;
 mod error;
+mod export;
 mod parser;
 mod reader;
 
+pub use boundaries::{count_lines_before, recent_area_start};
+pub use clock::SessionClock;
 pub use combat_event::*;
 pub use error::{ParseError, ReaderError};
+pub use export::{export_log_segment, read_log_segment};
 pub use parser::LogParser;
 pub use reader::Reader;
@@ -1,22 +1,71 @@
 use super::*;
-use crate::context::intern;
+use crate::context::{IStr, empty_istr, intern};
 use crate::game_data::{defense_type, effect_id, effect_type_id};
 use chrono::{Days, NaiveDateTime};
+use encoding_rs::WINDOWS_1252;
 use memchr::memchr_iter;
-use memchr::{memchr, memmem};
+use memchr::{memchr, memmem, memrchr};
 
 #[cfg(test)]
 mod tests;
 
-macro_rules! parse_i64 {
-    ($s:expr) => {
-        $s.parse::<i64>().unwrap_or_default()
+/// Parse a decimal integer from ASCII bytes. Returns 0 for anything
+/// `str::parse` would reject (empty, stray characters, overflow).
+macro_rules! int_parser {
+    ($name:ident, $t:ty) => {
+        fn $name(b: &[u8]) -> $t {
+            let (negative, digits) = match b {
+                [b'-', rest @ ..] => (true, rest),
+                [b'+', rest @ ..] => (false, rest),
+                _ => (false, b),
+            };
+            if digits.is_empty() {
+                return 0;
+            }
+            let mut value: $t = 0;
+            for &d in digits {
+                if !d.is_ascii_digit() {
+                    return 0;
+                }
+                let d = (d - b'0') as $t;
+                let next = value.checked_mul(10).and_then(|v| {
+                    if negative {
+                        v.checked_sub(d)
+                    } else {
+                        v.checked_add(d)
+                    }
+                });
+                match next {
+                    Some(v) => value = v,
+                    None => return 0,
+                }
+            }
+            value
+        }
     };
 }
-macro_rules! parse_i32 {
-    ($s:expr) => {
-        $s.parse::<i32>().unwrap_or_default()
-    };
+int_parser!(parse_i64, i64);
+int_parser!(parse_i32, i32);
+
+fn parse_f32(b: &[u8]) -> Option<f32> {
+    std::str::from_utf8(b).ok()?.parse().ok()
+}
+
+/// Position of the first byte that isn't an ASCII digit
+fn digits_end(b: &[u8]) -> usize {
+    b.iter().position(|c| !c.is_ascii_digit()).unwrap_or(b.len())
+}
+
+/// Intern a name taken from a log line.
+///
+/// Lines passed to `parse_line` are UTF-8; raw lines from disk are Windows-1252.
+/// Names are almost always ASCII, which is identical in both, so only a name
+/// that isn't valid UTF-8 pays for a Windows-1252 decode.
+fn intern_bytes(b: &[u8]) -> IStr {
+    match std::str::from_utf8(b) {
+        Ok(s) => intern(s),
+        Err(_) => intern(&WINDOWS_1252.decode_without_bom_handling(b).0),
+    }
 }
 
 pub struct LogParser {
@@ -28,9 +77,25 @@ impl LogParser {
         Self { session_date }
     }
 
-    pub fn parse_line(&self, line_number: u64, _line: &str) -> Option<CombatEvent> {
-        let b = _line.as_bytes();
+    /// Clock that dates this session's events across midnight and DST.
+    ///
+    /// `parse_line` only knows the session start, so a line is placed on the
+    /// day after it if its time of day is earlier. Readers that see the whole
+    /// session in order pass each event through the clock instead.
+    pub fn clock(&self) -> SessionClock {
+        SessionClock::new(self.session_date)
+    }
+
+    /// Parse a line that has already been decoded to a string
+    pub fn parse_line(&self, line_number: u64, line: &str) -> Option<CombatEvent> {
+        self.parse_raw_line(line_number, line.as_bytes())
+    }
 
+    /// Parse a line straight from the log file (Windows-1252 bytes, no decoding).
+    ///
+    /// Fields are split on the raw bytes; only names that get interned are
+    /// decoded, so ASCII-only lines never allocate a decoded copy.
+    pub fn parse_raw_line(&self, line_number: u64, b: &[u8]) -> Option<CombatEvent> {
         // Use fixed arrays instead of Vec to avoid heap allocation
         let mut brackets = [0usize; 5];
         let mut end_brackets = [0usize; 5];
@@ -60,12 +125,12 @@ impl LogParser {
             return None;
         }
 
-        let time_segment = &_line[brackets[0] + 1..end_brackets[0]];
-        let source_entity_segment = &_line[brackets[1] + 1..end_brackets[1]];
-        let target_entity_segment = &_line[brackets[2] + 1..end_brackets[2]];
-        let action_segment = &_line[brackets[3] + 1..end_brackets[3]];
-        let effect_segment = &_line[brackets[4] + 1..end_brackets[4]];
-        let details_segment = &_line[end_brackets[4] + 1..];
+        let time_segment = &b[brackets[0] + 1..end_brackets[0]];
+        let source_entity_segment = &b[brackets[1] + 1..end_brackets[1]];
+        let target_entity_segment = &b[brackets[2] + 1..end_brackets[2]];
+        let action_segment = &b[brackets[3] + 1..end_brackets[3]];
+        let effect_segment = &b[brackets[4] + 1..end_brackets[4]];
+        let details_segment = &b[end_brackets[4] + 1..];
 
         let timestamp = self.parse_timestamp(time_segment)?;
         let source_entity = self.parse_entity(source_entity_segment)?;
@@ -83,6 +148,8 @@ impl LogParser {
 
         let event = CombatEvent {
             line_number,
+            byte_offset: 0,
+            byte_end: 0,
             timestamp,
             source_entity,
             target_entity,
@@ -95,8 +162,7 @@ impl LogParser {
     }
 
     // parse HH:MM:SS.mmm
-    fn parse_timestamp(&self, segment: &str) -> Option<NaiveDateTime> {
-        let b = segment.as_bytes();
+    fn parse_timestamp(&self, b: &[u8]) -> Option<NaiveDateTime> {
         if b.len() != 12 || b[2] != b':' || b[5] != b':' || b[8] != b'.' {
             return None;
         }
@@ -132,12 +198,9 @@ impl LogParser {
         None
     }
 
-    fn parse_entity(&self, segment: &str) -> Option<Entity> {
-        let bytes = segment.as_bytes();
-        let self_target_pos = memchr(b'=', bytes);
-
+    fn parse_entity(&self, segment: &[u8]) -> Option<Entity> {
         // handle [=]
-        if self_target_pos.is_some() {
+        if memchr(b'=', segment).is_some() {
             return Some(Entity {
                 entity_type: EntityType::SelfReference,
                 ..Default::default()
@@ -151,7 +214,7 @@ impl LogParser {
         }
 
         // Find first two pipes (only need positions 0 and 1)
-        let mut pipe_iter = memchr_iter(b'|', bytes);
+        let mut pipe_iter = memchr_iter(b'|', segment);
         let pipe0 = pipe_iter.next()?;
         let pipe1 = pipe_iter.next()?;
 
@@ -163,7 +226,7 @@ impl LogParser {
         let health = LogParser::parse_entity_health(health_segment)?;
 
         Some(Entity {
-            name: intern(name),
+            name: intern_bytes(name),
             class_id,
             log_id,
             entity_type,
@@ -171,38 +234,34 @@ impl LogParser {
         })
     }
 
-    fn parse_entity_health(segment: &str) -> Option<(i32, i32)> {
-        let bytes = segment.as_bytes();
-        let paren = memchr(b'(', bytes);
-        let slash = memchr(b'/', bytes);
-        let paren_end = memchr(b')', bytes);
+    fn parse_entity_health(segment: &[u8]) -> Option<(i32, i32)> {
+        let paren = memchr(b'(', segment);
+        let slash = memchr(b'/', segment);
+        let paren_end = memchr(b')', segment);
 
-        let current_health = parse_i32!(&segment[paren? + 1..slash?]);
-        let health_end_pos = parse_i32!(&segment[slash? + 1..paren_end?]);
+        let current_health = parse_i32(segment.get(paren? + 1..slash?)?);
+        let health_end_pos = parse_i32(segment.get(slash? + 1..paren_end?)?);
 
         Some((current_health, health_end_pos))
     }
 
-    fn parse_entity_name_id(segment: &str) -> Option<(&str, i64, i64, EntityType)> {
-        let bytes = segment.as_bytes();
-
-        let brace = memchr(b'{', bytes);
-        let end_brace = memchr(b'}', bytes);
-        let hashtag = memchr(b'#', bytes);
-        let slash = memchr(b'/', bytes);
+    fn parse_entity_name_id(segment: &[u8]) -> Option<(&[u8], i64, i64, EntityType)> {
+        let brace = memchr(b'{', segment);
+        let end_brace = memchr(b'}', segment);
+        let hashtag = memchr(b'#', segment);
+        let slash = memchr(b'/', segment);
 
         // Parse Player and Player Companion
-        if hashtag.is_some() {
-            let player_name = &segment[1..hashtag?];
+        if let Some(hashtag) = hashtag {
+            let player_name = segment.get(1..hashtag)?;
 
             if slash.is_none() {
-                let player_id = parse_i64!(&segment[hashtag? + 1..]);
-
+                let player_id = parse_i64(&segment[hashtag + 1..]);
                 return Some((player_name, 0, player_id, EntityType::Player));
             } else {
-                let companion_name = &segment[slash? + 1..brace? - 1];
-                let companion_char_id = parse_i64!(&segment[brace? + 1..end_brace?]);
-                let companion_log_id = parse_i64!(&&segment[end_brace? + 2..]);
+                let companion_name = segment.get(slash? + 1..brace?.checked_sub(1)?)?;
+                let companion_char_id = parse_i64(segment.get(brace? + 1..end_brace?)?);
+                let companion_log_id = parse_i64(segment.get(end_brace? + 2..)?);
 
                 return Some((
                     companion_name,
@@ -214,37 +273,34 @@ impl LogParser {
         }
 
         // if no '#' detected parse NPC
-        let npc_name = segment[..brace?].trim();
-        let npc_char_id = parse_i64!(&segment[brace? + 1..end_brace?]);
-        let npc_log_id = parse_i64!(&segment[end_brace? + 2..]);
+        let npc_name = segment[..brace?].trim_ascii();
+        let npc_char_id = parse_i64(segment.get(brace? + 1..end_brace?)?);
+        let npc_log_id = parse_i64(segment.get(end_brace? + 2..)?);
 
         Some((npc_name, npc_char_id, npc_log_id, EntityType::Npc))
     }
 
-    fn parse_action(segment: &str) -> Option<Action> {
-        let bytes = segment.as_bytes();
-
-        let brace = memchr(b'{', bytes);
-        let end_brace = memchr(b'}', bytes);
-
+    fn parse_action(segment: &[u8]) -> Option<Action> {
         if segment.is_empty() {
             return Some(Action {
                 ..Default::default()
             });
         }
 
-        let action_name = segment[..brace?].trim();
-        let action_id = parse_i64!(segment[brace? + 1..end_brace?]);
+        let brace = memchr(b'{', segment)?;
+        let end_brace = memchr(b'}', segment)?;
+
+        let action_name = segment[..brace].trim_ascii();
+        let action_id = parse_i64(segment.get(brace + 1..end_brace)?);
 
         Some(Action {
-            name: intern(action_name),
+            name: intern_bytes(action_name),
             action_id,
         })
     }
 
-    fn parse_effect(segment: &str) -> Option<Effect> {
-        let bytes = segment.as_bytes();
-        let slash = memchr(b'/', bytes);
+    fn parse_effect(segment: &[u8]) -> Option<Effect> {
+        let slash = memchr(b'/', segment);
 
         // Collect up to 3 brace positions without heap allocation
         let mut braces = [0usize; 3];
@@ -252,13 +308,13 @@ impl LogParser {
         let mut brace_count = 0;
         let mut end_brace_count = 0;
 
-        for pos in memchr_iter(b'{', bytes) {
+        for pos in memchr_iter(b'{', segment) {
             if brace_count < 3 {
                 braces[brace_count] = pos;
                 brace_count += 1;
             }
         }
-        for pos in memchr_iter(b'}', bytes) {
+        for pos in memchr_iter(b'}', segment) {
             if end_brace_count < 3 {
                 end_braces[end_brace_count] = pos;
                 end_brace_count += 1;
@@ -271,28 +327,29 @@ impl LogParser {
             });
         }
 
-        let type_name = intern(segment[..braces[0]].trim());
-        let type_id = parse_i64!(&segment[braces[0] + 1..end_braces[0]]);
-        let effect_name = intern(segment[end_braces[0] + 2..braces[1] - 1].trim());
-        let effect_id = parse_i64!(&segment[braces[1] + 1..end_braces[1]]);
+        let type_name = intern_bytes(segment[..braces[0]].trim_ascii());
+        let type_id = parse_i64(segment.get(braces[0] + 1..end_braces[0])?);
+        let effect_name =
+            intern_bytes(segment.get(end_braces[0] + 2..braces[1].checked_sub(1)?)?.trim_ascii());
+        let effect_id = parse_i64(segment.get(braces[1] + 1..end_braces[1])?);
 
         let (difficulty_name, difficulty_id) =
             if type_id == effect_type_id::AREAENTERED && brace_count == 3 {
                 (
-                    intern(segment[end_braces[1] + 1..braces[2]].trim()),
-                    parse_i64!(segment[braces[2] + 1..end_braces[2]]),
+                    intern_bytes(segment.get(end_braces[1] + 1..braces[2])?.trim_ascii()),
+                    parse_i64(segment.get(braces[2] + 1..end_braces[2])?),
                 )
             } else {
-                (intern(""), 0)
+                (empty_istr(), 0)
             };
 
         let (discipline_name, discipline_id) = if type_id == effect_type_id::DISCIPLINECHANGED {
             (
-                intern(segment[slash? + 1..braces[2]].trim()),
-                parse_i64!(segment[braces[2] + 1..end_braces[2]]),
+                intern_bytes(segment.get(slash? + 1..braces[2])?.trim_ascii()),
+                parse_i64(segment.get(braces[2] + 1..end_braces[2])?),
             )
         } else {
-            (intern(""), 0)
+            (empty_istr(), 0)
         };
 
         Some(Effect {
@@ -307,28 +364,21 @@ impl LogParser {
         })
     }
 
-    fn parse_details(segment: &str, effect_id: i64, effect_type_id: i64) -> Option<Details> {
+    fn parse_details(segment: &[u8], effect_id: i64, effect_type_id: i64) -> Option<Details> {
         match effect_id {
             effect_id::DAMAGE => LogParser::parse_dmg_details(segment),
             effect_id::HEAL => LogParser::parse_heal_details(segment),
             effect_id::TAUNT => {
-                let bytes = segment.as_bytes();
-                let angle = memchr(b'<', bytes);
-                let angle_end = memchr(b'>', bytes);
                 // Parse threat from <value> - only present if effective heal occurred
-                let threat = angle
-                    .zip(angle_end)
-                    .and_then(|(s, e)| segment[s + 1..e].parse::<f32>().ok())
-                    .unwrap_or_default();
                 Some(Details {
-                    threat,
+                    threat: LogParser::parse_threat(segment),
                     ..Default::default()
                 })
             }
             _ => {
                 if (effect_type_id == effect_type_id::APPLYEFFECT
                     || effect_type_id == effect_type_id::MODIFYCHARGES)
-                    && memchr(b'(', segment.as_bytes()).is_some()
+                    && memchr(b'(', segment).is_some()
                 {
                     LogParser::parse_charges(segment)
                 } else {
@@ -340,26 +390,35 @@ impl LogParser {
         }
     }
 
-    fn parse_dmg_details(segment: &str) -> Option<Details> {
-        let bytes = segment.as_bytes();
+    /// Threat from the `<value>` suffix, 0 if absent
+    fn parse_threat(segment: &[u8]) -> f32 {
+        let angle = memchr(b'<', segment);
+        let angle_end = memchr(b'>', segment);
+        angle
+            .zip(angle_end)
+            .and_then(|(s, e)| parse_f32(segment.get(s + 1..e)?))
+            .unwrap_or_default()
+    }
+
+    /// Number following a `~` (effective amount), if present
+    fn parse_effective(inner: &[u8]) -> Option<i32> {
+        let start = memchr(b'~', inner)? + 1;
+        let digits = &inner[start..];
+        Some(parse_i32(&digits[..digits_end(digits)]))
+    }
 
+    fn parse_dmg_details(segment: &[u8]) -> Option<Details> {
         // Find main delimiters
-        let paren = memchr(b'(', bytes)?;
-        let paren_end = LogParser::rfind_matching_paren(bytes, paren)?;
-        let angle = memchr(b'<', bytes);
-        let angle_end = memchr(b'>', bytes);
+        let paren = memchr(b'(', segment)?;
+        let paren_end = LogParser::rfind_matching_paren(segment, paren)?;
 
         let inner = &segment[paren + 1..paren_end];
-        let inner_bytes = inner.as_bytes();
 
         // Parse threat from <value>
-        let threat = angle
-            .zip(angle_end)
-            .and_then(|(s, e)| segment[s + 1..e].parse::<f32>().ok())
-            .unwrap_or_default();
+        let threat = LogParser::parse_threat(segment);
 
         // Handle edge case: (0 -) - nullified damage from reflect
-        if inner.trim() == "0 -" {
+        if inner.trim_ascii() == b"0 -" {
             return Some(Details {
                 dmg_amount: 0,
                 defense_type_id: defense_type::REFLECTED,
@@ -370,85 +429,67 @@ impl LogParser {
         }
 
         // Check for crit marker
-        let is_crit = memchr(b'*', inner_bytes).is_some();
-
-        // Check for reflected marker
+        let is_crit = memchr(b'*', inner).is_some();
 
         // Check for avoidance (-miss, -dodge, -parry, -immune, -resist, -deflect, -shield, -)
-        let dash = memchr(b'-', inner_bytes);
-        let defense_type_id = if let Some(dash_pos) = dash {
-            let after_dash = &inner[dash_pos + 1..];
-            let after_bytes = after_dash.as_bytes();
-            if let (Some(b), Some(be)) = (memchr(b'{', after_bytes), memchr(b'}', after_bytes)) {
-                parse_i64!(&after_dash[b + 1..be])
-            } else {
-                0
-            }
-        } else {
-            0
-        };
+        let defense_type_id = memchr(b'-', inner)
+            .and_then(|dash_pos| {
+                let after_dash = &inner[dash_pos + 1..];
+                let b = memchr(b'{', after_dash)?;
+                let be = memchr(b'}', after_dash)?;
+                Some(parse_i64(after_dash.get(b + 1..be)?))
+            })
+            .unwrap_or(0);
 
         // match this pattern only shows up in lines containing "reflect"
-        let is_reflect = memmem::find(inner_bytes, b"}(").is_some();
+        let is_reflect = memmem::find(inner, b"}(").is_some();
 
         // Parse amount (first number)
-        let amount_end = inner
-            .find(|c: char| !c.is_ascii_digit())
-            .unwrap_or(inner.len());
-        let dmg_amount = parse_i32!(&inner[..amount_end]);
+        let dmg_amount = parse_i32(&inner[..digits_end(inner)]);
 
         // Parse effective damage after ~
-        let tilde = memchr(b'~', inner_bytes);
-        let dmg_effective = tilde
-            .map(|pos| {
-                let start = pos + 1;
-                let end = inner[start..]
-                    .find(|c: char| !c.is_ascii_digit())
-                    .map(|e| start + e)
-                    .unwrap_or(inner.len());
-                parse_i32!(&inner[start..end])
-            })
-            .unwrap_or(dmg_amount);
+        let dmg_effective = LogParser::parse_effective(inner).unwrap_or(dmg_amount);
+
         // Find damage type and ID (first { } pair in inner, but not "reflected" or "absorbed")
-        let brace = memchr(b'{', inner_bytes);
-        let brace_end = memchr(b'}', inner_bytes);
-
-        let (dmg_type, dmg_type_id) = if let (Some(bs), Some(be)) = (brace, brace_end) {
-            // Find type name before the brace - scan backwards for a word
-            let type_start = inner[..bs]
-                .trim_end()
-                .rfind(|c: char| c.is_whitespace())
-                .map(|p| p + 1)
-                .unwrap_or(0);
-            let dmg_type = inner[type_start..bs].trim();
-            let dmg_type_id = parse_i64!(&inner[bs + 1..be]);
-            if dmg_type.contains('-') {
-                (intern(""), 0)
-            } else {
-                (intern(dmg_type), dmg_type_id)
+        let brace = memchr(b'{', inner);
+        let brace_end = memchr(b'}', inner);
+
+        let (dmg_type, dmg_type_id) = match (brace, brace_end) {
+            (Some(bs), Some(be)) if bs < be => {
+                // Find type name before the brace - scan backwards for a word
+                let type_start = inner[..bs]
+                    .trim_ascii_end()
+                    .iter()
+                    .rposition(u8::is_ascii_whitespace)
+                    .map(|p| p + 1)
+                    .unwrap_or(0);
+                let dmg_type = inner[type_start..bs].trim_ascii();
+                let dmg_type_id = parse_i64(&inner[bs + 1..be]);
+                if memchr(b'-', dmg_type).is_some() {
+                    (empty_istr(), 0)
+                } else {
+                    (intern_bytes(dmg_type), dmg_type_id)
+                }
             }
-        } else {
-            (intern(""), 0)
+            _ => (empty_istr(), 0),
         };
 
         // Parse absorbed amount from nested (X absorbed {id})
-        let dmg_absorbed =
-            if let Some(absorbed_pos) = memchr::memmem::find(inner_bytes, b"{836045448945511}") {
+        let dmg_absorbed = match memmem::find(inner, b"{836045448945511}") {
+            Some(absorbed_pos) => {
                 let before_absorbed = &inner[..absorbed_pos];
-                if let Some(nested_paren) = before_absorbed.rfind('(') {
-                    let num_section = &before_absorbed[nested_paren + 1..].trim_start();
-                    // Extract only the leading digits
-                    let num_end = num_section
-                        .find(|c: char| !c.is_ascii_digit())
-                        .unwrap_or(num_section.len());
-                    Some(parse_i32!(&num_section[..num_end]))
-                } else {
-                    Some(0)
+                match memrchr(b'(', before_absorbed) {
+                    Some(nested_paren) => {
+                        let num_section = before_absorbed[nested_paren + 1..].trim_ascii_start();
+                        // Extract only the leading digits
+                        parse_i32(&num_section[..digits_end(num_section)])
+                    }
+                    None => 0,
                 }
-            } else {
-                None
             }
-            .unwrap_or(0);
+            None => 0,
+        };
+
         Some(Details {
             dmg_amount,
             is_crit,
@@ -480,45 +521,24 @@ impl LogParser {
         None
     }
 
-    fn parse_heal_details(segment: &str) -> Option<Details> {
-        let bytes = segment.as_bytes();
-
+    fn parse_heal_details(segment: &[u8]) -> Option<Details> {
         // Find main delimiters
-        let paren = memchr(b'(', bytes)?;
-        let paren_end = memchr(b')', bytes)?;
-        let angle = memchr(b'<', bytes);
-        let angle_end = memchr(b'>', bytes);
+        let paren = memchr(b'(', segment)?;
+        let paren_end = memchr(b')', segment)?;
 
-        let inner = &segment[paren + 1..paren_end];
-        let inner_bytes = inner.as_bytes();
+        let inner = segment.get(paren + 1..paren_end)?;
 
         // Parse threat from <value> - only present if effective heal occurred
-        let threat = angle
-            .zip(angle_end)
-            .and_then(|(s, e)| segment[s + 1..e].parse::<f32>().ok())
-            .unwrap_or_default();
+        let threat = LogParser::parse_threat(segment);
 
         // Check for crit marker
-        let is_crit = memchr(b'*', inner_bytes).is_some();
+        let is_crit = memchr(b'*', inner).is_some();
 
         // Parse heal amount (first number)
-        let amount_end = inner
-            .find(|c: char| !c.is_ascii_digit())
-            .unwrap_or(inner.len());
-        let heal_amount = parse_i32!(&inner[..amount_end]);
+        let heal_amount = parse_i32(&inner[..digits_end(inner)]);
 
         // Parse effective heal after ~, default to heal_amount if not present
-        let tilde = memchr(b'~', inner_bytes);
-        let heal_effective = tilde
-            .map(|pos| {
-                let start = pos + 1;
-                let end = inner[start..]
-                    .find(|c: char| !c.is_ascii_digit())
-                    .map(|e| start + e)
-                    .unwrap_or(inner.len());
-                parse_i32!(&inner[start..end])
-            })
-            .unwrap_or(heal_amount);
+        let heal_effective = LogParser::parse_effective(inner).unwrap_or(heal_amount);
 
         Some(Details {
             heal_amount,
@@ -529,23 +549,18 @@ impl LogParser {
         })
     }
 
-    fn parse_charges(segment: &str) -> Option<Details> {
-        let bytes = segment.as_bytes();
-
-        let paren = memchr(b'(', bytes)?;
-        let paren_end = memchr(b')', bytes)?;
-        let brace = memchr(b'{', bytes)?;
-        let brace_end = memchr(b'}', bytes)?;
+    fn parse_charges(segment: &[u8]) -> Option<Details> {
+        let paren = memchr(b'(', segment)?;
+        let paren_end = memchr(b')', segment)?;
+        let brace = memchr(b'{', segment)?;
+        let brace_end = memchr(b'}', segment)?;
 
         // Parse count: number before "charges"
-        let inner = &segment[paren + 1..paren_end];
-        let count_end = inner
-            .find(|c: char| !c.is_ascii_digit())
-            .unwrap_or(inner.len());
-        let charges = parse_i32!(&inner[..count_end]);
+        let inner = segment.get(paren + 1..paren_end)?;
+        let charges = parse_i32(&inner[..digits_end(inner)]);
 
         // Parse ability ID
-        let ability_id = parse_i64!(&segment[brace + 1..brace_end]);
+        let ability_id = parse_i64(segment.get(brace + 1..brace_end)?);
 
         Some(Details {
             charges,
@@ -12,7 +12,7 @@ fn test_parser() -> LogParser {
 fn test_parse_entity_npc() {
     let parser = test_parser();
     let input = "Dread Master Bestia {3273941900591104}:5320000112163|(137.28,-120.98,-8.85,81.28)|(0/19129210)";
-    let result = parser.parse_entity(input);
+    let result = parser.parse_entity(input.as_bytes());
     assert!(result.is_some());
 
     let entity = result.unwrap();
@@ -28,7 +28,7 @@ fn test_parse_entity_npc() {
 fn test_parse_entity_player() {
     let parser = test_parser();
     let input = "@Galen Ayder#690129185314118|(-4700.43,-4750.48,710.03,-0.71)|(1/414851)";
-    let result = parser.parse_entity(input);
+    let result = parser.parse_entity(input.as_bytes());
     assert!(result.is_some());
 
     let entity = result.unwrap();
@@ -44,7 +44,7 @@ fn test_parse_entity_player() {
 fn test_parse_entity_companion() {
     let parser = test_parser();
     let input = "@Jerran Zeva#689501114780828/Raina Temple {493328533553152}:87481369009487|(4749.87,4694.53,710.05,0.00)|(288866/288866)";
-    let result = parser.parse_entity(input);
+    let result = parser.parse_entity(input.as_bytes());
     assert!(result.is_some());
 
     let entity = result.unwrap();
@@ -59,7 +59,7 @@ fn test_parse_entity_companion() {
 fn test_parse_entity_self_reference() {
     let parser = test_parser();
     let input = "=";
-    let result = parser.parse_entity(input);
+    let result = parser.parse_entity(input.as_bytes());
     assert!(result.is_some());
 
     let entity = result.unwrap();
@@ -70,7 +70,7 @@ fn test_parse_entity_self_reference() {
 fn test_parse_entity_empty() {
     let parser = test_parser();
     let input = "";
-    let result = parser.parse_entity(input);
+    let result = parser.parse_entity(input.as_bytes());
     assert!(result.is_some());
 
     let entity = result.unwrap();
@@ -82,7 +82,7 @@ fn test_parse_entity_empty() {
 #[test]
 fn test_parse_charges_one() {
     let input = "(1 charges {836045448953667})";
-    let result = LogParser::parse_charges(input);
+    let result = LogParser::parse_charges(input.as_bytes());
     assert!(result.is_some());
     let details = result.unwrap();
     assert_eq!(details.charges, 1);
@@ -91,7 +91,7 @@ fn test_parse_charges_one() {
 #[test]
 fn test_parse_charges_ten() {
     let input = "(10 charges {836045448953667})";
-    let result = LogParser::parse_charges(input);
+    let result = LogParser::parse_charges(input.as_bytes());
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -102,7 +102,7 @@ fn test_parse_charges_ten() {
 #[test]
 fn test_parse_details_damage_basic() {
     let input = " (5765 energy {836045448940874}) <5765.0>";
-    let result = LogParser::parse_details(input, effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -118,7 +118,7 @@ fn test_parse_details_damage_basic() {
 #[test]
 fn test_parse_details_damage_crit() {
     let input = " (7500* energy {836045448940874}) <7500.0>";
-    let result = LogParser::parse_details(input, effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -130,7 +130,7 @@ fn test_parse_details_damage_crit() {
 #[test]
 fn test_parse_details_damage_with_effective() {
     let input = " (5000 ~3500 kinetic {836045448940873}) <3500.0>";
-    let result = LogParser::parse_details(input, effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -143,7 +143,7 @@ fn test_parse_details_damage_with_effective() {
 fn test_parse_details_damage_with_absorbed() {
     let input =
         " (5000 ~3000 kinetic {836045448940873} (2000 absorbed {836045448945511})) <5000.0>";
-    let result = LogParser::parse_details(input, effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -156,7 +156,7 @@ fn test_parse_details_damage_with_absorbed() {
 #[test]
 fn test_parse_details_damage_miss() {
     let input = " (0 -miss {836045448945502}) <15000.0>";
-    let result = LogParser::parse_details(input, effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -168,7 +168,7 @@ fn test_parse_details_damage_miss() {
 #[test]
 fn test_parse_dmage_shielded() {
     let input = "(2583* energy {836045448940874} -shield {836045448945509} (1150 absorbed {836045448945511})) <2583.0>";
-    let result = LogParser::parse_details(input, effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -181,7 +181,7 @@ fn test_parse_dmage_shielded() {
 #[test]
 fn test_parse_damage_after_death() {
     let input = "(41422 ~0 energy {836045448940874} -)";
-    let result = LogParser::parse_details(input, effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
 
     assert!(result.is_some());
 
@@ -194,7 +194,7 @@ fn test_parse_damage_after_death() {
 #[test]
 fn test_parse_details_damage_reflect() {
     let input = "(116010 kinetic {836045448940873}(reflected {836045448953649}))";
-    let result = LogParser::parse_details(input, effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
     let details = result.unwrap();
 
     assert!(details.is_reflect);
@@ -205,7 +205,7 @@ fn test_parse_details_damage_reflect() {
 #[test]
 fn test_parse_details_damage_reflect_nullified() {
     let input = " (0 -) <1500.0>";
-    let result = LogParser::parse_details(input, effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::DAMAGE, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -217,7 +217,7 @@ fn test_parse_details_damage_reflect_nullified() {
 #[test]
 fn test_parse_details_heal_basic() {
     let input = " (3500) <1750>";
-    let result = LogParser::parse_details(input, effect_id::HEAL, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::HEAL, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -230,7 +230,7 @@ fn test_parse_details_heal_basic() {
 #[test]
 fn test_parse_details_heal_crit() {
     let input = " (5000*) <2500>";
-    let result = LogParser::parse_details(input, effect_id::HEAL, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::HEAL, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -241,7 +241,7 @@ fn test_parse_details_heal_crit() {
 #[test]
 fn test_parse_details_heal_with_effective() {
     let input = " (4000 ~2000) <1000>";
-    let result = LogParser::parse_details(input, effect_id::HEAL, effect_type_id::APPLYEFFECT);
+    let result = LogParser::parse_details(input.as_bytes(), effect_id::HEAL, effect_type_id::APPLYEFFECT);
     assert!(result.is_some());
 
     let details = result.unwrap();
@@ -254,7 +254,7 @@ fn test_parse_details_heal_with_effective() {
 fn test_parse_details_modify_charges() {
     let input = " (3 charges {836045448953667})";
     let result = LogParser::parse_details(
-        input,
+        input.as_bytes(),
         effect_id::ABILITYACTIVATE,
         effect_type_id::MODIFYCHARGES,
     );
@@ -269,7 +269,7 @@ fn test_parse_details_modify_charges() {
 fn test_parse_details_apply_effect_with_charges() {
     let input = " (5 charges {836045448953667})";
     let result = LogParser::parse_details(
-        input,
+        input.as_bytes(),
         effect_id::ABILITYACTIVATE,
         effect_type_id::APPLYEFFECT,
     );
@@ -283,7 +283,7 @@ fn test_parse_details_apply_effect_with_charges() {
 fn test_parse_details_default() {
     let input = "";
     let result = LogParser::parse_details(
-        input,
+        input.as_bytes(),
         effect_id::ABILITYACTIVATE,
         effect_type_id::DISCIPLINECHANGED,
     );
@@ -294,3 +294,146 @@ fn test_parse_details_default() {
     assert_eq!(details.heal_amount, 0);
     assert_eq!(details.charges, 0);
 }
+
+#[test]
+fn test_parse_raw_line_windows_1252_name() {
+    let parser = test_parser();
+    // "Zoë" in Windows-1252 (0xEB), as written by the game
+    let mut line = b"[18:28:46.123] [@Zo".to_vec();
+    line.push(0xEB);
+    line.extend_from_slice(
+        b"#690129185314118|(-4700.43,-4750.48,710.00,-0.71)|(1/414851)] [=] [] [Event {836045448945472}: AbilityActivate {836045448945479}]",
+    );
+
+    let event = parser.parse_raw_line(1, &line).unwrap();
+    assert_eq!(resolve(event.source_entity.name), "Zoë");
+    assert_eq!(event.source_entity.log_id, 690129185314118);
+}
+
+#[test]
+fn test_parse_int_rejects_invalid() {
+    assert_eq!(parse_i64(b"-42"), -42);
+    assert_eq!(parse_i32(b"+7"), 7);
+    assert_eq!(parse_i32(b""), 0);
+    assert_eq!(parse_i32(b"12a"), 0);
+    assert_eq!(parse_i32(b"99999999999"), 0);
+}
+
+// session clock
+fn timestamp_line(time: &str) -> String {
+    format!(
+        "[{}] [@Galen Ayder#690129185314118|(-4700.43,-4750.48,710.03,-0.71)|(1/414851)] [=] [] [Event {{836045448945472}}: AbilityActivate {{836045448945479}}]",
+        time
+    )
+}
+
+fn clocked_timestamps(session_start: &str, times: &[&str]) -> Vec<String> {
+    let date = NaiveDateTime::parse_from_str(session_start, "%Y-%m-%d %H:%M:%S").unwrap();
+    let parser = LogParser::new(date);
+    let mut clock = parser.clock();
+    times
+        .iter()
+        .enumerate()
+        .map(|(idx, time)| {
+            let mut event = parser
+                .parse_line(idx as u64, &timestamp_line(time))
+                .unwrap();
+            clock.apply(&mut event);
+            event.timestamp.format("%Y-%m-%d %H:%M:%S%.3f").to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn test_clock_rolls_over_at_midnight() {
+    let timestamps = clocked_timestamps(
+        "2024-03-01 23:50:00",
+        &[
+            "23:50:01.000",
+            "23:59:59.900",
+            "00:00:00.100",
+            "00:10:00.000",
+        ],
+    );
+    assert_eq!(
+        timestamps,
+        [
+            "2024-03-01 23:50:01.000",
+            "2024-03-01 23:59:59.900",
+            "2024-03-02 00:00:00.100",
+            "2024-03-02 00:10:00.000",
+        ]
+    );
+}
+
+#[test]
+fn test_clock_rolls_over_each_midnight() {
+    let timestamps = clocked_timestamps(
+        "2024-03-01 22:00:00",
+        &[
+            "23:00:00.000",
+            "00:30:00.000",
+            "12:00:00.000",
+            "23:59:00.000",
+            "00:01:00.000",
+        ],
+    );
+    assert_eq!(
+        timestamps,
+        [
+            "2024-03-01 23:00:00.000",
+            "2024-03-02 00:30:00.000",
+            "2024-03-02 12:00:00.000",
+            "2024-03-02 23:59:00.000",
+            "2024-03-03 00:01:00.000",
+        ]
+    );
+}
+
+#[test]
+fn test_clock_keeps_date_for_lines_before_session_start() {
+    // The file is named a moment after the first line is written
+    let timestamps = clocked_timestamps("2024-03-01 18:00:05", &["18:00:04.500", "18:00:06.000"]);
+    assert_eq!(
+        timestamps,
+        ["2024-03-01 18:00:04.500", "2024-03-01 18:00:06.000"]
+    );
+}
+
+#[test]
+fn test_clock_resumes_after_midnight() {
+    // First line read is already past midnight (e.g. tailing a resumed session)
+    let timestamps = clocked_timestamps("2024-03-01 21:00:00", &["00:30:00.000"]);
+    assert_eq!(timestamps, ["2024-03-02 00:30:00.000"]);
+}
+
+#[test]
+fn test_clock_stays_increasing_when_dst_ends() {
+    let timestamps = clocked_timestamps(
+        "2024-11-03 01:30:00",
+        &["01:59:58.000", "01:00:01.000", "01:30:00.000"],
+    );
+    assert_eq!(
+        timestamps,
+        [
+            "2024-11-03 01:59:58.000",
+            "2024-11-03 02:00:01.000",
+            "2024-11-03 02:30:00.000",
+        ]
+    );
+}
+
+#[test]
+fn test_midnight_crossing_encounter_duration() {
+    let date = NaiveDateTime::parse_from_str("2024-03-01 23:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    let parser = LogParser::new(date);
+    let mut clock = parser.clock();
+    let mut resolve_time = |time: &str| {
+        let mut event = parser.parse_line(0, &timestamp_line(time)).unwrap();
+        clock.apply(&mut event);
+        event.timestamp
+    };
+    let enter = resolve_time("23:59:30.000");
+    let exit = resolve_time("00:01:00.000");
+    assert_eq!(exit.signed_duration_since(enter).num_milliseconds(), 90_000);
+}
@@ -1,7 +1,7 @@
+use super::SessionClock;
 use super::error::ReaderError;
-use crate::context::ParsingSession;
+use crate::context::{IdleState, ParsingSession};
 use crate::{CombatEvent, LogParser};
-use encoding_rs::WINDOWS_1252;
 use memchr::memchr_iter;
 use memmap2::Mmap;
 use rayon::prelude::*;
@@ -20,6 +20,7 @@ const TAIL_SLEEP_DURATION: Duration = Duration::from_millis(30);
 pub struct Reader {
     path: PathBuf,
     state: Arc<RwLock<ParsingSession>>,
+    idle: Option<Arc<IdleState>>,
 }
 
 impl Reader {
@@ -27,9 +28,16 @@ impl Reader {
         Reader {
             path: file_path,
             state,
+            idle: None,
         }
     }
 
+    /// Slow tailing down while `idle` is in deep idle, and wake it on new lines
+    pub fn with_idle(mut self, idle: Arc<IdleState>) -> Self {
+        self.idle = Some(idle);
+        self
+    }
+
     /// Parallel-parse log file, returning all events.
     /// Fast but allocates Vec<CombatEvent>. Call mi_collect after processing.
     pub fn read_log_file_parallel(
@@ -55,27 +63,55 @@ impl Reader {
         }
 
         let parser = LogParser::new(session_date);
-        let events: Vec<CombatEvent> = line_ranges
+        let mut events: Vec<CombatEvent> = line_ranges
             .par_iter()
             .enumerate()
             .filter_map(|(idx, &(start, end))| {
-                let (line, _, _) = WINDOWS_1252.decode(&bytes[start..end]);
-                parser.parse_line(idx as u64 + 1, &line)
+                parser
+                    .parse_raw_line(idx as u64 + 1, &bytes[start..end])
+                    .map(|event| {
+                        event.at_log_range(start as u64, (end + 1).min(bytes.len()) as u64)
+                    })
             })
             .collect();
 
+        // Dates depend on the lines before, so they're resolved in order
+        let mut clock = parser.clock();
+        for event in &mut events {
+            clock.apply(event);
+        }
+
         Ok((events, end_pos))
     }
 
     /// Stream-parse log file, calling `on_event` for each parsed event.
     ///
     /// This avoids allocating a giant Vec of all events, keeping memory stable.
-    /// Returns the final byte position and event count.
+    /// Returns the final byte position and event count; `clock` is left at the
+    /// last line so tailing can carry on dating from there.
     ///
     /// Note: `session_date` must be passed in to avoid deadlock when caller holds session lock.
     pub fn read_log_file_streaming<F>(
         &self,
         session_date: chrono::NaiveDateTime,
+        clock: &mut SessionClock,
+        on_event: F,
+    ) -> Result<(u64, usize)>
+    where
+        F: FnMut(CombatEvent),
+    {
+        self.read_log_file_streaming_from(0, session_date, clock, on_event)
+    }
+
+    /// Stream-parse the log file starting at byte `start_pos` (a line start).
+    ///
+    /// Used to catch up on lines written after a parse checkpoint; `clock`
+    /// must be the one that dated the lines before `start_pos`.
+    pub fn read_log_file_streaming_from<F>(
+        &self,
+        start_pos: u64,
+        session_date: chrono::NaiveDateTime,
+        clock: &mut SessionClock,
         mut on_event: F,
     ) -> Result<(u64, usize)>
     where
@@ -89,14 +125,15 @@ impl Reader {
         let parser = LogParser::new(session_date);
         let mut event_count = 0usize;
         let mut line_number = 0u64;
-        let mut start = 0usize;
+        let begin = (start_pos as usize).min(bytes.len());
+        let mut start = begin;
 
         // Parse line by line using memchr for fast newline detection
-        for end in memchr_iter(b'\n', bytes) {
+        for end in memchr_iter(b'\n', &bytes[begin..]).map(|i| begin + i) {
             if end > start {
-                let (line, _, _) = WINDOWS_1252.decode(&bytes[start..end]);
-                if let Some(event) = parser.parse_line(line_number, &line) {
-                    on_event(event);
+                if let Some(mut event) = parser.parse_raw_line(line_number, &bytes[start..end]) {
+                    clock.apply(&mut event);
+                    on_event(event.at_log_range(start as u64, end as u64 + 1));
                     event_count += 1;
                 }
                 line_number += 1;
@@ -105,12 +142,12 @@ impl Reader {
         }
 
         // Handle final line without trailing newline
-        if start < bytes.len() {
-            let (line, _, _) = WINDOWS_1252.decode(&bytes[start..]);
-            if let Some(event) = parser.parse_line(line_number, &line) {
-                on_event(event);
-                event_count += 1;
-            }
+        if start < bytes.len()
+            && let Some(mut event) = parser.parse_raw_line(line_number, &bytes[start..])
+        {
+            clock.apply(&mut event);
+            on_event(event.at_log_range(start as u64, end_pos));
+            event_count += 1;
         }
 
         Ok((end_pos, event_count))
@@ -129,12 +166,14 @@ impl Reader {
         let mut line_number = 0u64;
         let pos = self.state.read().await.current_byte.unwrap_or(0);
 
-        let session_date = self
-            .state
-            .read()
-            .await
-            .game_session_date
-            .ok_or(ReaderError::SessionDateMissing)?;
+        let (session_date, clock) = {
+            let state = self.state.read().await;
+            (state.game_session_date, state.session_clock)
+        };
+        let session_date = session_date.ok_or(ReaderError::SessionDateMissing)?;
+        // Continue from the clock of the parse that read up to `pos`, which
+        // may already be days past the session date
+        let mut clock = clock.unwrap_or_else(|| SessionClock::new(session_date));
 
         reader
             .seek(SeekFrom::Start(pos))
@@ -146,30 +185,147 @@ impl Reader {
 
         let parser = LogParser::new(session_date);
         let mut buf = Vec::new();
+        let mut read_pos = pos;
 
         loop {
             match reader.read_until(b'\n', &mut buf).await {
                 Ok(0) => {
                     // No new data - tick combat state for wall-clock timeout
                     self.state.write().await.tick();
-                    sleep(TAIL_SLEEP_DURATION).await;
+                    match &self.idle {
+                        // Deep idle only watches for file growth
+                        Some(idle) => idle.poll_sleep(TAIL_SLEEP_DURATION).await,
+                        None => sleep(TAIL_SLEEP_DURATION).await,
+                    }
                     continue;
                 }
-                Ok(_) => {
+                Ok(n) => {
+                    read_pos += n as u64;
+                    if let Some(idle) = &self.idle
+                        && idle.wake()
+                    {
+                        tracing::debug!("New log data, leaving deep idle");
+                    }
                     // Only process if line is complete (ends with CRLF)
                     if buf.ends_with(CRLF) {
-                        let (line, _, _) = WINDOWS_1252.decode(&buf);
-                        if let Some(event) = parser.parse_line(line_number, &line) {
-                            self.state.write().await.process_event(event);
+                        let mut state = self.state.write().await;
+                        if let Some(mut event) = parser.parse_raw_line(line_number, &buf) {
+                            clock.apply(&mut event);
+                            // buf holds the whole line, which ends at read_pos
+                            state.process_event(
+                                event.at_log_range(read_pos - buf.len() as u64, read_pos),
+                            );
                         }
+                        // Track progress so stalled readers can be detected and resumed
+                        state.current_byte = Some(read_pos);
+                        state.session_clock = Some(clock);
+                        drop(state);
                         buf.clear();
                         line_number += 1;
                     }
                     // Otherwise keep partial data, next read will append to it
                 }
-                Err(_) => break,
+                Err(source) => {
+                    return Err(ReaderError::ReadFile {
+                        path: self.path.clone(),
+                        source,
+                    });
+                }
             }
         }
-        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::parse_file;
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    use std::io::Write;
+
+    fn line(time: &str) -> String {
+        format!(
+            "[{time}] [@Tank#1|(0,0,0,0)|(1/1)] [] [] [AreaEntered {{836045448953664}}: Dxun {{833571547775792}}] (he3001)\r\n"
+        )
+    }
+
+    fn at(day: u32, time: &str) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, day)
+            .unwrap()
+            .and_time(NaiveTime::parse_from_str(time, "%H:%M:%S").unwrap())
+    }
+
+    /// Log starting at 23:00 that has run past midnight into the next afternoon
+    fn write_log(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("baras-reader-{}-{}.txt", name, std::process::id()));
+        let lines = ["23:00:00.000", "00:30:00.000", "12:00:00.000"].map(line);
+        fs::write(&path, lines.concat()).unwrap();
+        path
+    }
+
+    fn append(path: &PathBuf, time: &str) {
+        let mut file = fs::OpenOptions::new().append(true).open(path).unwrap();
+        file.write_all(line(time).as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn test_streaming_from_continues_the_clock() {
+        let path = write_log("streaming");
+        let session_date = at(1, "23:00:00");
+        let reader = Reader::from(
+            path.clone(),
+            Arc::new(RwLock::new(ParsingSession::historical())),
+        );
+
+        let mut clock = SessionClock::new(session_date);
+        let (end_pos, count) = reader
+            .read_log_file_streaming(session_date, &mut clock, |_| {})
+            .unwrap();
+        assert_eq!(count, 3);
+
+        // 23:30 on the second day, not the session's first evening
+        append(&path, "23:30:00.000");
+        let mut timestamps = Vec::new();
+        reader
+            .read_log_file_streaming_from(end_pos, session_date, &mut clock, |event| {
+                timestamps.push(event.timestamp)
+            })
+            .unwrap();
+        assert_eq!(timestamps, vec![at(2, "23:30:00")]);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_tail_continues_across_midnight() {
+        let path = write_log("tail");
+        let mut session = ParsingSession::historical();
+        session.active_file = Some(path.clone());
+        session.game_session_date = Some(at(1, "23:00:00"));
+        let state = Arc::new(RwLock::new(session));
+
+        let result = parse_file(Arc::clone(&state)).await.unwrap();
+        append(&path, "23:30:00.000");
+        let end_pos = fs::metadata(&path).unwrap().len();
+
+        let tail = tokio::spawn(result.reader.tail_log_file());
+        let caught_up = tokio::time::timeout(Duration::from_secs(5), async {
+            while state.read().await.current_byte != Some(end_pos) {
+                sleep(TAIL_SLEEP_DURATION).await;
+            }
+        })
+        .await;
+        tail.abort();
+        assert!(caught_up.is_ok());
+
+        // The tailed line was dated on the second day, so the next one is too
+        let mut clock = state.read().await.session_clock.unwrap();
+        assert_eq!(
+            clock.resolve(NaiveTime::from_hms_opt(23, 31, 0).unwrap()),
+            at(2, "23:31:00")
+        );
+
+        let _ = fs::remove_file(&path);
     }
 }
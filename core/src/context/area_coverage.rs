@@ -0,0 +1,149 @@
+//! Area coverage of boss definitions
+//!
+//! Collects the operations entered across the user's log history so the ones
+//! without boss definitions can be reported (and contributed).
+
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{NaiveDate, NaiveDateTime};
+use encoding_rs::WINDOWS_1252;
+use memchr::{memchr, memmem, memrchr};
+
+pub use baras_types::{AreaCoverageReport, ObservedArea};
+
+use crate::LogParser;
+use crate::boss::AreaIndex;
+use crate::context::resolve;
+use crate::game_data::{Difficulty, effect_type_id};
+
+/// Smallest group size counted as an operation (flashpoints are 4 player)
+const OPERATION_GROUP_SIZE: u8 = 8;
+
+#[derive(Debug)]
+struct AreaVisits {
+    name: String,
+    difficulties: BTreeSet<String>,
+    visits: u32,
+    last_seen: NaiveDate,
+}
+
+/// Operations entered across a set of logs
+#[derive(Debug, Default)]
+pub struct AreaHistory {
+    areas: HashMap<i64, AreaVisits>,
+    scanned_files: usize,
+}
+
+impl AreaHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the operations entered in one log. Only `AreaEntered` lines are
+    /// parsed, so this stays cheap on large files.
+    pub fn record_log(&mut self, bytes: &[u8], session_date: NaiveDateTime) {
+        self.scanned_files += 1;
+        let parser = LogParser::new(session_date);
+        let needle = format!("{{{}}}", effect_type_id::AREAENTERED);
+
+        for pos in memmem::find_iter(bytes, needle.as_bytes()) {
+            let start = memrchr(b'\n', &bytes[..pos]).map_or(0, |i| i + 1);
+            let end = memchr(b'\n', &bytes[pos..]).map_or(bytes.len(), |i| pos + i);
+            let (line, _, _) = WINDOWS_1252.decode(&bytes[start..end]);
+            let Some(event) = parser.parse_line(0, line.trim_end()) else {
+                continue;
+            };
+            let effect = &event.effect;
+            let is_operation = Difficulty::from_difficulty_id(effect.difficulty_id)
+                .is_some_and(|d| d.group_size() >= OPERATION_GROUP_SIZE);
+            if effect.type_id != effect_type_id::AREAENTERED || !is_operation {
+                continue;
+            }
+
+            let date = event.timestamp.date();
+            let entry = self
+                .areas
+                .entry(effect.effect_id)
+                .or_insert_with(|| AreaVisits {
+                    name: resolve(effect.effect_name).to_string(),
+                    difficulties: BTreeSet::new(),
+                    visits: 0,
+                    last_seen: date,
+                });
+            entry
+                .difficulties
+                .insert(resolve(effect.difficulty_name).to_string());
+            entry.visits += 1;
+            entry.last_seen = entry.last_seen.max(date);
+        }
+    }
+
+    /// Check the recorded operations against the areas that have definitions
+    pub fn coverage_report(&self, index: &AreaIndex) -> AreaCoverageReport {
+        let mut areas: Vec<ObservedArea> = self
+            .areas
+            .iter()
+            .map(|(&area_id, visits)| ObservedArea {
+                area_id,
+                name: visits.name.clone(),
+                difficulties: visits.difficulties.iter().cloned().collect(),
+                visits: visits.visits,
+                last_seen: visits.last_seen.format("%Y-%m-%d").to_string(),
+                has_definitions: index.contains_key(&area_id),
+            })
+            .collect();
+        areas.sort_by(|a, b| b.visits.cmp(&a.visits).then_with(|| a.name.cmp(&b.name)));
+        AreaCoverageReport {
+            scanned_files: self.scanned_files,
+            areas,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::boss::AreaIndexEntry;
+
+    const DXUN: &str = "[20:00:00.000] [@Tank#1|(0,0,0,0)|(1/1)] [] [] [AreaEntered {836045448953664}: Dxun {833571547775792} 8 Player Veteran {836045448953652}] (he3001)\r\n";
+    const PALACE: &str = "[21:00:00.000] [@Tank#1|(0,0,0,0)|(1/1)] [] [] [AreaEntered {836045448953664}: The Dread Palace {137438993410} 16 Player Master {836045448953656}] (he3001)\r\n";
+    const FLEET: &str = "[19:00:00.000] [@Tank#1|(0,0,0,0)|(1/1)] [] [] [AreaEntered {836045448953664}: Imperial Fleet {137438989504}] (he3001)\r\n";
+    const COMBAT: &str = "[20:00:05.000] [@Tank#1|(0,0,0,0)|(1/1)] [] [] [Event {836045448945472}: EnterCombat {836045448945489}]\r\n";
+
+    #[test]
+    fn test_reports_operations_without_definitions() {
+        let date = |day| {
+            NaiveDate::from_ymd_opt(2026, 1, day)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        };
+        let mut history = AreaHistory::new();
+        history.record_log([FLEET, DXUN, COMBAT, PALACE].concat().as_bytes(), date(1));
+        history.record_log([DXUN, COMBAT].concat().as_bytes(), date(3));
+
+        let mut index = AreaIndex::new();
+        index.insert(
+            137438993410,
+            AreaIndexEntry {
+                name: "The Dread Palace".to_string(),
+                area_id: 137438993410,
+                file_path: PathBuf::from("the_dread_palace.toml"),
+            },
+        );
+
+        let report = history.coverage_report(&index);
+        assert_eq!(report.scanned_files, 2);
+        // The fleet has no difficulty and is not an operation
+        assert_eq!(report.areas.len(), 2);
+
+        let uncovered: Vec<&ObservedArea> = report.uncovered().collect();
+        assert_eq!(uncovered.len(), 1);
+        assert_eq!(uncovered[0].name, "Dxun");
+        assert_eq!(uncovered[0].visits, 2);
+        assert_eq!(uncovered[0].difficulties, vec!["8 Player Veteran"]);
+        assert_eq!(uncovered[0].last_seen, "2026-01-03");
+    }
+}
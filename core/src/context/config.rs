@@ -3,14 +3,22 @@
 //! This module re-exports shared types from baras-types and provides
 //! platform-specific Default implementation and persistence for AppConfig.
 
+use std::path::PathBuf;
+
+use baras_types::SafeModeStash;
+
 use super::error::ConfigError;
+use crate::game_data::Role;
 
 // Re-export all shared types
 pub use baras_types::{
-    AlertsOverlayConfig, AppConfig, BossHealthConfig, ChallengeColumns, ChallengeLayout,
-    ChallengeOverlayConfig, Color, HotkeySettings, MAX_PROFILES, OverlayAppearanceConfig,
-    OverlayPositionConfig, OverlayProfile, OverlaySettings, PersonalOverlayConfig, PersonalStat,
-    RaidOverlaySettings, TimerOverlayConfig, overlay_colors,
+    AlertBannerConfig, AlertsOverlayConfig, AppConfig, BossHealthConfig, BossNotesConfig,
+    ChallengeColumns, ChallengeLayout, ChallengeOverlayConfig, CharacterSettings, Color,
+    DeathRecapConfig, HotkeySettings, IgnoredId, MAX_NUMBER_DECIMALS, MAX_PROFILES, NumberFormat,
+    NumberStyle, OverlayAppearanceConfig, OverlayFrameStyle, OverlayPositionConfig, OverlayProfile,
+    OverlayRenderer, OverlaySettings, PersonalOverlayConfig, PersonalStat, PersonalStatFormat,
+    RaidCooldownConfig, RaidFrameLayout, RaidNameStyle, RaidOverlaySettings, TankSwapConfig,
+    TimerBarDirection, TimerOverlayConfig, TimerTextLayout, overlay_colors,
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -48,13 +56,23 @@ fn default_log_directory() -> String {
 pub trait AppConfigExt {
     fn load() -> Self;
     fn load_with_defaults() -> Self;
+    fn try_load() -> Result<Self, ConfigError>
+    where
+        Self: Sized;
+    fn config_path() -> Option<PathBuf>;
     fn save(self) -> Result<(), ConfigError>;
+    fn merge_external(&mut self, external: Self) -> bool;
     fn save_profile(&mut self, name: String) -> Result<(), &'static str>;
     fn load_profile(&mut self, name: &str) -> Result<(), &'static str>;
     fn delete_profile(&mut self, name: &str) -> Result<(), &'static str>;
     fn rename_profile(&mut self, old_name: &str, new_name: String) -> Result<(), &'static str>;
     fn profile_names(&self) -> Vec<String>;
     fn is_profile_name_available(&self, name: &str) -> bool;
+    fn create_role_profile(&mut self, role: Role) -> Result<String, &'static str>;
+    fn switch_character(&mut self, name: &str) -> bool;
+    fn enter_safe_mode(&mut self);
+    fn edits_safe_mode_overlays(&self, updated: &Self) -> bool;
+    fn into_persisted(self) -> Self;
 }
 
 impl AppConfigExt for AppConfig {
@@ -67,12 +85,58 @@ impl AppConfigExt for AppConfig {
         AppConfig::with_log_directory(default_log_directory())
     }
 
+    /// Load from disk, surfacing parse errors instead of falling back to defaults.
+    ///
+    /// Used for live reload, where a half-written or invalid file must not
+    /// clobber the running configuration.
+    fn try_load() -> Result<Self, ConfigError> {
+        Ok(confy::load("baras", "config")?)
+    }
+
+    /// Location of the TOML config file on disk
+    fn config_path() -> Option<PathBuf> {
+        confy::get_configuration_file_path("baras", "config").ok()
+    }
+
     fn save(self) -> Result<(), ConfigError> {
-        confy::store("baras", "config", self).map_err(ConfigError::Save)?;
+        confy::store("baras", "config", self.into_persisted()).map_err(ConfigError::Save)?;
         tracing::debug!("Configuration saved successfully");
         Ok(())
     }
 
+    /// Merge an externally edited config file into the running config.
+    ///
+    /// Every settings change is saved as it is made, so the file holds all
+    /// persisted settings and its values win. Runtime state is kept: overlay
+    /// visibility (toggled by hotkey) and safe mode, whose overrides stay live
+    /// while the file's overlay settings replace the stashed ones.
+    ///
+    /// Returns `false`, leaving the config untouched, when the file matches
+    /// what the app last saved (its own saves also trigger the watcher).
+    fn merge_external(&mut self, external: Self) -> bool {
+        let saved = self.clone().into_persisted();
+        if toml::to_string(&external).ok() == toml::to_string(&saved).ok() {
+            return false;
+        }
+
+        let overlays_visible = self.overlay_settings.overlays_visible;
+        let mut merged = external;
+        if let Some(mut stash) = self.safe_mode_stash.take() {
+            stash.overlay_settings = std::mem::replace(
+                &mut merged.overlay_settings,
+                std::mem::take(&mut self.overlay_settings),
+            );
+            stash.active_profile_name = std::mem::replace(
+                &mut merged.active_profile_name,
+                self.active_profile_name.take(),
+            );
+            merged.safe_mode_stash = Some(stash);
+        }
+        merged.overlay_settings.overlays_visible = overlays_visible;
+        *self = merged;
+        true
+    }
+
     fn save_profile(&mut self, name: String) -> Result<(), &'static str> {
         // Clone settings but reset visibility to default (visibility is independent of profiles)
         let mut settings_to_save = self.overlay_settings.clone();
@@ -121,6 +185,11 @@ impl AppConfigExt for AppConfig {
         if self.active_profile_name.as_deref() == Some(name) {
             self.active_profile_name = None;
         }
+        for character in self.character_settings.values_mut() {
+            if character.profile_name.as_deref() == Some(name) {
+                character.profile_name = None;
+            }
+        }
         Ok(())
     }
 
@@ -136,6 +205,11 @@ impl AppConfigExt for AppConfig {
             .ok_or("Profile not found")?;
         profile.name = new_name.clone();
 
+        for character in self.character_settings.values_mut() {
+            if character.profile_name.as_deref() == Some(old_name) {
+                character.profile_name = Some(new_name.clone());
+            }
+        }
         if self.active_profile_name.as_deref() == Some(old_name) {
             self.active_profile_name = Some(new_name);
         }
@@ -149,4 +223,323 @@ impl AppConfigExt for AppConfig {
     fn is_profile_name_available(&self, name: &str) -> bool {
         !self.profiles.iter().any(|p| p.name == name)
     }
+
+    /// Add a starter profile for `role`: the current overlay settings with only
+    /// the overlays that role relies on enabled. Returns the new profile's name.
+    fn create_role_profile(&mut self, role: Role) -> Result<String, &'static str> {
+        let name = role_profile_name(role).to_string();
+        if !self.is_profile_name_available(&name) {
+            return Err("A profile with that name already exists");
+        }
+        if self.profiles.len() >= MAX_PROFILES {
+            return Err("Maximum number of profiles reached (12)");
+        }
+
+        let mut settings = self.overlay_settings.clone();
+        settings.overlays_visible = true;
+        settings.enabled = role_overlays(role)
+            .iter()
+            .map(|key| (key.to_string(), true))
+            .collect();
+
+        self.profiles.push(OverlayProfile::new(name.clone(), settings));
+        Ok(name)
+    }
+
+    /// Make `name` the active character and load the overlay profile bound to it.
+    ///
+    /// Returns `false` if `name` was already active. A bound profile that no
+    /// longer exists is skipped, leaving the current overlay settings.
+    fn switch_character(&mut self, name: &str) -> bool {
+        if self.active_character.as_deref() == Some(name) {
+            return false;
+        }
+        self.active_character = Some(name.to_string());
+
+        let profile = self
+            .character_settings
+            .get(name)
+            .and_then(|c| c.profile_name.clone());
+        // Safe mode keeps overlays off until restart
+        if let Some(profile) = profile
+            && self.safe_mode_stash.is_none()
+            && self.active_profile_name.as_ref() != Some(&profile)
+            && let Err(e) = self.load_profile(&profile)
+        {
+            tracing::warn!(
+                character = name,
+                profile,
+                error = e,
+                "Failed to load character profile"
+            );
+        }
+        true
+    }
+
+    /// Disable every overlay and reset overlay positions and appearance to
+    /// defaults. The replaced settings are stashed and written back on save,
+    /// so safe mode lasts until the app exits and never reaches disk.
+    fn enter_safe_mode(&mut self) {
+        if self.safe_mode_stash.is_none() {
+            self.safe_mode_stash = Some(Box::new(SafeModeStash {
+                overlay_settings: self.overlay_settings.clone(),
+                active_profile_name: self.active_profile_name.clone(),
+            }));
+        }
+
+        let settings = &mut self.overlay_settings;
+        settings
+            .enabled
+            .values_mut()
+            .for_each(|enabled| *enabled = false);
+        settings.positions.clear();
+        settings.layout_positions.clear();
+        settings.active_monitor_layout = None;
+        settings.appearances.clear();
+        settings.default_appearances.clear();
+        self.active_profile_name = None;
+    }
+
+    /// Whether `updated` changes overlay settings while safe mode is active.
+    /// Saves write the stashed settings instead, so such edits would be lost.
+    /// Visibility and the default appearances filled in for the frontend are
+    /// not edits.
+    fn edits_safe_mode_overlays(&self, updated: &Self) -> bool {
+        if self.safe_mode_stash.is_none() {
+            return false;
+        }
+        let comparable = |settings: &OverlaySettings| {
+            let mut settings = settings.clone();
+            settings.overlays_visible = false;
+            settings.default_appearances.clear();
+            serde_json::to_value(settings).ok()
+        };
+        comparable(&self.overlay_settings) != comparable(&updated.overlay_settings)
+    }
+
+    /// The config as it should be written to disk: safe mode overrides are
+    /// replaced by the settings they stashed.
+    fn into_persisted(mut self) -> Self {
+        if let Some(stash) = self.safe_mode_stash.take() {
+            self.overlay_settings = stash.overlay_settings;
+            self.active_profile_name = stash.active_profile_name;
+        }
+        self
+    }
+}
+
+fn role_profile_name(role: Role) -> &'static str {
+    match role {
+        Role::Tank => "Tank (default)",
+        Role::Healer => "Healer (default)",
+        Role::Dps => "DPS (default)",
+    }
+}
+
+/// Overlays enabled in a role's starter profile
+fn role_overlays(role: Role) -> &'static [&'static str] {
+    match role {
+        Role::Tank => &["tps", "dtps"],
+        Role::Healer => &["raid", "effects_a", "effects_b"],
+        Role::Dps => &["dps", "timers_a"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_profiles() -> AppConfig {
+        let mut config = AppConfig::with_log_directory(String::new());
+        let mut healer = OverlaySettings::default();
+        healer.metric_opacity = 42;
+        config.profiles = vec![
+            OverlayProfile::new("Tank".to_string(), OverlaySettings::default()),
+            OverlayProfile::new("Healer".to_string(), healer),
+        ];
+        config.character_settings.insert(
+            "Healbot".to_string(),
+            CharacterSettings {
+                profile_name: Some("Healer".to_string()),
+                audio: None,
+            },
+        );
+        config
+    }
+
+    #[test]
+    fn test_switch_character_loads_bound_profile() {
+        let mut config = config_with_profiles();
+
+        assert!(config.switch_character("Healbot"));
+        assert_eq!(config.active_profile_name.as_deref(), Some("Healer"));
+        assert_eq!(config.overlay_settings.metric_opacity, 42);
+
+        // Same character again is a no-op
+        assert!(!config.switch_character("Healbot"));
+
+        // Characters without a binding keep the current profile
+        assert!(config.switch_character("Alt"));
+        assert_eq!(config.active_character.as_deref(), Some("Alt"));
+        assert_eq!(config.active_profile_name.as_deref(), Some("Healer"));
+    }
+
+    #[test]
+    fn test_profile_rename_and_delete_update_bindings() {
+        let mut config = config_with_profiles();
+
+        config
+            .rename_profile("Healer", "Heals".to_string())
+            .unwrap();
+        assert_eq!(
+            config.character_settings["Healbot"].profile_name.as_deref(),
+            Some("Heals")
+        );
+
+        config.delete_profile("Heals").unwrap();
+        assert_eq!(config.character_settings["Healbot"].profile_name, None);
+    }
+
+    #[test]
+    fn test_create_role_profile_enables_role_overlays() {
+        let mut config = config_with_profiles();
+
+        let name = config.create_role_profile(Role::Healer).unwrap();
+        let profile = config.profiles.iter().find(|p| p.name == name).unwrap();
+        assert!(profile.settings.enabled["raid"]);
+        assert!(profile.settings.enabled["effects_a"]);
+        assert!(!profile.settings.enabled.contains_key("dps"));
+        // Creating doesn't switch to it
+        assert_eq!(config.active_profile_name, None);
+
+        assert!(config.create_role_profile(Role::Healer).is_err());
+    }
+
+    #[test]
+    fn test_safe_mode_is_not_persisted() {
+        let mut config = config_with_profiles();
+        config.load_profile("Healer").unwrap();
+        config
+            .overlay_settings
+            .enabled
+            .insert("dps".to_string(), true);
+        config.overlay_settings.set_position(
+            "dps",
+            OverlayPositionConfig {
+                x: 3000,
+                y: 40,
+                ..Default::default()
+            },
+        );
+        let mut appearance = OverlayAppearanceConfig::default();
+        appearance.max_entries = 3;
+        config.overlay_settings.set_appearance("dps", appearance);
+
+        config.enter_safe_mode();
+        assert!(!config.overlay_settings.enabled["dps"]);
+        assert!(config.overlay_settings.positions.is_empty());
+        assert!(config.overlay_settings.appearances.is_empty());
+        assert_eq!(config.active_profile_name, None);
+
+        // Entering again keeps the original stash
+        config.enter_safe_mode();
+
+        let saved = toml::to_string(&config.into_persisted()).unwrap();
+        let loaded: AppConfig = toml::from_str(&saved).unwrap();
+        let settings = &loaded.overlay_settings;
+        assert!(settings.enabled["dps"]);
+        assert_eq!(settings.get_position("dps").x, 3000);
+        assert_eq!(settings.get_appearance("dps").max_entries, 3);
+        assert_eq!(settings.metric_opacity, 42);
+        assert_eq!(loaded.active_profile_name.as_deref(), Some("Healer"));
+    }
+
+    #[test]
+    fn test_merge_external_takes_file_settings() {
+        let mut config = config_with_profiles();
+        config.overlay_settings.overlays_visible = false;
+
+        // The app's own save is not an external edit
+        assert!(!config.merge_external(config.clone()));
+
+        let mut external = config.clone();
+        external.overlay_settings.overlays_visible = true;
+        external.latency_ms = 120;
+        external.overlay_settings.metric_opacity = 10;
+        assert!(config.merge_external(external));
+        assert_eq!(config.latency_ms, 120);
+        assert_eq!(config.overlay_settings.metric_opacity, 10);
+        // Visibility is runtime state
+        assert!(!config.overlay_settings.overlays_visible);
+    }
+
+    #[test]
+    fn test_merge_external_keeps_safe_mode() {
+        let mut config = config_with_profiles();
+        config
+            .overlay_settings
+            .enabled
+            .insert("dps".to_string(), true);
+        let on_disk = config.clone();
+        config.enter_safe_mode();
+
+        // Saving in safe mode writes the original settings back
+        assert!(!config.merge_external(on_disk.clone()));
+
+        let mut external = on_disk;
+        external.latency_ms = 120;
+        external
+            .overlay_settings
+            .enabled
+            .insert("raid".to_string(), true);
+        assert!(config.merge_external(external));
+        assert_eq!(config.latency_ms, 120);
+        assert!(!config.overlay_settings.enabled["dps"]);
+        assert!(!config.overlay_settings.enabled.contains_key("raid"));
+
+        let persisted = config.into_persisted();
+        assert!(persisted.overlay_settings.enabled["dps"]);
+        assert!(persisted.overlay_settings.enabled["raid"]);
+    }
+
+    #[test]
+    fn test_safe_mode_overlay_edits_are_detected() {
+        let mut config = config_with_profiles();
+        let mut updated = config.clone();
+        updated.overlay_settings.set_enabled("dps", true);
+        assert!(!config.edits_safe_mode_overlays(&updated));
+
+        config.enter_safe_mode();
+        assert!(config.edits_safe_mode_overlays(&updated));
+
+        // The frontend's copy has default appearances filled in
+        let mut unchanged = config.clone();
+        unchanged.overlay_settings.overlays_visible = !config.overlay_settings.overlays_visible;
+        unchanged.overlay_settings.default_appearances.insert(
+            "dps".to_string(),
+            OverlayAppearanceConfig::default_for_type("dps"),
+        );
+        unchanged.latency_ms = 120;
+        assert!(!config.edits_safe_mode_overlays(&unchanged));
+
+        let mut moved = config.clone();
+        moved.overlay_settings.set_position(
+            "dps",
+            OverlayPositionConfig {
+                x: 100,
+                ..Default::default()
+            },
+        );
+        assert!(config.edits_safe_mode_overlays(&moved));
+    }
+
+    #[test]
+    fn test_safe_mode_ignores_character_profiles() {
+        let mut config = config_with_profiles();
+        config.enter_safe_mode();
+
+        assert!(config.switch_character("Healbot"));
+        assert_eq!(config.active_profile_name, None);
+        assert!(config.overlay_settings.enabled.is_empty());
+    }
 }
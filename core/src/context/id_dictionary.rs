@@ -0,0 +1,435 @@
+//! Local dictionary of game IDs seen in parsed logs
+//!
+//! Every NPC, ability and effect ID is recorded with the name it was logged
+//! under, the date it was first seen and the area it was seen in. An ID the
+//! game renames (usually in a patch) keeps all of its names, which lets
+//! name-based selectors written against an old name keep matching.
+//!
+//! The dictionary is stored as tab-separated rows of
+//! `kind, id, first_seen, context, name`.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub use baras_types::{IdDictionaryEntry, IdNameRecord};
+
+use crate::combat_log::CombatEvent;
+use crate::context::resolve;
+use crate::dsl::{AbilitySelector, BossEncounterDefinition, EffectSelector, Trigger};
+use crate::game_data::{GameIdKind, named_ids};
+
+/// File name of the dictionary, in the config directory and in each
+/// session's data directory (written by the parse worker)
+pub const ID_DICTIONARY_FILENAME: &str = "id_dictionary.tsv";
+
+const TSV_HEADER: &str = "kind\tid\tfirst_seen\tcontext\tname";
+
+/// Path of the user's dictionary (`~/.config/baras/id_dictionary.tsv`)
+pub fn id_dictionary_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join(ID_DICTIONARY_FILENAME))
+}
+
+fn to_entry(kind: GameIdKind, id: i64, records: &[IdNameRecord]) -> IdDictionaryEntry {
+    IdDictionaryEntry {
+        kind,
+        id,
+        names: records.to_vec(),
+    }
+}
+
+/// Game IDs with every name they were logged under
+#[derive(Debug, Default)]
+pub struct IdDictionary {
+    /// Names per ID, ordered by first-seen date
+    names: HashMap<(GameIdKind, i64), Vec<IdNameRecord>>,
+    /// Changed since the last load or save
+    dirty: bool,
+}
+
+impl IdDictionary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a dictionary file. A missing file gives an empty dictionary.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut dictionary = Self::new();
+        match std::fs::read_to_string(path) {
+            Ok(content) => dictionary.add_tsv(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        dictionary.dirty = false;
+        Ok(dictionary)
+    }
+
+    /// Write the dictionary and mark it clean
+    pub fn save(&mut self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_tsv())?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// True if IDs or names were added since the last load or save
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Record a name for an ID. A name already known for the ID keeps its
+    /// earliest first-seen date and context.
+    pub fn insert(&mut self, kind: GameIdKind, id: i64, record: IdNameRecord) {
+        if id == 0 || record.name.is_empty() {
+            return;
+        }
+        let records = self.names.entry((kind, id)).or_default();
+        match records.iter_mut().find(|r| r.name == record.name) {
+            Some(existing) if existing.first_seen <= record.first_seen => return,
+            Some(existing) => *existing = record,
+            None => records.push(record),
+        }
+        records.sort_by(|a, b| a.first_seen.cmp(&b.first_seen));
+        self.dirty = true;
+    }
+
+    /// Record the NPCs, ability and effect named in an event.
+    /// `context` is the area the event happened in.
+    pub fn record_event(&mut self, event: &CombatEvent, context: &str) {
+        for (kind, id, name) in named_ids(event).into_iter().flatten() {
+            let name = resolve(name);
+            // Fast path: the ID is already known under this name
+            if self
+                .names
+                .get(&(kind, id))
+                .is_some_and(|records| records.iter().any(|r| r.name == name))
+            {
+                continue;
+            }
+            self.insert(
+                kind,
+                id,
+                IdNameRecord {
+                    name: name.to_string(),
+                    first_seen: event.timestamp.format("%Y-%m-%d").to_string(),
+                    context: context.to_string(),
+                },
+            );
+        }
+    }
+
+    /// Add every name from another dictionary (e.g. one written by the parse worker)
+    pub fn merge(&mut self, other: IdDictionary) {
+        for ((kind, id), records) in other.names {
+            for record in records {
+                self.insert(kind, id, record);
+            }
+        }
+    }
+
+    /// Load rows written by [`Self::to_tsv`]. Malformed rows are skipped.
+    pub fn add_tsv(&mut self, tsv: &str) {
+        for line in tsv.lines().skip(1) {
+            let mut parts = line.splitn(5, '\t');
+            let (Some(kind), Some(id), Some(first_seen), Some(context), Some(name)) = (
+                parts.next(),
+                parts.next(),
+                parts.next(),
+                parts.next(),
+                parts.next(),
+            ) else {
+                continue;
+            };
+            if let (Some(kind), Ok(id)) = (GameIdKind::from_key(kind), id.parse()) {
+                self.insert(
+                    kind,
+                    id,
+                    IdNameRecord {
+                        name: name.to_string(),
+                        first_seen: first_seen.to_string(),
+                        context: context.to_string(),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Write the dictionary as TSV, sorted by kind and ID
+    pub fn to_tsv(&self) -> String {
+        let mut keys: Vec<_> = self.names.keys().copied().collect();
+        keys.sort();
+        let mut out = String::from(TSV_HEADER);
+        out.push('\n');
+        for (kind, id) in keys {
+            for record in &self.names[&(kind, id)] {
+                out.push_str(&format!(
+                    "{}\t{}\t{}\t{}\t{}\n",
+                    kind.key(),
+                    id,
+                    record.first_seen,
+                    record.context,
+                    record.name
+                ));
+            }
+        }
+        out
+    }
+
+    /// Every name an ID was logged under
+    pub fn lookup(&self, kind: GameIdKind, id: i64) -> Option<IdDictionaryEntry> {
+        self.names
+            .get(&(kind, id))
+            .map(|records| to_entry(kind, id, records))
+    }
+
+    /// IDs of `kind` logged under `name` at any time (case-insensitive)
+    pub fn ids_named(&self, kind: GameIdKind, name: &str) -> Vec<i64> {
+        let mut ids: Vec<i64> = self
+            .names
+            .iter()
+            .filter(|((k, _), records)| {
+                *k == kind && records.iter().any(|r| r.name.eq_ignore_ascii_case(name))
+            })
+            .map(|((_, id), _)| *id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Find IDs by any of their names (case-insensitive substring) or by ID prefix
+    pub fn search(
+        &self,
+        query: &str,
+        kind: Option<GameIdKind>,
+        limit: usize,
+    ) -> Vec<IdDictionaryEntry> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut matches: Vec<IdDictionaryEntry> = self
+            .names
+            .iter()
+            .filter(|((k, _), _)| kind.is_none_or(|kind| kind == *k))
+            .filter(|((_, id), records)| {
+                records
+                    .iter()
+                    .any(|r| r.name.to_lowercase().contains(&query))
+                    || id.to_string().starts_with(&query)
+            })
+            .map(|(&(kind, id), records)| to_entry(kind, id, records))
+            .collect();
+        matches.sort_by(|a, b| {
+            (a.current_name(), a.kind, a.id).cmp(&(b.current_name(), b.kind, b.id))
+        });
+        matches.truncate(limit);
+        matches
+    }
+
+    /// IDs logged under more than one name, most recently renamed first
+    pub fn renamed(&self) -> Vec<IdDictionaryEntry> {
+        let mut renamed: Vec<IdDictionaryEntry> = self
+            .names
+            .iter()
+            .filter(|(_, records)| records.len() > 1)
+            .map(|(&(kind, id), records)| to_entry(kind, id, records))
+            .collect();
+        renamed.sort_by(|a, b| {
+            let last_seen = |e: &IdDictionaryEntry| e.names.last().map(|r| r.first_seen.clone());
+            last_seen(b).cmp(&last_seen(a)).then(a.id.cmp(&b.id))
+        });
+        renamed
+    }
+
+    /// Renamed IDs of `kind` once logged under `name`, but not any more
+    fn former_ids(&self, kind: GameIdKind, name: &str) -> impl Iterator<Item = i64> {
+        self.names
+            .iter()
+            .filter(move |((k, _), records)| {
+                *k == kind
+                    && records.len() > 1
+                    && records
+                        .last()
+                        .is_some_and(|current| !current.name.eq_ignore_ascii_case(name))
+                    && records.iter().any(|r| r.name.eq_ignore_ascii_case(name))
+            })
+            .map(|((_, id), _)| *id)
+    }
+
+    /// Add the IDs behind renamed abilities and effects to name selectors in
+    /// a boss's timer, phase and counter triggers, so a selector written
+    /// against a name from before a patch still matches after it.
+    /// Returns the number of IDs added.
+    pub fn expand_renamed_selectors(&self, boss: &mut BossEncounterDefinition) -> usize {
+        let mut added = 0;
+        for timer in &mut boss.timers {
+            added += self.expand_trigger(&mut timer.trigger);
+            if let Some(cancel) = timer.cancel_trigger.as_mut() {
+                added += self.expand_trigger(cancel);
+            }
+        }
+        for phase in &mut boss.phases {
+            added += self.expand_trigger(&mut phase.start_trigger);
+            if let Some(end) = phase.end_trigger.as_mut() {
+                added += self.expand_trigger(end);
+            }
+        }
+        for counter in &mut boss.counters {
+            added += self.expand_trigger(&mut counter.increment_on);
+            if let Some(decrement) = counter.decrement_on.as_mut() {
+                added += self.expand_trigger(decrement);
+            }
+            added += self.expand_trigger(&mut counter.reset_on);
+        }
+        added
+    }
+
+    fn expand_trigger(&self, trigger: &mut Trigger) -> usize {
+        match trigger {
+            Trigger::AbilityCast { abilities, .. }
+            | Trigger::CastStarted { abilities, .. }
+            | Trigger::CastCompleted { abilities, .. }
+            | Trigger::DamageTaken { abilities, .. } => {
+                let ids: Vec<u64> = abilities
+                    .iter()
+                    .filter_map(|s| match s {
+                        AbilitySelector::Name(name) => Some(name),
+                        AbilitySelector::Id(_) => None,
+                    })
+                    .flat_map(|name| self.former_ids(GameIdKind::Ability, name))
+                    .map(|id| id as u64)
+                    .collect();
+                let before = abilities.len();
+                for id in ids {
+                    if !abilities.contains(&AbilitySelector::Id(id)) {
+                        abilities.push(AbilitySelector::Id(id));
+                    }
+                }
+                abilities.len() - before
+            }
+            Trigger::EffectApplied { effects, .. } | Trigger::EffectRemoved { effects, .. } => {
+                let ids: Vec<u64> = effects
+                    .iter()
+                    .filter_map(|s| match s {
+                        EffectSelector::Name(name) => Some(name),
+                        EffectSelector::Id(_) => None,
+                    })
+                    .flat_map(|name| self.former_ids(GameIdKind::Effect, name))
+                    .map(|id| id as u64)
+                    .collect();
+                let before = effects.len();
+                for id in ids {
+                    if !effects.contains(&EffectSelector::Id(id)) {
+                        effects.push(EffectSelector::Id(id));
+                    }
+                }
+                effects.len() - before
+            }
+            Trigger::AnyOf { conditions } | Trigger::AllOf { conditions, .. } => {
+                conditions.iter_mut().map(|c| self.expand_trigger(c)).sum()
+            }
+            Trigger::Not { condition } => self.expand_trigger(condition),
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat_log::LogParser;
+    use crate::dsl::BossConfig;
+    use chrono::NaiveDate;
+
+    const BEFORE_PATCH: &str = "[18:43:13.512] [@Tank#1|(0,0,0,0)|(1/1)] [Dread Monster {3291675820556288}:5320000116014|(0,0,0,0)|(100/100)] [Corrosive Dart {3465720780292096}] [ApplyEffect {836045448945477}: Corrosive Dart {3465720780292096}]";
+    const AFTER_PATCH: &str = "[18:43:13.512] [@Tank#1|(0,0,0,0)|(1/1)] [Dread Monster {3291675820556288}:5320000116014|(0,0,0,0)|(100/100)] [Toxic Dart {3465720780292096}] [ApplyEffect {836045448945477}: Toxic Dart {3465720780292096}]";
+
+    fn record(dictionary: &mut IdDictionary, line: &str, day: u32) {
+        let date = NaiveDate::from_ymd_opt(2026, 1, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let event = LogParser::new(date).parse_line(1, line).unwrap();
+        dictionary.record_event(&event, "The Dread Palace");
+    }
+
+    #[test]
+    fn test_records_renames() {
+        let mut dictionary = IdDictionary::new();
+        record(&mut dictionary, BEFORE_PATCH, 1);
+        record(&mut dictionary, BEFORE_PATCH, 2);
+        record(&mut dictionary, AFTER_PATCH, 9);
+        assert!(dictionary.is_dirty());
+
+        let entry = dictionary
+            .lookup(GameIdKind::Ability, 3465720780292096)
+            .unwrap();
+        assert_eq!(entry.current_name(), Some("Toxic Dart"));
+        assert_eq!(entry.names[0].name, "Corrosive Dart");
+        assert_eq!(entry.names[0].first_seen, "2026-01-01");
+        assert_eq!(entry.names[0].context, "The Dread Palace");
+        assert_eq!(
+            dictionary.ids_named(GameIdKind::Effect, "corrosive dart"),
+            vec![3465720780292096]
+        );
+        // The NPC was never renamed
+        assert_eq!(dictionary.renamed().len(), 2);
+        assert_eq!(dictionary.search("dart", None, 10).len(), 2);
+    }
+
+    #[test]
+    fn test_tsv_round_trip_and_merge() {
+        let mut older = IdDictionary::new();
+        record(&mut older, BEFORE_PATCH, 3);
+        let mut newer = IdDictionary::new();
+        record(&mut newer, BEFORE_PATCH, 1);
+        record(&mut newer, AFTER_PATCH, 9);
+
+        let mut loaded = IdDictionary::new();
+        loaded.add_tsv(&older.to_tsv());
+        loaded.merge(newer);
+        let entry = loaded
+            .lookup(GameIdKind::Ability, 3465720780292096)
+            .unwrap();
+        assert_eq!(entry.names.len(), 2);
+        // The earlier sighting wins
+        assert_eq!(entry.names[0].first_seen, "2026-01-01");
+    }
+
+    #[test]
+    fn test_expands_selectors_for_renamed_ids() {
+        let mut dictionary = IdDictionary::new();
+        record(&mut dictionary, BEFORE_PATCH, 1);
+        record(&mut dictionary, AFTER_PATCH, 9);
+
+        let config: BossConfig = toml::from_str(
+            r#"
+            [[boss]]
+            id = "bestia"
+            name = "Dread Master Bestia"
+
+            [[boss.timer]]
+            id = "dart"
+            name = "Dart"
+            trigger = { type = "ability_cast", abilities = ["Corrosive Dart"] }
+            "#,
+        )
+        .unwrap();
+        let mut boss = config.bosses.into_iter().next().unwrap();
+        assert_eq!(dictionary.expand_renamed_selectors(&mut boss), 1);
+        assert!(
+            boss.timers[0]
+                .trigger
+                .matches_ability(3465720780292096, Some("Toxic Dart"))
+        );
+        // Already expanded
+        assert_eq!(dictionary.expand_renamed_selectors(&mut boss), 0);
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use tokio::sync::Notify;
+
+/// How often a deep-idle loop checks for work when nothing wakes it
+pub const DEEP_IDLE_POLL: Duration = Duration::from_secs(1);
+
+/// Switch shared by the polling loops to drop into deep idle between play sessions.
+///
+/// In deep idle the tail reader only checks the log file for growth, and
+/// every other loop sleeps until [`IdleState::wake`] is called. The first
+/// new log line (or the window being shown) wakes everything at once.
+#[derive(Debug)]
+pub struct IdleState {
+    deep: AtomicBool,
+    /// Last combat or wake-up, which the deep-idle timeout counts from
+    last_activity: Mutex<Instant>,
+    wake: Notify,
+}
+
+impl Default for IdleState {
+    fn default() -> Self {
+        Self {
+            deep: AtomicBool::new(false),
+            last_activity: Mutex::new(Instant::now()),
+            wake: Notify::new(),
+        }
+    }
+}
+
+impl IdleState {
+    pub fn is_deep_idle(&self) -> bool {
+        self.deep.load(Ordering::Relaxed)
+    }
+
+    /// Record activity that postpones deep idle (e.g. an ongoing combat)
+    pub fn touch(&self) {
+        *self.last_activity.lock().unwrap_or_else(|p| p.into_inner()) = Instant::now();
+    }
+
+    /// Time since the last combat or wake-up
+    pub fn idle_for(&self) -> Duration {
+        self.last_activity
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .elapsed()
+    }
+
+    /// Enter deep idle. Returns false if already idle.
+    pub fn enter_deep_idle(&self) -> bool {
+        !self.deep.swap(true, Ordering::Relaxed)
+    }
+
+    /// Leave deep idle and release every waiting loop. Returns false if not idle.
+    pub fn wake(&self) -> bool {
+        if !self.deep.swap(false, Ordering::Relaxed) {
+            return false;
+        }
+        self.touch();
+        self.wake.notify_waiters();
+        true
+    }
+
+    /// Sleep for `interval`, or in deep idle until woken
+    pub async fn sleep(&self, interval: Duration) {
+        if !self.is_deep_idle() {
+            tokio::time::sleep(interval).await;
+            return;
+        }
+        // Registered before re-checking so a wake in between isn't missed
+        let woken = self.wake.notified();
+        if self.is_deep_idle() {
+            woken.await;
+        }
+    }
+
+    /// Sleep for `interval`, or in deep idle until woken or [`DEEP_IDLE_POLL`] passes
+    pub async fn poll_sleep(&self, interval: Duration) {
+        if !self.is_deep_idle() {
+            tokio::time::sleep(interval).await;
+            return;
+        }
+        let woken = self.wake.notified();
+        if self.is_deep_idle() {
+            let _ = tokio::time::timeout(DEEP_IDLE_POLL, woken).await;
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Ability/effect ignore list
+//!
+//! IDs from [`AppConfig::ignored_ids`](super::AppConfig) are dropped before
+//! events reach metrics and effect tracking, and filtered out of encounter
+//! queries. An ID matches an event's ability ID or its effect ID.
+
+use std::collections::HashSet;
+
+use crate::combat_log::CombatEvent;
+use crate::context::AppConfig;
+use crate::game_data::effect_id;
+
+/// Core event IDs shared by every damage/heal/combat event; ignoring one of
+/// these would empty the log, so they are refused.
+const PROTECTED_IDS: &[i64] = &[
+    effect_id::ABILITYACTIVATE,
+    effect_id::ABILITYCANCEL,
+    effect_id::ABILITYDEACTIVATE,
+    effect_id::ABILITYINTERRUPT,
+    effect_id::CONVERSATION,
+    effect_id::DEATH,
+    effect_id::DAMAGE,
+    effect_id::ENTERCOMBAT,
+    effect_id::EXITCOMBAT,
+    effect_id::FAILEDEFFECT,
+    effect_id::HEAL,
+    effect_id::REVIVED,
+    effect_id::RECENTLY_REVIVED,
+    effect_id::TARGETCLEARED,
+    effect_id::TARGETSET,
+    effect_id::TAUNT,
+];
+
+/// Set of ignored ability/effect IDs
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IgnoreList {
+    ids: HashSet<i64>,
+}
+
+impl IgnoreList {
+    pub fn new(ids: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            ids: ids.into_iter().filter(|id| Self::can_ignore(*id)).collect(),
+        }
+    }
+
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self::new(config.ignored_ids.iter().map(|entry| entry.id))
+    }
+
+    /// Whether an ID may be added to the list (rejects 0 and core event IDs)
+    pub fn can_ignore(id: i64) -> bool {
+        id != 0 && !PROTECTED_IDS.contains(&id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn contains(&self, id: i64) -> bool {
+        self.ids.contains(&id)
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = i64> + '_ {
+        self.ids.iter().copied()
+    }
+
+    /// Whether the event's ability or effect is ignored
+    pub fn matches(&self, event: &CombatEvent) -> bool {
+        !self.ids.is_empty()
+            && (self.ids.contains(&event.action.action_id)
+                || self.ids.contains(&event.effect.effect_id))
+    }
+
+    /// SQL predicate excluding ignored events, or `None` when the list is empty
+    pub fn sql_predicate(&self) -> Option<String> {
+        if self.ids.is_empty() {
+            return None;
+        }
+        let mut ids: Vec<i64> = self.ids.iter().copied().collect();
+        ids.sort_unstable();
+        let list = ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(format!(
+            "ability_id NOT IN ({list}) AND effect_id NOT IN ({list})"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+    use crate::combat_log::{Action, Details, Effect, Entity};
+
+    fn event(action_id: i64, effect_id: i64) -> CombatEvent {
+        CombatEvent {
+            line_number: 0,
+            byte_offset: 0,
+            byte_end: 0,
+            timestamp: NaiveDateTime::default(),
+            source_entity: Entity::default(),
+            target_entity: Entity::default(),
+            action: Action {
+                action_id,
+                ..Action::default()
+            },
+            effect: Effect {
+                effect_id,
+                ..Effect::default()
+            },
+            details: Details::default(),
+        }
+    }
+
+    #[test]
+    fn test_matches_ability_or_effect_id() {
+        let list = IgnoreList::new([42]);
+        assert!(!list.matches(&event(1, 2)));
+        assert!(list.matches(&event(42, 2)));
+        assert!(list.matches(&event(1, 42)));
+    }
+
+    #[test]
+    fn test_core_event_ids_are_refused() {
+        assert!(!IgnoreList::can_ignore(0));
+        assert!(!IgnoreList::can_ignore(effect_id::DAMAGE));
+        let list = IgnoreList::new([effect_id::HEAL, 7]);
+        assert!(!list.contains(effect_id::HEAL));
+        assert!(list.contains(7));
+    }
+
+    #[test]
+    fn test_sql_predicate() {
+        assert_eq!(IgnoreList::default().sql_predicate(), None);
+        assert_eq!(
+            IgnoreList::new([9, 3]).sql_predicate().as_deref(),
+            Some("ability_id NOT IN (3, 9) AND effect_id NOT IN (3, 9)")
+        );
+    }
+}
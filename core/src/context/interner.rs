@@ -27,6 +27,17 @@ pub fn empty_istr() -> IStr {
     *EMPTY_ISTR.get_or_init(|| interner().get_or_intern(""))
 }
 
+/// Number of interned strings and their estimated heap usage (bytes).
+/// Each entry is stored once as string data plus a key/slice in both the
+/// key-to-string and string-to-key maps.
+pub fn interner_stats() -> (usize, usize) {
+    let interner = interner();
+    let count = interner.len();
+    let string_bytes: usize = interner.strings().map(str::len).sum();
+    let entry_bytes = 2 * (std::mem::size_of::<Spur>() + std::mem::size_of::<&str>());
+    (count, string_bytes + count * entry_bytes)
+}
+
 /// Resolve an interned key back to a string.
 pub fn resolve(key: IStr) -> &'static str {
     interner().resolve(&key)
@@ -243,6 +243,11 @@ impl DirectoryIndex {
         }
     }
 
+    /// Character name read from a log file, if it has logged one yet
+    pub fn character_name(&self, path: &Path) -> Option<&str> {
+        self.entries.get(path)?.character_name.as_deref()
+    }
+
     /// Check if a specific file is missing character data
     pub fn is_missing_character(&self, path: &Path) -> bool {
         self.entries
@@ -1,20 +1,40 @@
+mod area_coverage;
 mod background_tasks;
 mod config;
 mod error;
+mod id_dictionary;
+mod idle;
+mod ignore_list;
 mod interner;
 mod log_files;
 mod parser;
+mod raid_plan;
+mod setup_health;
 pub mod watcher;
 
 pub use error::{ConfigError, WatcherError};
 
+pub use area_coverage::{AreaCoverageReport, AreaHistory, ObservedArea};
 pub use background_tasks::BackgroundTasks;
 pub use config::{
-    AlertsOverlayConfig, AppConfig, AppConfigExt, BossHealthConfig, ChallengeColumns,
-    ChallengeLayout, ChallengeOverlayConfig, Color, HotkeySettings, MAX_PROFILES,
-    OverlayAppearanceConfig, OverlayPositionConfig, OverlayProfile, OverlaySettings,
-    PersonalOverlayConfig, PersonalStat, RaidOverlaySettings, TimerOverlayConfig, overlay_colors,
+    AlertBannerConfig, AlertsOverlayConfig, AppConfig, AppConfigExt, BossHealthConfig,
+    BossNotesConfig, ChallengeColumns, ChallengeLayout, ChallengeOverlayConfig, CharacterSettings,
+    Color, DeathRecapConfig, HotkeySettings, IgnoredId, MAX_NUMBER_DECIMALS, MAX_PROFILES,
+    NumberFormat, NumberStyle, OverlayAppearanceConfig, OverlayFrameStyle, OverlayPositionConfig,
+    OverlayProfile, OverlayRenderer, OverlaySettings, PersonalOverlayConfig, PersonalStat,
+    PersonalStatFormat, RaidCooldownConfig, RaidFrameLayout, RaidNameStyle, RaidOverlaySettings,
+    TankSwapConfig, TimerBarDirection, TimerOverlayConfig, TimerTextLayout, overlay_colors,
 };
-pub use interner::{IStr, empty_istr, intern, resolve};
+pub use id_dictionary::{
+    ID_DICTIONARY_FILENAME, IdDictionary, IdDictionaryEntry, IdNameRecord, id_dictionary_path,
+};
+pub use idle::{DEEP_IDLE_POLL, IdleState};
+pub use ignore_list::IgnoreList;
+pub use interner::{IStr, empty_istr, intern, interner_stats, resolve};
 pub use log_files::{DirectoryIndex, parse_log_filename};
 pub use parser::{DefinitionLoader, ParseResult, ParsingSession, parse_file, resolve_log_path};
+pub use raid_plan::{
+    AssignmentCheck, HealerAssignment, RaidPlan, check_assignments, load_raid_plan,
+    raid_plan_path, save_raid_plan,
+};
+pub use setup_health::{SetupCheck, SetupCheckStatus, SetupHealth, check_setup_health};
@@ -5,10 +5,10 @@ use chrono::NaiveDateTime;
 use tokio::sync::RwLock;
 use tracing;
 
-use crate::combat_log::{CombatEvent, Reader};
-use crate::context::{AppConfig, parse_log_filename};
+use crate::combat_log::{CombatEvent, Reader, SessionClock};
+use crate::context::{AppConfig, IdDictionary, IgnoreList, parse_log_filename};
 use crate::dsl::BossEncounterDefinition;
-use crate::effects::{DefinitionSet, EffectTracker};
+use crate::effects::{DefinitionSet, EffectTracker, RaidCooldownTracker, TankSwapTracker};
 use crate::game_data::effect_type_id;
 use crate::signal_processor::{EventProcessor, GameSignal, SignalHandler};
 use crate::state::SessionCache;
@@ -25,6 +25,7 @@ pub type DefinitionLoader = Box<dyn Fn(i64) -> Option<Vec<BossEncounterDefinitio
 /// - Event processing pipeline (encounters, metrics)
 /// - Effect tracking (HoTs, debuffs, shields for overlay display) - Live mode only
 /// - Timer tracking (boss mechanics countdown timers) - Live mode only
+/// - Tank-swap debuff stacks - Live mode only
 /// - Signal handlers for cross-cutting concerns
 ///
 /// In Live mode, effect and timer tracking are enabled for overlay display.
@@ -33,6 +34,8 @@ pub struct ParsingSession {
     pub current_byte: Option<u64>,
     pub active_file: Option<PathBuf>,
     pub game_session_date: Option<NaiveDateTime>,
+    /// Clock that dated the lines up to `current_byte`, for readers resuming there
+    pub session_clock: Option<SessionClock>,
     pub session_cache: Option<SessionCache>,
     processor: EventProcessor,
     signal_handlers: Vec<Box<dyn SignalHandler + Send + Sync>>,
@@ -42,6 +45,17 @@ pub struct ParsingSession {
     /// Timer manager for boss/mechanic countdown timers.
     /// Only created in Live mode. None in Historical mode.
     timer_manager: Option<Arc<Mutex<TimerManager>>>,
+    /// Tank-swap debuff stacks for the tank-swap overlay.
+    /// Only created in Live mode. None in Historical mode.
+    tank_swap_tracker: Option<Arc<Mutex<TankSwapTracker>>>,
+    /// Last use of each player's raid cooldowns for the raid cooldown overlay.
+    /// Only created in Live mode. None in Historical mode.
+    raid_cooldown_tracker: Option<Arc<Mutex<RaidCooldownTracker>>>,
+    /// Abilities/effects dropped before processing (see [`IgnoreList`])
+    ignore_list: IgnoreList,
+    /// Dictionary the NPC, ability and effect IDs of processed events are
+    /// recorded into. Set by the app layer.
+    id_dictionary: Option<Arc<Mutex<IdDictionary>>>,
 
     // Live parquet writing (for streaming mode)
     /// Directory where encounter parquet files are written
@@ -72,11 +86,16 @@ impl ParsingSession {
             current_byte: None,
             active_file: None,
             game_session_date: None,
+            session_clock: None,
             session_cache: Some(SessionCache::new()),
             processor: EventProcessor::new(),
             signal_handlers: Vec::new(),
             effect_tracker: Some(Arc::new(Mutex::new(EffectTracker::default()))),
             timer_manager: Some(Arc::new(Mutex::new(TimerManager::default()))),
+            tank_swap_tracker: Some(Arc::new(Mutex::new(TankSwapTracker::new()))),
+            raid_cooldown_tracker: Some(Arc::new(Mutex::new(RaidCooldownTracker::new()))),
+            ignore_list: IgnoreList::default(),
+            id_dictionary: None,
             encounters_dir: None,
             encounter_idx: 0,
             encounter_writer: None,
@@ -92,11 +111,16 @@ impl ParsingSession {
             current_byte: None,
             active_file: None,
             game_session_date: None,
+            session_clock: None,
             session_cache: Some(SessionCache::new()),
             processor: EventProcessor::new(),
             signal_handlers: Vec::new(),
             effect_tracker: None,
             timer_manager: None,
+            tank_swap_tracker: None,
+            raid_cooldown_tracker: None,
+            ignore_list: IgnoreList::default(),
+            id_dictionary: None,
             encounters_dir: None,
             encounter_idx: 0,
             encounter_writer: None,
@@ -119,11 +143,16 @@ impl ParsingSession {
             current_byte: None,
             active_file: Some(path),
             game_session_date: date_stamp,
+            session_clock: None,
             session_cache: Some(SessionCache::new()),
             processor: EventProcessor::new(),
             signal_handlers: Vec::new(),
             effect_tracker: Some(Arc::new(Mutex::new(EffectTracker::new(definitions)))),
             timer_manager: Some(Arc::new(Mutex::new(TimerManager::default()))),
+            tank_swap_tracker: Some(Arc::new(Mutex::new(TankSwapTracker::new()))),
+            raid_cooldown_tracker: Some(Arc::new(Mutex::new(RaidCooldownTracker::new()))),
+            ignore_list: IgnoreList::default(),
+            id_dictionary: None,
             encounters_dir: None,
             encounter_idx: 0,
             encounter_writer: None,
@@ -138,6 +167,16 @@ impl ParsingSession {
         self.definition_loader = Some(loader);
     }
 
+    /// Replace the ignore list. Applies to events processed from now on.
+    pub fn set_ignore_list(&mut self, ignore_list: IgnoreList) {
+        self.ignore_list = ignore_list;
+    }
+
+    /// Record the IDs of processed events into `dictionary`
+    pub fn set_id_dictionary(&mut self, dictionary: Arc<Mutex<IdDictionary>>) {
+        self.id_dictionary = Some(dictionary);
+    }
+
     /// Register a signal handler to receive game signals
     pub fn add_signal_handler(&mut self, handler: Box<dyn SignalHandler + Send + Sync>) {
         self.signal_handlers.push(handler);
@@ -145,6 +184,11 @@ impl ParsingSession {
 
     /// Process a single event through the processor and dispatch signals
     pub fn process_event(&mut self, event: CombatEvent) {
+        // Ignored abilities/effects never reach metrics or effect tracking
+        if self.ignore_list.matches(&event) {
+            return;
+        }
+
         // Sync load definitions on AreaEntered BEFORE processing
         // This ensures boss definitions are available when combat events arrive
         if event.effect.type_id == effect_type_id::AREAENTERED {
@@ -165,6 +209,13 @@ impl ParsingSession {
             // This updates cache state (including current_phase) before we capture metadata.
             let (signals, event) = self.processor.process_event(event, cache);
 
+            if let Some(dictionary) = &self.id_dictionary {
+                dictionary
+                    .lock()
+                    .unwrap_or_else(|p| p.into_inner())
+                    .record_event(&event, &cache.current_area.area_name);
+            }
+
             // Write event to parquet buffer AFTER processing
             // (so metadata captures the updated phase state)
             if let Some(writer) = &mut self.encounter_writer {
@@ -211,6 +262,13 @@ impl ParsingSession {
 
     /// Flush current encounter buffer to parquet file
     fn flush_encounter_parquet(&mut self) {
+        // The encounter that just ended (a fresh one is already pushed on CombatEnded)
+        let encounter_key = self
+            .session_cache
+            .as_ref()
+            .and_then(|cache| cache.last_combat_encounter())
+            .and_then(|enc| enc.encounter_key());
+
         let Some(writer) = &mut self.encounter_writer else {
             return;
         };
@@ -221,19 +279,23 @@ impl ParsingSession {
         let Some(dir) = &self.encounters_dir else {
             return;
         };
+        let Some(encounter_key) = encounter_key else {
+            writer.clear();
+            return;
+        };
 
-        let filename = encounter_filename(self.encounter_idx);
+        let filename = encounter_filename(&encounter_key);
         let path = dir.join(&filename);
 
         if let Err(e) = writer.write_to_file(&path) {
             tracing::error!(
-                encounter_idx = self.encounter_idx,
+                encounter_key = %encounter_key,
                 error = %e,
                 "Failed to write encounter parquet"
             );
         } else {
             tracing::info!(
-                encounter_idx = self.encounter_idx,
+                encounter_key = %encounter_key,
                 event_count = writer.len(),
                 "Wrote encounter parquet"
             );
@@ -257,6 +319,9 @@ impl ParsingSession {
 
         if let Some(cache) = &mut self.session_cache {
             for event in events {
+                if self.ignore_list.matches(&event) {
+                    continue;
+                }
                 let (signals, _event) = self.processor.process_event(event, cache);
                 all_signals.extend(signals);
             }
@@ -300,6 +365,18 @@ impl ParsingSession {
             });
             timer_mgr.handle_signals(signals, encounter);
         }
+
+        // Forward to tank-swap tracker (Live mode only)
+        if let Some(tank_swap) = &self.tank_swap_tracker {
+            let mut tank_swap = tank_swap.lock().unwrap_or_else(|p| p.into_inner());
+            tank_swap.handle_signals(signals, encounter);
+        }
+
+        // Forward to raid cooldown tracker (Live mode only)
+        if let Some(raid_cooldowns) = &self.raid_cooldown_tracker {
+            let mut raid_cooldowns = raid_cooldowns.lock().unwrap_or_else(|p| p.into_inner());
+            raid_cooldowns.handle_signals(signals, encounter);
+        }
     }
 
     /// Process counter triggers from timer events (expires and starts).
@@ -341,6 +418,18 @@ impl ParsingSession {
         self.timer_manager.as_ref().map(Arc::clone)
     }
 
+    /// Get a shared reference to the tank-swap tracker for overlay queries.
+    /// Returns None in Historical mode.
+    pub fn tank_swap_tracker(&self) -> Option<Arc<Mutex<TankSwapTracker>>> {
+        self.tank_swap_tracker.as_ref().map(Arc::clone)
+    }
+
+    /// Get a shared reference to the raid cooldown tracker for overlay queries.
+    /// Returns None in Historical mode.
+    pub fn raid_cooldown_tracker(&self) -> Option<Arc<Mutex<RaidCooldownTracker>>> {
+        self.raid_cooldown_tracker.as_ref().map(Arc::clone)
+    }
+
     /// Tick the combat state, effect tracker, and timer manager.
     ///
     /// Call this periodically (e.g., from the tail loop during idle) to ensure:
@@ -392,6 +481,13 @@ impl ParsingSession {
         }
     }
 
+    /// Enable or disable session cache compaction after each encounter.
+    pub fn set_cache_compaction(&mut self, enabled: bool) {
+        if let Some(cache) = &mut self.session_cache {
+            cache.set_compaction_enabled(enabled);
+        }
+    }
+
     /// Set player alacrity for effect duration calculations.
     /// No-op in Historical mode (session has no effect tracker).
     pub fn set_effect_alacrity(&self, alacrity_percent: f32) {
@@ -468,6 +564,15 @@ impl ParsingSession {
         }
     }
 
+    /// Finalize a live session before it is dropped (e.g. on app exit).
+    ///
+    /// Adds the in-progress encounter to the encounter history and writes any
+    /// buffered events to parquet, so the last encounter isn't lost.
+    pub fn finalize_live_session(&mut self) {
+        self.finalize_session();
+        self.flush_encounter_parquet();
+    }
+
     /// Get the encounters directory path (for querying historical parquet files).
     pub fn encounters_dir(&self) -> Option<&std::path::PathBuf> {
         self.encounters_dir.as_ref()
@@ -526,13 +631,15 @@ pub async fn parse_file(state: Arc<RwLock<ParsingSession>>) -> Result<ParseResul
     // Stream-parse: process events one at a time without collecting
     let mut s = state.write().await;
     let session_date = s.game_session_date.unwrap_or_default();
+    let mut clock = SessionClock::new(session_date);
     let (end_pos, events_count) = reader
-        .read_log_file_streaming(session_date, |event| {
+        .read_log_file_streaming(session_date, &mut clock, |event| {
             s.process_event(event);
         })
         .map_err(|e| format!("failed to parse log file: {}", e))?;
 
     s.current_byte = Some(end_pos);
+    s.session_clock = Some(clock);
     // Sync area context to timer manager (handles mid-session starts)
     s.sync_timer_context();
     drop(s);
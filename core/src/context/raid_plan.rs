@@ -0,0 +1,142 @@
+//! Raid plan: healer assignments per boss
+//!
+//! Assignments (healer → tank or group) are stored in `raid_plan.toml` in the
+//! config directory and checked after a pull against who the healers actually
+//! healed:
+//!
+//! ```toml
+//! [[bosses."Dread Master Brontes"]]
+//! healer = "Karr"
+//! targets = ["Vossa"]
+//! ```
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub use baras_types::{AssignmentCheck, HealerAssignment, RaidPlan};
+
+/// Path of the user's raid plan (`~/.config/baras/raid_plan.toml`)
+pub fn raid_plan_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("raid_plan.toml"))
+}
+
+/// Load a raid plan. A missing file gives an empty plan.
+pub fn load_raid_plan(path: &Path) -> io::Result<RaidPlan> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => {
+            toml::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(RaidPlan::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Write a raid plan, creating the config directory if needed
+pub fn save_raid_plan(path: &Path, plan: &RaidPlan) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content =
+        toml::to_string_pretty(plan).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    std::fs::write(path, content)
+}
+
+/// Compare assignments against a pull's healing.
+///
+/// `healing` holds effective healing as `(healer, target, amount)` rows.
+/// Names are compared case insensitively.
+pub fn check_assignments(
+    assignments: &[HealerAssignment],
+    healing: &[(String, String, f64)],
+) -> Vec<AssignmentCheck> {
+    let mut by_healer: HashMap<String, Vec<(&str, f64)>> = HashMap::new();
+    for (healer, target, amount) in healing {
+        by_healer
+            .entry(healer.to_lowercase())
+            .or_default()
+            .push((target.as_str(), *amount));
+    }
+
+    assignments
+        .iter()
+        .map(|assignment| {
+            let rows = by_healer
+                .get(&assignment.healer.to_lowercase())
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            let is_assigned = |target: &str| {
+                assignment
+                    .targets
+                    .iter()
+                    .any(|t| t.eq_ignore_ascii_case(target))
+            };
+            AssignmentCheck {
+                healer: assignment.healer.clone(),
+                targets: assignment.targets.clone(),
+                assigned_healing: rows
+                    .iter()
+                    .filter(|(target, _)| is_assigned(target))
+                    .map(|(_, amount)| amount)
+                    .sum(),
+                total_healing: rows.iter().map(|(_, amount)| amount).sum(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(healer: &str, target: &str, amount: f64) -> (String, String, f64) {
+        (healer.to_string(), target.to_string(), amount)
+    }
+
+    #[test]
+    fn share_of_healing_on_assigned_targets() {
+        let assignments = vec![HealerAssignment {
+            healer: "Karr".to_string(),
+            targets: vec!["Vossa".to_string(), "Tiro".to_string()],
+        }];
+        let healing = vec![
+            row("Karr", "Vossa", 600.0),
+            row("Karr", "tiro", 150.0),
+            row("Karr", "Karr", 250.0),
+            row("Ilse", "Vossa", 900.0),
+        ];
+
+        let checks = check_assignments(&assignments, &healing);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].assigned_healing, 750.0);
+        assert_eq!(checks[0].total_healing, 1000.0);
+        assert_eq!(checks[0].assigned_pct(), 75.0);
+    }
+
+    #[test]
+    fn healer_missing_from_pull() {
+        let assignments = vec![HealerAssignment {
+            healer: "Absent".to_string(),
+            targets: vec!["Vossa".to_string()],
+        }];
+        let checks = check_assignments(&assignments, &[row("Karr", "Vossa", 100.0)]);
+        assert_eq!(checks[0].total_healing, 0.0);
+        assert_eq!(checks[0].assigned_pct(), 0.0);
+    }
+
+    #[test]
+    fn plan_round_trips_through_toml() {
+        let mut plan = RaidPlan::default();
+        plan.bosses.insert(
+            "Dread Master Brontes".to_string(),
+            vec![HealerAssignment {
+                healer: "Karr".to_string(),
+                targets: vec!["Vossa".to_string()],
+            }],
+        );
+        let text = toml::to_string_pretty(&plan).unwrap();
+        let parsed: RaidPlan = toml::from_str(&text).unwrap();
+        assert_eq!(parsed, plan);
+        assert_eq!(parsed.assignments_for("dread master brontes").len(), 1);
+    }
+}
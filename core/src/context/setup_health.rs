@@ -0,0 +1,201 @@
+//! Combat log setup diagnostics
+//!
+//! Run on startup so a misconfigured log directory, or combat logging left
+//! disabled in SWTOR, is reported before the user wonders why nothing shows.
+
+use std::fs;
+use std::path::Path;
+
+use chrono::NaiveDate;
+
+pub use baras_types::{SetupCheck, SetupCheckStatus, SetupHealth};
+
+use super::DirectoryIndex;
+
+/// Logs older than this no longer count as recent
+const RECENT_LOG_DAYS: i64 = 14;
+
+/// Created and removed again to test write access
+const WRITE_PROBE_FILENAME: &str = ".baras_write_probe";
+
+const ENABLE_LOGGING_HINT: &str =
+    "Enable combat logging in SWTOR under Preferences > Combat Logging, then log in again.";
+
+fn check(name: &str, status: SetupCheckStatus, message: String) -> SetupCheck {
+    SetupCheck {
+        name: name.to_string(),
+        status,
+        message,
+    }
+}
+
+/// Check the log directory and the files indexed from it.
+///
+/// `today` is the local date, used to tell whether the game wrote a log today.
+pub fn check_setup_health(dir: &Path, index: &DirectoryIndex, today: NaiveDate) -> SetupHealth {
+    let mut checks = Vec::new();
+
+    if dir.as_os_str().is_empty() {
+        checks.push(check(
+            "Log directory",
+            SetupCheckStatus::Error,
+            "No log directory is set. Select SWTOR's CombatLogs folder in Settings.".to_string(),
+        ));
+        return SetupHealth { checks };
+    }
+    if !dir.is_dir() {
+        checks.push(check(
+            "Log directory",
+            SetupCheckStatus::Error,
+            format!(
+                "{} does not exist. Select SWTOR's CombatLogs folder in Settings.",
+                dir.display()
+            ),
+        ));
+        return SetupHealth { checks };
+    }
+    checks.push(check(
+        "Log directory",
+        SetupCheckStatus::Ok,
+        dir.display().to_string(),
+    ));
+
+    // Read access
+    if let Err(e) = fs::read_dir(dir) {
+        checks.push(check(
+            "Read access",
+            SetupCheckStatus::Error,
+            format!("The log directory cannot be read ({e}). Check its permissions."),
+        ));
+        return SetupHealth { checks };
+    }
+    checks.push(check(
+        "Read access",
+        SetupCheckStatus::Ok,
+        "The log directory is readable.".to_string(),
+    ));
+
+    // Write access (needed to delete empty and old logs)
+    let probe = dir.join(WRITE_PROBE_FILENAME);
+    match fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+            checks.push(check(
+                "Write access",
+                SetupCheckStatus::Ok,
+                "The log directory is writable.".to_string(),
+            ));
+        }
+        Err(e) => checks.push(check(
+            "Write access",
+            SetupCheckStatus::Warning,
+            format!(
+                "The log directory is not writable ({e}). Log cleanup will not be able to delete files."
+            ),
+        )),
+    }
+
+    // Recent files
+    match index.newest_file() {
+        None => checks.push(check(
+            "Recent logs",
+            SetupCheckStatus::Warning,
+            format!(
+                "No combat logs found. Check that this is SWTOR's CombatLogs folder. {ENABLE_LOGGING_HINT}"
+            ),
+        )),
+        Some(newest) if (today - newest.date).num_days() > RECENT_LOG_DAYS => {
+            checks.push(check(
+                "Recent logs",
+                SetupCheckStatus::Warning,
+                format!(
+                    "The newest log is from {}. If you played since, the game may be writing logs to another folder.",
+                    newest.date
+                ),
+            ))
+        }
+        Some(newest) => checks.push(check(
+            "Recent logs",
+            SetupCheckStatus::Ok,
+            format!("The newest log is from {}.", newest.date),
+        )),
+    }
+
+    // Output today
+    let written_today = index
+        .entries()
+        .into_iter()
+        .filter(|e| e.date == today && !e.is_empty)
+        .count();
+    checks.push(if written_today > 0 {
+        check(
+            "Logging today",
+            SetupCheckStatus::Ok,
+            format!("{written_today} log(s) with combat data written today."),
+        )
+    } else {
+        check(
+            "Logging today",
+            SetupCheckStatus::Warning,
+            format!(
+                "No combat data has been logged today. If you played today: {ENABLE_LOGGING_HINT}"
+            ),
+        )
+    });
+
+    SetupHealth { checks }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statuses(health: &SetupHealth) -> Vec<(&str, SetupCheckStatus)> {
+        health
+            .checks
+            .iter()
+            .map(|c| (c.name.as_str(), c.status))
+            .collect()
+    }
+
+    #[test]
+    fn test_missing_directory_stops_early() {
+        let dir = std::env::temp_dir().join("baras-setup-health-missing");
+        let health = check_setup_health(&dir, &DirectoryIndex::new(), NaiveDate::default());
+        assert_eq!(
+            statuses(&health),
+            vec![("Log directory", SetupCheckStatus::Error)]
+        );
+    }
+
+    #[test]
+    fn test_logs_written_today() {
+        let dir = std::env::temp_dir().join(format!("baras-setup-health-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("combat_2026-03-02_19_30_00_000000.txt"), "").unwrap();
+        fs::write(dir.join("combat_2026-03-01_19_30_00_000000.txt"), "x").unwrap();
+        let index = DirectoryIndex::build_index(&dir).unwrap();
+
+        let today = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let health = check_setup_health(&dir, &index, today);
+        assert_eq!(
+            statuses(&health),
+            vec![
+                ("Log directory", SetupCheckStatus::Ok),
+                ("Read access", SetupCheckStatus::Ok),
+                ("Write access", SetupCheckStatus::Ok),
+                ("Recent logs", SetupCheckStatus::Ok),
+                // Today's only log is empty
+                ("Logging today", SetupCheckStatus::Warning),
+            ]
+        );
+        assert!(!dir.join(WRITE_PROBE_FILENAME).exists());
+
+        let later = NaiveDate::from_ymd_opt(2026, 4, 1).unwrap();
+        let health = check_setup_health(&dir, &index, later);
+        assert_eq!(health.checks[3].status, SetupCheckStatus::Warning);
+        assert_eq!(health.problems().count(), 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -121,6 +121,80 @@ impl DirectoryWatcher {
     }
 }
 
+/// Watches the application config file for edits made outside the app.
+///
+/// The parent directory is watched rather than the file itself: most editors
+/// save by writing a temp file and renaming it over the original, which would
+/// orphan a watch on the old inode.
+pub struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+    rx: Receiver<notify::Result<Event>>,
+    file_name: std::ffi::OsString,
+}
+
+impl ConfigWatcher {
+    /// Quiet period before reporting a change, so multi-step saves coalesce
+    const DEBOUNCE: Duration = Duration::from_millis(250);
+
+    pub fn new(config_path: &Path) -> notify::Result<Self> {
+        let dir = config_path
+            .parent()
+            .ok_or_else(|| notify::Error::path_not_found().add_path(config_path.to_path_buf()))?;
+        let file_name = config_path
+            .file_name()
+            .ok_or_else(|| notify::Error::path_not_found().add_path(config_path.to_path_buf()))?
+            .to_os_string();
+
+        let (tx, rx) = mpsc::channel(100);
+
+        let mut watcher = RecommendedWatcher::new(
+            move |res| {
+                let _ = tx.blocking_send(res);
+            },
+            Config::default(),
+        )?;
+
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            _watcher: watcher,
+            rx,
+            file_name,
+        })
+    }
+
+    /// Wait for the config file to change. Returns `None` when the watcher shuts down.
+    pub async fn next_change(&mut self) -> Option<()> {
+        loop {
+            let event = self.rx.recv().await?;
+            if self.is_config_write(event) {
+                break;
+            }
+        }
+
+        // Drain follow-up events until the file has been quiet for a moment
+        while let Ok(Some(_)) = tokio::time::timeout(Self::DEBOUNCE, self.rx.recv()).await {}
+
+        Some(())
+    }
+
+    fn is_config_write(&self, event: notify::Result<Event>) -> bool {
+        match event {
+            Ok(event) => {
+                matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                    && event
+                        .paths
+                        .iter()
+                        .any(|p| p.file_name() == Some(self.file_name.as_os_str()))
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Config watcher error");
+                false
+            }
+        }
+    }
+}
+
 fn is_combat_log(path: &Path) -> bool {
     path.file_name()
         .and_then(|n| n.to_str())
@@ -135,3 +209,31 @@ pub fn build_index(dir: &Path) -> Result<(DirectoryIndex, Option<PathBuf>), Stri
     let newest = index.newest_file().map(|f| f.path.clone());
     Ok((index, newest))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_config_watcher_reports_config_edits_only() {
+        let dir = std::env::temp_dir().join(format!("baras-config-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(&path, "latency_ms = 80").unwrap();
+        let mut watcher = ConfigWatcher::new(&path).unwrap();
+
+        // Other files in the directory are ignored
+        std::fs::write(dir.join("other.toml"), "x = 1").unwrap();
+        let quiet = tokio::time::timeout(Duration::from_millis(500), watcher.next_change()).await;
+        assert!(quiet.is_err());
+
+        // Editors that save by renaming a temp file over the config
+        let temp = dir.join("config.toml.tmp");
+        std::fs::write(&temp, "latency_ms = 120").unwrap();
+        std::fs::rename(&temp, &path).unwrap();
+        let change = tokio::time::timeout(Duration::from_secs(5), watcher.next_change()).await;
+        assert_eq!(change.unwrap(), Some(()));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -9,9 +9,14 @@ pub struct AudioConfig {
     #[serde(default)]
     pub enabled: bool,
 
-    /// Audio file to play (relative to sounds directory)
+    /// Audio file to play: a sound name (user sounds directory, then bundled)
+    /// or an absolute path
     pub file: Option<String>,
 
+    /// Volume for this item's sounds (0-100, None = global volume)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<u8>,
+
     /// Seconds before expiration to play audio (0 = on expiration)
     #[serde(default)]
     pub offset: u8,
@@ -28,12 +33,16 @@ pub struct AudioConfig {
     /// If non-empty, sends this text to the alert overlay.
     #[serde(default)]
     pub alert_text: Option<String>,
+
+    /// Speak the alert text with text-to-speech instead of playing `file`
+    #[serde(default)]
+    pub speak_alert: bool,
 }
 
 impl AudioConfig {
     /// Check if any audio is configured
     pub fn has_audio(&self) -> bool {
-        self.enabled && (self.file.is_some() || self.countdown_start > 0)
+        self.enabled && (self.file.is_some() || self.countdown_start > 0 || self.speak_alert)
     }
 
     /// Check if countdown audio is enabled
@@ -0,0 +1,385 @@
+//! Bulk edits on boss definitions
+//!
+//! Operations the encounter editor applies to many items at once, mostly
+//! needed when a PTS patch moves every timer of a phase or renumbers an NPC.
+
+use std::collections::HashMap;
+
+use super::{
+    BossEncounterDefinition, BossTimerDefinition, ChallengeCondition, EntityFilter, EntitySelector,
+    Trigger,
+};
+
+/// Add `delta_secs` to the duration of every timer limited to `phase_id`.
+///
+/// Alerts (no countdown) are left alone and durations never drop below zero.
+/// Returns the IDs of the changed timers.
+pub fn shift_phase_timer_durations(
+    boss: &mut BossEncounterDefinition,
+    phase_id: &str,
+    delta_secs: f32,
+) -> Vec<String> {
+    boss.timers
+        .iter_mut()
+        .filter(|t| !t.is_alert && t.phases.iter().any(|p| p == phase_id))
+        .map(|t| {
+            t.duration_secs = (t.duration_secs + delta_secs).max(0.0);
+            t.id.clone()
+        })
+        .collect()
+}
+
+/// Copy timers from `source` into `target`.
+///
+/// With `from_difficulty`, only timers listing that difficulty are copied.
+/// With `to_difficulty`, the copies apply to that difficulty only and their
+/// IDs get the difficulty as suffix. Copies to another boss swap the source
+/// boss ID prefix for the target's. Chains and timer triggers between copied
+/// timers follow the new IDs. Timers whose new ID already exists are skipped.
+///
+/// Returns the new timers (already added to `target`).
+pub fn clone_timers(
+    source: &BossEncounterDefinition,
+    target: &mut BossEncounterDefinition,
+    from_difficulty: Option<&str>,
+    to_difficulty: Option<&str>,
+) -> Vec<BossTimerDefinition> {
+    let selected: Vec<&BossTimerDefinition> = source
+        .timers
+        .iter()
+        .filter(|t| from_difficulty.is_none_or(|d| t.difficulties.iter().any(|x| x == d)))
+        .collect();
+
+    let new_ids: HashMap<String, String> = selected
+        .iter()
+        .map(|t| {
+            (
+                t.id.clone(),
+                cloned_timer_id(&t.id, source, target, to_difficulty),
+            )
+        })
+        .filter(|(old, new)| old != new || source.id != target.id)
+        .collect();
+
+    let mut cloned = Vec::new();
+    for timer in selected {
+        let Some(new_id) = new_ids.get(&timer.id) else {
+            continue;
+        };
+        if target.timers.iter().any(|t| &t.id == new_id) {
+            continue;
+        }
+        let mut copy = timer.clone();
+        copy.id = new_id.clone();
+        if let Some(difficulty) = to_difficulty {
+            copy.difficulties = vec![difficulty.to_string()];
+        }
+        if let Some(next) = copy.chains_to.as_mut()
+            && let Some(renamed) = new_ids.get(next.as_str())
+        {
+            *next = renamed.clone();
+        }
+        rename_timer_refs(&mut copy.trigger, &new_ids);
+        if let Some(cancel) = copy.cancel_trigger.as_mut() {
+            rename_timer_refs(cancel, &new_ids);
+        }
+        target.timers.push(copy.clone());
+        cloned.push(copy);
+    }
+    cloned
+}
+
+fn cloned_timer_id(
+    id: &str,
+    source: &BossEncounterDefinition,
+    target: &BossEncounterDefinition,
+    to_difficulty: Option<&str>,
+) -> String {
+    let mut new_id = match id.strip_prefix(source.id.as_str()) {
+        Some(rest) if source.id != target.id => format!("{}{}", target.id, rest),
+        _ => id.to_string(),
+    };
+    if let Some(difficulty) = to_difficulty {
+        let suffix = format!("_{}", difficulty.to_lowercase());
+        if !new_id.ends_with(&suffix) {
+            new_id.push_str(&suffix);
+        }
+    }
+    new_id
+}
+
+fn rename_timer_refs(trigger: &mut Trigger, new_ids: &HashMap<String, String>) {
+    match trigger {
+        Trigger::TimerExpires { timer_id } | Trigger::TimerStarted { timer_id } => {
+            if let Some(renamed) = new_ids.get(timer_id.as_str()) {
+                *timer_id = renamed.clone();
+            }
+        }
+        Trigger::AnyOf { conditions } | Trigger::AllOf { conditions, .. } => {
+            for condition in conditions {
+                rename_timer_refs(condition, new_ids);
+            }
+        }
+        Trigger::Not { condition } => rename_timer_refs(condition, new_ids),
+        _ => {}
+    }
+}
+
+/// IDs of the items a bulk edit changed, by item type
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangedItems {
+    /// Entity names (entities are keyed by name)
+    pub entities: Vec<String>,
+    pub timers: Vec<String>,
+    pub phases: Vec<String>,
+    pub counters: Vec<String>,
+    pub challenges: Vec<String>,
+}
+
+impl ChangedItems {
+    pub fn len(&self) -> usize {
+        self.entities.len()
+            + self.timers.len()
+            + self.phases.len()
+            + self.counters.len()
+            + self.challenges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Replace NPC ID `old_id` with `new_id` in entity rosters, every trigger
+/// (timers, phases, counters) and challenge conditions.
+pub fn replace_npc_id(
+    boss: &mut BossEncounterDefinition,
+    old_id: i64,
+    new_id: i64,
+) -> ChangedItems {
+    let mut changed = ChangedItems::default();
+    for entity in &mut boss.entities {
+        if replace_in_ids(&mut entity.ids, old_id, new_id) > 0 {
+            changed.entities.push(entity.name.clone());
+        }
+    }
+    for timer in &mut boss.timers {
+        let mut replaced = replace_in_trigger(&mut timer.trigger, old_id, new_id);
+        if let Some(cancel) = timer.cancel_trigger.as_mut() {
+            replaced += replace_in_trigger(cancel, old_id, new_id);
+        }
+        if replaced > 0 {
+            changed.timers.push(timer.id.clone());
+        }
+    }
+    for phase in &mut boss.phases {
+        let mut replaced = replace_in_trigger(&mut phase.start_trigger, old_id, new_id);
+        if let Some(end) = phase.end_trigger.as_mut() {
+            replaced += replace_in_trigger(end, old_id, new_id);
+        }
+        if replaced > 0 {
+            changed.phases.push(phase.id.clone());
+        }
+    }
+    for counter in &mut boss.counters {
+        let mut replaced = replace_in_trigger(&mut counter.increment_on, old_id, new_id);
+        if let Some(decrement) = counter.decrement_on.as_mut() {
+            replaced += replace_in_trigger(decrement, old_id, new_id);
+        }
+        replaced += replace_in_trigger(&mut counter.reset_on, old_id, new_id);
+        if replaced > 0 {
+            changed.counters.push(counter.id.clone());
+        }
+    }
+    for challenge in &mut boss.challenges {
+        let mut replaced = 0;
+        for condition in &mut challenge.conditions {
+            replaced += match condition {
+                ChallengeCondition::Source { matcher } | ChallengeCondition::Target { matcher } => {
+                    replace_in_filter(matcher, old_id, new_id)
+                }
+                ChallengeCondition::BossHpRange {
+                    npc_id: Some(npc_id),
+                    ..
+                } if *npc_id == old_id => {
+                    *npc_id = new_id;
+                    1
+                }
+                _ => 0,
+            };
+        }
+        if replaced > 0 {
+            changed.challenges.push(challenge.id.clone());
+        }
+    }
+    if !changed.is_empty() {
+        boss.build_indexes();
+    }
+    changed
+}
+
+fn replace_in_ids(ids: &mut [i64], old_id: i64, new_id: i64) -> usize {
+    ids.iter_mut()
+        .filter(|id| **id == old_id)
+        .map(|id| *id = new_id)
+        .count()
+}
+
+fn replace_in_selectors(selectors: &mut [EntitySelector], old_id: i64, new_id: i64) -> usize {
+    selectors
+        .iter_mut()
+        .filter(|s| matches!(s, EntitySelector::Id(id) if *id == old_id))
+        .map(|s| *s = EntitySelector::Id(new_id))
+        .count()
+}
+
+fn replace_in_filter(filter: &mut EntityFilter, old_id: i64, new_id: i64) -> usize {
+    match filter {
+        EntityFilter::Selector(selectors) => replace_in_selectors(selectors, old_id, new_id),
+        EntityFilter::Not(inner) => replace_in_filter(inner, old_id, new_id),
+        EntityFilter::AllOf(filters) | EntityFilter::AnyOf(filters) => filters
+            .iter_mut()
+            .map(|f| replace_in_filter(f, old_id, new_id))
+            .sum(),
+        _ => 0,
+    }
+}
+
+fn replace_in_trigger(trigger: &mut Trigger, old_id: i64, new_id: i64) -> usize {
+    match trigger {
+        Trigger::AbilityCast { source, target, .. }
+        | Trigger::CastStarted { source, target, .. }
+        | Trigger::CastCompleted { source, target, .. }
+        | Trigger::EffectApplied { source, target, .. }
+        | Trigger::EffectRemoved { source, target, .. }
+        | Trigger::DamageTaken { source, target, .. } => {
+            replace_in_filter(source, old_id, new_id) + replace_in_filter(target, old_id, new_id)
+        }
+        Trigger::BossHpBelow { selector, .. }
+        | Trigger::BossHpAbove { selector, .. }
+        | Trigger::NpcAppears { selector }
+        | Trigger::EntityDeath { selector } => replace_in_selectors(selector, old_id, new_id),
+        Trigger::TargetSet { selector, target } => {
+            replace_in_selectors(selector, old_id, new_id)
+                + replace_in_filter(target, old_id, new_id)
+        }
+        Trigger::AnyOf { conditions } | Trigger::AllOf { conditions, .. } => conditions
+            .iter_mut()
+            .map(|c| replace_in_trigger(c, old_id, new_id))
+            .sum(),
+        Trigger::Not { condition } => replace_in_trigger(condition, old_id, new_id),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsl::BossConfig;
+
+    fn boss(toml: &str) -> BossEncounterDefinition {
+        let config: BossConfig = toml::from_str(toml).expect("Failed to parse TOML");
+        config.bosses.into_iter().next().unwrap()
+    }
+
+    const BESTIA: &str = r#"
+[[boss]]
+id = "bestia"
+name = "Dread Master Bestia"
+
+[[boss.entities]]
+name = "Dread Master Bestia"
+ids = [3273941900591104]
+
+[[boss.phase]]
+id = "burn"
+name = "Burn"
+trigger = { type = "boss_hp_below", hp_percent = 30.0, selector = [3273941900591104] }
+
+[[boss.timer]]
+id = "bestia_swipe"
+name = "Swipe"
+trigger = { type = "ability_cast", abilities = [1], source = { selector = [3273941900591104] } }
+duration_secs = 10.0
+phases = ["burn"]
+difficulties = ["veteran"]
+chains_to = "bestia_swipe_2"
+
+[[boss.timer]]
+id = "bestia_swipe_2"
+name = "Swipe 2"
+trigger = { type = "timer_expires", timer_id = "bestia_swipe" }
+duration_secs = 4.0
+difficulties = ["veteran"]
+
+[[boss.timer]]
+id = "bestia_enrage"
+name = "Enrage"
+trigger = { type = "combat_start" }
+duration_secs = 2.0
+phases = ["burn"]
+difficulties = ["master"]
+"#;
+
+    #[test]
+    fn test_shift_phase_timer_durations() {
+        let mut boss = boss(BESTIA);
+        let changed = shift_phase_timer_durations(&mut boss, "burn", -5.0);
+        assert_eq!(changed, vec!["bestia_swipe", "bestia_enrage"]);
+        assert_eq!(boss.timers[0].duration_secs, 5.0);
+        assert_eq!(boss.timers[1].duration_secs, 4.0);
+        assert_eq!(boss.timers[2].duration_secs, 0.0);
+    }
+
+    #[test]
+    fn test_clone_timers_to_difficulty() {
+        let source = boss(BESTIA);
+        let mut target = source.clone();
+        let cloned = clone_timers(&source, &mut target, Some("veteran"), Some("master"));
+
+        let ids: Vec<&str> = cloned.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["bestia_swipe_master", "bestia_swipe_2_master"]);
+        assert_eq!(cloned[0].difficulties, vec!["master"]);
+        assert_eq!(
+            cloned[0].chains_to.as_deref(),
+            Some("bestia_swipe_2_master")
+        );
+        assert!(matches!(
+            &cloned[1].trigger,
+            Trigger::TimerExpires { timer_id } if timer_id == "bestia_swipe_master"
+        ));
+        assert_eq!(target.timers.len(), 5);
+
+        // Cloning again skips existing IDs
+        assert!(clone_timers(&source, &mut target, Some("veteran"), Some("master")).is_empty());
+    }
+
+    #[test]
+    fn test_clone_timers_to_other_boss() {
+        let source = boss(BESTIA);
+        let mut target = BossEncounterDefinition {
+            id: "tyrans".to_string(),
+            ..Default::default()
+        };
+        let cloned = clone_timers(&source, &mut target, None, None);
+        assert_eq!(cloned.len(), 3);
+        assert_eq!(cloned[0].id, "tyrans_swipe");
+        assert_eq!(cloned[0].chains_to.as_deref(), Some("tyrans_swipe_2"));
+    }
+
+    #[test]
+    fn test_replace_npc_id() {
+        let mut boss = boss(BESTIA);
+        let changed = replace_npc_id(&mut boss, 3273941900591104, 42);
+        assert_eq!(changed.entities, vec!["Dread Master Bestia"]);
+        assert_eq!(changed.phases, vec!["burn"]);
+        assert_eq!(changed.timers, vec!["bestia_swipe"]);
+        assert_eq!(boss.entities[0].ids, vec![42]);
+        assert!(boss.matches_npc_id(42));
+        assert!(matches!(
+            &boss.phases[0].start_trigger,
+            Trigger::BossHpBelow { selector, .. } if selector == &[EntitySelector::Id(42)]
+        ));
+        assert!(replace_npc_id(&mut boss, 3273941900591104, 42).is_empty());
+    }
+}
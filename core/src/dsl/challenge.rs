@@ -459,6 +459,35 @@ mod tests {
         assert!(!filter_matches(&EntityFilter::LocalPlayer, &npc, &boss_ids));
     }
 
+    #[test]
+    fn test_entity_filter_combinators() {
+        #[derive(serde::Deserialize)]
+        struct Wrapper {
+            filter: EntityFilter,
+        }
+        // Any NPC except the kill target
+        let filter = toml::from_str::<Wrapper>(
+            r#"filter = { all_of = ["any_npc", { not = { selector = [1001] } }] }"#,
+        )
+        .unwrap()
+        .filter;
+
+        let boss_ids = vec![1001];
+        let kill_target = EntityInfo::npc(1, "Boss", 1001);
+        let add = EntityInfo::npc(2, "Add", 9999);
+        let player = EntityInfo::player(3, "Player", false);
+
+        assert!(!filter_matches(&filter, &kill_target, &boss_ids));
+        assert!(filter_matches(&filter, &add, &boss_ids));
+        assert!(!filter_matches(&filter, &player, &boss_ids));
+
+        let any_of = EntityFilter::AnyOf(vec![EntityFilter::Boss, EntityFilter::LocalPlayer]);
+        let local = EntityInfo::player(4, "Me", true);
+        assert!(filter_matches(&any_of, &kill_target, &boss_ids));
+        assert!(filter_matches(&any_of, &local, &boss_ids));
+        assert!(!filter_matches(&any_of, &player, &boss_ids));
+    }
+
     #[test]
     fn test_phase_condition() {
         let ctx = test_context();
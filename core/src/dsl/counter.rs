@@ -9,6 +9,9 @@ use super::triggers::Trigger;
 // Re-export Trigger as CounterTrigger for backward compatibility during migration
 pub use super::triggers::Trigger as CounterTrigger;
 
+// Comparison operators are shared with the frontend
+pub use baras_types::ComparisonOp;
+
 /// A counter that tracks occurrences during a boss fight
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CounterDefinition {
@@ -69,29 +72,3 @@ pub struct CounterCondition {
     /// Value to compare against
     pub value: u32,
 }
-
-/// Comparison operators for counter conditions
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum ComparisonOp {
-    #[default]
-    Eq,
-    Lt,
-    Gt,
-    Lte,
-    Gte,
-    Ne,
-}
-
-impl ComparisonOp {
-    pub fn evaluate(&self, left: u32, right: u32) -> bool {
-        match self {
-            ComparisonOp::Eq => left == right,
-            ComparisonOp::Lt => left < right,
-            ComparisonOp::Gt => left > right,
-            ComparisonOp::Lte => left <= right,
-            ComparisonOp::Gte => left >= right,
-            ComparisonOp::Ne => left != right,
-        }
-    }
-}
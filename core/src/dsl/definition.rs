@@ -7,9 +7,11 @@ use hashbrown::HashSet;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    ChallengeDefinition, CounterCondition, CounterDefinition, CounterTrigger, PhaseDefinition,
+    ChallengeDefinition, CounterCondition, CounterDefinition, CounterTrigger, EffectSelector,
+    EntitySelectorExt, PhaseDefinition,
 };
 use crate::dsl::audio::AudioConfig;
+use crate::game_data::Difficulty;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // Root Config Structure
@@ -201,6 +203,19 @@ pub struct BossEncounterDefinition {
     #[serde(default, alias = "challenge", skip_serializing_if = "Vec::is_empty")]
     pub challenges: Vec<ChallengeDefinition>,
 
+    /// Stacking tank debuff shown on the tank-swap overlay
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tank_swap: Option<TankSwapDefinition>,
+
+    /// Hard enrage, in seconds after combat start. Starts a countdown that
+    /// can't be cancelled and feeds the personal overlay's time to enrage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enrage_secs: Option<f32>,
+
+    /// Difficulties the enrage applies to (empty = all)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enrage_difficulties: Vec<String>,
+
     #[serde(skip)]
     pub all_npc_ids: HashSet<i64>,
 }
@@ -209,6 +224,39 @@ fn is_zero(v: &i64) -> bool {
     *v == 0
 }
 
+/// Bar color of the generated enrage timer
+const ENRAGE_COLOR: [u8; 4] = [224, 31, 24, 255];
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tank Swap Definition
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Stacking debuff the boss puts on the current tank, calling for a swap
+/// once it reaches `swap_at_stacks`.
+///
+/// ```toml
+/// [boss.tank_swap]
+/// effects = [123456789]
+/// swap_at_stacks = 3
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TankSwapDefinition {
+    /// Debuff(s) to follow (ID or name)
+    pub effects: Vec<EffectSelector>,
+
+    /// Stack count at which the other tank should taunt
+    pub swap_at_stacks: u8,
+}
+
+impl TankSwapDefinition {
+    /// Check if an effect is one of the tank-swap debuffs
+    pub fn matches_effect(&self, effect_id: u64, effect_name: Option<&str>) -> bool {
+        self.effects
+            .iter()
+            .any(|selector| selector.matches(effect_id, effect_name))
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Boss Timer Definition
 // ═══════════════════════════════════════════════════════════════════════════
@@ -247,6 +295,13 @@ pub struct BossTimerDefinition {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub alert_text: Option<String>,
 
+    /// High priority alerts also flash in the center-screen alert banner
+    #[serde(
+        default,
+        skip_serializing_if = "crate::timers::AlertPriority::is_normal"
+    )]
+    pub alert_priority: crate::timers::AlertPriority,
+
     /// Display color [R, G, B, A]
     #[serde(default = "crate::serde_defaults::default_timer_color")]
     pub color: [u8; 4],
@@ -263,6 +318,10 @@ pub struct BossTimerDefinition {
     #[serde(default, skip_serializing_if = "crate::serde_defaults::is_empty_vec")]
     pub difficulties: Vec<String>,
 
+    /// Group sizes this timer applies to (e.g. `{ min = 16 }` for 16-man only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_size: Option<crate::timers::GroupSizeRange>,
+
     /// Whether timer is enabled
     #[serde(default = "crate::serde_defaults::default_true")]
     pub enabled: bool,
@@ -299,6 +358,10 @@ pub struct BossTimerDefinition {
     #[serde(default)]
     pub display_target: crate::timers::TimerDisplayTarget,
 
+    /// Cooldown suggested beside the timer bar, with per-discipline overrides
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_cooldown: Option<crate::timers::SuggestedCooldown>,
+
     // ─── Audio ───────────────────────────────────────────────────────────────
     /// Audio configuration (alerts, countdown, custom sounds)
     #[serde(default)]
@@ -339,8 +402,10 @@ impl BossTimerDefinition {
             show_on_raid_frames: self.show_on_raid_frames,
             show_at_secs: self.show_at_secs,
             display_target: self.display_target,
+            suggested_cooldown: self.suggested_cooldown.clone(),
             alert_at_secs: self.alert_at_secs,
             alert_text: self.alert_text.clone(),
+            alert_priority: self.alert_priority,
             audio: self.audio.clone(),
             triggers_timer: self.chains_to.clone(),
             cancel_trigger: self.cancel_trigger.clone(),
@@ -349,6 +414,7 @@ impl BossTimerDefinition {
             encounters: vec![area_name.to_string()], // Kept for logging/legacy
             boss: Some(boss_name.to_string()),
             difficulties: self.difficulties.clone(),
+            group_size: self.group_size,
             phases: self.phases.clone(),
             counter_condition: self.counter_condition.clone(),
             // Boss timers default to single-instance (per_target = false)
@@ -454,6 +520,85 @@ impl BossEncounterDefinition {
             .collect()
     }
 
+    /// HP percentages at which `BossHpBelow` phases start for an NPC, highest
+    /// first. Phases without a selector apply to every boss entity.
+    pub fn phase_hp_thresholds(&self, npc_id: i64, npc_name: Option<&str>) -> Vec<f32> {
+        let mut thresholds: Vec<f32> = self
+            .phases
+            .iter()
+            .filter_map(|phase| match &phase.start_trigger {
+                CounterTrigger::BossHpBelow {
+                    hp_percent,
+                    selector,
+                } => {
+                    let applies = if selector.is_empty() {
+                        self.boss_npc_ids().any(|id| id == npc_id)
+                    } else {
+                        selector.matches_with_roster(&self.entities, npc_id, npc_name)
+                    };
+                    applies.then_some(*hp_percent)
+                }
+                _ => None,
+            })
+            .filter(|hp| *hp > 0.0 && *hp < 100.0)
+            .collect();
+        thresholds.sort_by(|a, b| b.total_cmp(a));
+        thresholds.dedup();
+        thresholds
+    }
+
+    // ─── Enrage ──────────────────────────────────────────────────────────────
+
+    /// Countdown for the hard enrage, started on combat start. It has no
+    /// cancel trigger, so it runs until the enrage or the end of combat.
+    pub fn enrage_timer(&self) -> Option<BossTimerDefinition> {
+        let duration_secs = self.enrage_secs.filter(|secs| *secs > 0.0)?;
+        Some(BossTimerDefinition {
+            id: format!("{}_enrage", self.id),
+            name: "Enrage".to_string(),
+            display_text: None,
+            trigger: crate::timers::TimerTrigger::CombatStart,
+            duration_secs,
+            is_alert: false,
+            alert_text: None,
+            alert_priority: Default::default(),
+            color: ENRAGE_COLOR,
+            phases: Vec::new(),
+            counter_condition: None,
+            difficulties: self.enrage_difficulties.clone(),
+            group_size: None,
+            enabled: true,
+            can_be_refreshed: false,
+            repeats: 0,
+            chains_to: None,
+            cancel_trigger: None,
+            alert_at_secs: None,
+            show_on_raid_frames: false,
+            show_at_secs: 0.0,
+            display_target: Default::default(),
+            suggested_cooldown: None,
+            audio: AudioConfig::default(),
+            per_target: false,
+        })
+    }
+
+    /// Seconds left before the hard enrage, `combat_secs` into a pull on
+    /// `difficulty`. None if the boss has no enrage on that difficulty.
+    pub fn time_to_enrage(&self, combat_secs: f32, difficulty: Option<Difficulty>) -> Option<f32> {
+        let enrage_secs = self.enrage_secs.filter(|secs| *secs > 0.0)?;
+        if !self.enrage_difficulties.is_empty() {
+            let difficulty = difficulty?;
+            if !self
+                .enrage_difficulties
+                .iter()
+                .any(|d| difficulty.matches_config_key(d))
+            {
+                return None;
+            }
+        }
+        Some((enrage_secs - combat_secs).max(0.0))
+    }
+
     /// Check if this encounter is for the given area
     pub fn matches_area(&self, area_name: &str) -> bool {
         self.area_name.eq_ignore_ascii_case(area_name)
@@ -472,3 +617,84 @@ impl BossEncounterDefinition {
         self.all_npc_ids.contains(&npc_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::dsl::BossConfig;
+    use crate::game_data::Difficulty;
+
+    #[test]
+    fn test_phase_hp_thresholds() {
+        let config: BossConfig = toml::from_str(
+            r#"
+[[boss]]
+id = "brontes"
+name = "Dread Master Brontes"
+
+[[boss.entities]]
+name = "Brontes"
+ids = [100]
+is_boss = true
+
+[[boss.entities]]
+name = "Clock Tower"
+ids = [200]
+
+[[boss.phase]]
+id = "fingers"
+name = "Fingers"
+trigger = { type = "boss_hp_below", hp_percent = 76.0 }
+
+[[boss.phase]]
+id = "burn"
+name = "Burn"
+trigger = { type = "boss_hp_below", hp_percent = 30.0, selector = ["Brontes"] }
+
+[[boss.phase]]
+id = "tower_down"
+name = "Tower Down"
+trigger = { type = "boss_hp_below", hp_percent = 50.0, selector = [200] }
+"#,
+        )
+        .expect("Failed to parse TOML");
+        let boss = &config.bosses[0];
+
+        assert_eq!(
+            boss.phase_hp_thresholds(100, Some("Brontes")),
+            vec![76.0, 30.0]
+        );
+        assert_eq!(boss.phase_hp_thresholds(200, None), vec![50.0]);
+        assert!(boss.phase_hp_thresholds(300, None).is_empty());
+    }
+
+    #[test]
+    fn test_enrage_only_on_listed_difficulties() {
+        let config: BossConfig = toml::from_str(
+            r#"
+[[boss]]
+id = "brontes"
+name = "Dread Master Brontes"
+enrage_secs = 540.0
+enrage_difficulties = ["master"]
+"#,
+        )
+        .expect("Failed to parse TOML");
+        let boss = &config.bosses[0];
+
+        let timer = boss.enrage_timer().expect("enrage timer");
+        assert_eq!(timer.id, "brontes_enrage");
+        assert_eq!(timer.duration_secs, 540.0);
+        assert!(timer.cancel_trigger.is_none());
+
+        assert_eq!(
+            boss.time_to_enrage(40.0, Some(Difficulty::Master8)),
+            Some(500.0)
+        );
+        assert_eq!(
+            boss.time_to_enrage(600.0, Some(Difficulty::Master8)),
+            Some(0.0)
+        );
+        assert_eq!(boss.time_to_enrage(40.0, Some(Difficulty::Veteran8)), None);
+        assert_eq!(boss.time_to_enrage(40.0, None), None);
+    }
+}
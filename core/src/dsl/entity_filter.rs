@@ -120,6 +120,45 @@ impl EntityFilterMatching for EntityFilter {
                 let resolved_name = crate::context::resolve(entity_name);
                 selectors.matches_with_roster(entities, npc_id, Some(resolved_name))
             }
+            EntityFilter::NamedPlayer(name) => {
+                is_player && crate::context::resolve(entity_name).eq_ignore_ascii_case(name)
+            }
+
+            // Combinators
+            EntityFilter::Not(inner) => !inner.matches(
+                entities,
+                entity_id,
+                entity_type,
+                entity_name,
+                npc_id,
+                local_player_id,
+                current_target_id,
+                boss_entity_ids,
+            ),
+            EntityFilter::AllOf(filters) => filters.iter().all(|f| {
+                f.matches(
+                    entities,
+                    entity_id,
+                    entity_type,
+                    entity_name,
+                    npc_id,
+                    local_player_id,
+                    current_target_id,
+                    boss_entity_ids,
+                )
+            }),
+            EntityFilter::AnyOf(filters) => filters.iter().any(|f| {
+                f.matches(
+                    entities,
+                    entity_id,
+                    entity_type,
+                    entity_name,
+                    npc_id,
+                    local_player_id,
+                    current_target_id,
+                    boss_entity_ids,
+                )
+            }),
 
             // Any entity
             EntityFilter::Any => true,
@@ -164,6 +203,40 @@ impl EntityFilterMatching for EntityFilter {
                 let id = npc_id.unwrap_or(0);
                 selectors.matches_with_roster(entities, id, Some(name))
             }
+            EntityFilter::NamedPlayer(player) => is_player && name.eq_ignore_ascii_case(player),
+
+            // Combinators
+            EntityFilter::Not(inner) => !inner.matches_challenge(
+                entities,
+                is_player,
+                is_local_player,
+                is_current_target,
+                name,
+                npc_id,
+                boss_npc_ids,
+            ),
+            EntityFilter::AllOf(filters) => filters.iter().all(|f| {
+                f.matches_challenge(
+                    entities,
+                    is_player,
+                    is_local_player,
+                    is_current_target,
+                    name,
+                    npc_id,
+                    boss_npc_ids,
+                )
+            }),
+            EntityFilter::AnyOf(filters) => filters.iter().any(|f| {
+                f.matches_challenge(
+                    entities,
+                    is_player,
+                    is_local_player,
+                    is_current_target,
+                    name,
+                    npc_id,
+                    boss_npc_ids,
+                )
+            }),
 
             // Any entity
             EntityFilter::Any => true,
@@ -202,6 +275,16 @@ impl EntityFilterMatching for EntityFilter {
             EntityFilter::Selector(selectors) => {
                 selectors.matches_with_roster(entities, npc_id, Some(name))
             }
+            EntityFilter::NamedPlayer(player) => npc_id == 0 && name.eq_ignore_ascii_case(player),
+
+            // Combinators
+            EntityFilter::Not(inner) => !inner.matches_source_target(entities, npc_id, name),
+            EntityFilter::AllOf(filters) => filters
+                .iter()
+                .all(|f| f.matches_source_target(entities, npc_id, name)),
+            EntityFilter::AnyOf(filters) => filters
+                .iter()
+                .any(|f| f.matches_source_target(entities, npc_id, name)),
         }
     }
 }
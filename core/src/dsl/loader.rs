@@ -117,6 +117,53 @@ fn build_area_index_recursive(dir: &Path, index: &mut AreaIndex) -> Result<(), S
     Ok(())
 }
 
+/// User encounter definitions directory: standalone encounters and `_custom` overlays
+pub fn user_encounters_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("definitions").join("encounters"))
+}
+
+/// Build the area index the app uses: bundled files first, then user files.
+/// A user file for an area replaces the bundled file in the index.
+pub fn build_layered_area_index(bundled_dir: Option<&Path>, user_dir: Option<&Path>) -> AreaIndex {
+    let mut index = AreaIndex::new();
+    for dir in [bundled_dir, user_dir].into_iter().flatten() {
+        match build_area_index(dir) {
+            Ok(area_index) => index.extend(area_index),
+            Err(e) => tracing::warn!(error = %e, "Failed to index encounter definitions"),
+        }
+    }
+    index
+}
+
+/// Load an area's boss definitions from the index, merging the user's custom
+/// overlay file. Returns `None` for areas without definitions.
+pub fn load_indexed_area(
+    index: &AreaIndex,
+    area_id: i64,
+    user_dir: Option<&Path>,
+) -> Option<Vec<BossEncounterDefinition>> {
+    try_load_indexed_area(index, area_id, user_dir).unwrap_or(None)
+}
+
+/// Like [`load_indexed_area`], but returns the error when an indexed area's
+/// file fails to load. `Ok(None)` for areas without definitions.
+pub fn try_load_indexed_area(
+    index: &AreaIndex,
+    area_id: i64,
+    user_dir: Option<&Path>,
+) -> Result<Option<Vec<BossEncounterDefinition>>, String> {
+    let Some(entry) = index.get(&area_id) else {
+        return Ok(None);
+    };
+    match load_bosses_with_custom(&entry.file_path, user_dir) {
+        Ok(bosses) => Ok(Some(bosses)),
+        Err(e) => {
+            tracing::warn!(area = %entry.name, error = %e, "Failed to load area definitions");
+            Err(e)
+        }
+    }
+}
+
 /// Load all boss definitions from a directory (recursive)
 pub fn load_bosses_from_dir(dir: &Path) -> Result<Vec<BossEncounterDefinition>, String> {
     if !dir.exists() {
@@ -365,6 +412,17 @@ pub fn merge_boss_definition(base: &mut BossEncounterDefinition, custom: BossEnc
 
     // Merge entities by name (entities use name as ID)
     merge_by_id(&mut base.entities, custom.entities, |e| &e.name);
+
+    // Custom tank-swap debuff replaces the bundled one
+    if custom.tank_swap.is_some() {
+        base.tank_swap = custom.tank_swap;
+    }
+
+    // Custom enrage replaces the bundled one
+    if custom.enrage_secs.is_some() {
+        base.enrage_secs = custom.enrage_secs;
+        base.enrage_difficulties = custom.enrage_difficulties;
+    }
 }
 
 /// Generic merge helper: replace matching IDs, append new ones
@@ -636,4 +694,56 @@ conditions = [
             bestia.timers.len()
         );
     }
+
+    #[test]
+    fn test_layered_area_index_merges_custom_overlay() {
+        let root = std::env::temp_dir().join(format!("baras-area-index-{}", std::process::id()));
+        let bundled = root.join("bundled").join("operations");
+        let user = root.join("user");
+        fs::create_dir_all(&bundled).unwrap();
+        fs::create_dir_all(user.join("operations")).unwrap();
+
+        fs::write(
+            bundled.join("dxun.toml"),
+            r#"
+[area]
+name = "Dxun"
+area_id = 100
+
+[[boss]]
+id = "red"
+name = "Red"
+
+[[boss.timer]]
+id = "bundled_timer"
+name = "Bundled"
+trigger = { type = "combat_start" }
+duration_secs = 10.0
+"#,
+        )
+        .unwrap();
+        fs::write(
+            user.join("operations").join("dxun_custom.toml"),
+            r#"
+[[boss]]
+id = "red"
+name = "Red"
+
+[[boss.timer]]
+id = "user_timer"
+name = "Mine"
+trigger = { type = "combat_start" }
+duration_secs = 5.0
+"#,
+        )
+        .unwrap();
+
+        let index = build_layered_area_index(Some(&root.join("bundled")), Some(&user));
+        let bosses = load_indexed_area(&index, 100, Some(&user)).expect("area is indexed");
+        let timers: Vec<_> = bosses[0].timers.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(timers, ["bundled_timer", "user_timer"]);
+        assert!(load_indexed_area(&index, 200, Some(&user)).is_none());
+
+        let _ = fs::remove_dir_all(&root);
+    }
 }
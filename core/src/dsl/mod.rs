@@ -4,33 +4,42 @@
 //! These are the building blocks for defining boss encounters, timers, effects, etc.
 //!
 //! - **audio**: Audio configuration for timers/alerts
+//! - **bulk_edit**: Bulk edits for the encounter editor (timer shifts, clones, NPC ID swaps)
 //! - **definition**: Boss encounter definitions (entities, phases, counters, timers, challenges)
 //! - **challenge**: Challenge metric tracking definitions
 //! - **counter**: Counter definitions for tracking occurrences
 //! - **entity_filter**: Entity matching/filtering
 //! - **loader**: TOML loading and saving
+//! - **notes**: Per-boss raid notes shown on the notes overlay
 //! - **phase**: Phase definitions for boss encounters
+//! - **starparse**: Import of StarParse timer exports (XML)
 //! - **triggers**: Unified trigger system
 //!
 //! Note: Runtime state (phases, counters, HP) is tracked in `CombatEncounter`
 //! which consolidates all encounter-scoped state.
 
 mod audio;
+mod bulk_edit;
 mod challenge;
 mod counter;
 mod definition;
 mod entity_filter;
 mod error;
 mod loader;
+mod notes;
 mod phase;
+mod starparse;
 pub mod triggers;
 
 pub use audio::*;
+pub use bulk_edit::{ChangedItems, clone_timers, replace_npc_id, shift_phase_timer_durations};
 pub use challenge::*;
 pub use counter::*;
 pub use definition::*;
 pub use entity_filter::*;
 pub use error::DslError;
 pub use loader::*;
+pub use notes::*;
 pub use phase::*;
+pub use starparse::{StarParseImport, import_starparse_timers};
 pub use triggers::*;
@@ -0,0 +1,97 @@
+//! Per-boss raid notes (rotations, assignments)
+//!
+//! Notes are plain text files next to the user's encounter definitions, one per
+//! boss definition: `definitions/notes/<definition id>.md`. A line holding only
+//! `---` starts a new page.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Line that separates note pages
+const PAGE_BREAK: &str = "---";
+
+/// User notes directory (`~/.config/baras/definitions/notes`)
+pub fn user_notes_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join("definitions").join("notes"))
+}
+
+/// Path of the notes file for a boss definition
+pub fn boss_notes_path(dir: &Path, definition_id: &str) -> PathBuf {
+    dir.join(format!("{definition_id}.md"))
+}
+
+/// Load the notes for a boss definition. A missing file gives empty notes.
+pub fn load_boss_notes(dir: &Path, definition_id: &str) -> io::Result<String> {
+    match std::fs::read_to_string(boss_notes_path(dir, definition_id)) {
+        Ok(text) => Ok(text),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Save the notes for a boss definition. Blank notes remove the file.
+pub fn save_boss_notes(dir: &Path, definition_id: &str, text: &str) -> io::Result<()> {
+    let path = boss_notes_path(dir, definition_id);
+    if text.trim().is_empty() {
+        return match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        };
+    }
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(path, text)
+}
+
+/// Split notes into pages at `---` lines, dropping blank pages
+pub fn split_note_pages(text: &str) -> Vec<String> {
+    let mut pages = Vec::new();
+    let mut current = Vec::new();
+    for line in text.lines() {
+        if line.trim() == PAGE_BREAK {
+            pages.push(current.join("\n"));
+            current.clear();
+        } else {
+            current.push(line);
+        }
+    }
+    pages.push(current.join("\n"));
+
+    pages
+        .into_iter()
+        .map(|page| page.trim_matches('\n').to_string())
+        .filter(|page| !page.trim().is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_split_at_separator_lines() {
+        let text = "# Tanks\n- Swap at 3 stacks\n---\n\n# Healers\nKarr on Vossa\n---\n   \n";
+        let pages = split_note_pages(text);
+        assert_eq!(
+            pages,
+            vec!["# Tanks\n- Swap at 3 stacks", "# Healers\nKarr on Vossa"]
+        );
+    }
+
+    #[test]
+    fn separator_must_be_alone_on_its_line() {
+        let pages = split_note_pages("Stack --- then spread");
+        assert_eq!(pages, vec!["Stack --- then spread"]);
+    }
+
+    #[test]
+    fn notes_round_trip_and_blank_removes_file() {
+        let dir = std::env::temp_dir().join(format!("baras-notes-test-{}", std::process::id()));
+        save_boss_notes(&dir, "brontes", "# Phase 1").unwrap();
+        assert_eq!(load_boss_notes(&dir, "brontes").unwrap(), "# Phase 1");
+
+        save_boss_notes(&dir, "brontes", "  \n").unwrap();
+        assert!(!boss_notes_path(&dir, "brontes").exists());
+        assert_eq!(load_boss_notes(&dir, "brontes").unwrap(), "");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,640 @@
+//! StarParse timer import
+//!
+//! Converts timers exported from StarParse (XML) into boss encounter
+//! definitions, so guilds can bring their existing timer sets over. Timers are
+//! grouped into one definition per boss: the boss named in the trigger, else
+//! the last segment of the timer's folder, else "Imported".
+//!
+//! StarParse exports differ between versions, so element names are matched
+//! case-insensitively and values may be child elements or attributes. Timers
+//! that have no equivalent here are skipped with a warning rather than failing
+//! the whole import.
+
+use std::collections::HashMap;
+
+use roxmltree::{Document, Node};
+
+use super::{
+    AbilitySelector, AudioConfig, BossEncounterDefinition, BossTimerDefinition, EffectSelector,
+    EntityFilter, EntitySelector, Trigger,
+};
+use crate::serde_defaults::default_timer_color;
+
+/// Boss name for timers with neither a trigger boss nor a folder
+const FALLBACK_BOSS: &str = "Imported";
+
+/// Converted StarParse timers
+#[derive(Debug, Clone, Default)]
+pub struct StarParseImport {
+    /// One definition per boss, holding only timers
+    pub bosses: Vec<BossEncounterDefinition>,
+    /// Timers or settings that could not be converted
+    pub warnings: Vec<String>,
+}
+
+impl StarParseImport {
+    /// Number of converted timers
+    pub fn timer_count(&self) -> usize {
+        self.bosses.iter().map(|b| b.timers.len()).sum()
+    }
+}
+
+/// Convert a StarParse timer export into boss definitions
+pub fn import_starparse_timers(xml: &str) -> Result<StarParseImport, String> {
+    let doc = Document::parse(xml).map_err(|e| format!("Invalid StarParse XML: {}", e))?;
+    let raw: Vec<RawTimer> = doc
+        .descendants()
+        .filter(|n| is_timer_node(*n))
+        .map(RawTimer::from_node)
+        .collect();
+    if raw.is_empty() {
+        return Err("No StarParse timers found in file".to_string());
+    }
+
+    let mut import = StarParseImport::default();
+
+    // Group by boss, keeping the order bosses first appear in
+    let mut groups: Vec<(String, Vec<RawTimer>)> = Vec::new();
+    for timer in raw {
+        let boss = timer.boss_name();
+        match groups
+            .iter_mut()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&boss))
+        {
+            Some((_, timers)) => timers.push(timer),
+            None => groups.push((boss, vec![timer])),
+        }
+    }
+
+    // Assign ids up front so timer triggers can refer to timers of any boss
+    let mut boss_ids: Vec<String> = Vec::new();
+    let mut timer_ids: Vec<Vec<String>> = Vec::new();
+    let mut taken: Vec<String> = Vec::new();
+    let mut by_name: HashMap<String, String> = HashMap::new();
+    for (boss, timers) in &groups {
+        let boss_id = unique_id(slug(boss), &boss_ids);
+        let mut ids = Vec::with_capacity(timers.len());
+        for timer in timers {
+            let id = unique_id(format!("{}_{}", boss_id, slug(&timer.name)), &taken);
+            by_name
+                .entry(timer.name.to_lowercase())
+                .or_insert_with(|| id.clone());
+            taken.push(id.clone());
+            ids.push(id);
+        }
+        boss_ids.push(boss_id);
+        timer_ids.push(ids);
+    }
+    let resolve = |name: &str| by_name.get(&name.trim().to_lowercase()).cloned();
+
+    for (((boss, timers), boss_id), ids) in groups.into_iter().zip(boss_ids).zip(timer_ids) {
+        let mut def = BossEncounterDefinition {
+            id: boss_id,
+            name: boss,
+            ..Default::default()
+        };
+        for (timer, id) in timers.into_iter().zip(ids) {
+            match timer.convert(id, &resolve, &mut import.warnings) {
+                Ok(converted) => def.timers.push(converted),
+                Err(reason) => import
+                    .warnings
+                    .push(format!("Skipped timer '{}': {}", timer.name, reason)),
+            }
+        }
+        if !def.timers.is_empty() {
+            import.bosses.push(def);
+        }
+    }
+
+    Ok(import)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Raw XML Values
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Trigger or cancel condition as written by StarParse
+#[derive(Debug, Clone, Default)]
+struct RawTrigger {
+    kind: String,
+    source: Option<String>,
+    target: Option<String>,
+    ability: Option<String>,
+    ability_guid: Option<String>,
+    effect: Option<String>,
+    effect_guid: Option<String>,
+    timer: Option<String>,
+    boss: Option<String>,
+}
+
+impl RawTrigger {
+    fn from_node(node: Node) -> Self {
+        Self {
+            kind: value(node, &["type"]).unwrap_or_default(),
+            source: value(node, &["source"]),
+            target: value(node, &["target"]),
+            ability: value(node, &["ability"]),
+            ability_guid: value(node, &["abilityGuid"]),
+            effect: value(node, &["effect"]),
+            effect_guid: value(node, &["effectGuid"]),
+            timer: value(node, &["timer"]),
+            boss: value(node, &["boss"]),
+        }
+    }
+
+    fn convert(&self, resolve: &impl Fn(&str) -> Option<String>) -> Result<Trigger, String> {
+        let source = entity_filter(self.source.as_deref());
+        let target = entity_filter(self.target.as_deref());
+        let trigger = match self.kind.to_ascii_uppercase().as_str() {
+            "COMBAT_START" => Trigger::CombatStart,
+            "ABILITY_ACTIVATED" => Trigger::AbilityCast {
+                abilities: vec![self.ability_selector()?],
+                source,
+                target,
+            },
+            "DAMAGE" => Trigger::DamageTaken {
+                abilities: vec![self.ability_selector()?],
+                source,
+                target,
+            },
+            "EFFECT_GAINED" => Trigger::EffectApplied {
+                effects: vec![self.effect_selector()?],
+                source,
+                target,
+            },
+            "EFFECT_LOST" => Trigger::EffectRemoved {
+                effects: vec![self.effect_selector()?],
+                source,
+                target,
+            },
+            "TIMER_STARTED" => Trigger::TimerStarted {
+                timer_id: self.timer_id(resolve)?,
+            },
+            "TIMER_FINISHED" => Trigger::TimerExpires {
+                timer_id: self.timer_id(resolve)?,
+            },
+            "" => return Err("trigger has no type".to_string()),
+            other => return Err(format!("unsupported trigger type {}", other)),
+        };
+        Ok(trigger)
+    }
+
+    fn ability_selector(&self) -> Result<AbilitySelector, String> {
+        match (&self.ability_guid, &self.ability) {
+            (Some(guid), _) => Ok(AbilitySelector::from_input(guid)),
+            (None, Some(name)) => Ok(AbilitySelector::from_input(name)),
+            (None, None) => Err("trigger has no ability".to_string()),
+        }
+    }
+
+    fn effect_selector(&self) -> Result<EffectSelector, String> {
+        match (&self.effect_guid, &self.effect) {
+            (Some(guid), _) => Ok(EffectSelector::from_input(guid)),
+            (None, Some(name)) => Ok(EffectSelector::from_input(name)),
+            (None, None) => Err("trigger has no effect".to_string()),
+        }
+    }
+
+    fn timer_id(&self, resolve: &impl Fn(&str) -> Option<String>) -> Result<String, String> {
+        let name = self
+            .timer
+            .as_deref()
+            .ok_or_else(|| "trigger names no timer".to_string())?;
+        resolve(name).ok_or_else(|| format!("timer '{}' is not in the file", name))
+    }
+}
+
+/// Timer as written by StarParse
+#[derive(Debug, Clone, Default)]
+struct RawTimer {
+    name: String,
+    folder: Option<String>,
+    enabled: bool,
+    trigger: RawTrigger,
+    cancel: Option<RawTrigger>,
+    interval: Option<String>,
+    repeat: Option<String>,
+    color: Option<Result<[u8; 4], String>>,
+    audio: Option<String>,
+    volume: Option<String>,
+    countdown_count: Option<String>,
+    countdown_voice: Option<String>,
+    audio_text: Option<String>,
+}
+
+impl RawTimer {
+    fn from_node(node: Node) -> Self {
+        Self {
+            name: value(node, &["name"]).unwrap_or_default(),
+            folder: value(node, &["folder"]),
+            enabled: value(node, &["enabled"]).is_none_or(|v| is_true(&v)),
+            trigger: child(node, "trigger")
+                .map(RawTrigger::from_node)
+                .unwrap_or_default(),
+            cancel: child(node, "cancel")
+                .map(RawTrigger::from_node)
+                .filter(|c| !c.kind.is_empty()),
+            interval: value(node, &["interval"]),
+            repeat: value(node, &["repeat", "repeats"]),
+            color: child(node, "color")
+                .map(parse_color)
+                .or_else(|| node.attribute("color").map(parse_color_text)),
+            audio: value(node, &["audio", "sound"]),
+            volume: value(node, &["volume"]),
+            countdown_count: value(node, &["countdownCount"]),
+            countdown_voice: value(node, &["countdownVoice"]),
+            audio_text: value(node, &["audioText", "alertText", "text"]),
+        }
+    }
+
+    /// Boss the timer belongs to: trigger boss, else the innermost folder
+    fn boss_name(&self) -> String {
+        self.trigger
+            .boss
+            .clone()
+            .or_else(|| {
+                self.folder.as_deref().and_then(|folder| {
+                    folder
+                        .rsplit(['/', '\\', '»'])
+                        .map(str::trim)
+                        .find(|s| !s.is_empty())
+                        .map(str::to_string)
+                })
+            })
+            .unwrap_or_else(|| FALLBACK_BOSS.to_string())
+    }
+
+    fn convert(
+        &self,
+        id: String,
+        resolve: &impl Fn(&str) -> Option<String>,
+        warnings: &mut Vec<String>,
+    ) -> Result<BossTimerDefinition, String> {
+        if self.name.is_empty() {
+            return Err("timer has no name".to_string());
+        }
+        let trigger = self.trigger.convert(resolve)?;
+        let cancel_trigger = self.cancel.as_ref().and_then(|cancel| {
+            cancel
+                .convert(resolve)
+                .map_err(|reason| {
+                    warnings.push(format!(
+                        "Timer '{}': dropped cancel condition ({})",
+                        self.name, reason
+                    ))
+                })
+                .ok()
+        });
+
+        let duration_secs = match self.interval.as_deref().map(str::parse::<f32>) {
+            Some(Ok(secs)) if secs >= 0.0 => secs,
+            Some(_) => return Err("invalid interval".to_string()),
+            None => 0.0,
+        };
+        let repeats = self
+            .repeat
+            .as_deref()
+            .and_then(|r| r.parse::<u32>().ok())
+            .map_or(0, |r| r.min(u8::MAX as u32) as u8);
+        let color = match &self.color {
+            Some(Ok(color)) => *color,
+            Some(Err(raw)) => {
+                warnings.push(format!(
+                    "Timer '{}': unrecognized color '{}'",
+                    self.name, raw
+                ));
+                default_timer_color()
+            }
+            None => default_timer_color(),
+        };
+
+        Ok(BossTimerDefinition {
+            id,
+            name: self.name.clone(),
+            display_text: None,
+            trigger,
+            duration_secs,
+            is_alert: duration_secs == 0.0,
+            alert_text: None,
+            alert_priority: Default::default(),
+            color,
+            phases: Vec::new(),
+            counter_condition: None,
+            difficulties: Vec::new(),
+            group_size: None,
+            enabled: self.enabled,
+            can_be_refreshed: false,
+            repeats,
+            chains_to: None,
+            cancel_trigger,
+            alert_at_secs: None,
+            show_on_raid_frames: false,
+            show_at_secs: 0.0,
+            display_target: Default::default(),
+            suggested_cooldown: None,
+            audio: self.audio_config(),
+            per_target: false,
+        })
+    }
+
+    fn audio_config(&self) -> AudioConfig {
+        let countdown_start = self
+            .countdown_count
+            .as_deref()
+            .and_then(|c| c.parse::<u8>().ok())
+            .unwrap_or(0);
+        let speak_alert = self.audio.is_none() && self.audio_text.is_some();
+        AudioConfig {
+            enabled: self.audio.is_some() || countdown_start > 0 || speak_alert,
+            file: self.audio.clone(),
+            volume: self
+                .volume
+                .as_deref()
+                .and_then(|v| v.parse::<f32>().ok())
+                .map(|v| v.clamp(0.0, 100.0) as u8),
+            offset: 0,
+            countdown_start,
+            countdown_voice: self.countdown_voice.clone(),
+            alert_text: self.audio_text.clone(),
+            speak_alert,
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Helpers
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// A timer element: anything with both a name and a trigger
+fn is_timer_node(node: Node) -> bool {
+    node.is_element()
+        && child(node, "trigger").is_some()
+        && (child(node, "name").is_some() || node.attribute("name").is_some())
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|c| c.is_element() && c.tag_name().name().eq_ignore_ascii_case(name))
+}
+
+/// First non-empty value among `names`, from a child element or an attribute
+fn value(node: Node, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| {
+        let text = child(node, name).and_then(|c| c.text()).or_else(|| {
+            node.attributes()
+                .find(|a| a.name().eq_ignore_ascii_case(name))
+                .map(|a| a.value())
+        })?;
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    })
+}
+
+fn is_true(text: &str) -> bool {
+    matches!(text.to_ascii_lowercase().as_str(), "true" | "1" | "yes")
+}
+
+/// Source/target: `@Self`, `@Other`, an NPC ID or an NPC name
+fn entity_filter(value: Option<&str>) -> EntityFilter {
+    match value.map(str::trim) {
+        None | Some("") => EntityFilter::Any,
+        Some(v) if v.eq_ignore_ascii_case("@Self") => EntityFilter::LocalPlayer,
+        Some(v) if v.eq_ignore_ascii_case("@Other") => EntityFilter::OtherPlayers,
+        Some(v) if v.eq_ignore_ascii_case("@Any") => EntityFilter::Any,
+        Some(v) => EntityFilter::Selector(vec![EntitySelector::from_input(v)]),
+    }
+}
+
+/// Color as `#rrggbb[aa]`, `0xrrggbb[aa]`, `r,g,b[,a]`, or `red`/`green`/
+/// `blue`/`alpha` children (0-255, or 0-1 as JavaFX writes them)
+fn parse_color(node: Node) -> Result<[u8; 4], String> {
+    let channel = |names: &[&str]| value(node, names).map(|v| v.parse::<f32>());
+    match (
+        channel(&["red", "r"]),
+        channel(&["green", "g"]),
+        channel(&["blue", "b"]),
+    ) {
+        (Some(Ok(r)), Some(Ok(g)), Some(Ok(b))) => {
+            let a = match channel(&["alpha", "opacity", "a"]) {
+                Some(Ok(a)) => a,
+                _ if r.max(g).max(b) <= 1.0 => 1.0,
+                _ => 255.0,
+            };
+            Ok(scale_channels([r, g, b, a]))
+        }
+        _ => parse_color_text(node.text().unwrap_or_default()),
+    }
+}
+
+fn parse_color_text(text: &str) -> Result<[u8; 4], String> {
+    let text = text.trim();
+    let hex = text
+        .strip_prefix('#')
+        .or_else(|| text.strip_prefix("0x"))
+        .unwrap_or(text);
+    if matches!(hex.len(), 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(255);
+        let alpha = if hex.len() == 8 { byte(6) } else { 255 };
+        return Ok([byte(0), byte(2), byte(4), alpha]);
+    }
+
+    let parts: Vec<f32> = text
+        .split(',')
+        .map(|p| p.trim().parse::<f32>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| text.to_string())?;
+    match parts[..] {
+        [r, g, b] if r.max(g).max(b) <= 1.0 => Ok(scale_channels([r, g, b, 1.0])),
+        [r, g, b] => Ok(scale_channels([r, g, b, 255.0])),
+        [r, g, b, a] => Ok(scale_channels([r, g, b, a])),
+        _ => Err(text.to_string()),
+    }
+}
+
+/// Channels in 0-1 are scaled to 0-255 (all at once, so pure black stays black)
+fn scale_channels(channels: [f32; 4]) -> [u8; 4] {
+    let unit = channels.iter().all(|c| *c <= 1.0);
+    channels.map(|c| {
+        let c = if unit { c * 255.0 } else { c };
+        c.round().clamp(0.0, 255.0) as u8
+    })
+}
+
+/// Lowercase id with runs of non-alphanumerics collapsed to `_`
+fn slug(name: &str) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+    if slug.is_empty() {
+        "timer".to_string()
+    } else {
+        slug
+    }
+}
+
+/// `base`, suffixed with `_2`, `_3`, ... if already taken
+fn unique_id(base: String, taken: &[String]) -> String {
+    if !taken.contains(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}_{}", base, n))
+        .find(|id| !taken.contains(id))
+        .unwrap_or(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsl::BossConfig;
+
+    const EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<com.ixale.starparse.domain.ConfigTimers>
+  <timers>
+    <com.ixale.starparse.domain.ConfigTimer>
+      <name>Thundering Blast</name>
+      <folder>Operations/Dread Fortress</folder>
+      <enabled>true</enabled>
+      <trigger>
+        <type>ABILITY_ACTIVATED</type>
+        <source>3273941900591104</source>
+        <abilityGuid>3294098116231168</abilityGuid>
+        <boss>Dread Master Brontes</boss>
+      </trigger>
+      <interval>18.5</interval>
+      <color>0xff8000ff</color>
+      <audio>Beep.wav</audio>
+      <volume>80</volume>
+      <countdownCount>3</countdownCount>
+    </com.ixale.starparse.domain.ConfigTimer>
+    <com.ixale.starparse.domain.ConfigTimer>
+      <name>Thundering Blast (next)</name>
+      <enabled>false</enabled>
+      <trigger>
+        <type>TIMER_FINISHED</type>
+        <timer>thundering blast</timer>
+        <boss>Dread Master Brontes</boss>
+      </trigger>
+      <cancel>
+        <type>EFFECT_LOST</type>
+        <target>@Self</target>
+        <effect>Kinetic Bulwark</effect>
+      </cancel>
+      <interval>12</interval>
+      <repeat>2</repeat>
+      <color>
+        <red>0.0</red>
+        <green>0.5</green>
+        <blue>1.0</blue>
+      </color>
+      <audioText>Swap tanks</audioText>
+    </com.ixale.starparse.domain.ConfigTimer>
+    <com.ixale.starparse.domain.ConfigTimer>
+      <name>Tyrans Pull</name>
+      <folder>Operations/Dread Palace/Tyrans</folder>
+      <trigger>
+        <type>COMBAT_START</type>
+      </trigger>
+      <interval>30</interval>
+    </com.ixale.starparse.domain.ConfigTimer>
+    <com.ixale.starparse.domain.ConfigTimer>
+      <name>Raid Buff</name>
+      <trigger>
+        <type>RAID_CHALLENGE</type>
+      </trigger>
+      <interval>10</interval>
+    </com.ixale.starparse.domain.ConfigTimer>
+  </timers>
+</com.ixale.starparse.domain.ConfigTimers>"#;
+
+    fn timer<'a>(import: &'a StarParseImport, id: &str) -> &'a BossTimerDefinition {
+        import
+            .bosses
+            .iter()
+            .flat_map(|b| &b.timers)
+            .find(|t| t.id == id)
+            .unwrap_or_else(|| panic!("timer {} not imported", id))
+    }
+
+    #[test]
+    fn timers_are_grouped_by_trigger_boss_then_folder() {
+        let import = import_starparse_timers(EXPORT).unwrap();
+        let bosses: Vec<_> = import
+            .bosses
+            .iter()
+            .map(|b| (b.id.as_str(), b.name.as_str(), b.timers.len()))
+            .collect();
+        assert_eq!(
+            bosses,
+            vec![
+                ("dread_master_brontes", "Dread Master Brontes", 2),
+                ("tyrans", "Tyrans", 1),
+            ]
+        );
+        assert_eq!(import.timer_count(), 3);
+        // The unsupported trigger type is reported, not fatal
+        assert_eq!(import.warnings.len(), 1);
+        assert!(import.warnings[0].contains("Raid Buff"));
+    }
+
+    #[test]
+    fn trigger_duration_color_and_audio_are_converted() {
+        let import = import_starparse_timers(EXPORT).unwrap();
+        let blast = timer(&import, "dread_master_brontes_thundering_blast");
+        assert!(matches!(
+            &blast.trigger,
+            Trigger::AbilityCast { abilities, source: EntityFilter::Selector(source), target: EntityFilter::Any }
+                if abilities == &[AbilitySelector::Id(3294098116231168)]
+                    && source == &[EntitySelector::Id(3273941900591104)]
+        ));
+        assert_eq!(blast.duration_secs, 18.5);
+        assert_eq!(blast.color, [255, 128, 0, 255]);
+        assert!(blast.enabled);
+        assert!(blast.audio.enabled);
+        assert_eq!(blast.audio.file.as_deref(), Some("Beep.wav"));
+        assert_eq!(blast.audio.volume, Some(80));
+        assert_eq!(blast.audio.countdown_start, 3);
+    }
+
+    #[test]
+    fn timer_references_and_cancel_conditions_resolve() {
+        let import = import_starparse_timers(EXPORT).unwrap();
+        let next = timer(&import, "dread_master_brontes_thundering_blast_next");
+        assert!(matches!(
+            &next.trigger,
+            Trigger::TimerExpires { timer_id } if timer_id == "dread_master_brontes_thundering_blast"
+        ));
+        assert!(matches!(
+            &next.cancel_trigger,
+            Some(Trigger::EffectRemoved { effects, target: EntityFilter::LocalPlayer, .. })
+                if effects == &[EffectSelector::Name("Kinetic Bulwark".to_string())]
+        ));
+        assert!(!next.enabled);
+        assert_eq!(next.repeats, 2);
+        assert_eq!(next.color, [0, 128, 255, 255]);
+        assert!(next.audio.speak_alert);
+        assert_eq!(next.audio.alert_text.as_deref(), Some("Swap tanks"));
+    }
+
+    #[test]
+    fn imported_bosses_round_trip_through_toml() {
+        let import = import_starparse_timers(EXPORT).unwrap();
+        let config = BossConfig {
+            area: None,
+            bosses: import.bosses,
+        };
+        let text = toml::to_string(&config).unwrap();
+        let parsed: BossConfig = toml::from_str(&text).unwrap();
+        assert_eq!(parsed.bosses.len(), 2);
+        assert_eq!(parsed.bosses[0].timers.len(), 2);
+    }
+
+    #[test]
+    fn files_without_timers_are_rejected() {
+        assert!(import_starparse_timers("<timers></timers>").is_err());
+        assert!(import_starparse_timers("not xml").is_err());
+    }
+}
@@ -0,0 +1,99 @@
+//! Sliding-window state for `AllOf` triggers.
+//!
+//! Each `AllOf` trigger keeps one slot per condition holding the last time that
+//! condition matched. A trigger fires once every event slot holds a timestamp
+//! within `window_secs` of the current event, no `not` slot does, and every
+//! state condition holds; its slots are then reset.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+
+/// How one `AllOf` condition relates to the current event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllOfCondition {
+    /// Event condition; true if it matched this event
+    Event(bool),
+    /// `not` condition; true if its inner condition matched this event
+    Veto(bool),
+    /// State condition; true if it currently holds
+    State(bool),
+}
+
+/// Per-encounter condition hits for `AllOf` triggers, keyed by owner ID
+/// (e.g. timer definition ID).
+#[derive(Debug, Clone, Default)]
+pub struct AllOfState {
+    seen: HashMap<String, Vec<Option<NaiveDateTime>>>,
+}
+
+impl AllOfState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record how the conditions relate to the current event.
+    ///
+    /// `conditions` has one entry per condition. Returns true when all event
+    /// conditions have now been met within `window_secs`, no vetoing condition
+    /// was seen in that window and all state conditions hold; the slots are
+    /// reset so the trigger has to be satisfied again before firing a second time.
+    pub fn record(
+        &mut self,
+        key: &str,
+        conditions: &[AllOfCondition],
+        window_secs: f32,
+        timestamp: NaiveDateTime,
+    ) -> bool {
+        let event_hit = conditions.contains(&AllOfCondition::Event(true));
+        if !event_hit && !conditions.contains(&AllOfCondition::Veto(true)) {
+            return false;
+        }
+
+        let slots = self
+            .seen
+            .entry(key.to_string())
+            .or_insert_with(|| vec![None; conditions.len()]);
+        // Definitions can be reloaded mid-encounter with a different condition count
+        if slots.len() != conditions.len() {
+            *slots = vec![None; conditions.len()];
+        }
+
+        let window_ms = (window_secs.max(0.0) * 1000.0) as i64;
+        for (slot, condition) in slots.iter_mut().zip(conditions) {
+            match condition {
+                AllOfCondition::Event(true) | AllOfCondition::Veto(true) => *slot = Some(timestamp),
+                _ => {
+                    if let Some(seen_at) = *slot
+                        && (timestamp - seen_at).num_milliseconds() > window_ms
+                    {
+                        *slot = None;
+                    }
+                }
+            }
+        }
+
+        // Only an arriving event can complete the trigger, and only with at
+        // least one event condition to anchor the window
+        if !event_hit {
+            return false;
+        }
+        let satisfied = slots
+            .iter()
+            .zip(conditions)
+            .all(|(slot, condition)| match condition {
+                AllOfCondition::Event(_) => slot.is_some(),
+                AllOfCondition::Veto(_) => slot.is_none(),
+                AllOfCondition::State(holds) => *holds,
+            });
+        if satisfied {
+            self.seen.remove(key);
+        }
+        satisfied
+    }
+
+    /// Forget all recorded hits (call at encounter boundaries)
+    pub fn clear(&mut self) {
+        self.seen.clear();
+    }
+}
@@ -4,14 +4,16 @@
 //! separate `TimerTrigger`, `PhaseTrigger`, and `CounterTrigger` types.
 //! Each system only responds to the trigger variants it supports.
 
+mod all_of;
 mod matchers;
 
+pub use all_of::{AllOfCondition, AllOfState};
 pub use matchers::{AbilitySelector, EffectSelector, EntitySelector, EntitySelectorExt};
 
 // Re-export EntityFilter for use in triggers
 pub use baras_types::EntityFilter;
 
-use crate::dsl::EntityDefinition;
+use crate::dsl::{ComparisonOp, EntityDefinition};
 use serde::{Deserialize, Serialize};
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -38,6 +40,10 @@ impl TriggerScope {
     pub const fn union(self, other: Self) -> Self {
         Self(self.0 | other.0)
     }
+
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -76,6 +82,34 @@ pub enum Trigger {
         target: EntityFilter,
     },
 
+    /// Ability cast begins (`AbilityActivate`). [T only]
+    /// Same event as `ability_cast`, named explicitly to pair with `cast_completed`.
+    CastStarted {
+        /// Ability selectors (ID or name).
+        #[serde(default)]
+        abilities: Vec<AbilitySelector>,
+        /// Who cast the ability (default: any)
+        #[serde(default = "EntityFilter::default_any")]
+        source: EntityFilter,
+        /// Who the ability targets (default: any)
+        #[serde(default = "EntityFilter::default_any")]
+        target: EntityFilter,
+    },
+
+    /// Ability cast finishes (`AbilityDeactivate`). [T only]
+    /// Interrupted or cancelled casts never complete, so this does not fire for them.
+    CastCompleted {
+        /// Ability selectors (ID or name).
+        #[serde(default)]
+        abilities: Vec<AbilitySelector>,
+        /// Who cast the ability (default: any)
+        #[serde(default = "EntityFilter::default_any")]
+        source: EntityFilter,
+        /// Who the ability targets (default: any)
+        #[serde(default = "EntityFilter::default_any")]
+        target: EntityFilter,
+    },
+
     /// Effect/buff is applied. [TPC]
     EffectApplied {
         /// Effect selectors (ID or name).
@@ -195,11 +229,44 @@ pub enum Trigger {
     // ─── Composition [TPC] ─────────────────────────────────────────────────
     /// Any condition suffices (OR logic). [TPC]
     AnyOf { conditions: Vec<Trigger> },
+
+    /// Every condition must occur within `window_secs` of each other (AND logic). [T only]
+    /// Conditions are evaluated individually as events arrive; the timer fires
+    /// once the last outstanding condition is met inside the window.
+    /// `not` and state conditions (`phase_active`, `counter_is`) are checked at
+    /// that moment. A window of 0 means all events share one timestamp.
+    /// Only supported as a top-level timer trigger (not nested in `any_of`).
+    AllOf {
+        conditions: Vec<Trigger>,
+        #[serde(default)]
+        window_secs: f32,
+    },
+
+    /// The condition must NOT have occurred within the `all_of` window. [T only]
+    /// Only meaningful as an `all_of` condition; never fires on its own.
+    Not { condition: Box<Trigger> },
+
+    // ─── State Conditions [T only, inside all_of] ──────────────────────────
+    /// A phase is currently active. [T only]
+    /// Checked when an `all_of` completes; never fires on its own.
+    PhaseActive { phase_id: String },
+
+    /// A counter currently compares true against a value. [T only]
+    /// Checked when an `all_of` completes; never fires on its own.
+    CounterIs {
+        counter_id: String,
+        #[serde(default)]
+        operator: ComparisonOp,
+        value: u32,
+    },
 }
 
 impl Trigger {
     /// Returns which systems respond to this trigger type.
-    pub const fn scope(&self) -> TriggerScope {
+    ///
+    /// Composites only reach systems every condition supports, and `all_of`
+    /// (with its `not` and state conditions) is timer-only.
+    pub fn scope(&self) -> TriggerScope {
         match self {
             // Universal (all systems)
             Self::CombatStart
@@ -210,46 +277,76 @@ impl Trigger {
             | Self::BossHpBelow { .. }
             | Self::NpcAppears { .. }
             | Self::EntityDeath { .. }
-            | Self::PhaseEnded { .. }
-            | Self::AnyOf { .. } => TriggerScope::ALL,
+            | Self::PhaseEnded { .. } => TriggerScope::ALL,
 
             // Timer + Phase
             Self::TimeElapsed { .. } | Self::CounterReaches { .. } => TriggerScope::TIMER_PHASE,
 
             // Timer + Counter
-            Self::PhaseEntered { .. } => {
-                TriggerScope(TriggerScope::TIMER.0 | TriggerScope::COUNTER.0)
-            }
+            Self::PhaseEntered { .. } => TriggerScope::TIMER.union(TriggerScope::COUNTER),
 
             // Timer only
             Self::TimerExpires { .. }
             | Self::TimerStarted { .. }
             | Self::TargetSet { .. }
-            | Self::Manual => TriggerScope::TIMER,
+            | Self::CastStarted { .. }
+            | Self::CastCompleted { .. }
+            | Self::Manual
+            | Self::PhaseActive { .. }
+            | Self::CounterIs { .. } => TriggerScope::TIMER,
 
             // Phase only
             Self::BossHpAbove { .. } => TriggerScope::PHASE,
 
             // Counter only
             Self::CombatEnd | Self::AnyPhaseChange | Self::Never => TriggerScope::COUNTER,
+
+            // Composition
+            Self::AnyOf { conditions } => conditions
+                .iter()
+                .fold(TriggerScope::ALL, |scope, c| scope.intersection(c.scope())),
+            Self::AllOf { conditions, .. } => {
+                conditions.iter().fold(TriggerScope::TIMER, |scope, c| {
+                    scope.intersection(c.scope())
+                })
+            }
+            Self::Not { condition } => TriggerScope::TIMER.intersection(condition.scope()),
         }
     }
 
     /// Check if this trigger is valid for use as a timer trigger.
-    pub const fn valid_for_timer(&self) -> bool {
+    pub fn valid_for_timer(&self) -> bool {
         self.scope().contains(TriggerScope::TIMER)
     }
 
     /// Check if this trigger is valid for use as a phase trigger.
-    pub const fn valid_for_phase(&self) -> bool {
+    pub fn valid_for_phase(&self) -> bool {
         self.scope().contains(TriggerScope::PHASE)
     }
 
     /// Check if this trigger is valid for use as a counter trigger.
-    pub const fn valid_for_counter(&self) -> bool {
+    pub fn valid_for_counter(&self) -> bool {
         self.scope().contains(TriggerScope::COUNTER)
     }
 
+    /// Evaluate a state condition (`phase_active`, `counter_is`) against the
+    /// current encounter state. Returns `None` for event conditions.
+    pub fn state_holds(
+        &self,
+        current_phase: Option<&str>,
+        counter_value: impl Fn(&str) -> u32,
+    ) -> Option<bool> {
+        match self {
+            Self::PhaseActive { phase_id } => Some(current_phase == Some(phase_id.as_str())),
+            Self::CounterIs {
+                counter_id,
+                operator,
+                value,
+            } => Some(operator.evaluate(counter_value(counter_id), *value)),
+            _ => None,
+        }
+    }
+
     /// Check if this trigger contains CombatStart (directly or nested in AnyOf).
     pub fn contains_combat_start(&self) -> bool {
         match self {
@@ -264,6 +361,8 @@ impl Trigger {
     pub fn source_filter(&self) -> Option<&EntityFilter> {
         match self {
             Self::AbilityCast { source, .. }
+            | Self::CastStarted { source, .. }
+            | Self::CastCompleted { source, .. }
             | Self::EffectApplied { source, .. }
             | Self::EffectRemoved { source, .. }
             | Self::DamageTaken { source, .. } => Some(source),
@@ -300,6 +399,16 @@ impl Trigger {
                 source,
                 target,
             },
+            Self::CastStarted { abilities, .. } => Self::CastStarted {
+                abilities,
+                source,
+                target,
+            },
+            Self::CastCompleted { abilities, .. } => Self::CastCompleted {
+                abilities,
+                source,
+                target,
+            },
             Self::EffectApplied { effects, .. } => Self::EffectApplied {
                 effects,
                 source,
@@ -341,6 +450,40 @@ impl Trigger {
         }
     }
 
+    /// Check if trigger matches an ability cast starting.
+    pub fn matches_cast_started(&self, ability_id: u64, ability_name: Option<&str>) -> bool {
+        match self {
+            Self::CastStarted { abilities, .. } => {
+                // Require explicit selectors - empty list matches nothing
+                !abilities.is_empty()
+                    && abilities
+                        .iter()
+                        .any(|s| s.matches(ability_id, ability_name))
+            }
+            Self::AnyOf { conditions } => conditions
+                .iter()
+                .any(|c| c.matches_cast_started(ability_id, ability_name)),
+            _ => false,
+        }
+    }
+
+    /// Check if trigger matches an ability cast completing.
+    pub fn matches_cast_completed(&self, ability_id: u64, ability_name: Option<&str>) -> bool {
+        match self {
+            Self::CastCompleted { abilities, .. } => {
+                // Require explicit selectors - empty list matches nothing
+                !abilities.is_empty()
+                    && abilities
+                        .iter()
+                        .any(|s| s.matches(ability_id, ability_name))
+            }
+            Self::AnyOf { conditions } => conditions
+                .iter()
+                .any(|c| c.matches_cast_completed(ability_id, ability_name)),
+            _ => false,
+        }
+    }
+
     /// Check if trigger matches an effect being applied.
     pub fn matches_effect_applied(&self, effect_id: u64, effect_name: Option<&str>) -> bool {
         match self {
@@ -678,4 +821,110 @@ mod tests {
         let parsed: Trigger = toml::from_str(&toml).unwrap();
         assert_eq!(trigger, parsed);
     }
+
+    fn cast(ability_id: u64) -> Trigger {
+        Trigger::AbilityCast {
+            abilities: vec![AbilitySelector::Id(ability_id)],
+            source: EntityFilter::Any,
+            target: EntityFilter::Any,
+        }
+    }
+
+    #[test]
+    fn trigger_scope_composites_intersect() {
+        let any_of = Trigger::AnyOf {
+            conditions: vec![cast(1), Trigger::TimeElapsed { secs: 30.0 }],
+        };
+        assert_eq!(any_of.scope(), TriggerScope::TIMER_PHASE);
+
+        let all_of = Trigger::AllOf {
+            conditions: vec![cast(1), Trigger::CombatEnd],
+            window_secs: 1.0,
+        };
+        assert!(!all_of.valid_for_timer(), "counter-only condition");
+
+        let not = Trigger::Not {
+            condition: Box::new(cast(1)),
+        };
+        assert_eq!(not.scope(), TriggerScope::TIMER);
+        assert!(!not.valid_for_phase());
+    }
+
+    #[test]
+    fn state_conditions_never_match_events() {
+        let phase = Trigger::PhaseActive {
+            phase_id: "burn".to_string(),
+        };
+        assert!(!phase.matches_phase_entered("burn"));
+        assert_eq!(phase.state_holds(Some("burn"), |_| 0), Some(true));
+        assert_eq!(phase.state_holds(None, |_| 0), Some(false));
+
+        let counter = Trigger::CounterIs {
+            counter_id: "stacks".to_string(),
+            operator: ComparisonOp::Gte,
+            value: 2,
+        };
+        assert_eq!(counter.state_holds(None, |_| 2), Some(true));
+        assert_eq!(counter.state_holds(None, |_| 1), Some(false));
+        assert_eq!(cast(1).state_holds(None, |_| 0), None);
+
+        let not = Trigger::Not {
+            condition: Box::new(cast(1)),
+        };
+        assert!(!not.matches_ability(1, None));
+    }
+
+    #[test]
+    fn serde_all_of_round_trip() {
+        let trigger = Trigger::AllOf {
+            conditions: vec![
+                cast(123),
+                Trigger::Not {
+                    condition: Box::new(cast(456)),
+                },
+                Trigger::PhaseActive {
+                    phase_id: "burn".to_string(),
+                },
+                Trigger::CounterIs {
+                    counter_id: "stacks".to_string(),
+                    operator: ComparisonOp::Gte,
+                    value: 2,
+                },
+            ],
+            window_secs: 3.0,
+        };
+        let toml = toml::to_string(&trigger).unwrap();
+        let parsed: Trigger = toml::from_str(&toml).unwrap();
+        assert_eq!(trigger, parsed);
+    }
+
+    #[test]
+    fn serde_all_of_defaults() {
+        let parsed: Trigger = toml::from_str(
+            r#"
+            type = "all_of"
+            conditions = [
+                { type = "not", condition = { type = "phase_entered", phase_id = "p2" } },
+                { type = "counter_is", counter_id = "stacks", value = 3 },
+            ]
+            "#,
+        )
+        .unwrap();
+        let Trigger::AllOf {
+            conditions,
+            window_secs,
+        } = parsed
+        else {
+            panic!("expected all_of");
+        };
+        assert_eq!(window_secs, 0.0);
+        assert_eq!(
+            conditions[1],
+            Trigger::CounterIs {
+                counter_id: "stacks".to_string(),
+                operator: ComparisonOp::Eq,
+                value: 3,
+            }
+        );
+    }
 }
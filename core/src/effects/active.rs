@@ -17,8 +17,9 @@ use std::time::{Duration, Instant};
 
 use chrono::NaiveDateTime;
 
-use super::DisplayTarget;
+use super::{DisplayTarget, EffectSeverity};
 use crate::context::IStr;
+use crate::timers::lag_compensation;
 
 /// How long to show a faded effect after removal before deleting
 const FADE_DURATION: Duration = Duration::from_secs(2);
@@ -92,6 +93,9 @@ pub struct ActiveEffect {
     /// Which overlay should display this effect
     pub display_target: DisplayTarget,
 
+    /// Raid frame priority
+    pub severity: EffectSeverity,
+
     /// Ability ID for icon lookup (may differ from game_effect_id)
     pub icon_ability_id: u64,
 
@@ -123,6 +127,9 @@ pub struct ActiveEffect {
     /// Audio file for on-apply sound
     pub audio_file: Option<String>,
 
+    /// Volume override for `audio_file` (None = global volume)
+    pub audio_volume: Option<u8>,
+
     /// Audio offset (seconds before expiration to play sound)
     pub audio_offset: u8,
 
@@ -156,6 +163,7 @@ impl ActiveEffect {
         duration: Option<Duration>,
         color: [u8; 4],
         display_target: DisplayTarget,
+        severity: EffectSeverity,
         icon_ability_id: u64,
         show_at_secs: f32,
         show_icon: bool,
@@ -165,12 +173,7 @@ impl ActiveEffect {
         alert_text: Option<String>,
         alert_on_expire: bool,
     ) -> Self {
-        // Calculate lag compensation: how far behind was the game event from system time?
-        // This accounts for file I/O delay, processing time, etc.
-        let now_system = chrono::Local::now().naive_local();
-        let lag = now_system.signed_duration_since(event_timestamp);
-        let lag_ms = lag.num_milliseconds().max(0) as u64;
-        let lag_duration = Duration::from_millis(lag_ms);
+        let lag_duration = lag_compensation(chrono::Local::now().naive_local(), event_timestamp);
 
         // Backdate applied_instant to when the event actually happened in game
         // This ensures remaining_secs_realtime() reflects actual game time
@@ -199,6 +202,7 @@ impl ActiveEffect {
             stacks: 1,
             color,
             display_target,
+            severity,
             icon_ability_id,
             show_at_secs,
             show_icon,
@@ -212,6 +216,7 @@ impl ActiveEffect {
                 .clone()
                 .unwrap_or_else(|| "Amy".to_string()),
             audio_file: audio.file.clone(),
+            audio_volume: audio.volume,
             audio_offset: audio.offset,
             audio_enabled: audio.enabled,
             on_end_alert_fired: false,
@@ -229,13 +234,8 @@ impl ActiveEffect {
                 Some(event_timestamp + chrono::Duration::milliseconds(d.as_millis() as i64));
             self.duration = Some(d);
 
-            // Calculate lag between game event and system processing
-            let now_system = chrono::Local::now().naive_local();
-            let lag_ms = now_system
-                .signed_duration_since(event_timestamp)
-                .num_milliseconds()
-                .max(0) as u64;
-            let lag_duration = Duration::from_millis(lag_ms);
+            let lag_duration =
+                lag_compensation(chrono::Local::now().naive_local(), event_timestamp);
 
             // Backdate applied_instant to account for processing lag
             let now = Instant::now();
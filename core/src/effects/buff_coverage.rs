@@ -0,0 +1,213 @@
+//! Raid buff coverage
+//!
+//! Follows the class buffs (see [`RAID_BUFFS`]) and stim on the local player
+//! so a missing buff can be reported before a pull and after it. The combat
+//! log only shows effects involving the local player, and class buffs cover
+//! the whole group, so the local player's buffs stand in for the group's.
+
+use crate::combat_log::EntityType;
+use crate::context::resolve;
+use crate::encounter::CombatEncounter;
+use crate::game_data::{RAID_BUFFS, is_stim, raid_buff_index};
+use crate::signal_processor::{GameSignal, SignalHandler};
+
+/// Buffs missing on the local player at a point in time
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuffCoverageReport {
+    /// Labels of class buffs no one in the group provided
+    pub missing_buffs: Vec<&'static str>,
+    /// The local player has no stim active
+    pub missing_stim: bool,
+}
+
+impl BuffCoverageReport {
+    pub fn is_complete(&self) -> bool {
+        self.missing_buffs.is_empty() && !self.missing_stim
+    }
+
+    /// One-line summary, e.g. "Missing Coordination, Hunter's Boon, stim"
+    pub fn summary(&self) -> Option<String> {
+        if self.is_complete() {
+            return None;
+        }
+        let mut missing: Vec<&str> = self.missing_buffs.clone();
+        if self.missing_stim {
+            missing.push("stim");
+        }
+        Some(format!("Missing {}", missing.join(", ")))
+    }
+}
+
+/// Tracks raid buffs and stims active on the local player
+#[derive(Debug)]
+pub struct BuffCoverageTracker {
+    local_player_id: Option<i64>,
+    /// Active state per entry of [`RAID_BUFFS`]
+    active_buffs: Vec<bool>,
+    /// Effect ID of the active stim
+    active_stim: Option<i64>,
+}
+
+impl Default for BuffCoverageTracker {
+    fn default() -> Self {
+        Self {
+            local_player_id: None,
+            active_buffs: vec![false; RAID_BUFFS.len()],
+            active_stim: None,
+        }
+    }
+}
+
+impl BuffCoverageTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Buffs missing right now
+    pub fn report(&self) -> BuffCoverageReport {
+        BuffCoverageReport {
+            missing_buffs: RAID_BUFFS
+                .iter()
+                .zip(&self.active_buffs)
+                .filter(|(_, active)| !**active)
+                .map(|(buff, _)| buff.label)
+                .collect(),
+            missing_stim: self.active_stim.is_none(),
+        }
+    }
+
+    fn is_local_player(&self, entity_id: i64, entity_type: EntityType) -> bool {
+        entity_type == EntityType::Player && self.local_player_id == Some(entity_id)
+    }
+
+    fn set_effect(&mut self, effect_id: i64, effect_name: &str, active: bool) {
+        if let Some(idx) = raid_buff_index(effect_id, effect_name) {
+            self.active_buffs[idx] = active;
+        } else if is_stim(effect_name) {
+            if active {
+                self.active_stim = Some(effect_id);
+            } else if self.active_stim == Some(effect_id) {
+                self.active_stim = None;
+            }
+        }
+    }
+}
+
+impl SignalHandler for BuffCoverageTracker {
+    fn handle_signal(&mut self, signal: &GameSignal, _encounter: Option<&CombatEncounter>) {
+        match signal {
+            // First DisciplineChanged is always the local player
+            GameSignal::DisciplineChanged { entity_id, .. } if self.local_player_id.is_none() => {
+                self.local_player_id = Some(*entity_id);
+            }
+            GameSignal::EffectApplied {
+                effect_id,
+                effect_name,
+                target_id,
+                target_entity_type,
+                ..
+            } if self.is_local_player(*target_id, *target_entity_type) => {
+                self.set_effect(*effect_id, resolve(*effect_name), true);
+            }
+            GameSignal::EffectRemoved {
+                effect_id,
+                effect_name,
+                target_id,
+                target_entity_type,
+                ..
+            } if self.is_local_player(*target_id, *target_entity_type) => {
+                self.set_effect(*effect_id, resolve(*effect_name), false);
+            }
+            // Dying clears every buff
+            GameSignal::EntityDeath {
+                entity_id,
+                entity_type,
+                ..
+            } if self.is_local_player(*entity_id, *entity_type) => {
+                self.active_buffs.fill(false);
+                self.active_stim = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+    use crate::context::intern;
+
+    const LOCAL: i64 = 1;
+
+    fn tracker() -> BuffCoverageTracker {
+        let mut tracker = BuffCoverageTracker::new();
+        tracker.handle_signal(
+            &GameSignal::DisciplineChanged {
+                entity_id: LOCAL,
+                class_id: 0,
+                discipline_id: 0,
+                timestamp: NaiveDateTime::default(),
+            },
+            None,
+        );
+        tracker
+    }
+
+    fn applied(effect_id: i64, name: &str, target_id: i64) -> GameSignal {
+        GameSignal::EffectApplied {
+            effect_id,
+            effect_name: intern(name),
+            action_id: 0,
+            action_name: intern(""),
+            source_id: 2,
+            source_name: intern("Ally"),
+            source_entity_type: EntityType::Player,
+            source_npc_id: 0,
+            target_id,
+            target_name: intern("Me"),
+            target_entity_type: EntityType::Player,
+            target_npc_id: 0,
+            timestamp: NaiveDateTime::default(),
+            charges: None,
+        }
+    }
+
+    #[test]
+    fn test_reports_missing_buffs_and_stim() {
+        let mut tracker = tracker();
+        tracker.handle_signal(&applied(10, "Coordination", LOCAL), None);
+        tracker.handle_signal(&applied(11, "Force Valor", LOCAL), None);
+        // Buffs on other players don't count
+        tracker.handle_signal(&applied(12, "Fortification", 2), None);
+
+        let report = tracker.report();
+        assert_eq!(
+            report.missing_buffs,
+            vec!["Unnatural Might", "Hunter's Boon"]
+        );
+        assert!(report.missing_stim);
+        assert_eq!(
+            report.summary().as_deref(),
+            Some("Missing Unnatural Might, Hunter's Boon, stim")
+        );
+    }
+
+    #[test]
+    fn test_full_coverage_has_no_summary() {
+        let mut tracker = tracker();
+        for name in [
+            "Mark of Power",
+            "Force Might",
+            "Lucky Shots",
+            "Hunter's Boon",
+        ] {
+            tracker.handle_signal(&applied(10, name, LOCAL), None);
+        }
+        tracker.handle_signal(&applied(20, "Advanced Kyrprax Versatile Stim", LOCAL), None);
+
+        assert!(tracker.report().is_complete());
+        assert_eq!(tracker.report().summary(), None);
+    }
+}
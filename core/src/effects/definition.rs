@@ -29,6 +29,21 @@ pub enum AlertTrigger {
     OnExpire,
 }
 
+/// How urgently an effect needs attention on raid frames.
+///
+/// Raid frames list more severe effects first; critical ones are drawn
+/// enlarged with a flashing border.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectSeverity {
+    #[default]
+    Low,
+    Medium,
+    Critical,
+}
+
 /// Default RGBA color for effects without explicit color
 const DEFAULT_EFFECT_COLOR: [u8; 4] = [128, 128, 128, 255];
 
@@ -79,6 +94,10 @@ pub struct EffectDefinition {
     #[serde(default = "crate::serde_defaults::default_true")]
     pub enabled: bool,
 
+    /// ID of an [`EffectTemplate`] to inherit display/duration/behavior settings from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+
     // ─── Trigger ────────────────────────────────────────────────────────────
     /// What starts tracking this effect.
     /// Use EffectApplied/EffectRemoved for buff/debuff tracking,
@@ -130,6 +149,10 @@ pub struct EffectDefinition {
     #[serde(default)]
     pub display_target: DisplayTarget,
 
+    /// Raid frame priority (low/medium/critical)
+    #[serde(default)]
+    pub severity: EffectSeverity,
+
     /// Icon ability ID for display (falls back to effect_id or trigger ability if not set)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub icon_ability_id: Option<u64>,
@@ -252,6 +275,78 @@ impl EffectDefinition {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Effect Templates
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Named set of shared settings that effect definitions can `extends`.
+///
+/// Only the settings that tend to repeat across effects are templatable.
+/// A template value is used wherever the extending effect leaves that setting
+/// unset or at its default, so effects can still override anything they set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EffectTemplate {
+    /// Name effects refer to with `extends`
+    pub id: String,
+
+    pub color: Option<[u8; 4]>,
+    pub display_target: Option<DisplayTarget>,
+    pub severity: Option<EffectSeverity>,
+    pub duration_secs: Option<f32>,
+    pub is_affected_by_alacrity: Option<bool>,
+    pub cooldown_ready_secs: Option<f32>,
+    pub show_at_secs: Option<f32>,
+    pub show_icon: Option<bool>,
+    pub display_source: Option<bool>,
+    pub ignore_effect_removed: Option<bool>,
+    pub persist_past_death: Option<bool>,
+    pub track_outside_combat: Option<bool>,
+    pub alert_on: Option<AlertTrigger>,
+}
+
+impl EffectTemplate {
+    /// Fill the settings `def` leaves unset or at their defaults
+    pub fn apply_to(&self, def: &mut EffectDefinition) {
+        fn inherit<T: PartialEq + Copy>(field: &mut T, default: T, template: Option<T>) {
+            if *field == default
+                && let Some(value) = template
+            {
+                *field = value;
+            }
+        }
+
+        def.color = def.color.or(self.color);
+        def.duration_secs = def.duration_secs.or(self.duration_secs);
+        inherit(
+            &mut def.display_target,
+            DisplayTarget::None,
+            self.display_target,
+        );
+        inherit(
+            &mut def.is_affected_by_alacrity,
+            false,
+            self.is_affected_by_alacrity,
+        );
+        inherit(&mut def.severity, EffectSeverity::Low, self.severity);
+        inherit(&mut def.cooldown_ready_secs, 0.0, self.cooldown_ready_secs);
+        inherit(&mut def.show_at_secs, 0.0, self.show_at_secs);
+        inherit(&mut def.show_icon, true, self.show_icon);
+        inherit(&mut def.display_source, false, self.display_source);
+        inherit(
+            &mut def.ignore_effect_removed,
+            false,
+            self.ignore_effect_removed,
+        );
+        inherit(&mut def.persist_past_death, false, self.persist_past_death);
+        inherit(
+            &mut def.track_outside_combat,
+            true,
+            self.track_outside_combat,
+        );
+        inherit(&mut def.alert_on, AlertTrigger::None, self.alert_on);
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Config File Structure
 // ═══════════════════════════════════════════════════════════════════════════
@@ -269,6 +364,10 @@ pub struct DefinitionConfig {
     #[serde(default)]
     pub version: u32,
 
+    /// Templates effects can `extends` (usable from any file)
+    #[serde(default, rename = "template", skip_serializing_if = "Vec::is_empty")]
+    pub templates: Vec<EffectTemplate>,
+
     /// Effect definitions in this file
     #[serde(default, rename = "effect")]
     pub effects: Vec<EffectDefinition>,
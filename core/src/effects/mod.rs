@@ -4,6 +4,8 @@
 //! - **Definitions**: Templates that describe what effects to track (loaded from TOML)
 //! - **Active instances**: Runtime state of currently active effects
 //! - **Tracker**: Signal handler that manages effect lifecycle
+//! - **Tank swap**: Stacks of the active boss's tank-swap debuff
+//! - **Buff coverage**: Raid buffs and stim missing on the local player
 //!
 //! # Architecture
 //!
@@ -26,15 +28,21 @@
 //! ```
 
 mod active;
+pub mod buff_coverage;
 mod definition;
+pub mod raid_cooldowns;
+pub mod tank_swap;
 pub mod tracker;
 
 #[cfg(test)]
 mod tracker_tests;
 
 pub use active::{ActiveEffect, EffectKey};
+pub use buff_coverage::{BuffCoverageReport, BuffCoverageTracker};
 pub use definition::{
     AbilitySelector, AlertTrigger, DefinitionConfig, DisplayTarget, EFFECTS_DSL_VERSION,
-    EffectDefinition, EffectSelector, EntityFilter,
+    EffectDefinition, EffectSelector, EffectSeverity, EffectTemplate, EntityFilter,
 };
+pub use raid_cooldowns::{CooldownUse, RaidCooldownTracker};
+pub use tank_swap::{TankStacks, TankSwapTracker};
 pub use tracker::{DefinitionSet, EffectTracker, NewTargetInfo};
@@ -0,0 +1,181 @@
+//! Raid cooldown tracking
+//!
+//! Records when each player last cast one of the
+//! [`TRACKED_COOLDOWNS`](crate::game_data::TRACKED_COOLDOWNS), so the raid
+//! cooldown overlay can show who has a defensive or raid utility ready. A
+//! player's cooldown shows up once they have used it; the log doesn't say
+//! which abilities a player has until then.
+
+use std::time::Instant;
+
+use chrono::NaiveDateTime;
+
+use crate::combat_log::EntityType;
+use crate::context::{IStr, resolve};
+use crate::encounter::CombatEncounter;
+use crate::game_data::{TRACKED_COOLDOWNS, TrackedCooldown, tracked_cooldown_index};
+use crate::signal_processor::{GameSignal, SignalHandler};
+
+/// Last cast of one tracked cooldown by one player
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooldownUse {
+    pub entity_id: i64,
+    pub name: IStr,
+    /// Index into [`TRACKED_COOLDOWNS`]
+    pub cooldown_idx: usize,
+    pub cast_at: NaiveDateTime,
+}
+
+impl CooldownUse {
+    pub fn cooldown(&self) -> &'static TrackedCooldown {
+        &TRACKED_COOLDOWNS[self.cooldown_idx]
+    }
+
+    /// Seconds until the cooldown is ready again at game time `now` (0 when ready)
+    pub fn remaining_secs(&self, now: NaiveDateTime) -> f32 {
+        let elapsed = (now - self.cast_at).num_milliseconds() as f32 / 1000.0;
+        (self.cooldown().cooldown_secs - elapsed).max(0.0)
+    }
+}
+
+/// Tracks the last use of each player's raid cooldowns for the current area
+#[derive(Debug, Default)]
+pub struct RaidCooldownTracker {
+    /// One entry per player and cooldown, in the order first used
+    uses: Vec<CooldownUse>,
+    /// Newest log timestamp seen, and when it was seen
+    last_seen: Option<(NaiveDateTime, Instant)>,
+}
+
+impl RaidCooldownTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Last use of each player's cooldowns, in the order first used
+    pub fn uses(&self) -> &[CooldownUse] {
+        &self.uses
+    }
+
+    /// Current game time: the newest log timestamp, advanced by the real time
+    /// since it was read so countdowns keep moving between log lines
+    pub fn now(&self) -> Option<NaiveDateTime> {
+        self.last_seen.map(|(timestamp, seen_at)| {
+            timestamp + chrono::Duration::from_std(seen_at.elapsed()).unwrap_or_default()
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.uses.clear();
+    }
+
+    fn record(&mut self, entity_id: i64, name: IStr, cooldown_idx: usize, cast_at: NaiveDateTime) {
+        if let Some(existing) = self
+            .uses
+            .iter_mut()
+            .find(|u| u.entity_id == entity_id && u.cooldown_idx == cooldown_idx)
+        {
+            existing.cast_at = cast_at;
+            return;
+        }
+        self.uses.push(CooldownUse {
+            entity_id,
+            name,
+            cooldown_idx,
+            cast_at,
+        });
+    }
+}
+
+impl SignalHandler for RaidCooldownTracker {
+    fn handle_signal(&mut self, signal: &GameSignal, _encounter: Option<&CombatEncounter>) {
+        let timestamp = signal.timestamp();
+        if self.last_seen.is_none_or(|(last, _)| timestamp >= last) {
+            self.last_seen = Some((timestamp, Instant::now()));
+        }
+
+        match signal {
+            // A new area usually means a new group
+            GameSignal::AreaEntered { .. } => self.clear(),
+            GameSignal::AbilityActivated {
+                ability_id,
+                ability_name,
+                source_id,
+                source_entity_type,
+                source_name,
+                timestamp,
+                ..
+            } if *source_entity_type == EntityType::Player => {
+                if let Some(idx) = tracked_cooldown_index(*ability_id, resolve(*ability_name)) {
+                    self.record(*source_id, *source_name, idx, *timestamp);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::context::intern;
+
+    fn at(secs: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(20, 0, 0)
+            .unwrap()
+            + chrono::Duration::seconds(secs as i64)
+    }
+
+    fn cast(
+        source_id: i64,
+        ability_name: &str,
+        source_entity_type: EntityType,
+        secs: u32,
+    ) -> GameSignal {
+        GameSignal::AbilityActivated {
+            ability_id: 1,
+            ability_name: intern(ability_name),
+            source_id,
+            source_entity_type,
+            source_name: intern("Player"),
+            source_npc_id: 0,
+            target_id: source_id,
+            target_entity_type: source_entity_type,
+            target_name: intern("Player"),
+            target_npc_id: 0,
+            timestamp: at(secs),
+        }
+    }
+
+    #[test]
+    fn test_casts_start_cooldowns_per_player() {
+        let mut tracker = RaidCooldownTracker::new();
+        tracker.handle_signal(&cast(10, "Saber Ward", EntityType::Player, 0), None);
+        tracker.handle_signal(&cast(20, "Force Shroud", EntityType::Player, 30), None);
+        // Untracked abilities and NPC casts are ignored
+        tracker.handle_signal(&cast(10, "Force Leap", EntityType::Player, 30), None);
+        tracker.handle_signal(&cast(99, "Saber Ward", EntityType::Npc, 30), None);
+
+        let uses = tracker.uses();
+        assert_eq!(uses.len(), 2);
+        assert_eq!(uses[0].cooldown().label, "Saber Ward");
+        assert_eq!(uses[0].remaining_secs(at(30)), 60.0);
+        // Imperial mirror shares the Republic entry
+        assert_eq!(uses[1].cooldown().label, "Resilience");
+        assert_eq!(uses[1].remaining_secs(at(120)), 0.0);
+    }
+
+    #[test]
+    fn test_recast_restarts_cooldown() {
+        let mut tracker = RaidCooldownTracker::new();
+        tracker.handle_signal(&cast(10, "Saber Ward", EntityType::Player, 0), None);
+        tracker.handle_signal(&cast(10, "Saber Ward", EntityType::Player, 100), None);
+
+        assert_eq!(tracker.uses().len(), 1);
+        assert_eq!(tracker.uses()[0].remaining_secs(at(100)), 90.0);
+    }
+}
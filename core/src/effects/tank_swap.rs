@@ -0,0 +1,249 @@
+//! Tank-swap debuff tracking
+//!
+//! Follows the stacks of the active boss's tank-swap debuff (see
+//! [`TankSwapDefinition`](crate::dsl::TankSwapDefinition)) on the players it
+//! lands on, so the tank-swap overlay can show both tanks and call the swap.
+
+use chrono::NaiveDateTime;
+
+use crate::combat_log::EntityType;
+use crate::context::{IStr, resolve};
+use crate::dsl::TankSwapDefinition;
+use crate::encounter::CombatEncounter;
+use crate::signal_processor::{GameSignal, SignalHandler};
+
+/// Tanks shown on the overlay (the two most recently debuffed players)
+const MAX_TANKS: usize = 2;
+
+/// Debuff stacks on one tank
+#[derive(Debug, Clone, PartialEq)]
+pub struct TankStacks {
+    pub entity_id: i64,
+    pub name: IStr,
+    /// Current stacks (0 once the debuff fell off)
+    pub stacks: u8,
+    /// When the debuff was last applied to this tank
+    pub applied_at: NaiveDateTime,
+}
+
+/// Tracks tank-swap debuff stacks for the current boss fight
+#[derive(Debug, Default)]
+pub struct TankSwapTracker {
+    tanks: Vec<TankStacks>,
+    swap_at_stacks: u8,
+}
+
+impl TankSwapTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tanks in the order they first took the debuff
+    pub fn tanks(&self) -> &[TankStacks] {
+        &self.tanks
+    }
+
+    /// Stack count calling for a swap (0 if the boss has no tank-swap debuff)
+    pub fn swap_at_stacks(&self) -> u8 {
+        self.swap_at_stacks
+    }
+
+    /// Whether a tank has reached the swap threshold
+    pub fn swap_due(&self) -> bool {
+        self.swap_at_stacks > 0 && self.tanks.iter().any(|t| t.stacks >= self.swap_at_stacks)
+    }
+
+    pub fn clear(&mut self) {
+        self.tanks.clear();
+        self.swap_at_stacks = 0;
+    }
+
+    fn set_stacks(&mut self, entity_id: i64, name: IStr, stacks: u8, timestamp: NaiveDateTime) {
+        if let Some(tank) = self.tanks.iter_mut().find(|t| t.entity_id == entity_id) {
+            tank.stacks = stacks;
+            tank.applied_at = timestamp;
+            return;
+        }
+        // A third player took the debuff: drop the tank debuffed longest ago
+        if self.tanks.len() >= MAX_TANKS
+            && let Some(oldest) = self
+                .tanks
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, t)| t.applied_at)
+                .map(|(i, _)| i)
+        {
+            self.tanks.remove(oldest);
+        }
+        self.tanks.push(TankStacks {
+            entity_id,
+            name,
+            stacks,
+            applied_at: timestamp,
+        });
+    }
+
+    fn update_stacks(&mut self, entity_id: i64, stacks: u8) {
+        if let Some(tank) = self.tanks.iter_mut().find(|t| t.entity_id == entity_id) {
+            tank.stacks = stacks;
+        }
+    }
+}
+
+/// Tank-swap definition of the encounter's active boss
+fn tank_swap_definition(encounter: Option<&CombatEncounter>) -> Option<&TankSwapDefinition> {
+    encounter?.active_boss_definition()?.tank_swap.as_ref()
+}
+
+impl SignalHandler for TankSwapTracker {
+    fn handle_signal(&mut self, signal: &GameSignal, encounter: Option<&CombatEncounter>) {
+        if matches!(
+            signal,
+            GameSignal::CombatStarted { .. }
+                | GameSignal::CombatEnded { .. }
+                | GameSignal::AreaEntered { .. }
+        ) {
+            self.clear();
+            return;
+        }
+
+        let Some(def) = tank_swap_definition(encounter) else {
+            return;
+        };
+        self.swap_at_stacks = def.swap_at_stacks;
+
+        match signal {
+            GameSignal::EffectApplied {
+                effect_id,
+                effect_name,
+                target_id,
+                target_name,
+                target_entity_type,
+                timestamp,
+                charges,
+                ..
+            } if *target_entity_type == EntityType::Player
+                && def.matches_effect(*effect_id as u64, Some(resolve(*effect_name))) =>
+            {
+                self.set_stacks(*target_id, *target_name, charges.unwrap_or(1), *timestamp);
+            }
+            GameSignal::EffectChargesChanged {
+                effect_id,
+                effect_name,
+                target_id,
+                charges,
+                ..
+            } if def.matches_effect(*effect_id as u64, Some(resolve(*effect_name))) => {
+                self.update_stacks(*target_id, *charges);
+            }
+            GameSignal::EffectRemoved {
+                effect_id,
+                effect_name,
+                target_id,
+                ..
+            } if def.matches_effect(*effect_id as u64, Some(resolve(*effect_name))) => {
+                self.update_stacks(*target_id, 0);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::context::intern;
+    use crate::dsl::BossEncounterDefinition;
+    use crate::encounter::ProcessingMode;
+
+    const DEBUFF_ID: i64 = 100;
+
+    fn encounter() -> CombatEncounter {
+        let def: BossEncounterDefinition = toml::from_str(&format!(
+            "id = \"brontes\"\nname = \"Brontes\"\n\
+             [tank_swap]\neffects = [{DEBUFF_ID}]\nswap_at_stacks = 3\n"
+        ))
+        .unwrap();
+        let mut enc = CombatEncounter::new(1, ProcessingMode::Live);
+        enc.load_boss_definitions(Arc::new(vec![def]));
+        enc.set_active_boss_idx(Some(0));
+        enc
+    }
+
+    fn at(secs: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(20, 0, secs)
+            .unwrap()
+    }
+
+    fn applied(target_id: i64, secs: u32) -> GameSignal {
+        GameSignal::EffectApplied {
+            effect_id: DEBUFF_ID,
+            effect_name: intern("Debuff"),
+            action_id: 0,
+            action_name: intern(""),
+            source_id: 1,
+            source_name: intern("Brontes"),
+            source_entity_type: EntityType::Npc,
+            source_npc_id: 1,
+            target_id,
+            target_name: intern("Tank"),
+            target_entity_type: EntityType::Player,
+            target_npc_id: 0,
+            timestamp: at(secs),
+            charges: Some(1),
+        }
+    }
+
+    fn charges(target_id: i64, charges: u8) -> GameSignal {
+        GameSignal::EffectChargesChanged {
+            effect_id: DEBUFF_ID,
+            effect_name: intern("Debuff"),
+            action_id: 0,
+            action_name: intern(""),
+            target_id,
+            timestamp: at(0),
+            charges,
+        }
+    }
+
+    #[test]
+    fn test_stacks_reach_swap_threshold() {
+        let enc = encounter();
+        let mut tracker = TankSwapTracker::new();
+
+        tracker.handle_signal(&applied(10, 0), Some(&enc));
+        tracker.handle_signal(&charges(10, 2), Some(&enc));
+        assert!(!tracker.swap_due());
+
+        tracker.handle_signal(&charges(10, 3), Some(&enc));
+        assert!(tracker.swap_due());
+        assert_eq!(tracker.tanks()[0].stacks, 3);
+        assert_eq!(tracker.swap_at_stacks(), 3);
+    }
+
+    #[test]
+    fn test_keeps_two_most_recent_tanks() {
+        let enc = encounter();
+        let mut tracker = TankSwapTracker::new();
+
+        tracker.handle_signal(&applied(10, 0), Some(&enc));
+        tracker.handle_signal(&applied(20, 5), Some(&enc));
+        tracker.handle_signal(&applied(30, 10), Some(&enc));
+
+        let ids: Vec<i64> = tracker.tanks().iter().map(|t| t.entity_id).collect();
+        assert_eq!(ids, vec![20, 30]);
+    }
+
+    #[test]
+    fn test_ignored_without_tank_swap_definition() {
+        let mut tracker = TankSwapTracker::new();
+        tracker.handle_signal(&applied(10, 0), None);
+        assert!(tracker.tanks().is_empty());
+    }
+}
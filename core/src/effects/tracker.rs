@@ -16,9 +16,12 @@ use crate::dsl::{EntityFilter, EntityFilterMatching};
 use crate::encounter::CombatEncounter;
 use crate::signal_processor::{GameSignal, SignalHandler};
 
-use crate::timers::FiredAlert;
+use crate::timers::{AlertPriority, FiredAlert};
 
-use super::{ActiveEffect, AlertTrigger, DisplayTarget, EffectDefinition, EffectKey};
+use super::{
+    ActiveEffect, AlertTrigger, DisplayTarget, EffectDefinition, EffectKey, EffectSeverity,
+    EffectTemplate,
+};
 
 /// Get the entity roster from the current encounter, or empty slice if none.
 fn get_entities(encounter: Option<&CombatEncounter>) -> &[EntityDefinition] {
@@ -53,6 +56,9 @@ fn get_boss_ids(encounter: Option<&CombatEncounter>) -> HashSet<i64> {
 pub struct DefinitionSet {
     /// All effect definitions, keyed by ID
     pub effects: HashMap<String, EffectDefinition>,
+
+    /// Templates definitions can `extends`, keyed by ID
+    pub templates: HashMap<String, EffectTemplate>,
 }
 
 impl DefinitionSet {
@@ -81,6 +87,34 @@ impl DefinitionSet {
         duplicates
     }
 
+    /// Add templates. If `overwrite` is true, replaces existing templates with same ID.
+    pub fn add_templates(&mut self, templates: Vec<EffectTemplate>, overwrite: bool) {
+        for template in templates {
+            if overwrite || !self.templates.contains_key(&template.id) {
+                self.templates.insert(template.id.clone(), template);
+            }
+        }
+    }
+
+    /// Apply templates to every definition that `extends` one.
+    ///
+    /// Call once after all files are loaded so definitions can extend templates
+    /// from any file. Returns IDs of definitions whose template doesn't exist
+    /// (left as written).
+    pub fn resolve_templates(&mut self) -> Vec<String> {
+        let mut unresolved = Vec::new();
+        for def in self.effects.values_mut() {
+            let Some(base) = def.extends.as_deref() else {
+                continue;
+            };
+            match self.templates.get(base) {
+                Some(template) => template.apply_to(def),
+                None => unresolved.push(def.id.clone()),
+            }
+        }
+        unresolved
+    }
+
     /// Get an effect definition by ID
     pub fn get(&self, id: &str) -> Option<&EffectDefinition> {
         self.effects.get(id)
@@ -432,6 +466,9 @@ impl EffectTracker {
                     timestamp: current_time,
                     audio_enabled: false,
                     audio_file: None,
+                    audio_volume: None,
+                    speak: false,
+                    priority: AlertPriority::Normal,
                 });
             }
         }
@@ -536,6 +573,9 @@ impl EffectTracker {
                         timestamp,
                         audio_enabled: false,
                         audio_file: None,
+                        audio_volume: None,
+                        speak: false,
+                        priority: AlertPriority::Normal,
                     });
                 }
             } else {
@@ -556,6 +596,7 @@ impl EffectTracker {
                     duration,
                     def.effective_color(),
                     def.display_target,
+                    def.severity,
                     icon_ability_id,
                     def.show_at_secs,
                     def.show_icon,
@@ -585,6 +626,9 @@ impl EffectTracker {
                         timestamp,
                         audio_enabled: false,
                         audio_file: None,
+                        audio_volume: None,
+                        speak: false,
+                        priority: AlertPriority::Normal,
                     });
                 }
             }
@@ -653,6 +697,7 @@ impl EffectTracker {
             duration: Option<Duration>,
             color: [u8; 4],
             display_target: DisplayTarget,
+            severity: EffectSeverity,
             icon_ability_id: u64,
             show_at_secs: f32,
             show_icon: bool,
@@ -683,6 +728,7 @@ impl EffectTracker {
                 duration: self.effective_duration(def),
                 color: def.effective_color(),
                 display_target: def.display_target,
+                severity: def.severity,
                 icon_ability_id: def.icon_ability_id.unwrap_or(action_id as u64),
                 show_at_secs: def.show_at_secs,
                 show_icon: def.show_icon,
@@ -729,6 +775,7 @@ impl EffectTracker {
                     def.duration,
                     def.color,
                     def.display_target,
+                    def.severity,
                     def.icon_ability_id,
                     def.show_at_secs,
                     def.show_icon,
@@ -990,6 +1037,7 @@ impl EffectTracker {
                     duration,
                     def.effective_color(),
                     def.display_target,
+                    def.severity,
                     icon_ability_id,
                     def.show_at_secs,
                     def.show_icon,
@@ -1096,6 +1144,7 @@ impl EffectTracker {
                     duration,
                     def.effective_color(),
                     def.display_target,
+                    def.severity,
                     icon_ability_id,
                     def.show_at_secs,
                     def.show_icon,
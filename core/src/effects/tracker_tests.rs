@@ -1,3 +1,67 @@
 //! Tests for effect tracker
 //!
 //! TODO: Add integration tests for EffectTracker signal handling
+
+use super::{DefinitionConfig, DefinitionSet, DisplayTarget, EffectSeverity};
+
+#[test]
+fn test_effects_inherit_template_settings() {
+    let config: DefinitionConfig = toml::from_str(
+        r#"
+        version = 1
+
+        [[template]]
+        id = "proc"
+        display_target = "effects_a"
+        color = [1, 2, 3, 255]
+        duration_secs = 15.0
+        track_outside_combat = false
+        severity = "medium"
+
+        [[effect]]
+        id = "inherits"
+        name = "Inherits"
+        extends = "proc"
+        trigger = { type = "effect_applied", effects = [1] }
+
+        [[effect]]
+        id = "overrides"
+        name = "Overrides"
+        extends = "proc"
+        trigger = { type = "effect_applied", effects = [2] }
+        display_target = "cooldowns"
+        duration_secs = 5.0
+        severity = "critical"
+
+        [[effect]]
+        id = "missing"
+        name = "Missing"
+        extends = "nope"
+        trigger = { type = "effect_applied", effects = [3] }
+        "#,
+    )
+    .expect("valid config");
+
+    let mut set = DefinitionSet::new();
+    set.add_templates(config.templates, false);
+    set.add_definitions(config.effects, false);
+    assert_eq!(set.resolve_templates(), vec!["missing".to_string()]);
+
+    let inherits = set.get("inherits").unwrap();
+    assert_eq!(inherits.display_target, DisplayTarget::EffectsA);
+    assert_eq!(inherits.color, Some([1, 2, 3, 255]));
+    assert_eq!(inherits.duration_secs, Some(15.0));
+    assert!(!inherits.track_outside_combat);
+    assert_eq!(inherits.severity, EffectSeverity::Medium);
+
+    let overrides = set.get("overrides").unwrap();
+    assert_eq!(overrides.display_target, DisplayTarget::Cooldowns);
+    assert_eq!(overrides.duration_secs, Some(5.0));
+    assert_eq!(overrides.color, Some([1, 2, 3, 255]));
+    assert_eq!(overrides.severity, EffectSeverity::Critical);
+
+    let missing = set.get("missing").unwrap();
+    assert_eq!(missing.display_target, DisplayTarget::None);
+    assert!(missing.track_outside_combat);
+    assert_eq!(missing.severity, EffectSeverity::Low);
+}
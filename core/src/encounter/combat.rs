@@ -16,17 +16,25 @@ use hashbrown::{HashMap, HashSet};
 
 use crate::combat_log::{CombatEvent, Entity, EntityType};
 use crate::context::IStr;
-use crate::dsl::{BossEncounterDefinition, CounterCondition, CounterDefinition};
+use crate::dsl::{
+    BossEncounterDefinition, CounterCondition, CounterDefinition, EntitySelectorExt, Trigger,
+};
 use crate::game_data::{Difficulty, Discipline, SHIELD_EFFECT_IDS, defense_type, effect_id};
 use crate::{effect_type_id, is_boss};
 
 use super::challenge::ChallengeTracker;
 use super::effect_instance::EffectInstance;
 use super::entity_info::{NpcInfo, PlayerInfo};
-use super::metrics::MetricAccumulator;
+use super::incoming::{HitEstimate, IncomingDamage};
+use super::metrics::{AbilityTotals, MetricAccumulator};
+use super::pace::{HpTimeline, PhaseEta};
+use super::recent::{RecentEvent, RecentEvents};
 use super::{EncounterState, OverlayHealthEntry};
 use crate::dsl::ChallengeContext;
 
+/// Recent window of boss HP samples used to estimate the boss damage rate
+const PHASE_ETA_RATE_WINDOW_SECS: f32 = 20.0;
+
 /// Processing mode for the encounter
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ProcessingMode {
@@ -88,6 +96,12 @@ pub struct CombatEncounter {
     pub combat_time_secs: f32,
     /// Previous combat time (for TimeElapsed threshold detection)
     pub prev_combat_time_secs: f32,
+    /// Combined boss HP over combat time (for kill pace comparison)
+    pub hp_timeline: HpTimeline,
+    /// Damage players took per NPC ability (for incoming hit estimates)
+    pub incoming_damage: IncomingDamage,
+    /// Damage and healing each player received recently (Live mode only, for death recaps)
+    pub recent_events: RecentEvents,
 
     // ─── Combat State (from Encounter) ──────────────────────────────────────
     /// Current encounter state
@@ -98,6 +112,8 @@ pub struct CombatEncounter {
     pub exit_combat_time: Option<NaiveDateTime>,
     /// Last combat activity timestamp
     pub last_combat_activity_time: Option<NaiveDateTime>,
+    /// Bytes `[start, end)` of the combat's lines in the raw log (for raw export)
+    pub log_range: Option<(u64, u64)>,
 
     // ─── Entity Tracking ────────────────────────────────────────────────────
     /// Players in this encounter
@@ -142,12 +158,16 @@ impl CombatEncounter {
             counters: HashMap::new(),
             combat_time_secs: 0.0,
             prev_combat_time_secs: 0.0,
+            hp_timeline: HpTimeline::new(),
+            incoming_damage: IncomingDamage::new(),
+            recent_events: RecentEvents::new(),
 
             // Combat state
             state: EncounterState::NotStarted,
             enter_combat_time: None,
             exit_combat_time: None,
             last_combat_activity_time: None,
+            log_range: None,
 
             // Entity tracking
             players: HashMap::new(),
@@ -171,6 +191,39 @@ impl CombatEncounter {
         enc
     }
 
+    /// Estimated heap usage of entity, effect and metric tracking (bytes)
+    pub fn estimated_heap_bytes(&self) -> usize {
+        use crate::memory::{table_bytes, vec_bytes};
+
+        let effects: usize = self
+            .effects
+            .values()
+            .map(|v| vec_bytes::<EffectInstance>(v.capacity()))
+            .sum();
+
+        table_bytes::<(i64, PlayerInfo)>(self.players.capacity())
+            + table_bytes::<(i64, NpcInfo)>(self.npcs.capacity())
+            + table_bytes::<(i64, Vec<EffectInstance>)>(self.effects.capacity())
+            + effects
+            + table_bytes::<(i64, MetricAccumulator)>(self.accumulated_data.capacity())
+            + table_bytes::<(String, u32)>(self.counters.capacity())
+    }
+
+    /// Release per-event buffers once the encounter has ended.
+    ///
+    /// Effect instances only feed live shield attribution and recent events only
+    /// feed live death recaps, so both are dropped;
+    /// entity, metric and counter maps are shrunk to their final size but kept
+    /// for post-combat metrics and summaries.
+    pub fn compact(&mut self) {
+        self.effects = HashMap::new();
+        self.recent_events.clear();
+        self.players.shrink_to_fit();
+        self.npcs.shrink_to_fit();
+        self.accumulated_data.shrink_to_fit();
+        self.counters.shrink_to_fit();
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // Boss Definitions
     // ═══════════════════════════════════════════════════════════════════════
@@ -256,6 +309,141 @@ impl CombatEncounter {
         self.npcs.get(&npc_id).map(|n| n.hp_percent())
     }
 
+    /// Combined `(current, max)` HP of the active boss's HP-bar entities seen so far
+    fn boss_hp_totals(&self) -> Option<(i64, i64)> {
+        let def = self.active_boss_definition()?;
+        let totals = self
+            .npcs
+            .values()
+            .filter(|npc| {
+                def.entities
+                    .iter()
+                    .any(|e| e.shows_on_hp_overlay() && e.ids.contains(&npc.class_id))
+            })
+            .fold((0i64, 0i64), |(current, max), npc| {
+                (current + npc.current_hp as i64, max + npc.max_hp as i64)
+            });
+        Some(totals)
+    }
+
+    /// Combined HP percentage of the active boss's HP-bar entities seen so far
+    pub fn boss_hp_percent(&self) -> Option<f32> {
+        let (current, max) = self.boss_hp_totals()?;
+        (max > 0).then(|| current as f32 * 100.0 / max as f32)
+    }
+
+    /// Estimate the time until the next `BossHpBelow` phase starts, assuming the
+    /// recent boss damage rate keeps going into the entity that triggers it
+    pub fn next_phase_eta(&self) -> Option<PhaseEta> {
+        let def = self.active_boss_definition()?;
+        let (_, combined_max) = self.boss_hp_totals()?;
+        let hp_per_sec = self
+            .hp_timeline
+            .hp_rate_per_sec(PHASE_ETA_RATE_WINDOW_SECS)
+            .filter(|rate| *rate > 0.0)?
+            * combined_max as f32
+            / 100.0;
+
+        let mut next: Option<PhaseEta> = None;
+        for phase in &def.phases {
+            if self.current_phase.as_ref() == Some(&phase.id) {
+                continue;
+            }
+            if let Some(ref required) = phase.preceded_by {
+                let last_phase = self.current_phase.as_ref().or(self.previous_phase.as_ref());
+                if last_phase != Some(required) {
+                    continue;
+                }
+            }
+            if let Some(ref cond) = phase.counter_condition
+                && !self.check_counter_condition(cond)
+            {
+                continue;
+            }
+            let Trigger::BossHpBelow {
+                hp_percent,
+                ref selector,
+            } = phase.start_trigger
+            else {
+                continue;
+            };
+
+            // HP left before the first matching entity crosses the threshold
+            let remaining_hp = self
+                .npcs
+                .values()
+                .filter(|npc| !npc.is_dead && npc.max_hp > 0)
+                .filter(|npc| {
+                    if selector.is_empty() {
+                        npc.is_boss
+                    } else {
+                        selector.matches_with_roster(
+                            &def.entities,
+                            npc.class_id,
+                            Some(crate::context::resolve(npc.name)),
+                        )
+                    }
+                })
+                .map(|npc| npc.current_hp as f32 - npc.max_hp as f32 * hp_percent / 100.0)
+                .filter(|remaining| *remaining > 0.0)
+                .min_by(|a, b| a.total_cmp(b));
+            let Some(remaining_hp) = remaining_hp else {
+                continue;
+            };
+
+            let secs = remaining_hp / hp_per_sec;
+            if next.as_ref().is_none_or(|eta| secs < eta.secs) {
+                next = Some(PhaseEta {
+                    phase_name: phase.name.clone(),
+                    secs,
+                });
+            }
+        }
+        next
+    }
+
+    /// Per-cast hit estimates of this pull for the active boss's timers, keyed by
+    /// timer ID. A timer maps to the hardest-hitting ability its trigger names.
+    pub fn timer_hit_estimates(&self) -> Vec<(String, HitEstimate)> {
+        let Some(def) = self.active_boss_definition() else {
+            return Vec::new();
+        };
+        if self.incoming_damage.is_empty() {
+            return Vec::new();
+        }
+
+        def.timers
+            .iter()
+            .filter_map(|timer| {
+                let trigger = &timer.trigger;
+                let estimate = self
+                    .incoming_damage
+                    .estimates()
+                    .filter(|&(id, name, _)| {
+                        let name = Some(crate::context::resolve(name));
+                        let id = id as u64;
+                        trigger.matches_ability(id, name)
+                            || trigger.matches_cast_started(id, name)
+                            || trigger.matches_cast_completed(id, name)
+                            || trigger.matches_damage_taken(id, name)
+                    })
+                    .map(|(_, _, estimate)| estimate)
+                    .max_by_key(|estimate| estimate.per_person)?;
+                Some((timer.id.clone(), estimate))
+            })
+            .collect()
+    }
+
+    /// Sample the combined boss HP into the HP timeline
+    pub fn record_boss_hp(&mut self, timestamp: NaiveDateTime) {
+        let (Some(start), Some(hp_percent)) = (self.enter_combat_time, self.boss_hp_percent())
+        else {
+            return;
+        };
+        let secs = (timestamp - start).num_milliseconds() as f32 / 1000.0;
+        self.hp_timeline.record(secs, hp_percent);
+    }
+
     /// Get boss health entries for overlay display
     pub fn get_boss_health(&self) -> Vec<OverlayHealthEntry> {
         let Some(def) = self.active_boss_definition() else {
@@ -273,15 +461,19 @@ impl CombatEncounter {
             .npcs
             .values()
             .filter(|npc| entity_class_ids.contains(&npc.class_id))
-            .map(|npc| OverlayHealthEntry {
-                name: crate::context::resolve(npc.name).to_string(),
-                target_name: self
-                    .players
-                    .get(&npc.current_target_id)
-                    .map(|p| crate::context::resolve(p.name).to_string()),
-                current: npc.current_hp,
-                max: npc.max_hp,
-                first_seen_at: npc.first_seen_at,
+            .map(|npc| {
+                let name = crate::context::resolve(npc.name);
+                OverlayHealthEntry {
+                    name: name.to_string(),
+                    target_name: self
+                        .players
+                        .get(&npc.current_target_id)
+                        .map(|p| crate::context::resolve(p.name).to_string()),
+                    current: npc.current_hp,
+                    max: npc.max_hp,
+                    phase_markers: def.phase_hp_thresholds(npc.class_id, Some(name)),
+                    first_seen_at: npc.first_seen_at,
+                }
             })
             .collect();
 
@@ -410,6 +602,16 @@ impl CombatEncounter {
         self.counters.clear();
     }
 
+    /// Group size for this encounter.
+    ///
+    /// Taken from the difficulty when known (8 or 16 for operations), otherwise
+    /// from the number of players seen in the encounter.
+    pub fn group_size(&self) -> Option<u8> {
+        self.difficulty.map(|d| d.group_size()).or_else(|| {
+            (!self.players.is_empty()).then(|| self.players.len().min(u8::MAX as usize) as u8)
+        })
+    }
+
     /// Check a counter condition
     pub fn check_counter_condition(&self, cond: &CounterCondition) -> bool {
         let value = self.get_counter(&cond.counter_id);
@@ -431,6 +633,14 @@ impl CombatEncounter {
         (old_time, self.combat_time_secs)
     }
 
+    /// Stable key naming this encounter's parquet file and summary.
+    /// None if combat never started.
+    pub fn encounter_key(&self) -> Option<String> {
+        let start = self.enter_combat_time?;
+        let boss_id = self.active_boss_definition().map(|def| def.id.as_str());
+        Some(crate::storage::encounter_key(start, boss_id, self.area_id))
+    }
+
     /// Get combat duration in seconds (truncated)
     pub fn duration_seconds(&self) -> Option<i64> {
         Some(self.duration_ms()? / 1000)
@@ -438,21 +648,19 @@ impl CombatEncounter {
 
     /// Get combat duration in milliseconds
     pub fn duration_ms(&self) -> Option<i64> {
-        use chrono::TimeDelta;
-
         let enter = self.enter_combat_time?;
         let terminal = self
             .exit_combat_time
             .unwrap_or_else(|| chrono::offset::Local::now().naive_local());
 
-        let mut duration = terminal.signed_duration_since(enter);
-
-        // Handle midnight crossing
-        if duration.num_milliseconds().is_negative() {
-            duration = duration.checked_add(&TimeDelta::days(1))?;
-        }
-
-        Some(duration.num_milliseconds())
+        // Event timestamps are dated by the session clock, so a negative
+        // duration only means the wall clock is behind the log (end of DST)
+        Some(
+            terminal
+                .signed_duration_since(enter)
+                .num_milliseconds()
+                .max(0),
+        )
     }
 
     /// Build a ChallengeContext snapshot
@@ -654,6 +862,32 @@ impl CombatEncounter {
         }
         None
     }
+
+    /// NPC whose threat table a target-scoped TPS meter shows: the local
+    /// player's current target if it is an NPC, otherwise the first boss
+    /// still alive.
+    pub fn threat_target(&self, local_player_id: i64) -> Option<i64> {
+        if let Some(target) = self.get_current_target(local_player_id)
+            && self.npcs.contains_key(&target)
+        {
+            return Some(target);
+        }
+        self.npcs
+            .values()
+            .filter(|npc| !npc.is_dead && npc.is_boss)
+            .min_by_key(|npc| npc.first_seen_at)
+            .map(|npc| npc.log_id)
+    }
+
+    /// Threat `entity_id` generated by actions on `target_id`
+    pub fn threat_on(&self, entity_id: i64, target_id: i64) -> f64 {
+        self.accumulated_data
+            .get(&entity_id)
+            .and_then(|acc| acc.threat_by_target.get(&target_id))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // Effect Instances
     // ═══════════════════════════════════════════════════════════════════════
@@ -690,13 +924,55 @@ impl CombatEncounter {
         }
     }
 
+    /// Whether a RemoveEffect event is a cleanse/dispel: a player or companion
+    /// removing an effect that an NPC applied to a player or companion.
+    /// Natural expiry is logged with the applier as source, so it never matches.
+    pub fn is_dispel(&self, event: &CombatEvent) -> bool {
+        let is_friendly = |entity_type: EntityType| {
+            matches!(entity_type, EntityType::Player | EntityType::Companion)
+        };
+        if event.effect.type_id != effect_type_id::REMOVEEFFECT
+            || !is_friendly(event.source_entity.entity_type)
+            || !is_friendly(event.target_entity.entity_type)
+        {
+            return false;
+        }
+
+        self.effects
+            .get(&event.target_entity.log_id)
+            .is_some_and(|effects| {
+                effects.iter().any(|e| {
+                    e.effect_id == event.effect.effect_id
+                        && e.removed_at.is_none()
+                        && self
+                            .npcs
+                            .get(&e.source_id)
+                            .is_some_and(|npc| npc.entity_type == EntityType::Npc)
+                })
+            })
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // Metrics Accumulation
     // ═══════════════════════════════════════════════════════════════════════
 
+    /// Extend the raw log range to include `event`'s line (ignored before combat starts)
+    pub fn track_log_range(&mut self, event: &CombatEvent) {
+        if self.enter_combat_time.is_none() {
+            return;
+        }
+        let range = self
+            .log_range
+            .get_or_insert((event.byte_offset, event.byte_end));
+        range.0 = range.0.min(event.byte_offset);
+        range.1 = range.1.max(event.byte_end);
+    }
+
     pub fn accumulate_data(&mut self, event: &CombatEvent) {
         use crate::is_boss;
 
+        self.track_log_range(event);
+
         let defense_type = event.details.defense_type_id;
         let is_defense = matches!(
             defense_type,
@@ -707,6 +983,7 @@ impl CombatEncounter {
         );
         let is_natural_shield = defense_type == defense_type::SHIELD
             && event.details.dmg_effective == event.details.dmg_amount;
+        let is_dispel = self.is_dispel(event);
 
         // Source accumulation
         {
@@ -724,9 +1001,17 @@ impl CombatEncounter {
                 if event.details.is_crit {
                     source.damage_crit_count += 1;
                 }
+                if event.is_reflect_damage() {
+                    source.damage_reflected += event.details.dmg_amount as i64;
+                }
                 if is_boss(event.target_entity.class_id) {
                     source.damge_dealt_boss += event.details.dmg_amount as i64;
                 }
+                source
+                    .abilities
+                    .entry(event.action.action_id)
+                    .or_insert_with(|| AbilityTotals::new(event.action.name))
+                    .damage += event.details.dmg_amount as i64;
             }
 
             if event.details.heal_amount > 0 {
@@ -736,9 +1021,20 @@ impl CombatEncounter {
                 if event.details.is_crit {
                     source.heal_crit_count += 1;
                 }
+                source
+                    .abilities
+                    .entry(event.action.action_id)
+                    .or_insert_with(|| AbilityTotals::new(event.action.name))
+                    .healing += event.details.heal_amount as i64;
             }
 
             source.threat_generated += event.details.threat as f64;
+            if event.details.threat != 0.0 && event.target_entity.entity_type == EntityType::Npc {
+                *source
+                    .threat_by_target
+                    .entry(event.target_entity.log_id)
+                    .or_default() += event.details.threat as f64;
+            }
 
             if event.effect.effect_id == effect_id::ABILITYACTIVATE
                 && self.enter_combat_time.is_some_and(|t| event.timestamp >= t)
@@ -751,6 +1047,10 @@ impl CombatEncounter {
                 source.taunt_count += 1;
             }
 
+            if is_dispel {
+                source.dispel_count += 1;
+            }
+
             if event.details.dmg_absorbed > 0 && !is_natural_shield {
                 self.attribute_shield_absorption(event);
             }
@@ -783,6 +1083,52 @@ impl CombatEncounter {
                 target.healing_received += event.details.heal_amount as i64;
                 target.healing_received_effective += event.details.heal_effective as i64;
             }
+
+            if event.effect.effect_id == effect_id::DEATH
+                && event.target_entity.entity_type == EntityType::Player
+            {
+                target.deaths += 1;
+            }
+        }
+
+        // What players were hit and healed by, for recapping a death
+        if self.mode == ProcessingMode::Live
+            && event.target_entity.entity_type == EntityType::Player
+            && (event.details.dmg_amount > 0 || event.details.heal_amount > 0)
+        {
+            let is_heal = event.details.heal_amount > 0;
+            self.recent_events.record(
+                event.target_entity.log_id,
+                RecentEvent {
+                    timestamp: event.timestamp,
+                    source_name: event.source_entity.name,
+                    ability_name: event.action.name,
+                    amount: if is_heal {
+                        event.details.heal_effective as i64
+                    } else {
+                        event.details.dmg_amount as i64
+                    },
+                    absorbed: event.details.dmg_absorbed as i64,
+                    is_heal,
+                    is_crit: event.details.is_crit,
+                },
+            );
+        }
+
+        // Damage players take from NPC abilities (for incoming hit estimates)
+        if event.details.dmg_amount > 0
+            && event.source_entity.entity_type == EntityType::Npc
+            && event.target_entity.entity_type == EntityType::Player
+            && let Some(start) = self.enter_combat_time
+        {
+            let secs = (event.timestamp - start).num_milliseconds() as f32 / 1000.0;
+            self.incoming_damage.record(
+                event.action.action_id,
+                event.action.name,
+                event.target_entity.log_id,
+                secs,
+                event.details.dmg_amount as i64,
+            );
         }
     }
 
@@ -790,103 +1136,131 @@ impl CombatEncounter {
         &self,
         player_disciplines: &hashbrown::HashMap<i64, super::entity_info::PlayerInfo>,
     ) -> Option<Vec<super::metrics::EntityMetrics>> {
+        let mut stats = Vec::new();
+        self.calculate_entity_metrics_into(player_disciplines, &mut stats)
+            .then_some(stats)
+    }
+
+    /// Like [`Self::calculate_entity_metrics`], but fills `stats` (cleared
+    /// first) so a caller polling every tick can reuse its allocation.
+    /// Returns false if the encounter has no duration yet.
+    pub fn calculate_entity_metrics_into(
+        &self,
+        player_disciplines: &hashbrown::HashMap<i64, super::entity_info::PlayerInfo>,
+        stats: &mut Vec<super::metrics::EntityMetrics>,
+    ) -> bool {
         use super::metrics::EntityMetrics;
 
-        let duration_ms = self.duration_ms()?;
+        stats.clear();
+        let Some(duration_ms) = self.duration_ms() else {
+            return false;
+        };
         if duration_ms <= 0 {
-            return None;
+            return false;
         }
 
-        let mut stats: Vec<EntityMetrics> = self
-            .accumulated_data
-            .iter()
-            .filter_map(|(id, acc)| {
-                let name = self.get_entity_name(*id)?;
-                let entity_type = self.get_entity_type(*id)?;
-
-                let damage_crit_pct = if acc.damage_hit_count > 0 {
-                    (acc.damage_crit_count as f32 / acc.damage_hit_count as f32) * 100.0
-                } else {
-                    0.0
-                };
-                let heal_crit_pct = if acc.heal_count > 0 {
-                    (acc.heal_crit_count as f32 / acc.heal_count as f32) * 100.0
-                } else {
-                    0.0
-                };
-                let effective_heal_pct = if acc.healing_done > 0 {
-                    (acc.healing_effective as f32 / acc.healing_done as f32) * 100.0
-                } else {
-                    0.0
-                };
-                let defense_pct = if acc.attacks_received > 0 {
-                    (acc.defense_count as f32 / acc.attacks_received as f32) * 100.0
-                } else {
-                    0.0
-                };
-                let shield_pct = if acc.attacks_received > 0 {
-                    (acc.shield_roll_count as f32 / acc.attacks_received as f32) * 100.0
-                } else {
-                    0.0
-                };
-
-                // Look up discipline info from session-level registry (source of truth)
-                let (discipline, discipline_name, class_name) =
-                    if let Some(player) = player_disciplines.get(id) {
-                        let disc = Discipline::from_guid(player.discipline_id);
-                        let disc_name = if player.discipline_name.is_empty() {
-                            None
-                        } else {
-                            Some(player.discipline_name.clone())
-                        };
-                        // Derive class_name from Discipline enum (English) for CSS matching
-                        let cls_name = disc.map(|d| format!("{:?}", d.class()));
-                        (disc, disc_name, cls_name)
+        stats.extend(self.accumulated_data.iter().filter_map(|(id, acc)| {
+            let name = self.get_entity_name(*id)?;
+            let entity_type = self.get_entity_type(*id)?;
+
+            let damage_crit_pct = if acc.damage_hit_count > 0 {
+                (acc.damage_crit_count as f32 / acc.damage_hit_count as f32) * 100.0
+            } else {
+                0.0
+            };
+            let heal_crit_pct = if acc.heal_count > 0 {
+                (acc.heal_crit_count as f32 / acc.heal_count as f32) * 100.0
+            } else {
+                0.0
+            };
+            let effective_heal_pct = if acc.healing_done > 0 {
+                (acc.healing_effective as f32 / acc.healing_done as f32) * 100.0
+            } else {
+                0.0
+            };
+            let defense_pct = if acc.attacks_received > 0 {
+                (acc.defense_count as f32 / acc.attacks_received as f32) * 100.0
+            } else {
+                0.0
+            };
+            let shield_pct = if acc.attacks_received > 0 {
+                (acc.shield_roll_count as f32 / acc.attacks_received as f32) * 100.0
+            } else {
+                0.0
+            };
+
+            // Look up discipline info from session-level registry (source of truth)
+            let (discipline, discipline_name, class_name) =
+                if let Some(player) = player_disciplines.get(id) {
+                    let disc = Discipline::from_guid(player.discipline_id);
+                    let disc_name = if player.discipline_name.is_empty() {
+                        None
                     } else {
-                        (None, None, None)
+                        Some(player.discipline_name.clone())
                     };
+                    // Derive class_name from Discipline enum (English) for CSS matching
+                    let cls_name = disc.map(|d| d.class().name());
+                    (disc, disc_name, cls_name)
+                } else {
+                    (None, None, None)
+                };
 
-                Some(EntityMetrics {
-                    entity_id: *id,
-                    entity_type,
-                    name,
-                    discipline,
-                    discipline_name,
-                    class_name,
-                    total_damage: acc.damage_dealt,
-                    total_damage_boss: acc.damge_dealt_boss,
-                    total_damage_effective: acc.damage_dealt_effective,
-                    dps: (acc.damage_dealt * 1000 / duration_ms) as i32,
-                    edps: (acc.damage_dealt_effective * 1000 / duration_ms) as i32,
-                    bossdps: (acc.damge_dealt_boss * 1000 / duration_ms) as i32,
-                    damage_crit_pct,
-                    total_healing: acc.healing_done + acc.shielding_given,
-                    total_healing_effective: acc.healing_effective + acc.shielding_given,
-                    hps: ((acc.healing_done + acc.shielding_given) * 1000 / duration_ms) as i32,
-                    ehps: ((acc.healing_effective + acc.shielding_given) * 1000 / duration_ms)
-                        as i32,
-                    heal_crit_pct,
-                    effective_heal_pct,
-                    abs: (acc.shielding_given * 1000 / duration_ms) as i32,
-                    total_shielding: acc.shielding_given,
-                    total_damage_taken: acc.damage_received,
-                    total_damage_taken_effective: acc.damage_received_effective,
-                    dtps: (acc.damage_received * 1000 / duration_ms) as i32,
-                    edtps: (acc.damage_received_effective * 1000 / duration_ms) as i32,
-                    htps: (acc.healing_received * 1000 / duration_ms) as i32,
-                    ehtps: (acc.healing_received_effective * 1000 / duration_ms) as i32,
-                    defense_pct,
-                    shield_pct,
-                    total_shield_absorbed: acc.shield_roll_absorbed,
-                    taunt_count: acc.taunt_count,
-                    apm: (acc.actions as f32 * 60000.0 / duration_ms as f32),
-                    tps: (acc.threat_generated * 1000.0 / duration_ms as f64) as i32,
-                    total_threat: acc.threat_generated as i64,
-                })
+            // Ability breakdowns are only shown for players
+            let is_player = entity_type == EntityType::Player;
+            let (top_damage_abilities, top_healing_abilities) = if is_player {
+                (
+                    acc.top_abilities(|totals| totals.damage),
+                    acc.top_abilities(|totals| totals.healing),
+                )
+            } else {
+                (Vec::new(), Vec::new())
+            };
+
+            Some(EntityMetrics {
+                entity_id: *id,
+                entity_type,
+                name,
+                discipline,
+                discipline_name,
+                class_name,
+                total_damage: acc.damage_dealt,
+                total_damage_boss: acc.damge_dealt_boss,
+                total_damage_effective: acc.damage_dealt_effective,
+                dps: (acc.damage_dealt * 1000 / duration_ms) as i32,
+                edps: (acc.damage_dealt_effective * 1000 / duration_ms) as i32,
+                bossdps: (acc.damge_dealt_boss * 1000 / duration_ms) as i32,
+                damage_crit_pct,
+                total_reflect_damage: acc.damage_reflected,
+                reflect_dps: (acc.damage_reflected * 1000 / duration_ms) as i32,
+                total_healing: acc.healing_done + acc.shielding_given,
+                total_healing_effective: acc.healing_effective + acc.shielding_given,
+                hps: ((acc.healing_done + acc.shielding_given) * 1000 / duration_ms) as i32,
+                ehps: ((acc.healing_effective + acc.shielding_given) * 1000 / duration_ms) as i32,
+                heal_crit_pct,
+                effective_heal_pct,
+                abs: (acc.shielding_given * 1000 / duration_ms) as i32,
+                total_shielding: acc.shielding_given,
+                total_damage_taken: acc.damage_received,
+                total_damage_taken_effective: acc.damage_received_effective,
+                dtps: (acc.damage_received * 1000 / duration_ms) as i32,
+                edtps: (acc.damage_received_effective * 1000 / duration_ms) as i32,
+                htps: (acc.healing_received * 1000 / duration_ms) as i32,
+                ehtps: (acc.healing_received_effective * 1000 / duration_ms) as i32,
+                defense_pct,
+                shield_pct,
+                total_shield_absorbed: acc.shield_roll_absorbed,
+                taunt_count: acc.taunt_count,
+                dispel_count: acc.dispel_count,
+                deaths: acc.deaths,
+                apm: (acc.actions as f32 * 60000.0 / duration_ms as f32),
+                tps: (acc.threat_generated * 1000.0 / duration_ms as f64) as i32,
+                total_threat: acc.threat_generated as i64,
+                top_damage_abilities,
+                top_healing_abilities,
             })
-            .collect();
+        }));
 
         stats.sort_by(|a, b| b.dps.cmp(&a.dps));
-        Some(stats)
+        true
     }
 }
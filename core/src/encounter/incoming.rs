@@ -0,0 +1,153 @@
+//! Incoming damage from NPC abilities, for estimating upcoming raid-wide hits
+//!
+//! A pull records how much damage players took from each NPC ability. Ticks
+//! landing on the same player within [`HIT_BURST_SECS`] count as one hit, so a
+//! multi-tick attack yields one hit per player per cast. The per-cast
+//! [`HitEstimate`]s are kept across pulls so the timer overlay can warn what
+//! the next cast will do before it lands.
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::context::IStr;
+
+/// Hits on a player closer together than this belong to the same cast
+pub const HIT_BURST_SECS: f32 = 3.0;
+
+/// A cast hitting at least this many players on average counts as raid-wide
+pub const RAID_WIDE_MIN_PLAYERS: f32 = 4.0;
+
+/// Average effect of one cast of an NPC ability on the group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HitEstimate {
+    /// Damage each player takes from one cast
+    pub per_person: i64,
+    /// Players hit by one cast
+    pub players_hit: f32,
+}
+
+impl HitEstimate {
+    /// Whether a cast hits enough players to count as raid-wide
+    pub fn is_raid_wide(&self) -> bool {
+        self.players_hit >= RAID_WIDE_MIN_PLAYERS
+    }
+
+    /// Fold another pull into an estimate averaged over `pulls` earlier pulls
+    pub fn blend(&self, next: &HitEstimate, pulls: u32) -> HitEstimate {
+        let weight = pulls as f32;
+        let total = weight + 1.0;
+        HitEstimate {
+            per_person: ((self.per_person as f32 * weight + next.per_person as f32) / total)
+                as i64,
+            players_hit: (self.players_hit * weight + next.players_hit) / total,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AbilityHits {
+    name: IStr,
+    total_damage: i64,
+    player_hits: u32,
+    casts: u32,
+    last_hit_secs: f32,
+    last_player_hit_secs: HashMap<i64, f32>,
+}
+
+/// Damage players took per NPC ability during one pull
+#[derive(Debug, Clone, Default)]
+pub struct IncomingDamage {
+    abilities: HashMap<i64, AbilityHits>,
+}
+
+impl IncomingDamage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `amount` damage to `player_id` from an NPC ability at combat time `secs`
+    pub fn record(&mut self, ability_id: i64, name: IStr, player_id: i64, secs: f32, amount: i64) {
+        let hits = self
+            .abilities
+            .entry(ability_id)
+            .or_insert_with(|| AbilityHits {
+                name,
+                total_damage: 0,
+                player_hits: 0,
+                casts: 0,
+                last_hit_secs: f32::NEG_INFINITY,
+                last_player_hit_secs: HashMap::new(),
+            });
+
+        hits.total_damage += amount;
+        if secs - hits.last_hit_secs > HIT_BURST_SECS {
+            hits.casts += 1;
+        }
+        hits.last_hit_secs = secs;
+
+        let last = hits
+            .last_player_hit_secs
+            .insert(player_id, secs)
+            .unwrap_or(f32::NEG_INFINITY);
+        if secs - last > HIT_BURST_SECS {
+            hits.player_hits += 1;
+        }
+    }
+
+    /// Per-cast estimate of each ability seen, with its ID and name
+    pub fn estimates(&self) -> impl Iterator<Item = (i64, IStr, HitEstimate)> + '_ {
+        self.abilities.iter().filter_map(|(&id, hits)| {
+            if hits.player_hits == 0 || hits.casts == 0 {
+                return None;
+            }
+            let estimate = HitEstimate {
+                per_person: hits.total_damage / hits.player_hits as i64,
+                players_hit: hits.player_hits as f32 / hits.casts as f32,
+            };
+            Some((id, hits.name, estimate))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.abilities.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::intern;
+
+    #[test]
+    fn test_ticks_on_a_player_count_as_one_hit() {
+        let name = intern("Terminate");
+        let mut incoming = IncomingDamage::new();
+        // Two casts 30s apart, each ticking twice on 4 players
+        for cast_secs in [10.0, 40.0] {
+            for player in 1..=4 {
+                incoming.record(7, name, player, cast_secs, 15_000);
+                incoming.record(7, name, player, cast_secs + 1.0, 5_000);
+            }
+        }
+
+        let (_, _, estimate) = incoming.estimates().next().unwrap();
+        assert_eq!(estimate.per_person, 20_000);
+        assert_eq!(estimate.players_hit, 4.0);
+        assert!(estimate.is_raid_wide());
+    }
+
+    #[test]
+    fn test_blend_averages_over_pulls() {
+        let stored = HitEstimate {
+            per_person: 30_000,
+            players_hit: 8.0,
+        };
+        let next = HitEstimate {
+            per_person: 50_000,
+            players_hit: 4.0,
+        };
+        let blended = stored.blend(&next, 3);
+        assert_eq!(blended.per_person, 35_000);
+        assert_eq!(blended.players_hit, 7.0);
+    }
+}
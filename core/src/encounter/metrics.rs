@@ -2,7 +2,30 @@ use crate::combat_log::EntityType;
 use crate::context::IStr;
 use crate::context::resolve;
 use crate::game_data::Discipline;
+use hashbrown::HashMap;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Abilities listed per player in `top_damage_abilities`/`top_healing_abilities`
+pub const TOP_ABILITY_COUNT: usize = 3;
+
+/// Amounts one entity dealt with one ability
+#[derive(Debug, Clone)]
+pub struct AbilityTotals {
+    pub name: IStr,
+    pub damage: i64,
+    pub healing: i64,
+}
+
+impl AbilityTotals {
+    pub fn new(name: IStr) -> Self {
+        Self {
+            name,
+            damage: 0,
+            healing: 0,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Default)]
 pub struct MetricAccumulator {
@@ -12,6 +35,8 @@ pub struct MetricAccumulator {
     pub damage_dealt_effective: i64,
     pub damage_hit_count: u32,
     pub damage_crit_count: u32,
+    /// Portion of `damage_dealt` that was reflected back at the attacker
+    pub damage_reflected: i64,
 
     // Damage receiving
     pub damage_received: i64,
@@ -41,7 +66,29 @@ pub struct MetricAccumulator {
     // General
     pub actions: u32,
     pub threat_generated: f64,
+    /// Threat generated by actions on each NPC (heal threat is not tied to one)
+    pub threat_by_target: HashMap<i64, f64>,
     pub taunt_count: u32,
+    pub dispel_count: u32,
+    pub deaths: u32,
+
+    /// Damage and healing dealt per ability ID
+    pub abilities: HashMap<i64, AbilityTotals>,
+}
+
+impl MetricAccumulator {
+    /// The [`TOP_ABILITY_COUNT`] abilities with the largest non-zero `amount`
+    pub fn top_abilities(&self, amount: impl Fn(&AbilityTotals) -> i64) -> Vec<(IStr, i64)> {
+        let mut top: Vec<_> = self
+            .abilities
+            .values()
+            .map(|totals| (totals.name, amount(totals)))
+            .filter(|&(_, amount)| amount > 0)
+            .collect();
+        top.sort_by_key(|a| std::cmp::Reverse(a.1));
+        top.truncate(TOP_ABILITY_COUNT);
+        top
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -51,7 +98,7 @@ pub struct EntityMetrics {
     pub entity_type: EntityType,
     pub discipline: Option<Discipline>,
     pub discipline_name: Option<String>,
-    pub class_name: Option<String>,
+    pub class_name: Option<&'static str>,
 
     // Damage dealing
     pub total_damage: i64,
@@ -61,6 +108,8 @@ pub struct EntityMetrics {
     pub edps: i32,
     pub bossdps: i32,
     pub damage_crit_pct: f32,
+    pub total_reflect_damage: i64,
+    pub reflect_dps: i32,
 
     // Healing dealing
     pub total_healing: i64,
@@ -90,10 +139,18 @@ pub struct EntityMetrics {
     pub total_shield_absorbed: i64,
     pub taunt_count: u32,
 
+    // Healer utility
+    pub dispel_count: u32,
+
     // General
+    pub deaths: u32,
     pub apm: f32,
     pub tps: i32,
     pub total_threat: i64,
+
+    // Top abilities (players only)
+    pub top_damage_abilities: Vec<(IStr, i64)>,
+    pub top_healing_abilities: Vec<(IStr, i64)>,
 }
 
 impl EntityMetrics {
@@ -104,9 +161,11 @@ impl EntityMetrics {
             name: resolve(self.name).to_string(),
             discipline: self.discipline,
             discipline_name: self.discipline_name.clone(),
-            class_name: self.class_name.clone(),
-            class_icon: self.discipline.map(|d| d.class().icon_name().to_string()),
-            role_icon: self.discipline.map(|d| d.role().icon_name().to_string()),
+            class_name: self.class_name.map(Cow::Borrowed),
+            class_icon: self
+                .discipline
+                .map(|d| Cow::Borrowed(d.class().icon_name())),
+            role_icon: self.discipline.map(|d| Cow::Borrowed(d.role().icon_name())),
 
             // Damage dealing
             dps: self.dps as i64,
@@ -116,6 +175,8 @@ impl EntityMetrics {
             total_damage_effective: self.total_damage_effective,
             total_damage_boss: self.total_damage_boss,
             damage_crit_pct: self.damage_crit_pct,
+            reflect_dps: self.reflect_dps as i64,
+            total_reflect_damage: self.total_reflect_damage,
 
             // Healing
             hps: self.hps as i64,
@@ -125,9 +186,11 @@ impl EntityMetrics {
             heal_crit_pct: self.heal_crit_pct,
             effective_heal_pct: self.effective_heal_pct,
 
-            // Threat
+            // Threat (on the threat target: filled in by the caller)
             tps: self.tps as i64,
             total_threat: self.total_threat,
+            target_tps: 0,
+            target_total_threat: 0,
 
             // Damage taken
             dtps: self.dtps as i64,
@@ -140,22 +203,38 @@ impl EntityMetrics {
             total_shielding: self.total_shielding,
 
             // Activity
+            dispels: self.dispel_count,
+            deaths: self.deaths,
             apm: self.apm,
+
+            // Top abilities
+            top_damage_abilities: resolve_abilities(&self.top_damage_abilities),
+            top_healing_abilities: resolve_abilities(&self.top_healing_abilities),
         }
     }
 }
 
+fn resolve_abilities(abilities: &[(IStr, i64)]) -> Vec<(String, i64)> {
+    abilities
+        .iter()
+        .map(|&(name, amount)| (resolve(name).to_string(), amount))
+        .collect()
+}
+
 /// Unified player metrics struct for use across crate boundaries.
 /// This is the canonical representation used by service and overlay layers.
+///
+/// Class and icon names come from static game data, so they borrow instead of
+/// allocating on every metrics tick (deserialized values are owned).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PlayerMetrics {
     pub entity_id: i64,
     pub name: String,
     pub discipline: Option<Discipline>,
     pub discipline_name: Option<String>,
-    pub class_name: Option<String>,
-    pub class_icon: Option<String>,
-    pub role_icon: Option<String>,
+    pub class_name: Option<Cow<'static, str>>,
+    pub class_icon: Option<Cow<'static, str>>,
+    pub role_icon: Option<Cow<'static, str>>,
 
     // Damage dealing
     pub dps: i64,
@@ -165,6 +244,11 @@ pub struct PlayerMetrics {
     pub total_damage_effective: i64,
     pub total_damage_boss: i64,
     pub damage_crit_pct: f32,
+    /// Reflected portion of `dps`/`total_damage`
+    #[serde(default)]
+    pub reflect_dps: i64,
+    #[serde(default)]
+    pub total_reflect_damage: i64,
 
     // Healing
     pub hps: i64,
@@ -177,6 +261,12 @@ pub struct PlayerMetrics {
     // Threat
     pub tps: i64,
     pub total_threat: i64,
+    /// Threat on the encounter's threat target only
+    /// ([`CombatEncounter::threat_target`](super::CombatEncounter::threat_target))
+    #[serde(default)]
+    pub target_tps: i64,
+    #[serde(default)]
+    pub target_total_threat: i64,
 
     // Damage taken
     pub dtps: i64,
@@ -189,5 +279,16 @@ pub struct PlayerMetrics {
     pub total_shielding: i64,
 
     // Activity
+    #[serde(default)]
+    pub dispels: u32,
+    #[serde(default)]
+    pub deaths: u32,
     pub apm: f32,
+
+    /// Largest damage abilities by total, for expanded meter entries
+    #[serde(default)]
+    pub top_damage_abilities: Vec<(String, i64)>,
+    /// Largest healing abilities by total, for expanded meter entries
+    #[serde(default)]
+    pub top_healing_abilities: Vec<(String, i64)>,
 }
@@ -2,13 +2,19 @@ pub mod challenge;
 pub mod combat;
 pub mod effect_instance;
 pub mod entity_info;
+pub mod incoming;
 pub mod metrics;
+pub mod pace;
+pub mod recent;
 pub mod shielding;
 pub mod summary;
 
 pub use challenge::{ChallengeTracker, ChallengeValue};
 pub use combat::{ActiveBoss, CombatEncounter, ProcessingMode};
 pub use effect_instance::EffectInstance;
+pub use incoming::{HitEstimate, IncomingDamage};
+pub use pace::{HpTimeline, PhaseEta};
+pub use recent::{RECAP_WINDOW_SECS, RecentEvent, RecentEvents};
 pub use shielding::ShieldContext;
 
 use chrono::NaiveDateTime;
@@ -41,6 +47,8 @@ pub struct OverlayHealthEntry {
     pub target_name: Option<String>,
     pub current: i32,
     pub max: i32,
+    /// HP percentages where `BossHpBelow` phases start, highest first
+    pub phase_markers: Vec<f32>,
     /// Used for sorting by encounter order (not serialized)
     #[serde(skip)]
     pub first_seen_at: Option<NaiveDateTime>,
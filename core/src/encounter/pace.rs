@@ -0,0 +1,153 @@
+//! Kill pace against a recorded best kill
+//!
+//! A boss pull records its combined boss HP over combat time as an
+//! [`HpTimeline`]. Comparing the current HP and elapsed time against the
+//! timeline of the fastest recorded kill tells how far ahead or behind the
+//! pull is.
+
+use serde::{Deserialize, Serialize};
+
+/// Samples of `(combat_secs, boss_hp_percent)`, HP strictly decreasing
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HpTimeline {
+    samples: Vec<(f32, f32)>,
+}
+
+/// Combined boss HP at or below this counts as a kill
+pub const KILL_HP_PERCENT: f32 = 1.0;
+
+/// Estimated time until the next HP-triggered phase
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseEta {
+    pub phase_name: String,
+    pub secs: f32,
+}
+
+impl HpTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the HP at `secs` if it dropped at least one percent since the last sample
+    pub fn record(&mut self, secs: f32, hp_percent: f32) {
+        let last = self.samples.last().map_or(100.0, |&(_, hp)| hp);
+        if last - hp_percent >= 1.0 || (hp_percent <= 0.0 && last > 0.0) {
+            self.samples.push((secs, hp_percent));
+        }
+    }
+
+    pub fn samples(&self) -> &[(f32, f32)] {
+        &self.samples
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Whether the boss was brought down (a reset before that is not a kill)
+    pub fn is_kill(&self) -> bool {
+        self.samples
+            .last()
+            .is_some_and(|&(_, hp)| hp <= KILL_HP_PERCENT)
+    }
+
+    /// Combat time of the last sample
+    pub fn duration_secs(&self) -> Option<f32> {
+        self.samples.last().map(|&(secs, _)| secs)
+    }
+
+    /// Combat time at which the boss reached `hp_percent` (interpolated)
+    pub fn time_at_hp(&self, hp_percent: f32) -> Option<f32> {
+        let idx = self.samples.iter().position(|&(_, hp)| hp <= hp_percent)?;
+        let (secs, hp) = self.samples[idx];
+        let (prev_secs, prev_hp) = if idx == 0 {
+            (0.0, 100.0)
+        } else {
+            self.samples[idx - 1]
+        };
+        if prev_hp - hp <= f32::EPSILON {
+            return Some(secs);
+        }
+        let t = (prev_hp - hp_percent) / (prev_hp - hp);
+        Some(prev_secs + (secs - prev_secs) * t.clamp(0.0, 1.0))
+    }
+
+    /// Combined boss HP percent lost per second over the last `window_secs`
+    /// of samples (the whole pull if it is shorter)
+    pub fn hp_rate_per_sec(&self, window_secs: f32) -> Option<f32> {
+        let &(end_secs, end_hp) = self.samples.last()?;
+        let (start_secs, start_hp) = self
+            .samples
+            .iter()
+            .rev()
+            .find(|&&(secs, _)| end_secs - secs >= window_secs)
+            .copied()
+            .unwrap_or((0.0, 100.0));
+        let elapsed = end_secs - start_secs;
+        (elapsed > 0.0).then(|| (start_hp - end_hp) / elapsed)
+    }
+
+    /// Seconds this pull is behind `self` (negative when ahead), given the
+    /// pull's elapsed combat time and current combined boss HP
+    pub fn pace_delta_secs(&self, elapsed_secs: f32, hp_percent: f32) -> Option<f32> {
+        if hp_percent >= 100.0 {
+            return None;
+        }
+        Some(elapsed_secs - self.time_at_hp(hp_percent)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timeline(samples: &[(f32, f32)]) -> HpTimeline {
+        let mut timeline = HpTimeline::new();
+        for &(secs, hp) in samples {
+            timeline.record(secs, hp);
+        }
+        timeline
+    }
+
+    #[test]
+    fn test_record_keeps_whole_percent_drops() {
+        let t = timeline(&[
+            (1.0, 99.5),
+            (2.0, 99.0),
+            (3.0, 98.2),
+            (4.0, 97.9),
+            (5.0, 0.0),
+        ]);
+        assert_eq!(t.samples(), &[(2.0, 99.0), (4.0, 97.9), (5.0, 0.0)]);
+        assert!(t.is_kill());
+        assert_eq!(t.duration_secs(), Some(5.0));
+    }
+
+    #[test]
+    fn test_pace_delta_interpolates_best_kill() {
+        let best = timeline(&[(10.0, 90.0), (20.0, 80.0), (100.0, 0.0)]);
+        assert_eq!(best.time_at_hp(85.0), Some(15.0));
+        // Reached 85% in 12s, best kill took 15s: 3s ahead
+        assert_eq!(best.pace_delta_secs(12.0, 85.0), Some(-3.0));
+        // Reached 80% in 25s, best kill took 20s: 5s behind
+        assert_eq!(best.pace_delta_secs(25.0, 80.0), Some(5.0));
+        assert_eq!(best.pace_delta_secs(1.0, 100.0), None);
+    }
+
+    #[test]
+    fn test_hp_rate_uses_recent_window() {
+        let t = timeline(&[(10.0, 95.0), (20.0, 90.0), (30.0, 70.0)]);
+        // 90% -> 70% over the last 10s
+        assert_eq!(t.hp_rate_per_sec(10.0), Some(2.0));
+        // Window longer than the pull falls back to the pull start
+        assert_eq!(t.hp_rate_per_sec(60.0), Some(1.0));
+        assert_eq!(HpTimeline::new().hp_rate_per_sec(10.0), None);
+    }
+
+    #[test]
+    fn test_reset_is_not_a_kill() {
+        let reset = timeline(&[(10.0, 90.0), (20.0, 60.0)]);
+        assert!(!reset.is_kill());
+        assert!(!HpTimeline::new().is_kill());
+    }
+}
@@ -0,0 +1,105 @@
+//! Damage and healing players received in the last few seconds
+//!
+//! Live encounters keep a short rolling window of what each player took and
+//! was healed for, so a death can be recapped the moment it happens.
+
+use std::collections::VecDeque;
+
+use chrono::NaiveDateTime;
+use hashbrown::HashMap;
+
+use crate::context::IStr;
+
+/// Seconds of events kept per player (and shown in a death recap)
+pub const RECAP_WINDOW_SECS: i64 = 10;
+
+/// One hit or heal a player received
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentEvent {
+    pub timestamp: NaiveDateTime,
+    pub source_name: IStr,
+    pub ability_name: IStr,
+    /// Damage or healing amount
+    pub amount: i64,
+    /// Portion of the damage absorbed by shields
+    pub absorbed: i64,
+    pub is_heal: bool,
+    pub is_crit: bool,
+}
+
+/// Rolling per-player window of received damage and healing
+#[derive(Debug, Clone, Default)]
+pub struct RecentEvents {
+    players: HashMap<i64, VecDeque<RecentEvent>>,
+}
+
+impl RecentEvents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an event on `player_id`, dropping ones older than the window
+    pub fn record(&mut self, player_id: i64, event: RecentEvent) {
+        let events = self.players.entry(player_id).or_default();
+        let cutoff = event.timestamp - chrono::Duration::seconds(RECAP_WINDOW_SECS);
+        while events.front().is_some_and(|e| e.timestamp < cutoff) {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    /// Events on `player_id` in the window ending at `at`, oldest first
+    pub fn window(&self, player_id: i64, at: NaiveDateTime) -> Vec<RecentEvent> {
+        let cutoff = at - chrono::Duration::seconds(RECAP_WINDOW_SECS);
+        self.players
+            .get(&player_id)
+            .map(|events| {
+                events
+                    .iter()
+                    .filter(|e| e.timestamp >= cutoff && e.timestamp <= at)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn clear(&mut self) {
+        self.players.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::context::intern;
+
+    fn hit(secs: u32, amount: i64) -> RecentEvent {
+        RecentEvent {
+            timestamp: NaiveDate::from_ymd_opt(2025, 1, 1)
+                .unwrap()
+                .and_hms_opt(20, 0, secs)
+                .unwrap(),
+            source_name: intern("Brontes"),
+            ability_name: intern("Clockwork Missile"),
+            amount,
+            absorbed: 0,
+            is_heal: false,
+            is_crit: false,
+        }
+    }
+
+    #[test]
+    fn test_window_keeps_last_ten_seconds() {
+        let mut recent = RecentEvents::new();
+        for (secs, amount) in [(0, 1_000), (5, 2_000), (12, 3_000), (14, 4_000)] {
+            recent.record(1, hit(secs, amount));
+        }
+
+        let death = hit(15, 0).timestamp;
+        let amounts: Vec<_> = recent.window(1, death).iter().map(|e| e.amount).collect();
+        assert_eq!(amounts, vec![2_000, 3_000, 4_000]);
+        assert!(recent.window(2, death).is_empty());
+    }
+}
@@ -2,10 +2,11 @@
 //!
 //! The combat log's `dmg_absorbed` shows the TOTAL absorbed by all active shields.
 //! We use simple FIFO attribution: credit all absorbed damage to whoever applied
-//! the first (oldest) active shield.
+//! the first (oldest) shield in the target's shield context.
 //!
-//! If no shields are active, we check for recently closed shields (500ms grace window)
-//! to handle timing edge cases.
+//! The context also includes recently closed shields (500ms grace window) to
+//! handle timing edge cases. Live metrics and the parse worker share it, so
+//! both paths credit the same shield.
 
 use super::CombatEncounter;
 use crate::combat_log::CombatEvent;
@@ -29,78 +30,22 @@ pub struct ShieldContext {
     pub estimated_max: i64,
 }
 
-/// Lightweight shield reference for attribution without borrowing issues
-struct ActiveShield {
-    source_id: i64,
-}
-
 impl CombatEncounter {
     /// Process a damage event that has absorption.
-    /// Credits all absorbed damage to the first (oldest) active shield using FIFO.
+    /// Credits all absorbed damage to the first shield in the target's shield
+    /// context, the same attribution the overview query applies to the
+    /// `active_shields` column, so live APS matches post-combat numbers.
     pub fn attribute_shield_absorption(&mut self, event: &CombatEvent) {
         let absorbed = event.details.dmg_absorbed as i64;
         if absorbed == 0 {
             return;
         }
 
-        let target_id = event.target_entity.log_id;
-
-        // Find the first active shield (FIFO - oldest applied)
-        if let Some(first_shield) = self.get_first_active_shield(target_id, event.timestamp) {
-            self.credit_shielding(first_shield.source_id, absorbed);
-        } else {
-            // No active shields - try recently closed (grace window)
-            self.credit_recently_closed_shield(target_id, absorbed, event.timestamp);
-        }
-    }
-
-    /// Get the first (oldest) active shield for a target
-    fn get_first_active_shield(
-        &self,
-        target_id: i64,
-        timestamp: NaiveDateTime,
-    ) -> Option<ActiveShield> {
-        let effects = self.effects.get(&target_id)?;
-
-        effects
-            .iter()
-            .filter(|e| {
-                e.is_shield
-                    && e.applied_at < timestamp
-                    && e.removed_at.map_or(true, |r| r >= timestamp)
-            })
-            .min_by_key(|e| e.applied_at)
-            .map(|e| ActiveShield {
-                source_id: e.source_id,
-            })
-    }
-
-    /// Credit absorption to a recently closed shield within the grace window
-    fn credit_recently_closed_shield(
-        &mut self,
-        target_id: i64,
-        absorbed: i64,
-        timestamp: NaiveDateTime,
-    ) {
-        let Some(effects) = self.effects.get(&target_id) else {
-            return;
-        };
-
-        // Find a shield that was recently closed (within grace period)
-        let recent_shield = effects
-            .iter()
-            .filter(|e| e.is_shield)
-            .filter(|e| {
-                e.removed_at
-                    .map(|removed| {
-                        let delta = timestamp.signed_duration_since(removed).num_milliseconds();
-                        (0..=RECENTLY_CLOSED_GRACE_MS).contains(&delta)
-                    })
-                    .unwrap_or(false)
-            })
-            .max_by_key(|e| e.removed_at);
-
-        if let Some(shield) = recent_shield {
+        let first_shield = self
+            .get_shield_context(event.target_entity.log_id, event.timestamp)
+            .into_iter()
+            .next();
+        if let Some(shield) = first_shield {
             self.credit_shielding(shield.source_id, absorbed);
         }
     }
@@ -20,6 +20,8 @@ use crate::state::info::AreaInfo;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EncounterSummary {
     pub encounter_id: u64,
+    /// Stable key of the encounter's parquet file (survives re-parsing)
+    pub encounter_key: String,
     pub display_name: String,
     pub encounter_type: PhaseType,
     /// ISO 8601 formatted start time (or None if unknown)
@@ -36,6 +38,10 @@ pub struct EncounterSummary {
     pub is_phase_start: bool,
     /// Names of NPC enemies in the encounter
     pub npc_names: Vec<String>,
+    /// Bytes `[start, end)` of the combat's lines in the raw log, so the segment
+    /// can be read directly without scanning the file
+    #[serde(default)]
+    pub log_range: Option<(u64, u64)>,
 }
 
 /// Tracks encounter history for the current log file session
@@ -54,7 +60,13 @@ impl EncounterHistory {
         Self::default()
     }
 
-    pub fn add(&mut self, summary: EncounterSummary) {
+    pub fn add(&mut self, mut summary: EncounterSummary) {
+        // Keep IDs unique for encounters that started before a backfill merge
+        if let Some(last) = self.summaries.last()
+            && summary.encounter_id <= last.encounter_id
+        {
+            summary.encounter_id = last.encounter_id + 1;
+        }
         self.summaries.push(summary);
     }
 
@@ -62,6 +74,71 @@ impl EncounterHistory {
         &self.summaries
     }
 
+    /// Merge the summaries of a full re-parse of the log (e.g. a background
+    /// backfill after parsing only the most recent encounters).
+    ///
+    /// `full` replaces every summary with the same encounter key; summaries it
+    /// doesn't contain (encounters finished since) are kept after it. IDs are
+    /// renumbered in order and pull counts rebuilt for the current area.
+    pub fn merge_backfill(&mut self, full: Vec<EncounterSummary>) {
+        let newer: Vec<_> = {
+            let keys: hashbrown::HashSet<&str> =
+                full.iter().map(|s| s.encounter_key.as_str()).collect();
+            self.summaries
+                .drain(..)
+                .filter(|s| !keys.contains(s.encounter_key.as_str()))
+                .collect()
+        };
+
+        self.summaries = full;
+        self.summaries.extend(newer);
+        for (id, summary) in self.summaries.iter_mut().enumerate() {
+            summary.encounter_id = id as u64;
+        }
+
+        // Pull numbering restarts at every phase start (area change)
+        let phase_start = self
+            .summaries
+            .iter()
+            .rposition(|s| s.is_phase_start)
+            .unwrap_or(0);
+        self.trash_pull_count = 0;
+        self.boss_pull_counts.clear();
+        for summary in &self.summaries[phase_start..] {
+            match &summary.boss_name {
+                Some(name) => *self.boss_pull_counts.entry(name.clone()).or_insert(0) += 1,
+                None => self.trash_pull_count += 1,
+            }
+        }
+    }
+
+    /// Trim spare capacity from the most recent summary
+    pub fn compact_latest(&mut self) {
+        if let Some(summary) = self.summaries.last_mut() {
+            summary.player_metrics.shrink_to_fit();
+            summary.npc_names.shrink_to_fit();
+        }
+    }
+
+    /// Estimated heap usage of stored summaries and their player metrics (bytes)
+    pub fn estimated_heap_bytes(&self) -> usize {
+        use crate::memory::{table_bytes, vec_bytes};
+
+        let nested: usize = self
+            .summaries
+            .iter()
+            .map(|s| {
+                vec_bytes::<PlayerMetrics>(s.player_metrics.capacity())
+                    + vec_bytes::<String>(s.npc_names.capacity())
+                    + s.npc_names.iter().map(String::capacity).sum::<usize>()
+            })
+            .sum();
+
+        vec_bytes::<EncounterSummary>(self.summaries.capacity())
+            + nested
+            + table_bytes::<(String, u32)>(self.boss_pull_counts.capacity())
+    }
+
     pub fn clear(&mut self) {
         self.summaries.clear();
         self.boss_pull_counts.clear();
@@ -289,6 +366,7 @@ pub fn create_encounter_summary(
 
     Some(EncounterSummary {
         encounter_id: encounter.id,
+        encounter_key: encounter.encounter_key()?,
         display_name,
         encounter_type,
         start_time: encounter
@@ -305,5 +383,6 @@ pub fn create_encounter_summary(
         player_metrics,
         is_phase_start,
         npc_names,
+        log_range: encounter.log_range,
     })
 }
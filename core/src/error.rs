@@ -0,0 +1,131 @@
+//! Crate-wide error type
+//!
+//! Module errors ([`QueryError`], [`DslError`], [`StorageError`], ...) stay specific
+//! to their module. [`BarasError`] wraps them into the categories surfaced to users
+//! ([`ErrorKind`]) and is what crosses the Tauri command boundary, serialized as a
+//! [`CommandError`].
+
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+pub use baras_types::{CommandError, ErrorKind};
+
+use crate::combat_log::{ParseError, ReaderError};
+use crate::context::{ConfigError, WatcherError};
+use crate::dsl::DslError;
+use crate::query::QueryError;
+use crate::storage::StorageError;
+use crate::timers::TimerError;
+
+/// Errors returned by core and service operations
+#[derive(Debug, Error)]
+pub enum BarasError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+
+    #[error(transparent)]
+    Watcher(#[from] WatcherError),
+
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
+    #[error(transparent)]
+    Reader(#[from] ReaderError),
+
+    #[error(transparent)]
+    Definition(#[from] DslError),
+
+    #[error(transparent)]
+    Timer(#[from] TimerError),
+
+    #[error(transparent)]
+    Query(#[from] QueryError),
+
+    #[error("{0}")]
+    Platform(String),
+}
+
+impl BarasError {
+    /// Category shown to users
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Io(_) | Self::Storage(_) | Self::Config(_) | Self::Watcher(_) => ErrorKind::Io,
+            Self::Parse(_) | Self::Reader(_) => ErrorKind::Parse,
+            Self::Definition(_) | Self::Timer(_) => ErrorKind::Definition,
+            Self::Query(_) => ErrorKind::Query,
+            Self::Platform(_) => ErrorKind::Platform,
+        }
+    }
+
+    /// User-facing message. The single place where errors are worded for the UI.
+    pub fn user_message(&self) -> String {
+        match self {
+            Self::Query(QueryError::NoSession) => "No combat log is loaded.".to_string(),
+            Self::Query(QueryError::NoEncountersDir | QueryError::EncounterNotFound { .. }) => {
+                "Encounter data is not available yet. Try again once the log has finished loading."
+                    .to_string()
+            }
+            Self::Query(QueryError::NoLiveData) => "No live combat data yet.".to_string(),
+            _ => format!("{}: {}", self.kind().label(), self.chain()),
+        }
+    }
+
+    /// Error message followed by its sources (`outer: inner: root`)
+    fn chain(&self) -> String {
+        let mut message = self.to_string();
+        let mut source = std::error::Error::source(self);
+        while let Some(err) = source {
+            message.push_str(": ");
+            message.push_str(&err.to_string());
+            source = err.source();
+        }
+        message
+    }
+}
+
+impl From<&BarasError> for CommandError {
+    fn from(err: &BarasError) -> Self {
+        Self {
+            kind: err.kind(),
+            message: err.user_message(),
+        }
+    }
+}
+
+impl Serialize for BarasError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CommandError::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_message_includes_source_chain() {
+        let err = BarasError::from(StorageError::CreateFile {
+            path: "out/0001.parquet".into(),
+            source: std::io::Error::new(std::io::ErrorKind::PermissionDenied, "access denied"),
+        });
+        assert_eq!(err.kind(), ErrorKind::Io);
+        assert_eq!(
+            err.user_message(),
+            "File error: failed to create file out/0001.parquet: access denied"
+        );
+    }
+
+    #[test]
+    fn test_command_error_uses_friendly_message() {
+        let err = BarasError::from(QueryError::NoSession);
+        let command = CommandError::from(&err);
+        assert_eq!(command.kind, ErrorKind::Query);
+        assert_eq!(command.message, "No combat log is loaded.");
+    }
+}
@@ -166,6 +166,11 @@ pub fn lookup_boss(entity_id: i64) -> Option<&'static BossInfo> {
     BOSS_LOOKUP.get(&entity_id)
 }
 
+/// All boss entities in the built-in data
+pub fn all_bosses() -> impl Iterator<Item = (i64, &'static BossInfo)> {
+    BOSS_LOOKUP.iter().map(|(id, info)| (*id, info))
+}
+
 /// Check if an entity ID is a known boss.
 ///
 /// Returns true if the entity is in the dynamic registry OR the hardcoded data.
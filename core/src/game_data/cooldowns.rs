@@ -0,0 +1,129 @@
+//! Defensive and raid-utility cooldowns shown on the raid cooldown overlay
+//!
+//! Cooldowns are the base durations, before utilities and set bonuses
+//! shorten them.
+
+/// What a tracked cooldown is used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CooldownKind {
+    /// Personal damage reduction
+    Defensive,
+    /// Helps the whole group (raid buffs, group damage reduction, pulls)
+    RaidUtility,
+}
+
+/// One tracked ability (Republic and Imperial mirror share an entry)
+#[derive(Debug, Clone, Copy)]
+pub struct TrackedCooldown {
+    /// Display label on the overlay
+    pub label: &'static str,
+    pub kind: CooldownKind,
+    /// Cooldown after each cast, in seconds
+    pub cooldown_secs: f32,
+    /// Game ability IDs of the cast
+    pub ability_ids: &'static [i64],
+    /// English ability names, matched when an ID is not listed yet
+    pub ability_names: &'static [&'static str],
+}
+
+impl TrackedCooldown {
+    pub fn matches(&self, ability_id: i64, ability_name: &str) -> bool {
+        self.ability_ids.contains(&ability_id) || self.ability_names.contains(&ability_name)
+    }
+}
+
+// Ability IDs are added as they are confirmed from combat logs; until then
+// casts are recognized by name.
+pub static TRACKED_COOLDOWNS: &[TrackedCooldown] = &[
+    TrackedCooldown {
+        label: "Saber Ward",
+        kind: CooldownKind::Defensive,
+        cooldown_secs: 90.0,
+        ability_ids: &[],
+        ability_names: &["Saber Ward"],
+    },
+    TrackedCooldown {
+        label: "Resilience",
+        kind: CooldownKind::Defensive,
+        cooldown_secs: 60.0,
+        ability_ids: &[],
+        ability_names: &["Resilience", "Force Shroud"],
+    },
+    TrackedCooldown {
+        label: "Deflection",
+        kind: CooldownKind::Defensive,
+        cooldown_secs: 120.0,
+        ability_ids: &[],
+        ability_names: &["Deflection"],
+    },
+    TrackedCooldown {
+        label: "Reactive Shield",
+        kind: CooldownKind::Defensive,
+        cooldown_secs: 120.0,
+        ability_ids: &[],
+        ability_names: &["Reactive Shield", "Energy Shield"],
+    },
+    TrackedCooldown {
+        label: "Enraged Defense",
+        kind: CooldownKind::Defensive,
+        cooldown_secs: 120.0,
+        ability_ids: &[],
+        ability_names: &["Enraged Defense", "Focused Defense"],
+    },
+    TrackedCooldown {
+        label: "Invincible",
+        kind: CooldownKind::Defensive,
+        cooldown_secs: 180.0,
+        ability_ids: &[],
+        ability_names: &["Invincible", "Warding Call"],
+    },
+    TrackedCooldown {
+        label: "Battle Readiness",
+        kind: CooldownKind::Defensive,
+        cooldown_secs: 120.0,
+        ability_ids: &[],
+        ability_names: &["Battle Readiness"],
+    },
+    TrackedCooldown {
+        label: "Adrenaline Rush",
+        kind: CooldownKind::Defensive,
+        cooldown_secs: 180.0,
+        ability_ids: &[],
+        ability_names: &["Adrenaline Rush"],
+    },
+    TrackedCooldown {
+        label: "Kolto Overload",
+        kind: CooldownKind::Defensive,
+        cooldown_secs: 180.0,
+        ability_ids: &[],
+        ability_names: &["Kolto Overload"],
+    },
+    TrackedCooldown {
+        label: "Bloodthirst",
+        kind: CooldownKind::RaidUtility,
+        cooldown_secs: 300.0,
+        ability_ids: &[],
+        ability_names: &["Bloodthirst", "Inspiration"],
+    },
+    TrackedCooldown {
+        label: "Ballistic Shield",
+        kind: CooldownKind::RaidUtility,
+        cooldown_secs: 180.0,
+        ability_ids: &[],
+        ability_names: &["Ballistic Shield", "Scrambling Field"],
+    },
+    TrackedCooldown {
+        label: "Extrication",
+        kind: CooldownKind::RaidUtility,
+        cooldown_secs: 60.0,
+        ability_ids: &[],
+        ability_names: &["Extrication", "Rescue"],
+    },
+];
+
+/// Index into [`TRACKED_COOLDOWNS`] of the cooldown started by this cast
+pub fn tracked_cooldown_index(ability_id: i64, ability_name: &str) -> Option<usize> {
+    TRACKED_COOLDOWNS
+        .iter()
+        .position(|cd| cd.matches(ability_id, ability_name))
+}
@@ -48,6 +48,28 @@ pub enum Class {
 }
 
 impl Class {
+    /// English class name (same as the `Debug` output, used for CSS matching)
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Class::Sorcerer => "Sorcerer",
+            Class::Assassin => "Assassin",
+            Class::Juggernaut => "Juggernaut",
+            Class::Marauder => "Marauder",
+            Class::Mercenary => "Mercenary",
+            Class::Powertech => "Powertech",
+            Class::Operative => "Operative",
+            Class::Sniper => "Sniper",
+            Class::Sage => "Sage",
+            Class::Shadow => "Shadow",
+            Class::Guardian => "Guardian",
+            Class::Sentinel => "Sentinel",
+            Class::Commando => "Commando",
+            Class::Vanguard => "Vanguard",
+            Class::Scoundrel => "Scoundrel",
+            Class::Gunslinger => "Gunslinger",
+        }
+    }
+
     /// Get the icon filename for this class (without path)
     pub const fn icon_name(&self) -> &'static str {
         match self {
@@ -0,0 +1,277 @@
+//! Searchable database of named game IDs
+//!
+//! NPC, ability and effect IDs with their names, collected from combat logs
+//! and the bundled data, so the encounter editor can offer pick-lists
+//! instead of sending users to raw logs for IDs.
+//!
+//! The bundled database is a CSV with one `kind,id,name` row per ID, written
+//! by `baras-cli extract-ids`.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+use encoding_rs::WINDOWS_1252;
+
+pub use baras_types::{GameIdEntry, GameIdKind};
+
+use super::bosses::all_bosses;
+use super::{effect_id, effect_type_id};
+use crate::combat_log::{CombatEvent, Entity, EntityType, LogParser};
+use crate::context::{IStr, resolve};
+
+/// Name of the bundled database, under the bundled `definitions` directory
+pub const GAME_IDS_FILENAME: &str = "game_ids.csv";
+
+const CSV_HEADER: &str = "kind,id,name";
+
+/// The NPCs, ability and effect an event names (players and companions
+/// are skipped, as are the damage and heal pseudo-effects).
+pub fn named_ids(event: &CombatEvent) -> [Option<(GameIdKind, i64, IStr)>; 4] {
+    let npc = |entity: &Entity| {
+        (entity.entity_type == EntityType::Npc && entity.class_id != 0).then_some((
+            GameIdKind::Npc,
+            entity.class_id,
+            entity.name,
+        ))
+    };
+    let ability = (event.action.action_id != 0).then_some((
+        GameIdKind::Ability,
+        event.action.action_id,
+        event.action.name,
+    ));
+    // Damage and heals are logged as ApplyEffect too
+    let is_effect = matches!(
+        event.effect.type_id,
+        effect_type_id::APPLYEFFECT | effect_type_id::REMOVEEFFECT
+    ) && !matches!(event.effect.effect_id, effect_id::DAMAGE | effect_id::HEAL);
+    let effect = is_effect.then_some((
+        GameIdKind::Effect,
+        event.effect.effect_id,
+        event.effect.effect_name,
+    ));
+    [
+        npc(&event.source_entity),
+        npc(&event.target_entity),
+        ability,
+        effect,
+    ]
+}
+
+/// Named NPC, ability and effect IDs
+#[derive(Debug, Default)]
+pub struct GameIdDatabase {
+    names: HashMap<(GameIdKind, i64), String>,
+}
+
+impl GameIdDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Add an ID. The first name recorded for an ID is kept.
+    pub fn insert(&mut self, kind: GameIdKind, id: i64, name: &str) {
+        let name = name.trim();
+        if id == 0 || name.is_empty() {
+            return;
+        }
+        self.names
+            .entry((kind, id))
+            .or_insert_with(|| name.to_string());
+    }
+
+    /// Record the NPCs, ability and effect named in one event
+    pub fn record_event(&mut self, event: &CombatEvent) {
+        for (kind, id, name) in named_ids(event).into_iter().flatten() {
+            self.insert(kind, id, resolve(name));
+        }
+    }
+
+    /// Record every ID named in a combat log
+    pub fn record_log(&mut self, bytes: &[u8], session_date: NaiveDateTime) {
+        let parser = LogParser::new(session_date);
+        let (text, _, _) = WINDOWS_1252.decode(bytes);
+        for (line_number, line) in text.lines().enumerate() {
+            if let Some(event) = parser.parse_line(line_number as u64, line) {
+                self.record_event(&event);
+            }
+        }
+    }
+
+    /// Add the NPCs from the built-in boss data
+    pub fn add_known_bosses(&mut self) {
+        for (id, info) in all_bosses() {
+            self.insert(GameIdKind::Npc, id, info.boss);
+        }
+    }
+
+    /// Add ability names from the bundled icon mapping (`ability_id,en,icon`)
+    pub fn add_icon_names(&mut self, csv: &str) {
+        for line in csv.lines().skip(1) {
+            let mut parts = line.splitn(3, ',');
+            let (Some(id), Some(name)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            if let Ok(id) = id.trim_start_matches('\u{feff}').parse() {
+                self.insert(GameIdKind::Ability, id, name);
+            }
+        }
+    }
+
+    /// Load rows written by [`Self::to_csv`]. Unknown or malformed rows are skipped.
+    pub fn add_csv(&mut self, csv: &str) {
+        for line in csv.lines().skip(1) {
+            let mut parts = line.splitn(3, ',');
+            let (Some(kind), Some(id), Some(name)) = (parts.next(), parts.next(), parts.next())
+            else {
+                continue;
+            };
+            if let (Some(kind), Ok(id)) = (GameIdKind::from_key(kind), id.parse()) {
+                self.insert(kind, id, name);
+            }
+        }
+    }
+
+    /// Write the database as CSV, sorted by kind and name
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        for entry in self.sorted_entries() {
+            out.push_str(&format!(
+                "{},{},{}\n",
+                entry.kind.key(),
+                entry.id,
+                entry.name
+            ));
+        }
+        out
+    }
+
+    fn sorted_entries(&self) -> Vec<GameIdEntry> {
+        let mut entries: Vec<GameIdEntry> = self
+            .names
+            .iter()
+            .map(|(&(kind, id), name)| GameIdEntry {
+                kind,
+                id,
+                name: name.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| (a.kind, &a.name, a.id).cmp(&(b.kind, &b.name, b.id)));
+        entries
+    }
+
+    /// Find IDs by name (case-insensitive substring) or by ID prefix.
+    ///
+    /// Exact name matches come first, then names starting with the query,
+    /// then the rest alphabetically.
+    pub fn search(&self, query: &str, kind: Option<GameIdKind>, limit: usize) -> Vec<GameIdEntry> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let numeric = query.bytes().all(|b| b.is_ascii_digit());
+
+        let mut matches: Vec<(u8, GameIdEntry)> = self
+            .names
+            .iter()
+            .filter(|((k, _), _)| kind.is_none_or(|kind| kind == *k))
+            .filter_map(|(&(kind, id), name)| {
+                let lower = name.to_lowercase();
+                let rank = if lower == query {
+                    0
+                } else if lower.starts_with(&query) {
+                    1
+                } else if lower.contains(&query) {
+                    2
+                } else if numeric && id.to_string().starts_with(&query) {
+                    3
+                } else {
+                    return None;
+                };
+                Some((
+                    rank,
+                    GameIdEntry {
+                        kind,
+                        id,
+                        name: name.clone(),
+                    },
+                ))
+            })
+            .collect();
+        matches.sort_by(|(ra, a), (rb, b)| {
+            (ra, &a.name, a.kind, a.id).cmp(&(rb, &b.name, b.kind, b.id))
+        });
+        matches.into_iter().take(limit).map(|(_, e)| e).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    const LOG: &str = "\
+[18:43:12.014] [@Tank#1|(0,0,0,0)|(1/1)] [Dread Monster {3291675820556288}:5320000116014|(0,0,0,0)|(100/100)] [Saber Strike {947961856245760}] [ApplyEffect {836045448945477}: Damage {836045448945501}] (100 energy {836045448940874})
+[18:43:13.512] [@Tank#1|(0,0,0,0)|(1/1)] [Dread Monster {3291675820556288}:5320000116014|(0,0,0,0)|(100/100)] [Corrosive Dart {3465720780292096}] [ApplyEffect {836045448945477}: Corrosive Dart {3465720780292096}]
+";
+
+    fn database() -> GameIdDatabase {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 10)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let mut db = GameIdDatabase::new();
+        db.record_log(LOG.as_bytes(), date);
+        db
+    }
+
+    #[test]
+    fn test_records_log_ids() {
+        let db = database();
+        let npcs = db.search("dread", Some(GameIdKind::Npc), 10);
+        assert_eq!(npcs.len(), 1);
+        assert_eq!(npcs[0].id, 3291675820556288);
+        assert_eq!(npcs[0].name, "Dread Monster");
+
+        // Players and the damage pseudo-effect are not recorded
+        assert!(db.search("tank", None, 10).is_empty());
+        assert!(db.search("damage", None, 10).is_empty());
+        assert_eq!(db.search("corrosive dart", None, 10).len(), 2);
+        assert_eq!(db.search("34657207", Some(GameIdKind::Effect), 10).len(), 1);
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let db = database();
+        let mut loaded = GameIdDatabase::new();
+        loaded.add_csv(&db.to_csv());
+        assert_eq!(loaded.len(), db.len());
+        assert_eq!(
+            loaded.search("saber", None, 10),
+            db.search("saber", None, 10)
+        );
+    }
+
+    #[test]
+    fn test_search_ranks_exact_then_prefix() {
+        let mut db = GameIdDatabase::new();
+        db.insert(GameIdKind::Ability, 3, "Overloaded Strike");
+        db.insert(GameIdKind::Ability, 2, "Strike Force");
+        db.insert(GameIdKind::Ability, 1, "Strike");
+        let names: Vec<String> = db
+            .search("strike", None, 10)
+            .into_iter()
+            .map(|e| e.name)
+            .collect();
+        assert_eq!(names, vec!["Strike", "Strike Force", "Overloaded Strike"]);
+        assert_eq!(db.search("strike", None, 1).len(), 1);
+    }
+}
@@ -1,13 +1,17 @@
 mod boss_registry;
 mod bosses;
+mod cooldowns;
 mod discipline;
 mod effects;
 mod flashpoint_bosses;
 mod flashpoints;
+mod game_ids;
 mod lair_bosses;
 mod pvp_instance;
 mod raid_bosses;
+mod raid_buffs;
 mod raids;
+mod reflects;
 mod shield_absorbs;
 mod shield_effects;
 mod world_bosses;
@@ -16,12 +20,17 @@ pub use boss_registry::{
     clear_boss_registry, is_registered_boss, lookup_registered_name, register_hp_overlay_entity,
 };
 pub use bosses::{
-    BossInfo, ContentType, Difficulty, get_boss_ids, is_boss, lookup_area_content_type, lookup_boss,
+    BossInfo, ContentType, Difficulty, all_bosses, get_boss_ids, is_boss, lookup_area_content_type,
+    lookup_boss,
 };
+pub use cooldowns::{CooldownKind, TRACKED_COOLDOWNS, TrackedCooldown, tracked_cooldown_index};
 pub use discipline::{Class, Discipline, Role};
 pub use effects::*;
 pub use flashpoints::{FLASHPOINT_AREAS, get_flashpoint_name, is_flashpoint};
+pub use game_ids::{GAME_IDS_FILENAME, GameIdDatabase, GameIdEntry, GameIdKind, named_ids};
 pub use pvp_instance::is_pvp_area;
+pub use raid_buffs::{RAID_BUFFS, RaidBuff, is_stim, raid_buff_index};
 pub use raids::{OPERATION_AREAS, get_operation_name, is_operation, is_world_boss};
+pub use reflects::{REFLECT_ABILITY_IDS, is_reflect_damage};
 pub use shield_absorbs::{SHIELD_INFO, ShieldInfo, get_shield_info, is_known_shield};
 pub use shield_effects::SHIELD_EFFECT_IDS;
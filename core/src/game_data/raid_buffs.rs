@@ -0,0 +1,60 @@
+//! Raid-wide buffs checked by the buff coverage report
+//!
+//! Each class buff is granted to the whole group by one class, so a missing
+//! buff on the local player means no one in the group provided it.
+
+/// One class buff (Republic and Imperial mirror share an entry)
+#[derive(Debug, Clone, Copy)]
+pub struct RaidBuff {
+    /// Display label for the coverage report
+    pub label: &'static str,
+    /// Game effect IDs granting the buff
+    pub effect_ids: &'static [i64],
+    /// English effect names, matched when an ID is not listed yet
+    pub effect_names: &'static [&'static str],
+}
+
+impl RaidBuff {
+    pub fn matches(&self, effect_id: i64, effect_name: &str) -> bool {
+        self.effect_ids.contains(&effect_id) || self.effect_names.contains(&effect_name)
+    }
+}
+
+// Effect IDs are added as they are confirmed from combat logs; until then
+// buffs are recognized by name.
+pub static RAID_BUFFS: &[RaidBuff] = &[
+    RaidBuff {
+        label: "Mark of Power",
+        effect_ids: &[],
+        effect_names: &["Mark of Power", "Force Valor"],
+    },
+    RaidBuff {
+        label: "Unnatural Might",
+        effect_ids: &[],
+        effect_names: &["Unnatural Might", "Force Might"],
+    },
+    RaidBuff {
+        label: "Coordination",
+        effect_ids: &[],
+        effect_names: &["Coordination", "Lucky Shots"],
+    },
+    RaidBuff {
+        label: "Hunter's Boon",
+        effect_ids: &[],
+        effect_names: &["Hunter's Boon", "Fortification"],
+    },
+];
+
+/// Stims are consumables named "<grade> <material> <stat> Stim"
+const STIM_NAME_SUFFIX: &str = " Stim";
+
+/// Index into [`RAID_BUFFS`] of the buff granted by this effect
+pub fn raid_buff_index(effect_id: i64, effect_name: &str) -> Option<usize> {
+    RAID_BUFFS
+        .iter()
+        .position(|buff| buff.matches(effect_id, effect_name))
+}
+
+pub fn is_stim(effect_name: &str) -> bool {
+    effect_name.ends_with(STIM_NAME_SUFFIX)
+}
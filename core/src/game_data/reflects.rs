@@ -0,0 +1,18 @@
+//! Reflected damage classification.
+//!
+//! Most reflected damage carries a `(reflected {id})` marker in the log, which
+//! the parser records as `is_reflect`. Some mechanics bounce damage back
+//! without the marker, so their abilities are listed here by ID.
+
+use phf::phf_set;
+
+/// Abilities whose damage is always reflected, marker or not
+pub static REFLECT_ABILITY_IDS: phf::Set<i64> = phf_set! {
+    801354998088314i64,  // Sonic Rebounder (Dread Fortress, Brontes tentacles)
+    3126177845739520i64, // Saber Reflect
+};
+
+/// Check if damage from `ability_id` counts as reflected
+pub fn is_reflect_damage(ability_id: i64, is_reflect: bool) -> bool {
+    is_reflect || REFLECT_ABILITY_IDS.contains(&ability_id)
+}
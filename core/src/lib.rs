@@ -4,8 +4,10 @@ pub mod debug_log;
 pub mod dsl;
 pub mod effects;
 pub mod encounter;
+pub mod error;
 pub mod game_data;
 pub mod icons;
+mod memory;
 pub mod query;
 pub mod serde_defaults;
 pub mod signal_processor;
@@ -29,10 +31,11 @@ pub use dsl::{
 };
 pub use effects::{
     ActiveEffect, DefinitionConfig, DefinitionSet, DisplayTarget, EFFECTS_DSL_VERSION,
-    EffectDefinition, EffectTracker, NewTargetInfo,
+    EffectDefinition, EffectTemplate, EffectTracker, NewTargetInfo,
 };
 pub use encounter::metrics::PlayerMetrics;
 pub use encounter::summary::{EncounterHistory, EncounterSummary};
+pub use error::BarasError;
 pub use encounter::{ActiveBoss, CombatEncounter, OverlayHealthEntry, PhaseType, ProcessingMode};
 pub use game_data::*;
 pub use icons::{IconRegistry, TICK_BIAS_SECS, calculate_effect_duration};
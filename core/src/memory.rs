@@ -0,0 +1,24 @@
+//! Heap usage estimates for memory telemetry
+//!
+//! Estimates are computed from the allocated capacity of each subsystem's main
+//! collections rather than from the allocator. They undercount small nested
+//! allocations, but are cheap enough to compute on demand and show which
+//! subsystem is growing during a long session.
+
+use std::mem::size_of;
+
+/// Bytes allocated by a `Vec`/`VecDeque` with the given capacity
+pub(crate) fn vec_bytes<T>(capacity: usize) -> usize {
+    capacity * size_of::<T>()
+}
+
+/// Bytes allocated by a hash table with the given capacity.
+/// Swiss tables store one control byte per bucket next to each entry.
+pub(crate) fn table_bytes<T>(capacity: usize) -> usize {
+    capacity * (size_of::<T>() + 1)
+}
+
+/// Bytes allocated by an optional owned string
+pub(crate) fn opt_string_bytes(s: &Option<String>) -> usize {
+    s.as_ref().map_or(0, String::capacity)
+}
@@ -0,0 +1,226 @@
+//! Statistics across several pulls registered with
+//! [`QueryContext::register_parquet_set`] (the `pulls` table): per-player
+//! average and best DPS, consistency and deaths.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::*;
+use crate::game_data::effect_id;
+
+/// Table holding every pull of a registered set, with a `pull` index column
+pub const PULLS_TABLE: &str = "pulls";
+
+/// Columns of each pull copied into [`PULLS_TABLE`]
+pub(super) const PULLS_COLUMNS: &str = "source_id, source_name, source_entity_type, \
+     target_id, target_name, dmg_amount, heal_amount, heal_effective, effect_id, combat_time_secs";
+
+/// One player's totals in one pull of the set
+#[derive(Debug, Clone, Default)]
+pub struct PullPlayerStats {
+    /// Index of the pull in the registered set
+    pub pull: usize,
+    pub name: String,
+    /// Combat duration of the pull
+    pub duration_secs: f32,
+    pub damage: f64,
+    pub effective_healing: f64,
+    pub deaths: u32,
+}
+
+impl EncounterQuery<'_> {
+    /// Per-player totals of every pull in the registered set.
+    pub async fn pull_player_stats(&self) -> Result<Vec<PullPlayerStats>, QueryError> {
+        let batches = self
+            .sql(&format!(
+                r#"
+            WITH durations AS (
+                SELECT pull, MAX(combat_time_secs) as duration_secs
+                FROM {PULLS_TABLE}
+                WHERE combat_time_secs IS NOT NULL
+                GROUP BY pull
+            ),
+            players AS (
+                SELECT DISTINCT pull, source_name as name
+                FROM {PULLS_TABLE}
+                WHERE source_entity_type = 'Player'
+            ),
+            damage_dealt AS (
+                SELECT pull, source_name as name, SUM(dmg_amount) as damage_total
+                FROM {PULLS_TABLE}
+                WHERE dmg_amount > 0 AND source_id != target_id
+                GROUP BY pull, source_name
+            ),
+            healing_done AS (
+                SELECT pull, source_name as name, SUM(heal_effective) as healing_effective
+                FROM {PULLS_TABLE}
+                WHERE heal_amount > 0
+                GROUP BY pull, source_name
+            ),
+            deaths AS (
+                SELECT pull, target_name as name, COUNT(*) as death_count
+                FROM {PULLS_TABLE}
+                WHERE effect_id = {death_id}
+                GROUP BY pull, target_name
+            )
+            SELECT
+                p.pull,
+                p.name,
+                CAST(COALESCE(du.duration_secs, 0) AS DOUBLE) as duration_secs,
+                COALESCE(d.damage_total, 0) as damage_total,
+                COALESCE(h.healing_effective, 0) as healing_effective,
+                COALESCE(dt.death_count, 0) as death_count
+            FROM players p
+            LEFT JOIN durations du ON p.pull = du.pull
+            LEFT JOIN damage_dealt d ON p.pull = d.pull AND p.name = d.name
+            LEFT JOIN healing_done h ON p.pull = h.pull AND p.name = h.name
+            LEFT JOIN deaths dt ON p.pull = dt.pull AND p.name = dt.name
+            ORDER BY p.pull, p.name
+        "#,
+                death_id = effect_id::DEATH
+            ))
+            .await?;
+
+        let mut results = Vec::new();
+        for batch in &batches {
+            let pulls = col_i64(batch, 0)?;
+            let names = col_strings(batch, 1)?;
+            let durations = col_f64(batch, 2)?;
+            let damage = col_f64(batch, 3)?;
+            let healing = col_f64(batch, 4)?;
+            let deaths = col_i64(batch, 5)?;
+            for i in 0..batch.num_rows() {
+                results.push(PullPlayerStats {
+                    pull: pulls[i] as usize,
+                    name: names[i].clone(),
+                    duration_secs: durations[i] as f32,
+                    damage: damage[i],
+                    effective_healing: healing[i],
+                    deaths: deaths[i] as u32,
+                });
+            }
+        }
+        Ok(results)
+    }
+}
+
+impl QueryContext {
+    /// Per-player statistics across several encounter parquet files.
+    ///
+    /// Leaves the context pointing at the set (see [`Self::register_parquet_set`]).
+    pub async fn aggregate_pulls(
+        &self,
+        encounters: &[PathBuf],
+    ) -> Result<SessionAggregate, QueryError> {
+        self.register_parquet_set(encounters).await?;
+        let stats = self.query().await.query().pull_player_stats().await?;
+        Ok(aggregate_pulls(&stats, encounters.len()))
+    }
+}
+
+/// Combine per-pull player totals into per-player statistics.
+///
+/// Rates use each pull's own duration. Players are ordered by average DPS.
+pub fn aggregate_pulls(stats: &[PullPlayerStats], pull_count: usize) -> SessionAggregate {
+    let mut durations = vec![0.0f32; pull_count];
+    let mut by_player: HashMap<&str, Vec<&PullPlayerStats>> = HashMap::new();
+    for row in stats {
+        if let Some(duration) = durations.get_mut(row.pull) {
+            *duration = row.duration_secs;
+        }
+        by_player.entry(row.name.as_str()).or_default().push(row);
+    }
+
+    let rate = |amount: f64, row: &PullPlayerStats| {
+        if row.duration_secs > 0.0 {
+            amount / row.duration_secs as f64
+        } else {
+            0.0
+        }
+    };
+
+    let mut players: Vec<PlayerAggregate> = by_player
+        .into_iter()
+        .map(|(name, rows)| {
+            let count = rows.len() as f64;
+            let dps: Vec<f64> = rows.iter().map(|r| rate(r.damage, r)).collect();
+            let avg_dps = dps.iter().sum::<f64>() / count;
+            let (best_pull, best_dps) = rows
+                .iter()
+                .zip(&dps)
+                .map(|(r, dps)| (r.pull, *dps))
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap_or_default();
+            let variance = dps.iter().map(|d| (d - avg_dps).powi(2)).sum::<f64>() / count;
+            let consistency_pct = if avg_dps > 0.0 {
+                (100.0 - variance.sqrt() * 100.0 / avg_dps).max(0.0)
+            } else {
+                0.0
+            };
+            let avg_ehps = rows
+                .iter()
+                .map(|r| rate(r.effective_healing, r))
+                .sum::<f64>()
+                / count;
+            PlayerAggregate {
+                name: name.to_string(),
+                pulls: rows.len() as u32,
+                avg_dps,
+                best_dps,
+                best_pull,
+                consistency_pct,
+                avg_ehps,
+                deaths: rows.iter().map(|r| r.deaths).sum(),
+            }
+        })
+        .collect();
+    players.sort_by(|a, b| b.avg_dps.total_cmp(&a.avg_dps));
+
+    SessionAggregate {
+        pull_count: pull_count as u32,
+        total_duration_secs: durations.iter().sum(),
+        players,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(pull: usize, name: &str, duration: f32, damage: f64, deaths: u32) -> PullPlayerStats {
+        PullPlayerStats {
+            pull,
+            name: name.to_string(),
+            duration_secs: duration,
+            damage,
+            effective_healing: 0.0,
+            deaths,
+        }
+    }
+
+    #[test]
+    fn players_are_averaged_over_their_own_pulls() {
+        let stats = [
+            row(0, "Alice", 100.0, 1_000_000.0, 0),
+            row(0, "Bob", 100.0, 800_000.0, 1),
+            row(1, "Alice", 200.0, 2_400_000.0, 1),
+            row(2, "Alice", 50.0, 400_000.0, 0),
+            row(2, "Bob", 50.0, 400_000.0, 0),
+        ];
+        let agg = aggregate_pulls(&stats, 3);
+        assert_eq!(agg.pull_count, 3);
+        assert_eq!(agg.total_duration_secs, 350.0);
+
+        let alice = &agg.players[0];
+        assert_eq!(alice.name, "Alice");
+        assert_eq!(alice.pulls, 3);
+        assert_eq!(alice.avg_dps, 10_000.0);
+        assert_eq!((alice.best_pull, alice.best_dps), (1, 12_000.0));
+        assert_eq!(alice.deaths, 1);
+
+        let bob = &agg.players[1];
+        assert_eq!((bob.pulls, bob.avg_dps, bob.deaths), (2, 8_000.0, 1));
+        assert_eq!(bob.consistency_pct, 100.0);
+        assert!(alice.consistency_pct < 100.0);
+    }
+}
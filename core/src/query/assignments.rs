@@ -0,0 +1,43 @@
+//! Healer assignment checks (healing by healer and target).
+
+use super::*;
+use crate::context::{AssignmentCheck, HealerAssignment, check_assignments};
+
+impl EncounterQuery<'_> {
+    /// Effective healing per healer and target, as `(healer, target, amount)` rows.
+    /// Only players and companions are included.
+    pub async fn healing_by_target(&self) -> Result<Vec<(String, String, f64)>, QueryError> {
+        let batches = self
+            .sql(
+                r#"
+            SELECT source_name, target_name, SUM(heal_effective) as amount
+            FROM events
+            WHERE heal_effective > 0
+              AND source_entity_type IN ('Player', 'Companion')
+              AND target_entity_type IN ('Player', 'Companion')
+            GROUP BY source_name, target_name
+        "#,
+            )
+            .await?;
+
+        let mut rows = Vec::new();
+        for batch in &batches {
+            let healers = col_strings(batch, 0)?;
+            let targets = col_strings(batch, 1)?;
+            let amounts = col_f64(batch, 2)?;
+            for i in 0..batch.num_rows() {
+                rows.push((healers[i].clone(), targets[i].clone(), amounts[i]));
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Check healer assignments against the registered encounter.
+    pub async fn check_healer_assignments(
+        &self,
+        assignments: &[HealerAssignment],
+    ) -> Result<Vec<AssignmentCheck>, QueryError> {
+        let healing = self.healing_by_target().await?;
+        Ok(check_assignments(assignments, &healing))
+    }
+}
@@ -1,5 +1,7 @@
 //! Ability and entity breakdown queries.
 
+use std::collections::HashMap;
+
 use super::*;
 
 impl EncounterQuery<'_> {
@@ -7,6 +9,7 @@ impl EncounterQuery<'_> {
     /// - entity_name: For outgoing tabs (Damage/Healing), filters by source_name.
     ///                For incoming tabs (DamageTaken/HealingTaken), filters by target_name.
     /// - entity_types: Filters by source_entity_type for outgoing, target_entity_type for incoming.
+    /// - breakdown_mode.exclude_reflect: Leaves out reflected damage.
     pub async fn query_breakdown(
         &self,
         tab: DataTab,
@@ -15,7 +18,7 @@ impl EncounterQuery<'_> {
         entity_types: Option<&[&str]>,
         breakdown_mode: Option<&BreakdownMode>,
         duration_secs: Option<f32>,
-    ) -> Result<Vec<AbilityBreakdown>, String> {
+    ) -> Result<Vec<AbilityBreakdown>, QueryError> {
         let mode = breakdown_mode
             .copied()
             .unwrap_or(BreakdownMode::ability_only());
@@ -56,6 +59,9 @@ impl EncounterQuery<'_> {
         if let Some(tr) = time_range {
             conditions.push(tr.sql_filter());
         }
+        if mode.exclude_reflect {
+            conditions.push("NOT is_reflect".to_string());
+        }
         if let Some(types) = entity_types {
             let type_list = types
                 .iter()
@@ -141,6 +147,12 @@ impl EncounterQuery<'_> {
             duration_secs.unwrap_or(1.0).max(0.001) as f64
         };
 
+        let instances = if mode.by_target_instance {
+            self.npc_instance_numbers().await?
+        } else {
+            HashMap::new()
+        };
+
         let mut results = Vec::new();
         for batch in &batches {
             let mut col_idx = 0;
@@ -199,6 +211,9 @@ impl EncounterQuery<'_> {
                     target_class_id: target_class_ids.as_ref().map(|v| v[i]),
                     target_log_id: target_log_ids.as_ref().map(|v| v[i]),
                     target_first_hit_secs: first_hit_times.as_ref().map(|v| v[i]),
+                    target_instance: target_log_ids
+                        .as_ref()
+                        .and_then(|v| instances.get(&v[i]).copied()),
                     total_value: totals[i],
                     hit_count: hits[i],
                     crit_count: crits[i],
@@ -220,11 +235,18 @@ impl EncounterQuery<'_> {
     /// Query entity breakdown for any data tab.
     /// - For outgoing tabs (Damage/Healing): groups by source entity.
     /// - For incoming tabs (DamageTaken/HealingTaken): groups by target entity (who received).
+    ///
+    /// Same-named NPCs are listed per instance with an instance number, or
+    /// merged into one row when `breakdown_mode.group_instances` is set.
+    /// Reflected damage is left out when `breakdown_mode.exclude_reflect` is set.
     pub async fn breakdown_by_entity(
         &self,
         tab: DataTab,
         time_range: Option<&TimeRange>,
-    ) -> Result<Vec<EntityBreakdown>, String> {
+        breakdown_mode: Option<&BreakdownMode>,
+    ) -> Result<Vec<EntityBreakdown>, QueryError> {
+        let group_instances = breakdown_mode.is_some_and(|m| m.group_instances);
+        let exclude_reflect = breakdown_mode.is_some_and(|m| m.exclude_reflect);
         let value_col = tab.value_column();
         let is_outgoing = tab.is_outgoing();
 
@@ -240,21 +262,37 @@ impl EncounterQuery<'_> {
         if let Some(tr) = time_range {
             conditions.push(tr.sql_filter());
         }
+        if exclude_reflect {
+            conditions.push("NOT is_reflect".to_string());
+        }
         let filter = format!("WHERE {}", conditions.join(" AND "));
 
+        // Grouped instances report the first instance's ID
+        let (id_select, group_str) = if group_instances {
+            (format!("MIN({id_col})"), name_col.to_string())
+        } else {
+            (id_col.to_string(), format!("{name_col}, {id_col}"))
+        };
+
         let batches = self
             .sql(&format!(
                 r#"
-            SELECT {name_col}, {id_col}, MIN({type_col}) as entity_type,
+            SELECT {name_col}, {id_select}, MIN({type_col}) as entity_type,
                    SUM({value_col}) as total_value,
                    COUNT(DISTINCT ability_id) as abilities_used
             FROM events {filter}
-            GROUP BY {name_col}, {id_col}
+            GROUP BY {group_str}
             ORDER BY total_value DESC
         "#
             ))
             .await?;
 
+        let instances = if group_instances {
+            HashMap::new()
+        } else {
+            self.npc_instance_numbers().await?
+        };
+
         let mut results = Vec::new();
         for batch in &batches {
             let names = col_strings(batch, 0)?;
@@ -270,9 +308,48 @@ impl EncounterQuery<'_> {
                     entity_type: entity_types[i].clone(),
                     total_value: totals[i],
                     abilities_used: abilities[i],
+                    instance: instances.get(&ids[i]).copied(),
                 });
             }
         }
         Ok(results)
     }
+
+    /// Number same-named NPC instances (by log ID) in order of first appearance.
+    ///
+    /// Numbering covers the whole encounter, so an instance keeps its number
+    /// across tabs and time ranges. NPCs with a unique name are left out.
+    async fn npc_instance_numbers(&self) -> Result<HashMap<i64, u32>, QueryError> {
+        let batches = self
+            .sql(
+                r#"
+            SELECT name, id, MIN(t) as first_seen
+            FROM (
+                SELECT source_name as name, source_id as id, combat_time_secs as t
+                FROM events WHERE source_entity_type = 'Npc'
+                UNION ALL
+                SELECT target_name as name, target_id as id, combat_time_secs as t
+                FROM events WHERE target_entity_type = 'Npc'
+            )
+            GROUP BY name, id
+            ORDER BY first_seen, id
+        "#,
+            )
+            .await?;
+
+        let mut by_name: HashMap<String, Vec<i64>> = HashMap::new();
+        for batch in &batches {
+            let names = col_strings(batch, 0)?;
+            let ids = col_i64(batch, 1)?;
+            for (name, id) in names.into_iter().zip(ids) {
+                by_name.entry(name).or_default().push(id);
+            }
+        }
+
+        Ok(by_name
+            .into_values()
+            .filter(|ids| ids.len() > 1)
+            .flat_map(|ids| ids.into_iter().zip(1..))
+            .collect())
+    }
 }
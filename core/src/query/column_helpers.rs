@@ -4,12 +4,14 @@ use datafusion::arrow::array::{
 };
 use datafusion::arrow::record_batch::RecordBatch;
 
+use super::QueryError;
+
 // Re-export query types from shared types crate
 // ─────────────────────────────────────────────────────────────────────────────
 // Generic Column Extractors (handles Arrow type variations automatically)
 // ─────────────────────────────────────────────────────────────────────────────
 
-pub fn col_strings(batch: &RecordBatch, idx: usize) -> Result<Vec<String>, String> {
+pub fn col_strings(batch: &RecordBatch, idx: usize) -> Result<Vec<String>, QueryError> {
     let col = batch.column(idx);
     if let Some(a) = col.as_any().downcast_ref::<StringViewArray>() {
         return Ok((0..a.len()).map(|i| a.value(i).to_string()).collect());
@@ -20,13 +22,14 @@ pub fn col_strings(batch: &RecordBatch, idx: usize) -> Result<Vec<String>, Strin
     if let Some(a) = col.as_any().downcast_ref::<LargeStringArray>() {
         return Ok((0..a.len()).map(|i| a.value(i).to_string()).collect());
     }
-    Err(format!(
-        "col {idx}: expected string, got {:?}",
-        col.data_type()
-    ))
+    Err(QueryError::UnexpectedColumnType {
+        name: format!("col {idx}"),
+        expected: "string",
+        actual: format!("{:?}", col.data_type()),
+    })
 }
 
-pub fn col_i64(batch: &RecordBatch, idx: usize) -> Result<Vec<i64>, String> {
+pub fn col_i64(batch: &RecordBatch, idx: usize) -> Result<Vec<i64>, QueryError> {
     let col = batch.column(idx);
     if let Some(a) = col.as_any().downcast_ref::<Int64Array>() {
         return Ok((0..a.len()).map(|i| a.value(i)).collect());
@@ -37,13 +40,14 @@ pub fn col_i64(batch: &RecordBatch, idx: usize) -> Result<Vec<i64>, String> {
     if let Some(a) = col.as_any().downcast_ref::<UInt64Array>() {
         return Ok((0..a.len()).map(|i| a.value(i) as i64).collect());
     }
-    Err(format!(
-        "col {idx}: expected int, got {:?}",
-        col.data_type()
-    ))
+    Err(QueryError::UnexpectedColumnType {
+        name: format!("col {idx}"),
+        expected: "int",
+        actual: format!("{:?}", col.data_type()),
+    })
 }
 
-pub fn col_f64(batch: &RecordBatch, idx: usize) -> Result<Vec<f64>, String> {
+pub fn col_f64(batch: &RecordBatch, idx: usize) -> Result<Vec<f64>, QueryError> {
     let col = batch.column(idx);
     if let Some(a) = col.as_any().downcast_ref::<Float64Array>() {
         return Ok((0..a.len()).map(|i| a.value(i)).collect());
@@ -57,13 +61,14 @@ pub fn col_f64(batch: &RecordBatch, idx: usize) -> Result<Vec<f64>, String> {
     if let Some(a) = col.as_any().downcast_ref::<Int32Array>() {
         return Ok((0..a.len()).map(|i| a.value(i) as f64).collect());
     }
-    Err(format!(
-        "col {idx}: expected float, got {:?}",
-        col.data_type()
-    ))
+    Err(QueryError::UnexpectedColumnType {
+        name: format!("col {idx}"),
+        expected: "float",
+        actual: format!("{:?}", col.data_type()),
+    })
 }
 
-pub fn col_f32(batch: &RecordBatch, idx: usize) -> Result<Vec<f32>, String> {
+pub fn col_f32(batch: &RecordBatch, idx: usize) -> Result<Vec<f32>, QueryError> {
     let col = batch.column(idx);
     if let Some(a) = col.as_any().downcast_ref::<Float32Array>() {
         return Ok((0..a.len()).map(|i| a.value(i)).collect());
@@ -71,10 +76,11 @@ pub fn col_f32(batch: &RecordBatch, idx: usize) -> Result<Vec<f32>, String> {
     if let Some(a) = col.as_any().downcast_ref::<Float64Array>() {
         return Ok((0..a.len()).map(|i| a.value(i) as f32).collect());
     }
-    Err(format!(
-        "col {idx}: expected float, got {:?}",
-        col.data_type()
-    ))
+    Err(QueryError::UnexpectedColumnType {
+        name: format!("col {idx}"),
+        expected: "float",
+        actual: format!("{:?}", col.data_type()),
+    })
 }
 
 pub fn scalar_f32(batches: &[RecordBatch]) -> f32 {
@@ -89,7 +95,7 @@ pub fn scalar_f32(batches: &[RecordBatch]) -> f32 {
         .unwrap_or(0.0)
 }
 
-pub fn col_i32(batch: &RecordBatch, idx: usize) -> Result<Vec<i32>, String> {
+pub fn col_i32(batch: &RecordBatch, idx: usize) -> Result<Vec<i32>, QueryError> {
     let col = batch.column(idx);
     if let Some(a) = col.as_any().downcast_ref::<Int32Array>() {
         return Ok((0..a.len()).map(|i| a.value(i)).collect());
@@ -97,19 +103,21 @@ pub fn col_i32(batch: &RecordBatch, idx: usize) -> Result<Vec<i32>, String> {
     if let Some(a) = col.as_any().downcast_ref::<Int64Array>() {
         return Ok((0..a.len()).map(|i| a.value(i) as i32).collect());
     }
-    Err(format!(
-        "col {idx}: expected i32, got {:?}",
-        col.data_type()
-    ))
+    Err(QueryError::UnexpectedColumnType {
+        name: format!("col {idx}"),
+        expected: "i32",
+        actual: format!("{:?}", col.data_type()),
+    })
 }
 
-pub fn col_bool(batch: &RecordBatch, idx: usize) -> Result<Vec<bool>, String> {
+pub fn col_bool(batch: &RecordBatch, idx: usize) -> Result<Vec<bool>, QueryError> {
     let col = batch.column(idx);
     if let Some(a) = col.as_any().downcast_ref::<arrow::array::BooleanArray>() {
         return Ok((0..a.len()).map(|i| a.value(i)).collect());
     }
-    Err(format!(
-        "col {idx}: expected bool, got {:?}",
-        col.data_type()
-    ))
+    Err(QueryError::UnexpectedColumnType {
+        name: format!("col {idx}"),
+        expected: "bool",
+        actual: format!("{:?}", col.data_type()),
+    })
 }
@@ -1,5 +1,9 @@
 //! Combat log viewer queries.
 
+use std::path::Path;
+
+use futures_util::StreamExt;
+
 use super::*;
 use crate::game_data::{effect_id, effect_type_id};
 
@@ -83,48 +87,8 @@ fn build_event_filter_clause(filters: &CombatLogFilters) -> Option<String> {
     Some(clause)
 }
 
-impl EncounterQuery<'_> {
-    /// Query combat log rows for the combat log viewer.
-    /// Supports pagination via offset/limit for virtual scrolling.
-    /// Returns rows ordered by combat_time_secs.
-    pub async fn query_combat_log(
-        &self,
-        offset: u64,
-        limit: u64,
-        source_filter: Option<&str>,
-        target_filter: Option<&str>,
-        search_filter: Option<&str>,
-        time_range: Option<&TimeRange>,
-        event_filters: Option<&CombatLogFilters>,
-    ) -> Result<Vec<CombatLogRow>, String> {
-        let mut where_clauses = vec!["combat_time_secs IS NOT NULL".to_string()];
-
-        if let Some(source) = source_filter {
-            where_clauses.push(format!("source_name = '{}'", sql_escape(source)));
-        }
-        if let Some(target) = target_filter {
-            where_clauses.push(format!("target_name = '{}'", sql_escape(target)));
-        }
-        if let Some(search) = search_filter {
-            if !search.is_empty() {
-                where_clauses.push(build_search_clause(search));
-            }
-        }
-        if let Some(tr) = time_range {
-            where_clauses.push(tr.sql_filter());
-        }
-        if let Some(filters) = event_filters {
-            if let Some(filter_clause) = build_event_filter_clause(filters) {
-                where_clauses.push(filter_clause);
-            }
-        }
-
-        let where_clause = where_clauses.join(" AND ");
-
-        let batches = self
-            .sql(&format!(
-                r#"
-            SELECT
+/// Columns selected for combat log rows (see [`combat_log_rows`])
+const COMBAT_LOG_COLUMNS: &str = r#"
                 line_number,
                 combat_time_secs,
                 source_name,
@@ -145,7 +109,119 @@ impl EncounterQuery<'_> {
                 effect_id,
                 effect_type_id,
                 source_class_id,
-                target_class_id
+                target_class_id"#;
+
+/// WHERE clause shared by the combat log row, count and export queries.
+fn build_where_clause(
+    source_filter: Option<&str>,
+    target_filter: Option<&str>,
+    search_filter: Option<&str>,
+    time_range: Option<&TimeRange>,
+    event_filters: Option<&CombatLogFilters>,
+) -> String {
+    let mut where_clauses = vec!["combat_time_secs IS NOT NULL".to_string()];
+
+    if let Some(source) = source_filter {
+        where_clauses.push(format!("source_name = '{}'", sql_escape(source)));
+    }
+    if let Some(target) = target_filter {
+        where_clauses.push(format!("target_name = '{}'", sql_escape(target)));
+    }
+    if let Some(search) = search_filter {
+        if !search.is_empty() {
+            where_clauses.push(build_search_clause(search));
+        }
+    }
+    if let Some(tr) = time_range {
+        where_clauses.push(tr.sql_filter());
+    }
+    if let Some(filters) = event_filters {
+        if let Some(filter_clause) = build_event_filter_clause(filters) {
+            where_clauses.push(filter_clause);
+        }
+    }
+
+    where_clauses.join(" AND ")
+}
+
+/// Convert a batch selected with [`COMBAT_LOG_COLUMNS`] to rows.
+fn combat_log_rows(batch: &RecordBatch) -> Result<Vec<CombatLogRow>, QueryError> {
+    let line_numbers = col_i64(batch, 0)?;
+    let times = col_f32(batch, 1)?;
+    let source_names = col_strings(batch, 2)?;
+    let source_types = col_strings(batch, 3)?;
+    let target_names = col_strings(batch, 4)?;
+    let target_types = col_strings(batch, 5)?;
+    let effect_types = col_strings(batch, 6)?;
+    let ability_names = col_strings(batch, 7)?;
+    let ability_ids = col_i64(batch, 8)?;
+    let effect_names = col_strings(batch, 9)?;
+    let values = col_i32(batch, 10)?;
+    let absorbeds = col_i32(batch, 11)?;
+    let overheals = col_i32(batch, 12)?;
+    let threats = col_f32(batch, 13)?;
+    let is_crits = col_bool(batch, 14)?;
+    let damage_types = col_strings(batch, 15)?;
+    let defense_type_ids = col_i64(batch, 16)?;
+
+    let effect_ids = col_i64(batch, 17)?;
+    let effect_type_ids = col_i64(batch, 18)?;
+    let source_class_ids = col_i64(batch, 19)?;
+    let target_class_ids = col_i64(batch, 20)?;
+
+    Ok((0..batch.num_rows())
+        .map(|i| CombatLogRow {
+            row_idx: line_numbers[i] as u64,
+            time_secs: times[i],
+            source_name: source_names[i].clone(),
+            source_type: source_types[i].clone(),
+            target_name: target_names[i].clone(),
+            target_type: target_types[i].clone(),
+            effect_type: effect_types[i].clone(),
+            ability_name: ability_names[i].clone(),
+            ability_id: ability_ids[i],
+            effect_name: effect_names[i].clone(),
+            value: values[i],
+            absorbed: absorbeds[i],
+            overheal: overheals[i],
+            threat: threats[i],
+            is_crit: is_crits[i],
+            damage_type: damage_types[i].clone(),
+            defense_type_id: defense_type_ids[i],
+            effect_id: effect_ids[i],
+            effect_type_id: effect_type_ids[i],
+            source_class_id: source_class_ids[i],
+            target_class_id: target_class_ids[i],
+        })
+        .collect())
+}
+
+impl EncounterQuery<'_> {
+    /// Query combat log rows for the combat log viewer.
+    /// Supports pagination via offset/limit for virtual scrolling.
+    /// Returns rows ordered by combat_time_secs.
+    pub async fn query_combat_log(
+        &self,
+        offset: u64,
+        limit: u64,
+        source_filter: Option<&str>,
+        target_filter: Option<&str>,
+        search_filter: Option<&str>,
+        time_range: Option<&TimeRange>,
+        event_filters: Option<&CombatLogFilters>,
+    ) -> Result<Vec<CombatLogRow>, QueryError> {
+        let where_clause = build_where_clause(
+            source_filter,
+            target_filter,
+            search_filter,
+            time_range,
+            event_filters,
+        );
+
+        let batches = self
+            .sql(&format!(
+                r#"
+            SELECT {COMBAT_LOG_COLUMNS}
             FROM events
             WHERE {where_clause}
             ORDER BY combat_time_secs
@@ -156,91 +232,65 @@ impl EncounterQuery<'_> {
 
         let mut results = Vec::new();
         for batch in &batches {
-            let num_rows = batch.num_rows();
-            let line_numbers = col_i64(batch, 0)?;
-            let times = col_f32(batch, 1)?;
-            let source_names = col_strings(batch, 2)?;
-            let source_types = col_strings(batch, 3)?;
-            let target_names = col_strings(batch, 4)?;
-            let target_types = col_strings(batch, 5)?;
-            let effect_types = col_strings(batch, 6)?;
-            let ability_names = col_strings(batch, 7)?;
-            let ability_ids = col_i64(batch, 8)?;
-            let effect_names = col_strings(batch, 9)?;
-            let values = col_i32(batch, 10)?;
-            let absorbeds = col_i32(batch, 11)?;
-            let overheals = col_i32(batch, 12)?;
-            let threats = col_f32(batch, 13)?;
-            let is_crits = col_bool(batch, 14)?;
-            let damage_types = col_strings(batch, 15)?;
-            let defense_type_ids = col_i64(batch, 16)?;
-
-            let effect_ids = col_i64(batch, 17)?;
-            let effect_type_ids = col_i64(batch, 18)?;
-            let source_class_ids = col_i64(batch, 19)?;
-            let target_class_ids = col_i64(batch, 20)?;
-
-            for i in 0..num_rows {
-                results.push(CombatLogRow {
-                    row_idx: line_numbers[i] as u64,
-                    time_secs: times[i],
-                    source_name: source_names[i].clone(),
-                    source_type: source_types[i].clone(),
-                    target_name: target_names[i].clone(),
-                    target_type: target_types[i].clone(),
-                    effect_type: effect_types[i].clone(),
-                    ability_name: ability_names[i].clone(),
-                    ability_id: ability_ids[i],
-                    effect_name: effect_names[i].clone(),
-                    value: values[i],
-                    absorbed: absorbeds[i],
-                    overheal: overheals[i],
-                    threat: threats[i],
-                    is_crit: is_crits[i],
-                    damage_type: damage_types[i].clone(),
-                    defense_type_id: defense_type_ids[i],
-                    effect_id: effect_ids[i],
-                    effect_type_id: effect_type_ids[i],
-                    source_class_id: source_class_ids[i],
-                    target_class_id: target_class_ids[i],
-                });
-            }
+            results.extend(combat_log_rows(batch)?);
         }
         Ok(results)
     }
 
-    /// Get total count of combat log rows (for pagination).
-    pub async fn query_combat_log_count(
+    /// Write every combat log row matching the filters (from `offset` on) to
+    /// an export file, streaming batch by batch. Returns the number of rows.
+    pub async fn export_combat_log(
         &self,
+        offset: u64,
         source_filter: Option<&str>,
         target_filter: Option<&str>,
         search_filter: Option<&str>,
         time_range: Option<&TimeRange>,
         event_filters: Option<&CombatLogFilters>,
-    ) -> Result<u64, String> {
-        let mut where_clauses = vec!["combat_time_secs IS NOT NULL".to_string()];
+        format: ExportFormat,
+        path: &Path,
+    ) -> Result<usize, QueryError> {
+        let where_clause = build_where_clause(
+            source_filter,
+            target_filter,
+            search_filter,
+            time_range,
+            event_filters,
+        );
 
-        if let Some(source) = source_filter {
-            where_clauses.push(format!("source_name = '{}'", sql_escape(source)));
-        }
-        if let Some(target) = target_filter {
-            where_clauses.push(format!("target_name = '{}'", sql_escape(target)));
-        }
-        if let Some(search) = search_filter {
-            if !search.is_empty() {
-                where_clauses.push(build_search_clause(search));
-            }
-        }
-        if let Some(tr) = time_range {
-            where_clauses.push(tr.sql_filter());
-        }
-        if let Some(filters) = event_filters {
-            if let Some(filter_clause) = build_event_filter_clause(filters) {
-                where_clauses.push(filter_clause);
-            }
+        let mut stream = self
+            .ctx
+            .sql(&format!(
+                "SELECT {COMBAT_LOG_COLUMNS} FROM events WHERE {where_clause} \
+                 ORDER BY combat_time_secs OFFSET {offset}"
+            ))
+            .await?
+            .execute_stream()
+            .await?;
+
+        let mut writer = ExportWriter::create(path, format)?;
+        while let Some(batch) = stream.next().await {
+            writer.write_rows(&combat_log_rows(&batch?)?)?;
         }
+        writer.finish()
+    }
 
-        let where_clause = where_clauses.join(" AND ");
+    /// Get total count of combat log rows (for pagination).
+    pub async fn query_combat_log_count(
+        &self,
+        source_filter: Option<&str>,
+        target_filter: Option<&str>,
+        search_filter: Option<&str>,
+        time_range: Option<&TimeRange>,
+        event_filters: Option<&CombatLogFilters>,
+    ) -> Result<u64, QueryError> {
+        let where_clause = build_where_clause(
+            source_filter,
+            target_filter,
+            search_filter,
+            time_range,
+            event_filters,
+        );
 
         let batches = self
             .sql(&format!("SELECT COUNT(*) FROM events WHERE {where_clause}"))
@@ -256,7 +306,7 @@ impl EncounterQuery<'_> {
     }
 
     /// Get distinct source names for filter dropdown.
-    pub async fn query_source_names(&self) -> Result<Vec<String>, String> {
+    pub async fn query_source_names(&self) -> Result<Vec<String>, QueryError> {
         let batches = self
             .sql(
                 "SELECT DISTINCT source_name FROM events WHERE combat_time_secs IS NOT NULL ORDER BY source_name",
@@ -271,7 +321,7 @@ impl EncounterQuery<'_> {
     }
 
     /// Get distinct target names for filter dropdown.
-    pub async fn query_target_names(&self) -> Result<Vec<String>, String> {
+    pub async fn query_target_names(&self) -> Result<Vec<String>, QueryError> {
         let batches = self
             .sql(
                 "SELECT DISTINCT target_name FROM events WHERE combat_time_secs IS NOT NULL ORDER BY target_name",
@@ -296,30 +346,19 @@ impl EncounterQuery<'_> {
         target_filter: Option<&str>,
         time_range: Option<&TimeRange>,
         event_filters: Option<&CombatLogFilters>,
-    ) -> Result<Vec<CombatLogFindMatch>, String> {
+    ) -> Result<Vec<CombatLogFindMatch>, QueryError> {
         if find_text.is_empty() {
             return Ok(vec![]);
         }
 
         // Build base WHERE clause (same filters as main query)
-        let mut where_clauses = vec!["combat_time_secs IS NOT NULL".to_string()];
-
-        if let Some(source) = source_filter {
-            where_clauses.push(format!("source_name = '{}'", sql_escape(source)));
-        }
-        if let Some(target) = target_filter {
-            where_clauses.push(format!("target_name = '{}'", sql_escape(target)));
-        }
-        if let Some(tr) = time_range {
-            where_clauses.push(tr.sql_filter());
-        }
-        if let Some(filters) = event_filters {
-            if let Some(filter_clause) = build_event_filter_clause(filters) {
-                where_clauses.push(filter_clause);
-            }
-        }
-
-        let base_where = where_clauses.join(" AND ");
+        let base_where = build_where_clause(
+            source_filter,
+            target_filter,
+            None,
+            time_range,
+            event_filters,
+        );
 
         // Find text filter - use COALESCE to handle NULLs
         let find_lower = sql_escape(find_text).to_lowercase();
@@ -0,0 +1,293 @@
+//! Pull comparison: per-player DPS, phase durations and boss ability hits
+//! for two pulls of the same encounter.
+
+use std::collections::HashMap;
+
+use super::*;
+
+/// Everything [`diff_pulls`] needs from a single pull.
+#[derive(Debug, Clone, Default)]
+pub struct PullSummary {
+    pub duration_secs: f32,
+    /// (player name, DPS) for players only
+    pub player_dps: Vec<(String, f64)>,
+    pub deaths: Vec<PlayerDeath>,
+    pub phases: Vec<PhaseSegment>,
+    /// NPC ability hits on players: (ability id, ability name, hits, damage)
+    pub mechanic_hits: Vec<(i64, String, u32, f64)>,
+}
+
+impl EncounterQuery<'_> {
+    /// Collect the data compared between pulls for the registered encounter.
+    pub async fn pull_summary(&self) -> Result<PullSummary, QueryError> {
+        let timeline = self.encounter_timeline().await?;
+        let player_dps = self
+            .query_raid_overview(None, Some(timeline.duration_secs))
+            .await?
+            .into_iter()
+            .filter(|row| row.entity_type == "Player")
+            .map(|row| (row.name, row.dps))
+            .collect();
+        let deaths = self.query_player_deaths().await?;
+
+        let batches = self
+            .sql(
+                r#"
+            SELECT ability_id, MAX(ability_name) as ability_name,
+                   COUNT(*) as hits, SUM(dmg_amount) as damage
+            FROM events
+            WHERE source_entity_type = 'Npc'
+              AND target_entity_type = 'Player'
+              AND dmg_amount > 0
+            GROUP BY ability_id
+        "#,
+            )
+            .await?;
+
+        let mut mechanic_hits = Vec::new();
+        for batch in &batches {
+            let ids = col_i64(batch, 0)?;
+            let names = col_strings(batch, 1)?;
+            let hits = col_i64(batch, 2)?;
+            let damage = col_f64(batch, 3)?;
+            for i in 0..batch.num_rows() {
+                mechanic_hits.push((ids[i], names[i].clone(), hits[i] as u32, damage[i]));
+            }
+        }
+
+        Ok(PullSummary {
+            duration_secs: timeline.duration_secs,
+            player_dps,
+            deaths,
+            phases: timeline.phases,
+            mechanic_hits,
+        })
+    }
+}
+
+impl QueryContext {
+    /// Compare two encounter parquet files (pull A against pull B).
+    ///
+    /// Registers each file in turn, so the context is left pointing at `encounter_b`.
+    pub async fn compare_pulls(
+        &self,
+        encounter_a: &Path,
+        encounter_b: &Path,
+    ) -> Result<PullComparison, QueryError> {
+        self.register_parquet(encounter_a).await?;
+        let a = self.query().await.query().pull_summary().await?;
+        self.register_parquet(encounter_b).await?;
+        let b = self.query().await.query().pull_summary().await?;
+        Ok(diff_pulls(&a, &b))
+    }
+}
+
+fn death_counts(deaths: &[PlayerDeath]) -> HashMap<&str, u32> {
+    let mut counts = HashMap::new();
+    for death in deaths {
+        *counts.entry(death.name.as_str()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Match up players, phase occurrences and boss abilities between two pulls.
+///
+/// Players are ordered by their best DPS across both pulls, phases by when they
+/// first occur, and abilities by how many more hits landed in pull B.
+pub fn diff_pulls(a: &PullSummary, b: &PullSummary) -> PullComparison {
+    // Players
+    let deaths_a = death_counts(&a.deaths);
+    let deaths_b = death_counts(&b.deaths);
+    let mut players: Vec<PlayerPullDelta> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for (name, dps) in &a.player_dps {
+        index.insert(name, players.len());
+        players.push(PlayerPullDelta {
+            name: name.clone(),
+            dps_a: Some(*dps),
+            dps_b: None,
+            deaths_a: 0,
+            deaths_b: 0,
+        });
+    }
+    for (name, dps) in &b.player_dps {
+        match index.get(name.as_str()) {
+            Some(&i) => players[i].dps_b = Some(*dps),
+            None => {
+                index.insert(name, players.len());
+                players.push(PlayerPullDelta {
+                    name: name.clone(),
+                    dps_a: None,
+                    dps_b: Some(*dps),
+                    deaths_a: 0,
+                    deaths_b: 0,
+                });
+            }
+        }
+    }
+    for player in &mut players {
+        player.deaths_a = deaths_a.get(player.name.as_str()).copied().unwrap_or(0);
+        player.deaths_b = deaths_b.get(player.name.as_str()).copied().unwrap_or(0);
+    }
+    let best = |p: &PlayerPullDelta| p.dps_a.unwrap_or(0.0).max(p.dps_b.unwrap_or(0.0));
+    players.sort_by(|x, y| best(y).total_cmp(&best(x)));
+
+    // Phases (matched by phase id and occurrence)
+    let mut phases: Vec<PhasePullDelta> = a
+        .phases
+        .iter()
+        .map(|seg| PhasePullDelta {
+            phase_id: seg.phase_id.clone(),
+            phase_name: seg.phase_name.clone(),
+            instance: seg.instance,
+            duration_a: Some(seg.end_secs - seg.start_secs),
+            duration_b: None,
+        })
+        .collect();
+    for seg in &b.phases {
+        let duration = Some(seg.end_secs - seg.start_secs);
+        match phases
+            .iter_mut()
+            .find(|p| p.phase_id == seg.phase_id && p.instance == seg.instance)
+        {
+            Some(phase) => phase.duration_b = duration,
+            None => phases.push(PhasePullDelta {
+                phase_id: seg.phase_id.clone(),
+                phase_name: seg.phase_name.clone(),
+                instance: seg.instance,
+                duration_a: None,
+                duration_b: duration,
+            }),
+        }
+    }
+
+    // Boss abilities hitting players
+    let mut mechanics: Vec<MechanicPullDelta> = a
+        .mechanic_hits
+        .iter()
+        .map(|(id, name, hits, damage)| MechanicPullDelta {
+            ability_id: *id,
+            ability_name: name.clone(),
+            hits_a: *hits,
+            hits_b: 0,
+            damage_a: *damage,
+            damage_b: 0.0,
+        })
+        .collect();
+    for (id, name, hits, damage) in &b.mechanic_hits {
+        match mechanics.iter_mut().find(|m| m.ability_id == *id) {
+            Some(m) => {
+                m.hits_b = *hits;
+                m.damage_b = *damage;
+            }
+            None => mechanics.push(MechanicPullDelta {
+                ability_id: *id,
+                ability_name: name.clone(),
+                hits_a: 0,
+                hits_b: *hits,
+                damage_a: 0.0,
+                damage_b: *damage,
+            }),
+        }
+    }
+    mechanics.sort_by(|x, y| {
+        let dx = x.hits_b as i64 - x.hits_a as i64;
+        let dy = y.hits_b as i64 - y.hits_a as i64;
+        dy.cmp(&dx)
+            .then_with(|| (y.damage_b - y.damage_a).total_cmp(&(x.damage_b - x.damage_a)))
+    });
+
+    PullComparison {
+        duration_a: a.duration_secs,
+        duration_b: b.duration_secs,
+        players,
+        phases,
+        mechanics,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(id: &str, instance: i64, start: f32, end: f32) -> PhaseSegment {
+        PhaseSegment {
+            phase_id: id.to_string(),
+            phase_name: id.to_uppercase(),
+            instance,
+            start_secs: start,
+            end_secs: end,
+        }
+    }
+
+    #[test]
+    fn players_are_matched_by_name() {
+        let a = PullSummary {
+            player_dps: vec![("Alice".into(), 9000.0), ("Bob".into(), 7000.0)],
+            deaths: vec![PlayerDeath {
+                name: "Bob".into(),
+                death_time_secs: 120.0,
+            }],
+            ..Default::default()
+        };
+        let b = PullSummary {
+            player_dps: vec![("Bob".into(), 9500.0), ("Carol".into(), 8000.0)],
+            ..Default::default()
+        };
+
+        let diff = diff_pulls(&a, &b);
+        let names: Vec<_> = diff.players.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["Bob", "Alice", "Carol"]);
+
+        let bob = &diff.players[0];
+        assert_eq!(bob.dps_delta(), 2500.0);
+        assert_eq!((bob.deaths_a, bob.deaths_b), (1, 0));
+        assert_eq!(diff.players[1].dps_b, None);
+        assert_eq!(diff.players[2].dps_a, None);
+    }
+
+    #[test]
+    fn phases_are_matched_by_id_and_instance() {
+        let a = PullSummary {
+            phases: vec![segment("p1", 1, 0.0, 60.0), segment("p2", 1, 60.0, 100.0)],
+            ..Default::default()
+        };
+        let b = PullSummary {
+            phases: vec![
+                segment("p1", 1, 0.0, 50.0),
+                segment("p2", 1, 50.0, 80.0),
+                segment("p1", 2, 80.0, 90.0),
+            ],
+            ..Default::default()
+        };
+
+        let diff = diff_pulls(&a, &b);
+        assert_eq!(diff.phases.len(), 3);
+        assert_eq!(diff.phases[0].duration_a, Some(60.0));
+        assert_eq!(diff.phases[0].duration_b, Some(50.0));
+        assert_eq!(diff.phases[2].instance, 2);
+        assert_eq!(diff.phases[2].duration_a, None);
+    }
+
+    #[test]
+    fn mechanics_with_more_hits_in_b_come_first() {
+        let a = PullSummary {
+            mechanic_hits: vec![
+                (1, "Slam".into(), 8, 80_000.0),
+                (2, "Pool".into(), 2, 10_000.0),
+            ],
+            ..Default::default()
+        };
+        let b = PullSummary {
+            mechanic_hits: vec![
+                (1, "Slam".into(), 8, 82_000.0),
+                (2, "Pool".into(), 9, 45_000.0),
+            ],
+            ..Default::default()
+        };
+
+        let diff = diff_pulls(&a, &b);
+        assert_eq!(diff.mechanics[0].ability_name, "Pool");
+        assert_eq!((diff.mechanics[0].hits_a, diff.mechanics[0].hits_b), (2, 9));
+    }
+}
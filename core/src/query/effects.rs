@@ -22,7 +22,7 @@ impl EncounterQuery<'_> {
         target_name: Option<&str>,
         time_range: Option<&TimeRange>,
         duration_secs: f32,
-    ) -> Result<Vec<EffectChartData>, String> {
+    ) -> Result<Vec<EffectChartData>, QueryError> {
         let target_filter = target_name
             .map(|n| format!("AND target_name = '{}'", sql_escape(n)))
             .unwrap_or_default();
@@ -144,7 +144,7 @@ impl EncounterQuery<'_> {
         target_name: Option<&str>,
         time_range: Option<&TimeRange>,
         duration_secs: f32,
-    ) -> Result<Vec<EffectWindow>, String> {
+    ) -> Result<Vec<EffectWindow>, QueryError> {
         let target_filter = target_name
             .map(|n| format!("AND target_name = '{}'", sql_escape(n)))
             .unwrap_or_default();
@@ -1,5 +1,6 @@
 //! Error types for data queries
 
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// Errors during data queries
@@ -24,6 +25,18 @@ pub enum QueryError {
     #[error("no data available for query")]
     NoData,
 
+    #[error("no active session")]
+    NoSession,
+
+    #[error("session has no encounters directory")]
+    NoEncountersDir,
+
+    #[error("encounter file not found: {path}")]
+    EncounterNotFound { path: PathBuf },
+
+    #[error("live encounter buffer is empty")]
+    NoLiveData,
+
     #[error("failed to register parquet file: {path}")]
     RegisterParquet {
         path: String,
@@ -31,6 +44,13 @@ pub enum QueryError {
         source: datafusion::error::DataFusionError,
     },
 
+    #[error("failed to write export file: {path}")]
+    Export {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("SQL execution failed: {query}")]
     SqlExecution {
         query: String,
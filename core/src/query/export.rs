@@ -0,0 +1,251 @@
+//! Export of query results (Data Explorer tables) to CSV or JSON files.
+//!
+//! Rows are written as they arrive, so large results such as the full combat
+//! log can be streamed batch by batch without holding them in memory.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::{ExportFormat, QueryError};
+
+/// Writes rows of any serializable query result type to an export file.
+///
+/// CSV columns are the fields of the first rows written, in declaration
+/// order; missing or null values are left empty. JSON output is an array of
+/// row objects.
+pub struct ExportWriter {
+    out: BufWriter<File>,
+    path: PathBuf,
+    format: ExportFormat,
+    /// CSV header, set by the first non-empty write
+    columns: Option<Vec<String>>,
+    rows: usize,
+}
+
+impl ExportWriter {
+    /// Create (or truncate) the export file at `path`
+    pub fn create(path: &Path, format: ExportFormat) -> Result<Self, QueryError> {
+        let file = File::create(path).map_err(|source| QueryError::Export {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let mut writer = Self {
+            out: BufWriter::new(file),
+            path: path.to_path_buf(),
+            format,
+            columns: None,
+            rows: 0,
+        };
+        if format == ExportFormat::Json {
+            writer.io(|out| out.write_all(b"["))?;
+        }
+        Ok(writer)
+    }
+
+    /// Append rows to the file
+    pub fn write_rows<T: Serialize>(&mut self, rows: &[T]) -> Result<(), QueryError> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let values = rows
+            .iter()
+            .map(row_object)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| self.error(e.into()))?;
+
+        match self.format {
+            ExportFormat::Csv => {
+                let columns = self
+                    .columns
+                    .get_or_insert_with(|| csv_columns(&values))
+                    .clone();
+                if self.rows == 0 {
+                    self.io(|out| write_csv_record(out, columns.iter().map(String::as_str)))?;
+                }
+                for row in &values {
+                    let cells: Vec<String> = columns
+                        .iter()
+                        .map(|c| csv_cell(row.get(c).unwrap_or(&Value::Null)))
+                        .collect();
+                    self.io(|out| write_csv_record(out, cells.iter().map(String::as_str)))?;
+                }
+            }
+            ExportFormat::Json => {
+                for (i, row) in values.into_iter().enumerate() {
+                    let separator: &[u8] = if self.rows + i == 0 {
+                        b"\n  "
+                    } else {
+                        b",\n  "
+                    };
+                    self.io(|out| {
+                        out.write_all(separator)?;
+                        serde_json::to_writer(&mut *out, &row).map_err(io::Error::from)
+                    })?;
+                }
+            }
+        }
+        self.rows += rows.len();
+        Ok(())
+    }
+
+    /// Finish the file, returning the number of rows written
+    pub fn finish(mut self) -> Result<usize, QueryError> {
+        if self.format == ExportFormat::Json {
+            let tail: &[u8] = if self.rows == 0 { b"]\n" } else { b"\n]\n" };
+            self.io(|out| out.write_all(tail))?;
+        }
+        self.io(|out| out.flush())?;
+        Ok(self.rows)
+    }
+
+    fn io(
+        &mut self,
+        f: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>,
+    ) -> Result<(), QueryError> {
+        f(&mut self.out).map_err(|e| self.error(e))
+    }
+
+    fn error(&self, source: io::Error) -> QueryError {
+        QueryError::Export {
+            path: self.path.clone(),
+            source,
+        }
+    }
+}
+
+/// Write query results to `path`, returning the number of rows written
+pub fn export_query_results<T: Serialize>(
+    rows: &[T],
+    format: ExportFormat,
+    path: &Path,
+) -> Result<usize, QueryError> {
+    let mut writer = ExportWriter::create(path, format)?;
+    writer.write_rows(rows)?;
+    writer.finish()
+}
+
+/// Serialize a row to a JSON object (non-struct rows become a `value` column)
+fn row_object<T: Serialize>(row: &T) -> Result<Map<String, Value>, serde_json::Error> {
+    Ok(match serde_json::to_value(row)? {
+        Value::Object(map) => map,
+        other => Map::from_iter([("value".to_string(), other)]),
+    })
+}
+
+/// Union of the keys of all rows, in order of first appearance (optional
+/// fields are left out of rows where they are unset)
+fn csv_columns(rows: &[Map<String, Value>]) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for key in rows.iter().flat_map(|row| row.keys()) {
+        if !columns.contains(key) {
+            columns.push(key.clone());
+        }
+    }
+    columns
+}
+
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn write_csv_record<'a>(
+    out: &mut impl Write,
+    cells: impl Iterator<Item = &'a str>,
+) -> io::Result<()> {
+    for (i, cell) in cells.enumerate() {
+        if i > 0 {
+            out.write_all(b",")?;
+        }
+        if cell.contains([',', '"', '\n', '\r']) {
+            write!(out, "\"{}\"", cell.replace('"', "\"\""))?;
+        } else {
+            out.write_all(cell.as_bytes())?;
+        }
+    }
+    out.write_all(b"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Row {
+        name: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target: Option<&'static str>,
+        total: f64,
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("baras-export-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn csv_header_is_union_of_fields_and_cells_are_quoted() {
+        let path = temp_path("rows.csv");
+        let rows = [
+            Row {
+                name: "Shock",
+                target: None,
+                total: 1500.0,
+            },
+            Row {
+                name: "Force \"Lightning\", channeled",
+                target: Some("Brontes"),
+                total: 20.5,
+            },
+        ];
+        assert_eq!(
+            export_query_results(&rows, ExportFormat::Csv, &path).unwrap(),
+            2
+        );
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            text,
+            "name,total,target\nShock,1500.0,\n\"Force \"\"Lightning\"\", channeled\",20.5,Brontes\n"
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn json_streams_rows_across_writes() {
+        let path = temp_path("rows.json");
+        let mut writer = ExportWriter::create(&path, ExportFormat::Json).unwrap();
+        for total in [1.0, 2.0] {
+            writer
+                .write_rows(&[Row {
+                    name: "Shock",
+                    target: None,
+                    total,
+                }])
+                .unwrap();
+        }
+        assert_eq!(writer.finish().unwrap(), 2);
+
+        let value: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(value[1]["total"], 2.0);
+        assert_eq!(value.as_array().unwrap().len(), 2);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn empty_json_export_is_an_empty_array() {
+        let path = temp_path("empty.json");
+        assert_eq!(
+            export_query_results::<Row>(&[], ExportFormat::Json, &path).unwrap(),
+            0
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "[]\n");
+        let _ = std::fs::remove_file(&path);
+    }
+}
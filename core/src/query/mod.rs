@@ -3,13 +3,21 @@
 //! Provides SQL queries over:
 //! - Live Arrow buffers (current encounter)
 //! - Historical parquet files (completed encounters)
+//!
+//! Queries read the `events` table, which leaves out ignored abilities/effects
+//! (see [`IgnoreList`]).
 
+mod aggregate;
+mod assignments;
 mod breakdown;
 mod column_helpers;
 mod combat_log;
+mod compare;
 mod effects;
 pub mod error;
+mod export;
 mod overview;
+mod pair;
 mod time_series;
 mod timeline;
 
@@ -25,13 +33,27 @@ use datafusion::prelude::*;
 
 use column_helpers::*;
 
+use crate::context::IgnoreList;
+
 // Re-export query types from shared types crate
 pub use baras_types::{
-    AbilityBreakdown, BreakdownMode, CombatLogFilters, CombatLogFindMatch, CombatLogRow, DataTab,
-    EffectChartData, EffectWindow, EncounterTimeline, EntityBreakdown, PhaseSegment, PlayerDeath,
-    RaidOverviewRow, TimeRange, TimeSeriesPoint,
+    AbilityBreakdown, BigHit, BreakdownDelta, BreakdownMode, CombatLogFilters, CombatLogFindMatch,
+    CombatLogRow, DataTab, EffectChartData, EffectWindow, EncounterTimeline, EntityBreakdown,
+    ExportFormat, ExportTable, MechanicPullDelta, PhasePullDelta, PhaseSegment, PlayerAggregate,
+    PlayerDeath, PlayerPullDelta, PullComparison, RaidOverviewRow, SessionAggregate, TimeRange,
+    TimeSeriesPairPoint, TimeSeriesPoint,
 };
 
+pub use aggregate::{PULLS_TABLE, PullPlayerStats, aggregate_pulls};
+pub use compare::{PullSummary, diff_pulls};
+pub use export::{ExportWriter, export_query_results};
+pub use pair::{PAIR_TABLE_A, PAIR_TABLE_B, merge_breakdowns, merge_time_series};
+
+/// Render query results as an ASCII table (for CLI output)
+pub fn format_batches(batches: &[RecordBatch]) -> Result<String, QueryError> {
+    Ok(datafusion::arrow::util::pretty::pretty_format_batches(batches)?.to_string())
+}
+
 /// Escape single quotes for SQL string literals (O'Brien -> O''Brien)
 fn sql_escape(s: &str) -> String {
     s.replace('\'', "''")
@@ -48,6 +70,10 @@ enum RegisteredSource {
     None,
     /// Parquet file at the given path
     Parquet(std::path::PathBuf),
+    /// Two parquet files registered side by side (`events_a`, `events_b`)
+    ParquetPair(std::path::PathBuf, std::path::PathBuf),
+    /// Several parquet files combined into one `pulls` table
+    ParquetSet(Vec<std::path::PathBuf>),
     /// Live in-memory batch (changes frequently, always re-register)
     Live,
 }
@@ -56,8 +82,15 @@ enum RegisteredSource {
 struct QueryContextState {
     ctx: SessionContext,
     current_source: RegisteredSource,
+    /// In-memory size of the registered live batch (0 for parquet sources)
+    live_batch_bytes: usize,
+    /// Abilities/effects filtered out of the `events` table
+    ignore_list: IgnoreList,
 }
 
+/// Table holding the raw registered data when `events` is a filtered view
+const UNFILTERED_TABLE: &str = "events_unfiltered";
+
 /// Shared query context that manages DataFusion SessionContext lifecycle.
 ///
 /// Key design decisions to minimize memory growth:
@@ -84,20 +117,62 @@ fn create_session_context() -> SessionContext {
     SessionContext::new_with_config(config.into())
 }
 
+/// Expose `UNFILTERED_TABLE` as `events`, leaving out ignored abilities/effects
+async fn register_events_view(
+    ctx: &SessionContext,
+    ignore_list: &IgnoreList,
+) -> Result<(), QueryError> {
+    register_filtered_view(ctx, "events", UNFILTERED_TABLE, ignore_list).await
+}
+
+/// Expose `source` as `view`, leaving out ignored abilities/effects.
+/// No-op when the ignore list is empty (the data is registered as `view` directly).
+async fn register_filtered_view(
+    ctx: &SessionContext,
+    view: &str,
+    source: &str,
+    ignore_list: &IgnoreList,
+) -> Result<(), QueryError> {
+    let Some(predicate) = ignore_list.sql_predicate() else {
+        return Ok(());
+    };
+    let filtered = ctx
+        .sql(&format!("SELECT * FROM {source} WHERE {predicate}"))
+        .await?
+        .into_view();
+    ctx.register_table(view, filtered)?;
+    Ok(())
+}
+
 impl QueryContext {
     pub fn new() -> Self {
+        Self::with_ignore_list(IgnoreList::default())
+    }
+
+    pub fn with_ignore_list(ignore_list: IgnoreList) -> Self {
         Self {
             state: tokio::sync::RwLock::new(QueryContextState {
                 ctx: create_session_context(),
                 current_source: RegisteredSource::None,
+                live_batch_bytes: 0,
+                ignore_list,
             }),
         }
     }
 
+    /// Replace the ignore list. The next registration rebuilds the `events` table.
+    pub async fn set_ignore_list(&self, ignore_list: IgnoreList) {
+        let mut state = self.state.write().await;
+        if state.ignore_list != ignore_list {
+            state.ignore_list = ignore_list;
+            state.current_source = RegisteredSource::None;
+        }
+    }
+
     /// Register a parquet file for querying.
     /// - If same file is already registered: no-op (fast path)
     /// - If different file: creates a FRESH SessionContext to clear all caches
-    pub async fn register_parquet(&self, path: &Path) -> Result<(), String> {
+    pub async fn register_parquet(&self, path: &Path) -> Result<(), QueryError> {
         // Fast path: check if already registered (read lock only)
         {
             let state = self.state.read().await;
@@ -122,37 +197,155 @@ impl QueryContext {
         // This prevents memory accumulation from cached query plans, statistics, etc.
         state.ctx = create_session_context();
 
+        let table = if state.ignore_list.is_empty() {
+            "events"
+        } else {
+            UNFILTERED_TABLE
+        };
         state
             .ctx
             .register_parquet(
-                "events",
+                table,
                 path.to_string_lossy().as_ref(),
                 ParquetReadOptions::default(),
             )
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(|source| QueryError::RegisterParquet {
+                path: path.display().to_string(),
+                source,
+            })?;
+        register_events_view(&state.ctx, &state.ignore_list).await?;
 
         state.current_source = RegisteredSource::Parquet(path.to_path_buf());
+        state.live_batch_bytes = 0;
+        Ok(())
+    }
+
+    /// Register two parquet files side by side as `events_a` and `events_b`
+    /// for comparison queries. The `events` table is not available meanwhile.
+    /// - If the same pair is already registered: no-op (fast path)
+    /// - Otherwise: creates a FRESH SessionContext, like [`Self::register_parquet`]
+    pub async fn register_parquet_pair(&self, a: &Path, b: &Path) -> Result<(), QueryError> {
+        let source = RegisteredSource::ParquetPair(a.to_path_buf(), b.to_path_buf());
+        if self.state.read().await.current_source == source {
+            return Ok(());
+        }
+
+        let mut state = self.state.write().await;
+        if state.current_source == source {
+            return Ok(());
+        }
+
+        state.ctx = create_session_context();
+        for (view, path) in [(PAIR_TABLE_A, a), (PAIR_TABLE_B, b)] {
+            let table = if state.ignore_list.is_empty() {
+                view.to_string()
+            } else {
+                format!("{view}_unfiltered")
+            };
+            state
+                .ctx
+                .register_parquet(
+                    &table,
+                    path.to_string_lossy().as_ref(),
+                    ParquetReadOptions::default(),
+                )
+                .await
+                .map_err(|source| QueryError::RegisterParquet {
+                    path: path.display().to_string(),
+                    source,
+                })?;
+            register_filtered_view(&state.ctx, view, &table, &state.ignore_list).await?;
+        }
+
+        state.current_source = source;
+        state.live_batch_bytes = 0;
+        Ok(())
+    }
+
+    /// Register several parquet files as one [`PULLS_TABLE`], with a `pull`
+    /// column holding each row's index in `paths`. Used for statistics across
+    /// pulls; the `events` table is not available meanwhile.
+    /// - If the same set is already registered: no-op (fast path)
+    /// - Otherwise: creates a FRESH SessionContext, like [`Self::register_parquet`]
+    pub async fn register_parquet_set(
+        &self,
+        paths: &[std::path::PathBuf],
+    ) -> Result<(), QueryError> {
+        let source = RegisteredSource::ParquetSet(paths.to_vec());
+        if self.state.read().await.current_source == source {
+            return Ok(());
+        }
+
+        let mut state = self.state.write().await;
+        if state.current_source == source {
+            return Ok(());
+        }
+
+        state.ctx = create_session_context();
+        let mut selects = Vec::with_capacity(paths.len());
+        for (i, path) in paths.iter().enumerate() {
+            let view = format!("pull_{i}");
+            let table = if state.ignore_list.is_empty() {
+                view.clone()
+            } else {
+                format!("{view}_unfiltered")
+            };
+            state
+                .ctx
+                .register_parquet(
+                    &table,
+                    path.to_string_lossy().as_ref(),
+                    ParquetReadOptions::default(),
+                )
+                .await
+                .map_err(|source| QueryError::RegisterParquet {
+                    path: path.display().to_string(),
+                    source,
+                })?;
+            register_filtered_view(&state.ctx, &view, &table, &state.ignore_list).await?;
+            selects.push(format!(
+                "SELECT CAST({i} AS BIGINT) AS pull, {} FROM {view}",
+                aggregate::PULLS_COLUMNS
+            ));
+        }
+        if !selects.is_empty() {
+            let pulls = state
+                .ctx
+                .sql(&selects.join(" UNION ALL "))
+                .await?
+                .into_view();
+            state.ctx.register_table(PULLS_TABLE, pulls)?;
+        }
+
+        state.current_source = source;
+        state.live_batch_bytes = 0;
         Ok(())
     }
 
     /// Register a RecordBatch for querying (live data).
     /// Always re-registers since live data changes frequently.
-    pub async fn register_batch(&self, batch: RecordBatch) -> Result<(), String> {
+    pub async fn register_batch(&self, batch: RecordBatch) -> Result<(), QueryError> {
         let mut state = self.state.write().await;
 
         // For live data, just deregister and re-register (don't create fresh context
         // since this happens frequently during combat)
         let _ = state.ctx.deregister_table("events");
+        let _ = state.ctx.deregister_table(UNFILTERED_TABLE);
 
+        let batch_bytes = batch.get_array_memory_size();
         let schema = batch.schema();
-        let mem_table = MemTable::try_new(schema, vec![vec![batch]]).map_err(|e| e.to_string())?;
-        state
-            .ctx
-            .register_table("events", Arc::new(mem_table))
-            .map_err(|e| e.to_string())?;
+        let mem_table = MemTable::try_new(schema, vec![vec![batch]])?;
+        let table = if state.ignore_list.is_empty() {
+            "events"
+        } else {
+            UNFILTERED_TABLE
+        };
+        state.ctx.register_table(table, Arc::new(mem_table))?;
+        register_events_view(&state.ctx, &state.ignore_list).await?;
 
         state.current_source = RegisteredSource::Live;
+        state.live_batch_bytes = batch_bytes;
         Ok(())
     }
 
@@ -162,6 +355,14 @@ impl QueryContext {
         let mut state = self.state.write().await;
         state.ctx = create_session_context();
         state.current_source = RegisteredSource::None;
+        state.live_batch_bytes = 0;
+    }
+
+    /// Estimated memory held by the context (bytes): the registered live batch
+    /// plus whatever DataFusion has reserved for queries in flight.
+    pub async fn estimated_memory_bytes(&self) -> usize {
+        let state = self.state.read().await;
+        state.live_batch_bytes + state.ctx.runtime_env().memory_pool.reserved()
     }
 
     /// Create an EncounterQuery that uses the current context.
@@ -198,9 +399,9 @@ pub struct EncounterQuery<'a> {
 impl EncounterQuery<'_> {
     /// Execute SQL query, returning empty results if table doesn't exist.
     /// This prevents panics when queries are made before parquet data is loaded.
-    async fn sql(&self, query: &str) -> Result<Vec<RecordBatch>, String> {
+    async fn sql(&self, query: &str) -> Result<Vec<RecordBatch>, QueryError> {
         match self.ctx.sql(query).await {
-            Ok(df) => df.collect().await.map_err(|e| e.to_string()),
+            Ok(df) => Ok(df.collect().await?),
             Err(e) => {
                 let msg = e.to_string();
                 // Return empty results for missing table (common during startup or empty encounters)
@@ -211,9 +412,16 @@ impl EncounterQuery<'_> {
                 {
                     Ok(vec![])
                 } else {
-                    Err(msg)
+                    Err(e.into())
                 }
             }
         }
     }
+
+    /// Execute user-supplied SQL against the `events` table.
+    /// Unlike the internal helper, all errors (including a missing table) are returned.
+    pub async fn execute_sql(&self, query: &str) -> Result<Vec<RecordBatch>, QueryError> {
+        let df = self.ctx.sql(query).await?;
+        Ok(df.collect().await?)
+    }
 }
@@ -19,7 +19,7 @@ impl EncounterQuery<'_> {
     async fn query_shield_attribution(
         &self,
         _time_range: Option<&TimeRange>,
-    ) -> Result<HashMap<String, f64>, String> {
+    ) -> Result<HashMap<String, f64>, QueryError> {
         // Query with UNNEST, only fetch columns we need for FIFO attribution
         // Only keep position=1 rows (first shield) to avoid double-counting
         let batches = self
@@ -67,7 +67,7 @@ impl EncounterQuery<'_> {
     }
 
     /// Get entity ID to name mapping
-    async fn get_entity_names(&self) -> Result<HashMap<i64, String>, String> {
+    async fn get_entity_names(&self) -> Result<HashMap<i64, String>, QueryError> {
         let batches = self
             .sql("SELECT DISTINCT source_id, source_name FROM events")
             .await?;
@@ -89,7 +89,7 @@ impl EncounterQuery<'_> {
         &self,
         time_range: Option<&TimeRange>,
         duration_secs: Option<f32>,
-    ) -> Result<Vec<RaidOverviewRow>, String> {
+    ) -> Result<Vec<RaidOverviewRow>, QueryError> {
         let time_filter = time_range
             .map(|tr| format!("AND {}", tr.sql_filter()))
             .unwrap_or_default();
@@ -109,6 +109,7 @@ impl EncounterQuery<'_> {
         // damage_taken: sum of dmg_amount WHERE target = player
         // absorbed: sum of dmg_absorbed WHERE target = player
         // healing: sum of heal_amount WHERE source = player
+        // deaths: count of death events WHERE target = player
         let batches = self
             .sql(&format!(
                 r#"
@@ -146,6 +147,13 @@ impl EncounterQuery<'_> {
                 FROM events
                 WHERE threat > 0 {time_filter}
                 GROUP BY source_name
+            ),
+            deaths AS (
+                SELECT target_name as name,
+                       COUNT(*) as death_count
+                FROM events
+                WHERE effect_id = {death_id} {time_filter}
+                GROUP BY target_name
             )
             SELECT
                 p.name,
@@ -155,14 +163,17 @@ impl EncounterQuery<'_> {
                 COALESCE(t.damage_taken_total, 0) as damage_taken_total,
                 COALESCE(t.absorbed_total, 0) as absorbed_total,
                 COALESCE(h.healing_total, 0) as healing_total,
-                COALESCE(h.healing_effective, 0) as healing_effective
+                COALESCE(h.healing_effective, 0) as healing_effective,
+                COALESCE(dt.death_count, 0) as death_count
             FROM participants p
             LEFT JOIN damage_dealt d ON p.name = d.name
             LEFT JOIN damage_taken t ON p.name = t.name
             LEFT JOIN healing_done h ON p.name = h.name
             LEFT JOIN threat as th ON p.name = th.name
+            LEFT JOIN deaths dt ON p.name = dt.name
             ORDER BY damage_total DESC
-        "#
+        "#,
+                death_id = effect_id::DEATH
             ))
             .await?;
 
@@ -176,6 +187,7 @@ impl EncounterQuery<'_> {
             let absorbed_totals = col_f64(batch, 5)?;
             let healing_totals = col_f64(batch, 6)?;
             let healing_effectives = col_f64(batch, 7)?;
+            let death_counts = col_i64(batch, 8)?;
 
             for i in 0..batch.num_rows() {
                 let name = names[i].clone();
@@ -209,6 +221,7 @@ impl EncounterQuery<'_> {
                     healing_effective,
                     ehps: healing_effective * 1000.0 / duration_ms as f64,
                     healing_pct,
+                    deaths: death_counts[i],
                 });
             }
         }
@@ -217,7 +230,7 @@ impl EncounterQuery<'_> {
 
     /// Query player deaths in the encounter.
     /// Returns a list of player deaths ordered by time.
-    pub async fn query_player_deaths(&self) -> Result<Vec<PlayerDeath>, String> {
+    pub async fn query_player_deaths(&self) -> Result<Vec<PlayerDeath>, QueryError> {
         // Death events are identified by effect_id::DEATH
         // and target_entity_type = 'Player' or 'Companion'
         let sql = format!(
@@ -0,0 +1,293 @@
+//! Side-by-side queries over two pulls registered with
+//! [`QueryContext::register_parquet_pair`] (`events_a` and `events_b`).
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::time_series::TimeSeriesConfig;
+use super::*;
+
+/// Table holding pull A of a registered pair
+pub const PAIR_TABLE_A: &str = "events_a";
+/// Table holding pull B of a registered pair
+pub const PAIR_TABLE_B: &str = "events_b";
+
+/// Breakdown row of one pull: (ability id, name, total)
+type BreakdownRow = (Option<i64>, String, f64);
+
+impl EncounterQuery<'_> {
+    /// Combat duration of each pull in seconds (A, B).
+    pub async fn pair_durations(&self) -> Result<(f32, f32), QueryError> {
+        let mut durations = [0.0; 2];
+        for (duration, table) in durations.iter_mut().zip([PAIR_TABLE_A, PAIR_TABLE_B]) {
+            *duration = scalar_f32(
+                &self
+                    .sql(&format!(
+                        "SELECT COALESCE(MAX(combat_time_secs), 0) FROM {table} WHERE combat_time_secs IS NOT NULL"
+                    ))
+                    .await?,
+            );
+        }
+        Ok((durations[0], durations[1]))
+    }
+
+    /// Per-ability breakdown of both pulls for a data tab.
+    /// - entity_name: source_name for outgoing tabs, target_name for incoming tabs.
+    /// - entity_types: filters the same side by entity type.
+    pub async fn compare_breakdown(
+        &self,
+        tab: DataTab,
+        entity_name: Option<&str>,
+        entity_types: Option<&[&str]>,
+    ) -> Result<Vec<BreakdownDelta>, QueryError> {
+        let (entity_col, entity_type_col) = if tab.is_outgoing() {
+            ("source_name", "source_entity_type")
+        } else {
+            ("target_name", "target_entity_type")
+        };
+        let mut conditions = vec![format!("{} > 0", tab.value_column())];
+        if let Some(n) = entity_name {
+            conditions.push(format!("{} = '{}'", entity_col, sql_escape(n)));
+        }
+        if let Some(types) = entity_types {
+            conditions.push(entity_type_condition(entity_type_col, types));
+        }
+
+        let a = self
+            .pair_breakdown_rows(PAIR_TABLE_A, tab, &conditions, true)
+            .await?;
+        let b = self
+            .pair_breakdown_rows(PAIR_TABLE_B, tab, &conditions, true)
+            .await?;
+        let (duration_a, duration_b) = self.pair_durations().await?;
+        Ok(merge_breakdowns(&a, &b, duration_a, duration_b))
+    }
+
+    /// Per-entity breakdown of both pulls for a data tab (source entities for
+    /// outgoing tabs, targets for incoming), matched by name.
+    pub async fn compare_entity_breakdown(
+        &self,
+        tab: DataTab,
+        entity_types: Option<&[&str]>,
+    ) -> Result<Vec<BreakdownDelta>, QueryError> {
+        let entity_type_col = if tab.is_outgoing() {
+            "source_entity_type"
+        } else {
+            "target_entity_type"
+        };
+        let mut conditions = vec![format!("{} > 0", tab.value_column())];
+        if let Some(types) = entity_types {
+            conditions.push(entity_type_condition(entity_type_col, types));
+        }
+
+        let a = self
+            .pair_breakdown_rows(PAIR_TABLE_A, tab, &conditions, false)
+            .await?;
+        let b = self
+            .pair_breakdown_rows(PAIR_TABLE_B, tab, &conditions, false)
+            .await?;
+        let (duration_a, duration_b) = self.pair_durations().await?;
+        Ok(merge_breakdowns(&a, &b, duration_a, duration_b))
+    }
+
+    /// DPS over time for both pulls, bucketed by time interval.
+    pub async fn compare_dps_over_time(
+        &self,
+        bucket_ms: i64,
+        source_name: Option<&str>,
+    ) -> Result<Vec<TimeSeriesPairPoint>, QueryError> {
+        let series = |table| TimeSeriesConfig {
+            table,
+            value_column: "dmg_amount",
+            entity_column: "source_name",
+            entity_filter: source_name,
+        };
+        let a = self
+            .query_time_series(bucket_ms, series(PAIR_TABLE_A), None)
+            .await?;
+        let b = self
+            .query_time_series(bucket_ms, series(PAIR_TABLE_B), None)
+            .await?;
+        Ok(merge_time_series(&a, &b))
+    }
+
+    /// Totals for one side of the pair, grouped by ability or by entity name.
+    async fn pair_breakdown_rows(
+        &self,
+        table: &str,
+        tab: DataTab,
+        conditions: &[String],
+        by_ability: bool,
+    ) -> Result<Vec<BreakdownRow>, QueryError> {
+        let value_col = tab.value_column();
+        let filter = format!("WHERE {}", conditions.join(" AND "));
+        let sql = if by_ability {
+            format!(
+                "SELECT ability_id, MAX(ability_name) as name, SUM({value_col}) as total_value
+                 FROM {table} {filter} GROUP BY ability_id"
+            )
+        } else {
+            let name_col = if tab.is_outgoing() {
+                "source_name"
+            } else {
+                "target_name"
+            };
+            format!(
+                "SELECT 0 as ability_id, {name_col} as name, SUM({value_col}) as total_value
+                 FROM {table} {filter} GROUP BY {name_col}"
+            )
+        };
+
+        let mut rows = Vec::new();
+        for batch in &self.sql(&sql).await? {
+            let ids = col_i64(batch, 0)?;
+            let names = col_strings(batch, 1)?;
+            let totals = col_f64(batch, 2)?;
+            for i in 0..batch.num_rows() {
+                rows.push((by_ability.then_some(ids[i]), names[i].clone(), totals[i]));
+            }
+        }
+        Ok(rows)
+    }
+}
+
+fn entity_type_condition(column: &str, types: &[&str]) -> String {
+    let type_list = types
+        .iter()
+        .map(|t| format!("'{}'", sql_escape(t)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{column} IN ({type_list})")
+}
+
+/// Abilities match by id alone (names can differ by locale); entities by name
+fn merge_key(id: Option<i64>, name: &str) -> (Option<i64>, &str) {
+    (id, if id.is_some() { "" } else { name })
+}
+
+/// Match breakdown rows of two pulls (by ability id, or by name for entity
+/// rows) and compute per-second rates over each pull's duration.
+///
+/// Rows are ordered by their larger total across both pulls.
+pub fn merge_breakdowns(
+    a: &[(Option<i64>, String, f64)],
+    b: &[(Option<i64>, String, f64)],
+    duration_a: f32,
+    duration_b: f32,
+) -> Vec<BreakdownDelta> {
+    let mut rows: Vec<BreakdownDelta> = Vec::new();
+    let mut index: HashMap<(Option<i64>, &str), usize> = HashMap::new();
+
+    for (id, name, total) in a {
+        index.insert(merge_key(*id, name), rows.len());
+        rows.push(BreakdownDelta {
+            ability_id: *id,
+            name: name.clone(),
+            total_a: *total,
+            ..Default::default()
+        });
+    }
+    for (id, name, total) in b {
+        match index.get(&merge_key(*id, name)) {
+            Some(&i) => rows[i].total_b = *total,
+            None => {
+                index.insert(merge_key(*id, name), rows.len());
+                rows.push(BreakdownDelta {
+                    ability_id: *id,
+                    name: name.clone(),
+                    total_b: *total,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    let secs_a = duration_a.max(0.001) as f64;
+    let secs_b = duration_b.max(0.001) as f64;
+    for row in &mut rows {
+        row.rate_a = row.total_a / secs_a;
+        row.rate_b = row.total_b / secs_b;
+    }
+    let best = |r: &BreakdownDelta| r.total_a.max(r.total_b);
+    rows.sort_by(|x, y| best(y).total_cmp(&best(x)));
+    rows
+}
+
+/// Line up two time series by bucket, filling buckets missing from one pull with 0.
+pub fn merge_time_series(a: &[TimeSeriesPoint], b: &[TimeSeriesPoint]) -> Vec<TimeSeriesPairPoint> {
+    let mut buckets: BTreeMap<i64, (f64, f64)> = BTreeMap::new();
+    for point in a {
+        buckets.entry(point.bucket_start_ms).or_default().0 = point.total_value;
+    }
+    for point in b {
+        buckets.entry(point.bucket_start_ms).or_default().1 = point.total_value;
+    }
+    buckets
+        .into_iter()
+        .map(
+            |(bucket_start_ms, (value_a, value_b))| TimeSeriesPairPoint {
+                bucket_start_ms,
+                value_a,
+                value_b,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(id: Option<i64>, name: &str, total: f64) -> (Option<i64>, String, f64) {
+        (id, name.to_string(), total)
+    }
+
+    fn point(bucket_start_ms: i64, total_value: f64) -> TimeSeriesPoint {
+        TimeSeriesPoint {
+            bucket_start_ms,
+            total_value,
+        }
+    }
+
+    #[test]
+    fn abilities_matched_by_id_with_rates_per_pull() {
+        let a = vec![row(Some(1), "Shock", 1000.0), row(Some(2), "Lance", 400.0)];
+        let b = vec![
+            row(Some(1), "Schock", 1500.0),
+            row(Some(3), "Crush", 2000.0),
+        ];
+
+        let rows = merge_breakdowns(&a, &b, 10.0, 20.0);
+        assert_eq!(rows.len(), 3);
+        // Ordered by the larger total across both pulls
+        assert_eq!(rows[0].name, "Crush");
+        assert_eq!(rows[0].total_a, 0.0);
+        assert_eq!(rows[1].ability_id, Some(1));
+        assert_eq!(rows[1].rate_a, 100.0);
+        assert_eq!(rows[1].rate_b, 75.0);
+        assert_eq!(rows[1].rate_delta(), -25.0);
+        assert_eq!(rows[2].total_b, 0.0);
+    }
+
+    #[test]
+    fn entities_matched_by_name() {
+        let a = vec![row(None, "Karr", 300.0), row(None, "Vossa", 100.0)];
+        let b = vec![row(None, "Vossa", 250.0)];
+
+        let rows = merge_breakdowns(&a, &b, 1.0, 1.0);
+        let vossa = rows.iter().find(|r| r.name == "Vossa").unwrap();
+        assert_eq!(vossa.total_delta(), 150.0);
+        assert_eq!(rows.len(), 2);
+    }
+
+    #[test]
+    fn time_series_fill_missing_buckets() {
+        let a = vec![point(0, 10.0), point(1000, 20.0)];
+        let b = vec![point(0, 5.0), point(1000, 0.0), point(2000, 30.0)];
+
+        let merged = merge_time_series(&a, &b);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[2].value_a, 0.0);
+        assert_eq!(merged[2].value_b, 30.0);
+        assert_eq!(merged[0].value_a, 10.0);
+    }
+}
@@ -1,26 +1,32 @@
-//! Time series queries (DPS, HPS, DTPS over time).
+//! Time series queries (DPS, HPS, DTPS over time) and big-hit annotations.
 
 use super::*;
 
 /// Configuration for time series queries.
-struct TimeSeriesConfig<'a> {
+pub(super) struct TimeSeriesConfig<'a> {
+    /// Table to read (`events`, or one side of a registered pair)
+    pub table: &'static str,
     /// Column to sum ("dmg_amount" or "heal_amount")
-    value_column: &'static str,
+    pub value_column: &'static str,
     /// Column to filter by entity ("source_name" or "target_name")
-    entity_column: &'static str,
+    pub entity_column: &'static str,
     /// Optional entity name filter
-    entity_filter: Option<&'a str>,
+    pub entity_filter: Option<&'a str>,
 }
 
+/// Most big hits returned per query (the largest ones are kept)
+const MAX_BIG_HITS: usize = 50;
+
 impl EncounterQuery<'_> {
     /// Generic time series query - buckets values over time with optional entity filter.
-    async fn query_time_series(
+    pub(super) async fn query_time_series(
         &self,
         bucket_ms: i64,
         config: TimeSeriesConfig<'_>,
         time_range: Option<&TimeRange>,
-    ) -> Result<Vec<TimeSeriesPoint>, String> {
+    ) -> Result<Vec<TimeSeriesPoint>, QueryError> {
         let bucket_secs = (bucket_ms as f64 / 1000.0).max(1.0);
+        let table = config.table;
         let value_col = config.value_column;
         let entity_col = config.entity_column;
 
@@ -45,7 +51,7 @@ WITH bounds AS (
     SELECT
         CAST(MIN(FLOOR(combat_time_secs / {bucket_secs})) as BIGINT) as min_bucket,
         CAST(MAX(FLOOR(combat_time_secs / {bucket_secs})) as BIGINT) as max_bucket
-    FROM events
+    FROM {table}
     {tr_filter}
 ),
 time_series AS (
@@ -56,7 +62,7 @@ time_series AS (
 entity_ts AS (
     SELECT CAST(FLOOR(combat_time_secs / {bucket_secs}) * {bucket_secs} * 1000 AS BIGINT) as bucket_start_ms,
            SUM({value_col}) as total_value
-    FROM events
+    FROM {table}
     {entity_filter}
     GROUP BY bucket_start_ms
 )
@@ -90,10 +96,11 @@ ORDER BY time_series.bucket_start_ms
         bucket_ms: i64,
         source_name: Option<&str>,
         time_range: Option<&TimeRange>,
-    ) -> Result<Vec<TimeSeriesPoint>, String> {
+    ) -> Result<Vec<TimeSeriesPoint>, QueryError> {
         self.query_time_series(
             bucket_ms,
             TimeSeriesConfig {
+                table: "events",
                 value_column: "dmg_amount",
                 entity_column: "source_name",
                 entity_filter: source_name,
@@ -109,10 +116,11 @@ ORDER BY time_series.bucket_start_ms
         bucket_ms: i64,
         source_name: Option<&str>,
         time_range: Option<&TimeRange>,
-    ) -> Result<Vec<TimeSeriesPoint>, String> {
+    ) -> Result<Vec<TimeSeriesPoint>, QueryError> {
         self.query_time_series(
             bucket_ms,
             TimeSeriesConfig {
+                table: "events",
                 value_column: "heal_amount",
                 entity_column: "source_name",
                 entity_filter: source_name,
@@ -128,10 +136,11 @@ ORDER BY time_series.bucket_start_ms
         bucket_ms: i64,
         target_name: Option<&str>,
         time_range: Option<&TimeRange>,
-    ) -> Result<Vec<TimeSeriesPoint>, String> {
+    ) -> Result<Vec<TimeSeriesPoint>, QueryError> {
         self.query_time_series(
             bucket_ms,
             TimeSeriesConfig {
+                table: "events",
                 value_column: "dmg_amount",
                 entity_column: "target_name",
                 entity_filter: target_name,
@@ -140,4 +149,83 @@ ORDER BY time_series.bucket_start_ms
         )
         .await
     }
+
+    /// Damage hits of at least `min_amount`, in time order.
+    /// `entity_column` picks whether `entity_filter` matches the source or target.
+    async fn query_big_hits(
+        &self,
+        min_amount: f64,
+        entity_column: &'static str,
+        entity_filter: Option<&str>,
+        time_range: Option<&TimeRange>,
+    ) -> Result<Vec<BigHit>, QueryError> {
+        let mut conditions = vec![
+            "combat_time_secs IS NOT NULL".to_string(),
+            format!("dmg_amount >= {min_amount}"),
+        ];
+        if let Some(tr) = time_range {
+            conditions.push(tr.sql_filter());
+        }
+        if let Some(name) = entity_filter {
+            conditions.push(format!("{} = '{}'", entity_column, sql_escape(name)));
+        }
+
+        let batches = self
+            .sql(&format!(
+                r#"
+SELECT * FROM (
+    SELECT combat_time_secs, ability_name, CAST(dmg_amount AS DOUBLE) as amount,
+           source_name, target_name
+    FROM events
+    WHERE {}
+    ORDER BY dmg_amount DESC
+    LIMIT {MAX_BIG_HITS}
+)
+ORDER BY combat_time_secs
+            "#,
+                conditions.join(" AND ")
+            ))
+            .await?;
+
+        let mut results = Vec::new();
+        for batch in &batches {
+            let times = col_f32(batch, 0)?;
+            let abilities = col_strings(batch, 1)?;
+            let amounts = col_f64(batch, 2)?;
+            let sources = col_strings(batch, 3)?;
+            let targets = col_strings(batch, 4)?;
+            for i in 0..batch.num_rows() {
+                results.push(BigHit {
+                    combat_time_secs: times[i],
+                    ability_name: abilities[i].clone(),
+                    amount: amounts[i],
+                    source_name: sources[i].clone(),
+                    target_name: targets[i].clone(),
+                });
+            }
+        }
+        Ok(results)
+    }
+
+    /// Hits of at least `min_amount` dealt by a source entity (DPS chart annotations).
+    pub async fn big_hits_dealt(
+        &self,
+        min_amount: f64,
+        source_name: Option<&str>,
+        time_range: Option<&TimeRange>,
+    ) -> Result<Vec<BigHit>, QueryError> {
+        self.query_big_hits(min_amount, "source_name", source_name, time_range)
+            .await
+    }
+
+    /// Hits of at least `min_amount` taken by a target entity (DTPS chart annotations).
+    pub async fn big_hits_taken(
+        &self,
+        min_amount: f64,
+        target_name: Option<&str>,
+        time_range: Option<&TimeRange>,
+    ) -> Result<Vec<BigHit>, QueryError> {
+        self.query_big_hits(min_amount, "target_name", target_name, time_range)
+            .await
+    }
 }
@@ -4,7 +4,7 @@ use super::*;
 
 impl EncounterQuery<'_> {
     /// Get encounter timeline with phase segments (handles repeated phases).
-    pub async fn encounter_timeline(&self) -> Result<EncounterTimeline, String> {
+    pub async fn encounter_timeline(&self) -> Result<EncounterTimeline, QueryError> {
         // Calculate duration from combat_time_secs (only includes actual combat events)
         let duration_secs = scalar_f32(
             &self
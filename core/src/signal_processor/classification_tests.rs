@@ -0,0 +1,162 @@
+//! Fixture-driven encounter classification tests
+//!
+//! Every `<case>.txt` log snippet in `tests/fixtures/classification` is
+//! replayed through the processor and checked against the expectations in
+//! `<case>.toml` next to it:
+//!
+//! ```toml
+//! # Boss definitions to load, relative to the fixture directory (optional)
+//! definitions = "definitions/dread_palace.toml"
+//!
+//! # One entry per combat, in log order
+//! [[encounter]]
+//! boss = "bestia"          # detected definition ID (omit for trash)
+//! phases = ["monsters"]    # phases entered, in order
+//! wipe = true              # defaults to false (a kill)
+//! ```
+//!
+//! To add a regression case for a misclassified pull, cut the pull out of the
+//! reporter's log, save it with a manifest describing the correct outcome and
+//! run `cargo test -p baras-core classification`.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::combat_log::LogParser;
+use crate::dsl::load_bosses_from_file;
+use crate::state::SessionCache;
+
+use super::{EventProcessor, GameSignal};
+
+const FIXTURE_DIR: &str = "tests/fixtures/classification";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    definitions: Option<PathBuf>,
+    #[serde(default, rename = "encounter")]
+    encounters: Vec<Expected>,
+}
+
+/// Expected (or observed) classification of one combat
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    boss: Option<String>,
+    #[serde(default)]
+    phases: Vec<String>,
+    #[serde(default)]
+    wipe: bool,
+}
+
+/// Replay a fixture log and classify each combat in it
+fn classify(log_path: &Path, definitions: Option<&Path>) -> Result<Vec<Expected>, String> {
+    let bytes = std::fs::read(log_path)
+        .map_err(|e| format!("Failed to read {}: {}", log_path.display(), e))?;
+    // Player names may contain non-UTF-8 bytes
+    let content = String::from_utf8_lossy(&bytes);
+
+    let session_date = chrono::NaiveDate::from_ymd_opt(2025, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let parser = LogParser::new(session_date);
+    let mut processor = EventProcessor::new();
+    let mut cache = SessionCache::default();
+
+    if let Some(path) = definitions {
+        cache.load_boss_definitions(load_bosses_from_file(path)?);
+    }
+
+    let mut observed: Vec<Expected> = Vec::new();
+    for (line_num, line) in content.lines().enumerate() {
+        let Some(event) = parser.parse_line(line_num as u64, line) else {
+            continue;
+        };
+        let (signals, _) = processor.process_event(event, &mut cache);
+        for signal in signals {
+            match signal {
+                GameSignal::CombatStarted { .. } => observed.push(Expected::default()),
+                GameSignal::BossEncounterDetected { definition_id, .. } => {
+                    if let Some(current) = observed.last_mut() {
+                        current.boss = Some(definition_id);
+                    }
+                }
+                GameSignal::PhaseChanged { new_phase, .. } => {
+                    if let Some(current) = observed.last_mut() {
+                        current.phases.push(new_phase);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // A snippet may end mid-combat
+    cache.finalize_current_encounter();
+
+    let summaries = cache.encounter_history.summaries();
+    if summaries.len() != observed.len() {
+        return Err(format!(
+            "{} combats started but {} encounters were recorded",
+            observed.len(),
+            summaries.len()
+        ));
+    }
+    for (encounter, summary) in observed.iter_mut().zip(summaries) {
+        encounter.wipe = !summary.success;
+    }
+    Ok(observed)
+}
+
+/// Check one fixture against its manifest
+fn check_case(log_path: &Path) -> Result<(), String> {
+    let manifest_path = log_path.with_extension("toml");
+    let content = std::fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Failed to read {}: {}", manifest_path.display(), e))?;
+    let manifest: Manifest = toml::from_str(&content)
+        .map_err(|e| format!("Failed to parse {}: {}", manifest_path.display(), e))?;
+
+    let definitions = manifest
+        .definitions
+        .map(|path| Path::new(FIXTURE_DIR).join(path));
+    let observed = classify(log_path, definitions.as_deref())?;
+
+    if observed != manifest.encounters {
+        return Err(format!(
+            "expected {:#?}\nobserved {:#?}",
+            manifest.encounters, observed
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_classification_fixtures() {
+    let mut cases: Vec<PathBuf> = std::fs::read_dir(FIXTURE_DIR)
+        .expect("Failed to read classification fixture directory")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    cases.sort();
+    assert!(!cases.is_empty(), "No classification fixtures found");
+
+    // Check every case so one report lists all misclassifications
+    let failures: Vec<String> = cases
+        .iter()
+        .filter_map(|path| {
+            check_case(path)
+                .err()
+                .map(|e| format!("{}: {}", path.display(), e))
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} classification fixtures failed:\n\n{}",
+        failures.len(),
+        cases.len(),
+        failures.join("\n\n")
+    );
+}
@@ -200,6 +200,8 @@ fn handle_in_combat(
     {
         let encounter_id = cache.current_encounter().map(|e| e.id).unwrap_or(0);
         if let Some(enc) = cache.current_encounter_mut() {
+            // Keep the line that ended combat in the raw log range
+            enc.track_log_range(event);
             enc.exit_combat_time = Some(timestamp);
             enc.state = EncounterState::PostCombat {
                 exit_time: timestamp,
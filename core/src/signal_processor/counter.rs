@@ -502,7 +502,13 @@ fn check_signal_based_trigger(
         Trigger::TimeElapsed { .. }
         | Trigger::BossHpAbove { .. }
         | Trigger::TargetSet { .. }
-        | Trigger::Manual => false,
+        | Trigger::CastStarted { .. }
+        | Trigger::CastCompleted { .. }
+        | Trigger::Manual
+        | Trigger::AllOf { .. }
+        | Trigger::Not { .. }
+        | Trigger::PhaseActive { .. }
+        | Trigger::CounterIs { .. } => false,
 
         // Composition
         Trigger::AnyOf { conditions } => conditions
@@ -59,6 +59,13 @@ impl EventProcessor {
         // 1e. NPC Target Tracking
         signals.extend(self.handle_target_changed(&event, cache));
 
+        // 1g. Cleanse/dispel detection (before the removal is tracked in phase 3)
+        signals.extend(self.handle_dispel(&event, cache));
+
+        // 1h. Player health tracking (raid frame HP bars)
+        cache.record_player_health(&event.source_entity);
+        cache.record_player_health(&event.target_entity);
+
         // ═══════════════════════════════════════════════════════════════════════
         // PHASE 2: Signal Emission (pure transformation)
         // ═══════════════════════════════════════════════════════════════════════
@@ -270,6 +277,28 @@ impl EventProcessor {
         signals
     }
 
+    /// Handle RemoveEffect events that are dispels of NPC-applied effects.
+    fn handle_dispel(&self, event: &CombatEvent, cache: &SessionCache) -> Vec<GameSignal> {
+        if !cache
+            .current_encounter()
+            .is_some_and(|enc| enc.is_dispel(event))
+        {
+            return Vec::new();
+        }
+
+        vec![GameSignal::EffectDispelled {
+            effect_id: event.effect.effect_id,
+            effect_name: event.effect.effect_name,
+            action_id: event.action.action_id,
+            action_name: event.action.name,
+            source_id: event.source_entity.log_id,
+            source_name: event.source_entity.name,
+            target_id: event.target_entity.log_id,
+            target_name: event.target_entity.name,
+            timestamp: event.timestamp,
+        }]
+    }
+
     /// Handle AreaEntered events.
     fn handle_area_transition(
         &self,
@@ -502,6 +531,8 @@ impl EventProcessor {
             };
             if let Some((old_hp, new_hp)) = enc.update_entity_hp(entity.log_id, current_hp, max_hp)
             {
+                enc.record_boss_hp(event.timestamp);
+
                 signals.push(GameSignal::BossHpChanged {
                     entity_id: entity.log_id,
                     npc_id: entity.class_id,
@@ -643,7 +674,7 @@ impl EventProcessor {
         }
     }
 
-    /// Emit signals for ability activations and target changes.
+    /// Emit signals for ability activations/deactivations and target changes.
     /// Pure transformation - no encounter state modification.
     fn emit_action_signals(&self, event: &CombatEvent) -> Vec<GameSignal> {
         let mut signals = Vec::new();
@@ -665,6 +696,23 @@ impl EventProcessor {
                 timestamp: event.timestamp,
             });
         }
+
+        // Ability deactivation (cast completed; cancels/interrupts use other effect IDs)
+        if effect_id == effect_id::ABILITYDEACTIVATE {
+            signals.push(GameSignal::AbilityDeactivated {
+                ability_id: event.action.action_id,
+                ability_name: event.action.name,
+                source_id: event.source_entity.log_id,
+                source_entity_type: event.source_entity.entity_type,
+                source_name: event.source_entity.name,
+                source_npc_id: event.source_entity.class_id,
+                target_id: event.target_entity.log_id,
+                target_entity_type: event.target_entity.entity_type,
+                target_name: event.target_entity.name,
+                target_npc_id: event.target_entity.class_id,
+                timestamp: event.timestamp,
+            });
+        }
         signals
     }
 
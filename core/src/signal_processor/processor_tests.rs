@@ -95,7 +95,9 @@ fn signal_type_name(signal: &GameSignal) -> &'static str {
         GameSignal::EffectApplied { .. } => "EffectApplied",
         GameSignal::EffectRemoved { .. } => "EffectRemoved",
         GameSignal::EffectChargesChanged { .. } => "EffectChargesChanged",
+        GameSignal::EffectDispelled { .. } => "EffectDispelled",
         GameSignal::AbilityActivated { .. } => "AbilityActivated",
+        GameSignal::AbilityDeactivated { .. } => "AbilityDeactivated",
         GameSignal::DamageTaken { .. } => "DamageTaken",
         GameSignal::TargetChanged { .. } => "TargetChanged",
         GameSignal::TargetCleared { .. } => "TargetCleared",
@@ -622,14 +624,18 @@ fn test_bestia_complete_encounter() {
             color: bt.color,
             alert_at_secs: None,
             alert_text: None,
+            alert_priority: bt.alert_priority,
             audio: Default::default(),
             repeats: 0,
             show_on_raid_frames: false,
+            display_target: Default::default(),
+            suggested_cooldown: bt.suggested_cooldown.clone(),
             show_at_secs: 0.0,
             area_ids: Vec::new(),
             encounters: Vec::new(),
             boss: None,
             difficulties: Vec::new(),
+            group_size: None,
             phases: Vec::new(),
             counter_condition: None,
             per_target: bt.per_target,
@@ -870,3 +876,185 @@ fn test_bestia_complete_encounter() {
     eprintln!("Total timers activated: {}", timers_activated.len());
     eprintln!("Activated timers: {:?}", timers_activated);
 }
+
+/// Process a fixture into the given cache (read as raw bytes, like the reader)
+fn process_fixture_into(fixture_path: &Path, cache: &mut SessionCache) {
+    let bytes = std::fs::read(fixture_path).expect("Failed to read file");
+    let parser = LogParser::new(chrono::Local::now().naive_local());
+    let mut processor = EventProcessor::new();
+
+    for (line_num, line) in bytes.split(|&b| b == b'\n').enumerate() {
+        if let Some(event) = parser.parse_raw_line(line_num as u64, line) {
+            processor.process_event(event, cache);
+        }
+    }
+}
+
+/// Process a fixture and return the cache once the pull has ended
+fn process_fixture_into_cache(fixture_path: &Path, compaction: bool) -> SessionCache {
+    let mut cache = SessionCache::default();
+    cache.set_compaction_enabled(compaction);
+    process_fixture_into(fixture_path, &mut cache);
+    cache
+}
+
+#[test]
+fn test_finished_encounter_compacted_after_combat_end() {
+    let fixture_path = Path::new("../test-log-files/fixtures/bestia_complete_pull.txt");
+    if !fixture_path.exists() {
+        eprintln!(
+            "Skipping test: fixture file not found at {:?}",
+            fixture_path
+        );
+        return;
+    }
+
+    let kept = process_fixture_into_cache(fixture_path, false);
+    let compacted = process_fixture_into_cache(fixture_path, true);
+
+    let kept_enc = kept.last_combat_encounter().expect("pull should be recorded");
+    let compacted_enc = compacted
+        .last_combat_encounter()
+        .expect("pull should be recorded");
+    assert_ne!(
+        compacted_enc.id,
+        compacted.current_encounter().unwrap().id,
+        "combat should have ended and a new encounter started"
+    );
+
+    // Per-event effect buffers are dropped, metrics survive for display
+    assert!(!kept_enc.effects.is_empty());
+    assert!(compacted_enc.effects.is_empty());
+    assert_eq!(
+        kept_enc.accumulated_data.len(),
+        compacted_enc.accumulated_data.len()
+    );
+    assert_eq!(
+        kept.encounter_history.summaries().len(),
+        compacted.encounter_history.summaries().len()
+    );
+    assert!(compacted.estimated_heap_bytes() < kept.estimated_heap_bytes());
+}
+
+#[test]
+fn test_encounter_key_stable_across_reparse() {
+    let fixture_path = Path::new("../test-log-files/fixtures/bestia_complete_pull.txt");
+    let config_path = Path::new("../test-log-files/fixtures/config/dread_palace.toml");
+    if !fixture_path.exists() {
+        eprintln!(
+            "Skipping test: fixture file not found at {:?}",
+            fixture_path
+        );
+        return;
+    }
+
+    let keys = |cache: &SessionCache| -> Vec<String> {
+        cache
+            .encounter_history
+            .summaries()
+            .iter()
+            .map(|s| s.encounter_key.clone())
+            .collect()
+    };
+
+    let first = process_fixture_into_cache(fixture_path, true);
+    let second = process_fixture_into_cache(fixture_path, false);
+    assert!(!keys(&first).is_empty());
+    assert_eq!(keys(&first), keys(&second));
+
+    // Key is derived from the encounter itself, not from processing order
+    let mut shifted = SessionCache::default();
+    shifted.set_next_encounter_id(42);
+    shifted.push_new_encounter();
+    process_fixture_into(fixture_path, &mut shifted);
+    assert_ne!(
+        first.encounter_history.summaries()[0].encounter_id,
+        shifted.encounter_history.summaries()[0].encounter_id
+    );
+    assert_eq!(keys(&first), keys(&shifted));
+
+    // Boss definition id is part of the key once the boss is detected
+    let config = load_boss_config(config_path).expect("boss config");
+    let mut with_boss = SessionCache::default();
+    with_boss.load_boss_definitions(config.bosses);
+    process_fixture_into(fixture_path, &mut with_boss);
+    let boss_keys = keys(&with_boss);
+    assert!(
+        boss_keys.iter().any(|k| !k.contains("-trash-")),
+        "{:?}",
+        boss_keys
+    );
+}
+
+#[test]
+fn test_dispel_detected_for_npc_effect_removed_by_player() {
+    let lines = [
+        "[18:43:08.815] [@Healer#690000000000001|(0.00,0.00,0.00,0.00)|(400000/400000)] [] [] [Event {836045448945472}: EnterCombat {836045448945489}]",
+        "[18:43:10.000] [Dread Larva {3292079547482112}:5320000116113|(0.00,0.00,0.00,0.00)|(431234/431234)] [@Tank#690000000000002|(0.00,0.00,0.00,0.00)|(450000/450000)] [Burrow {3294815441649664}] [ApplyEffect {836045448945477}: Immobilized {3294815441649940}]",
+        "[18:43:11.000] [@Healer#690000000000001|(0.00,0.00,0.00,0.00)|(400000/400000)] [@Tank#690000000000002|(0.00,0.00,0.00,0.00)|(450000/450000)] [Purge {1000000000000001}] [RemoveEffect {836045448945478}: Immobilized {3294815441649940}]",
+        // Natural expiry is logged with the applier as source
+        "[18:43:12.000] [Dread Larva {3292079547482112}:5320000116113|(0.00,0.00,0.00,0.00)|(431234/431234)] [@Healer#690000000000001|(0.00,0.00,0.00,0.00)|(400000/400000)] [Burrow {3294815441649664}] [ApplyEffect {836045448945477}: Immobilized {3294815441649940}]",
+        "[18:43:14.000] [Dread Larva {3292079547482112}:5320000116113|(0.00,0.00,0.00,0.00)|(431234/431234)] [@Healer#690000000000001|(0.00,0.00,0.00,0.00)|(400000/400000)] [Burrow {3294815441649664}] [RemoveEffect {836045448945478}: Immobilized {3294815441649940}]",
+    ];
+
+    let parser = LogParser::new(chrono::Local::now().naive_local());
+    let mut processor = EventProcessor::new();
+    let mut cache = SessionCache::default();
+    let mut signals = Vec::new();
+    for (line_num, line) in lines.iter().enumerate() {
+        let event = parser
+            .parse_line(line_num as u64, line)
+            .expect("valid line");
+        signals.extend(processor.process_event(event, &mut cache).0);
+    }
+
+    let dispels: Vec<_> = signals
+        .iter()
+        .filter_map(|s| match s {
+            GameSignal::EffectDispelled {
+                source_id,
+                target_id,
+                action_name,
+                ..
+            } => Some((
+                *source_id,
+                *target_id,
+                crate::context::resolve(*action_name),
+            )),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        dispels,
+        vec![(690000000000001, 690000000000002, "Purge")],
+        "only the player removal should count as a dispel"
+    );
+
+    let enc = cache.current_encounter().unwrap();
+    assert_eq!(enc.accumulated_data[&690000000000001].dispel_count, 1);
+}
+
+#[test]
+fn test_player_health_tracked_from_events() {
+    let lines = [
+        "[18:43:08.815] [@Healer#690000000000001|(0.00,0.00,0.00,0.00)|(400000/400000)] [] [] [Event {836045448945472}: EnterCombat {836045448945489}]",
+        "[18:43:10.000] [Dread Larva {3292079547482112}:5320000116113|(0.00,0.00,0.00,0.00)|(431234/431234)] [@Tank#690000000000002|(0.00,0.00,0.00,0.00)|(225000/450000)] [Bite {3294815441649664}] [ApplyEffect {836045448945477}: Damage {836045448945501}] (225000 energy {836045448940874})",
+        "[18:43:11.000] [@Healer#690000000000001|(0.00,0.00,0.00,0.00)|(200000/400000)] [@Tank#690000000000002|(0.00,0.00,0.00,0.00)|(337500/450000)] [Heal {1000000000000002}] [ApplyEffect {836045448945477}: Heal {836045448945500}] (112500)",
+    ];
+
+    let parser = LogParser::new(chrono::Local::now().naive_local());
+    let mut processor = EventProcessor::new();
+    let mut cache = SessionCache::default();
+    for (line_num, line) in lines.iter().enumerate() {
+        let event = parser
+            .parse_line(line_num as u64, line)
+            .expect("valid line");
+        processor.process_event(event, &mut cache);
+    }
+
+    // Latest health value wins, for both source and target players
+    assert_eq!(cache.player_hp_percent(690000000000002), Some(0.75));
+    assert_eq!(cache.player_hp_percent(690000000000001), Some(0.5));
+    // NPCs are not tracked
+    assert_eq!(cache.player_hp_percent(5320000116113), None);
+}
@@ -92,6 +92,20 @@ pub enum GameSignal {
         charges: u8,
     },
 
+    /// A player or companion cleansed an NPC-applied effect from a friendly target
+    EffectDispelled {
+        effect_id: i64,
+        effect_name: IStr,
+        /// The cleanse/dispel ability used
+        action_id: i64,
+        action_name: IStr,
+        source_id: i64,
+        source_name: IStr,
+        target_id: i64,
+        target_name: IStr,
+        timestamp: NaiveDateTime,
+    },
+
     // Ability activation (for timer triggers and raid frame registration)
     AbilityActivated {
         ability_id: i64,
@@ -109,6 +123,23 @@ pub enum GameSignal {
         timestamp: NaiveDateTime,
     },
 
+    /// Ability deactivation (cast/channel finished without being interrupted)
+    AbilityDeactivated {
+        ability_id: i64,
+        ability_name: IStr,
+        source_id: i64,
+        source_entity_type: EntityType,
+        source_name: IStr,
+        /// NPC class/template ID of source (0 for players/companions)
+        source_npc_id: i64,
+        target_id: i64,
+        target_entity_type: EntityType,
+        target_name: IStr,
+        /// NPC class/template ID of target (0 for players/companions)
+        target_npc_id: i64,
+        timestamp: NaiveDateTime,
+    },
+
     /// Damage taken (for tank buster detection, etc.)
     DamageTaken {
         /// The ability that dealt damage
@@ -240,7 +271,9 @@ impl GameSignal {
             | Self::EffectApplied { timestamp, .. }
             | Self::EffectRemoved { timestamp, .. }
             | Self::EffectChargesChanged { timestamp, .. }
+            | Self::EffectDispelled { timestamp, .. }
             | Self::AbilityActivated { timestamp, .. }
+            | Self::AbilityDeactivated { timestamp, .. }
             | Self::DamageTaken { timestamp, .. }
             | Self::TargetChanged { timestamp, .. }
             | Self::TargetCleared { timestamp, .. }
@@ -1,3 +1,4 @@
+use crate::combat_log::{Entity, EntityType};
 use crate::dsl::BossEncounterDefinition;
 use crate::encounter::entity_info::PlayerInfo;
 use crate::encounter::summary::{EncounterHistory, create_encounter_summary};
@@ -40,6 +41,14 @@ pub struct SessionCache {
     /// Maps player entity_id -> PlayerInfo with discipline data
     /// This is the source of truth for player disciplines, updated on every DisciplineChanged event
     pub player_disciplines: HashMap<i64, PlayerInfo>,
+
+    // Player health (session-scoped)
+    /// Latest (current, max) health per player entity_id, from event health values.
+    /// Drives the raid frame HP bars.
+    pub player_health: HashMap<i64, (i32, i32)>,
+
+    /// Compact finished encounters and their summaries when combat ends
+    compaction_enabled: bool,
 }
 
 impl Default for SessionCache {
@@ -60,11 +69,47 @@ impl SessionCache {
             boss_definitions: Arc::new(Vec::new()),
             seen_npc_instances: HashSet::new(),
             player_disciplines: HashMap::new(),
+            player_health: HashMap::new(),
+            compaction_enabled: true,
         };
         cache.push_new_encounter();
         cache
     }
 
+    /// Estimated heap usage of live encounters, history and session registries (bytes)
+    pub fn estimated_heap_bytes(&self) -> usize {
+        use crate::memory::{table_bytes, vec_bytes};
+
+        vec_bytes::<CombatEncounter>(self.encounters.capacity())
+            + self
+                .encounters
+                .iter()
+                .map(CombatEncounter::estimated_heap_bytes)
+                .sum::<usize>()
+            + self.encounter_history.estimated_heap_bytes()
+            + table_bytes::<i64>(self.seen_npc_instances.capacity())
+            + table_bytes::<(i64, PlayerInfo)>(self.player_disciplines.capacity())
+            + table_bytes::<(i64, (i32, i32))>(self.player_health.capacity())
+    }
+
+    // --- Player Health ---
+
+    /// Record a player's health from an event entity.
+    /// Non-players and entities without a max HP are ignored.
+    pub fn record_player_health(&mut self, entity: &Entity) {
+        if entity.entity_type != EntityType::Player || entity.health.1 <= 0 {
+            return;
+        }
+        self.player_health.insert(entity.log_id, entity.health);
+    }
+
+    /// Current HP of a player as a fraction (0.0 - 1.0), if their health has been seen
+    pub fn player_hp_percent(&self, entity_id: i64) -> Option<f32> {
+        self.player_health
+            .get(&entity_id)
+            .map(|&(current, max)| (current as f32 / max as f32).clamp(0.0, 1.0))
+    }
+
     // --- Encounter Management ---
 
     /// Finalize the current encounter and add it to history (if it had combat)
@@ -91,6 +136,11 @@ impl SessionCache {
         self.next_encounter_id = id;
     }
 
+    /// Enable or disable compaction of finished encounters (enabled by default)
+    pub fn set_compaction_enabled(&mut self, enabled: bool) {
+        self.compaction_enabled = enabled;
+    }
+
     pub fn push_new_encounter(&mut self) -> u64 {
         // Finalize the current encounter before creating a new one
         self.finalize_current_encounter();
@@ -98,6 +148,10 @@ impl SessionCache {
         // Clear NPC instance tracking for fresh detection in new encounter
         self.seen_npc_instances.clear();
 
+        if self.compaction_enabled {
+            self.compact_finished_encounter();
+        }
+
         let id = self.next_encounter_id;
 
         let mut encounter = if self.player_initialized {
@@ -131,6 +185,18 @@ impl SessionCache {
         id
     }
 
+    /// Drop per-event buffers of the encounter that just ended and shrink
+    /// session maps, so long sessions don't keep peak-sized allocations around.
+    fn compact_finished_encounter(&mut self) {
+        if let Some(encounter) = self.encounters.back_mut()
+            && encounter.state != EncounterState::NotStarted
+        {
+            encounter.compact();
+            self.encounter_history.compact_latest();
+        }
+        self.seen_npc_instances.shrink_to_fit();
+    }
+
     fn trim_old_encounters(&mut self) {
         while self.encounters.len() > CACHE_DEFAULT_CAPACITY {
             self.encounters.pop_front();
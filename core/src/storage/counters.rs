@@ -0,0 +1,255 @@
+//! Persistent cross-session counters
+//!
+//! Long-term tallies such as total Revan kills or total wipes on Styrak. Each
+//! [`CounterRule`] names the boss pulls it counts; finished pulls are matched
+//! against the rules and the counts are kept in `counters.json` in the config
+//! directory together with the rules themselves.
+//!
+//! The key of the newest counted encounter is stored too, so re-parsing a log
+//! (or re-delivering the same combat end) never counts a pull twice.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+pub use baras_types::{CounterOutcome, CounterRule, CounterValue};
+
+use super::StorageError;
+use crate::encounter::summary::EncounterSummary;
+
+/// Counter file name inside the config directory
+pub const COUNTERS_FILENAME: &str = "counters.json";
+
+/// Path of the counter file (`~/.config/baras/counters.json`)
+pub fn counters_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("baras").join(COUNTERS_FILENAME))
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CounterFile {
+    #[serde(default)]
+    rules: Vec<CounterRule>,
+    #[serde(default)]
+    counts: BTreeMap<String, u64>,
+    /// Key of the newest counted encounter. Keys start with the combat start
+    /// time, so anything at or before it has been counted already.
+    #[serde(default)]
+    counted_through: Option<String>,
+}
+
+/// Counter rules and their current counts
+#[derive(Debug, Clone, Default)]
+pub struct CounterStore {
+    /// File the store is saved to (None keeps it in memory only)
+    path: Option<PathBuf>,
+    file: CounterFile,
+}
+
+impl CounterStore {
+    /// Load the store from `path`. A missing file gives an empty store.
+    pub fn load(path: PathBuf) -> Result<Self, StorageError> {
+        let file = match std::fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|e| StorageError::ReadCounters {
+                path: path.clone(),
+                reason: e.to_string(),
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => CounterFile::default(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            path: Some(path),
+            file,
+        })
+    }
+
+    pub fn rules(&self) -> &[CounterRule] {
+        &self.file.rules
+    }
+
+    /// Replace the rules, dropping the counts of removed ones
+    pub fn set_rules(&mut self, rules: Vec<CounterRule>) -> Result<(), StorageError> {
+        self.file
+            .counts
+            .retain(|id, _| rules.iter().any(|r| &r.id == id));
+        self.file.rules = rules;
+        self.save()
+    }
+
+    /// Count a finished encounter. Returns true if any counter changed.
+    pub fn record(&mut self, summary: &EncounterSummary) -> Result<bool, StorageError> {
+        let changed = self.record_pull(
+            &summary.encounter_key,
+            summary.boss_name.as_deref(),
+            summary.difficulty.as_deref(),
+            summary.success,
+        );
+        if changed {
+            self.save()?;
+        }
+        Ok(changed)
+    }
+
+    /// Count a pull against every matching rule, skipping pulls already counted
+    fn record_pull(
+        &mut self,
+        encounter_key: &str,
+        boss_name: Option<&str>,
+        difficulty: Option<&str>,
+        success: bool,
+    ) -> bool {
+        if self
+            .file
+            .counted_through
+            .as_deref()
+            .is_some_and(|through| encounter_key <= through)
+        {
+            return false;
+        }
+
+        let mut changed = false;
+        for rule in &self.file.rules {
+            if rule.matches(boss_name, difficulty, success) {
+                *self.file.counts.entry(rule.id.clone()).or_default() += 1;
+                changed = true;
+            }
+        }
+        if changed {
+            self.file.counted_through = Some(encounter_key.to_string());
+        }
+        changed
+    }
+
+    /// Every counter in rule order, including ones still at zero
+    pub fn values(&self) -> Vec<CounterValue> {
+        self.file
+            .rules
+            .iter()
+            .map(|rule| CounterValue {
+                id: rule.id.clone(),
+                label: rule.label.clone(),
+                count: self.file.counts.get(&rule.id).copied().unwrap_or(0),
+            })
+            .collect()
+    }
+
+    /// Set a counter back to zero
+    pub fn reset(&mut self, id: &str) -> Result<(), StorageError> {
+        if self.file.counts.remove(id).is_some() {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    fn save(&self) -> Result<(), StorageError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        write_file(path, &self.file)
+    }
+}
+
+fn write_file(path: &Path, file: &CounterFile) -> Result<(), StorageError> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|source| StorageError::CreateDir {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+    }
+    let json = serde_json::to_string_pretty(file).map_err(std::io::Error::from)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, boss: Option<&str>, outcome: CounterOutcome) -> CounterRule {
+        CounterRule {
+            id: id.to_string(),
+            label: id.to_string(),
+            boss: boss.map(str::to_string),
+            difficulty: None,
+            outcome,
+        }
+    }
+
+    fn count(store: &CounterStore, id: &str) -> u64 {
+        store
+            .values()
+            .into_iter()
+            .find(|v| v.id == id)
+            .map_or(0, |v| v.count)
+    }
+
+    #[test]
+    fn pulls_count_against_matching_rules_once() {
+        let mut store = CounterStore::default();
+        store.file.rules = vec![
+            rule("revan_kills", Some("Revan"), CounterOutcome::Kill),
+            rule("styrak_wipes", Some("Styrak"), CounterOutcome::Wipe),
+            rule("pulls", None, CounterOutcome::Any),
+        ];
+
+        assert!(store.record_pull("20250102-184308-revan-1", Some("revan"), None, true));
+        assert!(store.record_pull("20250102-190000-styrak-2", Some("Styrak"), None, false));
+        // Trash never counts, and already counted pulls are skipped
+        assert!(!store.record_pull("20250102-191000-trash-3", None, None, true));
+        assert!(!store.record_pull("20250102-184308-revan-1", Some("Revan"), None, true));
+
+        assert_eq!(count(&store, "revan_kills"), 1);
+        assert_eq!(count(&store, "styrak_wipes"), 1);
+        assert_eq!(count(&store, "pulls"), 2);
+    }
+
+    #[test]
+    fn difficulty_must_match_when_set() {
+        let mut store = CounterStore::default();
+        let mut nim = rule("revan_nim", Some("Revan"), CounterOutcome::Kill);
+        nim.difficulty = Some("Master".to_string());
+        store.file.rules = vec![nim];
+
+        assert!(!store.record_pull(
+            "20250102-184308-revan-1",
+            Some("Revan"),
+            Some("Veteran"),
+            true
+        ));
+        assert!(store.record_pull(
+            "20250102-194308-revan-2",
+            Some("Revan"),
+            Some("master"),
+            true
+        ));
+        assert_eq!(count(&store, "revan_nim"), 1);
+    }
+
+    #[test]
+    fn counts_survive_reload_and_removed_rules_drop_theirs() {
+        let path =
+            std::env::temp_dir().join(format!("baras-counters-test-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut store = CounterStore::load(path.clone()).unwrap();
+        store
+            .set_rules(vec![
+                rule("kills", None, CounterOutcome::Kill),
+                rule("wipes", None, CounterOutcome::Wipe),
+            ])
+            .unwrap();
+        store.record_pull("20250102-184308-revan-1", Some("Revan"), None, false);
+        store.save().unwrap();
+
+        let mut store = CounterStore::load(path.clone()).unwrap();
+        assert_eq!(count(&store, "wipes"), 1);
+        store
+            .set_rules(vec![rule("kills", None, CounterOutcome::Kill)])
+            .unwrap();
+        store
+            .set_rules(vec![rule("wipes", None, CounterOutcome::Wipe)])
+            .unwrap();
+        assert_eq!(count(&store, "wipes"), 0);
+        let _ = std::fs::remove_file(&path);
+    }
+}
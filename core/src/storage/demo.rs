@@ -0,0 +1,59 @@
+//! Bundled demo log
+//!
+//! An anonymized Dread Master Bestia pull ships zstd-compressed with the app.
+//! Loading the demo extracts it to a regular combat log file so it can be
+//! opened like any historical log.
+
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+
+/// Compressed demo log, under the bundled `definitions/demo` directory
+pub const DEMO_LOG_ARCHIVE: &str = "bestia_demo.txt.zst";
+
+/// Name of the extracted log (a regular combat log name, so its date parses)
+pub const DEMO_LOG_FILENAME: &str = "combat_2026-01-10_18_43_00_000000.txt";
+
+/// Get the directory the demo log is extracted to.
+/// Creates `~/.config/baras/demo/` if it doesn't exist.
+pub fn demo_dir() -> io::Result<PathBuf> {
+    let base = dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("baras")
+        .join("demo");
+
+    std::fs::create_dir_all(&base)?;
+    Ok(base)
+}
+
+/// Decompress the demo log archive into `dir`, replacing an earlier copy.
+/// Returns the path of the extracted log.
+pub fn extract_demo_log(archive: &Path, dir: &Path) -> io::Result<PathBuf> {
+    let path = dir.join(DEMO_LOG_FILENAME);
+    let mut decoder = zstd::Decoder::new(File::open(archive)?)?;
+    let mut out = BufWriter::new(File::create(&path)?);
+    io::copy(&mut decoder, &mut out)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{DirectoryIndex, parse_log_filename};
+
+    #[test]
+    fn test_bundled_demo_log_extracts() {
+        let archive = Path::new("definitions/demo").join(DEMO_LOG_ARCHIVE);
+        let dir = std::env::temp_dir().join(format!("baras-demo-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = extract_demo_log(&archive, &dir).unwrap();
+        assert!(parse_log_filename(DEMO_LOG_FILENAME).is_some());
+
+        let mut index = DirectoryIndex::new();
+        let entry = index.create_entry(&path).unwrap();
+        assert_eq!(entry.character_name.as_deref(), Some("Demo Healer"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -38,4 +38,10 @@ pub enum StorageError {
 
     #[error("failed to build record batch: {reason}")]
     BuildRecordBatch { reason: String },
+
+    #[error("invalid encounter history file {path}: {reason}")]
+    ReadHistory { path: PathBuf, reason: String },
+
+    #[error("invalid counter file {path}: {reason}")]
+    ReadCounters { path: PathBuf, reason: String },
 }
@@ -0,0 +1,378 @@
+//! Persistent encounter history
+//!
+//! [`EncounterHistory`](crate::EncounterHistory) only lives as long as the
+//! session. Finished encounters are also kept in `history/<log file>.parquet`
+//! in the config directory, one row per encounter, so boss pulls with their
+//! outcome, duration and the local player's DPS survive restarts.
+//!
+//! Recording upserts by encounter key, so re-parsing a log (or parsing it in
+//! several passes) never duplicates or drops encounters.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, BooleanBuilder, Int64Array, Int64Builder, StringArray,
+    StringBuilder,
+};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+pub use baras_types::{HistoricalEncounter, HistoryFilter};
+
+use super::StorageError;
+use crate::encounter::summary::EncounterSummary;
+
+/// History directory name inside the config directory
+pub const HISTORY_DIRNAME: &str = "history";
+
+/// Get the encounter history directory (`~/.config/baras/history/`), creating it
+/// if needed. Unlike [`data_dir`](super::data_dir) it is never cleared.
+pub fn history_dir() -> std::io::Result<PathBuf> {
+    let base = dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("baras")
+        .join(HISTORY_DIRNAME);
+
+    std::fs::create_dir_all(&base)?;
+    Ok(base)
+}
+
+/// History record of a session summary, with the DPS of player `player_id`
+pub fn to_historical(
+    summary: &EncounterSummary,
+    log_file: &str,
+    player_id: i64,
+) -> HistoricalEncounter {
+    let player = summary
+        .player_metrics
+        .iter()
+        .find(|m| m.entity_id == player_id);
+
+    HistoricalEncounter {
+        encounter_key: summary.encounter_key.clone(),
+        log_file: log_file.to_string(),
+        display_name: summary.display_name.clone(),
+        start_time: summary.start_time.clone(),
+        duration_secs: summary.duration_seconds,
+        area_name: summary.area_name.clone(),
+        difficulty: summary.difficulty.clone(),
+        boss_name: summary.boss_name.clone(),
+        success: summary.success,
+        player_name: player.map(|p| p.name.clone()).unwrap_or_default(),
+        discipline: player.and_then(|p| p.discipline_name.clone()),
+        dps: player.map_or(0, |p| p.dps),
+    }
+}
+
+/// Encounters of every parsed log, one parquet file per log
+#[derive(Debug, Clone)]
+pub struct EncounterHistoryStore {
+    dir: PathBuf,
+}
+
+impl EncounterHistoryStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Store in the default [`history_dir`]
+    pub fn open_default() -> Result<Self, StorageError> {
+        let dir = history_dir().map_err(|source| StorageError::CreateDir {
+            path: PathBuf::from(HISTORY_DIRNAME),
+            source,
+        })?;
+        Ok(Self::new(dir))
+    }
+
+    fn log_path(&self, log_file: &str) -> PathBuf {
+        self.dir.join(format!("{}.parquet", log_file))
+    }
+
+    /// Add or update the encounters of `log_file`, keeping ones stored earlier
+    pub fn record(
+        &self,
+        log_file: &str,
+        encounters: &[HistoricalEncounter],
+    ) -> Result<(), StorageError> {
+        if encounters.is_empty() {
+            return Ok(());
+        }
+        let path = self.log_path(log_file);
+        let mut stored: BTreeMap<String, HistoricalEncounter> = if path.exists() {
+            read_file(&path)?
+                .into_iter()
+                .map(|e| (e.encounter_key.clone(), e))
+                .collect()
+        } else {
+            BTreeMap::new()
+        };
+
+        let mut changed = false;
+        for encounter in encounters {
+            if stored.get(&encounter.encounter_key) != Some(encounter) {
+                stored.insert(encounter.encounter_key.clone(), encounter.clone());
+                changed = true;
+            }
+        }
+        if !changed {
+            return Ok(());
+        }
+
+        // Keys start with the combat start time, so this is chronological
+        let rows: Vec<HistoricalEncounter> = stored.into_values().collect();
+        write_file(&path, &rows)
+    }
+
+    /// Encounters matching `filter` across all logs, newest first
+    pub fn query(&self, filter: &HistoryFilter) -> Result<Vec<HistoricalEncounter>, StorageError> {
+        let mut encounters = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "parquet") {
+                continue;
+            }
+            match read_file(&path) {
+                Ok(rows) => encounters.extend(rows.into_iter().filter(|e| filter.matches(e))),
+                Err(e) => tracing::warn!(
+                    error = %e,
+                    path = %path.display(),
+                    "Skipping unreadable encounter history"
+                ),
+            }
+        }
+
+        encounters.sort_by(|a, b| b.encounter_key.cmp(&a.encounter_key));
+        if let Some(limit) = filter.limit {
+            encounters.truncate(limit);
+        }
+        Ok(encounters)
+    }
+}
+
+fn schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("encounter_key", DataType::Utf8, false),
+        Field::new("log_file", DataType::Utf8, false),
+        Field::new("display_name", DataType::Utf8, false),
+        Field::new("start_time", DataType::Utf8, true),
+        Field::new("duration_secs", DataType::Int64, false),
+        Field::new("area_name", DataType::Utf8, false),
+        Field::new("difficulty", DataType::Utf8, true),
+        Field::new("boss_name", DataType::Utf8, true),
+        Field::new("success", DataType::Boolean, false),
+        Field::new("player_name", DataType::Utf8, false),
+        Field::new("discipline", DataType::Utf8, true),
+        Field::new("dps", DataType::Int64, false),
+    ]))
+}
+
+fn string_column<'a>(
+    rows: &'a [HistoricalEncounter],
+    value: impl Fn(&'a HistoricalEncounter) -> Option<&'a str>,
+) -> ArrayRef {
+    let mut builder = StringBuilder::new();
+    for row in rows {
+        builder.append_option(value(row));
+    }
+    Arc::new(builder.finish())
+}
+
+fn int_column(
+    rows: &[HistoricalEncounter],
+    value: impl Fn(&HistoricalEncounter) -> i64,
+) -> ArrayRef {
+    let mut builder = Int64Builder::new();
+    for row in rows {
+        builder.append_value(value(row));
+    }
+    Arc::new(builder.finish())
+}
+
+fn write_file(path: &Path, rows: &[HistoricalEncounter]) -> Result<(), StorageError> {
+    let mut success = BooleanBuilder::new();
+    for row in rows {
+        success.append_value(row.success);
+    }
+
+    let schema = schema();
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            string_column(rows, |r| Some(&r.encounter_key)),
+            string_column(rows, |r| Some(&r.log_file)),
+            string_column(rows, |r| Some(&r.display_name)),
+            string_column(rows, |r| r.start_time.as_deref()),
+            int_column(rows, |r| r.duration_secs),
+            string_column(rows, |r| Some(&r.area_name)),
+            string_column(rows, |r| r.difficulty.as_deref()),
+            string_column(rows, |r| r.boss_name.as_deref()),
+            Arc::new(success.finish()),
+            string_column(rows, |r| Some(&r.player_name)),
+            string_column(rows, |r| r.discipline.as_deref()),
+            int_column(rows, |r| r.dps),
+        ],
+    )?;
+
+    // Write next to the target and rename, so a crash never leaves a torn file
+    let tmp = path.with_extension("parquet.tmp");
+    let file = File::create(&tmp).map_err(|source| StorageError::CreateFile {
+        path: tmp.clone(),
+        source,
+    })?;
+    let props = WriterProperties::builder()
+        .set_compression(Compression::LZ4)
+        .build();
+    let mut writer = ArrowWriter::try_new(file, schema, Some(props))?;
+    writer.write(&batch)?;
+    writer
+        .close()
+        .map_err(|source| StorageError::WriteParquet {
+            path: path.to_path_buf(),
+            source,
+        })?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Column `name` of `batch` as array type `T`
+fn typed_column<T: Array + Clone + 'static>(
+    batch: &RecordBatch,
+    path: &Path,
+    name: &str,
+) -> Result<T, StorageError> {
+    batch
+        .column_by_name(name)
+        .and_then(|column| column.as_any().downcast_ref::<T>())
+        .cloned()
+        .ok_or_else(|| StorageError::ReadHistory {
+            path: path.to_path_buf(),
+            reason: format!("missing or mistyped column {}", name),
+        })
+}
+
+fn optional(array: &StringArray, i: usize) -> Option<String> {
+    (!array.is_null(i)).then(|| array.value(i).to_string())
+}
+
+fn read_file(path: &Path) -> Result<Vec<HistoricalEncounter>, StorageError> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?.build()?;
+
+    let mut rows = Vec::new();
+    for batch in reader {
+        let batch = batch?;
+        let strings = |name| typed_column::<StringArray>(&batch, path, name);
+        let ints = |name| typed_column::<Int64Array>(&batch, path, name);
+
+        let encounter_key = strings("encounter_key")?;
+        let log_file = strings("log_file")?;
+        let display_name = strings("display_name")?;
+        let start_time = strings("start_time")?;
+        let duration_secs = ints("duration_secs")?;
+        let area_name = strings("area_name")?;
+        let difficulty = strings("difficulty")?;
+        let boss_name = strings("boss_name")?;
+        let success = typed_column::<BooleanArray>(&batch, path, "success")?;
+        let player_name = strings("player_name")?;
+        let discipline = strings("discipline")?;
+        let dps = ints("dps")?;
+
+        for i in 0..batch.num_rows() {
+            rows.push(HistoricalEncounter {
+                encounter_key: encounter_key.value(i).to_string(),
+                log_file: log_file.value(i).to_string(),
+                display_name: display_name.value(i).to_string(),
+                start_time: optional(&start_time, i),
+                duration_secs: duration_secs.value(i),
+                area_name: area_name.value(i).to_string(),
+                difficulty: optional(&difficulty, i),
+                boss_name: optional(&boss_name, i),
+                success: success.value(i),
+                player_name: player_name.value(i).to_string(),
+                discipline: optional(&discipline, i),
+                dps: dps.value(i),
+            });
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encounter(key: &str, boss: Option<&str>, day: &str, success: bool) -> HistoricalEncounter {
+        HistoricalEncounter {
+            encounter_key: key.to_string(),
+            log_file: "combat_test.txt".to_string(),
+            display_name: boss.unwrap_or("Trash").to_string(),
+            start_time: Some(format!("{}T20:00:00", day)),
+            duration_secs: 300,
+            area_name: "Dread Palace".to_string(),
+            difficulty: Some("Veteran 8".to_string()),
+            boss_name: boss.map(str::to_string),
+            success,
+            player_name: "Jerran Zeva".to_string(),
+            discipline: Some("Combat Medic".to_string()),
+            dps: 12_000,
+        }
+    }
+
+    #[test]
+    fn test_history_upserts_and_filters() {
+        let dir = std::env::temp_dir().join(format!("baras-history-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let store = EncounterHistoryStore::new(dir.clone());
+
+        let wipe = encounter(
+            "20250102-200000-bestia-1",
+            Some("Dread Master Bestia"),
+            "2025-01-02",
+            false,
+        );
+        let trash = encounter("20250102-201000-trash-2", None, "2025-01-02", true);
+        store
+            .record("combat_test.txt", &[wipe.clone(), trash.clone()])
+            .unwrap();
+
+        // A later pass re-records the trash pull and adds a kill
+        let kill = encounter(
+            "20250103-200000-bestia-3",
+            Some("Dread Master Bestia"),
+            "2025-01-03",
+            true,
+        );
+        store
+            .record("combat_test.txt", &[trash.clone(), kill.clone()])
+            .unwrap();
+
+        let all = store.query(&HistoryFilter::default()).unwrap();
+        assert_eq!(all, vec![kill.clone(), trash, wipe.clone()]);
+
+        let bosses = store
+            .query(&HistoryFilter {
+                boss: Some("dread master bestia".to_string()),
+                to_date: Some("2025-01-02".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(bosses, vec![wipe]);
+
+        let latest = store
+            .query(&HistoryFilter {
+                bosses_only: true,
+                limit: Some(1),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(latest, vec![kill]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,567 @@
+//! Compressed on-disk journal of every [`GameSignal`] in a session.
+//!
+//! Each signal becomes one tab-separated line (`timestamp, kind, subject, detail`).
+//! Lines are buffered and appended to the journal file as independent zstd frames,
+//! so a reader always sees complete frames even while the session is still live.
+//! Once the file reaches [`MAX_JOURNAL_BYTES`] only timeline signals (combat
+//! start/end, area changes, player deaths, phase changes) are kept, which keeps the
+//! file bounded without losing the session timeline.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use baras_types::{TimelineEvent, TimelineEventKind};
+use chrono::NaiveDateTime;
+
+use super::StorageError;
+use crate::combat_log::EntityType;
+use crate::context::resolve;
+use crate::encounter::CombatEncounter;
+use crate::signal_processor::{GameSignal, SignalHandler};
+
+/// Journal file name inside a session's encounters directory
+pub const JOURNAL_FILENAME: &str = "signals.journal.zst";
+
+/// Journal written by a backfill parse, prepended to the session journal once done
+pub const BACKFILL_JOURNAL_FILENAME: &str = "signals.backfill.journal.zst";
+
+/// Compressed size after which only timeline signals are recorded
+pub const MAX_JOURNAL_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Uncompressed bytes buffered before a frame is written
+const FLUSH_THRESHOLD: usize = 1024 * 1024;
+
+const ZSTD_LEVEL: i32 = 3;
+
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3f";
+
+/// One decoded journal line
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalEntry {
+    pub timestamp: NaiveDateTime,
+    /// Signal variant name (e.g. `EntityDeath`)
+    pub kind: String,
+    /// Main subject of the signal (entity, effect, ability, area, phase...)
+    pub subject: String,
+    /// Secondary information (source/target, entity type, values...)
+    pub detail: String,
+}
+
+/// Appends signals to the journal file of a session
+pub struct SignalJournal {
+    path: PathBuf,
+    buffer: Vec<u8>,
+    written: u64,
+}
+
+impl SignalJournal {
+    /// Start a new journal at `path`, replacing any previous one
+    pub fn create(path: PathBuf) -> Result<Self, StorageError> {
+        File::create(&path).map_err(|source| StorageError::CreateFile {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(Self {
+            path,
+            buffer: Vec::with_capacity(FLUSH_THRESHOLD),
+            written: 0,
+        })
+    }
+
+    /// Continue an existing journal (e.g. one written by the parse worker)
+    pub fn append(path: PathBuf) -> Self {
+        let written = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        Self {
+            path,
+            buffer: Vec::with_capacity(FLUSH_THRESHOLD),
+            written,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the size cap was reached and only timeline signals are recorded
+    pub fn is_full(&self) -> bool {
+        self.written >= MAX_JOURNAL_BYTES
+    }
+
+    /// Buffer a signal, writing a frame when a timeline signal arrives or the
+    /// buffer is large enough
+    pub fn record(&mut self, signal: &GameSignal) -> Result<(), StorageError> {
+        let timeline = is_timeline_signal(signal);
+        if !timeline && self.is_full() {
+            return Ok(());
+        }
+
+        let (kind, subject, detail) = describe(signal);
+        let _ = writeln!(
+            self.buffer,
+            "{}\t{}\t{}\t{}",
+            signal.timestamp().format(TIMESTAMP_FORMAT),
+            kind,
+            sanitize(&subject),
+            sanitize(&detail)
+        );
+
+        if timeline || self.buffer.len() >= FLUSH_THRESHOLD {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Compress buffered lines into one frame and append it to the file
+    pub fn flush(&mut self) -> Result<(), StorageError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let was_full = self.is_full();
+        let frame = zstd::encode_all(self.buffer.as_slice(), ZSTD_LEVEL);
+        // Lines of a failed frame are dropped rather than retried on every signal
+        self.buffer.clear();
+        let frame = frame?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(&frame)?;
+        // Re-read the size: the parse worker may have written to the same file
+        self.written = file.metadata()?.len();
+
+        if !was_full && self.is_full() {
+            tracing::warn!(
+                path = %self.path.display(),
+                max_bytes = MAX_JOURNAL_BYTES,
+                "Signal journal full, recording timeline signals only"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl SignalHandler for SignalJournal {
+    fn handle_signal(&mut self, signal: &GameSignal, _encounter: Option<&CombatEncounter>) {
+        if let Err(e) = self.record(signal) {
+            tracing::warn!(error = %e, path = %self.path.display(), "Failed to write signal journal");
+        }
+    }
+}
+
+impl Drop for SignalJournal {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            tracing::warn!(error = %e, path = %self.path.display(), "Failed to flush signal journal");
+        }
+    }
+}
+
+/// Move the frames of the journal at `earlier` in front of the journal at `path`.
+///
+/// Used when a backfill parse recorded the start of a session after its end was
+/// already journaled. Callers must keep other writers of `path` from flushing
+/// until this returns.
+pub fn prepend_journal(earlier: &Path, path: &Path) -> Result<(), StorageError> {
+    let mut combined = std::fs::read(earlier)?;
+    match std::fs::read(path) {
+        Ok(later) => combined.extend(later),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+    let tmp = path.with_extension("zst.tmp");
+    std::fs::write(&tmp, combined)?;
+    std::fs::rename(&tmp, path)?;
+    std::fs::remove_file(earlier)?;
+    Ok(())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Reading
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Decode every entry of a journal file, in recording order
+pub fn read_journal(path: &Path) -> Result<Vec<JournalEntry>, StorageError> {
+    let file = File::open(path)?;
+    // The decoder reads concatenated frames as one stream
+    let reader = BufReader::new(zstd::Decoder::new(file)?);
+
+    let mut entries = Vec::new();
+    for line in reader.lines() {
+        if let Some(entry) = parse_line(&line?) {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
+/// Combat starts/ends, area changes, player deaths and phase changes of a session
+pub fn read_timeline(path: &Path) -> Result<Vec<TimelineEvent>, StorageError> {
+    Ok(build_timeline(&read_journal(path)?))
+}
+
+fn build_timeline(entries: &[JournalEntry]) -> Vec<TimelineEvent> {
+    let Some(first) = entries.first() else {
+        return Vec::new();
+    };
+    let session_start = first.timestamp;
+    let mut current_area: Option<&str> = None;
+
+    let mut events = Vec::new();
+    for entry in entries {
+        let (kind, label) = match entry.kind.as_str() {
+            "CombatStarted" => (TimelineEventKind::CombatStarted, String::new()),
+            "CombatEnded" => (TimelineEventKind::CombatEnded, String::new()),
+            "AreaEntered" => {
+                // The game repeats AreaEntered on every load screen
+                if current_area == Some(entry.subject.as_str()) {
+                    continue;
+                }
+                current_area = Some(&entry.subject);
+                let label = if entry.detail.is_empty() {
+                    entry.subject.clone()
+                } else {
+                    format!("{} ({})", entry.subject, entry.detail)
+                };
+                (TimelineEventKind::AreaChanged, label)
+            }
+            "EntityDeath" if entry.detail == "Player" => {
+                (TimelineEventKind::PlayerDeath, entry.subject.clone())
+            }
+            "PhaseChanged" => (TimelineEventKind::PhaseChanged, entry.subject.clone()),
+            _ => continue,
+        };
+        events.push(TimelineEvent {
+            kind,
+            time: entry.timestamp.format("%H:%M:%S").to_string(),
+            offset_secs: (entry.timestamp - session_start).num_milliseconds() as f64 / 1000.0,
+            label,
+        });
+    }
+    events
+}
+
+fn parse_line(line: &str) -> Option<JournalEntry> {
+    let mut fields = line.splitn(4, '\t');
+    let timestamp = NaiveDateTime::parse_from_str(fields.next()?, TIMESTAMP_FORMAT).ok()?;
+    Some(JournalEntry {
+        timestamp,
+        kind: fields.next()?.to_string(),
+        subject: fields.next()?.to_string(),
+        detail: fields.next().unwrap_or_default().to_string(),
+    })
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Signal Formatting
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn is_timeline_signal(signal: &GameSignal) -> bool {
+    matches!(
+        signal,
+        GameSignal::CombatStarted { .. }
+            | GameSignal::CombatEnded { .. }
+            | GameSignal::AreaEntered { .. }
+            | GameSignal::PhaseChanged { .. }
+            | GameSignal::EntityDeath {
+                entity_type: EntityType::Player,
+                ..
+            }
+    )
+}
+
+/// Tabs and newlines separate fields and lines
+fn sanitize(value: &str) -> std::borrow::Cow<'_, str> {
+    if value.contains(['\t', '\n', '\r']) {
+        value.replace(['\t', '\n', '\r'], " ").into()
+    } else {
+        value.into()
+    }
+}
+
+/// `(kind, subject, detail)` for a signal
+fn describe(signal: &GameSignal) -> (&'static str, String, String) {
+    match signal {
+        GameSignal::CombatStarted { encounter_id, .. } => {
+            ("CombatStarted", encounter_id.to_string(), String::new())
+        }
+        GameSignal::CombatEnded { encounter_id, .. } => {
+            ("CombatEnded", encounter_id.to_string(), String::new())
+        }
+        GameSignal::EntityDeath {
+            entity_name,
+            entity_type,
+            ..
+        } => (
+            "EntityDeath",
+            entity_name.clone(),
+            format!("{:?}", entity_type),
+        ),
+        GameSignal::EntityRevived {
+            entity_id,
+            entity_type,
+            ..
+        } => (
+            "EntityRevived",
+            entity_id.to_string(),
+            format!("{:?}", entity_type),
+        ),
+        GameSignal::NpcFirstSeen {
+            entity_name,
+            npc_id,
+            ..
+        } => ("NpcFirstSeen", entity_name.clone(), npc_id.to_string()),
+        GameSignal::EffectApplied {
+            effect_name,
+            source_name,
+            target_name,
+            ..
+        } => (
+            "EffectApplied",
+            resolve(*effect_name).to_string(),
+            format!("{} -> {}", resolve(*source_name), resolve(*target_name)),
+        ),
+        GameSignal::EffectRemoved {
+            effect_name,
+            source_name,
+            target_name,
+            ..
+        } => (
+            "EffectRemoved",
+            resolve(*effect_name).to_string(),
+            format!("{} -> {}", resolve(*source_name), resolve(*target_name)),
+        ),
+        GameSignal::EffectChargesChanged {
+            effect_name,
+            charges,
+            ..
+        } => (
+            "EffectChargesChanged",
+            resolve(*effect_name).to_string(),
+            charges.to_string(),
+        ),
+        GameSignal::EffectDispelled {
+            effect_name,
+            source_name,
+            target_name,
+            ..
+        } => (
+            "EffectDispelled",
+            resolve(*effect_name).to_string(),
+            format!("{} -> {}", resolve(*source_name), resolve(*target_name)),
+        ),
+        GameSignal::AbilityActivated {
+            ability_name,
+            source_name,
+            target_name,
+            ..
+        } => (
+            "AbilityActivated",
+            resolve(*ability_name).to_string(),
+            format!("{} -> {}", resolve(*source_name), resolve(*target_name)),
+        ),
+        GameSignal::AbilityDeactivated {
+            ability_name,
+            source_name,
+            target_name,
+            ..
+        } => (
+            "AbilityDeactivated",
+            resolve(*ability_name).to_string(),
+            format!("{} -> {}", resolve(*source_name), resolve(*target_name)),
+        ),
+        GameSignal::DamageTaken {
+            ability_name,
+            source_name,
+            target_name,
+            ..
+        } => (
+            "DamageTaken",
+            resolve(*ability_name).to_string(),
+            format!("{} -> {}", resolve(*source_name), resolve(*target_name)),
+        ),
+        GameSignal::TargetChanged {
+            source_name,
+            target_name,
+            ..
+        } => (
+            "TargetChanged",
+            resolve(*source_name).to_string(),
+            resolve(*target_name).to_string(),
+        ),
+        GameSignal::TargetCleared { source_id, .. } => {
+            ("TargetCleared", source_id.to_string(), String::new())
+        }
+        GameSignal::AreaEntered {
+            area_name,
+            difficulty_name,
+            ..
+        } => ("AreaEntered", area_name.clone(), difficulty_name.clone()),
+        GameSignal::PlayerInitialized { entity_id, .. } => {
+            ("PlayerInitialized", entity_id.to_string(), String::new())
+        }
+        GameSignal::DisciplineChanged {
+            entity_id,
+            class_id,
+            discipline_id,
+            ..
+        } => (
+            "DisciplineChanged",
+            entity_id.to_string(),
+            format!("{}/{}", class_id, discipline_id),
+        ),
+        GameSignal::BossEncounterDetected {
+            definition_id,
+            boss_name,
+            ..
+        } => (
+            "BossEncounterDetected",
+            boss_name.clone(),
+            definition_id.clone(),
+        ),
+        GameSignal::BossHpChanged {
+            entity_name,
+            new_hp_percent,
+            ..
+        } => (
+            "BossHpChanged",
+            entity_name.clone(),
+            format!("{:.1}", new_hp_percent),
+        ),
+        GameSignal::PhaseChanged {
+            boss_id, new_phase, ..
+        } => ("PhaseChanged", new_phase.clone(), boss_id.clone()),
+        GameSignal::PhaseEndTriggered { phase_id, .. } => {
+            ("PhaseEndTriggered", phase_id.clone(), String::new())
+        }
+        GameSignal::CounterChanged {
+            counter_id,
+            old_value,
+            new_value,
+            ..
+        } => (
+            "CounterChanged",
+            counter_id.clone(),
+            format!("{} -> {}", old_value, new_value),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(secs: u32) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(2025, 1, 2)
+            .unwrap()
+            .and_hms_opt(20, 0, secs)
+            .unwrap()
+    }
+
+    fn death(name: &str, entity_type: EntityType, secs: u32) -> GameSignal {
+        GameSignal::EntityDeath {
+            entity_id: 1,
+            entity_type,
+            npc_id: 0,
+            entity_name: name.to_string(),
+            timestamp: ts(secs),
+        }
+    }
+
+    #[test]
+    fn test_journal_round_trip_builds_timeline() {
+        let path =
+            std::env::temp_dir().join(format!("baras-journal-test-{}.zst", std::process::id()));
+        let area = GameSignal::AreaEntered {
+            area_id: 1,
+            area_name: "Dxun".to_string(),
+            difficulty_id: 2,
+            difficulty_name: "8 Player Veteran".to_string(),
+            timestamp: ts(0),
+        };
+
+        let mut journal = SignalJournal::create(path.clone()).unwrap();
+        journal.record(&area).unwrap();
+        journal.record(&area).unwrap();
+        journal
+            .record(&GameSignal::CombatStarted {
+                timestamp: ts(10),
+                encounter_id: 1,
+            })
+            .unwrap();
+        journal
+            .record(&death("Trash Droid", EntityType::Npc, 12))
+            .unwrap();
+        journal
+            .record(&death("Tank\tName", EntityType::Player, 15))
+            .unwrap();
+        drop(journal);
+
+        // A second writer continues the same file
+        let mut journal = SignalJournal::append(path.clone());
+        journal
+            .record(&GameSignal::CombatEnded {
+                timestamp: ts(20),
+                encounter_id: 1,
+            })
+            .unwrap();
+        drop(journal);
+
+        let entries = read_journal(&path).unwrap();
+        assert_eq!(entries.len(), 6);
+
+        let timeline = read_timeline(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let kinds: Vec<_> = timeline.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TimelineEventKind::AreaChanged,
+                TimelineEventKind::CombatStarted,
+                TimelineEventKind::PlayerDeath,
+                TimelineEventKind::CombatEnded,
+            ]
+        );
+        assert_eq!(timeline[0].label, "Dxun (8 Player Veteran)");
+        assert_eq!(timeline[2].label, "Tank Name");
+        assert_eq!(timeline[2].time, "20:00:15");
+        assert_eq!(timeline[3].offset_secs, 20.0);
+    }
+
+    #[test]
+    fn test_prepend_journal_keeps_order() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("baras-journal-prepend-{}.zst", std::process::id()));
+        let earlier = path.with_extension("earlier");
+        let combat = |secs, start| {
+            if start {
+                GameSignal::CombatStarted {
+                    timestamp: ts(secs),
+                    encounter_id: 1,
+                }
+            } else {
+                GameSignal::CombatEnded {
+                    timestamp: ts(secs),
+                    encounter_id: 1,
+                }
+            }
+        };
+
+        let mut journal = SignalJournal::create(path.clone()).unwrap();
+        journal.record(&combat(30, true)).unwrap();
+        drop(journal);
+        let mut journal = SignalJournal::create(earlier.clone()).unwrap();
+        journal.record(&combat(10, true)).unwrap();
+        journal.record(&combat(20, false)).unwrap();
+        drop(journal);
+
+        prepend_journal(&earlier, &path).unwrap();
+        let entries = read_journal(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(!earlier.exists());
+        let times: Vec<_> = entries.iter().map(|e| e.timestamp).collect();
+        assert_eq!(times, vec![ts(10), ts(20), ts(30)]);
+    }
+}
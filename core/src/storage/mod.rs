@@ -1,16 +1,36 @@
 //! Storage module for writing combat events to Parquet files.
 //!
 //! Each encounter is written to a separate parquet file with denormalized metadata.
-//! Files are named `{encounter_idx:04}.parquet` (e.g., 0001.parquet, 0002.parquet).
+//! Files are named after the encounter key (e.g., `20250102-184308-bestia-5e1f03a2.parquet`),
+//! which only depends on the encounter itself, so re-parsing a log yields the same names.
 
+mod counters;
+mod demo;
 pub mod error;
+mod history;
+mod journal;
 mod writer;
 
+pub use counters::{
+    COUNTERS_FILENAME, CounterOutcome, CounterRule, CounterStore, CounterValue, counters_path,
+};
+pub use demo::{DEMO_LOG_ARCHIVE, DEMO_LOG_FILENAME, demo_dir, extract_demo_log};
 pub use error::StorageError;
+pub use history::{
+    EncounterHistoryStore, HISTORY_DIRNAME, HistoricalEncounter, HistoryFilter, history_dir,
+    to_historical,
+};
+
+pub use journal::{
+    BACKFILL_JOURNAL_FILENAME, JOURNAL_FILENAME, JournalEntry, MAX_JOURNAL_BYTES, SignalJournal,
+    prepend_journal, read_journal, read_timeline,
+};
 
 pub use writer::{EncounterWriter, EventMetadata, EventRow};
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDateTime;
 
 /// Get the data storage directory for parquet files.
 /// Creates `~/.config/baras/data/` (or equivalent on Windows/Mac) if it doesn't exist.
@@ -35,23 +55,117 @@ pub fn encounters_dir(session_id: &str) -> std::io::Result<PathBuf> {
 /// Clear all data in the data directory.
 /// Called on app startup and when switching log files.
 pub fn clear_data_dir() -> std::io::Result<()> {
-    let dir = data_dir()?;
-    if dir.exists() {
-        // Remove all contents but keep the directory itself
-        for entry in std::fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.is_dir() {
-                std::fs::remove_dir_all(&path)?;
-            } else {
-                std::fs::remove_file(&path)?;
-            }
+    clear_data_dir_except(None)
+}
+
+/// Clear the data directory, keeping the encounters of session `keep` (if any).
+/// Used when a parse checkpoint lets that session resume without re-parsing.
+pub fn clear_data_dir_except(keep: Option<&str>) -> std::io::Result<()> {
+    clear_dir_except(&data_dir()?, keep)
+}
+
+/// Remove all contents of `dir` but the entry named `keep`, keeping `dir` itself
+fn clear_dir_except(dir: &Path, keep: Option<&str>) -> std::io::Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if keep.is_some_and(|keep| entry.file_name() == keep) {
+            continue;
+        }
+        let path = entry.path();
+        if path.is_dir() {
+            std::fs::remove_dir_all(&path)?;
+        } else {
+            std::fs::remove_file(&path)?;
         }
     }
     Ok(())
 }
 
-/// Generate parquet filename for an encounter.
-pub fn encounter_filename(encounter_idx: u32) -> String {
-    format!("{:04}.parquet", encounter_idx)
+/// Stable key for an encounter: `{start}-{boss}-{hash}`.
+///
+/// Built from the combat start time, the boss definition id (`trash` if none) and
+/// the area, so it is identical across re-parses of the same log. The timestamp
+/// prefix keeps keys in chronological order when sorted; the hash, which covers
+/// the start's milliseconds, tells apart pulls that start within the same
+/// second. Pulls of the same boss in the same area that start in the same
+/// millisecond share a key.
+pub fn encounter_key(start: NaiveDateTime, boss_id: Option<&str>, area_id: Option<i64>) -> String {
+    let boss = boss_id.unwrap_or("trash");
+
+    // FNV-1a: stable across builds and platforms, unlike std's hasher
+    let mut hash: u32 = 0x811c_9dc5;
+    let start_ms = start.and_utc().timestamp_millis().to_le_bytes();
+    let area = area_id.unwrap_or(0).to_le_bytes();
+    for byte in start_ms.iter().chain(&area).chain(boss.as_bytes()) {
+        hash ^= u32::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+
+    let slug: String = boss
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("{}-{}-{:08x}", start.format("%Y%m%d-%H%M%S"), slug, hash)
+}
+
+/// Generate parquet filename for an encounter key.
+pub fn encounter_filename(encounter_key: &str) -> String {
+    format!("{}.parquet", encounter_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_dir_keeps_checkpointed_session() {
+        let dir = std::env::temp_dir().join(format!("baras-data-clear-{}", std::process::id()));
+        let keep = dir.join("combat_2025-01-01_20_00_00_000000.txt");
+        let other = dir.join("combat_2025-01-02_20_00_00_000000.txt");
+        std::fs::create_dir_all(&keep).unwrap();
+        std::fs::create_dir_all(&other).unwrap();
+        std::fs::write(keep.join("0001.parquet"), b"kept").unwrap();
+        std::fs::write(dir.join("stray.parquet"), b"").unwrap();
+
+        clear_dir_except(&dir, Some("combat_2025-01-01_20_00_00_000000.txt")).unwrap();
+        assert!(keep.join("0001.parquet").exists());
+        assert!(!other.exists());
+        assert!(!dir.join("stray.parquet").exists());
+
+        clear_dir_except(&dir, None).unwrap();
+        assert!(dir.is_dir());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_encounter_key_same_second_pulls() {
+        let start = chrono::NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_milli_opt(20, 0, 5, 100)
+            .unwrap();
+        let key = encounter_key(start, Some("bestia"), Some(1));
+        assert!(key.starts_with("20250101-200005-bestia-"));
+
+        // A later pull in the same second gets its own key
+        let later = start + chrono::TimeDelta::milliseconds(400);
+        assert_ne!(encounter_key(later, Some("bestia"), Some(1)), key);
+        assert_ne!(encounter_key(start, None, Some(1)), key);
+        assert_ne!(encounter_key(start, Some("bestia"), Some(2)), key);
+
+        // Log timestamps stop at milliseconds, so pulls starting on the same one collide
+        let same_ms = start + chrono::TimeDelta::microseconds(500);
+        assert_eq!(encounter_key(same_ms, Some("bestia"), Some(1)), key);
+    }
 }
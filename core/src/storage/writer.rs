@@ -126,7 +126,7 @@ impl EventRow {
             dmg_type_id: event.details.dmg_type_id,
             dmg_type: resolve(event.details.dmg_type).to_string(),
             is_crit: event.details.is_crit,
-            is_reflect: event.details.is_reflect,
+            is_reflect: event.is_reflect_damage(),
             defense_type_id: event.details.defense_type_id,
 
             // Healing details
@@ -264,6 +264,34 @@ impl EncounterWriter {
         self.rows.clear();
     }
 
+    /// Estimated heap usage of the buffered rows, including their strings (bytes)
+    pub fn estimated_heap_bytes(&self) -> usize {
+        use crate::memory::{opt_string_bytes, vec_bytes};
+
+        let strings: usize = self
+            .rows
+            .iter()
+            .map(|r| {
+                r.source_name.capacity()
+                    + r.target_name.capacity()
+                    + r.ability_name.capacity()
+                    + r.effect_name.capacity()
+                    + r.effect_type_name.capacity()
+                    + r.dmg_type.capacity()
+                    + r.area_name.capacity()
+                    + opt_string_bytes(&r.phase_id)
+                    + opt_string_bytes(&r.phase_name)
+                    + opt_string_bytes(&r.boss_name)
+                    + opt_string_bytes(&r.difficulty)
+                    + r.active_shields
+                        .as_ref()
+                        .map_or(0, |s| vec_bytes::<ShieldContext>(s.capacity()))
+            })
+            .sum();
+
+        vec_bytes::<EventRow>(self.rows.capacity()) + strings
+    }
+
     /// Get a RecordBatch snapshot of buffered rows for querying.
     /// Returns None if buffer is empty.
     pub fn to_record_batch(&self) -> Option<RecordBatch> {
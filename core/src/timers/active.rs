@@ -16,6 +16,19 @@ use chrono::NaiveDateTime;
 
 use crate::dsl::AudioConfig;
 
+/// How far system time has run ahead of a game event when it is processed
+///
+/// Covers file I/O delay, polling and processing time. Timers and effects
+/// backdate their realtime start by this much so countdowns reflect when the
+/// event happened in game. Events stamped in the future count as no lag.
+pub fn lag_compensation(now_system: NaiveDateTime, event_timestamp: NaiveDateTime) -> Duration {
+    let lag_ms = now_system
+        .signed_duration_since(event_timestamp)
+        .num_milliseconds()
+        .max(0) as u64;
+    Duration::from_millis(lag_ms)
+}
+
 /// An active timer instance
 ///
 /// Created when a `TimerDefinition`'s trigger condition is met.
@@ -85,14 +98,26 @@ pub struct ActiveTimer {
     /// Audio file to play when timer expires (or at offset)
     pub audio_file: Option<String>,
 
+    /// Volume override for `audio_file` (None = global volume)
+    pub audio_volume: Option<u8>,
+
     /// Seconds before expiration to play audio (0 = on expiration)
     pub audio_offset: u8,
 
+    /// Speak the timer's alert text instead of playing `audio_file`
+    pub speak_alert: bool,
+
     /// Whether the offset audio has been fired
     audio_offset_fired: bool,
 
     /// Which overlay should display this timer
     pub display_target: crate::timers::TimerDisplayTarget,
+
+    /// Cooldown suggested beside the bar (resolved for the local player's discipline)
+    pub suggested_cooldown: Option<crate::timers::CooldownSuggestion>,
+
+    /// Priority of the alerts this timer fires
+    pub alert_priority: crate::timers::AlertPriority,
 }
 
 impl ActiveTimer {
@@ -111,12 +136,7 @@ impl ActiveTimer {
         audio: &AudioConfig,
         display_target: crate::timers::TimerDisplayTarget,
     ) -> Self {
-        // Calculate lag compensation: how far behind was the game event from system time?
-        // This accounts for file I/O delay, processing time, etc.
-        let now_system = chrono::Local::now().naive_local();
-        let lag = now_system.signed_duration_since(event_timestamp);
-        let lag_ms = lag.num_milliseconds().max(0) as u64;
-        let lag_duration = Duration::from_millis(lag_ms);
+        let lag_duration = lag_compensation(chrono::Local::now().naive_local(), event_timestamp);
 
         // Backdate started_instant to when the event actually happened in game
         // This ensures remaining_secs_realtime() reflects actual game time
@@ -149,19 +169,20 @@ impl ActiveTimer {
                 .unwrap_or_else(|| "Amy".to_string()),
             audio_enabled: audio.enabled,
             audio_file: audio.file.clone(),
+            audio_volume: audio.volume,
             audio_offset: audio.offset,
+            speak_alert: audio.speak_alert,
             audio_offset_fired: false,
             display_target,
+            suggested_cooldown: None,
+            alert_priority: Default::default(),
         }
     }
 
     /// Refresh the timer (restart from now)
     pub fn refresh(&mut self, event_timestamp: NaiveDateTime) {
         // Apply same lag compensation as new() for consistent timing
-        let now_system = chrono::Local::now().naive_local();
-        let lag = now_system.signed_duration_since(event_timestamp);
-        let lag_ms = lag.num_milliseconds().max(0) as u64;
-        let lag_duration = Duration::from_millis(lag_ms);
+        let lag_duration = lag_compensation(chrono::Local::now().naive_local(), event_timestamp);
         let now = Instant::now();
 
         self.started_at = event_timestamp;
@@ -290,15 +311,15 @@ impl ActiveTimer {
     /// Check if the audio should fire at the configured offset
     ///
     /// Returns true (and marks as fired) when:
-    /// - audio_file is Some
+    /// - audio_file is Some or the alert text is spoken
     /// - audio_offset > 0 (offset of 0 means fire on expiration, handled separately)
     /// - remaining time just crossed below the offset threshold
     /// - hasn't already fired
     ///
     /// Uses realtime for accurate audio sync.
     pub fn check_audio_offset(&mut self) -> bool {
-        // No audio file configured
-        if self.audio_file.is_none() {
+        // Nothing to play or speak
+        if self.audio_file.is_none() && !self.speak_alert {
             return false;
         }
 
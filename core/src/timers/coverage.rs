@@ -0,0 +1,126 @@
+//! Definition coverage counters
+//!
+//! Counts live pulls of each boss definition and how often each of its timers
+//! started. The app accumulates these for the opt-in coverage report, which
+//! tells definition authors which encounters and timers never match in practice.
+//! Only definition and timer IDs are recorded.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::dsl::BossEncounterDefinition;
+
+/// Pulls and timer starts of one boss definition
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EncounterCoverage {
+    pub pulls: u32,
+    /// Timer ID -> number of starts (0 = the timer never matched)
+    pub timer_starts: HashMap<String, u32>,
+}
+
+/// The boss definition of the current combat and its timer IDs
+#[derive(Debug, Clone)]
+struct ActiveDefinition {
+    id: String,
+    timer_ids: HashSet<String>,
+}
+
+/// Coverage counters since they were last taken
+#[derive(Debug, Clone, Default)]
+pub struct DefinitionCoverage {
+    encounters: HashMap<String, EncounterCoverage>,
+    active: Option<ActiveDefinition>,
+}
+
+impl DefinitionCoverage {
+    /// Count a pull of `def` (once per combat) and list its enabled timers
+    pub fn record_pull(&mut self, def: &BossEncounterDefinition) {
+        if self.active.as_ref().is_some_and(|a| a.id == def.id) {
+            return;
+        }
+        let timer_ids: HashSet<String> = def
+            .timers
+            .iter()
+            .filter(|t| t.enabled)
+            .map(|t| t.id.clone())
+            .collect();
+
+        let coverage = self.encounters.entry(def.id.clone()).or_default();
+        coverage.pulls += 1;
+        for id in &timer_ids {
+            coverage.timer_starts.entry(id.clone()).or_insert(0);
+        }
+        self.active = Some(ActiveDefinition {
+            id: def.id.clone(),
+            timer_ids,
+        });
+    }
+
+    /// Count a timer start if the timer belongs to the current boss definition
+    pub fn record_timer_start(&mut self, timer_id: &str) {
+        let Some(active) = &self.active else {
+            return;
+        };
+        if !active.timer_ids.contains(timer_id) {
+            return;
+        }
+        *self
+            .encounters
+            .entry(active.id.clone())
+            .or_default()
+            .timer_starts
+            .entry(timer_id.to_string())
+            .or_insert(0) += 1;
+    }
+
+    pub fn end_combat(&mut self) {
+        self.active = None;
+    }
+
+    /// Take the counters collected so far (boss definition ID -> coverage)
+    pub fn take(&mut self) -> HashMap<String, EncounterCoverage> {
+        std::mem::take(&mut self.encounters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boss(timer_ids: &[&str]) -> BossEncounterDefinition {
+        let timers: String = timer_ids
+            .iter()
+            .map(|id| {
+                format!(
+                    "[[timer]]\nid = \"{id}\"\nname = \"{id}\"\n\
+                     trigger = {{ type = \"combat_start\" }}\n"
+                )
+            })
+            .collect();
+        toml::from_str(&format!("id = \"brontes\"\nname = \"Brontes\"\n{timers}")).unwrap()
+    }
+
+    #[test]
+    fn test_coverage_counts_pulls_and_timer_starts() {
+        let def = boss(&["fingers", "enrage"]);
+        let mut coverage = DefinitionCoverage::default();
+
+        coverage.record_pull(&def);
+        // Detected again within the same combat (second boss entity)
+        coverage.record_pull(&def);
+        coverage.record_timer_start("fingers");
+        coverage.record_timer_start("fingers");
+        coverage.record_timer_start("generic_user_timer");
+        coverage.end_combat();
+        // Outside a boss combat nothing is attributed
+        coverage.record_timer_start("enrage");
+        coverage.record_pull(&def);
+
+        let taken = coverage.take();
+        let brontes = &taken["brontes"];
+        assert_eq!(brontes.pulls, 2);
+        assert_eq!(brontes.timer_starts["fingers"], 2);
+        assert_eq!(brontes.timer_starts["enrage"], 0);
+        assert!(!brontes.timer_starts.contains_key("generic_user_timer"));
+        assert!(coverage.take().is_empty());
+    }
+}
@@ -3,13 +3,15 @@
 //! Definitions are templates loaded from TOML config files that describe
 //! what timers to track and how to display them.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::dsl::AudioConfig;
 use crate::dsl::CounterCondition;
 use crate::dsl::EntityDefinition;
 use crate::dsl::Trigger;
-use crate::game_data::Difficulty;
+use crate::game_data::{Difficulty, Discipline};
 
 // Re-export Trigger as TimerTrigger for backward compatibility during migration
 pub use crate::dsl::Trigger as TimerTrigger;
@@ -31,6 +33,105 @@ pub enum TimerDisplayTarget {
     None,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Alert Priority
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// How urgently a fired alert is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertPriority {
+    /// Alerts overlay only
+    #[default]
+    Normal,
+    /// Also flashed in the center-screen alert banner
+    High,
+}
+
+impl AlertPriority {
+    pub fn is_normal(&self) -> bool {
+        *self == AlertPriority::Normal
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Group Size
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Inclusive group size range a timer applies to.
+///
+/// Either bound may be omitted, e.g. `{ min = 16 }` for 16-man groups only.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupSizeRange {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<u8>,
+}
+
+impl GroupSizeRange {
+    /// Check if a group size falls within this range
+    pub fn contains(&self, size: u8) -> bool {
+        self.min.is_none_or(|min| size >= min) && self.max.is_none_or(|max| size <= max)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Suggested Cooldown
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// A cooldown to use against a timed mechanic
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CooldownSuggestion {
+    /// Ability name shown beside the timer bar
+    pub ability: String,
+
+    /// Ability ID for the icon (None = text only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_ability_id: Option<u64>,
+}
+
+/// Cooldown suggested beside a timer bar ("Dread Slash - use Saber Ward").
+///
+/// `disciplines` overrides the suggestion for the local player's discipline,
+/// keyed by discipline name (`"Darkness"`, `"Shield Tech"`). An empty `ability`
+/// means only the listed disciplines get a suggestion.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuggestedCooldown {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ability: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_ability_id: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub disciplines: BTreeMap<String, CooldownSuggestion>,
+}
+
+impl SuggestedCooldown {
+    /// Resolve the suggestion for the local player's discipline
+    pub fn resolve(&self, discipline: Option<Discipline>) -> Option<CooldownSuggestion> {
+        let discipline_override = discipline.and_then(|disc| {
+            // Accept both "Shield Tech" and "ShieldTech"
+            let name: String = disc.name().split_whitespace().collect();
+            self.disciplines
+                .iter()
+                .find(|(key, _)| {
+                    key.split_whitespace()
+                        .collect::<String>()
+                        .eq_ignore_ascii_case(&name)
+                })
+                .map(|(_, suggestion)| suggestion.clone())
+        });
+        discipline_override.or_else(|| {
+            (!self.ability.is_empty()).then(|| CooldownSuggestion {
+                ability: self.ability.clone(),
+                icon_ability_id: self.icon_ability_id,
+            })
+        })
+    }
+}
+
 /// Definition of a timer (loaded from config)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimerDefinition {
@@ -84,6 +185,10 @@ pub struct TimerDefinition {
     #[serde(default)]
     pub display_target: TimerDisplayTarget,
 
+    /// Cooldown suggested beside the timer bar
+    #[serde(default)]
+    pub suggested_cooldown: Option<SuggestedCooldown>,
+
     // ─── Alerts ─────────────────────────────────────────────────────────────
     /// Alert when this many seconds remain (None = no alert)
     pub alert_at_secs: Option<f32>,
@@ -91,6 +196,10 @@ pub struct TimerDefinition {
     /// Custom alert text (None = use timer name)
     pub alert_text: Option<String>,
 
+    /// High priority alerts also show in the alert banner
+    #[serde(default)]
+    pub alert_priority: AlertPriority,
+
     // ─── Audio ───────────────────────────────────────────────────────────────
     /// Audio configuration (alerts, countdown, custom sounds)
     #[serde(default)]
@@ -119,6 +228,10 @@ pub struct TimerDefinition {
     #[serde(default)]
     pub difficulties: Vec<String>,
 
+    /// Only active for group sizes in this range (e.g. 8-man vs 16-man)
+    #[serde(default)]
+    pub group_size: Option<GroupSizeRange>,
+
     // ─── Phase/Counter Conditions (optional) ─────────────────────────────────
     /// Only active during these phases (empty = all phases)
     #[serde(default)]
@@ -155,6 +268,19 @@ impl TimerDefinition {
         self.trigger.matches_ability(ability_id, ability_name)
     }
 
+    /// Check if this timer matches an ability cast starting.
+    /// Delegates to unified `Trigger::matches_cast_started`.
+    pub fn matches_cast_started(&self, ability_id: u64, ability_name: Option<&str>) -> bool {
+        self.trigger.matches_cast_started(ability_id, ability_name)
+    }
+
+    /// Check if this timer matches an ability cast completing.
+    /// Delegates to unified `Trigger::matches_cast_completed`.
+    pub fn matches_cast_completed(&self, ability_id: u64, ability_name: Option<&str>) -> bool {
+        self.trigger
+            .matches_cast_completed(ability_id, ability_name)
+    }
+
     /// Check if this timer matches a given effect ID/name for apply triggers.
     /// Delegates to unified `Trigger::matches_effect_applied`.
     pub fn matches_effect_applied(&self, effect_id: u64, effect_name: Option<&str>) -> bool {
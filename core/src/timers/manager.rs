@@ -13,12 +13,16 @@ use chrono::{Local, NaiveDateTime};
 
 use crate::combat_log::EntityType;
 use crate::context::{IStr, resolve};
-use crate::dsl::{BossEncounterDefinition, EntityDefinition};
+use crate::dsl::{AllOfState, BossEncounterDefinition, EntityDefinition};
+use crate::game_data::Discipline;
 use crate::signal_processor::{GameSignal, SignalHandler};
 
 use super::matching::{is_definition_active, matches_source_target_filters};
 use super::signal_handlers;
-use super::{ActiveTimer, TimerDefinition, TimerKey, TimerPreferences, TimerTrigger};
+use super::{
+    ActiveTimer, AlertPriority, DefinitionCoverage, EncounterCoverage, TimerDefinition, TimerKey,
+    TimerPreferences, TimerTrigger,
+};
 
 /// Maximum age (in minutes) for events to be processed by timers in live mode.
 /// Events older than this are skipped since timers are only useful for recent/live events.
@@ -37,8 +41,14 @@ pub struct FiredAlert {
     pub timestamp: NaiveDateTime,
     /// Whether audio is enabled for this alert
     pub audio_enabled: bool,
-    /// Optional custom audio file for this alert (relative path)
+    /// Optional custom audio file for this alert (sound name or absolute path)
     pub audio_file: Option<String>,
+    /// Volume override for this alert's sound (None = global volume)
+    pub audio_volume: Option<u8>,
+    /// Speak `text` with text-to-speech instead of playing `audio_file`
+    pub speak: bool,
+    /// High priority alerts also show in the alert banner
+    pub priority: AlertPriority,
 }
 
 /// Manages ability cooldown and buff timers.
@@ -82,6 +92,9 @@ pub struct TimerManager {
     /// Local player's entity ID (for LocalPlayer filter)
     pub(super) local_player_id: Option<i64>,
 
+    /// Local player's discipline (for per-discipline cooldown suggestions)
+    local_discipline: Option<Discipline>,
+
     /// Local player's current target entity ID (for CurrentTarget filter)
     pub(super) current_target_id: Option<i64>,
 
@@ -92,6 +105,13 @@ pub struct TimerManager {
     /// Boss NPC class IDs for the active encounter (to detect additional boss entities)
     /// When NPCs with these class IDs are first seen, add their entity_id to boss_entity_ids
     boss_npc_class_ids: HashSet<i64>,
+
+    // ─── Composite Trigger State ─────────────────────────────────────────────
+    /// Condition hits for `AllOf` triggers (reset when combat ends)
+    pub(super) all_of: AllOfState,
+
+    /// Boss definition pulls and timer starts in live mode (for coverage reports)
+    coverage: DefinitionCoverage,
 }
 
 impl Default for TimerManager {
@@ -115,9 +135,12 @@ impl TimerManager {
             last_timestamp: None,
             live_mode: true, // Default: apply recency threshold (skip old events)
             local_player_id: None,
+            local_discipline: None,
             current_target_id: None,
             boss_entity_ids: HashSet::new(),
             boss_npc_class_ids: HashSet::new(),
+            all_of: AllOfState::new(),
+            coverage: DefinitionCoverage::default(),
         }
     }
 
@@ -213,8 +236,10 @@ impl TimerManager {
         let boss_count = bosses.len();
 
         for boss in bosses {
-            // Extract boss timers and convert to TimerDefinition
-            for boss_timer in &boss.timers {
+            // Extract boss timers (plus the generated enrage countdown) and
+            // convert to TimerDefinition
+            let enrage_timer = boss.enrage_timer();
+            for boss_timer in boss.timers.iter().chain(enrage_timer.as_ref()) {
                 if boss_timer.enabled {
                     let timer_def =
                         boss_timer.to_timer_definition(boss.area_id, &boss.area_name, &boss.name);
@@ -358,6 +383,9 @@ impl TimerManager {
                         timer.name.clone(),
                         timer.color,
                         timer.audio_file.clone(),
+                        timer.audio_volume,
+                        timer.speak_alert,
+                        timer.alert_priority,
                     ))
                 } else {
                     None
@@ -368,18 +396,23 @@ impl TimerManager {
         // Now format with elapsed time
         triggered
             .into_iter()
-            .map(|(id, name, color, audio_file)| {
-                let text = self.format_alert_text(&name, now);
-                FiredAlert {
-                    id,
-                    name,
-                    text,
-                    color: Some(color),
-                    timestamp: now,
-                    audio_enabled: true,
-                    audio_file,
-                }
-            })
+            .map(
+                |(id, name, color, audio_file, audio_volume, speak, priority)| {
+                    let text = self.format_alert_text(&name, now);
+                    FiredAlert {
+                        id,
+                        name,
+                        text,
+                        color: Some(color),
+                        timestamp: now,
+                        audio_enabled: true,
+                        audio_file,
+                        audio_volume,
+                        speak,
+                        priority,
+                    }
+                },
+            )
             .collect()
     }
 
@@ -409,6 +442,11 @@ impl TimerManager {
         &self.cancelled_this_tick
     }
 
+    /// Take the definition coverage counted since the last call
+    pub fn take_coverage(&mut self) -> std::collections::HashMap<String, EncounterCoverage> {
+        self.coverage.take()
+    }
+
     /// Check if a timer definition is active for current encounter context.
     /// Reads context directly from the encounter (single source of truth).
     /// Also checks preference override for enabled state.
@@ -435,6 +473,11 @@ impl TimerManager {
         let color = self.preferences.get_color(def);
         let audio_enabled = self.preferences.is_audio_enabled(def);
         let audio_file = self.preferences.get_audio_file(def);
+        let speak_alert = self.preferences.is_speak_enabled(def);
+
+        if self.live_mode {
+            self.coverage.record_timer_start(&def.id);
+        }
 
         // Alerts are ephemeral notifications, not countdown timers
         if def.is_alert {
@@ -448,6 +491,9 @@ impl TimerManager {
                 timestamp,
                 audio_enabled,
                 audio_file,
+                audio_volume: def.audio.volume,
+                speak: speak_alert,
+                priority: def.alert_priority,
             });
 
             // Track alert firing for counter triggers and cancel other timers
@@ -474,14 +520,16 @@ impl TimerManager {
         let audio_with_prefs = crate::dsl::AudioConfig {
             enabled: audio_enabled,
             file: audio_file,
+            volume: def.audio.volume,
             offset: def.audio.offset,
             countdown_start: def.audio.countdown_start,
             countdown_voice: def.audio.countdown_voice.clone(),
             alert_text: def.audio.alert_text.clone(),
+            speak_alert,
         };
 
         // Create new timer
-        let timer = ActiveTimer::new(
+        let mut timer = ActiveTimer::new(
             def.id.clone(),
             def.name.clone(),
             target_id,
@@ -495,6 +543,11 @@ impl TimerManager {
             &audio_with_prefs,
             def.display_target,
         );
+        timer.suggested_cooldown = def
+            .suggested_cooldown
+            .as_ref()
+            .and_then(|s| s.resolve(self.local_discipline));
+        timer.alert_priority = def.alert_priority;
 
         self.active_timers.insert(key, timer);
 
@@ -625,7 +678,10 @@ impl TimerManager {
                 // Only fire on expiration if audio_offset == 0 (otherwise it already played at offset)
                 // Skip if audio_enabled == false
                 let has_chain = timer.triggers_timer.is_some();
-                if timer.audio_enabled && timer.audio_file.is_some() && timer.audio_offset == 0 {
+                if timer.audio_enabled
+                    && (timer.audio_file.is_some() || timer.speak_alert)
+                    && timer.audio_offset == 0
+                {
                     let text = self.format_alert_text(&timer.name, current_time);
                     // Move fields from timer since we own it and are done with it (unless chaining)
                     let (id, name, audio_file) = if has_chain {
@@ -651,6 +707,9 @@ impl TimerManager {
                         timestamp: current_time,
                         audio_enabled: true, // Already checked above
                         audio_file,
+                        audio_volume: timer.audio_volume,
+                        speak: timer.speak_alert,
+                        priority: timer.alert_priority,
                     });
                 }
                 // Prepare chain to next timer (take ownership of triggers_timer)
@@ -672,7 +731,7 @@ impl TimerManager {
         // Check for timers triggered by expirations
         let expired_ids = self.expired_this_tick.clone();
         for expired_id in &expired_ids {
-            let matching: Vec<_> = self
+            let mut matching: Vec<_> = self
                 .definitions
                 .values()
                 .filter(|d| {
@@ -680,6 +739,12 @@ impl TimerManager {
                 })
                 .cloned()
                 .collect();
+            matching.extend(signal_handlers::fire_all_of(
+                self,
+                encounter,
+                current_time,
+                |_, c| c.matches_timer_expires(expired_id),
+            ));
 
             for def in matching {
                 self.start_timer(&def, current_time, None);
@@ -766,6 +831,16 @@ impl SignalHandler for TimerManager {
                 self.local_player_id = Some(*entity_id);
                 return;
             }
+            GameSignal::DisciplineChanged {
+                entity_id,
+                discipline_id,
+                ..
+            } => {
+                if self.local_player_id == Some(*entity_id) {
+                    self.local_discipline = Discipline::from_guid(*discipline_id);
+                }
+                return;
+            }
             // AreaEntered: Context is now read from CombatEncounter directly
             GameSignal::AreaEntered { .. } => return,
 
@@ -780,6 +855,12 @@ impl SignalHandler for TimerManager {
                 // Track boss entity ID for source/target "boss" filter
                 self.boss_entity_ids.insert(*entity_id);
 
+                if self.live_mode
+                    && let Some(def) = encounter.and_then(|e| e.active_boss_definition())
+                {
+                    self.coverage.record_pull(def);
+                }
+
                 // Store boss NPC class IDs (for tracking additional boss entities in multi-boss fights)
                 self.boss_npc_class_ids.clear();
                 for &class_id in boss_npc_class_ids {
@@ -803,6 +884,7 @@ impl SignalHandler for TimerManager {
             // CombatEnded: Clear combat state even if definitions not loaded
             GameSignal::CombatEnded { .. } => {
                 signal_handlers::clear_combat_timers(self);
+                self.coverage.end_combat();
                 return;
             }
 
@@ -865,6 +947,36 @@ impl SignalHandler for TimerManager {
                 );
             }
 
+            GameSignal::AbilityDeactivated {
+                ability_id,
+                ability_name,
+                source_id,
+                source_entity_type,
+                source_name,
+                source_npc_id,
+                target_id,
+                target_entity_type,
+                target_name,
+                target_npc_id,
+                timestamp,
+            } => {
+                signal_handlers::handle_ability_deactivated(
+                    self,
+                    encounter,
+                    *ability_id,
+                    *ability_name,
+                    *source_id,
+                    *source_entity_type,
+                    *source_name,
+                    *source_npc_id,
+                    *target_id,
+                    *target_entity_type,
+                    *target_name,
+                    *target_npc_id,
+                    *timestamp,
+                );
+            }
+
             GameSignal::EffectApplied {
                 effect_id,
                 effect_name,
@@ -4,7 +4,7 @@
 
 use chrono::Local;
 
-use super::{TimerDefinition, TimerManager, TimerTrigger};
+use super::{AlertPriority, TimerDefinition, TimerManager, TimerTrigger};
 use crate::dsl::AudioConfig;
 use crate::dsl::EntityFilter;
 use crate::dsl::{AbilitySelector, EffectSelector, EntitySelector};
@@ -26,13 +26,17 @@ fn make_timer(id: &str, name: &str, trigger: TimerTrigger, duration: f32) -> Tim
         repeats: 0,
         alert_at_secs: None,
         alert_text: None,
+        alert_priority: Default::default(),
         audio: AudioConfig::default(),
         show_on_raid_frames: false,
+        display_target: Default::default(),
+        suggested_cooldown: None,
         show_at_secs: 0.0,
         area_ids: Vec::new(),
         encounters: Vec::new(),
         boss: None,
         difficulties: Vec::new(),
+        group_size: None,
         phases: Vec::new(),
         counter_condition: None,
         per_target: true, // Tests use per-target behavior by default
@@ -285,6 +289,290 @@ fn test_anyof_mixed_trigger_types() {
     );
 }
 
+#[test]
+fn test_allof_requires_all_conditions_within_window() {
+    let mut manager = TimerManager::new();
+
+    // Timer that needs BOTH abilities within 5 seconds of each other
+    let timer = make_timer(
+        "both_casts",
+        "Both Casts",
+        TimerTrigger::AllOf {
+            conditions: vec![
+                TimerTrigger::AbilityCast {
+                    abilities: vec![AbilitySelector::Id(111)],
+                    source: EntityFilter::Any,
+                    target: EntityFilter::Any,
+                },
+                TimerTrigger::AbilityCast {
+                    abilities: vec![AbilitySelector::Id(222)],
+                    source: EntityFilter::Any,
+                    target: EntityFilter::Any,
+                },
+            ],
+            window_secs: 5.0,
+        },
+        30.0,
+    );
+    manager.load_definitions(vec![timer]);
+
+    let start = now();
+    let cast = |ability_id: i64, secs: i64| GameSignal::AbilityActivated {
+        ability_id,
+        ability_name: crate::context::empty_istr(),
+        source_id: 1,
+        source_entity_type: crate::combat_log::EntityType::Player,
+        source_name: crate::context::empty_istr(),
+        source_npc_id: 0,
+        target_id: 0,
+        target_name: crate::context::empty_istr(),
+        target_entity_type: crate::combat_log::EntityType::Player,
+        target_npc_id: 0,
+        timestamp: start + chrono::Duration::seconds(secs),
+    };
+
+    manager.handle_signal(&cast(111, 0), None);
+    assert!(
+        manager.active_timers().is_empty(),
+        "One condition is not enough"
+    );
+
+    // Second condition arrives after the first has left the window
+    manager.handle_signal(&cast(222, 10), None);
+    assert!(
+        manager.active_timers().is_empty(),
+        "Conditions outside the window should not combine"
+    );
+
+    // First condition again, within 5s of the second
+    manager.handle_signal(&cast(111, 12), None);
+    assert_eq!(
+        manager.active_timers().len(),
+        1,
+        "All conditions within the window should trigger"
+    );
+}
+
+fn cast_signal(ability_id: i64, timestamp: chrono::NaiveDateTime) -> GameSignal {
+    GameSignal::AbilityActivated {
+        ability_id,
+        ability_name: crate::context::empty_istr(),
+        source_id: 1,
+        source_entity_type: crate::combat_log::EntityType::Player,
+        source_name: crate::context::empty_istr(),
+        source_npc_id: 0,
+        target_id: 0,
+        target_name: crate::context::empty_istr(),
+        target_entity_type: crate::combat_log::EntityType::Player,
+        target_npc_id: 0,
+        timestamp,
+    }
+}
+
+fn cast_trigger(ability_id: u64) -> TimerTrigger {
+    TimerTrigger::AbilityCast {
+        abilities: vec![AbilitySelector::Id(ability_id)],
+        source: EntityFilter::Any,
+        target: EntityFilter::Any,
+    }
+}
+
+#[test]
+fn test_allof_not_vetoes_within_window() {
+    let mut manager = TimerManager::new();
+
+    // Fires on 111 unless 222 was cast in the previous 5 seconds
+    let timer = make_timer(
+        "unless_222",
+        "Unless 222",
+        TimerTrigger::AllOf {
+            conditions: vec![
+                cast_trigger(111),
+                TimerTrigger::Not {
+                    condition: Box::new(cast_trigger(222)),
+                },
+            ],
+            window_secs: 5.0,
+        },
+        30.0,
+    );
+    manager.load_definitions(vec![timer]);
+
+    let start = now();
+    let at = |secs: i64| start + chrono::Duration::seconds(secs);
+
+    manager.handle_signal(&cast_signal(222, at(0)), None);
+    manager.handle_signal(&cast_signal(111, at(2)), None);
+    assert!(
+        manager.active_timers().is_empty(),
+        "A vetoing condition inside the window should block the trigger"
+    );
+
+    manager.handle_signal(&cast_signal(111, at(10)), None);
+    assert_eq!(
+        manager.active_timers().len(),
+        1,
+        "The veto should lapse once it leaves the window"
+    );
+}
+
+#[test]
+fn test_allof_state_conditions() {
+    use crate::dsl::ComparisonOp;
+    use crate::encounter::{CombatEncounter, ProcessingMode};
+
+    let mut manager = TimerManager::new();
+
+    // Ability cast AND phase == burn AND counter >= 2
+    let timer = make_timer(
+        "burn_cast",
+        "Burn Cast",
+        TimerTrigger::AllOf {
+            conditions: vec![
+                cast_trigger(111),
+                TimerTrigger::PhaseActive {
+                    phase_id: "burn".to_string(),
+                },
+                TimerTrigger::CounterIs {
+                    counter_id: "stacks".to_string(),
+                    operator: ComparisonOp::Gte,
+                    value: 2,
+                },
+            ],
+            window_secs: 0.0,
+        },
+        30.0,
+    );
+    manager.load_definitions(vec![timer]);
+
+    let mut encounter = CombatEncounter::new(1, ProcessingMode::Live);
+    encounter.current_phase = Some("burn".to_string());
+    encounter.counters.insert("stacks".to_string(), 1);
+
+    manager.handle_signal(&cast_signal(111, now()), Some(&encounter));
+    assert!(
+        manager.active_timers().is_empty(),
+        "Counter below the threshold should block the trigger"
+    );
+
+    encounter.counters.insert("stacks".to_string(), 2);
+    encounter.current_phase = Some("intro".to_string());
+    manager.handle_signal(&cast_signal(111, now()), Some(&encounter));
+    assert!(
+        manager.active_timers().is_empty(),
+        "Wrong phase should block the trigger"
+    );
+
+    encounter.current_phase = Some("burn".to_string());
+    manager.handle_signal(&cast_signal(111, now()), Some(&encounter));
+    assert_eq!(
+        manager.active_timers().len(),
+        1,
+        "Cast with every state condition holding should trigger"
+    );
+}
+
+#[test]
+fn test_group_size_condition() {
+    use crate::encounter::{CombatEncounter, ProcessingMode};
+    use crate::game_data::Difficulty;
+
+    let mut manager = TimerManager::new();
+
+    // Timer only for 16-man groups
+    let mut timer = make_timer("sixteen_only", "16m Only", TimerTrigger::CombatStart, 30.0);
+    timer.group_size = Some(super::GroupSizeRange {
+        min: Some(16),
+        max: None,
+    });
+    manager.load_definitions(vec![timer]);
+
+    let combat_start = GameSignal::CombatStarted {
+        timestamp: now(),
+        encounter_id: 1,
+    };
+
+    let mut encounter = CombatEncounter::new(1, ProcessingMode::Live);
+    encounter.difficulty = Some(Difficulty::Veteran8);
+    manager.handle_signal(&combat_start, Some(&encounter));
+    assert!(
+        manager.active_timers().is_empty(),
+        "16-man timer should not start in an 8-man group"
+    );
+
+    manager.handle_signal(
+        &GameSignal::CombatEnded {
+            timestamp: now(),
+            encounter_id: 1,
+        },
+        None,
+    );
+
+    encounter.difficulty = Some(Difficulty::Veteran16);
+    manager.handle_signal(&combat_start, Some(&encounter));
+    assert_eq!(
+        manager.active_timers().len(),
+        1,
+        "16-man timer should start in a 16-man group"
+    );
+}
+
+#[test]
+fn test_cast_completed_only_fires_on_deactivation() {
+    let mut manager = TimerManager::new();
+
+    let timer = make_timer(
+        "cast_done",
+        "Cast Done",
+        TimerTrigger::CastCompleted {
+            abilities: vec![AbilitySelector::Id(777)],
+            source: EntityFilter::Any,
+            target: EntityFilter::Any,
+        },
+        10.0,
+    );
+    manager.load_definitions(vec![timer]);
+
+    let activated = GameSignal::AbilityActivated {
+        ability_id: 777,
+        ability_name: crate::context::empty_istr(),
+        source_id: 1,
+        source_entity_type: crate::combat_log::EntityType::Npc,
+        source_name: crate::context::empty_istr(),
+        source_npc_id: 0,
+        target_id: 0,
+        target_name: crate::context::empty_istr(),
+        target_entity_type: crate::combat_log::EntityType::Player,
+        target_npc_id: 0,
+        timestamp: now(),
+    };
+    manager.handle_signal(&activated, None);
+    assert!(
+        manager.active_timers().is_empty(),
+        "Cast start should not trigger a cast_completed timer"
+    );
+
+    let deactivated = GameSignal::AbilityDeactivated {
+        ability_id: 777,
+        ability_name: crate::context::empty_istr(),
+        source_id: 1,
+        source_entity_type: crate::combat_log::EntityType::Npc,
+        source_name: crate::context::empty_istr(),
+        source_npc_id: 0,
+        target_id: 0,
+        target_name: crate::context::empty_istr(),
+        target_entity_type: crate::combat_log::EntityType::Player,
+        target_npc_id: 0,
+        timestamp: now(),
+    };
+    manager.handle_signal(&deactivated, None);
+    assert_eq!(
+        manager.active_timers().len(),
+        1,
+        "Cast completion should trigger the timer"
+    );
+}
+
 #[test]
 fn test_cancel_on_timer() {
     let mut manager = TimerManager::new();
@@ -1189,3 +1477,221 @@ fn test_integration_timer_expiration_with_real_log() {
         "Follow Up Timer should have been triggered by Quick Timer expiring"
     );
 }
+
+#[test]
+fn test_suggested_cooldown_resolves_local_discipline() {
+    use super::{CooldownSuggestion, SuggestedCooldown};
+
+    let mut manager = TimerManager::new();
+
+    let suggestion = SuggestedCooldown {
+        ability: "Saber Ward".to_string(),
+        icon_ability_id: Some(812736661422080),
+        disciplines: [(
+            "Darkness".to_string(),
+            CooldownSuggestion {
+                ability: "Deflection".to_string(),
+                icon_ability_id: None,
+            },
+        )]
+        .into(),
+    };
+    let timer = TimerDefinition {
+        suggested_cooldown: Some(suggestion),
+        ..make_timer(
+            "dread_slash",
+            "Dread Slash",
+            TimerTrigger::CombatStart,
+            20.0,
+        )
+    };
+    manager.load_definitions(vec![timer]);
+
+    let start = |manager: &mut TimerManager| {
+        manager.handle_signal(
+            &GameSignal::CombatEnded {
+                timestamp: now(),
+                encounter_id: 1,
+            },
+            None,
+        );
+        manager.handle_signal(
+            &GameSignal::CombatStarted {
+                timestamp: now(),
+                encounter_id: 1,
+            },
+            None,
+        );
+        manager.active_timers()[0]
+            .suggested_cooldown
+            .clone()
+            .map(|s| s.ability)
+    };
+
+    // No discipline known yet: default suggestion
+    assert_eq!(start(&mut manager).as_deref(), Some("Saber Ward"));
+
+    manager.handle_signal(
+        &GameSignal::PlayerInitialized {
+            entity_id: 1,
+            timestamp: now(),
+        },
+        None,
+    );
+    // Another player's discipline doesn't count
+    manager.handle_signal(
+        &GameSignal::DisciplineChanged {
+            entity_id: 2,
+            class_id: 0,
+            discipline_id: 2031339142381582,
+            timestamp: now(),
+        },
+        None,
+    );
+    assert_eq!(start(&mut manager).as_deref(), Some("Saber Ward"));
+
+    // Local player is Darkness: discipline override
+    manager.handle_signal(
+        &GameSignal::DisciplineChanged {
+            entity_id: 1,
+            class_id: 0,
+            discipline_id: 2031339142381582,
+            timestamp: now(),
+        },
+        None,
+    );
+    assert_eq!(start(&mut manager).as_deref(), Some("Deflection"));
+}
+
+#[test]
+fn test_speak_alert_carries_to_fired_alert() {
+    let mut manager = TimerManager::new();
+
+    let mut alert = make_timer("stack", "Stack", TimerTrigger::CombatStart, 0.0);
+    alert.is_alert = true;
+    alert.alert_text = Some("Stack on the tank".to_string());
+    alert.audio = AudioConfig {
+        enabled: true,
+        speak_alert: true,
+        ..Default::default()
+    };
+    manager.load_definitions(vec![alert]);
+
+    let signal = GameSignal::CombatStarted {
+        timestamp: now(),
+        encounter_id: 1,
+    };
+    manager.handle_signal(&signal, None);
+
+    let fired = manager.take_fired_alerts();
+    assert_eq!(fired.len(), 1);
+    assert!(fired[0].speak);
+    assert!(fired[0].audio_file.is_none());
+}
+
+#[test]
+fn test_alert_priority_carries_to_fired_alert() {
+    let mut manager = TimerManager::new();
+
+    let mut alert = make_timer("get_out", "Get Out", TimerTrigger::CombatStart, 0.0);
+    alert.is_alert = true;
+    alert.alert_priority = AlertPriority::High;
+    let normal = TimerDefinition {
+        is_alert: true,
+        ..make_timer("normal", "Normal", TimerTrigger::CombatStart, 0.0)
+    };
+    manager.load_definitions(vec![alert, normal]);
+
+    let signal = GameSignal::CombatStarted {
+        timestamp: now(),
+        encounter_id: 1,
+    };
+    manager.handle_signal(&signal, None);
+
+    let fired = manager.take_fired_alerts();
+    assert_eq!(fired.len(), 2);
+    let priority = |id: &str| fired.iter().find(|a| a.id == id).map(|a| a.priority);
+    assert_eq!(priority("get_out"), Some(AlertPriority::High));
+    assert_eq!(priority("normal"), Some(AlertPriority::Normal));
+}
+
+fn sphere_targets(target_id: i64, target_name: &str) -> GameSignal {
+    GameSignal::TargetChanged {
+        source_id: 900,
+        source_entity_type: crate::combat_log::EntityType::Npc,
+        source_npc_id: 4242,
+        source_name: crate::context::intern("Sphere"),
+        target_id,
+        target_entity_type: crate::combat_log::EntityType::Player,
+        target_name: crate::context::intern(target_name),
+        target_npc_id: 0,
+        timestamp: now(),
+    }
+}
+
+fn targeted_by_sphere(target: EntityFilter) -> TimerDefinition {
+    make_timer(
+        "sphere",
+        "Targeted by Sphere",
+        TimerTrigger::TargetSet {
+            selector: vec![EntitySelector::Id(4242)],
+            target,
+        },
+        8.0,
+    )
+}
+
+#[test]
+fn test_target_set_local_player_ignores_other_targets() {
+    let mut manager = TimerManager::new();
+    manager.set_local_player_id(1);
+    manager.load_definitions(vec![targeted_by_sphere(EntityFilter::LocalPlayer)]);
+
+    manager.handle_signal(&sphere_targets(2, "Someone Else"), None);
+    assert!(manager.active_timers().is_empty());
+
+    manager.handle_signal(&sphere_targets(1, "Me"), None);
+    assert_eq!(manager.active_timers().len(), 1);
+}
+
+#[test]
+fn test_target_set_named_player() {
+    let mut manager = TimerManager::new();
+    manager.load_definitions(vec![targeted_by_sphere(EntityFilter::NamedPlayer(
+        "karr".to_string(),
+    ))]);
+
+    manager.handle_signal(&sphere_targets(2, "Vossa"), None);
+    assert!(manager.active_timers().is_empty());
+
+    manager.handle_signal(&sphere_targets(3, "Karr"), None);
+    assert_eq!(manager.active_timers().len(), 1);
+}
+
+#[test]
+fn test_target_set_cancel_honors_target_filter() {
+    let mut manager = TimerManager::new();
+    manager.set_local_player_id(1);
+
+    let timer = TimerDefinition {
+        cancel_trigger: Some(TimerTrigger::TargetSet {
+            selector: vec![EntitySelector::Id(4242)],
+            target: EntityFilter::LocalPlayer,
+        }),
+        ..make_timer("soak", "Soak", TimerTrigger::CombatStart, 60.0)
+    };
+    manager.load_definitions(vec![timer]);
+    manager.handle_signal(
+        &GameSignal::CombatStarted {
+            timestamp: now(),
+            encounter_id: 1,
+        },
+        None,
+    );
+
+    manager.handle_signal(&sphere_targets(2, "Someone Else"), None);
+    assert_eq!(manager.active_timers().len(), 1, "other player targeted");
+
+    manager.handle_signal(&sphere_targets(1, "Me"), None);
+    assert!(manager.active_timers().is_empty());
+}
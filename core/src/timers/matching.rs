@@ -100,6 +100,15 @@ pub(super) fn is_definition_active(
         }
     }
 
+    // Check group size (unknown size never satisfies a range)
+    if let Some(range) = def.group_size
+        && !encounter
+            .and_then(|enc| enc.group_size())
+            .is_some_and(|size| range.contains(size))
+    {
+        return false;
+    }
+
     // Check counter condition
     if let Some(ref cond) = def.counter_condition {
         let value = counters.get(&cond.counter_id).copied().unwrap_or(0);
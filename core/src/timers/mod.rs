@@ -17,6 +17,7 @@
 use tracing;
 
 mod active;
+mod coverage;
 mod definition;
 mod error;
 mod manager;
@@ -27,8 +28,12 @@ mod signal_handlers;
 #[cfg(test)]
 mod manager_tests;
 
-pub use active::{ActiveTimer, TimerKey};
-pub use definition::{TimerConfig, TimerDefinition, TimerDisplayTarget, TimerTrigger};
+pub use active::{ActiveTimer, TimerKey, lag_compensation};
+pub use coverage::{DefinitionCoverage, EncounterCoverage};
+pub use definition::{
+    AlertPriority, CooldownSuggestion, GroupSizeRange, SuggestedCooldown, TimerConfig,
+    TimerDefinition, TimerDisplayTarget, TimerTrigger,
+};
 pub use error::TimerError;
 pub use manager::{FiredAlert, TimerManager};
 pub use preferences::{
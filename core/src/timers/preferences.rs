@@ -31,6 +31,10 @@ pub struct TimerPreference {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub audio_file: Option<String>,
 
+    /// Override whether the alert text is spoken
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speak_alert: Option<bool>,
+
     /// Override display color [R, G, B, A]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<[u8; 4]>,
@@ -42,6 +46,7 @@ impl TimerPreference {
         self.enabled.is_none()
             && self.audio_enabled.is_none()
             && self.audio_file.is_none()
+            && self.speak_alert.is_none()
             && self.color.is_none()
     }
 }
@@ -125,6 +130,12 @@ impl TimerPreferences {
         pref.audio_file = file;
     }
 
+    /// Update whether a timer's alert text is spoken
+    pub fn update_speak_alert(&mut self, key: &str, speak: bool) {
+        let pref = self.timers.entry(key.to_string()).or_default();
+        pref.speak_alert = Some(speak);
+    }
+
     /// Update color for a timer
     pub fn update_color(&mut self, key: &str, color: [u8; 4]) {
         let pref = self.timers.entry(key.to_string()).or_default();
@@ -219,6 +230,15 @@ impl TimerPreferences {
             .unwrap_or(def.audio.enabled)
     }
 
+    /// Get effective speak-alert state (preference overrides definition)
+    pub fn is_speak_enabled(&self, def: &TimerDefinition) -> bool {
+        let key = Self::key_for_definition(def);
+        self.timers
+            .get(&key)
+            .and_then(|p| p.speak_alert)
+            .unwrap_or(def.audio.speak_alert)
+    }
+
     /// Get effective audio file (preference overrides definition)
     pub fn get_audio_file(&self, def: &TimerDefinition) -> Option<String> {
         let key = Self::key_for_definition(def);
@@ -3,14 +3,16 @@
 //! Contains all the game signal handling logic extracted from TimerManager.
 //! Each function takes `&mut TimerManager` and processes a specific signal type.
 
+use std::sync::Arc;
+
 use chrono::NaiveDateTime;
 
 use crate::combat_log::EntityType;
 use crate::context::IStr;
-use crate::dsl::EntityDefinition;
+use crate::dsl::{AllOfCondition, EntityDefinition};
 use crate::encounter::CombatEncounter;
 
-use super::{TimerManager, TimerTrigger};
+use super::{TimerDefinition, TimerManager, TimerTrigger};
 
 /// Get the entity roster from the current encounter, or empty slice if none.
 fn get_entities(encounter: Option<&CombatEncounter>) -> &[EntityDefinition] {
@@ -24,6 +26,63 @@ fn get_entities(encounter: Option<&CombatEncounter>) -> &[EntityDefinition] {
     enc.boss_definitions()[idx].entities.as_slice()
 }
 
+/// Record which `AllOf` conditions the current event satisfies and return the
+/// definitions whose conditions have now all been met within their window.
+///
+/// `condition_matches` evaluates a single condition against the current event;
+/// `not` conditions use it on their inner condition, and state conditions are
+/// read from the encounter instead.
+pub(super) fn fire_all_of<F>(
+    manager: &mut TimerManager,
+    encounter: Option<&CombatEncounter>,
+    timestamp: NaiveDateTime,
+    condition_matches: F,
+) -> Vec<Arc<TimerDefinition>>
+where
+    F: Fn(&TimerManager, &TimerTrigger) -> bool,
+{
+    let current_phase = encounter.and_then(|enc| enc.current_phase.as_deref());
+    let counter_value = |id: &str| encounter.map_or(0, |enc| enc.get_counter(id));
+
+    let hits: Vec<_> = manager
+        .definitions
+        .values()
+        .filter_map(|d| {
+            let TimerTrigger::AllOf {
+                conditions,
+                window_secs,
+            } = &d.trigger
+            else {
+                return None;
+            };
+            let results: Vec<AllOfCondition> = conditions
+                .iter()
+                .map(|c| match c {
+                    TimerTrigger::Not { condition } => {
+                        AllOfCondition::Veto(condition_matches(manager, condition))
+                    }
+                    _ => match c.state_holds(current_phase, counter_value) {
+                        Some(holds) => AllOfCondition::State(holds),
+                        None => AllOfCondition::Event(condition_matches(manager, c)),
+                    },
+                })
+                .collect();
+            let hit = results
+                .iter()
+                .any(|c| matches!(c, AllOfCondition::Event(true) | AllOfCondition::Veto(true)));
+            (hit && manager.is_definition_active(d, encounter))
+                .then(|| (d.clone(), results, *window_secs))
+        })
+        .collect();
+
+    hits.into_iter()
+        .filter(|(d, results, window_secs)| {
+            manager.all_of.record(&d.id, results, *window_secs, timestamp)
+        })
+        .map(|(d, _, _)| d)
+        .collect()
+}
+
 /// Handle ability activation
 pub(super) fn handle_ability(
     manager: &mut TimerManager,
@@ -43,11 +102,12 @@ pub(super) fn handle_ability(
     let ability_id = ability_id as u64;
     let ability_name_str = crate::context::resolve(ability_name);
 
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
-            d.matches_ability_with_name(ability_id, Some(ability_name_str))
+            (d.matches_ability_with_name(ability_id, Some(ability_name_str))
+                || d.matches_cast_started(ability_id, Some(ability_name_str)))
                 && manager.is_definition_active(d, encounter)
                 && manager.matches_source_target_filters(
                     &d.trigger,
@@ -64,6 +124,22 @@ pub(super) fn handle_ability(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |m, c| {
+        (c.matches_ability(ability_id, Some(ability_name_str))
+            || c.matches_cast_started(ability_id, Some(ability_name_str)))
+            && m.matches_source_target_filters(
+                c,
+                get_entities(encounter),
+                source_id,
+                source_type,
+                source_name,
+                source_npc_id,
+                target_id,
+                target_type,
+                target_name,
+                target_npc_id,
+            )
+    }));
 
     for def in matching {
         let instance_id = if def.per_target { Some(target_id) } else { None };
@@ -72,11 +148,79 @@ pub(super) fn handle_ability(
 
     // Check for cancel triggers on ability cast
     manager.cancel_timers_matching(
-        |t| matches!(t, TimerTrigger::AbilityCast { abilities, .. } if abilities.iter().any(|s| s.matches(ability_id, Some(ability_name_str)))),
+        |t| matches!(t, TimerTrigger::AbilityCast { abilities, .. } | TimerTrigger::CastStarted { abilities, .. } if abilities.iter().any(|s| s.matches(ability_id, Some(ability_name_str)))),
         &format!("ability {} cast", ability_id)
     );
 }
 
+/// Handle ability deactivation - check for CastCompleted triggers
+pub(super) fn handle_ability_deactivated(
+    manager: &mut TimerManager,
+    encounter: Option<&CombatEncounter>,
+    ability_id: i64,
+    ability_name: IStr,
+    source_id: i64,
+    source_type: EntityType,
+    source_name: IStr,
+    source_npc_id: i64,
+    target_id: i64,
+    target_type: EntityType,
+    target_name: IStr,
+    target_npc_id: i64,
+    timestamp: NaiveDateTime,
+) {
+    let ability_id = ability_id as u64;
+    let ability_name_str = crate::context::resolve(ability_name);
+
+    let mut matching: Vec<_> = manager
+        .definitions
+        .values()
+        .filter(|d| {
+            d.matches_cast_completed(ability_id, Some(ability_name_str))
+                && manager.is_definition_active(d, encounter)
+                && manager.matches_source_target_filters(
+                    &d.trigger,
+                    get_entities(encounter),
+                    source_id,
+                    source_type,
+                    source_name,
+                    source_npc_id,
+                    target_id,
+                    target_type,
+                    target_name,
+                    target_npc_id,
+                )
+        })
+        .cloned()
+        .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |m, c| {
+        c.matches_cast_completed(ability_id, Some(ability_name_str))
+            && m.matches_source_target_filters(
+                c,
+                get_entities(encounter),
+                source_id,
+                source_type,
+                source_name,
+                source_npc_id,
+                target_id,
+                target_type,
+                target_name,
+                target_npc_id,
+            )
+    }));
+
+    for def in matching {
+        let instance_id = if def.per_target { Some(target_id) } else { None };
+        manager.start_timer(&def, timestamp, instance_id);
+    }
+
+    // Check for cancel triggers on cast completed
+    manager.cancel_timers_matching(
+        |t| matches!(t, TimerTrigger::CastCompleted { abilities, .. } if abilities.iter().any(|s| s.matches(ability_id, Some(ability_name_str)))),
+        &format!("ability {} cast completed", ability_id)
+    );
+}
+
 /// Handle effect applied
 pub(super) fn handle_effect_applied(
     manager: &mut TimerManager,
@@ -96,7 +240,7 @@ pub(super) fn handle_effect_applied(
     // Convert i64 to u64 for matching (game IDs are always positive)
     let effect_id = effect_id as u64;
 
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
@@ -117,6 +261,21 @@ pub(super) fn handle_effect_applied(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |m, c| {
+        c.matches_effect_applied(effect_id, Some(effect_name))
+            && m.matches_source_target_filters(
+                c,
+                get_entities(encounter),
+                source_id,
+                source_type,
+                source_name,
+                source_npc_id,
+                target_id,
+                target_type,
+                target_name,
+                target_npc_id,
+            )
+    }));
 
     for def in matching {
         let instance_id = if def.per_target { Some(target_id) } else { None };
@@ -149,7 +308,7 @@ pub(super) fn handle_effect_removed(
     // Convert i64 to u64 for matching (game IDs are always positive)
     let effect_id = effect_id as u64;
 
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
@@ -170,6 +329,21 @@ pub(super) fn handle_effect_removed(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |m, c| {
+        c.matches_effect_removed(effect_id, Some(effect_name))
+            && m.matches_source_target_filters(
+                c,
+                get_entities(encounter),
+                source_id,
+                source_type,
+                source_name,
+                source_npc_id,
+                target_id,
+                target_type,
+                target_name,
+                target_npc_id,
+            )
+    }));
 
     for def in matching {
         let instance_id = if def.per_target { Some(target_id) } else { None };
@@ -198,7 +372,7 @@ pub(super) fn handle_boss_hp_change(
         return;
     }
 
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
@@ -212,6 +386,9 @@ pub(super) fn handle_boss_hp_change(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |_, c| {
+        c.matches_boss_hp_below(get_entities(encounter), npc_id, npc_name, previous_hp, current_hp)
+    }));
 
     for def in matching {
         manager.start_timer(&def, timestamp, None);
@@ -234,12 +411,15 @@ pub(super) fn handle_phase_change(
     phase_id: &str,
     timestamp: NaiveDateTime,
 ) {
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| d.matches_phase_entered(phase_id) && manager.is_definition_active(d, encounter))
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |_, c| {
+        c.matches_phase_entered(phase_id)
+    }));
 
     for def in matching {
         manager.start_timer(&def, timestamp, None);
@@ -260,12 +440,15 @@ pub(super) fn handle_phase_ended(
     phase_id: &str,
     timestamp: NaiveDateTime,
 ) {
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| d.matches_phase_ended(phase_id) && manager.is_definition_active(d, encounter))
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |_, c| {
+        c.matches_phase_ended(phase_id)
+    }));
 
     for def in matching {
         manager.start_timer(&def, timestamp, None);
@@ -288,7 +471,7 @@ pub(super) fn handle_counter_change(
     new_value: u32,
     timestamp: NaiveDateTime,
 ) {
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
@@ -297,6 +480,9 @@ pub(super) fn handle_counter_change(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |_, c| {
+        c.matches_counter_reaches(counter_id, old_value, new_value)
+    }));
 
     for def in matching {
         manager.start_timer(&def, timestamp, None);
@@ -321,7 +507,7 @@ pub(super) fn handle_npc_first_seen(
     npc_name: &str,
     timestamp: NaiveDateTime,
 ) {
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
@@ -330,6 +516,9 @@ pub(super) fn handle_npc_first_seen(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |_, c| {
+        c.matches_npc_appears(get_entities(encounter), npc_id, npc_name)
+    }));
 
     for def in matching {
         manager.start_timer(&def, timestamp, None);
@@ -353,7 +542,7 @@ pub(super) fn handle_entity_death(
     entity_name: &str,
     timestamp: NaiveDateTime,
 ) {
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
@@ -362,6 +551,9 @@ pub(super) fn handle_entity_death(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |_, c| {
+        c.matches_entity_death(get_entities(encounter), npc_id, entity_name)
+    }));
 
     for def in matching {
         manager.start_timer(&def, timestamp, None);
@@ -392,7 +584,7 @@ pub(super) fn handle_target_set(
     let source_name_str = crate::context::resolve(source_name);
     let entities = get_entities(encounter);
 
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
@@ -413,16 +605,52 @@ pub(super) fn handle_target_set(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |m, c| {
+        c.matches_target_set(entities, source_npc_id, Some(source_name_str))
+            && m.matches_source_target_filters(
+                c,
+                entities,
+                source_entity_id,
+                EntityType::Npc,
+                source_name,
+                source_npc_id,
+                target_id,
+                target_entity_type,
+                target_name,
+                0,
+            )
+    }));
 
     for def in matching {
         manager.start_timer(&def, timestamp, None);
     }
 
-    // Check for cancel triggers on target set
+    // Check for cancel triggers on target set (honoring the target filter, so a
+    // `target = "local_player"` cancel only fires when the local player is targeted)
     let source_name_owned = source_name_str.to_string();
+    let local_player_id = manager.local_player_id;
+    let current_target_id = manager.current_target_id;
+    let boss_entity_ids = manager.boss_entity_ids.clone();
     manager.cancel_timers_matching_with_entities(
         entities,
-        |t, ents| t.matches_target_set(ents, source_npc_id, Some(&source_name_owned)),
+        |t, ents| {
+            t.matches_target_set(ents, source_npc_id, Some(&source_name_owned))
+                && super::matching::matches_source_target_filters(
+                    t,
+                    ents,
+                    source_entity_id,
+                    EntityType::Npc,
+                    source_name,
+                    source_npc_id,
+                    target_id,
+                    target_entity_type,
+                    target_name,
+                    0,
+                    local_player_id,
+                    current_target_id,
+                    &boss_entity_ids,
+                )
+        },
         &format!("target set by {}", source_name_owned),
     );
 }
@@ -446,7 +674,7 @@ pub(super) fn handle_damage_taken(
     let ability_id = ability_id as u64;
     let ability_name_str = crate::context::resolve(ability_name);
 
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
@@ -467,6 +695,21 @@ pub(super) fn handle_damage_taken(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |m, c| {
+        c.matches_damage_taken(ability_id, Some(ability_name_str))
+            && m.matches_source_target_filters(
+                c,
+                get_entities(encounter),
+                source_id,
+                source_type,
+                source_name,
+                source_npc_id,
+                target_id,
+                target_type,
+                target_name,
+                target_npc_id,
+            )
+    }));
 
     for def in matching {
         let instance_id = if def.per_target { Some(target_id) } else { None };
@@ -499,7 +742,7 @@ pub(super) fn handle_time_elapsed(
         return;
     }
 
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| {
@@ -508,6 +751,9 @@ pub(super) fn handle_time_elapsed(
         })
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, _timestamp, |_, c| {
+        c.matches_time_elapsed(old_combat_secs, new_combat_secs)
+    }));
 
     for def in matching {
         manager.start_timer(&def, _timestamp, None);
@@ -529,12 +775,15 @@ pub(super) fn handle_combat_start(
     manager.in_combat = true;
     manager.combat_start_time = Some(timestamp);
 
-    let matching: Vec<_> = manager
+    let mut matching: Vec<_> = manager
         .definitions
         .values()
         .filter(|d| d.triggers_on_combat_start() && manager.is_definition_active(d, encounter))
         .cloned()
         .collect();
+    matching.extend(fire_all_of(manager, encounter, timestamp, |_, c| {
+        matches!(c, TimerTrigger::CombatStart)
+    }));
 
     for def in matching {
         manager.start_timer(&def, timestamp, None);
@@ -548,6 +797,7 @@ pub(super) fn clear_combat_timers(manager: &mut TimerManager) {
     manager.active_timers.clear();
     manager.fired_alerts.clear();
     manager.boss_entity_ids.clear();
+    manager.all_of.clear();
     // Boss name is now read from encounter.active_boss directly
     manager.clear_boss_npc_class_ids();
 }
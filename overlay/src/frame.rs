@@ -1,20 +1,69 @@
 //! Overlay frame abstraction
 //!
 //! `OverlayFrame` encapsulates the common chrome shared by all overlay types:
-//! - Rounded background with configurable alpha
+//! - Rounded background with configurable alpha and optional drop shadow
 //! - Interactive border when in move mode
 //! - Resize indicator in the corner
+//! - Warning badge (with hover tooltip) while a subsystem feeding it is degraded
 //! - Scaling calculations based on window dimensions
 //!
 //! This allows overlay implementations to focus solely on their content rendering.
 
 #![allow(clippy::too_many_arguments)]
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+use baras_core::context::OverlayFrameStyle;
+
 use crate::manager::OverlayWindow;
-use crate::platform::{OverlayConfig, PlatformError};
+use crate::platform::{OverlayConfig, OverlayKey, PlatformError};
 use crate::utils::color_from_rgba;
-use crate::widgets::colors;
+use crate::widgets::{LabeledValue, Tooltip, colors};
 use tiny_skia::Color;
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Frame Style
+// ─────────────────────────────────────────────────────────────────────────────
+
+static CORNER_RADIUS: AtomicU8 = AtomicU8::new(6);
+static SHADOW: AtomicBool = AtomicBool::new(false);
+
+/// Room kept free at the right and bottom edges for the drop shadow (pixels)
+const SHADOW_SIZE: f32 = 6.0;
+
+/// Stacked rounded rects making up the shadow falloff
+const SHADOW_LAYERS: usize = 4;
+
+/// Shadow opacity relative to the background alpha
+const SHADOW_STRENGTH: f32 = 0.6;
+
+/// Set the corner radius and drop shadow of all overlay backgrounds.
+/// Applies from the next rendered frame.
+pub fn set_frame_style(style: OverlayFrameStyle) {
+    CORNER_RADIUS.store(style.corner_radius, Ordering::Relaxed);
+    SHADOW.store(style.shadow, Ordering::Relaxed);
+}
+
+/// Rect `(x, y, w, h, radius)` of shadow layer `layer` in a `width` x `height`
+/// window. Layer 0 is the outermost and reaches the window edge; later layers
+/// shrink toward the background rect shifted by half the shadow size, so the
+/// stacked layers darken toward the middle.
+fn shadow_layer_rect(
+    layer: usize,
+    width: f32,
+    height: f32,
+    radius: f32,
+) -> (f32, f32, f32, f32, f32) {
+    let offset = SHADOW_SIZE / 2.0;
+    let grow = offset * (SHADOW_LAYERS - 1 - layer) as f32 / (SHADOW_LAYERS - 1) as f32;
+    (
+        offset - grow,
+        offset - grow,
+        width - SHADOW_SIZE + grow * 2.0,
+        height - SHADOW_SIZE + grow * 2.0,
+        radius + grow,
+    )
+}
+
 /// A frame wrapper around an overlay window that handles common rendering
 pub struct OverlayFrame {
     window: OverlayWindow,
@@ -23,6 +72,8 @@ pub struct OverlayFrame {
     base_height: f32,
     /// Optional label shown in move mode to identify the overlay
     label: Option<String>,
+    /// Degraded subsystems as (subsystem, problem), shown as a warning badge
+    warnings: Vec<(String, String)>,
 }
 
 impl OverlayFrame {
@@ -45,6 +96,7 @@ impl OverlayFrame {
             base_width,
             base_height,
             label: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -63,6 +115,12 @@ impl OverlayFrame {
         self.label = Some(label.into());
     }
 
+    /// Show a warning badge for degraded subsystems feeding this overlay.
+    /// Each entry is (subsystem, problem); an empty list clears the badge.
+    pub fn set_warnings(&mut self, warnings: Vec<(String, String)>) {
+        self.warnings = warnings;
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Scaling
     // ─────────────────────────────────────────────────────────────────────────
@@ -93,9 +151,10 @@ impl OverlayFrame {
     /// Call this at the start of render(), then draw your content,
     /// then call `end_frame()`.
     pub fn begin_frame(&mut self) {
-        let width = self.window.width() as f32;
-        let height = self.window.height() as f32;
-        let corner_radius = self.scaled(6.0);
+        let window_width = self.window.width() as f32;
+        let window_height = self.window.height() as f32;
+        let corner_radius = self.scaled(CORNER_RADIUS.load(Ordering::Relaxed) as f32);
+        let shadow = SHADOW.load(Ordering::Relaxed);
         let in_move_mode = self.window.is_interactive() && self.window.is_drag_enabled();
 
         // Clear with transparent
@@ -109,6 +168,22 @@ impl OverlayFrame {
             self.background_alpha
         };
 
+        // The shadow needs room at the right and bottom edges, so the
+        // background shrinks by its size. Fully transparent overlays keep
+        // the whole window.
+        let (width, height) = if shadow && alpha > 0 {
+            let layer_alpha = (alpha as f32 * SHADOW_STRENGTH / SHADOW_LAYERS as f32).round() as u8;
+            let shadow_color = Color::from_rgba8(0, 0, 0, layer_alpha);
+            for layer in 0..SHADOW_LAYERS {
+                let (x, y, w, h, r) =
+                    shadow_layer_rect(layer, window_width, window_height, corner_radius);
+                self.window.fill_rounded_rect(x, y, w, h, r, shadow_color);
+            }
+            (window_width - SHADOW_SIZE, window_height - SHADOW_SIZE)
+        } else {
+            (window_width, window_height)
+        };
+
         // Draw background if there's any alpha to show
         if alpha > 0 {
             let bg_color = Color::from_rgba8(30, 30, 30, alpha);
@@ -124,7 +199,7 @@ impl OverlayFrame {
                 1.0,
                 width - 2.0,
                 height - 2.0,
-                corner_radius - 1.0,
+                (corner_radius - 1.0).max(0.0),
                 2.0,
                 colors::frame_border(),
             );
@@ -145,10 +220,60 @@ impl OverlayFrame {
     ///
     /// Call this after drawing your content.
     pub fn end_frame(&mut self) {
+        self.draw_warning_badge();
         self.draw_resize_indicator();
         self.window.commit();
     }
 
+    /// Draw the warning badge in the top-right corner while any subsystem is
+    /// degraded. Hovering it (overlays only see the pointer while interactive)
+    /// lists the problems.
+    fn draw_warning_badge(&mut self) {
+        if self.warnings.is_empty() {
+            return;
+        }
+
+        let width = self.window.width() as f32;
+        let size = self.scaled(14.0).max(12.0);
+        let margin = self.scaled(4.0);
+        let x = width - size - margin;
+        let y = margin;
+
+        self.window
+            .fill_rounded_rect(x, y, size, size, size / 2.0, colors::warning_badge());
+        let font_size = size * 0.8;
+        let (text_width, text_height) = self.window.measure_text("!", font_size);
+        self.window.draw_text(
+            "!",
+            x + (size - text_width) / 2.0,
+            y + (size + text_height) / 2.0,
+            font_size,
+            colors::black(),
+        );
+
+        let (px, py) = self.window.pointer_position();
+        let hovered = self.window.is_interactive()
+            && self.window.is_pointer_inside()
+            && px >= x
+            && px <= x + size
+            && py >= y
+            && py <= y + size;
+        if !hovered {
+            return;
+        }
+
+        let font_size = self.scaled(11.0).max(10.0);
+        let mut tooltip_width = 0.0f32;
+        let mut tooltip = Tooltip::new("Degraded");
+        for (subsystem, problem) in &self.warnings {
+            let row_text = format!("{}  {}", subsystem, problem);
+            tooltip_width = tooltip_width.max(self.window.measure_text(&row_text, font_size).0);
+            tooltip = tooltip.with_row(LabeledValue::new(subsystem.clone(), problem.clone()));
+        }
+        let tooltip_width = (tooltip_width + self.scaled(12.0)).min(width);
+        tooltip.render(self, (px, py), tooltip_width, font_size);
+    }
+
     /// Draw the resize grip indicator in the bottom-right corner
     /// Only shown in move mode (interactive AND drag enabled)
     fn draw_resize_indicator(&mut self) {
@@ -359,6 +484,41 @@ impl OverlayFrame {
         self.window.take_pending_click()
     }
 
+    /// Take a pending button release position (if any)
+    pub fn take_pending_release(&mut self) -> Option<(f32, f32)> {
+        self.window.take_pending_release()
+    }
+
+    /// Check if in keyboard mode
+    pub fn is_keyboard_mode(&self) -> bool {
+        self.window.is_keyboard_mode()
+    }
+
+    /// Enter or leave keyboard mode
+    pub fn set_keyboard_mode(&mut self, enabled: bool) {
+        self.window.set_keyboard_mode(enabled);
+    }
+
+    /// Take the next key pressed in keyboard mode
+    pub fn take_pending_key(&mut self) -> Option<OverlayKey> {
+        self.window.take_pending_key()
+    }
+
+    /// Take the mouse wheel notches since the last call (positive = down)
+    pub fn take_pending_scroll(&mut self) -> i32 {
+        self.window.take_pending_scroll()
+    }
+
+    /// Last known pointer position within the overlay
+    pub fn pointer_position(&self) -> (f32, f32) {
+        self.window.pointer_position()
+    }
+
+    /// Check if the pointer is over the overlay (interactive modes only)
+    pub fn is_pointer_inside(&self) -> bool {
+        self.window.is_pointer_inside()
+    }
+
     /// Set the window position
     pub fn set_position(&mut self, x: i32, y: i32) {
         self.window.set_position(x, y);
@@ -369,3 +529,22 @@ impl OverlayFrame {
         self.window.set_size(width, height);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shadow_layers_stay_inside_window() {
+        let (x, y, w, h, r) = shadow_layer_rect(0, 200.0, 100.0, 6.0);
+        assert_eq!((x, y), (0.0, 0.0));
+        assert_eq!((x + w, y + h), (200.0, 100.0));
+        assert_eq!(r, 9.0);
+
+        // Innermost layer is the background rect offset by half the shadow
+        let (x, y, w, h, r) = shadow_layer_rect(SHADOW_LAYERS - 1, 200.0, 100.0, 6.0);
+        assert_eq!((x, y), (SHADOW_SIZE / 2.0, SHADOW_SIZE / 2.0));
+        assert_eq!((w, h), (200.0 - SHADOW_SIZE, 100.0 - SHADOW_SIZE));
+        assert_eq!(r, 6.0);
+    }
+}
@@ -0,0 +1,35 @@
+//! Process-wide redraw rate for locked overlays
+//!
+//! Overlay threads and render throttles read the current rate each frame, so
+//! a change from the performance settings applies without respawning them.
+
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::time::Duration;
+
+const DEFAULT_FPS: u32 = 10;
+const MIN_FPS: u32 = 1;
+const MAX_FPS: u32 = 60;
+
+/// Frame interval while the app is in deep idle (no new data to show)
+const IDLE_FRAME_INTERVAL: Duration = Duration::from_secs(1);
+
+static OVERLAY_FPS: AtomicU32 = AtomicU32::new(DEFAULT_FPS);
+static OVERLAY_IDLE: AtomicBool = AtomicBool::new(false);
+
+/// Set the redraw rate of locked overlays (clamped to 1-60 FPS)
+pub fn set_overlay_fps(fps: u32) {
+    OVERLAY_FPS.store(fps.clamp(MIN_FPS, MAX_FPS), Ordering::Relaxed);
+}
+
+/// Drop locked overlays to one frame per second while nothing is being logged
+pub fn set_overlay_idle(idle: bool) {
+    OVERLAY_IDLE.store(idle, Ordering::Relaxed);
+}
+
+/// Time between frames of a locked overlay
+pub fn overlay_frame_interval() -> Duration {
+    if OVERLAY_IDLE.load(Ordering::Relaxed) {
+        return IDLE_FRAME_INTERVAL;
+    }
+    Duration::from_millis(1000 / OVERLAY_FPS.load(Ordering::Relaxed) as u64)
+}
@@ -0,0 +1,1065 @@
+//! GPU renderer backend using wgpu
+//!
+//! Draw calls are recorded for the frame and composited on the GPU when the
+//! frame is finished: rectangles and outlines as antialiased instanced quads,
+//! text, icons and dashed outlines as cached sprite textures. The result is
+//! read back into the window's pixel buffer, since every platform presents a
+//! CPU buffer (wl_shm, XShm, GDI, CGImage).
+//!
+//! A frame that records the same commands as the previous one (the common
+//! case for locked overlays between data updates) skips the GPU and reuses the
+//! last composited pixels. There is no finer-grained damage tracking: any
+//! change composites and reads back the whole frame, which is why the GPU
+//! backend is opt-in rather than the automatic choice.
+//!
+//! One device is shared by every overlay thread. If it can't be created, or
+//! fails later, overlays draw with the software [`Renderer`] instead.
+#![allow(clippy::too_many_arguments)]
+
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::sync::{Arc, OnceLock};
+
+use tiny_skia::Color;
+use wgpu::util::DeviceExt;
+
+use crate::render_backend::RenderBackend;
+use crate::renderer::Renderer;
+
+const SHADER: &str = include_str!("shader.wgsl");
+
+/// Same byte layout as the software renderer's buffer (premultiplied RGBA)
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+/// Sprites unused for this many frames are released
+const SPRITE_MAX_IDLE_FRAMES: u64 = 30;
+
+/// Largest sprite texture (the downlevel limit every adapter supports)
+const SPRITE_MAX_SIZE: u32 = 2048;
+
+/// Transparent border around rasterized text and outlines
+const SPRITE_PADDING: f32 = 2.0;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Shared Device
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Device, queue and pipelines shared by all overlay threads
+struct GpuContext {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    shape_pipeline: wgpu::RenderPipeline,
+    sprite_pipeline: wgpu::RenderPipeline,
+    globals_layout: wgpu::BindGroupLayout,
+    sprite_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+/// Created on first use; a failure is remembered so later overlays don't retry
+static CONTEXT: OnceLock<Result<Arc<GpuContext>, String>> = OnceLock::new();
+
+fn shared_context() -> Result<Arc<GpuContext>, String> {
+    CONTEXT
+        .get_or_init(|| GpuContext::new().map(Arc::new))
+        .clone()
+}
+
+impl GpuContext {
+    fn new() -> Result<Self, String> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            force_fallback_adapter: false,
+            compatible_surface: None,
+        }))
+        .map_err(|e| format!("no GPU adapter: {}", e))?;
+
+        let info = adapter.get_info();
+        // A CPU adapter (llvmpipe, WARP) is slower than drawing with tiny-skia
+        if info.device_type == wgpu::DeviceType::Cpu {
+            return Err(format!(
+                "only a software adapter is available ({})",
+                info.name
+            ));
+        }
+
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            label: Some("overlay"),
+            required_limits: wgpu::Limits::downlevel_defaults().using_resolution(adapter.limits()),
+            ..Default::default()
+        }))
+        .map_err(|e| format!("failed to open GPU device: {}", e))?;
+
+        tracing::info!(
+            adapter = %info.name,
+            backend = ?info.backend,
+            "GPU overlay renderer ready"
+        );
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("overlay shader"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+
+        let globals_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("overlay globals"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let sprite_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("overlay sprite"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let shape_pipeline = create_pipeline(
+            &device,
+            &shader,
+            "overlay shapes",
+            &[&globals_layout],
+            "vs_shape",
+            "fs_shape",
+            wgpu::VertexBufferLayout {
+                array_stride: std::mem::size_of::<ShapeInstance>() as wgpu::BufferAddress,
+                step_mode: wgpu::VertexStepMode::Instance,
+                attributes: &wgpu::vertex_attr_array![
+                    0 => Float32x4,
+                    1 => Float32x4,
+                    2 => Float32x4,
+                ],
+            },
+        );
+
+        let sprite_pipeline = create_pipeline(
+            &device,
+            &shader,
+            "overlay sprites",
+            &[&globals_layout, &sprite_layout],
+            "vs_sprite",
+            "fs_sprite",
+            wgpu::VertexBufferLayout {
+                array_stride: std::mem::size_of::<[f32; 4]>() as wgpu::BufferAddress,
+                step_mode: wgpu::VertexStepMode::Instance,
+                attributes: &wgpu::vertex_attr_array![0 => Float32x4],
+            },
+        );
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("overlay sprite sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        Ok(Self {
+            device,
+            queue,
+            shape_pipeline,
+            sprite_pipeline,
+            globals_layout,
+            sprite_layout,
+            sampler,
+        })
+    }
+}
+
+fn create_pipeline(
+    device: &wgpu::Device,
+    shader: &wgpu::ShaderModule,
+    label: &str,
+    bind_group_layouts: &[&wgpu::BindGroupLayout],
+    vertex_entry: &str,
+    fragment_entry: &str,
+    instances: wgpu::VertexBufferLayout<'_>,
+) -> wgpu::RenderPipeline {
+    let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some(label),
+        bind_group_layouts,
+        push_constant_ranges: &[],
+    });
+
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some(label),
+        layout: Some(&layout),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: Some(vertex_entry),
+            compilation_options: Default::default(),
+            buffers: &[instances],
+        },
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleStrip,
+            ..Default::default()
+        },
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState::default(),
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: Some(fragment_entry),
+            compilation_options: Default::default(),
+            targets: &[Some(wgpu::ColorTargetState {
+                format: FORMAT,
+                blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        multiview: None,
+        cache: None,
+    })
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Frame Recording
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// One rectangle, rounded rectangle or outline (matches `ShapeInstance` in the shader)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct ShapeInstance {
+    rect: [f32; 4],
+    /// Premultiplied RGBA
+    color: [f32; 4],
+    /// Corner radius, stroke width (0 = fill), unused, unused
+    params: [f32; 4],
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Command {
+    Clear(Color),
+    Shape(ShapeInstance),
+    Sprite { key: u64, rect: [f32; 4] },
+}
+
+impl Command {
+    fn hash_into(&self, hasher: &mut DefaultHasher) {
+        match self {
+            Command::Clear(color) => {
+                0u8.hash(hasher);
+                hash_floats(hasher, &color_floats(*color));
+            }
+            Command::Shape(shape) => {
+                1u8.hash(hasher);
+                hash_floats(hasher, &shape.rect);
+                hash_floats(hasher, &shape.color);
+                hash_floats(hasher, &shape.params);
+            }
+            Command::Sprite { key, rect } => {
+                2u8.hash(hasher);
+                key.hash(hasher);
+                hash_floats(hasher, rect);
+            }
+        }
+    }
+}
+
+fn hash_floats(hasher: &mut DefaultHasher, values: &[f32]) {
+    for value in values {
+        value.to_bits().hash(hasher);
+    }
+}
+
+fn color_floats(color: Color) -> [f32; 4] {
+    [color.red(), color.green(), color.blue(), color.alpha()]
+}
+
+fn premultiplied(color: Color) -> [f32; 4] {
+    let a = color.alpha();
+    [color.red() * a, color.green() * a, color.blue() * a, a]
+}
+
+/// Cache key of a frame, or `None` if it can't be reused: a frame that
+/// doesn't start with a clear draws over whatever the buffer held
+fn frame_key(width: u32, height: u32, commands: &[Command]) -> Option<u64> {
+    if !matches!(commands.first(), Some(Command::Clear(_))) {
+        return None;
+    }
+    let mut hasher = DefaultHasher::new();
+    (width, height).hash(&mut hasher);
+    for command in commands {
+        command.hash_into(&mut hasher);
+    }
+    Some(hasher.finish())
+}
+
+/// Pixels of the last composited frame, reused while the commands match
+#[derive(Default)]
+struct FrameCache {
+    key: Option<u64>,
+    pixels: Vec<u8>,
+}
+
+impl FrameCache {
+    /// Copy the cached frame into `buffer` if it was composited from the
+    /// same commands. Returns false if the frame must be composited.
+    fn reuse(&self, key: Option<u64>, buffer: &mut [u8]) -> bool {
+        if key.is_none() || self.key != key || self.pixels.len() != buffer.len() {
+            return false;
+        }
+        buffer.copy_from_slice(&self.pixels);
+        true
+    }
+
+    fn store(&mut self, key: Option<u64>, buffer: &[u8]) {
+        self.key = key;
+        self.pixels.clear();
+        self.pixels.extend_from_slice(buffer);
+    }
+}
+
+/// A cached texture drawn as a quad
+struct Sprite {
+    /// Kept alive for the bind group
+    _texture: wgpu::Texture,
+    bind_group: wgpu::BindGroup,
+    last_used: u64,
+}
+
+/// Offscreen render target and readback buffer for one window size
+struct Target {
+    width: u32,
+    height: u32,
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    globals: wgpu::BindGroup,
+    readback: wgpu::Buffer,
+    /// Readback rows are padded to wgpu's copy alignment
+    padded_row: u32,
+}
+
+/// A consecutive run of draws using the same pipeline
+enum Draw {
+    Shapes(Range<u32>),
+    Sprite { key: u64, instance: u32 },
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Renderer
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Overlay renderer that composites frames on the GPU
+pub struct GpuRenderer {
+    ctx: Arc<GpuContext>,
+    /// Shapes text and rasterizes sprites; also draws every frame after a GPU failure
+    software: Renderer,
+    commands: Vec<Command>,
+    sprites: HashMap<u64, Sprite>,
+    target: Option<Target>,
+    frame: u64,
+    /// Last composited frame, for skipping unchanged frames
+    frame_cache: FrameCache,
+    failed: bool,
+}
+
+impl GpuRenderer {
+    /// Create a renderer on the shared GPU device
+    pub fn new() -> Result<Self, String> {
+        Ok(Self {
+            ctx: shared_context()?,
+            software: Renderer::new(),
+            commands: Vec::new(),
+            sprites: HashMap::new(),
+            target: None,
+            frame: 0,
+            frame_cache: FrameCache::default(),
+            failed: false,
+        })
+    }
+
+    fn push_shape(&mut self, rect: [f32; 4], color: Color, radius: f32, stroke_width: f32) {
+        if rect[2] <= 0.0 || rect[3] <= 0.0 {
+            return;
+        }
+        self.commands.push(Command::Shape(ShapeInstance {
+            rect,
+            color: premultiplied(color),
+            params: [radius.max(0.0), stroke_width.max(0.0), 0.0, 0.0],
+        }));
+    }
+
+    /// Record a sprite, rasterizing it on first use with `rasterize`
+    /// (which fills a transparent premultiplied RGBA tile)
+    fn push_sprite(
+        &mut self,
+        key: u64,
+        rect: [f32; 4],
+        tile_size: (u32, u32),
+        rasterize: impl FnOnce(&mut Renderer, &mut [u8], u32, u32),
+    ) {
+        let (width, height) = tile_size;
+        if width == 0 || height == 0 || width > SPRITE_MAX_SIZE || height > SPRITE_MAX_SIZE {
+            return;
+        }
+
+        if let Some(sprite) = self.sprites.get_mut(&key) {
+            sprite.last_used = self.frame;
+        } else {
+            let mut pixels = Renderer::create_buffer(width, height);
+            rasterize(&mut self.software, &mut pixels, width, height);
+            let sprite = self.upload_sprite(&pixels, width, height);
+            self.sprites.insert(key, sprite);
+        }
+
+        self.commands.push(Command::Sprite { key, rect });
+    }
+
+    fn upload_sprite(&self, pixels: &[u8], width: u32, height: u32) -> Sprite {
+        let device = &self.ctx.device;
+        let texture = device.create_texture_with_data(
+            &self.ctx.queue,
+            &wgpu::TextureDescriptor {
+                label: Some("overlay sprite"),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: FORMAT,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            pixels,
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("overlay sprite"),
+            layout: &self.ctx.sprite_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.ctx.sampler),
+                },
+            ],
+        });
+
+        Sprite {
+            _texture: texture,
+            bind_group,
+            last_used: self.frame,
+        }
+    }
+
+    /// (Re)create the render target when the window size changes
+    fn ensure_target(&mut self, width: u32, height: u32) {
+        if self
+            .target
+            .as_ref()
+            .is_some_and(|t| t.width == width && t.height == height)
+        {
+            return;
+        }
+
+        let device = &self.ctx.device;
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("overlay frame"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC
+                | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let globals_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("overlay globals"),
+            contents: bytemuck::cast_slice(&[width as f32, height as f32, 0.0, 0.0]),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let globals = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("overlay globals"),
+            layout: &self.ctx.globals_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: globals_buffer.as_entire_binding(),
+            }],
+        });
+
+        let padded_row = (width * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("overlay readback"),
+            size: padded_row as u64 * height as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        self.target = Some(Target {
+            width,
+            height,
+            texture,
+            view,
+            globals,
+            readback,
+            padded_row,
+        });
+    }
+
+    /// Composite the recorded frame and read it back into `buffer`
+    fn composite(
+        &mut self,
+        commands: &[Command],
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+    ) -> Result<(), String> {
+        self.ensure_target(width, height);
+        let Some(target) = &self.target else {
+            return Ok(());
+        };
+        let ctx = &self.ctx;
+        let extent = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+
+        // Batch the commands; a clear discards everything drawn before it
+        let mut clear = None;
+        let mut shapes: Vec<ShapeInstance> = Vec::new();
+        let mut sprite_rects: Vec<[f32; 4]> = Vec::new();
+        let mut draws: Vec<Draw> = Vec::new();
+        for command in commands {
+            match *command {
+                Command::Clear(color) => {
+                    clear = Some(color);
+                    shapes.clear();
+                    sprite_rects.clear();
+                    draws.clear();
+                }
+                Command::Shape(shape) => {
+                    let index = shapes.len() as u32;
+                    shapes.push(shape);
+                    match draws.last_mut() {
+                        Some(Draw::Shapes(range)) if range.end == index => range.end += 1,
+                        _ => draws.push(Draw::Shapes(index..index + 1)),
+                    }
+                }
+                Command::Sprite { key, rect } => {
+                    let instance = sprite_rects.len() as u32;
+                    sprite_rects.push(rect);
+                    draws.push(Draw::Sprite { key, instance });
+                }
+            }
+        }
+
+        // Without a clear, drawing continues on top of the buffer's contents
+        let load = match clear {
+            Some(color) => {
+                let [r, g, b, a] = premultiplied(color);
+                wgpu::LoadOp::Clear(wgpu::Color {
+                    r: r as f64,
+                    g: g as f64,
+                    b: b as f64,
+                    a: a as f64,
+                })
+            }
+            None => {
+                ctx.queue.write_texture(
+                    wgpu::TexelCopyTextureInfo {
+                        texture: &target.texture,
+                        mip_level: 0,
+                        origin: wgpu::Origin3d::ZERO,
+                        aspect: wgpu::TextureAspect::All,
+                    },
+                    buffer,
+                    wgpu::TexelCopyBufferLayout {
+                        offset: 0,
+                        bytes_per_row: Some(width * 4),
+                        rows_per_image: Some(height),
+                    },
+                    extent,
+                );
+                wgpu::LoadOp::Load
+            }
+        };
+
+        let shape_buffer = (!shapes.is_empty()).then(|| {
+            ctx.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("overlay shapes"),
+                    contents: bytemuck::cast_slice(&shapes),
+                    usage: wgpu::BufferUsages::VERTEX,
+                })
+        });
+        let sprite_buffer = (!sprite_rects.is_empty()).then(|| {
+            ctx.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("overlay sprites"),
+                    contents: bytemuck::cast_slice(&sprite_rects),
+                    usage: wgpu::BufferUsages::VERTEX,
+                })
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("overlay frame"),
+            });
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("overlay frame"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &target.view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_bind_group(0, &target.globals, &[]);
+
+            for draw in &draws {
+                match draw {
+                    Draw::Shapes(range) => {
+                        let Some(shape_buffer) = &shape_buffer else {
+                            continue;
+                        };
+                        pass.set_pipeline(&ctx.shape_pipeline);
+                        pass.set_vertex_buffer(0, shape_buffer.slice(..));
+                        pass.draw(0..4, range.clone());
+                    }
+                    Draw::Sprite { key, instance } => {
+                        let (Some(sprite), Some(sprite_buffer)) =
+                            (self.sprites.get(key), &sprite_buffer)
+                        else {
+                            continue;
+                        };
+                        pass.set_pipeline(&ctx.sprite_pipeline);
+                        pass.set_bind_group(1, &sprite.bind_group, &[]);
+                        pass.set_vertex_buffer(0, sprite_buffer.slice(..));
+                        pass.draw(0..4, *instance..*instance + 1);
+                    }
+                }
+            }
+        }
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &target.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &target.readback,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(target.padded_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            extent,
+        );
+        ctx.queue.submit(Some(encoder.finish()));
+
+        // Overlay threads render synchronously, so wait for the readback
+        let slice = target.readback.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+        ctx.device
+            .poll(wgpu::PollType::Wait)
+            .map_err(|e| format!("GPU poll failed: {}", e))?;
+        rx.recv()
+            .map_err(|e| format!("GPU readback dropped: {}", e))?
+            .map_err(|e| format!("GPU readback failed: {}", e))?;
+
+        {
+            let data = slice.get_mapped_range();
+            let row = (width * 4) as usize;
+            let padded_row = target.padded_row as usize;
+            for (y, dest) in buffer
+                .chunks_exact_mut(row)
+                .take(height as usize)
+                .enumerate()
+            {
+                let start = y * padded_row;
+                dest.copy_from_slice(&data[start..start + row]);
+            }
+        }
+        target.readback.unmap();
+
+        Ok(())
+    }
+}
+
+impl RenderBackend for GpuRenderer {
+    fn name(&self) -> &'static str {
+        if self.failed { "software" } else { "gpu" }
+    }
+
+    fn clear(&mut self, buffer: &mut [u8], width: u32, height: u32, color: Color) {
+        if self.failed {
+            return self.software.clear(buffer, width, height, color);
+        }
+        self.commands.push(Command::Clear(color));
+    }
+
+    fn fill_rect(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        color: Color,
+    ) {
+        if self.failed {
+            return self
+                .software
+                .fill_rect(buffer, width, height, x, y, w, h, color);
+        }
+        self.push_shape([x, y, w, h], color, 0.0, 0.0);
+    }
+
+    fn fill_rounded_rect(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        radius: f32,
+        color: Color,
+    ) {
+        if self.failed {
+            return self
+                .software
+                .fill_rounded_rect(buffer, width, height, x, y, w, h, radius, color);
+        }
+        self.push_shape([x, y, w, h], color, radius, 0.0);
+    }
+
+    fn stroke_rounded_rect(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        radius: f32,
+        stroke_width: f32,
+        color: Color,
+    ) {
+        if self.failed {
+            return self.software.stroke_rounded_rect(
+                buffer,
+                width,
+                height,
+                x,
+                y,
+                w,
+                h,
+                radius,
+                stroke_width,
+                color,
+            );
+        }
+        if stroke_width > 0.0 {
+            self.push_shape([x, y, w, h], color, radius, stroke_width);
+        }
+    }
+
+    fn stroke_rounded_rect_dashed(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        radius: f32,
+        stroke_width: f32,
+        color: Color,
+        dash_length: f32,
+        gap_length: f32,
+    ) {
+        if self.failed {
+            return self.software.stroke_rounded_rect_dashed(
+                buffer,
+                width,
+                height,
+                x,
+                y,
+                w,
+                h,
+                radius,
+                stroke_width,
+                color,
+                dash_length,
+                gap_length,
+            );
+        }
+
+        // Dashes are rasterized once by tiny-skia and drawn as a sprite
+        let mut hasher = DefaultHasher::new();
+        "dashed".hash(&mut hasher);
+        hash_floats(
+            &mut hasher,
+            &[w, h, radius, stroke_width, dash_length, gap_length],
+        );
+        hash_floats(&mut hasher, &color_floats(color));
+        let key = hasher.finish();
+
+        let inset = stroke_width / 2.0 + SPRITE_PADDING;
+        let tile = (
+            (w + inset * 2.0).ceil() as u32,
+            (h + inset * 2.0).ceil() as u32,
+        );
+        let rect = [x - inset, y - inset, tile.0 as f32, tile.1 as f32];
+        self.push_sprite(key, rect, tile, |software, pixels, tile_w, tile_h| {
+            software.stroke_rounded_rect_dashed(
+                pixels,
+                tile_w,
+                tile_h,
+                inset,
+                inset,
+                w,
+                h,
+                radius,
+                stroke_width,
+                color,
+                dash_length,
+                gap_length,
+            );
+        });
+    }
+
+    fn draw_text(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        text: &str,
+        x: f32,
+        y: f32,
+        font_size: f32,
+        color: Color,
+    ) {
+        if self.failed {
+            return self
+                .software
+                .draw_text(buffer, width, height, text, x, y, font_size, color);
+        }
+        if text.is_empty() {
+            return;
+        }
+
+        let mut hasher = DefaultHasher::new();
+        "text".hash(&mut hasher);
+        text.hash(&mut hasher);
+        hash_floats(&mut hasher, &[font_size]);
+        hash_floats(&mut hasher, &color_floats(color));
+        let key = hasher.finish();
+
+        // `y` is the baseline; leave room for ascenders above and descenders below
+        let (text_width, _) = self.software.measure_text(text, font_size);
+        let ascent = (font_size * 1.2).ceil() + SPRITE_PADDING;
+        let tile = (
+            (text_width + SPRITE_PADDING * 2.0).ceil() as u32,
+            (ascent + font_size * 0.5 + SPRITE_PADDING).ceil() as u32,
+        );
+        // Snap to whole pixels so the sprite isn't resampled
+        let rect = [
+            (x - SPRITE_PADDING).round(),
+            (y - ascent).round(),
+            tile.0 as f32,
+            tile.1 as f32,
+        ];
+        self.push_sprite(key, rect, tile, |software, pixels, tile_w, tile_h| {
+            software.draw_text(
+                pixels,
+                tile_w,
+                tile_h,
+                text,
+                SPRITE_PADDING,
+                ascent,
+                font_size,
+                color,
+            );
+        });
+    }
+
+    fn measure_text(&mut self, text: &str, font_size: f32) -> (f32, f32) {
+        self.software.measure_text(text, font_size)
+    }
+
+    fn draw_image(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        image_data: &[u8],
+        image_width: u32,
+        image_height: u32,
+        dest_x: f32,
+        dest_y: f32,
+        dest_width: f32,
+        dest_height: f32,
+    ) {
+        if self.failed {
+            return self.software.draw_image(
+                buffer,
+                width,
+                height,
+                image_data,
+                image_width,
+                image_height,
+                dest_x,
+                dest_y,
+                dest_width,
+                dest_height,
+            );
+        }
+        if image_data.len() != (image_width * image_height * 4) as usize {
+            return;
+        }
+
+        let mut hasher = DefaultHasher::new();
+        "image".hash(&mut hasher);
+        (image_width, image_height).hash(&mut hasher);
+        image_data.hash(&mut hasher);
+        let key = hasher.finish();
+
+        let rect = [dest_x, dest_y, dest_width, dest_height];
+        self.push_sprite(key, rect, (image_width, image_height), |_, pixels, _, _| {
+            // Icons are straight alpha; sprites are premultiplied
+            for (dest, src) in pixels.chunks_exact_mut(4).zip(image_data.chunks_exact(4)) {
+                let a = src[3] as u32;
+                dest[0] = (src[0] as u32 * a / 255) as u8;
+                dest[1] = (src[1] as u32 * a / 255) as u8;
+                dest[2] = (src[2] as u32 * a / 255) as u8;
+                dest[3] = src[3];
+            }
+        });
+    }
+
+    fn finish_frame(&mut self, buffer: &mut [u8], width: u32, height: u32) {
+        let commands = std::mem::take(&mut self.commands);
+        if self.failed || width == 0 || height == 0 {
+            return;
+        }
+        if buffer.len() < (width * height * 4) as usize {
+            return;
+        }
+        self.frame += 1;
+
+        let key = frame_key(width, height, &commands);
+        if self.frame_cache.reuse(key, buffer) {
+            return;
+        }
+
+        match self.composite(&commands, buffer, width, height) {
+            Ok(()) => self.frame_cache.store(key, buffer),
+            Err(e) => {
+                tracing::warn!(error = %e, "GPU overlay rendering failed, switching to software");
+                self.failed = true;
+                self.sprites.clear();
+                self.target = None;
+                self.frame_cache = FrameCache::default();
+            }
+        }
+
+        let frame = self.frame;
+        self.sprites
+            .retain(|_, sprite| frame - sprite.last_used <= SPRITE_MAX_IDLE_FRAMES);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape(x: f32) -> Command {
+        Command::Shape(ShapeInstance {
+            rect: [x, 0.0, 10.0, 10.0],
+            color: [1.0; 4],
+            params: [0.0; 4],
+        })
+    }
+
+    #[test]
+    fn test_frame_key_changes_with_commands() {
+        let clear = Command::Clear(Color::TRANSPARENT);
+        let frame = [clear, shape(0.0)];
+
+        let key = frame_key(100, 50, &frame);
+        assert!(key.is_some());
+        assert_eq!(frame_key(100, 50, &frame), key);
+        assert_ne!(frame_key(100, 50, &[clear, shape(1.0)]), key);
+        assert_ne!(frame_key(100, 60, &frame), key);
+        // Drawing over the previous buffer contents is never reused
+        assert_eq!(frame_key(100, 50, &[shape(0.0)]), None);
+    }
+
+    #[test]
+    fn test_unchanged_frame_reuses_pixels() {
+        let mut cache = FrameCache::default();
+        cache.store(Some(7), &[1, 2, 3, 4]);
+
+        let mut buffer = [0; 4];
+        assert!(cache.reuse(Some(7), &mut buffer));
+        assert_eq!(buffer, [1, 2, 3, 4]);
+
+        let mut buffer = [0; 4];
+        assert!(!cache.reuse(Some(8), &mut buffer));
+        assert!(!cache.reuse(None, &mut buffer));
+        assert_eq!(buffer, [0; 4]);
+
+        // Resized window
+        assert!(!cache.reuse(Some(7), &mut [0; 8]));
+    }
+}
@@ -0,0 +1,99 @@
+// Overlay compositing shaders
+//
+// Shapes are instanced quads shaded with a rounded-box signed distance, so
+// fills, rounded corners and outlines are all antialiased in one pipeline.
+// Sprites (text, icons, dashed outlines) are premultiplied textures.
+
+struct Globals {
+    // Target size in pixels
+    size: vec2<f32>,
+    _pad: vec2<f32>,
+};
+
+@group(0) @binding(0) var<uniform> globals: Globals;
+
+fn to_clip(p: vec2<f32>) -> vec4<f32> {
+    return vec4<f32>(p.x / globals.size.x * 2.0 - 1.0, 1.0 - p.y / globals.size.y * 2.0, 0.0, 1.0);
+}
+
+fn quad_corner(vertex_index: u32) -> vec2<f32> {
+    return vec2<f32>(f32(vertex_index & 1u), f32((vertex_index >> 1u) & 1u));
+}
+
+// ─── Shapes ─────────────────────────────────────────────────────────────────
+
+struct ShapeInstance {
+    // x, y, width, height in pixels
+    @location(0) rect: vec4<f32>,
+    // Premultiplied RGBA
+    @location(1) color: vec4<f32>,
+    // corner radius, stroke width (0 = fill), unused, unused
+    @location(2) params: vec4<f32>,
+};
+
+struct ShapeOut {
+    @builtin(position) position: vec4<f32>,
+    // Position relative to the rect center
+    @location(0) local: vec2<f32>,
+    @location(1) half_size: vec2<f32>,
+    @location(2) color: vec4<f32>,
+    @location(3) params: vec4<f32>,
+};
+
+@vertex
+fn vs_shape(@builtin(vertex_index) vertex_index: u32, shape: ShapeInstance) -> ShapeOut {
+    // Grow the quad so antialiased edges and outer stroke halves aren't clipped
+    let grow = shape.params.y * 0.5 + 1.0;
+    let origin = shape.rect.xy - vec2<f32>(grow);
+    let size = shape.rect.zw + vec2<f32>(grow * 2.0);
+    let p = origin + quad_corner(vertex_index) * size;
+
+    var out: ShapeOut;
+    out.position = to_clip(p);
+    out.local = p - (shape.rect.xy + shape.rect.zw * 0.5);
+    out.half_size = shape.rect.zw * 0.5;
+    out.color = shape.color;
+    out.params = shape.params;
+    return out;
+}
+
+fn rounded_box(p: vec2<f32>, half_size: vec2<f32>, radius: f32) -> f32 {
+    let q = abs(p) - half_size + vec2<f32>(radius);
+    return length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+@fragment
+fn fs_shape(in: ShapeOut) -> @location(0) vec4<f32> {
+    let radius = min(in.params.x, min(in.half_size.x, in.half_size.y));
+    var distance = rounded_box(in.local, in.half_size, radius);
+    if in.params.y > 0.0 {
+        distance = abs(distance) - in.params.y * 0.5;
+    }
+    let coverage = clamp(0.5 - distance, 0.0, 1.0);
+    return in.color * coverage;
+}
+
+// ─── Sprites ────────────────────────────────────────────────────────────────
+
+@group(1) @binding(0) var sprite_texture: texture_2d<f32>;
+@group(1) @binding(1) var sprite_sampler: sampler;
+
+struct SpriteOut {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+@vertex
+fn vs_sprite(@builtin(vertex_index) vertex_index: u32, @location(0) rect: vec4<f32>) -> SpriteOut {
+    let corner = quad_corner(vertex_index);
+
+    var out: SpriteOut;
+    out.position = to_clip(rect.xy + corner * rect.zw);
+    out.uv = corner;
+    return out;
+}
+
+@fragment
+fn fs_sprite(in: SpriteOut) -> @location(0) vec4<f32> {
+    return textureSample(sprite_texture, sprite_sampler, in.uv);
+}
@@ -18,8 +18,8 @@
 //! │                  OverlayWindow                       │
 //! │          (window + renderer wrapper)                 │
 //! ├─────────────────────────────────────────────────────┤
-//! │                    renderer                          │
-//! │            tiny-skia + cosmic-text                   │
+//! │                 render_backend                       │
+//! │     tiny-skia + cosmic-text, or wgpu (gpu feature)   │
 //! │              (drawing primitives)                    │
 //! ├─────────────────────────────────────────────────────┤
 //! │                    platform/                         │
@@ -30,10 +30,14 @@
 
 pub mod class_icons;
 pub mod frame;
+pub mod frame_rate;
+#[cfg(feature = "gpu")]
+mod gpu;
 pub mod icons;
 pub mod manager;
 pub mod overlays;
 pub mod platform;
+pub mod render_backend;
 pub mod renderer;
 pub mod utils;
 pub mod widgets;
@@ -42,14 +46,22 @@ pub mod widgets;
 pub use class_icons::{
     ClassIcon, Role, get_class_icon, get_tinted_class_icon, get_white_class_icon,
 };
-pub use frame::OverlayFrame;
+pub use frame::{OverlayFrame, set_frame_style};
+pub use frame_rate::{overlay_frame_interval, set_overlay_fps, set_overlay_idle};
 pub use manager::OverlayWindow;
 pub use overlays::{
+    // Alert banner overlay
+    AlertBannerData,
+    AlertBannerOverlay,
     AlertEntry,
     AlertsData,
     AlertsOverlay,
     BossHealthData,
+    BossHealthLinger,
     BossHealthOverlay,
+    // Boss notes overlay
+    BossNotesData,
+    BossNotesOverlay,
     ChallengeData,
     ChallengeEntry,
     ChallengeOverlay,
@@ -58,6 +70,10 @@ pub use overlays::{
     CooldownData,
     CooldownEntry,
     CooldownOverlay,
+    // Death recap overlay
+    DeathRecapData,
+    DeathRecapEvent,
+    DeathRecapOverlay,
     // DOT tracker overlay
     DotEntry,
     DotTarget,
@@ -91,6 +107,11 @@ pub use overlays::{
     PersonalStats,
     PlayerContribution,
     PlayerRole,
+    // Raid cooldown overlay
+    RaidCooldownCell,
+    RaidCooldownRow,
+    RaidCooldownsData,
+    RaidCooldownsOverlay,
     RaidEffect,
     RaidFrame,
     RaidFrameData,
@@ -99,17 +120,23 @@ pub use overlays::{
     RaidOverlayConfig,
     RaidRegistryAction,
     SwapState,
+    // Tank swap overlay
+    TankSwapData,
+    TankSwapEntry,
+    TankSwapOverlay,
     TimerData,
     TimerEntry,
     TimerOverlay,
 };
 pub use platform::{
-    MonitorInfo, NativeOverlay, OverlayConfig, OverlayPlatform, PlatformError, VirtualScreenBounds,
-    clamp_to_virtual_screen, find_monitor_at, find_monitor_by_id, get_all_monitors,
-    resolve_absolute_position,
+    DisplayChangeCallback, MonitorInfo, NativeOverlay, OverlayConfig, OverlayKey, OverlayPlatform,
+    PlatformError, VirtualScreenBounds, clamp_to_virtual_screen, find_monitor_at,
+    find_monitor_by_id, get_all_monitors, monitor_layout_fingerprint, resolve_absolute_position,
+    watch_display_changes,
 };
+pub use render_backend::{RenderBackend, create_backend, set_renderer_preference};
 pub use renderer::Renderer;
-pub use utils::{color_from_rgba, format_number, format_time, truncate_name};
+pub use utils::{color_from_rgba, format_number, format_number_as, format_time, truncate_name};
 pub use widgets::{Footer, Header, LabeledValue, ProgressBar, colors};
 
 // Re-export tiny_skia Color for external use
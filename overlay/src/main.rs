@@ -71,6 +71,9 @@ mod examples {
                 split_color: None,
                 class_icon: None,
                 role: None,
+                deaths: 0,
+                reflect_value: 0,
+                top_abilities: Vec::new(),
             },
             MetricEntry {
                 name: "Player 2".to_string(),
@@ -83,6 +86,9 @@ mod examples {
                 split_color: None,
                 class_icon: None,
                 role: None,
+                deaths: 0,
+                reflect_value: 0,
+                top_abilities: Vec::new(),
             },
             MetricEntry {
                 name: "Player 3".to_string(),
@@ -95,6 +101,9 @@ mod examples {
                 split_color: None,
                 class_icon: None,
                 role: None,
+                deaths: 0,
+                reflect_value: 0,
+                top_abilities: Vec::new(),
             },
             MetricEntry {
                 name: "Player 4".to_string(),
@@ -107,6 +116,9 @@ mod examples {
                 split_color: None,
                 class_icon: None,
                 role: None,
+                deaths: 0,
+                reflect_value: 0,
+                top_abilities: Vec::new(),
             },
         ];
 
@@ -199,6 +211,9 @@ mod examples {
                 split_color: None,
                 class_icon: None,
                 role: None,
+                deaths: 0,
+                reflect_value: 0,
+                top_abilities: Vec::new(),
             })
             .collect();
 
@@ -309,6 +324,9 @@ mod examples {
                 split_color: None,
                 class_icon: None,
                 role: None,
+                deaths: 0,
+                reflect_value: 0,
+                top_abilities: Vec::new(),
             })
             .collect();
 
@@ -553,7 +571,8 @@ mod examples {
                 effects: vec![
                     RaidEffect::new(500, "Burning")
                         .with_color(tiny_skia::Color::from_rgba8(255, 100, 50, 255))
-                        .with_is_buff(false),
+                        .with_is_buff(false)
+                        .with_severity(baras_core::effects::EffectSeverity::Critical),
                 ],
                 is_self: false,
             },
@@ -808,7 +827,11 @@ mod examples {
 
                 // Create sample timer entries with staggered durations
                 let entries = create_sample_timers(elapsed);
-                overlay.set_data(TimerData { entries });
+                overlay.set_data(TimerData {
+                    entries,
+                    next_phase: Some(("Burn Phase".to_string(), 90.0 - elapsed % 90.0)),
+                    incoming_hit: Some(("Terminate".to_string(), 30.0 - elapsed % 30.0, 40_000)),
+                });
                 overlay.render();
                 last_frame = now;
             }
@@ -840,6 +863,8 @@ mod examples {
                     remaining_secs: remaining,
                     total_secs: *cycle,
                     color: *color,
+                    suggested_cooldown: None,
+                    suggested_cooldown_icon: None,
                 }
             })
             .collect()
@@ -1325,6 +1350,7 @@ mod examples {
             name: "Dread Master Styrak".to_string(),
             current: 8_500_000,
             max: 12_000_000,
+            phase_markers: vec![75.0, 50.0, 30.0],
             first_seen_at: None,
             target_name: Some("Tanky McTank".to_string()),
         }];
@@ -1335,6 +1361,7 @@ mod examples {
                 name: "Operator IX".to_string(),
                 current: 4_200_000,
                 max: 6_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: Some("Tanky McTank".to_string()),
             },
@@ -1342,6 +1369,7 @@ mod examples {
                 name: "Master Control".to_string(),
                 current: 2_800_000,
                 max: 4_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: Some("PewPewLazors".to_string()),
             },
@@ -1349,6 +1377,7 @@ mod examples {
                 name: "Regulator".to_string(),
                 current: 1_500_000,
                 max: 2_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: None,
             },
@@ -1360,6 +1389,7 @@ mod examples {
                 name: "Dread Master Brontes".to_string(),
                 current: 6_200_000,
                 max: 8_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: Some("Shield Wall".to_string()),
             },
@@ -1367,6 +1397,7 @@ mod examples {
                 name: "Dread Master Bestia".to_string(),
                 current: 5_800_000,
                 max: 8_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: Some("Tanky McTank".to_string()),
             },
@@ -1374,6 +1405,7 @@ mod examples {
                 name: "Dread Master Calphayus".to_string(),
                 current: 7_100_000,
                 max: 8_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: None,
             },
@@ -1381,6 +1413,7 @@ mod examples {
                 name: "Dread Master Raptus".to_string(),
                 current: 4_500_000,
                 max: 8_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: Some("PewPewLazors".to_string()),
             },
@@ -1388,6 +1421,7 @@ mod examples {
                 name: "Dread Master Styrak".to_string(),
                 current: 3_200_000,
                 max: 8_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: Some("StabbySith".to_string()),
             },
@@ -1395,6 +1429,7 @@ mod examples {
                 name: "Dread Master Tyrans".to_string(),
                 current: 6_800_000,
                 max: 8_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: None,
             },
@@ -1402,6 +1437,7 @@ mod examples {
                 name: "Dread Guard".to_string(),
                 current: 1_500_000,
                 max: 2_000_000,
+                phase_markers: Vec::new(),
                 first_seen_at: None,
                 target_name: Some("ArsenalMerc".to_string()),
             },
@@ -1409,12 +1445,15 @@ mod examples {
 
         overlay_single.set_data(BossHealthData {
             entries: single_entries,
+            pace_secs: Some(-4.0),
         });
         overlay_triple.set_data(BossHealthData {
             entries: triple_entries,
+            pace_secs: Some(7.0),
         });
         overlay_multi.set_data(BossHealthData {
             entries: multi_entries,
+            pace_secs: None,
         });
 
         let mut last_frame = Instant::now();
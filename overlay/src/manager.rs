@@ -4,23 +4,32 @@
 //! with a high-level rendering API.
 #![allow(clippy::too_many_arguments)]
 
-use crate::platform::{MonitorInfo, NativeOverlay, OverlayConfig, OverlayPlatform, PlatformError};
-use crate::renderer::Renderer;
+use crate::platform::{
+    MonitorInfo, NativeOverlay, OverlayConfig, OverlayKey, OverlayPlatform, PlatformError,
+};
+use crate::render_backend::{RenderBackend, create_backend};
 use tiny_skia::Color;
 
 /// A managed overlay window with its own renderer
 pub struct OverlayWindow {
     platform: NativeOverlay,
-    renderer: Renderer,
+    renderer: Box<dyn RenderBackend>,
+    /// Keyboard mode: overlays take focus and navigation keys (hotkey toggled)
+    keyboard_mode: bool,
 }
 
 impl OverlayWindow {
     /// Create a new overlay window
     pub fn new(config: OverlayConfig) -> Result<Self, PlatformError> {
         let platform = NativeOverlay::new(config)?;
-        let renderer = Renderer::new();
+        let renderer = create_backend();
+        tracing::debug!(renderer = renderer.name(), "Overlay renderer selected");
 
-        Ok(Self { platform, renderer })
+        Ok(Self {
+            platform,
+            renderer,
+            keyboard_mode: false,
+        })
     }
 
     /// Get the window width
@@ -78,6 +87,55 @@ impl OverlayWindow {
         self.platform.take_pending_click()
     }
 
+    /// Take a pending button release position (if any)
+    pub fn take_pending_release(&mut self) -> Option<(f32, f32)> {
+        self.platform.take_pending_release()
+    }
+
+    /// Take the mouse wheel notches since the last call (positive = down)
+    pub fn take_pending_scroll(&mut self) -> i32 {
+        self.platform.take_pending_scroll()
+    }
+
+    /// Last known pointer position within the overlay
+    pub fn pointer_position(&self) -> (f32, f32) {
+        self.platform.pointer_position()
+    }
+
+    /// Check if the pointer is over the overlay (interactive modes only)
+    pub fn is_pointer_inside(&self) -> bool {
+        self.platform.is_pointer_inside()
+    }
+
+    /// Enter or leave keyboard mode
+    ///
+    /// While on, the window takes clicks (reported as pending clicks, not
+    /// drags) and keyboard focus when clicked, and queues navigation keys.
+    pub fn set_keyboard_mode(&mut self, enabled: bool) {
+        self.keyboard_mode = enabled;
+        self.platform.set_click_through(!enabled);
+        self.platform.set_drag_enabled(!enabled);
+        self.platform.set_keyboard_input(enabled);
+    }
+
+    /// Check if in keyboard mode
+    pub fn is_keyboard_mode(&self) -> bool {
+        self.keyboard_mode
+    }
+
+    /// Take the next key pressed in keyboard mode
+    ///
+    /// Keys the platform queued outside keyboard mode are dropped, so a
+    /// mode switch never replays stale input.
+    pub fn take_pending_key(&mut self) -> Option<OverlayKey> {
+        while let Some(key) = self.platform.take_pending_key() {
+            if self.keyboard_mode {
+                return Some(key);
+            }
+        }
+        None
+    }
+
     /// Clear the overlay with a color
     pub fn clear(&mut self, color: Color) {
         let width = self.platform.width();
@@ -215,6 +273,11 @@ impl OverlayWindow {
 
     /// Commit the current frame to the screen
     pub fn commit(&mut self) {
+        let width = self.platform.width();
+        let height = self.platform.height();
+        if let Some(buffer) = self.platform.pixel_buffer() {
+            self.renderer.finish_frame(buffer, width, height);
+        }
         self.platform.commit();
     }
 
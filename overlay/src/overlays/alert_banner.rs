@@ -0,0 +1,236 @@
+//! Alert Banner Overlay
+//!
+//! Flashes high priority alerts ("GET OUT") in large text, centered in the
+//! window. Meant to sit in the middle of the screen with a transparent
+//! background. Only the newest alert is shown; it disappears after the
+//! configured duration, or when dismissed with Escape/Enter.
+
+use std::time::Instant;
+
+use baras_core::context::AlertBannerConfig;
+
+use super::{Overlay, OverlayConfigUpdate, OverlayData};
+use crate::frame::OverlayFrame;
+use crate::platform::{OverlayConfig, OverlayKey, PlatformError};
+use crate::utils::color_from_rgba;
+
+/// A high priority alert to show in the banner
+#[derive(Debug, Clone)]
+pub struct AlertBannerData {
+    /// Banner text
+    pub text: String,
+    /// Alert color (RGBA), used unless the config overrides it
+    pub color: [u8; 4],
+    /// When the alert fired
+    pub created_at: Instant,
+}
+
+impl AlertBannerData {
+    /// Create a banner alert with current timestamp
+    pub fn new(text: String, color: [u8; 4]) -> Self {
+        Self {
+            text,
+            color,
+            created_at: Instant::now(),
+        }
+    }
+}
+
+/// Base dimensions for scaling calculations
+const BASE_WIDTH: f32 = 500.0;
+const BASE_HEIGHT: f32 = 80.0;
+
+/// Horizontal padding at base size
+const BASE_PADDING: f32 = 8.0;
+
+/// Flash toggles per second while `flash` is on
+const FLASH_TOGGLES_PER_SEC: f32 = 4.0;
+
+/// Opacity of the "off" half of a flash
+const FLASH_DIM: f32 = 0.35;
+
+/// Seconds the banner fades out at the end of its duration
+const FADE_SECS: f32 = 0.5;
+
+/// Text opacity `elapsed` seconds into a banner shown for `duration` seconds
+fn banner_opacity(elapsed: f32, duration: f32, flash: bool) -> f32 {
+    if elapsed >= duration {
+        return 0.0;
+    }
+    let remaining = duration - elapsed;
+    if remaining < FADE_SECS {
+        return remaining / FADE_SECS;
+    }
+    if flash && !((elapsed * FLASH_TOGGLES_PER_SEC) as u32).is_multiple_of(2) {
+        FLASH_DIM
+    } else {
+        1.0
+    }
+}
+
+/// Center-screen alert banner overlay
+pub struct AlertBannerOverlay {
+    frame: OverlayFrame,
+    config: AlertBannerConfig,
+    /// Alert currently shown
+    current: Option<AlertBannerData>,
+}
+
+impl AlertBannerOverlay {
+    /// Create a new alert banner overlay
+    pub fn new(
+        window_config: OverlayConfig,
+        config: AlertBannerConfig,
+        background_alpha: u8,
+    ) -> Result<Self, PlatformError> {
+        let mut frame = OverlayFrame::new(window_config, BASE_WIDTH, BASE_HEIGHT)?;
+        frame.set_background_alpha(background_alpha);
+        frame.set_label("Alert Banner");
+
+        Ok(Self {
+            frame,
+            config,
+            current: None,
+        })
+    }
+
+    /// Update the config
+    pub fn set_config(&mut self, config: AlertBannerConfig) {
+        self.config = config;
+    }
+
+    /// Update background alpha
+    pub fn set_background_alpha(&mut self, alpha: u8) {
+        self.frame.set_background_alpha(alpha);
+    }
+
+    /// Show an alert, replacing the one on screen
+    pub fn show(&mut self, alert: AlertBannerData) {
+        self.current = Some(alert);
+    }
+
+    /// Drop the current alert once its duration has passed
+    fn prune_expired(&mut self) {
+        let duration = self.config.duration_secs;
+        if self
+            .current
+            .as_ref()
+            .is_some_and(|a| a.created_at.elapsed().as_secs_f32() >= duration)
+        {
+            self.current = None;
+        }
+    }
+
+    /// Render the overlay
+    pub fn render(&mut self) {
+        self.prune_expired();
+
+        let width = self.frame.width() as f32;
+        let height = self.frame.height() as f32;
+
+        self.frame.begin_frame();
+
+        let Some(alert) = self.current.clone() else {
+            self.frame.end_frame();
+            return;
+        };
+
+        let opacity = banner_opacity(
+            alert.created_at.elapsed().as_secs_f32(),
+            self.config.duration_secs,
+            self.config.flash,
+        );
+        let mut color = if self.config.use_alert_color {
+            alert.color
+        } else {
+            self.config.font_color
+        };
+        color[3] = (color[3] as f32 * opacity) as u8;
+
+        // Shrink long text to fit the window width
+        let padding = self.frame.scaled(BASE_PADDING);
+        let mut font_size = self.frame.scaled(self.config.font_size as f32);
+        let (text_width, _) = self.frame.measure_text(&alert.text, font_size);
+        let max_width = width - padding * 2.0;
+        if text_width > max_width && text_width > 0.0 {
+            font_size *= max_width / text_width;
+        }
+        let (text_width, text_height) = self.frame.measure_text(&alert.text, font_size);
+
+        let x = (width - text_width) / 2.0;
+        let y = (height + text_height) / 2.0;
+        self.frame
+            .draw_text(&alert.text, x, y, font_size, color_from_rgba(color));
+
+        self.frame.end_frame();
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Overlay Trait Implementation
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl Overlay for AlertBannerOverlay {
+    fn update_data(&mut self, data: OverlayData) -> bool {
+        if let OverlayData::AlertBanner(alert) = data {
+            self.show(alert);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update_config(&mut self, config: OverlayConfigUpdate) {
+        if let OverlayConfigUpdate::AlertBanner(banner_config, alpha) = config {
+            self.set_config(banner_config);
+            self.set_background_alpha(alpha);
+        }
+    }
+
+    fn render(&mut self) {
+        AlertBannerOverlay::render(self);
+    }
+
+    fn poll_events(&mut self) -> bool {
+        self.frame.poll_events()
+    }
+
+    /// Escape or Enter dismisses the banner
+    fn handle_key(&mut self, key: OverlayKey) -> bool {
+        match key {
+            OverlayKey::Escape | OverlayKey::Enter if self.current.is_some() => {
+                self.current = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn frame(&self) -> &OverlayFrame {
+        &self.frame
+    }
+
+    fn frame_mut(&mut self) -> &mut OverlayFrame {
+        &mut self.frame
+    }
+
+    /// The banner needs continuous render while flashing and fading
+    fn needs_render(&self) -> bool {
+        self.current.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn banner_flashes_then_fades_out() {
+        assert_eq!(banner_opacity(0.1, 3.0, true), 1.0);
+        assert_eq!(banner_opacity(0.3, 3.0, true), FLASH_DIM);
+        assert_eq!(banner_opacity(0.3, 3.0, false), 1.0);
+        // Last half second fades regardless of flashing
+        assert!((banner_opacity(2.75, 3.0, true) - 0.5).abs() < 1e-4);
+        assert_eq!(banner_opacity(3.0, 3.0, true), 0.0);
+    }
+}
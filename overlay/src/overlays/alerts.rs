@@ -9,7 +9,7 @@ use baras_core::context::AlertsOverlayConfig;
 
 use super::{Overlay, OverlayConfigUpdate, OverlayData};
 use crate::frame::OverlayFrame;
-use crate::platform::{OverlayConfig, PlatformError};
+use crate::platform::{OverlayConfig, OverlayKey, PlatformError};
 use crate::utils::color_from_rgba;
 
 /// A single alert entry for display
@@ -204,6 +204,17 @@ impl Overlay for AlertsOverlay {
         self.frame.poll_events()
     }
 
+    /// Escape or Enter dismisses the newest alert
+    fn handle_key(&mut self, key: OverlayKey) -> bool {
+        match key {
+            OverlayKey::Escape | OverlayKey::Enter if !self.entries.is_empty() => {
+                self.entries.remove(0);
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn frame(&self) -> &OverlayFrame {
         &self.frame
     }
@@ -2,6 +2,8 @@
 //!
 //! Displays real-time health bars for boss NPCs in the current encounter.
 
+use std::time::{Duration, Instant};
+
 use baras_core::OverlayHealthEntry;
 use baras_core::context::BossHealthConfig;
 
@@ -17,6 +19,51 @@ use crate::widgets::colors;
 pub struct BossHealthData {
     /// Current boss health entries (sorted by encounter order)
     pub entries: Vec<OverlayHealthEntry>,
+    /// Seconds behind the best recorded kill at this HP (negative = ahead)
+    pub pace_secs: Option<f32>,
+}
+
+/// Holds the boss bars through short gaps in the boss health data path.
+///
+/// Combat briefly dropping (or the bosses vanishing during an immune phase)
+/// would otherwise blank the overlay and bring it back a moment later.
+/// Gaps shorter than the linger duration are smoothed over by leaving the
+/// last bars up; only a longer gap clears the overlay.
+#[derive(Debug, Default)]
+pub struct BossHealthLinger {
+    /// Whether the overlay is showing bars
+    showing: bool,
+    /// When the current gap began
+    gap_started: Option<Instant>,
+}
+
+impl BossHealthLinger {
+    /// Decide what to send for one poll. `data` is the current boss health
+    /// (None while out of combat). Returns the update to send, if any.
+    pub fn update(
+        &mut self,
+        data: Option<BossHealthData>,
+        linger: Duration,
+        now: Instant,
+    ) -> Option<BossHealthData> {
+        if let Some(data) = data.filter(|d| !d.entries.is_empty()) {
+            self.showing = true;
+            self.gap_started = None;
+            return Some(data);
+        }
+        if !self.showing {
+            return None;
+        }
+
+        // Keep the last bars up until the gap outlasts the linger
+        let gap_started = *self.gap_started.get_or_insert(now);
+        if now.duration_since(gap_started) < linger {
+            return None;
+        }
+        self.showing = false;
+        self.gap_started = None;
+        Some(BossHealthData::default())
+    }
 }
 
 /// Base dimensions for scaling calculations
@@ -169,7 +216,7 @@ impl BossHealthOverlay {
 
         let mut y = padding;
 
-        for entry in &entries {
+        for (i, entry) in entries.iter().enumerate() {
             let progress = entry.percent() / 100.0;
 
             // Scale font to fit boss name if too wide
@@ -185,6 +232,26 @@ impl BossHealthOverlay {
                 font_color,
             );
 
+            // Kill pace against the best recorded kill, right-aligned on the first name row
+            if self.config.show_pace
+                && i == 0
+                && let Some(pace) = self.data.pace_secs
+            {
+                let (pace_text, pace_color) = if pace <= 0.0 {
+                    (format!("▲ {:.0}s ahead", -pace), colors::pace_ahead())
+                } else {
+                    (format!("▼ {:.0}s behind", pace), colors::pace_behind())
+                };
+                let (text_width, _) = self.frame.measure_text(&pace_text, label_font_size);
+                self.frame.draw_text(
+                    &pace_text,
+                    padding + content_width - text_width,
+                    y + label_font_size,
+                    label_font_size,
+                    pace_color,
+                );
+            }
+
             y += label_height + label_bar_gap;
 
             // Format health text for inside bar: "(1.5M/2.0M)"
@@ -214,6 +281,21 @@ impl BossHealthOverlay {
                     bar_radius,
                 );
 
+            // Tick at each phase threshold; ones already pushed past are dimmed
+            if self.config.show_phase_markers {
+                let tick_width = (1.5 * self.frame.scale_factor()).max(1.0);
+                for &marker in &entry.phase_markers {
+                    let tick_color = if entry.percent() > marker {
+                        colors::phase_marker()
+                    } else {
+                        colors::phase_marker_passed()
+                    };
+                    let tick_x = padding + content_width * marker / 100.0 - tick_width / 2.0;
+                    self.frame
+                        .fill_rect(tick_x, y, tick_width, bar_height, tick_color);
+                }
+            }
+
             y += bar_height;
 
             // Draw target name below bar, right-aligned
@@ -278,3 +360,64 @@ impl Overlay for BossHealthOverlay {
         &mut self.frame
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boss_data() -> BossHealthData {
+        BossHealthData {
+            entries: vec![OverlayHealthEntry {
+                name: "Brontes".to_string(),
+                target_name: None,
+                current: 500,
+                max: 1000,
+                phase_markers: Vec::new(),
+                first_seen_at: None,
+            }],
+            pace_secs: None,
+        }
+    }
+
+    #[test]
+    fn test_linger_holds_bars_through_short_gaps() {
+        let mut linger = BossHealthLinger::default();
+        let linger_for = Duration::from_secs(3);
+        let start = Instant::now();
+
+        // Nothing shown yet: gaps send nothing
+        assert!(linger.update(None, linger_for, start).is_none());
+
+        assert!(
+            linger
+                .update(Some(boss_data()), linger_for, start)
+                .is_some()
+        );
+
+        // Short gap, then combat resumes: the overlay is never cleared
+        let gap = start + Duration::from_secs(1);
+        assert!(linger.update(None, linger_for, gap).is_none());
+        let empty = BossHealthData::default();
+        assert!(
+            linger
+                .update(Some(empty), linger_for, gap + Duration::from_secs(1))
+                .is_none()
+        );
+        assert!(
+            linger
+                .update(Some(boss_data()), linger_for, gap + Duration::from_secs(2))
+                .is_some()
+        );
+
+        // A gap longer than the linger clears once
+        let gap = start + Duration::from_secs(10);
+        assert!(linger.update(None, linger_for, gap).is_none());
+        let cleared = linger.update(None, linger_for, gap + linger_for);
+        assert!(cleared.is_some_and(|d| d.entries.is_empty()));
+        assert!(
+            linger
+                .update(None, linger_for, gap + Duration::from_secs(5))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Boss Notes Overlay
+//!
+//! Shows the user's notes for the current boss, one page at a time. Lines
+//! starting with `#` are drawn as headings and `-` lines as bullets. Left/Right
+//! (or the paging hotkeys) flip pages and Escape hides the notes.
+
+use baras_core::context::BossNotesConfig;
+
+use super::{Overlay, OverlayConfigUpdate, OverlayData};
+use crate::frame::OverlayFrame;
+use crate::platform::{OverlayConfig, OverlayKey, PlatformError};
+use crate::utils::color_from_rgba;
+use crate::widgets::{Footer, Header};
+
+/// Data sent from service to boss notes overlay
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BossNotesData {
+    /// Boss the notes belong to (empty when there is nothing to show)
+    pub boss_name: String,
+    /// Note pages, already split
+    pub pages: Vec<String>,
+}
+
+/// Base dimensions for scaling calculations
+const BASE_WIDTH: f32 = 280.0;
+const BASE_HEIGHT: f32 = 200.0;
+
+/// Base layout values (at BASE_WIDTH x BASE_HEIGHT)
+const BASE_PADDING: f32 = 6.0;
+const BASE_SPACING: f32 = 3.0;
+const BASE_BULLET_INDENT: f32 = 10.0;
+
+/// Line height relative to font size
+const LINE_HEIGHT_FACTOR: f32 = 1.3;
+
+/// One line of a note page
+#[derive(Debug, Clone, PartialEq)]
+enum NoteLine {
+    Heading(String),
+    Bullet(String),
+    Text(String),
+}
+
+impl NoteLine {
+    fn parse(line: &str) -> Self {
+        let trimmed = line.trim();
+        if let Some(heading) = trimmed.strip_prefix('#') {
+            NoteLine::Heading(heading.trim_start_matches('#').trim().to_string())
+        } else if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            NoteLine::Bullet(item.trim().to_string())
+        } else {
+            NoteLine::Text(trimmed.to_string())
+        }
+    }
+}
+
+/// Boss notes overlay
+pub struct BossNotesOverlay {
+    frame: OverlayFrame,
+    config: BossNotesConfig,
+    data: BossNotesData,
+    page: usize,
+}
+
+impl BossNotesOverlay {
+    /// Create a new boss notes overlay
+    pub fn new(
+        window_config: OverlayConfig,
+        config: BossNotesConfig,
+        background_alpha: u8,
+    ) -> Result<Self, PlatformError> {
+        let mut frame = OverlayFrame::new(window_config, BASE_WIDTH, BASE_HEIGHT)?;
+        frame.set_background_alpha(background_alpha);
+        frame.set_label("Boss Notes");
+
+        Ok(Self {
+            frame,
+            config,
+            data: BossNotesData::default(),
+            page: 0,
+        })
+    }
+
+    /// Update the config
+    pub fn set_config(&mut self, config: BossNotesConfig) {
+        self.config = config;
+    }
+
+    /// Update background alpha
+    pub fn set_background_alpha(&mut self, alpha: u8) {
+        self.frame.set_background_alpha(alpha);
+    }
+
+    /// Show new notes from the first page
+    pub fn set_data(&mut self, data: BossNotesData) {
+        self.data = data;
+        self.page = 0;
+    }
+
+    /// Split text into lines that fit `max_width`, breaking at spaces
+    fn wrap(&mut self, text: &str, max_width: f32, font_size: f32) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut current = String::new();
+        for word in text.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{current} {word}")
+            };
+            if !current.is_empty() && self.frame.measure_text(&candidate, font_size).0 > max_width {
+                lines.push(std::mem::replace(&mut current, word.to_string()));
+            } else {
+                current = candidate;
+            }
+        }
+        lines.push(current);
+        lines
+    }
+
+    /// Render the overlay
+    pub fn render(&mut self) {
+        let width = self.frame.width() as f32;
+        let height = self.frame.height() as f32;
+
+        self.frame.begin_frame();
+
+        let Some(page) = self.data.pages.get(self.page).cloned() else {
+            self.frame.end_frame();
+            return;
+        };
+
+        let padding = self.frame.scaled(BASE_PADDING);
+        let font_size = self.frame.scaled(self.config.font_size as f32);
+        let spacing = self.frame.scaled(BASE_SPACING);
+        let indent = self.frame.scaled(BASE_BULLET_INDENT);
+        let line_height = font_size * LINE_HEIGHT_FACTOR;
+        let content_width = width - padding * 2.0;
+        let font_color = color_from_rgba(self.config.font_color);
+        let heading_color = color_from_rgba(self.config.heading_color);
+
+        let mut y = Header::new(self.data.boss_name.clone())
+            .with_color(heading_color)
+            .render(
+                &mut self.frame,
+                padding,
+                padding,
+                content_width,
+                font_size,
+                spacing,
+            );
+
+        // Leave room for the page indicator
+        let page_count = self.data.pages.len();
+        let bottom = if page_count > 1 {
+            height - padding - line_height
+        } else {
+            height - padding
+        };
+
+        'lines: for line in page.lines().map(NoteLine::parse) {
+            let (text, color, x, bullet) = match line {
+                NoteLine::Heading(text) => (text, heading_color, padding, false),
+                NoteLine::Bullet(text) => (text, font_color, padding + indent, true),
+                NoteLine::Text(text) => (text, font_color, padding, false),
+            };
+            for (i, wrapped) in self
+                .wrap(&text, width - padding - x, font_size)
+                .into_iter()
+                .enumerate()
+            {
+                if y + line_height > bottom {
+                    break 'lines;
+                }
+                if bullet && i == 0 {
+                    self.frame
+                        .draw_text("•", padding, y + font_size, font_size, color);
+                }
+                self.frame
+                    .draw_text(&wrapped, x, y + font_size, font_size, color);
+                y += line_height;
+            }
+        }
+
+        if page_count > 1 {
+            Footer::new(format!("{}/{}", self.page + 1, page_count))
+                .with_color(font_color)
+                .render(&mut self.frame, padding, bottom, content_width, font_size);
+        }
+
+        self.frame.end_frame();
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Overlay Trait Implementation
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl Overlay for BossNotesOverlay {
+    fn update_data(&mut self, data: OverlayData) -> bool {
+        if let OverlayData::BossNotes(notes) = data {
+            if notes == self.data {
+                return false;
+            }
+            self.set_data(notes);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update_config(&mut self, config: OverlayConfigUpdate) {
+        if let OverlayConfigUpdate::BossNotes(notes_config, alpha) = config {
+            self.set_config(notes_config);
+            self.set_background_alpha(alpha);
+        }
+    }
+
+    fn render(&mut self) {
+        BossNotesOverlay::render(self);
+    }
+
+    fn poll_events(&mut self) -> bool {
+        self.frame.poll_events()
+    }
+
+    fn frame(&self) -> &OverlayFrame {
+        &self.frame
+    }
+
+    fn frame_mut(&mut self) -> &mut OverlayFrame {
+        &mut self.frame
+    }
+
+    /// Left/Right page through the notes, Escape hides them
+    fn handle_key(&mut self, key: OverlayKey) -> bool {
+        match key {
+            OverlayKey::Right | OverlayKey::Down if self.page + 1 < self.data.pages.len() => {
+                self.page += 1;
+                true
+            }
+            OverlayKey::Left | OverlayKey::Up if self.page > 0 => {
+                self.page -= 1;
+                true
+            }
+            OverlayKey::Escape if !self.data.pages.is_empty() => {
+                self.set_data(BossNotesData::default());
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_parse_headings_and_bullets() {
+        assert_eq!(
+            NoteLine::parse("## Phase 2"),
+            NoteLine::Heading("Phase 2".to_string())
+        );
+        assert_eq!(
+            NoteLine::parse("  - Tanks swap at 3"),
+            NoteLine::Bullet("Tanks swap at 3".to_string())
+        );
+        assert_eq!(
+            NoteLine::parse("-5% damage"),
+            NoteLine::Text("-5% damage".to_string())
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! Death Recap Overlay
+//!
+//! Lists the damage and healing a player received in the seconds before they
+//! died, killing blow last. In keyboard mode the list scrolls with Up/Down and
+//! Escape dismisses it.
+
+use baras_core::context::DeathRecapConfig;
+
+use super::{Overlay, OverlayConfigUpdate, OverlayData};
+use crate::frame::OverlayFrame;
+use crate::platform::{OverlayConfig, OverlayKey, PlatformError};
+use crate::utils::{color_from_rgba, format_number, truncate_name};
+use crate::widgets::{EventList, EventRow, Header};
+
+/// One hit or heal in a death recap
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeathRecapEvent {
+    /// Seconds between the event and the death
+    pub secs_before_death: f32,
+    pub source: String,
+    pub ability: String,
+    pub amount: i64,
+    /// Portion of the damage absorbed by shields
+    pub absorbed: i64,
+    pub is_heal: bool,
+    pub is_crit: bool,
+}
+
+/// Data sent from service to death recap overlay
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeathRecapData {
+    /// Player who died (empty when there is nothing to show)
+    pub player_name: String,
+    /// Whether the player who died is the local player
+    pub is_local_player: bool,
+    /// Events oldest first
+    pub events: Vec<DeathRecapEvent>,
+}
+
+/// Base dimensions for scaling calculations
+const BASE_WIDTH: f32 = 300.0;
+const BASE_HEIGHT: f32 = 220.0;
+
+/// Base layout values (at BASE_WIDTH x BASE_HEIGHT)
+const BASE_PADDING: f32 = 6.0;
+const BASE_FONT_SIZE: f32 = 11.0;
+const BASE_SPACING: f32 = 3.0;
+
+/// Maximum characters for ability and source names
+const MAX_ABILITY_CHARS: usize = 20;
+const MAX_SOURCE_CHARS: usize = 14;
+
+/// Death recap overlay
+pub struct DeathRecapOverlay {
+    frame: OverlayFrame,
+    config: DeathRecapConfig,
+    data: DeathRecapData,
+    rows: Vec<EventRow>,
+    /// Rows scrolled up from the bottom (0 shows the killing blow)
+    scroll_from_end: usize,
+    /// Rows drawn last frame (bounds scrolling)
+    visible_rows: usize,
+}
+
+impl DeathRecapOverlay {
+    /// Create a new death recap overlay
+    pub fn new(
+        window_config: OverlayConfig,
+        config: DeathRecapConfig,
+        background_alpha: u8,
+    ) -> Result<Self, PlatformError> {
+        let mut frame = OverlayFrame::new(window_config, BASE_WIDTH, BASE_HEIGHT)?;
+        frame.set_background_alpha(background_alpha);
+        frame.set_label("Death Recap");
+
+        Ok(Self {
+            frame,
+            config,
+            data: DeathRecapData::default(),
+            rows: Vec::new(),
+            scroll_from_end: 0,
+            visible_rows: 0,
+        })
+    }
+
+    /// Update the config
+    pub fn set_config(&mut self, config: DeathRecapConfig) {
+        self.config = config;
+        self.rebuild_rows();
+    }
+
+    /// Update background alpha
+    pub fn set_background_alpha(&mut self, alpha: u8) {
+        self.frame.set_background_alpha(alpha);
+    }
+
+    /// Show a new recap, scrolled to the killing blow
+    pub fn set_data(&mut self, data: DeathRecapData) {
+        self.data = data;
+        self.scroll_from_end = 0;
+        self.rebuild_rows();
+    }
+
+    fn rebuild_rows(&mut self) {
+        let damage_color = color_from_rgba(self.config.damage_color);
+        let heal_color = color_from_rgba(self.config.heal_color);
+
+        self.rows = self
+            .data
+            .events
+            .iter()
+            .filter(|e| self.config.show_heals || !e.is_heal)
+            .map(|e| {
+                let mut amount = if e.is_heal {
+                    format!("+{}", format_number(e.amount))
+                } else {
+                    format!("-{}", format_number(e.amount))
+                };
+                if e.is_crit {
+                    amount.push('*');
+                }
+                if e.absorbed > 0 {
+                    amount = format!("{} ({} abs)", amount, format_number(e.absorbed));
+                }
+                EventRow {
+                    time: format!("-{:.1}s", e.secs_before_death),
+                    text: format!(
+                        "{} ({})",
+                        truncate_name(&e.ability, MAX_ABILITY_CHARS),
+                        truncate_name(&e.source, MAX_SOURCE_CHARS)
+                    ),
+                    amount,
+                    amount_color: if e.is_heal { heal_color } else { damage_color },
+                }
+            })
+            .collect();
+    }
+
+    /// Render the overlay
+    pub fn render(&mut self) {
+        let width = self.frame.width() as f32;
+        let height = self.frame.height() as f32;
+
+        self.frame.begin_frame();
+
+        if self.data.player_name.is_empty() {
+            self.visible_rows = 0;
+            self.frame.end_frame();
+            return;
+        }
+
+        let padding = self.frame.scaled(BASE_PADDING);
+        let font_size = self.frame.scaled(BASE_FONT_SIZE);
+        let spacing = self.frame.scaled(BASE_SPACING);
+        let content_width = width - padding * 2.0;
+        let font_color = color_from_rgba(self.config.font_color);
+
+        let list_y = Header::new(format!("{} died", self.data.player_name))
+            .with_color(font_color)
+            .render(
+                &mut self.frame,
+                padding,
+                padding,
+                content_width,
+                font_size,
+                spacing,
+            );
+
+        let list_height = height - list_y - padding;
+        let row_height = EventList::row_height(font_size);
+        let fits = (list_height / row_height).floor().max(0.0) as usize;
+        let max_scroll = self.rows.len().saturating_sub(fits);
+        self.scroll_from_end = self.scroll_from_end.min(max_scroll);
+        let first_row = max_scroll - self.scroll_from_end;
+
+        self.visible_rows = EventList::new(&self.rows)
+            .with_text_color(font_color)
+            .with_first_row(first_row)
+            .render(
+                &mut self.frame,
+                padding,
+                list_y,
+                content_width,
+                list_height,
+                font_size,
+            );
+
+        self.frame.end_frame();
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Overlay Trait Implementation
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl Overlay for DeathRecapOverlay {
+    fn update_data(&mut self, data: OverlayData) -> bool {
+        if let OverlayData::DeathRecap(recap) = data {
+            // Cleared recaps always apply; others may be limited to the local player
+            let filtered = self.config.local_player_only
+                && !recap.is_local_player
+                && !recap.player_name.is_empty();
+            if filtered || recap == self.data {
+                return false;
+            }
+            self.set_data(recap);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update_config(&mut self, config: OverlayConfigUpdate) {
+        if let OverlayConfigUpdate::DeathRecap(recap_config, alpha) = config {
+            self.set_config(recap_config);
+            self.set_background_alpha(alpha);
+        }
+    }
+
+    fn render(&mut self) {
+        DeathRecapOverlay::render(self);
+    }
+
+    fn poll_events(&mut self) -> bool {
+        self.frame.poll_events()
+    }
+
+    fn frame(&self) -> &OverlayFrame {
+        &self.frame
+    }
+
+    fn frame_mut(&mut self) -> &mut OverlayFrame {
+        &mut self.frame
+    }
+
+    /// Up/Down scroll through older events, Escape dismisses the recap
+    fn handle_key(&mut self, key: OverlayKey) -> bool {
+        let max_scroll = self.rows.len().saturating_sub(self.visible_rows);
+        match key {
+            OverlayKey::Up if self.scroll_from_end < max_scroll => {
+                self.scroll_from_end += 1;
+                true
+            }
+            OverlayKey::Down if self.scroll_from_end > 0 => {
+                self.scroll_from_end -= 1;
+                true
+            }
+            OverlayKey::Escape if !self.data.player_name.is_empty() => {
+                self.set_data(DeathRecapData::default());
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -2,15 +2,17 @@
 //!
 //! Displays a ranked list of players with their damage/healing output.
 
+use std::borrow::Cow;
+
 use baras_core::context::OverlayAppearanceConfig;
 use tiny_skia::Color;
 
 use super::{Overlay, OverlayConfigUpdate, OverlayData};
 use crate::frame::OverlayFrame;
-use crate::platform::{OverlayConfig, PlatformError};
-use crate::utils::{color_from_rgba, format_number, truncate_name};
+use crate::platform::{OverlayConfig, OverlayKey, PlatformError};
+use crate::utils::{color_from_rgba, format_number, format_number_as, truncate_name};
 use crate::widgets::colors;
-use crate::widgets::{Footer, Header, ProgressBar};
+use crate::widgets::{Footer, Header, LabeledValue, ProgressBar, ScrollIndicator, Tooltip};
 
 /// Entry in a DPS/HPS metric
 #[derive(Debug, Clone)]
@@ -30,9 +32,15 @@ pub struct MetricEntry {
     /// Optional custom color for secondary portion of split bar
     pub split_color: Option<Color>,
     /// Optional class icon name (e.g., "assassin", "guardian")
-    pub class_icon: Option<String>,
+    pub class_icon: Option<Cow<'static, str>>,
     /// Optional role for icon tinting
     pub role: Option<crate::class_icons::Role>,
+    /// Deaths in the encounter (shown as a badge when enabled)
+    pub deaths: u32,
+    /// Reflected portion of `value` (shown next to the rate when enabled)
+    pub reflect_value: i64,
+    /// Top abilities by amount, shown when the entry is expanded in keyboard mode
+    pub top_abilities: Vec<(String, i64)>,
 }
 
 impl MetricEntry {
@@ -48,6 +56,9 @@ impl MetricEntry {
             split_color: None,
             class_icon: None,
             role: None,
+            deaths: 0,
+            reflect_value: 0,
+            top_abilities: Vec::new(),
         }
     }
 
@@ -75,15 +86,37 @@ impl MetricEntry {
         self
     }
 
+    /// Set the death count
+    pub fn with_deaths(mut self, deaths: u32) -> Self {
+        self.deaths = deaths;
+        self
+    }
+
+    /// Set the reflected portion of the rate
+    pub fn with_reflect(mut self, reflect_rate: i64) -> Self {
+        self.reflect_value = reflect_rate;
+        self
+    }
+
+    /// Set the top abilities shown when expanded
+    pub fn with_top_abilities(mut self, abilities: Vec<(String, i64)>) -> Self {
+        self.top_abilities = abilities;
+        self
+    }
+
     /// Set class icon for display
-    pub fn with_icon(mut self, icon: String) -> Self {
-        self.class_icon = Some(icon);
+    pub fn with_icon(mut self, icon: impl Into<Cow<'static, str>>) -> Self {
+        self.class_icon = Some(icon.into());
         self
     }
 
     /// Set class icon and role for icon display
-    pub fn with_class_icon(mut self, icon: String, role: crate::class_icons::Role) -> Self {
-        self.class_icon = Some(icon);
+    pub fn with_class_icon(
+        mut self,
+        icon: impl Into<Cow<'static, str>>,
+        role: crate::class_icons::Role,
+    ) -> Self {
+        self.class_icon = Some(icon.into());
         self.role = Some(role);
         self
     }
@@ -104,6 +137,9 @@ const BASE_FONT_SIZE: f32 = 14.0;
 /// Maximum characters for player names before truncation
 const MAX_NAME_CHARS: usize = 16;
 
+/// Width of the hover tooltip (at BASE_WIDTH)
+const BASE_TOOLTIP_WIDTH: f32 = 200.0;
+
 /// A specialized DPS/HPS metric overlay
 pub struct MetricOverlay {
     frame: OverlayFrame,
@@ -114,6 +150,19 @@ pub struct MetricOverlay {
     stack_from_bottom: bool,
     scaling_factor: f32,
     show_class_icons: bool,
+    /// Entry selected in keyboard mode (index among non-empty entries)
+    selected: Option<usize>,
+    /// First entry shown when there are more than `max_entries`
+    scroll_offset: usize,
+    /// Scroll to keep the keyboard selection in view (off after wheel scrolling)
+    follow_selection: bool,
+    /// Whether the selected entry shows its top abilities
+    expanded: bool,
+    /// Index, top and height of each bar drawn last frame (for hover hit-testing)
+    bar_hits: Vec<(usize, f32, f32)>,
+    /// Hovered entry and the pointer position the tooltip is anchored to
+    hovered: Option<(usize, (f32, f32))>,
+    needs_render: bool,
 }
 
 impl MetricOverlay {
@@ -141,6 +190,13 @@ impl MetricOverlay {
             stack_from_bottom,
             scaling_factor: scaling_factor.clamp(1.0, 2.0),
             show_class_icons,
+            selected: None,
+            scroll_offset: 0,
+            follow_selection: true,
+            expanded: false,
+            bar_hits: Vec::new(),
+            hovered: None,
+            needs_render: false,
         })
     }
 
@@ -189,8 +245,21 @@ impl MetricOverlay {
         self.entries.len()
     }
 
+    /// Entry under the pointer while the overlay is interactive
+    fn hit_test_hover(&self) -> Option<usize> {
+        if !self.frame.is_interactive() || !self.frame.is_pointer_inside() {
+            return None;
+        }
+        let (_, py) = self.frame.pointer_position();
+        self.bar_hits
+            .iter()
+            .find(|&&(_, top, height)| py >= top && py < top + height)
+            .map(|&(index, _, _)| index)
+    }
+
     /// Render the metric
     pub fn render(&mut self) {
+        self.needs_render = false;
         let width = self.frame.width() as f32;
         let height = self.frame.height() as f32;
 
@@ -213,16 +282,52 @@ impl MetricOverlay {
         let show_total = self.appearance.show_total;
         let show_per_second = self.appearance.show_per_second;
         let show_class_icons = self.show_class_icons;
+        let show_deaths = self.appearance.show_deaths;
+        let show_reflect = self.appearance.show_reflect;
+        let number_format = self.appearance.number_format;
+        let format_value = |n: i64| format_number_as(n as f64, number_format);
 
-        // Filter and limit entries to max_entries
+        // Filter entries, then show a max_entries window starting at the scroll offset
         let max_entries = self.appearance.max_entries as usize;
-        let visible_entries: Vec<_> = self
+        let shown_entries: Vec<_> = self
             .entries
             .iter()
             .filter(|e| self.show_empty_bars || e.value != 0)
-            .take(max_entries)
             .collect();
-        let num_entries = visible_entries.len();
+        let selected = self.selected.filter(|&i| i < shown_entries.len());
+
+        // An expanded entry lists its top abilities in rows taking the place of bars
+        let detail_rows = match selected {
+            Some(i) if self.expanded => shown_entries[i].top_abilities.len().max(1),
+            _ => 0,
+        };
+        let bar_slots = max_entries.saturating_sub(detail_rows).max(1);
+
+        // Keep the selected entry in view
+        let mut scroll_offset = self.scroll_offset;
+        if let Some(i) = selected
+            && self.follow_selection
+        {
+            if i < scroll_offset {
+                scroll_offset = i;
+            } else if i >= scroll_offset + bar_slots {
+                scroll_offset = i + 1 - bar_slots;
+            }
+        }
+        scroll_offset = scroll_offset.min(shown_entries.len().saturating_sub(bar_slots));
+        self.scroll_offset = scroll_offset;
+
+        let visible_entries: Vec<_> = shown_entries
+            .iter()
+            .skip(scroll_offset)
+            .take(bar_slots)
+            .copied()
+            .collect();
+        let num_entries = if selected.is_some() {
+            visible_entries.len() + detail_rows
+        } else {
+            visible_entries.len()
+        };
 
         // Calculate space reserved for header and footer (must match actual widget heights)
         // Header with separator: font_size + spacing + 2.0 + spacing + 4.0 * scale
@@ -263,6 +368,7 @@ impl MetricOverlay {
 
         // Begin frame (clear, background, border)
         self.frame.begin_frame();
+        self.bar_hits.clear();
 
         let content_width = width - padding * 2.0;
         let bar_radius = 4.0 * self.frame.scale_factor();
@@ -325,7 +431,7 @@ impl MetricOverlay {
         let icon_size = bar_height - 4.0 * self.frame.scale_factor(); // Slightly smaller than bar
         let icon_padding = 2.0 * self.frame.scale_factor();
 
-        for entry in &visible_entries {
+        for (i, entry) in visible_entries.iter().enumerate() {
             // Determine fill color (use entry color if custom, otherwise config bar_color)
             let fill_color = if entry.color != colors::dps_bar_fill() {
                 entry.color
@@ -336,7 +442,10 @@ impl MetricOverlay {
             // Check if we have an icon to show
             let has_icon = show_class_icons && entry.class_icon.is_some();
 
-            let display_name = truncate_name(&entry.name, MAX_NAME_CHARS);
+            let mut display_name = truncate_name(&entry.name, MAX_NAME_CHARS);
+            if show_deaths && entry.deaths > 0 {
+                display_name = format!("{} †{}", display_name, entry.deaths);
+            }
             let progress = if max_val > 0.0 {
                 (entry.value as f64 / max_val) as f32
             } else {
@@ -364,19 +473,30 @@ impl MetricOverlay {
                 }
             }
 
+            // Reflected share trails the rate, e.g. "12.3K (R 1.2K)"
+            let rate_text = if show_reflect && entry.reflect_value > 0 {
+                format!(
+                    "{} (R {})",
+                    format_value(entry.value),
+                    format_value(entry.reflect_value)
+                )
+            } else {
+                format_value(entry.value)
+            };
+
             // Add text based on show_total and show_per_second settings
             // Per-second is always rightmost when enabled, total goes center or right
             if show_per_second && show_total {
                 // Both: total in center, rate on right
                 bar = bar
-                    .with_center_text(format_number(entry.total_value))
-                    .with_right_text(format_number(entry.value));
+                    .with_center_text(format_value(entry.total_value))
+                    .with_right_text(rate_text);
             } else if show_per_second {
                 // Rate only (default): rate on right
-                bar = bar.with_right_text(format_number(entry.value));
+                bar = bar.with_right_text(rate_text);
             } else if show_total {
                 // Total only: total on right
-                bar = bar.with_right_text(format_number(entry.total_value));
+                bar = bar.with_right_text(format_value(entry.total_value));
             }
             // If neither, just show name (no values)
 
@@ -410,22 +530,79 @@ impl MetricOverlay {
                 }
             }
 
+            self.bar_hits.push((scroll_offset + i, y, bar_height));
+
+            if selected == Some(scroll_offset + i) {
+                self.frame.stroke_rounded_rect(
+                    padding,
+                    y,
+                    content_width,
+                    bar_height,
+                    bar_radius,
+                    2.0 * self.frame.scale_factor(),
+                    colors::keyboard_selection(),
+                );
+                y += bar_height + effective_spacing;
+
+                if self.expanded {
+                    let text_y = |row_y: f32| row_y + bar_height / 2.0 + text_font_size / 3.0;
+                    let indent = padding + bar_height;
+                    if entry.top_abilities.is_empty() {
+                        self.frame.draw_text(
+                            "No ability data",
+                            indent,
+                            text_y(y),
+                            text_font_size,
+                            colors::label_dim(),
+                        );
+                        y += bar_height + effective_spacing;
+                    }
+                    for (name, amount) in &entry.top_abilities {
+                        LabeledValue::new(
+                            truncate_name(name, MAX_NAME_CHARS),
+                            format_number(*amount),
+                        )
+                        .with_label_color(colors::label_dim())
+                        .with_value_color(font_color)
+                        .render(
+                            &mut self.frame,
+                            indent,
+                            text_y(y),
+                            content_width - bar_height,
+                            text_font_size,
+                        );
+                        y += bar_height + effective_spacing;
+                    }
+                }
+                continue;
+            }
+
             y += bar_height + effective_spacing;
         }
 
+        // Scroll indicator in the right padding, alongside the bars
+        let indicator_width = (3.0 * self.frame.scale_factor()).min(padding);
+        ScrollIndicator::new(shown_entries.len(), bar_slots, scroll_offset).render(
+            &mut self.frame,
+            padding + content_width + (padding - indicator_width) / 2.0,
+            bars_start_y,
+            indicator_width,
+            total_bars_height,
+        );
+
         // Draw footer using Footer widget
         if self.appearance.show_footer {
             let footer = if show_per_second && show_total {
                 // Both enabled: show total sum in center, rate sum on right
-                Footer::new(format_number(rate_sum))
-                    .with_secondary(format_number(total_sum))
+                Footer::new(format_value(rate_sum))
+                    .with_secondary(format_value(total_sum))
                     .with_color(font_color)
             } else if show_per_second {
                 // Rate only: show rate sum on right
-                Footer::new(format_number(rate_sum)).with_color(font_color)
+                Footer::new(format_value(rate_sum)).with_color(font_color)
             } else if show_total {
                 // Total only: show total sum on right
-                Footer::new(format_number(total_sum)).with_color(font_color)
+                Footer::new(format_value(total_sum)).with_color(font_color)
             } else {
                 // Neither: empty footer (just separator)
                 Footer::new("").with_color(font_color)
@@ -434,6 +611,28 @@ impl MetricOverlay {
             footer.render(&mut self.frame, padding, y, content_width, font_size - 2.0);
         }
 
+        // Tooltip goes last so it draws over the bars and footer
+        if let Some((index, pointer)) = self.hovered
+            && let Some(entry) = shown_entries.get(index)
+        {
+            let tooltip = entry.top_abilities.iter().fold(
+                Tooltip::new(truncate_name(&entry.name, MAX_NAME_CHARS))
+                    .with_empty_text("No ability data")
+                    .with_text_color(font_color),
+                |tooltip, (name, amount)| {
+                    tooltip.with_row(
+                        LabeledValue::new(
+                            truncate_name(name, MAX_NAME_CHARS),
+                            format_number(*amount),
+                        )
+                        .with_value_color(font_color),
+                    )
+                },
+            );
+            let tooltip_width = self.frame.scaled(BASE_TOOLTIP_WIDTH).min(content_width);
+            tooltip.render(&mut self.frame, pointer, tooltip_width, text_font_size);
+        }
+
         // End frame (resize indicator, commit)
         self.frame.end_frame();
     }
@@ -477,7 +676,69 @@ impl Overlay for MetricOverlay {
     }
 
     fn poll_events(&mut self) -> bool {
-        self.frame.poll_events()
+        let running = self.frame.poll_events();
+
+        // Mouse wheel scrolls the list (render clamps the offset to the entries)
+        let scroll = self.frame.take_pending_scroll();
+        if scroll != 0 {
+            self.scroll_offset = self.scroll_offset.saturating_add_signed(scroll as isize);
+            self.follow_selection = false;
+            self.needs_render = true;
+        }
+
+        // Show the tooltip for the hovered bar; it stays put while the pointer
+        // moves within that bar
+        let hovered = self.hit_test_hover();
+        if hovered != self.hovered.map(|(index, _)| index) {
+            self.hovered = hovered.map(|index| (index, self.frame.pointer_position()));
+            self.needs_render = true;
+        }
+        running
+    }
+
+    fn needs_render(&self) -> bool {
+        self.needs_render
+    }
+
+    fn set_keyboard_mode(&mut self, enabled: bool) {
+        self.frame.set_keyboard_mode(enabled);
+        self.selected = None;
+        self.expanded = false;
+    }
+
+    fn handle_key(&mut self, key: OverlayKey) -> bool {
+        self.follow_selection = true;
+        let count = self
+            .entries
+            .iter()
+            .filter(|e| self.show_empty_bars || e.value != 0)
+            .count();
+        if count == 0 {
+            return false;
+        }
+
+        match key {
+            OverlayKey::Down => {
+                self.selected = Some(self.selected.map_or(0, |i| (i + 1).min(count - 1)));
+            }
+            OverlayKey::Up => {
+                self.selected = Some(self.selected.map_or(0, |i| i.saturating_sub(1)));
+            }
+            OverlayKey::Enter => {
+                self.selected.get_or_insert(0);
+                self.expanded = !self.expanded;
+            }
+            OverlayKey::Right => {
+                self.selected.get_or_insert(0);
+                self.expanded = true;
+            }
+            OverlayKey::Left => self.expanded = false,
+            OverlayKey::Escape => {
+                self.selected = None;
+                self.expanded = false;
+            }
+        }
+        true
     }
 
     fn frame(&self) -> &OverlayFrame {
@@ -9,22 +9,30 @@
 //! All overlays implement the `Overlay` trait, which provides a unified
 //! interface for the application layer to interact with any overlay type.
 
+mod alert_banner;
 mod alerts;
 mod boss_health;
+mod boss_notes;
 mod challenges;
 mod cooldowns;
+mod death_recap;
 mod dot_tracker;
 mod effects;
 mod effects_ab;
 mod metric;
 mod personal;
 mod raid;
+mod raid_cooldowns;
+mod tank_swap;
 mod timers;
 
+pub use alert_banner::{AlertBannerData, AlertBannerOverlay};
 pub use alerts::{AlertEntry, AlertsData, AlertsOverlay};
-pub use boss_health::{BossHealthData, BossHealthOverlay};
+pub use boss_health::{BossHealthData, BossHealthLinger, BossHealthOverlay};
+pub use boss_notes::{BossNotesData, BossNotesOverlay};
 pub use challenges::{ChallengeData, ChallengeEntry, ChallengeOverlay, PlayerContribution};
 pub use cooldowns::{CooldownConfig, CooldownData, CooldownEntry, CooldownOverlay};
+pub use death_recap::{DeathRecapData, DeathRecapEvent, DeathRecapOverlay};
 pub use dot_tracker::{DotEntry, DotTarget, DotTrackerConfig, DotTrackerData, DotTrackerOverlay};
 pub use effects::{EffectEntry, EffectsData, EffectsOverlay};
 pub use effects_ab::{
@@ -50,6 +58,10 @@ pub use raid::{
     RaidOverlayConfig,
     SwapState,
 };
+pub use raid_cooldowns::{
+    RaidCooldownCell, RaidCooldownRow, RaidCooldownsData, RaidCooldownsOverlay,
+};
+pub use tank_swap::{TankSwapData, TankSwapEntry, TankSwapOverlay};
 pub use timers::{TimerData, TimerEntry, TimerOverlay};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -67,9 +79,11 @@ pub enum RaidRegistryAction {
 }
 
 use crate::frame::OverlayFrame;
+use crate::platform::OverlayKey;
 use baras_core::context::{
-    AlertsOverlayConfig, BossHealthConfig, ChallengeOverlayConfig, OverlayAppearanceConfig,
-    PersonalOverlayConfig, TimerOverlayConfig,
+    AlertBannerConfig, AlertsOverlayConfig, BossHealthConfig, BossNotesConfig,
+    ChallengeOverlayConfig, DeathRecapConfig, OverlayAppearanceConfig, PersonalOverlayConfig,
+    RaidCooldownConfig, TankSwapConfig, TimerOverlayConfig,
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -105,6 +119,16 @@ pub enum OverlayData {
     Cooldowns(CooldownData),
     /// DOTs on enemy targets
     DotTracker(DotTrackerData),
+    /// Tank-swap debuff stacks on both tanks
+    TankSwap(TankSwapData),
+    /// Defensive and raid utility cooldowns of every player
+    RaidCooldowns(RaidCooldownsData),
+    /// Events leading up to a player's death
+    DeathRecap(DeathRecapData),
+    /// User notes for the current boss
+    BossNotes(BossNotesData),
+    /// High priority alert for the center-screen banner
+    AlertBanner(AlertBannerData),
 }
 
 /// Configuration updates that can be sent to overlays
@@ -136,6 +160,16 @@ pub enum OverlayConfigUpdate {
     Cooldowns(CooldownConfig, u8),
     /// Config for DOT tracker overlay (+ background alpha)
     DotTracker(DotTrackerConfig, u8),
+    /// Config for tank swap overlay (+ background alpha)
+    TankSwap(TankSwapConfig, u8),
+    /// Config for raid cooldown overlay (+ background alpha)
+    RaidCooldowns(RaidCooldownConfig, u8),
+    /// Config for death recap overlay (+ background alpha)
+    DeathRecap(DeathRecapConfig, u8),
+    /// Config for boss notes overlay (+ background alpha)
+    BossNotes(BossNotesConfig, u8),
+    /// Config for alert banner overlay (+ background alpha)
+    AlertBanner(AlertBannerConfig, u8),
 }
 
 /// Position information for an overlay
@@ -237,6 +271,31 @@ pub trait Overlay: 'static {
         // Default: no-op for non-raid overlays
     }
 
+    /// Set keyboard mode (global, toggled by hotkey): overlays take focus when
+    /// clicked and respond to navigation keys.
+    /// Default implementation switches the window over. Override to reset
+    /// navigation state, or to opt out.
+    fn set_keyboard_mode(&mut self, enabled: bool) {
+        self.frame_mut().set_keyboard_mode(enabled);
+    }
+
+    /// Handle a key pressed in keyboard mode.
+    /// Returns `true` if the overlay needs a re-render.
+    /// Default implementation ignores keys.
+    fn handle_key(&mut self, _key: OverlayKey) -> bool {
+        false
+    }
+
+    /// Deliver keys pressed since the last call to `handle_key`.
+    /// Returns `true` if any of them needs a re-render.
+    fn route_keys(&mut self) -> bool {
+        let mut needs_render = false;
+        while let Some(key) = self.frame_mut().take_pending_key() {
+            needs_render |= self.handle_key(key);
+        }
+        needs_render
+    }
+
     /// Take any pending registry actions (raid overlay only).
     /// Returns actions that need to be sent to the service for registry updates.
     /// Default implementation returns empty vec.
@@ -7,7 +7,7 @@ use baras_core::context::{PersonalOverlayConfig, PersonalStat};
 use super::{Overlay, OverlayConfigUpdate, OverlayData};
 use crate::frame::OverlayFrame;
 use crate::platform::{OverlayConfig, PlatformError};
-use crate::utils::{color_from_rgba, format_number, format_time};
+use crate::utils::{color_from_rgba, format_number_as, format_time};
 use crate::widgets::LabeledValue;
 
 /// Data for the personal overlay
@@ -27,6 +27,7 @@ pub struct PersonalStats {
     pub hps: i32,
     pub ehps: i32,
     pub total_healing: i64,
+    pub abs: i32,
     pub dtps: i32,
     pub edtps: i32,
     pub tps: i32,
@@ -36,6 +37,12 @@ pub struct PersonalStats {
     pub effective_heal_pct: f32,
     pub current_phase: Option<String>,
     pub phase_time_secs: f32,
+    /// Next HP-triggered phase and the estimated seconds until it starts
+    pub next_phase_eta: Option<(String, f32)>,
+    /// Seconds left before the boss's hard enrage
+    pub time_to_enrage_secs: Option<f32>,
+    /// Persistent cross-session counters as (label, count)
+    pub counters: Vec<(String, u64)>,
 }
 
 /// Base dimensions for scaling calculations
@@ -87,6 +94,8 @@ impl PersonalOverlay {
 
     /// Get the display value for a stat
     fn stat_display(&self, stat: PersonalStat) -> (&'static str, String) {
+        let number_format = self.config.format_for(stat);
+        let amount = |value: f64| format_number_as(value, number_format);
         match stat {
             PersonalStat::EncounterName => {
                 let name = self.stats.encounter_name.as_deref().unwrap_or("");
@@ -104,17 +113,18 @@ impl PersonalOverlay {
                 format!("{}", self.stats.encounter_count),
             ),
             PersonalStat::Apm => ("APM", format!("{:.1}", self.stats.apm)),
-            PersonalStat::Dps => ("DPS", format_number(self.stats.dps as i64)),
-            PersonalStat::EDps => ("eDPS", format_number(self.stats.edps as i64)),
-            PersonalStat::BossDps => ("Boss DPS", format_number(self.stats.bossdps as i64)),
-            PersonalStat::TotalDamage => ("Damage", format_number(self.stats.total_damage)),
-            PersonalStat::BossDamage => ("Boss Dmg", format_number(self.stats.total_damage_boss)),
-            PersonalStat::Hps => ("HPS", format_number(self.stats.hps as i64)),
-            PersonalStat::EHps => ("eHPS", format_number(self.stats.ehps as i64)),
-            PersonalStat::TotalHealing => ("Healing", format_number(self.stats.total_healing)),
-            PersonalStat::Dtps => ("eDTPS", format_number(self.stats.edtps as i64)),
-            PersonalStat::Tps => ("TPS", format_number(self.stats.tps as i64)),
-            PersonalStat::TotalThreat => ("Threat", format_number(self.stats.total_threat)),
+            PersonalStat::Dps => ("DPS", amount(self.stats.dps as f64)),
+            PersonalStat::EDps => ("eDPS", amount(self.stats.edps as f64)),
+            PersonalStat::BossDps => ("Boss DPS", amount(self.stats.bossdps as f64)),
+            PersonalStat::TotalDamage => ("Damage", amount(self.stats.total_damage as f64)),
+            PersonalStat::BossDamage => ("Boss Dmg", amount(self.stats.total_damage_boss as f64)),
+            PersonalStat::Hps => ("HPS", amount(self.stats.hps as f64)),
+            PersonalStat::EHps => ("eHPS", amount(self.stats.ehps as f64)),
+            PersonalStat::TotalHealing => ("Healing", amount(self.stats.total_healing as f64)),
+            PersonalStat::Aps => ("APS", amount(self.stats.abs as f64)),
+            PersonalStat::Dtps => ("eDTPS", amount(self.stats.edtps as f64)),
+            PersonalStat::Tps => ("TPS", amount(self.stats.tps as f64)),
+            PersonalStat::TotalThreat => ("Threat", amount(self.stats.total_threat as f64)),
             PersonalStat::DamageCritPct => {
                 ("Dmg Crit", format!("{:.1}%", self.stats.damage_crit_pct))
             }
@@ -143,6 +153,25 @@ impl PersonalOverlay {
                 };
                 ("Phase Time", time_str)
             }
+            PersonalStat::NextPhaseEta => {
+                let eta_str = self
+                    .stats
+                    .next_phase_eta
+                    .as_ref()
+                    .map(|(phase, secs)| format!("{} ~{}", phase, format_time(*secs as u64)))
+                    .unwrap_or_default();
+                ("Next Phase", eta_str)
+            }
+            PersonalStat::TimeToEnrage => {
+                let enrage_str = self
+                    .stats
+                    .time_to_enrage_secs
+                    .map(|secs| format_time(secs.ceil() as u64))
+                    .unwrap_or_default();
+                ("Enrage", enrage_str)
+            }
+            // Drawn one line per counter by `render`
+            PersonalStat::Counters => ("Counters", String::new()),
         }
     }
 
@@ -165,6 +194,18 @@ impl PersonalOverlay {
         let content_width = width - padding * 2.0;
 
         for stat in &self.config.visible_stats {
+            if *stat == PersonalStat::Counters {
+                for (label, count) in &self.stats.counters {
+                    LabeledValue::new(label.as_str(), count.to_string())
+                        .with_label_color(label_color)
+                        .with_value_color(font_color)
+                        .render(&mut self.frame, padding, y, content_width, font_size);
+
+                    y += line_height;
+                }
+                continue;
+            }
+
             let (label, value) = self.stat_display(*stat);
 
             LabeledValue::new(label, value)
@@ -1,15 +1,17 @@
 //! Raid Frame Overlay
 //!
 //! Displays a grid of player frames showing health, effects, and role icons.
-//! Supports click-to-swap rearrangement of frames.
+//! Supports click-to-swap and drag-and-drop rearrangement of frames.
 
+use baras_core::context::{RaidFrameLayout, RaidNameStyle};
+use baras_core::effects::EffectSeverity;
 use std::time::Instant;
 use tiny_skia::Color;
 
 use super::{Overlay, OverlayConfigUpdate, OverlayData, RaidRegistryAction};
 use crate::frame::OverlayFrame;
 use crate::platform::{OverlayConfig, PlatformError};
-use crate::utils::truncate_name;
+use crate::utils::{name_initials, truncate_name};
 use crate::widgets::colors;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -81,6 +83,8 @@ pub struct RaidEffect {
     pub is_buff: bool,
     /// Pre-loaded icon RGBA data (width, height, rgba_bytes) - Arc for cheap cloning
     pub icon: Option<std::sync::Arc<(u32, u32, Vec<u8>)>>,
+    /// Mechanic severity (critical effects are enlarged and flash)
+    pub severity: EffectSeverity,
 }
 
 impl RaidEffect {
@@ -94,6 +98,7 @@ impl RaidEffect {
             color: Color::from_rgba8(100, 180, 255, 255),
             is_buff: true,
             icon: None,
+            severity: EffectSeverity::Low,
         }
     }
 
@@ -142,6 +147,11 @@ impl RaidEffect {
         self
     }
 
+    pub fn with_severity(mut self, severity: EffectSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
     /// Check if the effect has expired
     pub fn is_expired(&self) -> bool {
         self.expires_at.is_some_and(|exp| exp <= Instant::now())
@@ -232,8 +242,16 @@ impl RaidFrame {
         } else if self.effects.len() < max_effects {
             // New effect, have room
             self.effects.push(effect);
+        } else if let Some(least) = self
+            .effects
+            .iter_mut()
+            .min_by_key(|e| e.severity)
+            .filter(|e| e.severity < effect.severity)
+        {
+            // At max effects: a more severe effect displaces the least severe one
+            *least = effect;
         }
-        // At max effects: ignore new effect (oldest stay)
+        // Otherwise ignore new effect (oldest stay)
     }
 
     /// Remove an effect by ID
@@ -251,11 +269,15 @@ impl RaidFrame {
 // Swap State
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// State for the click-to-swap interaction
+/// State for the click-to-swap and drag-and-drop interactions
 #[derive(Debug, Clone, Copy, Default)]
 pub struct SwapState {
     /// Currently selected slot for swapping (first click)
     pub selected_slot: Option<u8>,
+    /// Slot the button was pressed on (while held)
+    pub drag_source: Option<u8>,
+    /// Slot under the pointer while dragging
+    pub drop_target: Option<u8>,
 }
 
 impl SwapState {
@@ -281,9 +303,50 @@ impl SwapState {
         }
     }
 
+    /// Handle a button press on a slot (start of a possible drag)
+    pub fn on_press(&mut self, slot: u8) {
+        self.drag_source = Some(slot);
+        self.drop_target = Some(slot);
+    }
+
+    /// Update the slot under the pointer while dragging.
+    /// Returns true if the drop target changed.
+    pub fn on_drag_over(&mut self, slot: Option<u8>) -> bool {
+        if self.drag_source.is_none() || self.drop_target == slot {
+            return false;
+        }
+        self.drop_target = slot;
+        true
+    }
+
+    /// Handle the button release that ends a press
+    /// Returns Some((a, b)) if a swap should occur between slots a and b
+    pub fn on_release(&mut self, slot: Option<u8>) -> Option<(u8, u8)> {
+        let source = self.drag_source.take()?;
+        self.drop_target = None;
+        match slot {
+            // Released on the pressed slot: a plain click
+            Some(slot) if slot == source => self.on_click(slot),
+            // Dropped on another slot: swap directly
+            Some(slot) => {
+                self.selected_slot = None;
+                Some((source, slot))
+            }
+            // Dropped outside the grid: nothing to do
+            None => None,
+        }
+    }
+
+    /// Whether a frame is being dragged onto another slot
+    pub fn is_dragging(&self) -> bool {
+        self.drag_source.is_some() && self.drop_target != self.drag_source
+    }
+
     /// Cancel selection (e.g., on Escape or background click)
     pub fn cancel(&mut self) {
         self.selected_slot = None;
+        self.drag_source = None;
+        self.drop_target = None;
     }
 }
 
@@ -297,7 +360,7 @@ pub enum InteractionMode {
     #[default]
     Normal, // click_through = true, clicks pass through
     Move,      // click_through = false, drag = move window
-    Rearrange, // click_through = false, click = swap slots
+    Rearrange, // click_through = false, click or drag = swap slots
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -395,6 +458,16 @@ pub struct RaidOverlayConfig {
     pub effect_fill_opacity: u8,
     /// Whether to render effect icons (true) or colored squares (false)
     pub show_effect_icons: bool,
+
+    // ─── Compact Layout ────────────────────────────────────────────────────
+    /// Smaller frames showing a short name and effects as thin bars
+    pub compact: bool,
+    /// How names are shortened in the compact layout
+    pub name_style: RaidNameStyle,
+
+    // ─── Health ────────────────────────────────────────────────────────────
+    /// Draw an HP fill with the missing health in a deficit color
+    pub show_health_bars: bool,
 }
 
 impl Default for RaidOverlayConfig {
@@ -408,6 +481,9 @@ impl Default for RaidOverlayConfig {
             effect_vertical_offset: EFFECT_OFFSET_DEFAULT,
             effect_fill_opacity: 255, // Fully opaque when no icons
             show_effect_icons: false,
+            compact: false,
+            name_style: RaidNameStyle::Initials,
+            show_health_bars: false,
         }
     }
 }
@@ -423,6 +499,14 @@ impl RaidOverlayConfig {
         self.effect_vertical_offset
             .clamp(EFFECT_OFFSET_MIN, EFFECT_OFFSET_MAX)
     }
+
+    /// Player name as shown in the compact layout
+    pub fn short_name(&self, name: &str) -> String {
+        match self.name_style {
+            RaidNameStyle::Initials => name_initials(name),
+            RaidNameStyle::Truncated => name.chars().take(COMPACT_NAME_CHARS).collect(),
+        }
+    }
 }
 
 impl From<baras_core::context::RaidOverlaySettings> for RaidOverlayConfig {
@@ -436,6 +520,9 @@ impl From<baras_core::context::RaidOverlaySettings> for RaidOverlayConfig {
             effect_vertical_offset: settings.effect_vertical_offset,
             effect_fill_opacity: settings.effect_fill_opacity,
             show_effect_icons: settings.show_effect_icons,
+            compact: settings.frame_layout == RaidFrameLayout::Compact,
+            name_style: settings.compact_name_style,
+            show_health_bars: settings.show_health_bars,
         }
     }
 }
@@ -460,9 +547,19 @@ const BASE_HEIGHT: f32 = 180.0;
 const BASE_GAP: f32 = 4.0;
 const BASE_PADDING: f32 = 8.0;
 
-/// Minimum interval between renders in Normal mode (10 FPS = 100ms)
-/// This reduces CPU usage significantly while still providing smooth timer countdowns
-const RENDER_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+/// Compact layout spacing and minimum frame size
+const COMPACT_GAP: f32 = 2.0;
+const COMPACT_PADDING: f32 = 3.0;
+const COMPACT_MIN_FRAME: f32 = 12.0;
+
+/// Characters kept by [`RaidNameStyle::Truncated`]
+const COMPACT_NAME_CHARS: usize = 6;
+
+/// Size multiplier for critical-severity effects
+const CRITICAL_EFFECT_SCALE: f32 = 1.3;
+
+/// Border flash toggles per second on critical-severity effects
+const FLASH_TOGGLES_PER_SEC: f32 = 4.0;
 
 /// The complete raid frame overlay
 pub struct RaidOverlay {
@@ -482,8 +579,8 @@ pub struct RaidOverlay {
     /// Dirty flag - when true, the overlay needs to be re-rendered
     /// In rearrange mode, we skip rendering when this is false to save CPU
     needs_render: bool,
-    /// Last render timestamp for frame rate limiting
-    last_render: Instant,
+    /// Last render timestamp for frame rate limiting (`None` until the first render)
+    last_render: Option<Instant>,
     /// Pending registry actions to be sent to the service
     pending_registry_actions: Vec<RaidRegistryAction>,
 }
@@ -512,8 +609,8 @@ impl RaidOverlay {
             swap_state: SwapState::default(),
             config,
             overflow_count: 0,
-            needs_render: true,                            // Initial render needed
-            last_render: Instant::now() - RENDER_INTERVAL, // Allow immediate first render
+            needs_render: true, // Initial render needed
+            last_render: None,
             pending_registry_actions: Vec::new(),
         })
     }
@@ -523,11 +620,27 @@ impl RaidOverlay {
     // ─────────────────────────────────────────────────────────────────────────
 
     fn padding(&self) -> f32 {
-        self.frame.scaled(BASE_PADDING)
+        if self.config.compact {
+            self.frame.scaled(COMPACT_PADDING)
+        } else {
+            self.frame.scaled(BASE_PADDING)
+        }
     }
 
     fn gap(&self) -> f32 {
-        self.frame.scaled(BASE_GAP)
+        if self.config.compact {
+            self.frame.scaled(COMPACT_GAP)
+        } else {
+            self.frame.scaled(BASE_GAP)
+        }
+    }
+
+    fn min_frame_size(&self) -> f32 {
+        if self.config.compact {
+            COMPACT_MIN_FRAME
+        } else {
+            20.0
+        }
     }
 
     /// Calculate frame width based on container size and column count
@@ -540,7 +653,7 @@ impl RaidOverlay {
         // Available width = container - 2*padding - (cols-1)*gap
         // Frame width = available / cols
         let available = container_width - (2.0 * padding) - ((cols - 1.0) * gap);
-        (available / cols).max(20.0) // Minimum 20px width
+        (available / cols).max(self.min_frame_size())
     }
 
     /// Calculate frame height based on container size and row count
@@ -553,12 +666,16 @@ impl RaidOverlay {
         // Available height = container - 2*padding - (rows-1)*gap
         // Frame height = available / rows
         let available = container_height - (2.0 * padding) - ((rows - 1.0) * gap);
-        (available / rows).max(20.0) // Minimum 20px height
+        (available / rows).max(self.min_frame_size())
     }
 
     fn font_size(&self) -> f32 {
         // Scale font relative to frame height for readability
-        (self.frame_height() * 0.28).clamp(8.0, 16.0)
+        if self.config.compact {
+            (self.frame_height() * 0.4).clamp(7.0, 11.0)
+        } else {
+            (self.frame_height() * 0.28).clamp(8.0, 16.0)
+        }
     }
 
     // ─────────────────────────────────────────────────────────────────────────
@@ -727,9 +844,14 @@ impl RaidOverlay {
                 }
             }
             InteractionMode::Normal => {
-                // Render at 10 FPS for smooth effect timer countdowns
-                // Also render immediately if dirty (data update)
-                if !self.needs_render && now.duration_since(self.last_render) < RENDER_INTERVAL {
+                // Render at the configured overlay rate (10 FPS by default) for
+                // smooth effect timer countdowns without the CPU cost of redrawing
+                // every poll. Also render immediately if dirty (data update)
+                if !self.needs_render
+                    && self.last_render.is_some_and(|last| {
+                        now.duration_since(last) < crate::overlay_frame_interval()
+                    })
+                {
                     return;
                 }
             }
@@ -740,7 +862,7 @@ impl RaidOverlay {
 
         // Clear dirty flag and update last render time
         self.needs_render = false;
-        self.last_render = now;
+        self.last_render = Some(now);
 
         self.frame.begin_frame();
 
@@ -756,6 +878,7 @@ impl RaidOverlay {
                 let frame_data = self.frames[i].clone();
                 self.render_rearrange_overlay(&frame_data);
             }
+            self.render_drop_targets();
         }
 
         // Overflow indicator
@@ -804,7 +927,11 @@ impl RaidOverlay {
 
         // In move mode: render a placeholder effect on ALL frames so user can see positioning
         if self.interaction_mode == InteractionMode::Move {
-            self.render_placeholder_effect(x, y);
+            if self.config.compact {
+                self.render_placeholder_bar(x, y, w);
+            } else {
+                self.render_placeholder_effect(x, y);
+            }
             return;
         }
 
@@ -813,6 +940,16 @@ impl RaidOverlay {
             return;
         }
 
+        // HP fill sits behind the effects and name
+        if self.config.show_health_bars {
+            self.render_health_bar(raid_frame.hp_percent, x, y, w, h, corner_radius);
+        }
+
+        if self.config.compact {
+            self.render_compact_frame(raid_frame, x, y, w, h);
+            return;
+        }
+
         // Effect indicators (TOP-LEFT, to match SWTOR's debuff placement)
         let effect_size = self.render_effects(raid_frame, x, y);
 
@@ -822,6 +959,34 @@ impl RaidOverlay {
         }
     }
 
+    /// Render the HP fill (left) and missing health (right) across the frame
+    fn render_health_bar(&mut self, hp_percent: f32, x: f32, y: f32, w: f32, h: f32, radius: f32) {
+        let hp = hp_percent.clamp(0.0, 1.0);
+        self.frame
+            .fill_rounded_rect(x, y, w, h, radius, colors::health_deficit());
+
+        let fill_width = (w * hp).round();
+        if fill_width <= 0.0 {
+            return;
+        }
+        let fill = if hp > 0.6 {
+            colors::health_high()
+        } else if hp > 0.3 {
+            colors::health_medium()
+        } else {
+            colors::health_low()
+        };
+        // Keep the fill translucent so effects stay readable on top of it
+        let fill = Color::from_rgba8(
+            (fill.red() * 255.0) as u8,
+            (fill.green() * 255.0) as u8,
+            (fill.blue() * 255.0) as u8,
+            110,
+        );
+        self.frame
+            .fill_rounded_rect(x, y, fill_width, h, radius.min(fill_width / 2.0), fill);
+    }
+
     /// Render the role icon at bottom-left, below the effects row
     fn render_role_icon(&mut self, role: PlayerRole, x: f32, y: f32, h: f32, effect_size: f32) {
         let icon_size = (self.frame_height() * 0.3).clamp(10.0, 16.0);
@@ -914,9 +1079,119 @@ impl RaidOverlay {
         );
     }
 
+    /// Render a placeholder effect bar in move mode (compact layout)
+    fn render_placeholder_bar(&mut self, x: f32, y: f32, w: f32) {
+        let bar_height = self.compact_bar_height();
+        let bx = x + 2.0;
+        let by = y + self.font_size() + 4.0;
+        let bar_width = w - 4.0;
+
+        self.frame
+            .fill_rect(bx, by, bar_width, bar_height, colors::effect_icon_bg());
+        self.frame.stroke_rounded_rect_dashed(
+            bx,
+            by,
+            bar_width,
+            bar_height,
+            0.0,
+            1.0, // stroke width
+            colors::effect_icon_border(),
+            3.0, // dash length
+            2.0, // gap length
+        );
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Compact Layout
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Height of a compact effect bar, derived from the configured effect size
+    fn compact_bar_height(&self) -> f32 {
+        (self.config.effect_size() * 0.25).clamp(2.0, 6.0)
+    }
+
+    /// Render a compact frame: a short name (colored by role) with one thin
+    /// bar per effect underneath, each draining as the effect runs out
+    fn render_compact_frame(&mut self, raid_frame: &RaidFrame, x: f32, y: f32, w: f32, h: f32) {
+        let font_size = self.font_size();
+        let name = self.config.short_name(&raid_frame.name);
+        let name_color = match raid_frame.role {
+            PlayerRole::Tank if self.config.show_role_icons => colors::role_tank(),
+            PlayerRole::Healer if self.config.show_role_icons => colors::role_healer(),
+            _ => colors::white(),
+        };
+        // Note: draw_text y is baseline
+        let text_x = x + 2.0;
+        let text_y = y + font_size + 1.0;
+        self.frame.draw_text(
+            &name,
+            text_x + 1.0,
+            text_y + 1.0,
+            font_size,
+            colors::text_shadow(),
+        );
+        self.frame
+            .draw_text(&name, text_x, text_y, font_size, name_color);
+
+        let bar_height = self.compact_bar_height();
+        let bar_width = w - 4.0;
+        let spacing = 1.0;
+        let fill_opacity = self.config.effect_fill_opacity as u16;
+        let now = Instant::now();
+        let mut by = text_y + 3.0;
+
+        for effect in raid_frame
+            .effects
+            .iter()
+            .take(self.config.max_effects_per_frame as usize)
+        {
+            // Stop at the bottom edge rather than spilling into the next frame
+            if by + bar_height > y + h {
+                break;
+            }
+            let bx = x + 2.0;
+
+            self.frame
+                .fill_rect(bx, by, bar_width, bar_height, colors::effect_bar_bg());
+
+            let fill_width = (bar_width * effect.fill_percent()).round();
+            if fill_width > 0.0 {
+                let effect_alpha = (effect.color.alpha() * 255.0) as u16;
+                let fill_color = Color::from_rgba8(
+                    (effect.color.red() * 255.0) as u8,
+                    (effect.color.green() * 255.0) as u8,
+                    (effect.color.blue() * 255.0) as u8,
+                    ((effect_alpha * fill_opacity) / 255).min(255) as u8,
+                );
+                self.frame
+                    .fill_rect(bx, by, fill_width, bar_height, fill_color);
+            }
+
+            // Critical effects flash an outline around their bar
+            let flash_on = effect.expires_at.is_none_or(|exp| {
+                ((exp.saturating_duration_since(now).as_secs_f32() * FLASH_TOGGLES_PER_SEC) as u32)
+                    .is_multiple_of(2)
+            });
+            if effect.severity == EffectSeverity::Critical && flash_on {
+                self.frame.stroke_rounded_rect(
+                    bx,
+                    by,
+                    bar_width,
+                    bar_height,
+                    0.0,
+                    1.0,
+                    colors::effect_critical_border(),
+                );
+            }
+
+            by += bar_height + spacing;
+        }
+    }
+
     /// Render effect indicators on the LEFT side of the frame (matches SWTOR debuff placement)
     /// Effects with duration show a fill that depletes from bottom to top as time expires.
     /// When show_effect_icons is enabled, renders icons with wipedown effect instead of colored squares.
+    /// Critical effects are drawn enlarged with a flashing border.
     /// Returns the effect row height for layout calculations
     fn render_effects(&mut self, raid_frame: &RaidFrame, x: f32, y: f32) -> f32 {
         let max_effects = self.config.max_effects_per_frame as usize;
@@ -926,10 +1201,20 @@ impl RaidOverlay {
         let spacing = effect_size * 0.2;
         let corner_radius = 2.0;
         let border_width = 1.0;
+        let now = Instant::now();
+        let mut ex = x + 3.0;
+        let mut row_size = effect_size;
+
+        for effect in raid_frame.effects.iter().take(max_effects) {
+            let is_critical = effect.severity == EffectSeverity::Critical;
+            let size = if is_critical {
+                effect_size * CRITICAL_EFFECT_SCALE
+            } else {
+                effect_size
+            };
+            row_size = row_size.max(size);
 
-        for (i, effect) in raid_frame.effects.iter().take(max_effects).enumerate() {
             // LEFT side positioning, growing rightward
-            let ex = x + 3.0 + (i as f32 * (effect_size + spacing));
             let ey = y + vertical_offset;
 
             // Draw icon or colored square
@@ -937,7 +1222,7 @@ impl RaidOverlay {
                 if let Some(ref icon_arc) = effect.icon {
                     let (img_w, img_h, ref rgba) = **icon_arc;
                     self.frame
-                        .draw_image(rgba, img_w, img_h, ex, ey, effect_size, effect_size);
+                        .draw_image(rgba, img_w, img_h, ex, ey, size, size);
                     true
                 } else {
                     false
@@ -951,8 +1236,8 @@ impl RaidOverlay {
                 self.frame.fill_rounded_rect(
                     ex,
                     ey,
-                    effect_size,
-                    effect_size,
+                    size,
+                    size,
                     corner_radius,
                     colors::effect_bar_bg(),
                 );
@@ -963,8 +1248,8 @@ impl RaidOverlay {
                 if fill_percent > 0.0 {
                     // Fill depletes from bottom to top (remaining time shrinks upward)
                     // Use explicit bottom coordinate to avoid floating-point rounding issues
-                    let max_fill_height = effect_size - border_width * 2.0;
-                    let fill_bottom = ey + effect_size - border_width;
+                    let max_fill_height = size - border_width * 2.0;
+                    let fill_bottom = ey + size - border_width;
                     let fill_height = (max_fill_height * fill_percent).round();
                     let fill_y = fill_bottom - fill_height;
 
@@ -995,39 +1280,55 @@ impl RaidOverlay {
             // Wipedown overlay (works for both icon and colored square)
             // Shows remaining duration as darkened area from top
             let progress = effect.fill_percent();
-            let overlay_height = effect_size * (1.0 - progress);
+            let overlay_height = size * (1.0 - progress);
             if overlay_height > 1.0 {
                 self.frame.fill_rect(
                     ex,
                     ey,
-                    effect_size,
+                    size,
                     overlay_height,
                     Color::from_rgba8(0, 0, 0, 140),
                 );
             }
 
-            // Thin border outline for visibility
-            self.frame.stroke_rounded_rect(
-                ex,
-                ey,
-                effect_size,
-                effect_size,
-                corner_radius,
-                1.0,
-                colors::effect_bar_border(),
-            );
+            // Thin border outline for visibility; critical effects flash a thick one
+            let flash_on = effect.expires_at.is_none_or(|exp| {
+                ((exp.saturating_duration_since(now).as_secs_f32() * FLASH_TOGGLES_PER_SEC) as u32)
+                    .is_multiple_of(2)
+            });
+            if is_critical && flash_on {
+                self.frame.stroke_rounded_rect(
+                    ex,
+                    ey,
+                    size,
+                    size,
+                    corner_radius,
+                    2.0,
+                    colors::effect_critical_border(),
+                );
+            } else {
+                self.frame.stroke_rounded_rect(
+                    ex,
+                    ey,
+                    size,
+                    size,
+                    corner_radius,
+                    1.0,
+                    colors::effect_bar_border(),
+                );
+            }
 
             // Stack count if applicable (centered in the effect square)
             if effect.charges > 1 {
                 let count = format!("{}", effect.charges);
-                let stack_font = (effect_size * 0.55).max(8.0);
+                let stack_font = (size * 0.55).max(8.0);
 
                 // Measure text for proper centering
                 let (text_w, _) = self.frame.measure_text(&count, stack_font);
 
                 // Center horizontally, position in lower portion of square
-                let text_x = ex + (effect_size - text_w) / 2.0;
-                let text_y = ey + effect_size * 0.78;
+                let text_x = ex + (size - text_w) / 2.0;
+                let text_y = ey + size * 0.78;
 
                 // Draw shadow (subtle drop shadow for readability)
                 self.frame.draw_text(
@@ -1042,10 +1343,12 @@ impl RaidOverlay {
                 self.frame
                     .draw_text(&count, text_x, text_y, stack_font, colors::white());
             }
+
+            ex += size + spacing;
         }
 
         // Return effect row height for role icon positioning
-        effect_size + vertical_offset.max(3.0)
+        row_size + vertical_offset.max(3.0)
     }
 
     /// Render the clickable overlay for rearrange mode
@@ -1088,6 +1391,8 @@ impl RaidOverlay {
         let font_size = self.font_size() * 1.1;
         let text = if raid_frame.is_empty() {
             "Empty".to_string()
+        } else if self.config.compact {
+            self.config.short_name(&raid_frame.name)
         } else {
             truncate_name(&raid_frame.name, 12).to_string()
         };
@@ -1129,6 +1434,46 @@ impl RaidOverlay {
         }
     }
 
+    /// Highlight the dragged frame and the slot it would be dropped on
+    fn render_drop_targets(&mut self) {
+        if !self.swap_state.is_dragging() {
+            return;
+        }
+        let (Some(source), target) = (self.swap_state.drag_source, self.swap_state.drop_target)
+        else {
+            return;
+        };
+
+        // Dragged frame: selection outline
+        let (x, y, w, h) = self.slot_bounds(source);
+        let corner_radius = (h * 0.1).clamp(2.0, 6.0);
+        let selection = Color::from_rgba8(
+            self.config.selection_color[0],
+            self.config.selection_color[1],
+            self.config.selection_color[2],
+            self.config.selection_color[3],
+        );
+        self.frame
+            .stroke_rounded_rect(x, y, w, h, corner_radius, 2.0, selection);
+
+        // Drop target: tinted fill with a thick outline
+        let Some(target) = target else {
+            return;
+        };
+        let (x, y, w, h) = self.slot_bounds(target);
+        self.frame
+            .fill_rounded_rect(x, y, w, h, corner_radius, colors::raid_drop_target());
+        self.frame.stroke_rounded_rect(
+            x + 1.0,
+            y + 1.0,
+            w - 2.0,
+            h - 2.0,
+            corner_radius - 1.0,
+            3.0,
+            colors::raid_slot_text(),
+        );
+    }
+
     /// Render overflow indicator
     fn render_overflow_indicator(&mut self) {
         if self.overflow_count == 0 {
@@ -1147,9 +1492,9 @@ impl RaidOverlay {
     // Event Handling
     // ─────────────────────────────────────────────────────────────────────────
 
-    /// Handle a click in rearrange mode
+    /// Handle a button press in rearrange mode
     /// Instead of modifying local state, we queue actions for the registry.
-    fn handle_rearrange_click(&mut self, px: f32, py: f32) {
+    fn handle_rearrange_press(&mut self, px: f32, py: f32) {
         // Check clear buttons first - queue ClearSlot action
         for i in 0..self.frames.len() {
             let frame = &self.frames[i];
@@ -1162,23 +1507,36 @@ impl RaidOverlay {
             }
         }
 
-        // Then check slot selection for swapping
+        // Then start a drag; a release on the same slot is a click
         if let Some(slot) = self.hit_test(px, py) {
-            if let Some((a, b)) = self.swap_state.on_click(slot) {
-                // Queue swap action - registry will update, then data will flow back
-                self.pending_registry_actions
-                    .push(RaidRegistryAction::SwapSlots(a, b));
-                self.needs_render = true;
-            } else {
-                // Selection changed (first click or deselect same slot)
-                self.needs_render = true;
-            }
+            self.swap_state.on_press(slot);
         } else {
             // Clicked outside any slot - deselect
             self.swap_state.cancel();
+        }
+        self.needs_render = true;
+    }
+
+    /// Track the drop target while a frame is dragged
+    fn handle_rearrange_drag(&mut self) {
+        let (px, py) = self.frame.pointer_position();
+        let slot = self.hit_test(px, py);
+        if self.swap_state.on_drag_over(slot) {
             self.needs_render = true;
         }
     }
+
+    /// Handle the button release that ends a click or drag in rearrange mode
+    fn handle_rearrange_release(&mut self, px: f32, py: f32) {
+        let slot = self.hit_test(px, py);
+        if let Some((a, b)) = self.swap_state.on_release(slot) {
+            // Queue swap action - registry will update, then data will flow back
+            self.pending_registry_actions
+                .push(RaidRegistryAction::SwapSlots(a, b));
+        }
+        // Selection or drop target changed either way
+        self.needs_render = true;
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1191,8 +1549,17 @@ impl Overlay for RaidOverlay {
             // Skip render if both old and new have no players with effects
             let old_has_effects = self.frames.iter().any(|f| !f.effects.is_empty());
             let new_has_effects = raid_data.frames.iter().any(|f| !f.effects.is_empty());
-            let skip_render =
-                !old_has_effects && !new_has_effects && self.frames.len() == raid_data.frames.len();
+            // HP bars need a redraw whenever a player's health moves
+            let hp_changed = self.config.show_health_bars
+                && raid_data.frames.iter().any(|f| {
+                    self.frames
+                        .get(f.slot as usize)
+                        .is_none_or(|old| old.hp_percent != f.hp_percent)
+                });
+            let skip_render = !old_has_effects
+                && !new_has_effects
+                && !hp_changed
+                && self.frames.len() == raid_data.frames.len();
             self.set_frames(raid_data.frames);
             !skip_render
         } else {
@@ -1222,11 +1589,18 @@ impl Overlay for RaidOverlay {
             self.needs_render = true;
         }
 
-        // Handle clicks in rearrange mode (platform reports clicks when drag is disabled)
-        if self.interaction_mode == InteractionMode::Rearrange
-            && let Some((px, py)) = self.frame.take_pending_click()
-        {
-            self.handle_rearrange_click(px, py);
+        // Handle presses and releases in rearrange mode (platform reports them
+        // when drag is disabled)
+        if self.interaction_mode == InteractionMode::Rearrange {
+            if let Some((px, py)) = self.frame.take_pending_click() {
+                self.handle_rearrange_press(px, py);
+            }
+            if self.swap_state.drag_source.is_some() {
+                self.handle_rearrange_drag();
+            }
+            if let Some((px, py)) = self.frame.take_pending_release() {
+                self.handle_rearrange_release(px, py);
+            }
         }
 
         true
@@ -1258,6 +1632,10 @@ impl Overlay for RaidOverlay {
         self.set_interaction_mode(new_mode);
     }
 
+    fn set_keyboard_mode(&mut self, _enabled: bool) {
+        // Raid frames keep their own click-to-select and rearrange modes
+    }
+
     fn take_pending_registry_actions(&mut self) -> Vec<RaidRegistryAction> {
         std::mem::take(&mut self.pending_registry_actions)
     }
@@ -0,0 +1,261 @@
+//! Raid Cooldown Overlay
+//!
+//! Grid of every player's defensive and raid utility cooldowns: one row per
+//! player, one cell per cooldown, colored by whether it is ready or still
+//! counting down.
+
+use baras_core::context::RaidCooldownConfig;
+use baras_core::game_data::CooldownKind;
+
+use super::{Overlay, OverlayConfigUpdate, OverlayData};
+use crate::frame::OverlayFrame;
+use crate::platform::{OverlayConfig, PlatformError};
+use crate::utils::color_from_rgba;
+
+/// One cooldown of one player
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaidCooldownCell {
+    pub label: String,
+    pub kind: CooldownKind,
+    /// Whole seconds until ready (0 = ready)
+    pub remaining_secs: u16,
+}
+
+/// Cooldowns of one player
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaidCooldownRow {
+    pub name: String,
+    pub cooldowns: Vec<RaidCooldownCell>,
+}
+
+/// Data sent from service to raid cooldown overlay
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RaidCooldownsData {
+    /// Players who have used a tracked cooldown, in the order first seen
+    pub players: Vec<RaidCooldownRow>,
+}
+
+/// Base dimensions for scaling calculations
+const BASE_WIDTH: f32 = 320.0;
+const BASE_HEIGHT: f32 = 160.0;
+
+/// Base layout values (at BASE_WIDTH x BASE_HEIGHT)
+const BASE_ROW_HEIGHT: f32 = 18.0;
+const BASE_ROW_SPACING: f32 = 3.0;
+const BASE_CELL_SPACING: f32 = 3.0;
+const BASE_NAME_WIDTH: f32 = 80.0;
+const BASE_PADDING: f32 = 6.0;
+const BASE_FONT_SIZE: f32 = 11.0;
+
+/// Cell text: the label, followed by the countdown while on cooldown
+fn cell_text(cell: &RaidCooldownCell) -> String {
+    let secs = cell.remaining_secs;
+    match secs {
+        0 => cell.label.clone(),
+        1..60 => format!("{} {}", cell.label, secs),
+        _ => format!("{} {}:{:02}", cell.label, secs / 60, secs % 60),
+    }
+}
+
+/// Raid cooldown overlay
+pub struct RaidCooldownsOverlay {
+    frame: OverlayFrame,
+    config: RaidCooldownConfig,
+    data: RaidCooldownsData,
+}
+
+impl RaidCooldownsOverlay {
+    /// Create a new raid cooldown overlay
+    pub fn new(
+        window_config: OverlayConfig,
+        config: RaidCooldownConfig,
+        background_alpha: u8,
+    ) -> Result<Self, PlatformError> {
+        let mut frame = OverlayFrame::new(window_config, BASE_WIDTH, BASE_HEIGHT)?;
+        frame.set_background_alpha(background_alpha);
+        frame.set_label("Raid Cooldowns");
+
+        Ok(Self {
+            frame,
+            config,
+            data: RaidCooldownsData::default(),
+        })
+    }
+
+    /// Update the config
+    pub fn set_config(&mut self, config: RaidCooldownConfig) {
+        self.config = config;
+    }
+
+    /// Update background alpha
+    pub fn set_background_alpha(&mut self, alpha: u8) {
+        self.frame.set_background_alpha(alpha);
+    }
+
+    /// Update the data
+    pub fn set_data(&mut self, data: RaidCooldownsData) {
+        self.data = data;
+    }
+
+    fn shows(&self, kind: CooldownKind) -> bool {
+        match kind {
+            CooldownKind::Defensive => self.config.show_defensives,
+            CooldownKind::RaidUtility => self.config.show_raid_utility,
+        }
+    }
+
+    /// Font size at which `text` fits in `max_width`
+    fn fit_font(&mut self, text: &str, font_size: f32, max_width: f32) -> f32 {
+        let (text_width, _) = self.frame.measure_text(text, font_size);
+        if text_width > max_width && text_width > 0.0 {
+            font_size * max_width / text_width
+        } else {
+            font_size
+        }
+    }
+
+    /// Render the overlay
+    pub fn render(&mut self) {
+        let width = self.frame.width() as f32;
+
+        self.frame.begin_frame();
+
+        // Rows with at least one cooldown of a shown kind
+        let rows: Vec<(String, Vec<RaidCooldownCell>)> = self
+            .data
+            .players
+            .iter()
+            .map(|row| {
+                let cells = row
+                    .cooldowns
+                    .iter()
+                    .filter(|cell| self.shows(cell.kind))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (row.name.clone(), cells)
+            })
+            .filter(|(_, cells)| !cells.is_empty())
+            .collect();
+
+        let Some(max_cells) = rows.iter().map(|(_, cells)| cells.len()).max() else {
+            self.frame.end_frame();
+            return;
+        };
+
+        let padding = self.frame.scaled(BASE_PADDING);
+        let row_height = self.frame.scaled(BASE_ROW_HEIGHT);
+        let row_spacing = self.frame.scaled(BASE_ROW_SPACING);
+        let cell_spacing = self.frame.scaled(BASE_CELL_SPACING);
+        let name_width = self.frame.scaled(BASE_NAME_WIDTH);
+        let font_size = self.frame.scaled(BASE_FONT_SIZE);
+        let cell_radius = 3.0 * self.frame.scale_factor();
+        let text_padding = 3.0 * self.frame.scale_factor();
+
+        let grid_width = width - padding * 2.0 - name_width - cell_spacing;
+        let cell_width =
+            ((grid_width - cell_spacing * (max_cells - 1) as f32) / max_cells as f32).max(1.0);
+
+        let font_color = color_from_rgba(self.config.font_color);
+        let ready_color = color_from_rgba(self.config.ready_color);
+        let cooldown_color = color_from_rgba(self.config.cooldown_color);
+
+        let mut y = padding;
+        for (name, cells) in &rows {
+            let name_font = self.fit_font(name, font_size, name_width);
+            let (_, text_height) = self.frame.measure_text(name, name_font);
+            self.frame.draw_text(
+                name,
+                padding,
+                y + (row_height + text_height) / 2.0,
+                name_font,
+                font_color,
+            );
+
+            let mut x = padding + name_width + cell_spacing;
+            for cell in cells {
+                let fill = if cell.remaining_secs == 0 {
+                    ready_color
+                } else {
+                    cooldown_color
+                };
+                self.frame
+                    .fill_rounded_rect(x, y, cell_width, row_height, cell_radius, fill);
+
+                let text = cell_text(cell);
+                let cell_font = self.fit_font(&text, font_size, cell_width - text_padding * 2.0);
+                let (_, text_height) = self.frame.measure_text(&text, cell_font);
+                self.frame.draw_text(
+                    &text,
+                    x + text_padding,
+                    y + (row_height + text_height) / 2.0,
+                    cell_font,
+                    font_color,
+                );
+                x += cell_width + cell_spacing;
+            }
+            y += row_height + row_spacing;
+        }
+
+        self.frame.end_frame();
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Overlay Trait Implementation
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl Overlay for RaidCooldownsOverlay {
+    fn update_data(&mut self, data: OverlayData) -> bool {
+        if let OverlayData::RaidCooldowns(cooldown_data) = data {
+            if cooldown_data == self.data {
+                return false;
+            }
+            self.set_data(cooldown_data);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update_config(&mut self, config: OverlayConfigUpdate) {
+        if let OverlayConfigUpdate::RaidCooldowns(cooldown_config, alpha) = config {
+            self.set_config(cooldown_config);
+            self.set_background_alpha(alpha);
+        }
+    }
+
+    fn render(&mut self) {
+        RaidCooldownsOverlay::render(self);
+    }
+
+    fn poll_events(&mut self) -> bool {
+        self.frame.poll_events()
+    }
+
+    fn frame(&self) -> &OverlayFrame {
+        &self.frame
+    }
+
+    fn frame_mut(&mut self) -> &mut OverlayFrame {
+        &mut self.frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cell_text_shows_countdown_while_on_cooldown() {
+        let mut cell = RaidCooldownCell {
+            label: "Saber Ward".to_string(),
+            kind: CooldownKind::Defensive,
+            remaining_secs: 0,
+        };
+        assert_eq!(cell_text(&cell), "Saber Ward");
+        cell.remaining_secs = 42;
+        assert_eq!(cell_text(&cell), "Saber Ward 42");
+        cell.remaining_secs = 95;
+        assert_eq!(cell_text(&cell), "Saber Ward 1:35");
+    }
+}
@@ -0,0 +1,202 @@
+//! Tank Swap Overlay
+//!
+//! Shows the tank-swap debuff stacks on both tanks and flashes once a tank
+//! reaches the boss's swap threshold.
+
+use std::time::Instant;
+
+use baras_core::context::TankSwapConfig;
+
+use super::{Overlay, OverlayConfigUpdate, OverlayData};
+use crate::frame::OverlayFrame;
+use crate::platform::{OverlayConfig, PlatformError};
+use crate::utils::color_from_rgba;
+use crate::widgets::ProgressBar;
+use crate::widgets::colors;
+
+/// Debuff stacks on one tank
+#[derive(Debug, Clone, PartialEq)]
+pub struct TankSwapEntry {
+    pub name: String,
+    pub stacks: u8,
+}
+
+/// Data sent from service to tank swap overlay
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TankSwapData {
+    /// Tanks carrying the debuff (at most two)
+    pub tanks: Vec<TankSwapEntry>,
+    /// Stack count calling for a swap
+    pub swap_at_stacks: u8,
+}
+
+impl TankSwapData {
+    /// Whether a tank has reached the swap threshold
+    pub fn swap_due(&self) -> bool {
+        self.swap_at_stacks > 0 && self.tanks.iter().any(|t| t.stacks >= self.swap_at_stacks)
+    }
+}
+
+/// Base dimensions for scaling calculations
+const BASE_WIDTH: f32 = 200.0;
+const BASE_HEIGHT: f32 = 60.0;
+
+/// Base layout values (at BASE_WIDTH x BASE_HEIGHT)
+const BASE_BAR_HEIGHT: f32 = 18.0;
+const BASE_ENTRY_SPACING: f32 = 4.0;
+const BASE_PADDING: f32 = 6.0;
+const BASE_FONT_SIZE: f32 = 11.0;
+
+/// Flash toggles per second while a swap is due
+const FLASH_TOGGLES_PER_SEC: f32 = 4.0;
+
+/// Tank swap overlay
+pub struct TankSwapOverlay {
+    frame: OverlayFrame,
+    config: TankSwapConfig,
+    data: TankSwapData,
+    /// When the current swap became due (drives the flash)
+    swap_due_since: Option<Instant>,
+}
+
+impl TankSwapOverlay {
+    /// Create a new tank swap overlay
+    pub fn new(
+        window_config: OverlayConfig,
+        config: TankSwapConfig,
+        background_alpha: u8,
+    ) -> Result<Self, PlatformError> {
+        let mut frame = OverlayFrame::new(window_config, BASE_WIDTH, BASE_HEIGHT)?;
+        frame.set_background_alpha(background_alpha);
+        frame.set_label("Tank Swap");
+
+        Ok(Self {
+            frame,
+            config,
+            data: TankSwapData::default(),
+            swap_due_since: None,
+        })
+    }
+
+    /// Update the config
+    pub fn set_config(&mut self, config: TankSwapConfig) {
+        self.config = config;
+    }
+
+    /// Update background alpha
+    pub fn set_background_alpha(&mut self, alpha: u8) {
+        self.frame.set_background_alpha(alpha);
+    }
+
+    /// Update the data
+    pub fn set_data(&mut self, data: TankSwapData) {
+        self.swap_due_since = match (data.swap_due(), self.swap_due_since) {
+            (true, None) => Some(Instant::now()),
+            (true, since) => since,
+            (false, _) => None,
+        };
+        self.data = data;
+    }
+
+    fn flashing(&self) -> bool {
+        self.config.flash_on_swap && self.swap_due_since.is_some()
+    }
+
+    /// Render the overlay
+    pub fn render(&mut self) {
+        let width = self.frame.width() as f32;
+
+        self.frame.begin_frame();
+
+        if self.data.tanks.is_empty() {
+            self.frame.end_frame();
+            return;
+        }
+
+        let padding = self.frame.scaled(BASE_PADDING);
+        let bar_height = self.frame.scaled(BASE_BAR_HEIGHT);
+        let entry_spacing = self.frame.scaled(BASE_ENTRY_SPACING);
+        let font_size = self.frame.scaled(BASE_FONT_SIZE);
+        let bar_radius = 3.0 * self.frame.scale_factor();
+        let content_width = width - padding * 2.0;
+
+        let stack_color = color_from_rgba(self.config.stack_color);
+        let font_color = color_from_rgba(self.config.font_color);
+        let swap_at = self.data.swap_at_stacks.max(1);
+
+        let flash_on = self.flashing()
+            && self.swap_due_since.is_some_and(|since| {
+                ((since.elapsed().as_secs_f32() * FLASH_TOGGLES_PER_SEC) as u32).is_multiple_of(2)
+            });
+
+        let mut y = padding;
+        for tank in &self.data.tanks {
+            let progress = (tank.stacks as f32 / swap_at as f32).min(1.0);
+            let due = tank.stacks >= swap_at;
+            ProgressBar::new(&tank.name, progress)
+                .with_fill_color(stack_color)
+                .with_bg_color(colors::dps_bar_bg())
+                .with_text_color(font_color)
+                .with_right_text(format!("{}/{}", tank.stacks, swap_at))
+                .with_highlight(due && flash_on)
+                .render(
+                    &mut self.frame,
+                    padding,
+                    y,
+                    content_width,
+                    bar_height,
+                    font_size,
+                    bar_radius,
+                );
+            y += bar_height + entry_spacing;
+        }
+
+        self.frame.end_frame();
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Overlay Trait Implementation
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl Overlay for TankSwapOverlay {
+    fn update_data(&mut self, data: OverlayData) -> bool {
+        if let OverlayData::TankSwap(tank_data) = data {
+            if tank_data == self.data {
+                return false;
+            }
+            self.set_data(tank_data);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update_config(&mut self, config: OverlayConfigUpdate) {
+        if let OverlayConfigUpdate::TankSwap(tank_config, alpha) = config {
+            self.set_config(tank_config);
+            self.set_background_alpha(alpha);
+        }
+    }
+
+    fn render(&mut self) {
+        TankSwapOverlay::render(self);
+    }
+
+    fn poll_events(&mut self) -> bool {
+        self.frame.poll_events()
+    }
+
+    fn frame(&self) -> &OverlayFrame {
+        &self.frame
+    }
+
+    fn frame_mut(&mut self) -> &mut OverlayFrame {
+        &mut self.frame
+    }
+
+    /// Keep rendering while a swap is due so the flash animates
+    fn needs_render(&self) -> bool {
+        self.flashing()
+    }
+}
@@ -2,12 +2,14 @@
 //!
 //! Displays countdown timers for boss mechanics, ability cooldowns, etc.
 
-use baras_core::context::TimerOverlayConfig;
+use std::sync::Arc;
+
+use baras_core::context::{TimerBarDirection, TimerOverlayConfig, TimerTextLayout};
 
 use super::{Overlay, OverlayConfigUpdate, OverlayData};
 use crate::frame::OverlayFrame;
 use crate::platform::{OverlayConfig, PlatformError};
-use crate::utils::color_from_rgba;
+use crate::utils::{color_from_rgba, format_number};
 use crate::widgets::{ProgressBar, colors};
 
 /// A single timer entry for display
@@ -21,9 +23,21 @@ pub struct TimerEntry {
     pub total_secs: f32,
     /// Bar color (RGBA)
     pub color: [u8; 4],
+    /// Suggested cooldown ability name ("use Saber Ward")
+    pub suggested_cooldown: Option<String>,
+    /// Pre-loaded icon of the suggested cooldown (width, height, rgba_bytes)
+    pub suggested_cooldown_icon: Option<Arc<(u32, u32, Vec<u8>)>>,
 }
 
 impl TimerEntry {
+    /// Bar label, with the suggested cooldown appended if any
+    pub fn label(&self) -> String {
+        match &self.suggested_cooldown {
+            Some(ability) => format!("{} - use {}", self.name, ability),
+            None => self.name.clone(),
+        }
+    }
+
     /// Progress as 0.0 (expired) to 1.0 (full)
     pub fn progress(&self) -> f32 {
         if self.total_secs <= 0.0 {
@@ -56,6 +70,10 @@ impl TimerEntry {
 pub struct TimerData {
     /// Current active timers
     pub entries: Vec<TimerEntry>,
+    /// Next HP-triggered phase and the estimated seconds until it starts
+    pub next_phase: Option<(String, f32)>,
+    /// Next raid-wide hit: timer name, seconds until it lands, learned damage per person
+    pub incoming_hit: Option<(String, f32, i64)>,
 }
 
 /// Base dimensions for scaling calculations
@@ -68,6 +86,9 @@ const BASE_ENTRY_SPACING: f32 = 4.0;
 const BASE_PADDING: f32 = 6.0;
 const BASE_FONT_SIZE: f32 = 11.0;
 
+/// Flash toggles per second while a timer is in its last `flash_last_secs`
+const FLASH_TOGGLES_PER_SEC: f32 = 4.0;
+
 /// Timer bar overlay
 pub struct TimerOverlay {
     frame: OverlayFrame,
@@ -132,7 +153,13 @@ impl TimerOverlay {
 
         // Nothing to render if no timers
         let max_display = self.config.max_display as usize;
-        if self.data.entries.is_empty() {
+        let incoming_hit = self
+            .data
+            .incoming_hit
+            .as_ref()
+            .filter(|_| self.config.show_incoming_hit);
+        if self.data.entries.is_empty() && self.data.next_phase.is_none() && incoming_hit.is_none()
+        {
             self.frame.end_frame();
             return;
         }
@@ -142,21 +169,76 @@ impl TimerOverlay {
 
         let mut y = padding;
 
+        // Upcoming phase transition above the bars
+        if let Some((ref phase, secs)) = self.data.next_phase {
+            let text = format!("→ {} in ~{:.0}s", phase, secs);
+            self.frame
+                .draw_text(&text, padding, y + font_size, font_size, font_color);
+            y += font_size + entry_spacing;
+        }
+
+        // Next raid-wide hit, e.g. "Terminate in 6s, ~40K/person"
+        if let Some((name, secs, per_person)) = incoming_hit {
+            let text = format!(
+                "{} in {:.0}s, ~{}/person",
+                name,
+                secs,
+                format_number(*per_person)
+            );
+            self.frame
+                .draw_text(&text, padding, y + font_size, font_size, font_color);
+            y += font_size + entry_spacing;
+        }
+
+        let flash_secs = self.config.flash_last_secs;
+
         for entry in self.data.entries.iter().take(max_display) {
             let bar_color = color_from_rgba(entry.color);
             let time_text = entry.format_time();
 
-            // Draw timer bar with name on left, time on right
-            ProgressBar::new(&entry.name, entry.progress())
-                .with_fill_color(bar_color)
+            let progress = match self.config.bar_direction {
+                TimerBarDirection::Drain => entry.progress(),
+                TimerBarDirection::Fill => 1.0 - entry.progress(),
+            };
+            let flashing = flash_secs > 0.0
+                && entry.remaining_secs <= flash_secs
+                && ((entry.remaining_secs * FLASH_TOGGLES_PER_SEC) as u32).is_multiple_of(2);
+
+            // Suggested cooldown icon sits right of the bar
+            let mut bar_width = content_width;
+            if let Some(ref icon) = entry.suggested_cooldown_icon {
+                let (img_w, img_h, ref rgba) = **icon;
+                bar_width -= bar_height + entry_spacing;
+                self.frame.draw_image(
+                    rgba,
+                    img_w,
+                    img_h,
+                    padding + bar_width + entry_spacing,
+                    y,
+                    bar_height,
+                    bar_height,
+                );
+            }
+
+            let bar = match self.config.text_layout {
+                // Name on left, time on right
+                TimerTextLayout::Split => {
+                    ProgressBar::new(entry.label(), progress).with_right_text(time_text)
+                }
+                TimerTextLayout::Centered => {
+                    ProgressBar::new(format!("{} {}", entry.label(), time_text), progress)
+                        .with_centered_label()
+                }
+            };
+            bar.with_fill_color(bar_color)
                 .with_bg_color(colors::dps_bar_bg())
                 .with_text_color(font_color)
-                .with_right_text(time_text)
+                .with_highlight(flashing)
                 .render(
                     &mut self.frame,
                     padding,
                     y,
-                    content_width,
+                    bar_width,
                     bar_height,
                     font_size,
                     bar_radius,
@@ -183,8 +265,12 @@ impl Overlay for TimerOverlay {
         };
         // Skip render only when transitioning empty → empty
         // Active timers need every frame for smooth bar animation
-        let was_empty = self.data.entries.is_empty();
-        let is_empty = timer_data.entries.is_empty();
+        let was_empty = self.data.entries.is_empty()
+            && self.data.next_phase.is_none()
+            && self.data.incoming_hit.is_none();
+        let is_empty = timer_data.entries.is_empty()
+            && timer_data.next_phase.is_none()
+            && timer_data.incoming_hit.is_none();
         self.set_data(timer_data);
         !(was_empty && is_empty)
     }
@@ -3,8 +3,10 @@
 //! Uses objc2-app-kit for transparent, always-on-top overlay windows
 //! with click-through support.
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
 use std::ffi::c_void;
+use std::sync::OnceLock;
 
 // objc2 core
 use objc2::rc::Retained;
@@ -24,8 +26,13 @@ use core_graphics::base::{kCGBitmapByteOrder32Little, kCGImageAlphaPremultiplied
 use core_graphics::color_space::CGColorSpace;
 use core_graphics::context::CGContext;
 
+use super::{
+    DisplayChangeCallback, MonitorInfo, OverlayConfig, OverlayKey, OverlayPlatform, PlatformError,
+};
 use super::{MAX_OVERLAY_HEIGHT, MAX_OVERLAY_WIDTH, MIN_OVERLAY_SIZE, RESIZE_CORNER_SIZE};
-use super::{MonitorInfo, OverlayConfig, OverlayPlatform, PlatformError};
+
+/// Precise (trackpad) scrolling distance treated as one wheel notch, in points
+const PRECISE_SCROLL_NOTCH: f64 = 10.0;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Standalone Monitor Enumeration
@@ -64,6 +71,46 @@ pub fn get_all_monitors() -> Vec<MonitorInfo> {
         .collect()
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Display Change Notifications
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// kCGDisplayBeginConfigurationFlag: sent before the change, ignored
+const DISPLAY_BEGIN_CONFIGURATION: u32 = 1;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGDisplayRegisterReconfigurationCallback(
+        callback: extern "C" fn(display: u32, flags: u32, user_info: *mut c_void),
+        user_info: *mut c_void,
+    ) -> i32;
+}
+
+/// Callback of the display watcher (one watcher per process)
+static DISPLAY_CHANGE_CALLBACK: OnceLock<DisplayChangeCallback> = OnceLock::new();
+
+extern "C" fn display_reconfigured(_display: u32, flags: u32, _user_info: *mut c_void) {
+    if flags & DISPLAY_BEGIN_CONFIGURATION != 0 {
+        return;
+    }
+    if let Some(on_change) = DISPLAY_CHANGE_CALLBACK.get() {
+        on_change();
+    }
+}
+
+/// Watch for monitors being added, removed or rearranged. Quartz delivers the
+/// reconfiguration callbacks on the main run loop.
+pub fn watch_display_changes(on_change: DisplayChangeCallback) -> bool {
+    if DISPLAY_CHANGE_CALLBACK.set(on_change).is_err() {
+        return false;
+    }
+    // SAFETY: the callback is a plain function with no user data
+    let err = unsafe {
+        CGDisplayRegisterReconfigurationCallback(display_reconfigured, std::ptr::null_mut())
+    };
+    err == 0
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Custom NSView for rendering
 // ─────────────────────────────────────────────────────────────────────────────
@@ -84,6 +131,10 @@ pub struct BarasOverlayViewIvars {
     // Global mouse location for drag calculations
     global_mouse_x: Cell<f64>,
     global_mouse_y: Cell<f64>,
+    // Virtual key codes pressed while the window is key
+    key_codes: RefCell<Vec<u16>>,
+    // Vertical scrolling since the last poll, in wheel notches (positive = up)
+    scroll_notches: Cell<f64>,
 }
 
 // SAFETY: BarasOverlayView is only used on the main thread (AppKit requirement)
@@ -185,9 +236,13 @@ define_class!(
 
         /// Handle mouse up events
         #[unsafe(method(mouseUp:))]
-        fn mouse_up(&self, _event: &NSEvent) {
+        fn mouse_up(&self, event: &NSEvent) {
             let ivars = self.ivars();
+            let loc = event.locationInWindow();
+
             ivars.mouse_up.set(true);
+            ivars.mouse_x.set(loc.x);
+            ivars.mouse_y.set(loc.y);
         }
 
         /// Handle mouse dragged events
@@ -214,6 +269,25 @@ define_class!(
             ivars.mouse_y.set(loc.y);
         }
 
+        /// Handle scroll wheel and trackpad scrolling
+        #[unsafe(method(scrollWheel:))]
+        fn scroll_wheel(&self, event: &NSEvent) {
+            // Trackpads report precise deltas in points rather than wheel lines
+            let delta = if event.hasPreciseScrollingDeltas() {
+                event.scrollingDeltaY() / PRECISE_SCROLL_NOTCH
+            } else {
+                event.scrollingDeltaY()
+            };
+            let ivars = self.ivars();
+            ivars.scroll_notches.set(ivars.scroll_notches.get() + delta);
+        }
+
+        /// Handle key down events (only delivered while the window is key)
+        #[unsafe(method(keyDown:))]
+        fn key_down(&self, event: &NSEvent) {
+            self.ivars().key_codes.borrow_mut().push(event.keyCode());
+        }
+
         /// Allow the view to be first responder to receive key/mouse events
         #[unsafe(method(acceptsFirstResponder))]
         fn accepts_first_responder(&self) -> bool {
@@ -287,6 +361,79 @@ impl BarasOverlayView {
         let ivars = self.ivars();
         (ivars.mouse_x.get(), ivars.mouse_y.get())
     }
+
+    /// Take key codes pressed since the last call
+    fn take_key_codes(&self) -> Vec<u16> {
+        self.ivars().key_codes.take()
+    }
+
+    /// Take scrolling since the last call, in wheel notches (positive = up)
+    fn take_scroll(&self) -> f64 {
+        self.ivars().scroll_notches.take()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Custom NSWindow for keyboard focus
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Instance variables for BarasOverlayWindow.
+#[derive(Default)]
+pub struct BarasOverlayWindowIvars {
+    can_become_key: Cell<bool>,
+}
+
+define_class!(
+    // SAFETY: NSWindow permits subclassing. We only override canBecomeKeyWindow,
+    // which is designed to be overridden (borderless windows return NO).
+    #[unsafe(super(NSWindow))]
+    #[thread_kind = MainThreadOnly]
+    #[name = "BarasOverlayWindow"]
+    #[ivars = BarasOverlayWindowIvars]
+    pub struct BarasOverlayWindow;
+
+    impl BarasOverlayWindow {
+        /// Let the window become key (and receive key events) in keyboard mode
+        #[unsafe(method(canBecomeKeyWindow))]
+        fn can_become_key_window(&self) -> bool {
+            self.ivars().can_become_key.get()
+        }
+    }
+);
+
+impl BarasOverlayWindow {
+    /// Create a borderless window with the given content rect.
+    fn new(rect: NSRect, mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm);
+        let ivars = BarasOverlayWindowIvars::default();
+        // SAFETY: Calling NSWindow's designated initializer
+        unsafe {
+            msg_send![
+                super(this.set_ivars(ivars)),
+                initWithContentRect: rect,
+                styleMask: NSWindowStyleMask::Borderless,
+                backing: NSBackingStoreType::Buffered,
+                defer: false
+            ]
+        }
+    }
+
+    fn set_can_become_key(&self, enabled: bool) {
+        self.ivars().can_become_key.set(enabled);
+    }
+}
+
+/// Map a macOS virtual key code
+fn overlay_key(key_code: u16) -> Option<OverlayKey> {
+    match key_code {
+        126 => Some(OverlayKey::Up),
+        125 => Some(OverlayKey::Down),
+        123 => Some(OverlayKey::Left),
+        124 => Some(OverlayKey::Right),
+        36 | 76 => Some(OverlayKey::Enter), // Return, keypad Enter
+        53 => Some(OverlayKey::Escape),
+        _ => None,
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -294,7 +441,7 @@ impl BarasOverlayView {
 // ─────────────────────────────────────────────────────────────────────────────
 
 pub struct MacOSOverlay {
-    window: Retained<NSWindow>,
+    window: Retained<BarasOverlayWindow>,
     view: Retained<BarasOverlayView>,
     width: u32,
     height: u32,
@@ -311,6 +458,12 @@ pub struct MacOSOverlay {
     in_resize_corner: bool,
     position_dirty: bool,
     pending_click: Option<(f32, f32)>,
+    pending_release: Option<(f32, f32)>,
+    /// Wheel notches not yet taken, and the remainder of trackpad scrolling
+    pending_scroll: i32,
+    scroll_accum: f64,
+    keyboard_input: bool,
+    pending_keys: VecDeque<OverlayKey>,
 
     // Drag tracking
     drag_start_x: f64,
@@ -373,17 +526,8 @@ impl OverlayPlatform for MacOSOverlay {
                 NSSize::new(config.width as f64, config.height as f64),
             );
 
-            // Create borderless window using objc2-app-kit
-            let window: Retained<NSWindow> = {
-                let alloc = NSWindow::alloc(mtm);
-                NSWindow::initWithContentRect_styleMask_backing_defer(
-                    alloc,
-                    rect,
-                    NSWindowStyleMask::Borderless,
-                    NSBackingStoreType::Buffered,
-                    false,
-                )
-            };
+            // Create borderless window (subclassed so it can take keyboard focus)
+            let window = BarasOverlayWindow::new(rect, mtm);
 
             // CRITICAL: Prevent window from being released when closed (MAC-04)
             // This is required for correct memory management when not using a window controller
@@ -429,6 +573,11 @@ impl OverlayPlatform for MacOSOverlay {
                 in_resize_corner: false,
                 position_dirty: false,
                 pending_click: None,
+                pending_release: None,
+                pending_scroll: 0,
+                scroll_accum: 0.0,
+                keyboard_input: false,
+                pending_keys: VecDeque::new(),
                 drag_start_x: 0.0,
                 drag_start_y: 0.0,
                 drag_start_win_x: config.x,
@@ -554,6 +703,42 @@ impl OverlayPlatform for MacOSOverlay {
         self.pending_click.take()
     }
 
+    fn take_pending_release(&mut self) -> Option<(f32, f32)> {
+        self.pending_release.take()
+    }
+
+    fn take_pending_scroll(&mut self) -> i32 {
+        std::mem::take(&mut self.pending_scroll)
+    }
+
+    fn set_keyboard_input(&mut self, enabled: bool) {
+        self.keyboard_input = enabled;
+        self.window.set_can_become_key(enabled);
+        if !enabled {
+            self.pending_keys.clear();
+        }
+    }
+
+    fn take_pending_key(&mut self) -> Option<OverlayKey> {
+        self.pending_keys.pop_front()
+    }
+
+    fn pointer_position(&self) -> (f32, f32) {
+        // Convert from bottom-left to top-left within window
+        let (x, y) = self.view.mouse_position();
+        (x as f32, (self.height as f64 - y) as f32)
+    }
+
+    fn is_pointer_inside(&self) -> bool {
+        // Both in bottom-left screen coordinates
+        let mouse = NSEvent::mouseLocation();
+        let frame = self.window.frame();
+        mouse.x >= frame.origin.x
+            && mouse.x < frame.origin.x + frame.size.width
+            && mouse.y >= frame.origin.y
+            && mouse.y < frame.origin.y + frame.size.height
+    }
+
     fn in_resize_corner(&self) -> bool {
         self.in_resize_corner
     }
@@ -624,6 +809,9 @@ impl OverlayPlatform for MacOSOverlay {
 
             // Handle mouse up
             if self.view.take_mouse_up() {
+                if !self.drag_enabled {
+                    self.pending_release = Some(self.pointer_position());
+                }
                 self.is_dragging = false;
                 if self.is_resizing {
                     self.is_resizing = false;
@@ -667,6 +855,12 @@ impl OverlayPlatform for MacOSOverlay {
                 }
             }
 
+            // Scroll up is positive in AppKit; notches count down the list
+            self.scroll_accum += self.view.take_scroll();
+            let notches = self.scroll_accum.trunc();
+            self.scroll_accum -= notches;
+            self.pending_scroll -= notches as i32;
+
             // Update resize corner state from current mouse position
             let (mouse_x, mouse_y) = self.view.mouse_position();
             if mouse_x != 0.0 || mouse_y != 0.0 {
@@ -677,6 +871,13 @@ impl OverlayPlatform for MacOSOverlay {
             }
         }
 
+        // Key presses reach the view only while the window is key
+        let key_codes = self.view.take_key_codes();
+        if self.keyboard_input {
+            self.pending_keys
+                .extend(key_codes.into_iter().filter_map(overlay_key));
+        }
+
         self.running
     }
 
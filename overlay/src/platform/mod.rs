@@ -179,6 +179,33 @@ pub fn find_monitor_at(
     monitors.iter().find(|m| m.is_primary).or(monitors.first())
 }
 
+/// Keys overlays respond to in keyboard mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayKey {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Escape,
+}
+
+impl OverlayKey {
+    /// Map a Linux evdev key code (Wayland key events, X11 keycodes minus 8)
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub(crate) fn from_evdev(code: u32) -> Option<Self> {
+        match code {
+            103 => Some(Self::Up),
+            108 => Some(Self::Down),
+            105 => Some(Self::Left),
+            106 => Some(Self::Right),
+            28 | 96 => Some(Self::Enter), // Enter, keypad Enter
+            1 => Some(Self::Escape),
+            _ => None,
+        }
+    }
+}
+
 /// Configuration for creating an overlay window
 #[derive(Debug, Clone)]
 pub struct OverlayConfig {
@@ -290,6 +317,37 @@ pub trait OverlayPlatform: Sized {
     /// The click is consumed (subsequent calls return None until next click).
     fn take_pending_click(&mut self) -> Option<(f32, f32)>;
 
+    /// Take a pending button release position (if any)
+    ///
+    /// Reported alongside `take_pending_click()` when drag is disabled, so the
+    /// overlay can tell a press-drag-release apart from a click.
+    fn take_pending_release(&mut self) -> Option<(f32, f32)>;
+
+    /// Take the mouse wheel movement since the last call, in notches
+    /// (positive = down, toward later entries)
+    ///
+    /// Only reported while the overlay is interactive.
+    fn take_pending_scroll(&mut self) -> i32;
+
+    /// Last known pointer position within the overlay
+    fn pointer_position(&self) -> (f32, f32);
+
+    /// Check if the pointer is currently over the overlay
+    ///
+    /// Only meaningful while the overlay is interactive; click-through windows
+    /// don't see the pointer.
+    fn is_pointer_inside(&self) -> bool;
+
+    /// Enable or disable keyboard input (keyboard mode)
+    ///
+    /// While enabled the window may take keyboard focus when clicked, and
+    /// presses of the keys in [`OverlayKey`] are queued for `take_pending_key()`.
+    /// Disabling drops any queued keys.
+    fn set_keyboard_input(&mut self, enabled: bool);
+
+    /// Take the oldest key pressed while keyboard input was enabled
+    fn take_pending_key(&mut self) -> Option<OverlayKey>;
+
     /// Check if pointer is in the resize corner (for visual feedback)
     fn in_resize_corner(&self) -> bool;
 
@@ -387,6 +445,28 @@ pub fn get_all_monitors() -> Vec<MonitorInfo> {
     macos::get_all_monitors()
 }
 
+/// Called (from a platform watcher thread or the main run loop) when monitors
+/// are plugged in, removed or rearranged
+pub type DisplayChangeCallback = Box<dyn Fn() + Send + Sync + 'static>;
+
+/// Subscribe to the platform's display change events. Returns false if no
+/// watcher could be started, in which case callers should poll
+/// [`get_all_monitors`] instead. Only one watcher can run per process.
+#[cfg(all(unix, not(target_os = "macos")))]
+pub fn watch_display_changes(on_change: DisplayChangeCallback) -> bool {
+    linux::watch_display_changes(on_change)
+}
+
+#[cfg(target_os = "windows")]
+pub fn watch_display_changes(on_change: DisplayChangeCallback) -> bool {
+    windows::watch_display_changes(on_change)
+}
+
+#[cfg(target_os = "macos")]
+pub fn watch_display_changes(on_change: DisplayChangeCallback) -> bool {
+    macos::watch_display_changes(on_change)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Linux Runtime Detection (Wayland vs X11)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -409,6 +489,15 @@ mod linux {
         }
     }
 
+    /// Watch display changes using the appropriate backend
+    pub fn watch_display_changes(on_change: DisplayChangeCallback) -> bool {
+        if use_wayland() {
+            wayland::watch_display_changes(on_change)
+        } else {
+            x11::watch_display_changes(on_change)
+        }
+    }
+
     /// Linux overlay that wraps either Wayland or X11 backend
     pub enum LinuxOverlay {
         Wayland(wayland::WaylandOverlay),
@@ -501,6 +590,48 @@ mod linux {
             }
         }
 
+        fn take_pending_release(&mut self) -> Option<(f32, f32)> {
+            match self {
+                Self::Wayland(w) => w.take_pending_release(),
+                Self::X11(x) => x.take_pending_release(),
+            }
+        }
+
+        fn take_pending_scroll(&mut self) -> i32 {
+            match self {
+                Self::Wayland(w) => w.take_pending_scroll(),
+                Self::X11(x) => x.take_pending_scroll(),
+            }
+        }
+
+        fn pointer_position(&self) -> (f32, f32) {
+            match self {
+                Self::Wayland(w) => w.pointer_position(),
+                Self::X11(x) => x.pointer_position(),
+            }
+        }
+
+        fn is_pointer_inside(&self) -> bool {
+            match self {
+                Self::Wayland(w) => w.is_pointer_inside(),
+                Self::X11(x) => x.is_pointer_inside(),
+            }
+        }
+
+        fn set_keyboard_input(&mut self, enabled: bool) {
+            match self {
+                Self::Wayland(w) => w.set_keyboard_input(enabled),
+                Self::X11(x) => x.set_keyboard_input(enabled),
+            }
+        }
+
+        fn take_pending_key(&mut self) -> Option<OverlayKey> {
+            match self {
+                Self::Wayland(w) => w.take_pending_key(),
+                Self::X11(x) => x.take_pending_key(),
+            }
+        }
+
         fn in_resize_corner(&self) -> bool {
             match self {
                 Self::Wayland(w) => w.in_resize_corner(),
@@ -589,3 +720,60 @@ pub fn resolve_absolute_position(
         (relative_x, relative_y)
     }
 }
+
+/// Fingerprint of a monitor arrangement, e.g. `1920x1080+0+0,2560x1440+1920+0`.
+/// Monitors are sorted by position so enumeration order does not matter, and
+/// IDs are left out since they can change when a dock reconnects.
+pub fn monitor_layout_fingerprint(monitors: &[MonitorInfo]) -> String {
+    let mut geometries: Vec<_> = monitors
+        .iter()
+        .map(|m| (m.x, m.y, m.width, m.height))
+        .collect();
+    geometries.sort_unstable();
+    geometries
+        .iter()
+        .map(|(x, y, w, h)| format!("{w}x{h}+{x}+{y}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(id: &str, x: i32, width: u32, height: u32) -> MonitorInfo {
+        MonitorInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            x,
+            y: 0,
+            width,
+            height,
+            is_primary: x == 0,
+        }
+    }
+
+    #[test]
+    fn layout_fingerprint_ignores_order_and_ids() {
+        let docked = [
+            monitor("DP-2", 1920, 2560, 1440),
+            monitor("eDP-1", 0, 1920, 1080),
+        ];
+        let redocked = [
+            monitor("eDP-1", 0, 1920, 1080),
+            monitor("DP-3", 1920, 2560, 1440),
+        ];
+        assert_eq!(
+            monitor_layout_fingerprint(&docked),
+            "1920x1080+0+0,2560x1440+1920+0"
+        );
+        assert_eq!(
+            monitor_layout_fingerprint(&docked),
+            monitor_layout_fingerprint(&redocked)
+        );
+        assert_ne!(
+            monitor_layout_fingerprint(&docked[1..]),
+            monitor_layout_fingerprint(&docked)
+        );
+    }
+}
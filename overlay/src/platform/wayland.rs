@@ -5,6 +5,7 @@
 //! Not GNOME though why are you trying to game on GNOME?
 #![allow(clippy::too_many_arguments)]
 
+use std::collections::VecDeque;
 use std::os::fd::AsFd;
 
 use rustix::fs::{MemfdFlags, memfd_create};
@@ -12,6 +13,7 @@ use rustix::mm::{MapFlags, ProtFlags, mmap};
 use wayland_client::globals::GlobalListContents;
 use wayland_client::protocol::wl_buffer::WlBuffer;
 use wayland_client::protocol::wl_compositor::WlCompositor;
+use wayland_client::protocol::wl_keyboard::{self, WlKeyboard};
 use wayland_client::protocol::wl_output::{self, WlOutput};
 use wayland_client::protocol::wl_pointer::{self, WlPointer};
 use wayland_client::protocol::wl_region::WlRegion;
@@ -34,8 +36,13 @@ use wayland_protocols_wlr::layer_shell::v1::client::{
     zwlr_layer_surface_v1::{self, Anchor, KeyboardInteractivity, ZwlrLayerSurfaceV1},
 };
 
+use super::{
+    DisplayChangeCallback, MonitorInfo, OverlayConfig, OverlayKey, OverlayPlatform, PlatformError,
+};
 use super::{MAX_OVERLAY_HEIGHT, MAX_OVERLAY_WIDTH, MIN_OVERLAY_SIZE, RESIZE_CORNER_SIZE};
-use super::{MonitorInfo, OverlayConfig, OverlayPlatform, PlatformError};
+
+/// Vertical axis distance of one mouse wheel notch (surface units)
+const WHEEL_NOTCH: f64 = 10.0;
 // ─────────────────────────────────────────────────────────────────────────────
 // Standalone Monitor Enumeration
 // ─────────────────────────────────────────────────────────────────────────────
@@ -272,6 +279,98 @@ pub fn get_all_monitors() -> Vec<MonitorInfo> {
         .collect()
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Display Change Notifications
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// State of the display watcher connection
+struct DisplayWatchState {
+    /// Global names of the bound outputs
+    outputs: Vec<u32>,
+    /// Set once the initial globals have been seen, so startup isn't a change
+    ready: bool,
+    changed: bool,
+}
+
+impl Dispatch<wl_registry::WlRegistry, ()> for DisplayWatchState {
+    fn event(
+        state: &mut Self,
+        registry: &wl_registry::WlRegistry,
+        event: wl_registry::Event,
+        _data: &(),
+        _conn: &Connection,
+        qh: &QueueHandle<Self>,
+    ) {
+        match event {
+            wl_registry::Event::Global {
+                name,
+                interface,
+                version,
+            } if interface == "wl_output" => {
+                let _: WlOutput = registry.bind(name, version.min(4), qh, name);
+                state.outputs.push(name);
+                state.changed |= state.ready;
+            }
+            wl_registry::Event::GlobalRemove { name } if state.outputs.contains(&name) => {
+                state.outputs.retain(|n| *n != name);
+                state.changed = true;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Dispatch<WlOutput, u32> for DisplayWatchState {
+    fn event(
+        state: &mut Self,
+        _output: &WlOutput,
+        event: wl_output::Event,
+        _name: &u32,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+        // Done closes every batch of output changes (mode, scale, position)
+        if matches!(event, wl_output::Event::Done) {
+            state.changed |= state.ready;
+        }
+    }
+}
+
+/// Watch for monitors being added, removed or reconfigured (wl_output globals
+/// on a connection of its own)
+pub fn watch_display_changes(on_change: DisplayChangeCallback) -> bool {
+    let Ok(connection) = Connection::connect_to_env() else {
+        return false;
+    };
+    let mut event_queue = connection.new_event_queue::<DisplayWatchState>();
+    let qh = event_queue.handle();
+    let _registry = connection.display().get_registry(&qh, ());
+
+    let mut state = DisplayWatchState {
+        outputs: Vec::new(),
+        ready: false,
+        changed: false,
+    };
+    // Initial globals, then the first output info batch
+    if event_queue.roundtrip(&mut state).is_err() || event_queue.roundtrip(&mut state).is_err() {
+        return false;
+    }
+    state.ready = true;
+
+    std::thread::Builder::new()
+        .name("display-watch".to_string())
+        .spawn(move || {
+            // Keep the connection alive for the lifetime of the watcher
+            let _connection = connection;
+            while event_queue.blocking_dispatch(&mut state).is_ok() {
+                if std::mem::take(&mut state.changed) {
+                    on_change();
+                }
+            }
+        })
+        .is_ok()
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Wayland Overlay Implementation
 // ─────────────────────────────────────────────────────────────────────────────
@@ -374,6 +473,7 @@ struct WaylandState {
     buffer: Option<WlBuffer>,
     seat: Option<WlSeat>,
     pointer: Option<WlPointer>,
+    keyboard: Option<WlKeyboard>,
     relative_pointer_manager: Option<ZwpRelativePointerManagerV1>,
     relative_pointer: Option<ZwpRelativePointerV1>,
 
@@ -394,6 +494,7 @@ struct WaylandState {
     // Drag/resize state
     pointer_x: f64,
     pointer_y: f64,
+    pointer_inside: bool,
     is_dragging: bool,
     is_resizing: bool,
     in_resize_corner: bool, // true when pointer is in resize corner (for visual feedback)
@@ -414,6 +515,12 @@ struct WaylandState {
     click_through: bool,
     drag_enabled: bool,
     pending_click: Option<(f32, f32)>,
+    pending_release: Option<(f32, f32)>,
+    /// Wheel notches not yet taken, and the remainder of smooth scrolling
+    pending_scroll: i32,
+    scroll_accum: f64,
+    keyboard_input: bool,
+    pending_keys: VecDeque<OverlayKey>,
 
     // Cross-monitor drag: pending rebind to a different output
     pending_output_rebind: Option<u32>, // Output name (global id) to rebind to
@@ -454,6 +561,7 @@ impl WaylandState {
             buffer: None,
             seat: None,
             pointer: None,
+            keyboard: None,
             relative_pointer_manager: None,
             relative_pointer: None,
             xdg_output_manager: None,
@@ -464,6 +572,7 @@ impl WaylandState {
             shm_data: None,
             pointer_x: 0.0,
             pointer_y: 0.0,
+            pointer_inside: false,
             is_dragging: false,
             is_resizing: false,
             in_resize_corner: false,
@@ -480,10 +589,29 @@ impl WaylandState {
             click_through,
             drag_enabled: true,
             pending_click: None,
+            pending_release: None,
+            pending_scroll: 0,
+            scroll_accum: 0.0,
+            keyboard_input: false,
+            pending_keys: VecDeque::new(),
             pending_output_rebind: None,
         }
     }
 
+    /// Keyboard interactivity for a layer surface. Focus on click
+    /// (`OnDemand`) needs layer-shell v4; older compositors only offer
+    /// exclusive focus.
+    fn keyboard_interactivity(&self, layer_surface: &ZwlrLayerSurfaceV1) -> KeyboardInteractivity {
+        use wayland_client::Proxy;
+        if !self.keyboard_input {
+            KeyboardInteractivity::None
+        } else if layer_surface.version() >= 4 {
+            KeyboardInteractivity::OnDemand
+        } else {
+            KeyboardInteractivity::Exclusive
+        }
+    }
+
     /// Clamp position to the bound output's local bounds.
     /// Layer-shell surfaces are bound to a specific output, so we clamp within that output.
     /// Coordinates are relative to the output's top-left corner (0,0 to width,height).
@@ -718,7 +846,7 @@ impl WaylandOverlay {
         // Configure the new layer surface
         layer_surface.set_anchor(Anchor::Top | Anchor::Left);
         layer_surface.set_margin(clamped_y, 0, 0, clamped_x);
-        layer_surface.set_keyboard_interactivity(KeyboardInteractivity::None);
+        layer_surface.set_keyboard_interactivity(self.state.keyboard_interactivity(&layer_surface));
         layer_surface.set_size(self.state.width, self.state.height);
         surface.commit();
 
@@ -1141,6 +1269,51 @@ impl OverlayPlatform for WaylandOverlay {
         self.state.pending_click.take()
     }
 
+    fn take_pending_release(&mut self) -> Option<(f32, f32)> {
+        self.state.pending_release.take()
+    }
+
+    fn take_pending_scroll(&mut self) -> i32 {
+        std::mem::take(&mut self.state.pending_scroll)
+    }
+
+    fn set_keyboard_input(&mut self, enabled: bool) {
+        self.state.keyboard_input = enabled;
+
+        if let (Some(layer_surface), Some(surface)) =
+            (&self.state.layer_surface, &self.state.surface)
+        {
+            layer_surface
+                .set_keyboard_interactivity(self.state.keyboard_interactivity(layer_surface));
+            surface.commit();
+        }
+
+        if enabled {
+            if self.state.keyboard.is_none()
+                && let Some(seat) = &self.state.seat
+            {
+                self.state.keyboard = Some(seat.get_keyboard(&self.qh, ()));
+            }
+        } else {
+            if let Some(keyboard) = self.state.keyboard.take() {
+                keyboard.release();
+            }
+            self.state.pending_keys.clear();
+        }
+    }
+
+    fn take_pending_key(&mut self) -> Option<OverlayKey> {
+        self.state.pending_keys.pop_front()
+    }
+
+    fn pointer_position(&self) -> (f32, f32) {
+        (self.state.pointer_x as f32, self.state.pointer_y as f32)
+    }
+
+    fn is_pointer_inside(&self) -> bool {
+        self.state.pointer_inside
+    }
+
     fn in_resize_corner(&self) -> bool {
         self.state.in_resize_corner
     }
@@ -1480,6 +1653,7 @@ impl Dispatch<WlPointer, ()> for WaylandState {
             } => {
                 state.pointer_x = surface_x;
                 state.pointer_y = surface_y;
+                state.pointer_inside = true;
                 // Check if in resize corner for visual feedback
                 state.in_resize_corner =
                     ResizeCorner::is_in_corner(surface_x, surface_y, state.width, state.height);
@@ -1563,6 +1737,11 @@ impl Dispatch<WlPointer, ()> for WaylandState {
                             }
                         }
                         WEnum::Value(wl_pointer::ButtonState::Released) => {
+                            if !state.drag_enabled {
+                                // Rearrange mode - report release for drag-and-drop
+                                state.pending_release =
+                                    Some((state.pointer_x as f32, state.pointer_y as f32));
+                            }
                             state.is_dragging = false;
                             state.is_resizing = false;
                             // Recalculate corner state based on current pointer position
@@ -1579,6 +1758,7 @@ impl Dispatch<WlPointer, ()> for WaylandState {
                 }
             }
             wl_pointer::Event::Leave { .. } => {
+                state.pointer_inside = false;
                 // Only reset corner state if not actively resizing
                 // (during resize, keep grip visible)
                 if !state.is_resizing {
@@ -1586,6 +1766,17 @@ impl Dispatch<WlPointer, ()> for WaylandState {
                 }
                 // Don't cancel drag/resize on leave - user might move fast
             }
+            wl_pointer::Event::Axis {
+                axis: wayland_client::WEnum::Value(wl_pointer::Axis::VerticalScroll),
+                value,
+                ..
+            } => {
+                // Touchpads scroll smoothly, so whole notches are accumulated
+                state.scroll_accum += value;
+                let notches = (state.scroll_accum / WHEEL_NOTCH).trunc();
+                state.scroll_accum -= notches * WHEEL_NOTCH;
+                state.pending_scroll += notches as i32;
+            }
             _ => {}
         }
     }
@@ -1593,6 +1784,31 @@ impl Dispatch<WlPointer, ()> for WaylandState {
 
 impl_empty_dispatch!(ZwpRelativePointerManagerV1, (), WaylandState);
 
+impl Dispatch<WlKeyboard, ()> for WaylandState {
+    fn event(
+        state: &mut Self,
+        _proxy: &WlKeyboard,
+        event: wl_keyboard::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+        use wayland_client::WEnum;
+        // Key codes are evdev codes; the keymap is not needed for the few
+        // navigation keys overlays respond to (its fd is closed on drop)
+        if let wl_keyboard::Event::Key {
+            key,
+            state: WEnum::Value(wl_keyboard::KeyState::Pressed),
+            ..
+        } = event
+            && state.keyboard_input
+            && let Some(key) = OverlayKey::from_evdev(key)
+        {
+            state.pending_keys.push_back(key);
+        }
+    }
+}
+
 impl Dispatch<ZwpRelativePointerV1, ()> for WaylandState {
     fn event(
         state: &mut Self,
@@ -11,8 +11,10 @@ macro_rules! overlay_log {
     };
 }
 
+use std::collections::VecDeque;
 use std::mem;
 use std::ptr;
+use std::sync::OnceLock;
 
 use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, POINT, WPARAM};
 use windows::Win32::Graphics::Gdi::{
@@ -21,22 +23,29 @@ use windows::Win32::Graphics::Gdi::{
     HMONITOR, MONITORINFOEXW, OBJ_BITMAP, ReleaseDC, SelectObject, SetDIBits,
 };
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
-use windows::Win32::UI::Input::KeyboardAndMouse::{ReleaseCapture, SetCapture};
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    ReleaseCapture, SetCapture, SetFocus, VK_DOWN, VK_ESCAPE, VK_LEFT, VK_RETURN, VK_RIGHT, VK_UP,
+};
 use windows::Win32::UI::WindowsAndMessaging::{
     CS_HREDRAW, CS_VREDRAW, CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW,
-    GWL_EXSTYLE, GetCursorPos, HTCLIENT, HWND_TOPMOST, IDC_ARROW, LoadCursorW, MSG, PM_REMOVE,
-    PeekMessageW, RegisterClassExW, SW_SHOWNOACTIVATE, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE,
-    SetWindowLongPtrW, SetWindowPos, ShowWindow, TranslateMessage, ULW_ALPHA, UpdateLayeredWindow,
-    WM_DESTROY, WM_ERASEBKGND, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MOUSEMOVE, WM_NCHITTEST, WM_QUIT,
-    WNDCLASSEXW, WS_EX_LAYERED, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST,
-    WS_EX_TRANSPARENT, WS_POPUP,
+    GWL_EXSTYLE, GetCursorPos, GetMessageW, HTCLIENT, HWND_TOPMOST, IDC_ARROW, LoadCursorW, MSG,
+    PM_REMOVE, PeekMessageW, RegisterClassExW, SW_SHOWNOACTIVATE, SWP_NOACTIVATE, SWP_NOMOVE,
+    SWP_NOSIZE, SetWindowLongPtrW, SetWindowPos, ShowWindow, TranslateMessage, ULW_ALPHA,
+    UpdateLayeredWindow, WM_DESTROY, WM_DISPLAYCHANGE, WM_ERASEBKGND, WM_KEYDOWN, WM_LBUTTONDOWN,
+    WM_LBUTTONUP, WM_MOUSEMOVE, WM_MOUSEWHEEL, WM_NCHITTEST, WM_QUIT, WNDCLASSEXW, WS_EX_LAYERED,
+    WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_POPUP,
 };
 use windows::core::PCWSTR;
 
 use windows::Win32::Foundation::RECT;
 
+use super::{
+    DisplayChangeCallback, MonitorInfo, OverlayConfig, OverlayKey, OverlayPlatform, PlatformError,
+};
 use super::{MAX_OVERLAY_HEIGHT, MAX_OVERLAY_WIDTH, MIN_OVERLAY_SIZE, RESIZE_CORNER_SIZE};
-use super::{MonitorInfo, OverlayConfig, OverlayPlatform, PlatformError};
+
+/// Wheel delta of one notch (WHEEL_DELTA)
+const WHEEL_DELTA: i32 = 120;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Standalone Monitor Enumeration
@@ -143,6 +152,83 @@ pub fn get_all_monitors() -> Vec<MonitorInfo> {
     monitors
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Display Change Notifications
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Callback of the display watcher (one watcher per process)
+static DISPLAY_CHANGE_CALLBACK: OnceLock<DisplayChangeCallback> = OnceLock::new();
+
+/// Watch for monitors being added, removed or rearranged.
+///
+/// WM_DISPLAYCHANGE is only broadcast to top-level windows, so this creates a
+/// hidden (never shown) window on its own thread and pumps its messages.
+pub fn watch_display_changes(on_change: DisplayChangeCallback) -> bool {
+    if DISPLAY_CHANGE_CALLBACK.set(on_change).is_err() {
+        return false;
+    }
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel::<bool>();
+    let spawned = std::thread::Builder::new()
+        .name("display-watch".to_string())
+        .spawn(move || unsafe {
+            let class_name = wide_string("BarasDisplayWatchClass");
+            let Ok(hinstance) = GetModuleHandleW(None) else {
+                let _ = ready_tx.send(false);
+                return;
+            };
+            let wc = WNDCLASSEXW {
+                cbSize: mem::size_of::<WNDCLASSEXW>() as u32,
+                lpfnWndProc: Some(display_watch_proc),
+                hInstance: hinstance.into(),
+                lpszClassName: PCWSTR(class_name.as_ptr()),
+                ..Default::default()
+            };
+            RegisterClassExW(&wc);
+            let hwnd = CreateWindowExW(
+                WS_EX_TOOLWINDOW,
+                PCWSTR(class_name.as_ptr()),
+                PCWSTR(class_name.as_ptr()),
+                WS_POPUP,
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                hinstance,
+                None,
+            );
+            let _ = ready_tx.send(hwnd.is_ok());
+            if hwnd.is_err() {
+                return;
+            }
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        })
+        .is_ok();
+    spawned && ready_rx.recv().unwrap_or(false)
+}
+
+unsafe extern "system" fn display_watch_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    if msg == WM_DISPLAYCHANGE {
+        overlay_log!("WM_DISPLAYCHANGE: monitor configuration changed");
+        if let Some(on_change) = DISPLAY_CHANGE_CALLBACK.get() {
+            on_change();
+        }
+        return LRESULT(0);
+    }
+    unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) }
+}
+
 /// Windows overlay implementation
 pub struct WindowsOverlay {
     hwnd: HWND,
@@ -165,6 +251,13 @@ pub struct WindowsOverlay {
     in_resize_corner: bool,
     drag_enabled: bool,
     pending_click: Option<(f32, f32)>,
+    pending_release: Option<(f32, f32)>,
+    // Wheel notches not yet taken, and the remainder of high-resolution wheels
+    pending_scroll: i32,
+    scroll_accum: i32,
+    // Keyboard input (keyboard mode)
+    keyboard_input: bool,
+    pending_keys: VecDeque<OverlayKey>,
     // Drag tracking - uses screen coordinates for stable movement
     drag_start_screen_x: i32,
     drag_start_screen_y: i32,
@@ -484,6 +577,11 @@ impl OverlayPlatform for WindowsOverlay {
             in_resize_corner: false,
             drag_enabled: true,
             pending_click: None,
+            pending_release: None,
+            pending_scroll: 0,
+            scroll_accum: 0,
+            keyboard_input: false,
+            pending_keys: VecDeque::new(),
             drag_start_screen_x: 0,
             drag_start_screen_y: 0,
             drag_start_win_x: abs_x,
@@ -622,6 +720,43 @@ impl OverlayPlatform for WindowsOverlay {
         self.pending_click.take()
     }
 
+    fn take_pending_release(&mut self) -> Option<(f32, f32)> {
+        self.pending_release.take()
+    }
+
+    fn take_pending_scroll(&mut self) -> i32 {
+        mem::take(&mut self.pending_scroll)
+    }
+
+    fn pointer_position(&self) -> (f32, f32) {
+        (self.pointer_x as f32, self.pointer_y as f32)
+    }
+
+    fn is_pointer_inside(&self) -> bool {
+        // WM_MOUSELEAVE needs TrackMouseEvent re-armed on every entry; asking
+        // for the cursor directly is simpler
+        let mut pt = POINT::default();
+        if unsafe { GetCursorPos(&mut pt) }.is_err() {
+            return false;
+        }
+        pt.x >= self.x
+            && pt.x < self.x + self.width as i32
+            && pt.y >= self.y
+            && pt.y < self.y + self.height as i32
+    }
+
+    fn set_keyboard_input(&mut self, enabled: bool) {
+        overlay_log!("HWND={:?}: set_keyboard_input({})", self.hwnd, enabled);
+        self.keyboard_input = enabled;
+        if !enabled {
+            self.pending_keys.clear();
+        }
+    }
+
+    fn take_pending_key(&mut self) -> Option<OverlayKey> {
+        self.pending_keys.pop_front()
+    }
+
     fn in_resize_corner(&self) -> bool {
         self.in_resize_corner
     }
@@ -701,9 +836,20 @@ impl OverlayPlatform for WindowsOverlay {
                             // Drag disabled (rearrange mode) - report click to overlay
                             overlay_log!("  Storing pending click for overlay");
                             self.pending_click = Some((x as f32, y as f32));
+                            // Keyboard mode: the clicked overlay takes keyboard focus
+                            if self.keyboard_input {
+                                let _ = SetFocus(self.hwnd);
+                            }
+                            // Capture so the release is seen even outside the window
+                            let _ = SetCapture(self.hwnd);
                         }
                     }
                     WM_LBUTTONUP => {
+                        if !self.drag_enabled && !self.click_through {
+                            let x = (msg.lParam.0 & 0xFFFF) as i16 as i32;
+                            let y = ((msg.lParam.0 >> 16) & 0xFFFF) as i16 as i32;
+                            self.pending_release = Some((x as f32, y as f32));
+                        }
                         if self.is_dragging || self.is_resizing {
                             overlay_log!("HWND={:?}: WM_LBUTTONUP - ending drag/resize", self.hwnd);
                         }
@@ -754,6 +900,28 @@ impl OverlayPlatform for WindowsOverlay {
                             }
                         }
                     }
+                    WM_MOUSEWHEEL if !self.click_through => {
+                        // Positive deltas roll away from the user (scroll up)
+                        let delta = ((msg.wParam.0 >> 16) & 0xFFFF) as i16 as i32;
+                        self.scroll_accum += delta;
+                        let notches = self.scroll_accum / WHEEL_DELTA;
+                        self.scroll_accum -= notches * WHEEL_DELTA;
+                        self.pending_scroll -= notches;
+                    }
+                    WM_KEYDOWN if self.keyboard_input => {
+                        let key = match msg.wParam.0 as u16 {
+                            vk if vk == VK_UP.0 => Some(OverlayKey::Up),
+                            vk if vk == VK_DOWN.0 => Some(OverlayKey::Down),
+                            vk if vk == VK_LEFT.0 => Some(OverlayKey::Left),
+                            vk if vk == VK_RIGHT.0 => Some(OverlayKey::Right),
+                            vk if vk == VK_RETURN.0 => Some(OverlayKey::Enter),
+                            vk if vk == VK_ESCAPE.0 => Some(OverlayKey::Escape),
+                            _ => None,
+                        };
+                        if let Some(key) = key {
+                            self.pending_keys.push_back(key);
+                        }
+                    }
                     WM_DESTROY => {
                         overlay_log!("HWND={:?}: Received WM_DESTROY - exiting!", self.hwnd);
                         self.running = false;
@@ -3,6 +3,7 @@
 //! Uses XCB via x11rb for transparent, always-on-top overlay windows
 //! with click-through support. Requires a compositor for transparency.
 
+use std::collections::VecDeque;
 use std::fs::File;
 use std::os::fd::AsFd;
 
@@ -10,15 +11,17 @@ use rustix::fs::{MemfdFlags, memfd_create};
 use rustix::mm::{MapFlags, ProtFlags, mmap};
 use x11rb::atom_manager;
 use x11rb::connection::Connection;
-use x11rb::protocol::randr::ConnectionExt as _;
+use x11rb::protocol::randr::{self, ConnectionExt as _};
 use x11rb::protocol::shape::{self, ConnectionExt as _};
 use x11rb::protocol::shm::{self, ConnectionExt as _};
 use x11rb::protocol::xproto::*;
 use x11rb::rust_connection::RustConnection;
 use x11rb::wrapper::ConnectionExt as _;
 
+use super::{
+    DisplayChangeCallback, MonitorInfo, OverlayConfig, OverlayKey, OverlayPlatform, PlatformError,
+};
 use super::{MAX_OVERLAY_HEIGHT, MAX_OVERLAY_WIDTH, MIN_OVERLAY_SIZE, RESIZE_CORNER_SIZE};
-use super::{MonitorInfo, OverlayConfig, OverlayPlatform, PlatformError};
 
 // Atoms needed for EWMH hints
 atom_manager! {
@@ -78,6 +81,49 @@ pub fn get_all_monitors() -> Vec<MonitorInfo> {
         .collect()
 }
 
+/// Watch for monitors being added, removed or rearranged (RandR notify events
+/// on a connection of its own)
+pub fn watch_display_changes(on_change: DisplayChangeCallback) -> bool {
+    let Ok((conn, screen_num)) = x11rb::connect(None) else {
+        return false;
+    };
+    let root = conn.setup().roots[screen_num].root;
+
+    // RandR only sends notify events to clients that negotiated a version
+    let subscribed = conn
+        .randr_query_version(1, 2)
+        .ok()
+        .and_then(|c| c.reply().ok())
+        .is_some()
+        && conn
+            .randr_select_input(
+                root,
+                randr::NotifyMask::SCREEN_CHANGE
+                    | randr::NotifyMask::CRTC_CHANGE
+                    | randr::NotifyMask::OUTPUT_CHANGE,
+            )
+            .is_ok()
+        && conn.flush().is_ok();
+    if !subscribed {
+        return false;
+    }
+
+    std::thread::Builder::new()
+        .name("display-watch".to_string())
+        .spawn(move || {
+            while let Ok(event) = conn.wait_for_event() {
+                if matches!(
+                    event,
+                    x11rb::protocol::Event::RandrScreenChangeNotify(_)
+                        | x11rb::protocol::Event::RandrNotify(_)
+                ) {
+                    on_change();
+                }
+            }
+        })
+        .is_ok()
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // X11 Overlay Implementation
 // ─────────────────────────────────────────────────────────────────────────────
@@ -115,6 +161,14 @@ pub struct X11Overlay {
     in_resize_corner: bool,
     position_dirty: bool,
     pending_click: Option<(f32, f32)>,
+    pending_release: Option<(f32, f32)>,
+    /// Wheel notches not yet taken
+    pending_scroll: i32,
+    keyboard_input: bool,
+    pending_keys: VecDeque<OverlayKey>,
+    pointer_x: i32,
+    pointer_y: i32,
+    pointer_inside: bool,
 
     // Drag tracking (root coordinates for stability)
     drag_start_root_x: i32,
@@ -337,6 +391,7 @@ impl OverlayPlatform for X11Overlay {
                 EventMask::EXPOSURE
                     | EventMask::BUTTON_PRESS
                     | EventMask::BUTTON_RELEASE
+                    | EventMask::KEY_PRESS
                     | EventMask::POINTER_MOTION
                     | EventMask::ENTER_WINDOW
                     | EventMask::LEAVE_WINDOW
@@ -388,6 +443,13 @@ impl OverlayPlatform for X11Overlay {
             in_resize_corner: false,
             position_dirty: false,
             pending_click: None,
+            pending_release: None,
+            pending_scroll: 0,
+            keyboard_input: false,
+            pending_keys: VecDeque::new(),
+            pointer_x: 0,
+            pointer_y: 0,
+            pointer_inside: false,
             drag_start_root_x: 0,
             drag_start_root_y: 0,
             drag_start_win_x: abs_x,
@@ -504,6 +566,41 @@ impl OverlayPlatform for X11Overlay {
         self.pending_click.take()
     }
 
+    fn take_pending_release(&mut self) -> Option<(f32, f32)> {
+        self.pending_release.take()
+    }
+
+    fn take_pending_scroll(&mut self) -> i32 {
+        std::mem::take(&mut self.pending_scroll)
+    }
+
+    fn set_keyboard_input(&mut self, enabled: bool) {
+        self.keyboard_input = enabled;
+        if !enabled {
+            self.pending_keys.clear();
+            // Override-redirect windows are never focused by the window
+            // manager, so hand focus back to whatever is under the pointer
+            let _ = self.conn.set_input_focus(
+                InputFocus::POINTER_ROOT,
+                InputFocus::POINTER_ROOT,
+                x11rb::CURRENT_TIME,
+            );
+            let _ = self.conn.flush();
+        }
+    }
+
+    fn take_pending_key(&mut self) -> Option<OverlayKey> {
+        self.pending_keys.pop_front()
+    }
+
+    fn pointer_position(&self) -> (f32, f32) {
+        (self.pointer_x as f32, self.pointer_y as f32)
+    }
+
+    fn is_pointer_inside(&self) -> bool {
+        self.pointer_inside
+    }
+
     fn in_resize_corner(&self) -> bool {
         self.in_resize_corner
     }
@@ -563,6 +660,12 @@ impl OverlayPlatform for X11Overlay {
     fn poll_events(&mut self) -> bool {
         while let Ok(Some(event)) = self.conn.poll_for_event() {
             match event {
+                // The wheel arrives as presses of buttons 4 (up) and 5 (down)
+                x11rb::protocol::Event::ButtonPress(e)
+                    if !self.click_through && matches!(e.detail, 4 | 5) =>
+                {
+                    self.pending_scroll += if e.detail == 5 { 1 } else { -1 };
+                }
                 x11rb::protocol::Event::ButtonPress(e) if !self.click_through => {
                     let x = e.event_x as i32;
                     let y = e.event_y as i32;
@@ -584,16 +687,38 @@ impl OverlayPlatform for X11Overlay {
                             }
                         } else {
                             self.pending_click = Some((x as f32, y as f32));
+                            // Keyboard mode: the clicked overlay takes keyboard focus
+                            if self.keyboard_input {
+                                let _ = self.conn.set_input_focus(
+                                    InputFocus::PARENT,
+                                    self.window,
+                                    x11rb::CURRENT_TIME,
+                                );
+                                let _ = self.conn.flush();
+                            }
                         }
                     }
                 }
+                x11rb::protocol::Event::KeyPress(e) if self.keyboard_input => {
+                    // Keycodes are evdev codes offset by 8 on modern X servers
+                    if let Some(key) = OverlayKey::from_evdev(u32::from(e.detail).saturating_sub(8))
+                    {
+                        self.pending_keys.push_back(key);
+                    }
+                }
                 x11rb::protocol::Event::ButtonRelease(e) if e.detail == 1 => {
+                    if !self.drag_enabled && !self.click_through {
+                        self.pending_release = Some((e.event_x as f32, e.event_y as f32));
+                    }
                     self.is_dragging = false;
                     self.is_resizing = false;
                 }
                 x11rb::protocol::Event::MotionNotify(e) if !self.click_through => {
                     let x = e.event_x as i32;
                     let y = e.event_y as i32;
+                    self.pointer_x = x;
+                    self.pointer_y = y;
+                    self.pointer_inside = true;
 
                     if !self.is_resizing {
                         self.in_resize_corner = self.is_in_resize_corner(x, y);
@@ -622,6 +747,7 @@ impl OverlayPlatform for X11Overlay {
                     }
                 }
                 x11rb::protocol::Event::LeaveNotify(_) => {
+                    self.pointer_inside = false;
                     if !self.is_resizing {
                         self.in_resize_corner = false;
                     }
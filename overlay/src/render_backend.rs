@@ -0,0 +1,361 @@
+//! Renderer backends and runtime selection
+//!
+//! Overlays draw through [`RenderBackend`], which targets the RGBA pixel
+//! buffer the platform window presents. The software [`Renderer`] draws
+//! straight into it; the GPU backend (`gpu` feature) records the frame,
+//! composites it with wgpu and reads the result back into the buffer when the
+//! frame is finished, skipping the GPU for frames that didn't change.
+//!
+//! The backend is chosen when an overlay window is created, from the
+//! process-wide preference set by [`set_renderer_preference`]. The GPU
+//! backend is only used when picked explicitly: its per-frame readback is not
+//! yet shown to beat software drawing, so `Auto` resolves to software.
+#![allow(clippy::too_many_arguments)]
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use baras_core::context::OverlayRenderer;
+use tiny_skia::Color;
+
+use crate::renderer::Renderer;
+
+/// Drawing operations an overlay window needs from a renderer.
+///
+/// Every call targets the window's RGBA pixel buffer of `width` x `height`.
+/// Backends that defer drawing must have written the frame into the buffer
+/// by the time [`RenderBackend::finish_frame`] returns.
+pub trait RenderBackend {
+    /// Short name for logs
+    fn name(&self) -> &'static str;
+
+    fn clear(&mut self, buffer: &mut [u8], width: u32, height: u32, color: Color);
+
+    fn fill_rect(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        color: Color,
+    );
+
+    fn fill_rounded_rect(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        radius: f32,
+        color: Color,
+    );
+
+    fn stroke_rounded_rect(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        radius: f32,
+        stroke_width: f32,
+        color: Color,
+    );
+
+    fn stroke_rounded_rect_dashed(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        radius: f32,
+        stroke_width: f32,
+        color: Color,
+        dash_length: f32,
+        gap_length: f32,
+    );
+
+    fn draw_text(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        text: &str,
+        x: f32,
+        y: f32,
+        font_size: f32,
+        color: Color,
+    );
+
+    fn measure_text(&mut self, text: &str, font_size: f32) -> (f32, f32);
+
+    fn draw_image(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        image_data: &[u8],
+        image_width: u32,
+        image_height: u32,
+        dest_x: f32,
+        dest_y: f32,
+        dest_width: f32,
+        dest_height: f32,
+    );
+
+    /// Write any deferred drawing into the buffer before it is committed
+    fn finish_frame(&mut self, _buffer: &mut [u8], _width: u32, _height: u32) {}
+}
+
+impl RenderBackend for Renderer {
+    fn name(&self) -> &'static str {
+        "software"
+    }
+
+    fn clear(&mut self, buffer: &mut [u8], width: u32, height: u32, color: Color) {
+        Renderer::clear(self, buffer, width, height, color);
+    }
+
+    fn fill_rect(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        color: Color,
+    ) {
+        Renderer::fill_rect(self, buffer, width, height, x, y, w, h, color);
+    }
+
+    fn fill_rounded_rect(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        radius: f32,
+        color: Color,
+    ) {
+        Renderer::fill_rounded_rect(self, buffer, width, height, x, y, w, h, radius, color);
+    }
+
+    fn stroke_rounded_rect(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        radius: f32,
+        stroke_width: f32,
+        color: Color,
+    ) {
+        Renderer::stroke_rounded_rect(
+            self,
+            buffer,
+            width,
+            height,
+            x,
+            y,
+            w,
+            h,
+            radius,
+            stroke_width,
+            color,
+        );
+    }
+
+    fn stroke_rounded_rect_dashed(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        radius: f32,
+        stroke_width: f32,
+        color: Color,
+        dash_length: f32,
+        gap_length: f32,
+    ) {
+        Renderer::stroke_rounded_rect_dashed(
+            self,
+            buffer,
+            width,
+            height,
+            x,
+            y,
+            w,
+            h,
+            radius,
+            stroke_width,
+            color,
+            dash_length,
+            gap_length,
+        );
+    }
+
+    fn draw_text(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        text: &str,
+        x: f32,
+        y: f32,
+        font_size: f32,
+        color: Color,
+    ) {
+        Renderer::draw_text(self, buffer, width, height, text, x, y, font_size, color);
+    }
+
+    fn measure_text(&mut self, text: &str, font_size: f32) -> (f32, f32) {
+        Renderer::measure_text(self, text, font_size)
+    }
+
+    fn draw_image(
+        &mut self,
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        image_data: &[u8],
+        image_width: u32,
+        image_height: u32,
+        dest_x: f32,
+        dest_y: f32,
+        dest_width: f32,
+        dest_height: f32,
+    ) {
+        Renderer::draw_image(
+            self,
+            buffer,
+            width,
+            height,
+            image_data,
+            image_width,
+            image_height,
+            dest_x,
+            dest_y,
+            dest_width,
+            dest_height,
+        );
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Runtime Selection
+// ─────────────────────────────────────────────────────────────────────────────
+
+static PREFERENCE: AtomicU8 = AtomicU8::new(0);
+
+fn encode(preference: OverlayRenderer) -> u8 {
+    match preference {
+        OverlayRenderer::Auto => 0,
+        OverlayRenderer::Gpu => 1,
+        OverlayRenderer::Software => 2,
+    }
+}
+
+fn decode(value: u8) -> OverlayRenderer {
+    match value {
+        1 => OverlayRenderer::Gpu,
+        2 => OverlayRenderer::Software,
+        _ => OverlayRenderer::Auto,
+    }
+}
+
+/// Set the renderer used by overlay windows created from now on
+pub fn set_renderer_preference(preference: OverlayRenderer) {
+    PREFERENCE.store(encode(preference), Ordering::Relaxed);
+}
+
+pub fn renderer_preference() -> OverlayRenderer {
+    decode(PREFERENCE.load(Ordering::Relaxed))
+}
+
+/// Create the preferred backend
+pub fn create_backend() -> Box<dyn RenderBackend> {
+    backend_for(renderer_preference(), create_gpu_backend)
+}
+
+/// Backend for `preference`, falling back to software rendering when no GPU
+/// device is available (e.g. EGL or driver initialization fails)
+fn backend_for(
+    preference: OverlayRenderer,
+    create_gpu: impl FnOnce() -> Result<Box<dyn RenderBackend>, String>,
+) -> Box<dyn RenderBackend> {
+    match preference {
+        OverlayRenderer::Auto | OverlayRenderer::Software => Box::new(Renderer::new()),
+        OverlayRenderer::Gpu => create_gpu().unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "GPU renderer unavailable, using software rendering");
+            Box::new(Renderer::new())
+        }),
+    }
+}
+
+#[cfg(feature = "gpu")]
+fn create_gpu_backend() -> Result<Box<dyn RenderBackend>, String> {
+    Ok(Box::new(crate::gpu::GpuRenderer::new()?))
+}
+
+#[cfg(not(feature = "gpu"))]
+fn create_gpu_backend() -> Result<Box<dyn RenderBackend>, String> {
+    Err("built without GPU support".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_preference_encoding_round_trips() {
+        for preference in OverlayRenderer::ALL {
+            assert_eq!(decode(encode(preference)), preference);
+        }
+        assert_eq!(decode(u8::MAX), OverlayRenderer::Auto);
+    }
+
+    #[test]
+    fn test_gpu_only_when_picked() {
+        let gpu_created = Cell::new(false);
+        let gpu = || {
+            gpu_created.set(true);
+            Ok(Box::new(Renderer::new()) as Box<dyn RenderBackend>)
+        };
+
+        backend_for(OverlayRenderer::Auto, gpu);
+        backend_for(OverlayRenderer::Software, gpu);
+        assert!(!gpu_created.get());
+
+        backend_for(OverlayRenderer::Gpu, gpu);
+        assert!(gpu_created.get());
+    }
+
+    #[test]
+    fn test_gpu_failure_falls_back_to_software() {
+        let backend = backend_for(OverlayRenderer::Gpu, || Err("no adapter".to_string()));
+        assert_eq!(backend.name(), "software");
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! These are shared across different overlay types.
 
+use baras_core::context::{MAX_NUMBER_DECIMALS, NumberFormat, NumberStyle};
 use tiny_skia::Color;
 
 /// Convert [u8; 4] RGBA array to tiny_skia Color
@@ -20,6 +21,24 @@ pub fn truncate_name(name: &str, max_chars: usize) -> String {
     }
 }
 
+/// Shorten a player name to its initials ("Kira Carsen" -> "KC"), or the
+/// first two letters of a single-word name ("Tharan" -> "Th")
+pub fn name_initials(name: &str) -> String {
+    let words: Vec<&str> = name
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|w| !w.is_empty())
+        .collect();
+    match words.as_slice() {
+        [] => String::new(),
+        [word] => word.chars().take(2).collect(),
+        _ => words
+            .iter()
+            .filter_map(|w| w.chars().next())
+            .take(3)
+            .collect(),
+    }
+}
+
 /// Format a duration in seconds as MM:SS
 pub fn format_time(secs: u64) -> String {
     format!("{}:{:02}", secs / 60, secs % 60)
@@ -33,12 +52,49 @@ pub fn format_duration_short(secs: f32) -> String {
 
 /// Format a large number with K/M suffix for compact display
 pub fn format_number(n: i64) -> String {
-    if n >= 1_000_000 {
-        format!("{:.2}M", n as f64 / 1_000_000.0)
-    } else if n >= 10_000 {
-        format!("{:.2}K", n as f64 / 1_000.0)
-    } else {
-        format!("{}", n)
+    format_number_as(n as f64, NumberFormat::default())
+}
+
+/// Format a number in a user-chosen style and precision.
+///
+/// Compact style only applies the decimals to K/M values; smaller values are
+/// whole numbers as in [`format_number`].
+pub fn format_number_as(value: f64, format: NumberFormat) -> String {
+    let decimals = format.decimals.min(MAX_NUMBER_DECIMALS) as usize;
+    match format.style {
+        NumberStyle::Compact => {
+            if value >= 1_000_000.0 {
+                format!("{:.*}M", decimals, value / 1_000_000.0)
+            } else if value >= 10_000.0 {
+                format!("{:.*}K", decimals, value / 1_000.0)
+            } else {
+                format!("{:.0}", value)
+            }
+        }
+        NumberStyle::Full => {
+            let text = format!("{:.*}", decimals, value);
+            let (int_part, frac_part) = match text.split_once('.') {
+                Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+                None => (text.as_str(), None),
+            };
+            let (sign, digits) = match int_part.strip_prefix('-') {
+                Some(digits) => ("-", digits),
+                None => ("", int_part),
+            };
+            let mut grouped = String::with_capacity(text.len() + digits.len() / 3);
+            grouped.push_str(sign);
+            for (i, c) in digits.chars().enumerate() {
+                if i > 0 && (digits.len() - i) % 3 == 0 {
+                    grouped.push(',');
+                }
+                grouped.push(c);
+            }
+            if let Some(frac_part) = frac_part {
+                grouped.push('.');
+                grouped.push_str(frac_part);
+            }
+            grouped
+        }
     }
 }
 
@@ -53,6 +109,14 @@ mod tests {
         assert_eq!(truncate_name("exactly10!", 10), "exactly10!");
     }
 
+    #[test]
+    fn test_name_initials() {
+        assert_eq!(name_initials("Kira Carsen"), "KC");
+        assert_eq!(name_initials("Tharan"), "Th");
+        assert_eq!(name_initials("Jaesa Willsaam-Vette"), "JWV");
+        assert_eq!(name_initials("  "), "");
+    }
+
     #[test]
     fn test_format_time() {
         assert_eq!(format_time(0), "0:00");
@@ -68,4 +132,22 @@ mod tests {
         assert_eq!(format_number(10000), "10.0K");
         assert_eq!(format_number(1500000), "1.5M");
     }
+
+    #[test]
+    fn test_format_number_as() {
+        let full = |decimals| NumberFormat {
+            style: NumberStyle::Full,
+            decimals,
+        };
+        let compact = |decimals| NumberFormat {
+            style: NumberStyle::Compact,
+            decimals,
+        };
+        assert_eq!(format_number_as(23_412.0, full(0)), "23,412");
+        assert_eq!(format_number_as(1_234_567.26, full(1)), "1,234,567.3");
+        assert_eq!(format_number_as(-999.0, full(0)), "-999");
+        assert_eq!(format_number_as(23_412.0, compact(1)), "23.4K");
+        assert_eq!(format_number_as(23_412.0, compact(0)), "23K");
+        assert_eq!(format_number_as(9_999.0, compact(1)), "9999");
+    }
 }
@@ -71,6 +71,36 @@ pub fn label_dim() -> Color {
     Color::from_rgba8(180, 180, 180, 255)
 }
 
+/// Tooltip background
+#[inline]
+pub fn tooltip_bg() -> Color {
+    Color::from_rgba8(20, 20, 24, 235)
+}
+
+/// Tooltip outline
+#[inline]
+pub fn tooltip_border() -> Color {
+    Color::from_rgba8(90, 90, 100, 255)
+}
+
+/// Outline of the entry selected in keyboard mode
+#[inline]
+pub fn keyboard_selection() -> Color {
+    Color::from_rgba8(120, 180, 255, 230)
+}
+
+/// Scroll indicator track
+#[inline]
+pub fn scroll_track() -> Color {
+    Color::from_rgba8(255, 255, 255, 30)
+}
+
+/// Scroll indicator thumb
+#[inline]
+pub fn scroll_thumb() -> Color {
+    Color::from_rgba8(200, 200, 200, 170)
+}
+
 // ─────────────────────────────────────────────────────────────────────────
 // Effect Type Colors
 // ─────────────────────────────────────────────────────────────────────────
@@ -139,6 +169,12 @@ pub fn resize_indicator() -> Color {
     Color::from_rgba8(255, 255, 255, 150)
 }
 
+/// Badge shown while a subsystem feeding the overlay is degraded
+#[inline]
+pub fn warning_badge() -> Color {
+    Color::from_rgba8(240, 170, 40, 235)
+}
+
 // ─────────────────────────────────────────────────────────────────────────
 // Raid Frame Colors
 // ─────────────────────────────────────────────────────────────────────────
@@ -155,6 +191,12 @@ pub fn raid_selection() -> Color {
     Color::from_rgba8(80, 120, 180, 220)
 }
 
+/// Drop target highlight while dragging a raid frame
+#[inline]
+pub fn raid_drop_target() -> Color {
+    Color::from_rgba8(120, 180, 255, 90)
+}
+
 /// Guide lines for rearrange mode
 #[inline]
 pub fn raid_guide() -> Color {
@@ -213,6 +255,12 @@ pub fn health_low() -> Color {
     Color::from_rgba8(220, 60, 60, 255)
 }
 
+/// Health bar - missing health behind the fill
+#[inline]
+pub fn health_deficit() -> Color {
+    Color::from_rgba8(90, 20, 20, 140)
+}
+
 // ─────────────────────────────────────────────────────────────────────────
 // Effect Icon Colors
 // ─────────────────────────────────────────────────────────────────────────
@@ -229,6 +277,36 @@ pub fn effect_icon_border() -> Color {
     Color::from_rgba8(150, 150, 150, 200)
 }
 
+/// Flashing border on critical-severity effects
+#[inline]
+pub fn effect_critical_border() -> Color {
+    Color::from_rgba8(255, 60, 40, 255)
+}
+
+/// Boss health kill pace: ahead of the best kill
+#[inline]
+pub fn pace_ahead() -> Color {
+    Color::from_rgba8(100, 220, 100, 255)
+}
+
+/// Boss health kill pace: behind the best kill
+#[inline]
+pub fn pace_behind() -> Color {
+    Color::from_rgba8(240, 110, 90, 255)
+}
+
+/// Boss health phase threshold tick (still ahead)
+#[inline]
+pub fn phase_marker() -> Color {
+    Color::from_rgba8(255, 255, 255, 230)
+}
+
+/// Boss health phase threshold tick (already pushed past)
+#[inline]
+pub fn phase_marker_passed() -> Color {
+    Color::from_rgba8(255, 255, 255, 70)
+}
+
 /// Preview border for move mode placeholders
 #[inline]
 pub fn preview_border() -> Color {
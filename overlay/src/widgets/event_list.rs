@@ -0,0 +1,100 @@
+//! Event list widget for scrolling logs of timed events
+//!
+//! Renders rows of `time | text | amount`, with the amount right-aligned in
+//! its own color. Rows past the visible range are skipped, so callers can
+//! scroll by moving the first row.
+
+use tiny_skia::Color;
+
+use crate::frame::OverlayFrame;
+use crate::widgets::colors;
+
+/// One row of an event list
+#[derive(Debug, Clone)]
+pub struct EventRow {
+    /// Short time label (e.g., "-2.4s")
+    pub time: String,
+    pub text: String,
+    pub amount: String,
+    pub amount_color: Color,
+}
+
+/// A list of event rows with a scroll position
+#[derive(Debug, Clone)]
+pub struct EventList<'a> {
+    pub rows: &'a [EventRow],
+    pub text_color: Color,
+    pub time_color: Color,
+    /// Index of the first row drawn
+    pub first_row: usize,
+}
+
+impl<'a> EventList<'a> {
+    pub fn new(rows: &'a [EventRow]) -> Self {
+        Self {
+            rows,
+            text_color: colors::white(),
+            time_color: colors::label_dim(),
+            first_row: 0,
+        }
+    }
+
+    pub fn with_text_color(mut self, color: Color) -> Self {
+        self.text_color = color;
+        self
+    }
+
+    pub fn with_first_row(mut self, first_row: usize) -> Self {
+        self.first_row = first_row;
+        self
+    }
+
+    /// Render as many rows as fit and return how many were drawn
+    ///
+    /// # Arguments
+    /// * `frame` - The overlay frame to render to
+    /// * `x` - Left edge x position
+    /// * `y` - Top y position
+    /// * `width` - Total width available
+    /// * `height` - Height available for rows
+    /// * `font_size` - Font size for all columns
+    pub fn render(
+        &self,
+        frame: &mut OverlayFrame,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        font_size: f32,
+    ) -> usize {
+        let row_height = Self::row_height(font_size);
+        let visible = (height / row_height).floor().max(0.0) as usize;
+        let (time_width, _) = frame.measure_text("-10.0s", font_size);
+        let text_x = x + time_width + font_size * 0.5;
+
+        let mut baseline = y + font_size;
+        let mut drawn = 0;
+        for row in self.rows.iter().skip(self.first_row).take(visible) {
+            frame.draw_text(&row.time, x, baseline, font_size, self.time_color);
+            frame.draw_text(&row.text, text_x, baseline, font_size, self.text_color);
+
+            let (amount_width, _) = frame.measure_text(&row.amount, font_size);
+            frame.draw_text(
+                &row.amount,
+                x + width - amount_width,
+                baseline,
+                font_size,
+                row.amount_color,
+            );
+
+            baseline += row_height;
+            drawn += 1;
+        }
+        drawn
+    }
+
+    /// Height of one row at `font_size`
+    pub fn row_height(font_size: f32) -> f32 {
+        font_size * 1.35
+    }
+}
@@ -9,13 +9,22 @@
 //! - [`LabeledValue`] - Key-value row with right-aligned value
 //! - [`Header`] - Section title with separator line
 //! - [`Footer`] - Summary footer with separator and value
+//! - [`Tooltip`] - Boxed label/value rows shown beside the pointer
+//! - [`EventList`] - Scrollable rows of timed events with colored amounts
+//! - [`ScrollIndicator`] - Track and thumb showing the visible part of a long list
 
 pub mod colors;
+mod event_list;
 mod header;
 mod labeled_value;
 mod progress_bar;
+mod scroll_indicator;
+mod tooltip;
 
 pub use colors::*;
+pub use event_list::{EventList, EventRow};
 pub use header::{Footer, Header};
 pub use labeled_value::LabeledValue;
 pub use progress_bar::ProgressBar;
+pub use scroll_indicator::ScrollIndicator;
+pub use tooltip::Tooltip;
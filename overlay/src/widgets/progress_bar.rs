@@ -25,6 +25,7 @@ fn lighten_color(color: Color, amount: f32) -> Color {
 /// - Label + right: `| Name              Value |`
 /// - Label + center + right: `| Name    Center   Value |` (3-column, smaller font)
 /// - Label + center: `| Name           Center   |`
+/// - Centered label: `|       Name 5.2       |` (right/center text ignored)
 #[derive(Debug, Clone)]
 pub struct ProgressBar {
     pub label: String,
@@ -44,6 +45,10 @@ pub struct ProgressBar {
     pub split_color: Option<Color>,
     /// Optional offset for label text start position (for icon space)
     pub label_offset: f32,
+    /// Draw the label centered across the whole bar
+    pub centered_label: bool,
+    /// Draw the fill brightened (used to flash bars)
+    pub highlighted: bool,
 }
 
 impl ProgressBar {
@@ -59,6 +64,8 @@ impl ProgressBar {
             split_progress: None,
             split_color: None,
             label_offset: 0.0,
+            centered_label: false,
+            highlighted: false,
         }
     }
 
@@ -108,6 +115,18 @@ impl ProgressBar {
         self
     }
 
+    /// Draw the label centered across the bar instead of left-aligned
+    pub fn with_centered_label(mut self) -> Self {
+        self.centered_label = true;
+        self
+    }
+
+    /// Brighten the fill color (toggle per frame to flash the bar)
+    pub fn with_highlight(mut self, highlighted: bool) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
     /// Check if this is a 3-column layout (has both center and right text)
     fn is_three_column(&self) -> bool {
         self.center_text.is_some() && self.right_text.is_some()
@@ -172,6 +191,12 @@ impl ProgressBar {
         // Draw background
         frame.fill_rounded_rect(x, y, width, height, radius, self.bg_color);
 
+        let fill_color = if self.highlighted {
+            lighten_color(self.fill_color, 0.5)
+        } else {
+            self.fill_color
+        };
+
         // Draw fill (with optional split for primary/secondary visualization)
         let fill_width = width * self.progress;
         if fill_width > 0.0 {
@@ -179,21 +204,39 @@ impl ProgressBar {
                 // Split bar: draw full fill as secondary color, then primary on top
                 let secondary_color = self
                     .split_color
-                    .unwrap_or_else(|| lighten_color(self.fill_color, 0.4));
+                    .unwrap_or_else(|| lighten_color(fill_color, 0.4));
                 frame.fill_rounded_rect(x, y, fill_width, height, radius, secondary_color);
 
                 // Draw primary segment on top (covers left portion)
                 let primary_width = fill_width * primary_fraction;
                 if primary_width > 0.0 {
-                    frame.fill_rounded_rect(x, y, primary_width, height, radius, self.fill_color);
+                    frame.fill_rounded_rect(x, y, primary_width, height, radius, fill_color);
                 }
             } else {
                 // Normal single-color fill
-                frame.fill_rounded_rect(x, y, fill_width, height, radius, self.fill_color);
+                frame.fill_rounded_rect(x, y, fill_width, height, radius, fill_color);
             }
         }
 
         let text_padding = 4.0 * frame.scale_factor();
+
+        if self.centered_label {
+            let display_label = self.truncate_label_to_width(
+                frame,
+                (width - text_padding * 2.0).max(0.0),
+                font_size,
+            );
+            let (label_width, _) = frame.measure_text(&display_label, font_size);
+            frame.draw_text(
+                &display_label,
+                x + (width - label_width) / 2.0,
+                y + height / 2.0 + font_size / 3.0,
+                font_size,
+                self.text_color,
+            );
+            return;
+        }
+
         let is_three_col = self.is_three_column();
 
         // Use smaller font for 3-column layout to fit everything
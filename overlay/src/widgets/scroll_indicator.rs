@@ -0,0 +1,69 @@
+//! Scroll indicator widget for lists longer than their overlay
+//!
+//! Renders a thin track with a thumb sized and positioned by the visible
+//! window of rows.
+
+use tiny_skia::Color;
+
+use crate::frame::OverlayFrame;
+use crate::widgets::colors;
+
+/// A vertical scroll position indicator
+#[derive(Debug, Clone)]
+pub struct ScrollIndicator {
+    /// Total number of rows in the list
+    pub total: usize,
+    /// Number of rows visible at once
+    pub visible: usize,
+    /// Index of the first visible row
+    pub offset: usize,
+    pub track_color: Color,
+    pub thumb_color: Color,
+}
+
+impl ScrollIndicator {
+    pub fn new(total: usize, visible: usize, offset: usize) -> Self {
+        Self {
+            total,
+            visible,
+            offset,
+            track_color: colors::scroll_track(),
+            thumb_color: colors::scroll_thumb(),
+        }
+    }
+
+    pub fn with_thumb_color(mut self, color: Color) -> Self {
+        self.thumb_color = color;
+        self
+    }
+
+    /// Whether there are rows outside the visible window
+    pub fn is_scrollable(&self) -> bool {
+        self.total > self.visible
+    }
+
+    /// Render the indicator in a vertical strip (nothing if all rows fit)
+    ///
+    /// # Arguments
+    /// * `frame` - The overlay frame to render to
+    /// * `x` - Left edge of the strip
+    /// * `y` - Top of the strip
+    /// * `width` - Strip width
+    /// * `height` - Strip height (usually the height of the visible rows)
+    pub fn render(&self, frame: &mut OverlayFrame, x: f32, y: f32, width: f32, height: f32) {
+        if !self.is_scrollable() || height <= 0.0 {
+            return;
+        }
+
+        let radius = width / 2.0;
+        frame.fill_rounded_rect(x, y, width, height, radius, self.track_color);
+
+        // Thumb keeps a minimum size so it stays visible on very long lists
+        let total = self.total as f32;
+        let thumb_height = (height * self.visible as f32 / total).max(width * 2.0);
+        let max_offset = (self.total - self.visible) as f32;
+        let progress = (self.offset as f32 / max_offset).clamp(0.0, 1.0);
+        let thumb_y = y + (height - thumb_height) * progress;
+        frame.fill_rounded_rect(x, thumb_y, width, thumb_height, radius, self.thumb_color);
+    }
+}
@@ -0,0 +1,117 @@
+//! Tooltip widget for hover details
+//!
+//! Renders a small boxed list of label/value rows next to the pointer,
+//! kept inside the overlay's bounds.
+
+use tiny_skia::Color;
+
+use crate::frame::OverlayFrame;
+use crate::widgets::LabeledValue;
+use crate::widgets::colors;
+
+/// Gap between the pointer and the tooltip box
+const POINTER_GAP: f32 = 12.0;
+
+/// A boxed title with label/value rows, drawn over the overlay content
+#[derive(Debug, Clone)]
+pub struct Tooltip {
+    pub title: String,
+    pub rows: Vec<LabeledValue>,
+    pub empty_text: String,
+    pub text_color: Color,
+}
+
+impl Tooltip {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            rows: Vec::new(),
+            empty_text: String::new(),
+            text_color: colors::white(),
+        }
+    }
+
+    pub fn with_row(mut self, row: LabeledValue) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Text shown in place of rows when there are none
+    pub fn with_empty_text(mut self, text: impl Into<String>) -> Self {
+        self.empty_text = text.into();
+        self
+    }
+
+    pub fn with_text_color(mut self, color: Color) -> Self {
+        self.text_color = color;
+        self
+    }
+
+    /// Render the tooltip beside the pointer
+    ///
+    /// # Arguments
+    /// * `frame` - The overlay frame to render to
+    /// * `pointer` - Pointer position within the overlay
+    /// * `width` - Width of the tooltip box
+    /// * `font_size` - Font size for all rows
+    pub fn render(
+        &self,
+        frame: &mut OverlayFrame,
+        pointer: (f32, f32),
+        width: f32,
+        font_size: f32,
+    ) {
+        let scale = frame.scale_factor();
+        let padding = 6.0 * scale;
+        let line_height = font_size * 1.4;
+        let lines = 1 + self.rows.len().max(1);
+        let height = padding * 2.0 + lines as f32 * line_height;
+
+        // Prefer below-right of the pointer, flip when that would leave the overlay
+        let (px, py) = pointer;
+        let gap = POINTER_GAP * scale;
+        let frame_w = frame.width() as f32;
+        let frame_h = frame.height() as f32;
+        let x = if px + gap + width <= frame_w {
+            px + gap
+        } else {
+            (px - gap - width).max(0.0)
+        };
+        let y = if py + gap + height <= frame_h {
+            py + gap
+        } else {
+            (py - gap - height).max(0.0)
+        };
+
+        frame.fill_rounded_rect(x, y, width, height, 4.0 * scale, colors::tooltip_bg());
+        frame.stroke_rounded_rect(
+            x,
+            y,
+            width,
+            height,
+            4.0 * scale,
+            1.0,
+            colors::tooltip_border(),
+        );
+
+        let text_x = x + padding;
+        let text_width = width - padding * 2.0;
+        let mut baseline = y + padding + font_size;
+        frame.draw_text(&self.title, text_x, baseline, font_size, self.text_color);
+        baseline += line_height;
+
+        if self.rows.is_empty() {
+            frame.draw_text(
+                &self.empty_text,
+                text_x,
+                baseline,
+                font_size,
+                colors::label_dim(),
+            );
+        }
+        for row in &self.rows {
+            row.render(frame, text_x, baseline, text_width, font_size);
+            baseline += line_height;
+        }
+    }
+}
@@ -6,6 +6,17 @@
 //! Usage: baras-parse-worker <file_path> <session_id> <output_dir> [definitions_dir]
 //!
 //! Output: JSON to stdout with encounter summaries and final byte position.
+//! Every signal is also recorded to the session's signal journal in `output_dir`,
+//! and the NPC, ability and effect IDs seen to an ID dictionary the app merges.
+//!
+//! Set BARAS_PARSE_WRITERS to write encounter files on several threads (default 1).
+//! Set BARAS_IGNORED_IDS to a comma-separated list of ability/effect IDs to skip.
+//!
+//! Large files are parsed newest-first by running the worker twice:
+//! - BARAS_PARSE_RECENT=1 parses only from the last area entry before the final
+//!   combat, so the most recent encounters are available quickly.
+//! - BARAS_PARSE_BACKFILL=<offset> then parses the whole file, journaling only the
+//!   signals before `offset` to a separate file that the main app prepends.
 
 #![cfg_attr(target_os = "windows", windows_subsystem = "windows")]
 
@@ -17,15 +28,24 @@ use arrow::array::{
 use arrow::buffer::{NullBuffer, OffsetBuffer};
 use arrow::datatypes::{DataType, Field, Fields, Schema, TimeUnit};
 use arrow::record_batch::RecordBatch;
-use baras_core::combat_log::{CombatEvent, EntityType, LogParser};
-use baras_core::context::{parse_log_filename, resolve};
-use baras_core::dsl::{BossEncounterDefinition, load_bosses_from_dir, merge_boss_definition};
+use baras_core::combat_log::{
+    CombatEvent, EntityType, LogParser, count_lines_before, recent_area_start,
+};
+use baras_core::context::{
+    ID_DICTIONARY_FILENAME, IdDictionary, IgnoreList, id_dictionary_path, parse_log_filename,
+    resolve,
+};
+use baras_core::dsl::{
+    AreaIndex, BossEncounterDefinition, build_layered_area_index, load_indexed_area,
+    user_encounters_dir,
+};
 use baras_core::encounter::summary::EncounterSummary;
-use baras_core::game_data::defense_type;
+use baras_core::game_data::{defense_type, effect_type_id};
 use baras_core::signal_processor::{EventProcessor, GameSignal};
 use baras_core::state::SessionCache;
-use baras_core::storage::encounter_filename;
-use encoding_rs::WINDOWS_1252;
+use baras_core::storage::{
+    BACKFILL_JOURNAL_FILENAME, JOURNAL_FILENAME, SignalJournal, encounter_filename,
+};
 use memchr::memchr_iter;
 use memmap2::Mmap;
 use parquet::arrow::ArrowWriter;
@@ -35,7 +55,9 @@ use rayon::prelude::*;
 use serde::Serialize;
 use std::fs::{self, File};
 use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, SyncSender};
 use std::sync::{Arc, LazyLock};
+use std::thread::JoinHandle;
 use tracing_subscriber::filter::EnvFilter;
 
 /// Player session info for main process.
@@ -70,8 +92,13 @@ struct AreaInfoOutput {
 /// Output sent to main process via stdout.
 #[derive(Debug, Serialize)]
 struct ParseOutput {
+    /// Byte position parsing started at (non-zero for BARAS_PARSE_RECENT).
+    start_pos: u64,
     /// Final byte position in the file (for tailing).
     end_pos: u64,
+    /// Timestamp of the last event, so tailing dates new lines from there
+    /// rather than from the session date.
+    last_timestamp: Option<String>,
     /// Number of events parsed.
     event_count: usize,
     /// Number of encounters written.
@@ -252,7 +279,7 @@ impl FastEncounterWriter {
         self.dmg_type_id.append_value(event.details.dmg_type_id);
         self.dmg_type.append_value(resolve(event.details.dmg_type));
         self.is_crit.append_value(event.details.is_crit);
-        self.is_reflect.append_value(event.details.is_reflect);
+        self.is_reflect.append_value(event.is_reflect_damage());
         self.defense_type_id
             .append_value(event.details.defense_type_id);
 
@@ -530,6 +557,93 @@ const fn entity_type_str(entity_type: &EntityType) -> &'static str {
 /// Static schema to avoid recreation on every batch write.
 static ENCOUNTER_SCHEMA: LazyLock<Arc<Schema>> = LazyLock::new(FastEncounterWriter::build_schema);
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Writer Pool - bounded queues so parsing can't outrun parquet output
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Encounter batches queued per writer before the processing loop blocks.
+const WRITE_QUEUE_DEPTH: usize = 2;
+
+/// Upper bound for `BARAS_PARSE_WRITERS`.
+const MAX_WRITER_THREADS: usize = 8;
+
+/// Number of parquet writer threads, from BARAS_PARSE_WRITERS (default 1).
+fn writer_thread_count() -> usize {
+    std::env::var("BARAS_PARSE_WRITERS")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(1)
+        .clamp(1, MAX_WRITER_THREADS)
+}
+
+/// Whether only the most recent encounters should be parsed (BARAS_PARSE_RECENT).
+fn recent_only() -> bool {
+    std::env::var("BARAS_PARSE_RECENT").is_ok_and(|v| v == "1")
+}
+
+/// Offset before which a backfill run journals signals (BARAS_PARSE_BACKFILL).
+fn backfill_until() -> Option<u64> {
+    std::env::var("BARAS_PARSE_BACKFILL")
+        .ok()
+        .and_then(|v| v.parse().ok())
+}
+
+/// Abilities/effects to skip, from BARAS_IGNORED_IDS (comma-separated).
+fn ignore_list() -> IgnoreList {
+    std::env::var("BARAS_IGNORED_IDS")
+        .map(|v| IgnoreList::new(v.split(',').filter_map(|id| id.trim().parse().ok())))
+        .unwrap_or_default()
+}
+
+/// Background parquet writers fed through bounded channels.
+///
+/// Each encounter is routed to a writer by its index, so files are written
+/// in parallel while every writer holds at most `WRITE_QUEUE_DEPTH` pending
+/// batches. Once a queue is full, `submit` blocks, capping peak memory on
+/// giant logs at roughly `threads * (WRITE_QUEUE_DEPTH + 1)` encounters.
+struct WriterPool {
+    senders: Vec<SyncSender<(RecordBatch, PathBuf)>>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl WriterPool {
+    fn new(threads: usize) -> Self {
+        let (senders, handles) = (0..threads.max(1))
+            .map(|_| {
+                let (tx, rx) = mpsc::sync_channel::<(RecordBatch, PathBuf)>(WRITE_QUEUE_DEPTH);
+                let handle = std::thread::spawn(move || {
+                    for (batch, path) in rx {
+                        let file = path.display().to_string();
+                        if let Err(e) = FastEncounterWriter::write_batch_to_file(batch, path) {
+                            tracing::warn!(
+                                error = %e,
+                                path = %file,
+                                "Failed to write encounter"
+                            );
+                        }
+                    }
+                });
+                (tx, handle)
+            })
+            .unzip();
+        Self { senders, handles }
+    }
+
+    /// Queue an encounter batch, blocking while its writer is backed up.
+    fn submit(&self, encounter_idx: u32, batch: RecordBatch, path: PathBuf) {
+        let sender = &self.senders[encounter_idx as usize % self.senders.len()];
+        let _ = sender.send((batch, path));
+    }
+
+    /// Close the queues and wait for all pending writes to finish.
+    fn finish(self) {
+        drop(self.senders);
+        for handle in self.handles {
+            let _ = handle.join();
+        }
+    }
+}
+
 /// Initialize logging, writing to BARAS_LOG_PATH if set, otherwise stderr.
 fn init_logging() {
     let filter = EnvFilter::builder()
@@ -561,6 +675,25 @@ fn init_logging() {
         .init();
 }
 
+/// Boss definitions indexed by area, loaded as the log enters each area
+struct AreaDefinitions {
+    index: AreaIndex,
+    user_dir: Option<PathBuf>,
+    dictionary: Option<IdDictionary>,
+}
+
+impl AreaDefinitions {
+    fn load(&self, area_id: i64) -> Option<Vec<BossEncounterDefinition>> {
+        let mut bosses = load_indexed_area(&self.index, area_id, self.user_dir.as_deref())?;
+        if let Some(dictionary) = &self.dictionary {
+            for boss in &mut bosses {
+                dictionary.expand_renamed_selectors(boss);
+            }
+        }
+        Some(bosses)
+    }
+}
+
 fn main() {
     // Initialize tracing subscriber (parse-worker is separate process, needs its own)
     init_logging();
@@ -584,58 +717,28 @@ fn main() {
         std::process::exit(1);
     }
 
-    // Load boss definitions from bundled dir (passed as arg) and user config dir
-    let mut boss_definitions: Vec<BossEncounterDefinition> = Vec::new();
-
-    // 1. Load from bundled directory
-    if let Some(ref dir) = definitions_dir {
-        match load_bosses_from_dir(dir) {
-            Ok(bosses) => {
-                tracing::debug!(count = bosses.len(), "Loaded bundled boss definitions");
-                boss_definitions = bosses;
-            }
-            Err(e) => {
-                tracing::warn!(error = %e, "Failed to load bundled definitions");
-            }
-        }
-    }
+    // Boss definitions load per area as the log enters it, exactly like the live
+    // service: bundled and user files indexed by area, with custom overlays merged
+    let user_dir = user_encounters_dir();
+    let area_index = build_layered_area_index(definitions_dir.as_deref(), user_dir.as_deref());
+    tracing::debug!(areas = area_index.len(), "Indexed encounter definitions");
+
+    // Let name selectors match abilities and effects renamed since they were written
+    let dictionary = id_dictionary_path().and_then(|path| {
+        IdDictionary::load(&path)
+            .inspect_err(|e| tracing::warn!(error = %e, "Failed to load ID dictionary"))
+            .ok()
+    });
 
-    // 2. Load from user config directory (standalone + overlay user encounters)
-    if let Some(user_dir) =
-        dirs::config_dir().map(|p| p.join("baras").join("definitions").join("encounters"))
-        && user_dir.exists()
-    {
-        match load_bosses_from_dir(&user_dir) {
-            Ok(user_bosses) => {
-                if !user_bosses.is_empty() {
-                    tracing::debug!(count = user_bosses.len(), "Loaded user boss definitions");
-                    // Merge: field-level merge for existing bosses, append new ones
-                    for user_boss in user_bosses {
-                        if let Some(existing) =
-                            boss_definitions.iter_mut().find(|b| b.id == user_boss.id)
-                        {
-                            // Field-level merge: timers, phases, entities, etc. by ID
-                            merge_boss_definition(existing, user_boss);
-                        } else {
-                            // New standalone boss - just add it
-                            boss_definitions.push(user_boss);
-                        }
-                    }
-                    // Rebuild indexes after merge (entities may have been added)
-                    for boss in &mut boss_definitions {
-                        boss.build_indexes();
-                    }
-                }
-            }
-            Err(e) => {
-                tracing::warn!(error = %e, "Failed to load user definitions");
-            }
-        }
-    }
+    let definitions = AreaDefinitions {
+        index: area_index,
+        user_dir,
+        dictionary,
+    };
 
     let timer = std::time::Instant::now();
 
-    match parse_file(&file_path, session_id, &output_dir, boss_definitions) {
+    match parse_file(&file_path, session_id, &output_dir, &definitions) {
         Ok(output) => {
             let mut output = output;
             output.elapsed_ms = timer.elapsed().as_millis();
@@ -656,7 +759,7 @@ fn parse_file(
     file_path: &Path,
     _session_id: &str,
     output_dir: &Path,
-    boss_definitions: Vec<BossEncounterDefinition>,
+    definitions: &AreaDefinitions,
 ) -> Result<ParseOutput, String> {
     // Extract session date from filename
     let date_stamp = file_path
@@ -672,10 +775,19 @@ fn parse_file(
     let bytes = mmap.as_ref();
     let end_pos = bytes.len() as u64;
 
+    // Newest-first mode starts at the area the final combat happened in
+    let start_pos = if recent_only() {
+        recent_area_start(bytes).unwrap_or(0)
+    } else {
+        0
+    };
+    // Keep line numbers identical to a parse of the whole file
+    let first_line = count_lines_before(bytes, start_pos);
+
     // Find line boundaries
     let mut line_ranges: Vec<(usize, usize)> = Vec::new();
-    let mut start = 0;
-    for end in memchr_iter(b'\n', bytes) {
+    let mut start = start_pos;
+    for end in memchr_iter(b'\n', &bytes[start_pos..]).map(|i| start_pos + i) {
         if end > start {
             line_ranges.push((start, end));
         }
@@ -687,23 +799,35 @@ fn parse_file(
 
     // Parallel parse
     let parser = LogParser::new(date_stamp);
-    let events: Vec<CombatEvent> = line_ranges
+    let mut events: Vec<CombatEvent> = line_ranges
         .par_iter()
         .enumerate()
         .filter_map(|(idx, &(start, end))| {
-            let (line, _, _) = WINDOWS_1252.decode(&bytes[start..end]);
-            parser.parse_line(idx as u64 + 1, &line)
+            parser
+                .parse_raw_line(first_line + idx as u64 + 1, &bytes[start..end])
+                .map(|event| event.at_log_range(start as u64, (end + 1).min(bytes.len()) as u64))
         })
         .collect();
 
+    // Dates depend on the lines before, so they're resolved in order
+    let mut clock = parser.clock();
+    for event in &mut events {
+        clock.apply(event);
+    }
+
     let event_count = events.len();
+    let last_timestamp = events
+        .last()
+        .map(|e| e.timestamp.format("%Y-%m-%dT%H:%M:%S%.3f").to_string());
 
     // Process events and write encounters
     let (encounters, player, area, player_disciplines) =
-        process_and_write_encounters(events, output_dir, boss_definitions)?;
+        process_and_write_encounters(events, output_dir, definitions)?;
 
     Ok(ParseOutput {
+        start_pos: start_pos as u64,
         end_pos,
+        last_timestamp,
         event_count,
         encounter_count: encounters.len(),
         encounters,
@@ -717,7 +841,7 @@ fn parse_file(
 fn process_and_write_encounters(
     events: Vec<CombatEvent>,
     output_dir: &Path,
-    boss_definitions: Vec<BossEncounterDefinition>,
+    definitions: &AreaDefinitions,
 ) -> Result<
     (
         Vec<EncounterSummary>,
@@ -727,15 +851,8 @@ fn process_and_write_encounters(
     ),
     String,
 > {
-    use std::sync::mpsc;
-
-    // Spawn background writer thread
-    let (tx, rx) = mpsc::channel::<(RecordBatch, PathBuf)>();
-    let writer_thread = std::thread::spawn(move || {
-        for (batch, path) in rx {
-            let _ = FastEncounterWriter::write_batch_to_file(batch, path);
-        }
-    });
+    // Spawn background writer threads
+    let writers = WriterPool::new(writer_thread_count());
 
     let mut cache = SessionCache::new();
     let mut processor = EventProcessor::new();
@@ -744,39 +861,85 @@ fn process_and_write_encounters(
     let mut pending_write = false;
     let output_dir = output_dir.to_path_buf();
 
-    cache.load_boss_definitions(boss_definitions);
+    let mut loaded_area_id: i64 = 0;
+
+    // A backfill only journals what the recent parse didn't cover
+    let journal_until = backfill_until();
+    let journal_name = match journal_until {
+        Some(_) => BACKFILL_JOURNAL_FILENAME,
+        None => JOURNAL_FILENAME,
+    };
+
+    // Journal failures only lose the session timeline, not the parse
+    let mut journal = SignalJournal::create(output_dir.join(journal_name))
+        .inspect_err(|e| tracing::warn!(error = %e, "Failed to create signal journal"))
+        .ok();
+
+    let ignore_list = ignore_list();
+    let mut id_dictionary = IdDictionary::new();
 
     for event in events {
+        if ignore_list.matches(&event) {
+            continue;
+        }
+        // Load definitions before the event is processed, as the live session does
+        if event.effect.type_id == effect_type_id::AREAENTERED {
+            let area_id = event.effect.effect_id;
+            if area_id != 0 && area_id != loaded_area_id {
+                if let Some(bosses) = definitions.load(area_id) {
+                    tracing::debug!(area_id, count = bosses.len(), "Loaded boss definitions");
+                    cache.load_boss_definitions(bosses);
+                }
+                loaded_area_id = area_id;
+            }
+        }
+        let journaled = journal_until.is_none_or(|until| event.byte_offset < until);
         let (signals, event) = processor.process_event(event, &mut cache);
         writer.append_event(&event, &cache, current_encounter_idx);
+        id_dictionary.record_event(&event, &cache.current_area.area_name);
 
         for signal in &signals {
+            if journaled
+                && let Some(journal) = &mut journal
+                && let Err(e) = journal.record(signal)
+            {
+                tracing::warn!(error = %e, "Failed to write signal journal");
+            }
             if let GameSignal::CombatEnded { .. } = signal {
                 pending_write = true;
             }
         }
 
         if pending_write {
-            if let Some(batch) = writer.take_batch() {
-                let filename = encounter_filename(current_encounter_idx);
-                let path = output_dir.join(&filename);
-                let _ = tx.send((batch, path));
+            // The encounter that just ended (a fresh one is already pushed on CombatEnded)
+            let key = cache.last_combat_encounter().and_then(|e| e.encounter_key());
+            if let Some(batch) = writer.take_batch()
+                && let Some(key) = key
+            {
+                let path = output_dir.join(encounter_filename(&key));
+                writers.submit(current_encounter_idx, batch, path);
                 current_encounter_idx += 1;
             }
             pending_write = false;
         }
     }
 
-    // Send any remaining events (final incomplete encounter)
-    if let Some(batch) = writer.take_batch() {
-        let filename = encounter_filename(current_encounter_idx);
-        let path = output_dir.join(&filename);
-        let _ = tx.send((batch, path));
+    // Send any remaining events (final encounter, still in combat at end of file)
+    let key = cache.current_encounter().and_then(|e| e.encounter_key());
+    if let Some(batch) = writer.take_batch()
+        && let Some(key) = key
+    {
+        let path = output_dir.join(encounter_filename(&key));
+        writers.submit(current_encounter_idx, batch, path);
     }
 
-    // Close channel and wait for writer thread to finish
-    drop(tx);
-    let _ = writer_thread.join();
+    // Close channels and wait for writer threads to finish
+    writers.finish();
+    drop(journal);
+
+    if let Err(e) = id_dictionary.save(&output_dir.join(ID_DICTIONARY_FILENAME)) {
+        tracing::warn!(error = %e, "Failed to write ID dictionary");
+    }
 
     let encounter_summaries: Vec<EncounterSummary> = cache.encounter_history.summaries().to_vec();
 
@@ -68,6 +68,13 @@ pub struct BreakdownMode {
     pub by_target_type: bool,
     /// Group by target/source instance (log_id) - context depends on DataTab
     pub by_target_instance: bool,
+    /// Merge same-named NPCs into one row in the entity list instead of
+    /// listing each instance ("Monolith Tentacle #3") separately
+    #[serde(default)]
+    pub group_instances: bool,
+    /// Leave out reflected damage (see `is_reflect` in the events schema)
+    #[serde(default)]
+    pub exclude_reflect: bool,
 }
 
 impl BreakdownMode {
@@ -76,6 +83,8 @@ impl BreakdownMode {
             by_ability: true,
             by_target_type: false,
             by_target_instance: false,
+            group_instances: false,
+            exclude_reflect: false,
         }
     }
 }
@@ -98,6 +107,10 @@ pub struct AbilityBreakdown {
     /// First hit time in seconds (for distinguishing target instances)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub target_first_hit_secs: Option<f32>,
+    /// Instance number among same-named NPCs, in order of first appearance
+    /// (only set when the encounter has more than one of them)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_instance: Option<u32>,
 
     // Metrics
     pub total_value: f64,
@@ -122,6 +135,19 @@ pub struct EntityBreakdown {
     pub entity_type: String, // "Player", "Npc", "Companion"
     pub total_value: f64,
     pub abilities_used: i64,
+    /// Instance number among same-named NPCs (see [`AbilityBreakdown::target_instance`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<u32>,
+}
+
+impl EntityBreakdown {
+    /// Name with the instance number appended, e.g. "Monolith Tentacle #3"
+    pub fn display_name(&self) -> String {
+        match self.instance {
+            Some(n) => format!("{} #{}", self.source_name, n),
+            None => self.source_name.clone(),
+        }
+    }
 }
 
 /// Raid overview row - aggregated stats per player across all metrics.
@@ -162,6 +188,10 @@ pub struct RaidOverviewRow {
     pub ehps: f64,
     /// Percentage of total raid effective healing
     pub healing_pct: f64,
+
+    // Deaths
+    #[serde(default)]
+    pub deaths: i64,
 }
 
 /// Query result for time-series data (DPS/HPS over time).
@@ -171,6 +201,17 @@ pub struct TimeSeriesPoint {
     pub total_value: f64,
 }
 
+/// A single hit at or above the big-hit threshold (for chart annotations).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BigHit {
+    /// Time of the hit in seconds from combat start
+    pub combat_time_secs: f32,
+    pub ability_name: String,
+    pub amount: f64,
+    pub source_name: String,
+    pub target_name: String,
+}
+
 /// Time window when an effect was active (for chart highlighting).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EffectWindow {
@@ -252,7 +293,7 @@ pub struct CombatLogRow {
 }
 
 /// Filter options for combat log event types.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CombatLogFilters {
     /// Show damage events
     pub damage: bool,
@@ -324,6 +365,644 @@ impl TimeRange {
     }
 }
 
+/// File format for Data Explorer table exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    /// File extension without the dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    /// Display label for file dialogs and buttons
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+        }
+    }
+}
+
+/// A Data Explorer table to export, with the same parameters as its query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExportTable {
+    /// Ability breakdown (see `query_breakdown`)
+    Breakdown {
+        tab: DataTab,
+        entity_name: Option<String>,
+        time_range: Option<TimeRange>,
+        entity_types: Option<Vec<String>>,
+        breakdown_mode: Option<BreakdownMode>,
+        duration_secs: Option<f32>,
+    },
+    /// Per-entity totals (see `query_entity_breakdown`)
+    EntityBreakdown {
+        tab: DataTab,
+        time_range: Option<TimeRange>,
+        breakdown_mode: Option<BreakdownMode>,
+    },
+    /// Raid overview rows
+    RaidOverview {
+        time_range: Option<TimeRange>,
+        duration_secs: Option<f32>,
+    },
+    /// Combat log rows. `limit: None` exports every row from `offset` on.
+    CombatLog {
+        offset: u64,
+        limit: Option<u64>,
+        source_filter: Option<String>,
+        target_filter: Option<String>,
+        search_filter: Option<String>,
+        time_range: Option<TimeRange>,
+        event_filters: Option<CombatLogFilters>,
+    },
+    /// Phase segments of the encounter timeline
+    Timeline,
+}
+
+/// Per-player DPS and deaths in two pulls (`None` when absent from a pull).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerPullDelta {
+    pub name: String,
+    pub dps_a: Option<f64>,
+    pub dps_b: Option<f64>,
+    pub deaths_a: u32,
+    pub deaths_b: u32,
+}
+
+impl PlayerPullDelta {
+    /// DPS change from pull A to pull B (missing pulls count as 0)
+    pub fn dps_delta(&self) -> f64 {
+        self.dps_b.unwrap_or(0.0) - self.dps_a.unwrap_or(0.0)
+    }
+}
+
+/// Duration of one phase occurrence in two pulls.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhasePullDelta {
+    pub phase_id: String,
+    pub phase_name: String,
+    pub instance: i64,
+    pub duration_a: Option<f32>,
+    pub duration_b: Option<f32>,
+}
+
+/// Boss ability hits taken by players in two pulls.
+///
+/// Damage from NPC abilities is used as the mechanic-failure signal:
+/// more hits from the same ability usually means a mechanic was handled worse.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MechanicPullDelta {
+    pub ability_id: i64,
+    pub ability_name: String,
+    pub hits_a: u32,
+    pub hits_b: u32,
+    pub damage_a: f64,
+    pub damage_b: f64,
+}
+
+/// Side-by-side comparison of two pulls of the same boss.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PullComparison {
+    pub duration_a: f32,
+    pub duration_b: f32,
+    pub players: Vec<PlayerPullDelta>,
+    pub phases: Vec<PhasePullDelta>,
+    pub mechanics: Vec<MechanicPullDelta>,
+}
+
+/// One player's stats across a set of pulls.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PlayerAggregate {
+    pub name: String,
+    /// Pulls the player took part in
+    pub pulls: u32,
+    pub avg_dps: f64,
+    pub best_dps: f64,
+    /// Index of the pull with `best_dps`, in the order the pulls were given
+    pub best_pull: usize,
+    /// 100 minus the DPS coefficient of variation (floored at 0): 100 means the
+    /// same DPS on every pull
+    pub consistency_pct: f64,
+    pub avg_ehps: f64,
+    pub deaths: u32,
+}
+
+/// Per-player statistics over several pulls (e.g. every pull of a boss tonight).
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SessionAggregate {
+    pub pull_count: u32,
+    /// Combat time of all pulls together
+    pub total_duration_secs: f32,
+    /// Ordered by average DPS
+    pub players: Vec<PlayerAggregate>,
+}
+
+/// One ability or entity in a breakdown of two pulls side by side.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BreakdownDelta {
+    /// Ability ID (None for entity rows, which are matched by name)
+    pub ability_id: Option<i64>,
+    pub name: String,
+    pub total_a: f64,
+    pub total_b: f64,
+    /// Per-second rate over each pull's duration
+    pub rate_a: f64,
+    pub rate_b: f64,
+}
+
+impl BreakdownDelta {
+    pub fn total_delta(&self) -> f64 {
+        self.total_b - self.total_a
+    }
+
+    pub fn rate_delta(&self) -> f64 {
+        self.rate_b - self.rate_a
+    }
+}
+
+/// A time bucket with values from both pulls of a comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeSeriesPairPoint {
+    pub bucket_start_ms: i64,
+    pub value_a: f64,
+    pub value_b: f64,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Raid Plan (healer assignments)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A healer and the players they are assigned to heal (e.g. a tank or a group).
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct HealerAssignment {
+    pub healer: String,
+    #[serde(default)]
+    pub targets: Vec<String>,
+}
+
+/// Healer assignments per boss, stored in `raid_plan.toml`.
+///
+/// Bosses are keyed by boss name as shown in the encounter history.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct RaidPlan {
+    #[serde(default)]
+    pub bosses: HashMap<String, Vec<HealerAssignment>>,
+}
+
+impl RaidPlan {
+    /// Assignments for a boss (case insensitive), empty if none
+    pub fn assignments_for(&self, boss_name: &str) -> &[HealerAssignment] {
+        self.bosses
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(boss_name))
+            .map(|(_, assignments)| assignments.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+/// How much of a healer's healing in a pull landed on their assigned targets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignmentCheck {
+    pub healer: String,
+    pub targets: Vec<String>,
+    /// Effective healing done to the assigned targets
+    pub assigned_healing: f64,
+    /// Effective healing done to everyone
+    pub total_healing: f64,
+}
+
+impl AssignmentCheck {
+    /// Percentage of the healer's healing on assigned targets (0 when they did no healing)
+    pub fn assigned_pct(&self) -> f64 {
+        if self.total_healing > 0.0 {
+            self.assigned_healing * 100.0 / self.total_healing
+        } else {
+            0.0
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Command Errors (shared between backend and frontend)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Broad category of a backend error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ErrorKind {
+    /// File system and storage failures
+    Io,
+    /// Combat log parsing failures
+    Parse,
+    /// Encounter, timer and effect definition problems
+    Definition,
+    /// Data explorer queries
+    Query,
+    /// Windowing, overlays and other OS integration
+    Platform,
+}
+
+impl ErrorKind {
+    /// Short label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Io => "File error",
+            Self::Parse => "Parse error",
+            Self::Definition => "Definition error",
+            Self::Query => "Query error",
+            Self::Platform => "System error",
+        }
+    }
+}
+
+/// Error returned by Tauri commands: a category plus a user-facing message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Session Events (backend → frontend IPC)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Tauri event name carrying a [`SessionEvent`] payload
+pub const SESSION_UPDATED_EVENT: &str = "session-updated";
+
+/// Session state change emitted on [`SESSION_UPDATED_EVENT`].
+/// Serialized as `{ "kind": "CombatEnded" }`; listeners refetch what they need.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum SessionEvent {
+    CombatStarted,
+    CombatEnded,
+    AreaChanged,
+    PlayerInitialized,
+    /// Switched between live tailing and a historical file
+    TailingModeChanged,
+    /// Directory watcher (re)started
+    WatcherStarted,
+    /// A log file finished its initial parse
+    FileLoaded,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Session Timeline
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// What a [`TimelineEvent`] marks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimelineEventKind {
+    CombatStarted,
+    CombatEnded,
+    AreaChanged,
+    PlayerDeath,
+    PhaseChanged,
+}
+
+/// One marker on the session timeline, read back from the signal journal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineEvent {
+    pub kind: TimelineEventKind,
+    /// Game time of the event (HH:MM:SS)
+    pub time: String,
+    /// Seconds since the first recorded signal of the session
+    pub offset_secs: f64,
+    /// Area name, player name, phase ID, ... (empty for combat start/end)
+    pub label: String,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Encounter History
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A finished encounter kept in the persistent history across restarts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoricalEncounter {
+    /// Stable key of the encounter (see `baras_core::storage::encounter_key`)
+    pub encounter_key: String,
+    /// Combat log file the encounter was parsed from
+    pub log_file: String,
+    pub display_name: String,
+    /// ISO 8601 start time
+    pub start_time: Option<String>,
+    pub duration_secs: i64,
+    pub area_name: String,
+    pub difficulty: Option<String>,
+    pub boss_name: Option<String>,
+    pub success: bool,
+    /// Local player's character and discipline in this encounter
+    pub player_name: String,
+    pub discipline: Option<String>,
+    /// Local player's DPS
+    pub dps: i64,
+}
+
+/// Filters for historical encounter queries; unset fields match everything
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HistoryFilter {
+    /// Boss name (case-insensitive)
+    pub boss: Option<String>,
+    /// Difficulty name as shown in the encounter history
+    pub difficulty: Option<String>,
+    /// First day included (YYYY-MM-DD)
+    pub from_date: Option<String>,
+    /// Last day included (YYYY-MM-DD)
+    pub to_date: Option<String>,
+    /// Skip trash pulls
+    pub bosses_only: bool,
+    /// Only the most recent N encounters
+    pub limit: Option<usize>,
+}
+
+impl HistoryFilter {
+    pub fn matches(&self, encounter: &HistoricalEncounter) -> bool {
+        let day = encounter.start_time.as_deref().and_then(|t| t.get(..10));
+        let same = |wanted: &Option<String>, value: Option<&str>| {
+            wanted
+                .as_deref()
+                .is_none_or(|w| value.is_some_and(|v| v.eq_ignore_ascii_case(w)))
+        };
+
+        same(&self.boss, encounter.boss_name.as_deref())
+            && same(&self.difficulty, encounter.difficulty.as_deref())
+            && !(self.bosses_only && encounter.boss_name.is_none())
+            // ISO dates compare in chronological order
+            && self
+                .from_date
+                .as_deref()
+                .is_none_or(|from| day.is_some_and(|d| d >= from))
+            && self
+                .to_date
+                .as_deref()
+                .is_none_or(|to| day.is_some_and(|d| d <= to))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Persistent Counters
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Boss pull outcome counted by a [`CounterRule`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CounterOutcome {
+    #[default]
+    Kill,
+    Wipe,
+    /// Every pull, kill or wipe
+    Any,
+}
+
+impl CounterOutcome {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Kill => "Kills",
+            Self::Wipe => "Wipes",
+            Self::Any => "Pulls",
+        }
+    }
+
+    pub fn all() -> &'static [CounterOutcome] {
+        &[Self::Kill, Self::Wipe, Self::Any]
+    }
+}
+
+/// A long-term counter (e.g. total Revan kills) and the boss pulls that
+/// increment it. Trash pulls never count.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CounterRule {
+    /// Key the count is stored under
+    pub id: String,
+    /// Label on the personal overlay
+    pub label: String,
+    /// Boss name (case-insensitive); unset counts every boss
+    #[serde(default)]
+    pub boss: Option<String>,
+    /// Difficulty name as shown in the encounter history; unset for any
+    #[serde(default)]
+    pub difficulty: Option<String>,
+    #[serde(default)]
+    pub outcome: CounterOutcome,
+}
+
+impl CounterRule {
+    /// Whether a finished pull increments this counter
+    pub fn matches(
+        &self,
+        boss_name: Option<&str>,
+        difficulty: Option<&str>,
+        success: bool,
+    ) -> bool {
+        let Some(boss_name) = boss_name else {
+            return false;
+        };
+        let same = |wanted: &Option<String>, value: Option<&str>| {
+            wanted
+                .as_deref()
+                .is_none_or(|w| value.is_some_and(|v| v.eq_ignore_ascii_case(w)))
+        };
+
+        same(&self.boss, Some(boss_name))
+            && same(&self.difficulty, difficulty)
+            && match self.outcome {
+                CounterOutcome::Kill => success,
+                CounterOutcome::Wipe => !success,
+                CounterOutcome::Any => true,
+            }
+    }
+}
+
+/// Current value of a persistent counter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CounterValue {
+    pub id: String,
+    pub label: String,
+    pub count: u64,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Definition Coverage
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Payload of the opt-in definition coverage report.
+///
+/// Contains boss definition and timer IDs with counts only: no character,
+/// guild or log data.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub app_version: String,
+    /// Local date counting started (YYYY-MM-DD)
+    pub since: Option<String>,
+    pub encounters: Vec<EncounterCoverageReport>,
+}
+
+/// Live pulls of one boss definition and how often its timers started
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncounterCoverageReport {
+    pub definition_id: String,
+    pub pulls: u32,
+    pub timers: Vec<TimerCoverageReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimerCoverageReport {
+    pub timer_id: String,
+    /// Number of starts (0 = the timer never matched)
+    pub starts: u32,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Setup Health
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SetupCheckStatus {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// Result of one startup check on the combat log setup
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetupCheck {
+    pub name: String,
+    pub status: SetupCheckStatus,
+    /// What was found and, for problems, how to fix it
+    pub message: String,
+}
+
+/// Startup diagnostics for the combat log directory
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SetupHealth {
+    pub checks: Vec<SetupCheck>,
+}
+
+impl SetupHealth {
+    /// Checks that did not pass
+    pub fn problems(&self) -> impl Iterator<Item = &SetupCheck> {
+        self.checks
+            .iter()
+            .filter(|c| c.status != SetupCheckStatus::Ok)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Area Coverage
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Operation entered in the user's log history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObservedArea {
+    pub area_id: i64,
+    pub name: String,
+    /// Difficulties entered, e.g. "8 Player Veteran"
+    pub difficulties: Vec<String>,
+    /// Number of times the area was entered
+    pub visits: u32,
+    /// Date of the last visit (YYYY-MM-DD)
+    pub last_seen: String,
+    /// Bundled or user boss definitions exist for the area
+    pub has_definitions: bool,
+}
+
+/// Operations from the log history checked against the definitions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AreaCoverageReport {
+    pub scanned_files: usize,
+    /// Most visited first
+    pub areas: Vec<ObservedArea>,
+}
+
+impl AreaCoverageReport {
+    /// Operations the user runs that have no definitions
+    pub fn uncovered(&self) -> impl Iterator<Item = &ObservedArea> {
+        self.areas.iter().filter(|a| !a.has_definitions)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Game ID Database
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// What a game ID refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameIdKind {
+    Npc,
+    Ability,
+    Effect,
+}
+
+impl GameIdKind {
+    /// Key used in the ID database files
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Npc => "npc",
+            Self::Ability => "ability",
+            Self::Effect => "effect",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "npc" => Some(Self::Npc),
+            "ability" => Some(Self::Ability),
+            "effect" => Some(Self::Effect),
+            _ => None,
+        }
+    }
+}
+
+/// A named game ID, for pick-lists in the encounter editor
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameIdEntry {
+    pub kind: GameIdKind,
+    pub id: i64,
+    pub name: String,
+}
+
+/// A name a game ID was logged under
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdNameRecord {
+    pub name: String,
+    /// Date the ID was first logged under this name (YYYY-MM-DD)
+    pub first_seen: String,
+    /// Area the ID was first logged in under this name
+    pub context: String,
+}
+
+/// Every name a game ID was logged under, oldest first
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdDictionaryEntry {
+    pub kind: GameIdKind,
+    pub id: i64,
+    pub names: Vec<IdNameRecord>,
+}
+
+impl IdDictionaryEntry {
+    /// Most recently logged name
+    pub fn current_name(&self) -> Option<&str> {
+        self.names.last().map(|r| r.name.as_str())
+    }
+
+    /// True if the game renamed the ID (e.g. in a patch)
+    pub fn is_renamed(&self) -> bool {
+        self.names.len() > 1
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Color Type
 // ─────────────────────────────────────────────────────────────────────────────
@@ -454,6 +1133,47 @@ impl EntityMatcher {
     }
 }
 
+/// Comparison operators for counter conditions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOp {
+    #[default]
+    Eq,
+    Lt,
+    Gt,
+    Lte,
+    Gte,
+    Ne,
+}
+
+impl ComparisonOp {
+    pub fn evaluate(&self, left: u32, right: u32) -> bool {
+        match self {
+            Self::Eq => left == right,
+            Self::Lt => left < right,
+            Self::Gt => left > right,
+            Self::Lte => left <= right,
+            Self::Gte => left >= right,
+            Self::Ne => left != right,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Lt => "<",
+            Self::Gt => ">",
+            Self::Lte => "<=",
+            Self::Gte => ">=",
+            Self::Ne => "!=",
+        }
+    }
+
+    pub fn all() -> &'static [ComparisonOp] {
+        &[Self::Eq, Self::Lt, Self::Gt, Self::Lte, Self::Gte, Self::Ne]
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Trigger Types (shared across timers, phases, counters)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -487,6 +1207,26 @@ pub enum Trigger {
         target: EntityFilter,
     },
 
+    /// Ability cast begins (`AbilityActivate`). [T only]
+    CastStarted {
+        #[serde(default)]
+        abilities: Vec<AbilitySelector>,
+        #[serde(default)]
+        source: EntityFilter,
+        #[serde(default)]
+        target: EntityFilter,
+    },
+
+    /// Ability cast finishes without interruption (`AbilityDeactivate`). [T only]
+    CastCompleted {
+        #[serde(default)]
+        abilities: Vec<AbilitySelector>,
+        #[serde(default)]
+        source: EntityFilter,
+        #[serde(default)]
+        target: EntityFilter,
+    },
+
     /// Effect/buff is applied. [TPC]
     EffectApplied {
         #[serde(default)]
@@ -588,6 +1328,28 @@ pub enum Trigger {
     // ─── Composition [TPC] ─────────────────────────────────────────────────
     /// Any condition suffices (OR logic). [TPC]
     AnyOf { conditions: Vec<Trigger> },
+
+    /// Every condition must occur within `window_secs` of each other (AND logic). [T only]
+    AllOf {
+        conditions: Vec<Trigger>,
+        #[serde(default)]
+        window_secs: f32,
+    },
+
+    /// Vetoes an `all_of` if the condition occurred within its window. [T only]
+    Not { condition: Box<Trigger> },
+
+    // ─── State Conditions [T only, inside all_of] ──────────────────────────
+    /// A phase is currently active. [T only]
+    PhaseActive { phase_id: String },
+
+    /// A counter currently compares true against a value. [T only]
+    CounterIs {
+        counter_id: String,
+        #[serde(default)]
+        operator: ComparisonOp,
+        value: u32,
+    },
 }
 
 impl Trigger {
@@ -597,6 +1359,8 @@ impl Trigger {
             Self::CombatStart => "Combat Start",
             Self::CombatEnd => "Combat End",
             Self::AbilityCast { .. } => "Ability Cast",
+            Self::CastStarted { .. } => "Cast Started",
+            Self::CastCompleted { .. } => "Cast Completed",
             Self::EffectApplied { .. } => "Effect Applied",
             Self::EffectRemoved { .. } => "Effect Removed",
             Self::DamageTaken { .. } => "Damage Taken",
@@ -615,6 +1379,10 @@ impl Trigger {
             Self::Manual => "Manual",
             Self::Never => "Never",
             Self::AnyOf { .. } => "Any Of (OR)",
+            Self::AllOf { .. } => "All Of (AND)",
+            Self::Not { .. } => "Not",
+            Self::PhaseActive { .. } => "Phase Active",
+            Self::CounterIs { .. } => "Counter Is",
         }
     }
 
@@ -624,6 +1392,8 @@ impl Trigger {
             Self::CombatStart => "combat_start",
             Self::CombatEnd => "combat_end",
             Self::AbilityCast { .. } => "ability_cast",
+            Self::CastStarted { .. } => "cast_started",
+            Self::CastCompleted { .. } => "cast_completed",
             Self::EffectApplied { .. } => "effect_applied",
             Self::EffectRemoved { .. } => "effect_removed",
             Self::DamageTaken { .. } => "damage_taken",
@@ -642,6 +1412,10 @@ impl Trigger {
             Self::Manual => "manual",
             Self::Never => "never",
             Self::AnyOf { .. } => "any_of",
+            Self::AllOf { .. } => "all_of",
+            Self::Not { .. } => "not",
+            Self::PhaseActive { .. } => "phase_active",
+            Self::CounterIs { .. } => "counter_is",
         }
     }
 }
@@ -660,6 +1434,13 @@ pub mod overlay_colors {
     pub const TPS: Color = [50, 100, 180, 255]; // Blue
     pub const DTPS: Color = [180, 80, 80, 255]; // Dark red
     pub const ABS: Color = [100, 150, 200, 255]; // Light blue
+    pub const DISPELS: Color = [160, 90, 200, 255]; // Purple
+    pub const TANK_SWAP_STACKS: Color = [220, 150, 40, 255]; // Amber
+    pub const COOLDOWN_READY: Color = [60, 160, 80, 255]; // Green
+    pub const COOLDOWN_ACTIVE: Color = [70, 70, 70, 255]; // Gray
+    pub const RECAP_DAMAGE: Color = [230, 110, 100, 255]; // Soft red
+    pub const RECAP_HEAL: Color = [110, 210, 120, 255]; // Soft green
+    pub const NOTES_HEADING: Color = [120, 180, 240, 255]; // Light blue
     pub const BOSS_BAR: Color = [200, 50, 50, 255]; // Boss health red
     pub const FRAME_BG: Color = [40, 40, 40, 200]; // Raid frame background
 
@@ -671,6 +1452,7 @@ pub mod overlay_colors {
             "tps" => TPS,
             "dtps" | "edtps" => DTPS,
             "abs" => ABS,
+            "dispels" => DISPELS,
             _ => DPS,
         }
     }
@@ -690,6 +1472,60 @@ fn default_scaling_factor() -> f32 {
     1.0
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Number Format
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// How large numbers are written on overlays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberStyle {
+    /// K/M suffix from 10,000 up (`23.41K`)
+    #[default]
+    Compact,
+    /// Every digit with thousands separators (`23,412`)
+    Full,
+}
+
+impl NumberStyle {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Compact => "Compact (23.4K)",
+            Self::Full => "Full (23,412)",
+        }
+    }
+
+    pub fn all() -> &'static [NumberStyle] {
+        &[Self::Compact, Self::Full]
+    }
+}
+
+/// Style and precision of a displayed stat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumberFormat {
+    #[serde(default)]
+    pub style: NumberStyle,
+    /// Digits after the decimal point (K/M values only in compact style)
+    #[serde(default = "default_number_decimals")]
+    pub decimals: u8,
+}
+
+/// Most decimals a [`NumberFormat`] can ask for
+pub const MAX_NUMBER_DECIMALS: u8 = 3;
+
+fn default_number_decimals() -> u8 {
+    2
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            style: NumberStyle::Compact,
+            decimals: default_number_decimals(),
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Overlay Appearance Config
 // ─────────────────────────────────────────────────────────────────────────────
@@ -717,6 +1553,18 @@ pub struct OverlayAppearanceConfig {
     pub show_percent: bool,
     #[serde(default = "default_true")]
     pub show_duration: bool,
+    /// Show a death count badge after player names
+    #[serde(default)]
+    pub show_deaths: bool,
+    /// Show the reflected share of damage next to the rate
+    #[serde(default)]
+    pub show_reflect: bool,
+    /// How values, totals and the footer are written
+    #[serde(default)]
+    pub number_format: NumberFormat,
+    /// TPS meter: show threat on the current target (or the boss) only
+    #[serde(default)]
+    pub current_target_only: bool,
 }
 
 fn default_font_color() -> Color {
@@ -742,6 +1590,10 @@ impl Default for OverlayAppearanceConfig {
             show_per_second: true,
             show_percent: true,
             show_duration: true,
+            show_deaths: false,
+            show_reflect: false,
+            number_format: NumberFormat::default(),
+            current_target_only: false,
         }
     }
 }
@@ -776,6 +1628,8 @@ pub enum PersonalStat {
     Hps,
     EHps,
     TotalHealing,
+    /// Effect shielding given per second (Static Barrier, etc.)
+    Aps,
     Dtps,
     Tps,
     TotalThreat,
@@ -787,6 +1641,12 @@ pub enum PersonalStat {
     Phase,
     /// Time in current phase
     PhaseTime,
+    /// Estimated time until the next HP-triggered phase
+    NextPhaseEta,
+    /// Time left before the boss's hard enrage
+    TimeToEnrage,
+    /// Persistent cross-session counters, one line each
+    Counters,
 }
 
 impl PersonalStat {
@@ -806,6 +1666,7 @@ impl PersonalStat {
             Self::Hps => "HPS",
             Self::EHps => "eHPS",
             Self::TotalHealing => "Total Healing",
+            Self::Aps => "APS",
             Self::Dtps => "eDTPS",
             Self::Tps => "TPS",
             Self::TotalThreat => "Total Threat",
@@ -815,9 +1676,32 @@ impl PersonalStat {
             Self::ClassDiscipline => "Spec",
             Self::Phase => "Phase",
             Self::PhaseTime => "Phase Time",
+            Self::NextPhaseEta => "Next Phase ETA",
+            Self::TimeToEnrage => "Time to Enrage",
+            Self::Counters => "Counters",
         }
     }
 
+    /// Whether the stat is a damage/healing/threat amount or rate, written
+    /// with a [`NumberFormat`]
+    pub fn is_amount(&self) -> bool {
+        matches!(
+            self,
+            Self::Dps
+                | Self::EDps
+                | Self::BossDps
+                | Self::TotalDamage
+                | Self::BossDamage
+                | Self::Hps
+                | Self::EHps
+                | Self::TotalHealing
+                | Self::Aps
+                | Self::Dtps
+                | Self::Tps
+                | Self::TotalThreat
+        )
+    }
+
     /// Get all stats in display order
     pub fn all() -> &'static [PersonalStat] {
         &[
@@ -835,6 +1719,7 @@ impl PersonalStat {
             Self::Hps,
             Self::EHps,
             Self::TotalHealing,
+            Self::Aps,
             Self::Dtps,
             Self::Tps,
             Self::TotalThreat,
@@ -843,6 +1728,9 @@ impl PersonalStat {
             Self::EffectiveHealPct,
             Self::Phase,
             Self::PhaseTime,
+            Self::NextPhaseEta,
+            Self::TimeToEnrage,
+            Self::Counters,
         ]
     }
 }
@@ -856,6 +1744,37 @@ pub struct PersonalOverlayConfig {
     pub font_color: Color,
     #[serde(default = "default_font_color")]
     pub label_color: Color,
+    /// Format of amount stats without their own entry in `stat_formats`
+    #[serde(default)]
+    pub number_format: NumberFormat,
+    /// Per-stat format overrides (e.g. one decimal on HPS, none on DPS)
+    #[serde(default)]
+    pub stat_formats: Vec<PersonalStatFormat>,
+}
+
+/// Number format override for one personal overlay stat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonalStatFormat {
+    pub stat: PersonalStat,
+    pub format: NumberFormat,
+}
+
+impl PersonalOverlayConfig {
+    /// Format used for `stat`: its override, else the overlay-wide format
+    pub fn format_for(&self, stat: PersonalStat) -> NumberFormat {
+        self.stat_formats
+            .iter()
+            .find(|f| f.stat == stat)
+            .map_or(self.number_format, |f| f.format)
+    }
+
+    /// Set or (with `None`) remove the override for `stat`
+    pub fn set_stat_format(&mut self, stat: PersonalStat, format: Option<NumberFormat>) {
+        self.stat_formats.retain(|f| f.stat != stat);
+        if let Some(format) = format {
+            self.stat_formats.push(PersonalStatFormat { stat, format });
+        }
+    }
 }
 
 fn default_personal_stats() -> Vec<PersonalStat> {
@@ -876,6 +1795,8 @@ impl Default for PersonalOverlayConfig {
             visible_stats: default_personal_stats(),
             font_color: overlay_colors::WHITE,
             label_color: overlay_colors::WHITE,
+            number_format: NumberFormat::default(),
+            stat_formats: Vec::new(),
         }
     }
 }
@@ -910,6 +1831,28 @@ impl Default for OverlayPositionConfig {
 // Raid Overlay Settings
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Raid frame layout density
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RaidFrameLayout {
+    /// Effect squares or icons, no names (default)
+    #[default]
+    Standard,
+    /// Smaller frames with short names and effects as thin bars
+    Compact,
+}
+
+/// How player names are shortened in the compact raid frame layout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RaidNameStyle {
+    /// Initials, or the first two letters of a single-word name (default)
+    #[default]
+    Initials,
+    /// The first few letters of the name
+    Truncated,
+}
+
 /// Configuration for the raid frame overlay
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RaidOverlaySettings {
@@ -930,7 +1873,18 @@ pub struct RaidOverlaySettings {
     #[serde(default = "default_effect_fill_opacity")]
     pub effect_fill_opacity: u8,
     #[serde(default)]
-    pub show_effect_icons: bool,
+    pub show_effect_icons: bool,
+    /// Keep the raid frames up while not grouped (otherwise they only show in a group)
+    #[serde(default)]
+    pub show_when_solo: bool,
+    #[serde(default)]
+    pub frame_layout: RaidFrameLayout,
+    /// Name shortening in the compact layout
+    #[serde(default)]
+    pub compact_name_style: RaidNameStyle,
+    /// Draw each frame's HP fill and deficit from combat-log health values
+    #[serde(default)]
+    pub show_health_bars: bool,
 }
 
 fn default_grid_columns() -> u8 {
@@ -967,6 +1921,10 @@ impl Default for RaidOverlaySettings {
             show_role_icons: true,
             effect_fill_opacity: 255,
             show_effect_icons: false,
+            show_when_solo: false,
+            frame_layout: RaidFrameLayout::Standard,
+            compact_name_style: RaidNameStyle::Initials,
+            show_health_bars: false,
         }
     }
 }
@@ -984,6 +1942,14 @@ impl RaidOverlaySettings {
     }
 }
 
+/// A slot in the raid frame grid and the player in it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaidSlotAssignment {
+    pub slot: u8,
+    /// None for an empty slot
+    pub name: Option<String>,
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Boss Health Settings
 // ─────────────────────────────────────────────────────────────────────────────
@@ -999,11 +1965,24 @@ pub struct BossHealthConfig {
     pub show_percent: bool,
     #[serde(default = "default_true")]
     pub show_target: bool,
+    /// Show how far ahead/behind the best recorded kill the pull is
+    #[serde(default = "default_true")]
+    pub show_pace: bool,
+    /// Tick marks on the bars where HP-triggered phases start
+    #[serde(default = "default_true")]
+    pub show_phase_markers: bool,
+    /// Keep the bars up this many seconds after combat drops or the bosses
+    /// disappear, so short gaps (e.g. immune phase transitions) don't blank it
+    #[serde(default = "default_boss_linger_secs")]
+    pub linger_secs: f32,
 }
 
 fn default_boss_bar_color() -> Color {
     overlay_colors::BOSS_BAR
 }
+fn default_boss_linger_secs() -> f32 {
+    3.0
+}
 
 impl Default for BossHealthConfig {
     fn default() -> Self {
@@ -1012,6 +1991,9 @@ impl Default for BossHealthConfig {
             font_color: overlay_colors::WHITE,
             show_percent: true,
             show_target: true,
+            show_pace: true,
+            show_phase_markers: true,
+            linger_secs: 3.0,
         }
     }
 }
@@ -1020,6 +2002,28 @@ impl Default for BossHealthConfig {
 // Timer Overlay Configuration
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Which way timer bars move as time runs out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerBarDirection {
+    /// Bar starts full and empties (default)
+    #[default]
+    Drain,
+    /// Bar starts empty and fills up
+    Fill,
+}
+
+/// Where the timer name and remaining time are drawn on the bar
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerTextLayout {
+    /// Name on the left, time on the right (default)
+    #[default]
+    Split,
+    /// Name and time together in the center
+    Centered,
+}
+
 /// Configuration for the timer bar overlay
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimerOverlayConfig {
@@ -1035,6 +2039,18 @@ pub struct TimerOverlayConfig {
     /// Sort by remaining time (vs. activation order)
     #[serde(default = "default_true")]
     pub sort_by_remaining: bool,
+    /// Whether bars drain or fill as time runs out
+    #[serde(default)]
+    pub bar_direction: TimerBarDirection,
+    /// Placement of name and remaining time
+    #[serde(default)]
+    pub text_layout: TimerTextLayout,
+    /// Flash bars during their last N seconds (0 = never)
+    #[serde(default)]
+    pub flash_last_secs: f32,
+    /// Show the next raid-wide hit with damage learned from earlier pulls
+    #[serde(default)]
+    pub show_incoming_hit: bool,
 }
 
 fn default_timer_bar_color() -> Color {
@@ -1051,6 +2067,10 @@ impl Default for TimerOverlayConfig {
             font_color: overlay_colors::WHITE,
             max_display: 10,
             sort_by_remaining: true,
+            bar_direction: TimerBarDirection::default(),
+            text_layout: TimerTextLayout::default(),
+            flash_last_secs: 0.0,
+            show_incoming_hit: false,
         }
     }
 }
@@ -1100,6 +2120,45 @@ impl Default for AlertsOverlayConfig {
     }
 }
 
+/// Configuration for the center-screen alert banner (high priority alerts)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertBannerConfig {
+    /// Font size for the banner text (default 32)
+    #[serde(default = "default_banner_font_size")]
+    pub font_size: u8,
+    /// Seconds each alert stays on screen
+    #[serde(default = "default_banner_duration")]
+    pub duration_secs: f32,
+    /// Flash the text while it is shown
+    #[serde(default = "default_true")]
+    pub flash: bool,
+    /// Draw the text in the alert's own color instead of `font_color`
+    #[serde(default = "default_true")]
+    pub use_alert_color: bool,
+    /// Text color when `use_alert_color` is off
+    #[serde(default = "default_font_color")]
+    pub font_color: Color,
+}
+
+fn default_banner_font_size() -> u8 {
+    32
+}
+fn default_banner_duration() -> f32 {
+    3.0
+}
+
+impl Default for AlertBannerConfig {
+    fn default() -> Self {
+        Self {
+            font_size: default_banner_font_size(),
+            duration_secs: default_banner_duration(),
+            flash: true,
+            use_alert_color: true,
+            font_color: overlay_colors::WHITE,
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Challenge Overlay Configuration
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1378,6 +2437,154 @@ impl Default for DotTrackerConfig {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Tank Swap Overlay Config
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Configuration for the tank-swap helper overlay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TankSwapConfig {
+    /// Font color for tank names
+    #[serde(default = "default_font_color")]
+    pub font_color: Color,
+    /// Color of the debuff stack bars
+    #[serde(default = "default_tank_swap_stack_color")]
+    pub stack_color: Color,
+    /// Flash the overlay when a tank reaches the swap stack count
+    #[serde(default = "default_true")]
+    pub flash_on_swap: bool,
+}
+
+fn default_tank_swap_stack_color() -> Color {
+    overlay_colors::TANK_SWAP_STACKS
+}
+
+impl Default for TankSwapConfig {
+    fn default() -> Self {
+        Self {
+            font_color: overlay_colors::WHITE,
+            stack_color: overlay_colors::TANK_SWAP_STACKS,
+            flash_on_swap: true,
+        }
+    }
+}
+
+/// Configuration for the death recap overlay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeathRecapConfig {
+    /// Font color for names and times
+    #[serde(default = "default_font_color")]
+    pub font_color: Color,
+    /// Color of damage amounts
+    #[serde(default = "default_recap_damage_color")]
+    pub damage_color: Color,
+    /// Color of healing amounts
+    #[serde(default = "default_recap_heal_color")]
+    pub heal_color: Color,
+    /// Include healing received, not just damage
+    #[serde(default = "default_true")]
+    pub show_heals: bool,
+    /// Only recap the local player's deaths
+    #[serde(default)]
+    pub local_player_only: bool,
+}
+
+fn default_recap_damage_color() -> Color {
+    overlay_colors::RECAP_DAMAGE
+}
+
+fn default_recap_heal_color() -> Color {
+    overlay_colors::RECAP_HEAL
+}
+
+impl Default for DeathRecapConfig {
+    fn default() -> Self {
+        Self {
+            font_color: overlay_colors::WHITE,
+            damage_color: overlay_colors::RECAP_DAMAGE,
+            heal_color: overlay_colors::RECAP_HEAL,
+            show_heals: true,
+            local_player_only: false,
+        }
+    }
+}
+
+/// Configuration for the boss notes overlay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossNotesConfig {
+    /// Font color for note text
+    #[serde(default = "default_font_color")]
+    pub font_color: Color,
+    /// Color of the boss name and `#` heading lines
+    #[serde(default = "default_notes_heading_color")]
+    pub heading_color: Color,
+    /// Font size for note text
+    #[serde(default = "default_notes_font_size")]
+    pub font_size: u8,
+}
+
+fn default_notes_heading_color() -> Color {
+    overlay_colors::NOTES_HEADING
+}
+
+fn default_notes_font_size() -> u8 {
+    13
+}
+
+impl Default for BossNotesConfig {
+    fn default() -> Self {
+        Self {
+            font_color: overlay_colors::WHITE,
+            heading_color: overlay_colors::NOTES_HEADING,
+            font_size: default_notes_font_size(),
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Raid Cooldown Overlay Config
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Configuration for the raid cooldown overlay (defensives and raid
+/// utilities of every player)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaidCooldownConfig {
+    /// Font color for player names and cooldown labels
+    #[serde(default = "default_font_color")]
+    pub font_color: Color,
+    /// Cell color of a cooldown that is ready
+    #[serde(default = "default_cooldown_ready_color")]
+    pub ready_color: Color,
+    /// Cell color of a cooldown still counting down
+    #[serde(default = "default_cooldown_active_color")]
+    pub cooldown_color: Color,
+    /// Show personal defensives
+    #[serde(default = "default_true")]
+    pub show_defensives: bool,
+    /// Show raid utilities (raid buffs, group damage reduction, pulls)
+    #[serde(default = "default_true")]
+    pub show_raid_utility: bool,
+}
+
+fn default_cooldown_ready_color() -> Color {
+    overlay_colors::COOLDOWN_READY
+}
+fn default_cooldown_active_color() -> Color {
+    overlay_colors::COOLDOWN_ACTIVE
+}
+
+impl Default for RaidCooldownConfig {
+    fn default() -> Self {
+        Self {
+            font_color: overlay_colors::WHITE,
+            ready_color: overlay_colors::COOLDOWN_READY,
+            cooldown_color: overlay_colors::COOLDOWN_ACTIVE,
+            show_defensives: true,
+            show_raid_utility: true,
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Hotkey Settings
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1391,6 +2598,15 @@ pub struct HotkeySettings {
     pub toggle_move_mode: Option<String>,
     #[serde(default)]
     pub toggle_rearrange_mode: Option<String>,
+    /// Lets clicked overlays take arrow keys, Enter and Escape
+    #[serde(default)]
+    pub toggle_keyboard_mode: Option<String>,
+    /// Show the next page of the boss notes overlay
+    #[serde(default)]
+    pub notes_next_page: Option<String>,
+    /// Show the previous page of the boss notes overlay
+    #[serde(default)]
+    pub notes_prev_page: Option<String>,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1413,14 +2629,50 @@ impl OverlayProfile {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Overlay Frame Style
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Window chrome shared by every overlay
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OverlayFrameStyle {
+    /// Background corner radius in pixels at base size (0 = square corners)
+    #[serde(default = "default_corner_radius")]
+    pub corner_radius: u8,
+    /// Soft drop shadow under the background
+    #[serde(default)]
+    pub shadow: bool,
+}
+
+fn default_corner_radius() -> u8 {
+    6
+}
+
+impl Default for OverlayFrameStyle {
+    fn default() -> Self {
+        Self {
+            corner_radius: default_corner_radius(),
+            shadow: false,
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Overlay Settings (combined)
 // ─────────────────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverlaySettings {
+    /// Positions in use, for the monitor layout in `active_monitor_layout`
     #[serde(default)]
     pub positions: HashMap<String, OverlayPositionConfig>,
+    /// Saved position sets of other monitor layouts, keyed by layout
+    /// fingerprint (e.g. laptop-only vs docked)
+    #[serde(default)]
+    pub layout_positions: HashMap<String, HashMap<String, OverlayPositionConfig>>,
+    /// Fingerprint of the monitor layout `positions` belongs to
+    #[serde(default)]
+    pub active_monitor_layout: Option<String>,
     #[serde(default)]
     pub appearances: HashMap<String, OverlayAppearanceConfig>,
     #[serde(default, alias = "visibility")]
@@ -1487,6 +2739,30 @@ pub struct OverlaySettings {
     pub dot_tracker: DotTrackerConfig,
     #[serde(default = "default_opacity")]
     pub dot_tracker_opacity: u8,
+    #[serde(default)]
+    pub tank_swap: TankSwapConfig,
+    #[serde(default = "default_opacity")]
+    pub tank_swap_opacity: u8,
+    #[serde(default)]
+    pub raid_cooldowns: RaidCooldownConfig,
+    #[serde(default = "default_opacity")]
+    pub raid_cooldowns_opacity: u8,
+    #[serde(default)]
+    pub death_recap: DeathRecapConfig,
+    #[serde(default = "default_opacity")]
+    pub death_recap_opacity: u8,
+    #[serde(default)]
+    pub boss_notes: BossNotesConfig,
+    #[serde(default = "default_opacity")]
+    pub boss_notes_opacity: u8,
+    #[serde(default)]
+    pub alert_banner: AlertBannerConfig,
+    /// Banner background opacity (transparent by default)
+    #[serde(default)]
+    pub alert_banner_opacity: u8,
+    /// Corner radius and drop shadow of every overlay background
+    #[serde(default)]
+    pub frame_style: OverlayFrameStyle,
     /// Auto-hide overlays when local player is in a conversation
     #[serde(default)]
     pub hide_during_conversations: bool,
@@ -1496,6 +2772,8 @@ impl Default for OverlaySettings {
     fn default() -> Self {
         Self {
             positions: HashMap::new(),
+            layout_positions: HashMap::new(),
+            active_monitor_layout: None,
             appearances: HashMap::new(),
             enabled: HashMap::new(),
             overlays_visible: true,
@@ -1529,6 +2807,17 @@ impl Default for OverlaySettings {
             cooldown_tracker_opacity: 180,
             dot_tracker: DotTrackerConfig::default(),
             dot_tracker_opacity: 180,
+            tank_swap: TankSwapConfig::default(),
+            tank_swap_opacity: 180,
+            raid_cooldowns: RaidCooldownConfig::default(),
+            raid_cooldowns_opacity: 180,
+            death_recap: DeathRecapConfig::default(),
+            death_recap_opacity: 180,
+            boss_notes: BossNotesConfig::default(),
+            boss_notes_opacity: 180,
+            alert_banner: AlertBannerConfig::default(),
+            alert_banner_opacity: 0,
+            frame_style: OverlayFrameStyle::default(),
             hide_during_conversations: false,
         }
     }
@@ -1546,6 +2835,29 @@ impl OverlaySettings {
         self.positions.insert(overlay_type.to_string(), config);
     }
 
+    /// Switch to the position set of monitor layout `fingerprint`, keeping the
+    /// current positions under the previous layout. A layout seen for the first
+    /// time starts from the current positions. Returns true if the positions
+    /// changed.
+    pub fn switch_monitor_layout(&mut self, fingerprint: &str) -> bool {
+        if self.active_monitor_layout.as_deref() == Some(fingerprint) {
+            return false;
+        }
+        let saved = self.layout_positions.remove(fingerprint);
+        if let Some(previous) = self.active_monitor_layout.take() {
+            self.layout_positions
+                .insert(previous, self.positions.clone());
+        }
+        self.active_monitor_layout = Some(fingerprint.to_string());
+        match saved {
+            Some(positions) => {
+                self.positions = positions;
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn get_appearance(&self, overlay_type: &str) -> OverlayAppearanceConfig {
         self.appearances
             .get(overlay_type)
@@ -1578,7 +2890,7 @@ impl OverlaySettings {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Audio settings for timer alerts and countdowns
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioSettings {
     /// Master enable for all audio
     #[serde(default = "default_true")]
@@ -1595,12 +2907,24 @@ pub struct AudioSettings {
     /// Enable alert speech when timers fire
     #[serde(default = "default_true")]
     pub alerts_enabled: bool,
+
+    /// Text-to-speech voice name (None = system default)
+    #[serde(default)]
+    pub tts_voice: Option<String>,
+
+    /// Text-to-speech rate as a percentage of normal speed (50-200)
+    #[serde(default = "default_tts_rate")]
+    pub tts_rate: u8,
 }
 
 fn default_audio_volume() -> u8 {
     80
 }
 
+fn default_tts_rate() -> u8 {
+    100
+}
+
 impl Default for AudioSettings {
     fn default() -> Self {
         Self {
@@ -1608,10 +2932,24 @@ impl Default for AudioSettings {
             volume: 80,
             countdown_enabled: true,
             alerts_enabled: true,
+            tts_voice: None,
+            tts_rate: 100,
         }
     }
 }
 
+/// Settings kept per character, keyed by character name in
+/// [`AppConfig::character_settings`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharacterSettings {
+    /// Overlay profile loaded when this character's log is tailed
+    #[serde(default)]
+    pub profile_name: Option<String>,
+    /// Alert audio for this character (the global audio settings apply when unset)
+    #[serde(default)]
+    pub audio: Option<AudioSettings>,
+}
+
 /// Parsely.io upload settings
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ParselySettings {
@@ -1623,6 +2961,161 @@ pub struct ParselySettings {
     pub guild: String,
 }
 
+/// Opt-in definition coverage reporting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TelemetrySettings {
+    /// Send coverage reports (off until the user opts in)
+    #[serde(default)]
+    pub enabled: bool,
+    /// URL reports are posted to; nothing is sent while empty
+    #[serde(default)]
+    pub endpoint: String,
+}
+
+/// Local WebSocket server streaming live overlay data (for OBS browser sources)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LiveServerSettings {
+    /// Run the server (off until the user enables it)
+    #[serde(default)]
+    pub enabled: bool,
+    /// Port on 127.0.0.1 the server listens on
+    #[serde(default = "default_live_server_port")]
+    pub port: u16,
+}
+
+fn default_live_server_port() -> u16 {
+    7878
+}
+
+impl Default for LiveServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_live_server_port(),
+        }
+    }
+}
+
+/// Which renderer overlays draw with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayRenderer {
+    /// Currently software: the GPU path reads every changed frame back to
+    /// the CPU and isn't yet shown to be faster
+    #[default]
+    Auto,
+    /// GPU (wgpu), falling back to software if no device can be created
+    Gpu,
+    /// CPU only (tiny-skia)
+    Software,
+}
+
+impl OverlayRenderer {
+    pub const ALL: [OverlayRenderer; 3] = [Self::Auto, Self::Gpu, Self::Software];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Auto => "Automatic",
+            Self::Gpu => "GPU",
+            Self::Software => "Software",
+        }
+    }
+}
+
+/// Built-in refresh rate levels for [`PerformanceSettings`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PerformancePreset {
+    /// Slower refreshes for low-end machines
+    Low,
+    Default,
+    /// Faster refreshes for smoother overlays
+    High,
+}
+
+impl PerformancePreset {
+    pub const ALL: [PerformancePreset; 3] = [Self::Low, Self::Default, Self::High];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Default => "Default",
+            Self::High => "High",
+        }
+    }
+
+    pub fn settings(self) -> PerformanceSettings {
+        let (metrics_interval_ms, effects_interval_ms, overlay_fps) = match self {
+            Self::Low => (500, 100, 5),
+            Self::Default => (250, 30, 10),
+            Self::High => (100, 16, 30),
+        };
+        PerformanceSettings {
+            metrics_interval_ms,
+            effects_interval_ms,
+            overlay_fps,
+        }
+    }
+}
+
+/// How often live data is recalculated and overlays redraw
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerformanceSettings {
+    /// Metrics recalculation interval during combat
+    #[serde(default = "default_metrics_interval_ms")]
+    pub metrics_interval_ms: u32,
+    /// Effects, timers and boss health polling interval while overlays are open
+    #[serde(default = "default_effects_interval_ms")]
+    pub effects_interval_ms: u32,
+    /// Redraw rate of locked overlays (moving or resizing is always 60 FPS)
+    #[serde(default = "default_overlay_fps")]
+    pub overlay_fps: u32,
+}
+
+fn default_metrics_interval_ms() -> u32 {
+    PerformancePreset::Default.settings().metrics_interval_ms
+}
+
+fn default_effects_interval_ms() -> u32 {
+    PerformancePreset::Default.settings().effects_interval_ms
+}
+
+fn default_overlay_fps() -> u32 {
+    PerformancePreset::Default.settings().overlay_fps
+}
+
+impl Default for PerformanceSettings {
+    fn default() -> Self {
+        PerformancePreset::Default.settings()
+    }
+}
+
+impl PerformanceSettings {
+    /// The preset these settings match, or `None` if they were customized
+    pub fn preset(&self) -> Option<PerformancePreset> {
+        PerformancePreset::ALL
+            .into_iter()
+            .find(|preset| preset.settings() == *self)
+    }
+}
+
+/// An ability or effect ID left out of metrics, effect tracking, and queries
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IgnoredId {
+    pub id: i64,
+    /// Ability/effect name when it was ignored (for display only)
+    #[serde(default)]
+    pub name: String,
+}
+
+/// Settings replaced while safe mode is active, kept so they can be written
+/// back in place of the safe mode overrides when the config is saved
+#[derive(Debug, Clone)]
+pub struct SafeModeStash {
+    pub overlay_settings: OverlaySettings,
+    pub active_profile_name: Option<String>,
+}
+
 ///
 /// Note: Persistence methods (load/save) are provided by baras-core via the
 /// `AppConfigExt` trait, as they require platform-specific dependencies.
@@ -1650,8 +3143,17 @@ pub struct AppConfig {
     #[serde(default)]
     pub parsely: ParselySettings,
     #[serde(default)]
+    pub telemetry: TelemetrySettings,
+    #[serde(default)]
+    pub live_server: LiveServerSettings,
+    #[serde(default)]
     pub audio: AudioSettings,
     #[serde(default)]
+    pub performance: PerformanceSettings,
+    /// Overlay renderer (applies to overlays opened after a change)
+    #[serde(default)]
+    pub overlay_renderer: OverlayRenderer,
+    #[serde(default)]
     pub show_only_bosses: bool,
 
     /// Hide log files smaller than 1MB in the file browser (enabled by default).
@@ -1668,16 +3170,53 @@ pub struct AppConfig {
     #[serde(default = "default_latency")]
     pub latency_ms: u16,
 
+    /// Compact finished encounters between pulls so memory stays flat over
+    /// long sessions (enabled by default).
+    #[serde(default = "default_true")]
+    pub compact_session_cache: bool,
+
+    /// Minutes without combat, with the window hidden to the tray, before
+    /// background polling stops until new log lines arrive (0 disables).
+    #[serde(default = "default_deep_idle_minutes")]
+    pub deep_idle_minutes: u32,
+
+    /// Noisy abilities/effects (scaling auras, vehicle buffs) matched against
+    /// both the ability and effect ID of each event.
+    #[serde(default)]
+    pub ignored_ids: Vec<IgnoredId>,
+
+    /// Per-character overlay profile and alert audio, keyed by character name.
+    #[serde(default)]
+    pub character_settings: HashMap<String, CharacterSettings>,
+
+    /// Character of the most recently tailed log file.
+    #[serde(default)]
+    pub active_character: Option<String>,
+
+    /// Discipline IDs already played, so a starter profile is only offered
+    /// the first time each one is detected.
+    #[serde(default)]
+    pub seen_disciplines: Vec<i64>,
+
     /// Last version for which the changelog was shown.
     /// Used to show "What's New" popup only once per version.
     #[serde(default)]
     pub last_viewed_changelog_version: Option<String>,
+
+    /// Original settings while safe mode is active (runtime only, never
+    /// serialized).
+    #[serde(skip)]
+    pub safe_mode_stash: Option<Box<SafeModeStash>>,
 }
 
 fn default_retention_days() -> u32 {
     21
 }
 
+fn default_deep_idle_minutes() -> u32 {
+    10
+}
+
 fn default_alacrity() -> f32 {
     7.5
 }
@@ -1701,12 +3240,45 @@ impl AppConfig {
             profiles: Vec::new(),
             active_profile_name: None,
             parsely: ParselySettings::default(),
+            telemetry: TelemetrySettings::default(),
+            live_server: LiveServerSettings::default(),
             audio: AudioSettings::default(),
+            performance: PerformanceSettings::default(),
+            overlay_renderer: OverlayRenderer::default(),
             show_only_bosses: false,
             hide_small_log_files: true,
             alacrity_percent: 0.0,
             latency_ms: 0,
+            compact_session_cache: true,
+            deep_idle_minutes: default_deep_idle_minutes(),
+            ignored_ids: Vec::new(),
+            character_settings: HashMap::new(),
+            active_character: None,
+            seen_disciplines: Vec::new(),
             last_viewed_changelog_version: None,
+            safe_mode_stash: None,
+        }
+    }
+
+    /// Audio settings in effect: the active character's own, else the global ones
+    pub fn active_audio(&self) -> &AudioSettings {
+        self.active_character
+            .as_ref()
+            .and_then(|name| self.character_settings.get(name))
+            .and_then(|c| c.audio.as_ref())
+            .unwrap_or(&self.audio)
+    }
+
+    /// Mutable [`active_audio`](Self::active_audio), for editing whichever applies
+    pub fn active_audio_mut(&mut self) -> &mut AudioSettings {
+        let own = self
+            .active_character
+            .as_ref()
+            .and_then(|name| self.character_settings.get_mut(name))
+            .and_then(|c| c.audio.as_mut());
+        match own {
+            Some(audio) => audio,
+            None => &mut self.audio,
         }
     }
 }
@@ -1746,6 +3318,15 @@ pub enum EntityFilter {
     AnyNpc,
     /// Specific entities by selector (IDs, names, or roster aliases)
     Selector(Vec<EntitySelector>),
+    /// A player by character name (case insensitive), e.g. `{ named_player = "Karr" }`
+    NamedPlayer(String),
+    /// Entities the inner filter does not match, e.g. `{ not = "boss" }`
+    Not(Box<EntityFilter>),
+    /// Entities matching every inner filter,
+    /// e.g. `{ all_of = ["any_npc", { not = { selector = ["Kill Target"] } }] }`
+    AllOf(Vec<EntityFilter>),
+    /// Entities matching at least one inner filter
+    AnyOf(Vec<EntityFilter>),
     /// Any entity whatsoever
     #[default]
     Any,
@@ -1766,6 +3347,10 @@ impl EntityFilter {
             Self::NpcExceptBoss => "Adds (Non-Boss)",
             Self::AnyNpc => "Any NPC",
             Self::Selector(_) => "Specific Selector",
+            Self::NamedPlayer(_) => "Named Player",
+            Self::Not(_) => "Not",
+            Self::AllOf(_) => "All Of",
+            Self::AnyOf(_) => "Any Of",
             Self::Any => "Any",
         }
     }
@@ -1790,6 +3375,11 @@ impl EntityFilter {
         matches!(self, Self::Boss)
     }
 
+    /// Returns true for `Not`/`AllOf`/`AnyOf` (combinations of other filters)
+    pub fn is_combinator(&self) -> bool {
+        matches!(self, Self::Not(_) | Self::AllOf(_) | Self::AnyOf(_))
+    }
+
     /// Check if this filter matches a specific NPC by class ID
     pub fn matches_npc_id(&self, npc_id: i64) -> bool {
         match self {
@@ -1797,6 +3387,9 @@ impl EntityFilter {
                 .iter()
                 .any(|s| matches!(s, EntitySelector::Id(id) if *id == npc_id)),
             Self::AnyNpc | Self::Boss | Self::NpcExceptBoss | Self::Any => true,
+            Self::Not(inner) => !inner.matches_npc_id(npc_id),
+            Self::AllOf(filters) => filters.iter().all(|f| f.matches_npc_id(npc_id)),
+            Self::AnyOf(filters) => filters.iter().any(|f| f.matches_npc_id(npc_id)),
             _ => false,
         }
     }
@@ -1807,7 +3400,11 @@ impl EntityFilter {
             Self::Selector(selectors) => selectors
                 .iter()
                 .any(|s| matches!(s, EntitySelector::Name(n) if n.eq_ignore_ascii_case(name))),
+            Self::NamedPlayer(n) => n.eq_ignore_ascii_case(name),
             Self::Any => true,
+            Self::Not(inner) => !inner.matches_name(name),
+            Self::AllOf(filters) => filters.iter().all(|f| f.matches_name(name)),
+            Self::AnyOf(filters) => filters.iter().any(|f| f.matches_name(name)),
             _ => false,
         }
     }
@@ -1838,6 +3435,10 @@ impl EntityFilter {
             Self::NpcExceptBoss => "npc_except_boss",
             Self::AnyNpc => "any_npc",
             Self::Selector(_) => "selector",
+            Self::NamedPlayer(_) => "named_player",
+            Self::Not(_) => "not",
+            Self::AllOf(_) => "all_of",
+            Self::AnyOf(_) => "any_of",
             Self::Any => "any",
         }
     }
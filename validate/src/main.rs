@@ -8,6 +8,7 @@
 mod output;
 mod replay;
 mod verification;
+mod watch;
 
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
@@ -24,8 +25,8 @@ use baras_core::boss::{
 };
 use baras_core::combat_log::{CombatEvent, EntityType, LogParser};
 use baras_core::context::resolve;
-use baras_core::encounter::ChallengeTracker;
 use baras_core::encounter::combat::ActiveBoss;
+use baras_core::encounter::{ChallengeTracker, ChallengeValue};
 use baras_core::game_data::{effect_id, effect_type_id};
 use baras_core::signal_processor::{
     EventProcessor, GameSignal, SignalHandler, check_counter_timer_triggers,
@@ -33,9 +34,13 @@ use baras_core::signal_processor::{
 use baras_core::state::SessionCache;
 use baras_core::timers::TimerManager;
 
-use crate::output::{CliOutput, OutputLevel};
-use crate::replay::{LagSimulator, VirtualClock};
+use crate::output::json::{
+    AbilityRecord, BossInfo, ChallengePlayer, ChallengeRecord, CheckpointSummary, EntityRecord,
+};
+use crate::output::{CliOutput, FiredEvent, JsonReport, OutputLevel};
+use crate::replay::{DriftTracker, LagSimulator, VirtualClock};
 use crate::verification::{CheckpointVerifier, Expectations};
+use crate::watch::{FileWatcher, diff_fired, print_diff};
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // CLI Arguments
@@ -55,6 +60,14 @@ impl Default for ReplayMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Colored event log and tables (default)
+    Text,
+    /// One JSON report on stdout, nothing else
+    Json,
+}
+
 #[derive(Parser, Debug)]
 #[command(name = "baras-validate")]
 #[command(about = "Validate timer definitions against combat logs")]
@@ -87,6 +100,11 @@ struct Args {
     #[arg(long)]
     simulate_lag: bool,
 
+    /// Process each event this many milliseconds after it happened and report
+    /// timer drift (combines with --simulate-lag jitter and spikes)
+    #[arg(long, value_name = "MS")]
+    lag_ms: Option<u64>,
+
     // ─────────────────────────────────────────────────────────────────────────
     // Output Mode
     // ─────────────────────────────────────────────────────────────────────────
@@ -106,6 +124,10 @@ struct Args {
     #[arg(long)]
     all_entities: bool,
 
+    /// Report format (json includes everything regardless of the flags above)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
     // ─────────────────────────────────────────────────────────────────────────
     // Verification
     // ─────────────────────────────────────────────────────────────────────────
@@ -113,6 +135,14 @@ struct Args {
     #[arg(long)]
     expect: Option<PathBuf>,
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Watch Mode
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Re-run when the boss TOML, log or expectations file changes, printing
+    /// only the timers and phases that fired differently
+    #[arg(long)]
+    watch: bool,
+
     // ─────────────────────────────────────────────────────────────────────────
     // Debug
     // ─────────────────────────────────────────────────────────────────────────
@@ -166,6 +196,14 @@ struct ValidationState {
     boss_entity_ids: HashSet<i64>,
 }
 
+/// Result of one validation run
+struct ValidationRun {
+    /// Boss definition file that was validated
+    boss_path: PathBuf,
+    fired: Vec<FiredEvent>,
+    checkpoints: Option<(u32, u32)>,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Main
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -173,14 +211,86 @@ struct ValidationState {
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
-    // Determine output level
-    let output_level = if args.quiet {
+    if args.watch {
+        if args.format == OutputFormat::Json {
+            return Err("--format json can't be combined with --watch".into());
+        }
+        return watch_loop(&args);
+    }
+
+    // Determine output level (JSON replaces the event log)
+    let output_level = if args.quiet || args.format == OutputFormat::Json {
         OutputLevel::Quiet
     } else if args.verbose {
         OutputLevel::Verbose
     } else {
         OutputLevel::Normal
     };
+    let run = run_validation(&args, output_level)?;
+
+    // Exit with error code if verification failed
+    if let Some((passed, total)) = run.checkpoints {
+        if passed != total {
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Re-run validation whenever an input file changes, printing the diff of
+/// fired timers and phases against the previous run
+fn watch_loop(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let first = run_validation(args, OutputLevel::Quiet)?;
+
+    let mut watcher = FileWatcher::new();
+    watcher.watch(&first.boss_path);
+    watcher.watch(&args.log);
+    if let Some(expect_path) = &args.expect {
+        watcher.watch(expect_path);
+    }
+
+    eprintln!(
+        "Watching {} and {} (Ctrl+C to stop)",
+        first.boss_path.display(),
+        args.log.display()
+    );
+    println!(
+        "Initial run: {} timer starts and phase changes",
+        first.fired.len()
+    );
+    print_checkpoints(first.checkpoints);
+
+    let mut previous = first.fired;
+    loop {
+        for path in watcher.wait_for_change() {
+            eprintln!("\nChanged: {}", path.display());
+        }
+
+        // A half-saved or invalid TOML shouldn't end the session
+        match run_validation(args, OutputLevel::Quiet) {
+            Ok(run) => {
+                watcher.watch(&run.boss_path);
+                print_diff(&diff_fired(&previous, &run.fired));
+                print_checkpoints(run.checkpoints);
+                previous = run.fired;
+            }
+            Err(e) => eprintln!("Validation failed: {}", e),
+        }
+    }
+}
+
+fn print_checkpoints(checkpoints: Option<(u32, u32)>) {
+    if let Some((passed, total)) = checkpoints {
+        println!("  Verification: {}/{} checkpoints passed", passed, total);
+    }
+}
+
+/// Replay the log through the boss definition once
+fn run_validation(
+    args: &Args,
+    output_level: OutputLevel,
+) -> Result<ValidationRun, Box<dyn std::error::Error>> {
     let mut cli = CliOutput::new(output_level);
 
     // Determine speed multiplier
@@ -210,38 +320,40 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     let bosses_with_paths = load_bosses_with_paths(&def_path)?;
-    let bosses: Vec<&BossEncounterDefinition> = bosses_with_paths.iter().map(|b| &b.boss).collect();
 
     // Find the requested boss
-    let boss_def = bosses
+    let boss_with_path = bosses_with_paths
         .iter()
-        .find(|b| b.id.eq_ignore_ascii_case(&args.boss))
+        .find(|b| b.boss.id.eq_ignore_ascii_case(&args.boss))
         .ok_or_else(|| {
             format!(
                 "Boss '{}' not found. Available: {}",
                 args.boss,
-                bosses
+                bosses_with_paths
                     .iter()
-                    .map(|b| b.id.as_str())
+                    .map(|b| b.boss.id.as_str())
                     .collect::<Vec<_>>()
                     .join(", ")
             )
         })?;
-
-    eprintln!("Validating: {} ({})", boss_def.name, boss_def.area_name);
-    eprintln!(
-        "Mode: {} (speed: {}x)",
-        if speed == 0.0 {
-            "accelerated"
-        } else {
-            "realtime"
-        },
-        if speed == 0.0 {
-            "instant".to_string()
-        } else {
-            format!("{:.1}", speed)
-        }
-    );
+    let boss_def = &boss_with_path.boss;
+
+    if !args.watch {
+        eprintln!("Validating: {} ({})", boss_def.name, boss_def.area_name);
+        eprintln!(
+            "Mode: {} (speed: {}x)",
+            if speed == 0.0 {
+                "accelerated"
+            } else {
+                "realtime"
+            },
+            if speed == 0.0 {
+                "instant".to_string()
+            } else {
+                format!("{:.1}", speed)
+            }
+        );
+    }
 
     // Load expectations for verification (if provided)
     let mut verifier = if let Some(expect_path) = &args.expect {
@@ -274,13 +386,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let session_date = extract_session_date(&lines[0])?;
     let parser = LogParser::new(session_date);
+    let mut session_clock = parser.clock();
 
     // Initialize processing components
     let mut processor = EventProcessor::new();
     let mut cache = SessionCache::default();
     let mut timer_manager = TimerManager::new();
 
-    let boss_defs = vec![(*boss_def).clone()];
+    let boss_defs = vec![boss_def.clone()];
     cache.load_boss_definitions(boss_defs.clone());
     timer_manager.load_boss_definitions(boss_defs);
 
@@ -310,20 +423,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Timing components
     let mut clock: Option<VirtualClock> = None;
-    let mut lag_sim = if args.simulate_lag {
-        LagSimulator::new()
-    } else {
-        LagSimulator::disabled()
+    let mut lag_sim = match args.lag_ms {
+        Some(ms) if args.simulate_lag => LagSimulator::new().with_base_lag(ms),
+        Some(ms) => LagSimulator::custom(ms, 0, 0.0, 0),
+        None if args.simulate_lag => LagSimulator::new(),
+        None => LagSimulator::disabled(),
     };
+    let mut drift = (args.simulate_lag || args.lag_ms.is_some()).then(DriftTracker::default);
 
     let mut event_count = 0;
     let mut local_player_id: i64 = 0;
     let mut kill_target_death_time: Option<NaiveDateTime> = None;
 
     for (line_num, line) in lines.iter().enumerate() {
-        let Some(event) = parser.parse_line(line_num as u64, line) else {
+        let Some(mut event) = parser.parse_line(line_num as u64, line) else {
             continue;
         };
+        session_clock.apply(&mut event);
 
         event_count += 1;
 
@@ -355,8 +471,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Advance virtual clock (sleeps in realtime mode)
         clock.advance_to(event.timestamp);
 
-        // Apply simulated lag
-        let _lag = lag_sim.next_lag();
+        // Simulated delay between the event happening and being processed
+        let lag = lag_sim.next_lag();
 
         // Process event
         let (signals, _processed_event) = processor.process_event(event.clone(), &mut cache);
@@ -420,6 +536,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 if let Some(ref mut v) = verifier {
                     v.record_timer_start(&timer.definition_id, combat_time_secs);
                 }
+                if let Some(ref mut d) = drift {
+                    d.record(
+                        &timer.definition_id,
+                        format_combat_time(state.combat_start, event.timestamp),
+                        event.timestamp,
+                        lag,
+                    );
+                }
             }
         }
 
@@ -706,28 +830,44 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         (result.checkpoints_passed, result.checkpoints_total)
     });
 
-    cli.print_summary(checkpoint_result);
-
-    // Print detailed report (unless quiet)
-    if !args.quiet {
-        print_detailed_report(
-            &args,
+    // Watch mode prints only the diff between runs
+    if args.format == OutputFormat::Json {
+        build_json_report(
+            args,
+            &cli,
             &state,
             boss_def,
             event_count,
             &challenge_tracker,
             &player_names,
-        );
-    }
+            checkpoint_result,
+            drift.as_ref(),
+        )
+        .print()?;
+    } else if !args.watch {
+        cli.print_summary(checkpoint_result);
+        if let Some(ref d) = drift {
+            d.print();
+        }
 
-    // Exit with error code if verification failed
-    if let Some((passed, total)) = checkpoint_result {
-        if passed != total {
-            std::process::exit(1);
+        // Print detailed report (unless quiet)
+        if !args.quiet {
+            print_detailed_report(
+                args,
+                &state,
+                boss_def,
+                event_count,
+                &challenge_tracker,
+                &player_names,
+            );
         }
     }
 
-    Ok(())
+    Ok(ValidationRun {
+        boss_path: boss_with_path.file_path.clone(),
+        fired: cli.fired().to_vec(),
+        checkpoints: checkpoint_result,
+    })
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -780,7 +920,9 @@ fn entity_to_info(entity: &baras_core::combat_log::Entity, local_player_id: i64)
 fn populate_tracked_ids(state: &mut ValidationState, boss: &BossEncounterDefinition) {
     for timer in &boss.timers {
         match &timer.trigger {
-            baras_core::timers::TimerTrigger::AbilityCast { abilities, .. } => {
+            baras_core::timers::TimerTrigger::AbilityCast { abilities, .. }
+            | baras_core::timers::TimerTrigger::CastStarted { abilities, .. }
+            | baras_core::timers::TimerTrigger::CastCompleted { abilities, .. } => {
                 for selector in abilities {
                     if let baras_core::AbilitySelector::Id(id) = selector {
                         state.tracked_ability_ids.insert(*id);
@@ -964,26 +1106,9 @@ fn print_detailed_report(
         println!("  {}", "─".repeat(65));
 
         for cv in &challenge_values {
-            let phase_ids: Option<Vec<String>> = boss
-                .challenges
-                .iter()
-                .find(|c| c.id == cv.id)
-                .and_then(|c| c.phase_ids().map(|ids| ids.to_vec()));
-
-            let duration = if let Some(ref phases) = phase_ids {
-                phases
-                    .iter()
-                    .map(|p| challenges.phase_duration(p))
-                    .sum::<f32>()
-            } else {
-                challenges.total_duration()
-            };
+            let (phase_ids, duration) = challenge_duration(cv, boss, challenges);
 
-            let dps = if duration > 0.0 {
-                cv.value as f32 / duration
-            } else {
-                0.0
-            };
+            let dps = per_second(cv.value, duration);
             let dps_str = if dps > 0.0 {
                 format!("{:.1}/s", dps)
             } else {
@@ -1012,11 +1137,7 @@ fn print_detailed_report(
                         .get(entity_id)
                         .map(|s| s.as_str())
                         .unwrap_or("Unknown");
-                    let player_dps = if duration > 0.0 {
-                        *value as f32 / duration
-                    } else {
-                        0.0
-                    };
+                    let player_dps = per_second(*value, duration);
                     println!(
                         "      {:20} {:>12} {:>12.1}/s",
                         truncate(name, 20),
@@ -1067,6 +1188,138 @@ fn print_detailed_report(
     println!();
 }
 
+/// Phases a challenge is scoped to (None = whole fight) and the seconds it covers
+fn challenge_duration(
+    cv: &ChallengeValue,
+    boss: &BossEncounterDefinition,
+    challenges: &ChallengeTracker,
+) -> (Option<Vec<String>>, f32) {
+    let phase_ids: Option<Vec<String>> = boss
+        .challenges
+        .iter()
+        .find(|c| c.id == cv.id)
+        .and_then(|c| c.phase_ids().map(|ids| ids.to_vec()));
+
+    let duration = if let Some(ref phases) = phase_ids {
+        phases
+            .iter()
+            .map(|p| challenges.phase_duration(p))
+            .sum::<f32>()
+    } else {
+        challenges.total_duration()
+    };
+    (phase_ids, duration)
+}
+
+fn per_second(value: i64, duration: f32) -> f32 {
+    if duration > 0.0 {
+        value as f32 / duration
+    } else {
+        0.0
+    }
+}
+
+/// Collect everything the text report can show into one JSON report
+#[allow(clippy::too_many_arguments)]
+fn build_json_report(
+    args: &Args,
+    cli: &CliOutput,
+    state: &ValidationState,
+    boss: &BossEncounterDefinition,
+    event_count: usize,
+    challenges: &ChallengeTracker,
+    player_names: &HashMap<i64, String>,
+    checkpoints: Option<(u32, u32)>,
+    drift: Option<&DriftTracker>,
+) -> JsonReport {
+    let mut entities: Vec<_> = state.entities.values().collect();
+    entities.sort_by_key(|e| (e.first_seen, e.npc_id));
+    let entities = entities
+        .into_iter()
+        .map(|e| EntityRecord {
+            npc_id: e.npc_id,
+            name: e.name.clone(),
+            first_seen: e
+                .first_seen
+                .map(|ts| format_combat_time(state.combat_start, ts)),
+            last_death: e
+                .last_death
+                .map(|ts| format_combat_time(state.combat_start, ts)),
+            deaths: e.death_count,
+            last_hp: e.last_hp,
+            max_hp: e.max_hp,
+        })
+        .collect();
+
+    let challenges = challenges
+        .snapshot()
+        .iter()
+        .map(|cv| {
+            let (phases, duration) = challenge_duration(cv, boss, challenges);
+            let mut players: Vec<_> = cv
+                .by_player
+                .iter()
+                .map(|(entity_id, value)| ChallengePlayer {
+                    name: player_names
+                        .get(entity_id)
+                        .cloned()
+                        .unwrap_or_else(|| "Unknown".to_string()),
+                    value: *value,
+                    per_second: per_second(*value, duration),
+                })
+                .collect();
+            players.sort_by(|a, b| b.value.cmp(&a.value));
+
+            ChallengeRecord {
+                id: cv.id.clone(),
+                name: cv.name.clone(),
+                value: cv.value,
+                events: cv.event_count,
+                duration_secs: duration,
+                per_second: per_second(cv.value, duration),
+                phases,
+                players,
+            }
+        })
+        .collect();
+
+    let mut untracked_abilities: Vec<_> = state
+        .abilities_from_bosses
+        .values()
+        .filter(|a| !state.tracked_ability_ids.contains(&(a.ability_id as u64)))
+        .map(|a| {
+            let mut sources: Vec<String> = a.sources.iter().cloned().collect();
+            sources.sort();
+            AbilityRecord {
+                id: a.ability_id,
+                name: a.name.clone(),
+                count: a.count,
+                sources,
+            }
+        })
+        .collect();
+    untracked_abilities.sort_by(|a, b| b.count.cmp(&a.count).then(a.id.cmp(&b.id)));
+
+    JsonReport {
+        boss: BossInfo {
+            id: boss.id.clone(),
+            name: boss.name.clone(),
+            area: boss.area_name.clone(),
+        },
+        log: args.log.display().to_string(),
+        event_count,
+        summary: cli.summary_counts(),
+        checkpoints: checkpoints.map(|(passed, total)| CheckpointSummary { passed, total }),
+        entities,
+        timers: cli.timer_log().to_vec(),
+        phases: cli.phase_log(),
+        counters: cli.counter_log().to_vec(),
+        challenges,
+        untracked_abilities,
+        lag: drift.map(DriftTracker::summary),
+    }
+}
+
 fn truncate(s: &str, max: usize) -> String {
     if s.len() <= max {
         s.to_string()
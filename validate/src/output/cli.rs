@@ -8,6 +8,8 @@ use std::collections::HashMap;
 use baras_core::encounter::ChallengeValue;
 use chrono::NaiveDateTime;
 
+use super::json::{CounterRecord, PhaseRecord, SummaryCounts, TimerEventKind, TimerRecord};
+
 /// Output verbosity level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum OutputLevel {
@@ -33,6 +35,25 @@ pub struct PhaseSpan {
     pub end_time: Option<NaiveDateTime>,
 }
 
+/// What a fired event was
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FiredKind {
+    Timer,
+    Phase,
+}
+
+/// A timer start or phase change, kept to compare runs in watch mode
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FiredEvent {
+    /// Boss pull the event fired in (1-based)
+    pub pull: u32,
+    /// Combat time, formatted as MM:SS.ss
+    pub at: String,
+    pub kind: FiredKind,
+    /// Timer or phase ID
+    pub id: String,
+}
+
 /// Boss HP state for the current encounter
 #[derive(Debug, Clone)]
 pub struct BossHpState {
@@ -60,6 +81,13 @@ pub struct CliOutput {
     current_phase: Option<(String, NaiveDateTime)>,
     // Boss HP tracking for current encounter
     boss_hp: HashMap<i64, BossHpState>,
+    // Timer starts and phase changes across all pulls
+    boss_pulls: u32,
+    fired: Vec<FiredEvent>,
+    // Full event history for the JSON report
+    timer_log: Vec<TimerRecord>,
+    phase_log: Vec<PhaseRecord>,
+    counter_log: Vec<CounterRecord>,
 }
 
 impl Default for CliOutput {
@@ -84,9 +112,104 @@ impl CliOutput {
             phase_spans: Vec::new(),
             current_phase: None,
             boss_hp: HashMap::new(),
+            boss_pulls: 0,
+            fired: Vec::new(),
+            timer_log: Vec::new(),
+            phase_log: Vec::new(),
+            counter_log: Vec::new(),
+        }
+    }
+
+    /// Timer events during boss pulls, in firing order
+    pub fn timer_log(&self) -> &[TimerRecord] {
+        &self.timer_log
+    }
+
+    /// Phases of every pull, including one still running at the end of the log
+    pub fn phase_log(&self) -> Vec<PhaseRecord> {
+        let mut phases = self.phase_log.clone();
+        if let Some((phase_id, start_time)) = &self.current_phase {
+            phases.push(PhaseRecord {
+                pull: self.boss_pulls,
+                phase_id: phase_id.clone(),
+                start: self.format_time(*start_time),
+                end: None,
+                duration_secs: None,
+            });
+        }
+        phases
+    }
+
+    /// Counter changes during boss pulls, in firing order
+    pub fn counter_log(&self) -> &[CounterRecord] {
+        &self.counter_log
+    }
+
+    /// Event totals across the whole log
+    pub fn summary_counts(&self) -> SummaryCounts {
+        SummaryCounts {
+            timers_started: self.timers_started,
+            timers_expired: self.timers_expired,
+            alerts_fired: self.alerts_fired,
+            phase_changes: self.phase_changes,
+            counter_changes: self.counter_changes,
         }
     }
 
+    fn record_timer(
+        &mut self,
+        time: NaiveDateTime,
+        event: TimerEventKind,
+        timer_id: &str,
+        name: &str,
+        duration_secs: Option<f32>,
+    ) {
+        let at = self.format_time(time);
+        self.timer_log.push(TimerRecord {
+            pull: self.boss_pulls,
+            at,
+            event,
+            timer_id: timer_id.to_string(),
+            name: name.to_string(),
+            duration_secs,
+        });
+    }
+
+    /// Close the running phase at `time`
+    fn end_phase(&mut self, time: NaiveDateTime) {
+        let Some((phase_id, start_time)) = self.current_phase.take() else {
+            return;
+        };
+        let secs = (time - start_time).num_milliseconds() as f32 / 1000.0;
+        self.phase_log.push(PhaseRecord {
+            pull: self.boss_pulls,
+            phase_id: phase_id.clone(),
+            start: self.format_time(start_time),
+            end: Some(self.format_time(time)),
+            duration_secs: Some(secs),
+        });
+        self.phase_spans.push(PhaseSpan {
+            phase_id,
+            start_time,
+            end_time: Some(time),
+        });
+    }
+
+    /// Timer starts and phase changes recorded so far, in firing order
+    pub fn fired(&self) -> &[FiredEvent] {
+        &self.fired
+    }
+
+    fn record_fired(&mut self, time: NaiveDateTime, kind: FiredKind, id: &str) {
+        let at = self.format_time(time);
+        self.fired.push(FiredEvent {
+            pull: self.boss_pulls,
+            at,
+            kind,
+            id: id.to_string(),
+        });
+    }
+
     /// Check if we should output (boss detected in current combat)
     fn should_output(&self) -> bool {
         self.boss_detected_in_combat
@@ -183,6 +306,16 @@ impl CliOutput {
         timer_id: &str,
     ) {
         self.timers_started += 1;
+        if self.should_output() {
+            self.record_fired(time, FiredKind::Timer, timer_id);
+            self.record_timer(
+                time,
+                TimerEventKind::Start,
+                timer_id,
+                name,
+                Some(duration_secs),
+            );
+        }
         if self.level < OutputLevel::Normal || !self.should_output() {
             return;
         }
@@ -201,6 +334,9 @@ impl CliOutput {
     /// Log timer expiration
     pub fn timer_expire(&mut self, time: NaiveDateTime, name: &str, timer_id: &str) {
         self.timers_expired += 1;
+        if self.should_output() {
+            self.record_timer(time, TimerEventKind::Expire, timer_id, name, None);
+        }
         if self.level < OutputLevel::Normal || !self.should_output() {
             return;
         }
@@ -215,6 +351,9 @@ impl CliOutput {
 
     /// Log timer cancellation
     pub fn timer_cancel(&mut self, time: NaiveDateTime, name: &str, timer_id: &str) {
+        if self.should_output() {
+            self.record_timer(time, TimerEventKind::Cancel, timer_id, name, None);
+        }
         if self.level < OutputLevel::Normal || !self.should_output() {
             return;
         }
@@ -281,15 +420,10 @@ impl CliOutput {
     /// Log phase change - now collects phase spans instead of printing inline
     pub fn phase_change(&mut self, time: NaiveDateTime, _old_phase: Option<&str>, new_phase: &str) {
         self.phase_changes += 1;
+        self.record_fired(time, FiredKind::Phase, new_phase);
 
         // End the previous phase
-        if let Some((phase_id, start_time)) = self.current_phase.take() {
-            self.phase_spans.push(PhaseSpan {
-                phase_id,
-                start_time,
-                end_time: Some(time),
-            });
-        }
+        self.end_phase(time);
 
         // Start tracking the new phase
         self.current_phase = Some((new_phase.to_string(), time));
@@ -304,6 +438,16 @@ impl CliOutput {
     /// Log counter change
     pub fn counter_change(&mut self, time: NaiveDateTime, counter_id: &str, old: u32, new: u32) {
         self.counter_changes += 1;
+        if self.should_output() {
+            let at = self.format_time(time);
+            self.counter_log.push(CounterRecord {
+                pull: self.boss_pulls,
+                at,
+                counter_id: counter_id.to_string(),
+                old_value: old,
+                new_value: new,
+            });
+        }
         if self.level < OutputLevel::Normal || !self.should_output() {
             return;
         }
@@ -324,6 +468,7 @@ impl CliOutput {
 
         // Print buffered combat start
         if let Some(start_time) = self.pending_combat_start.take() {
+            self.boss_pulls += 1;
             if self.level >= OutputLevel::Normal {
                 let label = self.bold(&self.green("═══ COMBAT START ═══"));
                 println!("\n{}\n", label);
@@ -376,13 +521,7 @@ impl CliOutput {
         challenges: &[ChallengeValue],
     ) {
         // Finalize current phase if still active
-        if let Some((phase_id, start_time)) = self.current_phase.take() {
-            self.phase_spans.push(PhaseSpan {
-                phase_id,
-                start_time,
-                end_time: Some(time),
-            });
-        }
+        self.end_phase(time);
 
         if self.level < OutputLevel::Normal || !self.should_output() {
             // Clear pending combat start if we're not outputting
@@ -611,4 +750,26 @@ mod tests {
         assert_eq!(output.timers_started, 1);
         assert_eq!(output.alerts_fired, 1);
     }
+
+    #[test]
+    fn test_fired_records_boss_pulls_only() {
+        let mut output = CliOutput::new(OutputLevel::Quiet);
+
+        // Trash pull: no boss detected, nothing recorded
+        output.combat_start(make_time(12, 0, 0, 0));
+        output.timer_start(make_time(12, 0, 5, 0), "Trash", 10.0, "trash_timer");
+
+        output.combat_start(make_time(12, 5, 0, 0));
+        output.boss_detected(make_time(12, 5, 0, 500), "Brontes");
+        output.timer_start(make_time(12, 5, 12, 0), "Missiles", 15.0, "missiles");
+        output.phase_change(make_time(12, 5, 30, 0), None, "p2");
+
+        let fired = output.fired();
+        assert_eq!(fired.len(), 2);
+        assert_eq!(fired[0].pull, 1);
+        assert_eq!(fired[0].at, "00:12.00");
+        assert_eq!(fired[0].kind, FiredKind::Timer);
+        assert_eq!(fired[1].kind, FiredKind::Phase);
+        assert_eq!(fired[1].id, "p2");
+    }
 }
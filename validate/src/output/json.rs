@@ -0,0 +1,143 @@
+//! Machine-readable validation report
+//!
+//! The same information as the text report, serialized as one JSON document
+//! on stdout for tooling and CI pipelines.
+
+use serde::Serialize;
+
+use crate::replay::DriftSummary;
+
+/// Timer lifecycle event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerEventKind {
+    Start,
+    Expire,
+    Cancel,
+}
+
+/// A timer start, expiry or cancellation during a boss pull
+#[derive(Debug, Clone, Serialize)]
+pub struct TimerRecord {
+    /// Boss pull the event fired in (1-based)
+    pub pull: u32,
+    /// Combat time, formatted as MM:SS.ss
+    pub at: String,
+    pub event: TimerEventKind,
+    pub timer_id: String,
+    pub name: String,
+    /// Timer duration (starts only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f32>,
+}
+
+/// A completed or still-running phase
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseRecord {
+    pub pull: u32,
+    pub phase_id: String,
+    pub start: String,
+    pub end: Option<String>,
+    pub duration_secs: Option<f32>,
+}
+
+/// A counter value change
+#[derive(Debug, Clone, Serialize)]
+pub struct CounterRecord {
+    pub pull: u32,
+    pub at: String,
+    pub counter_id: String,
+    pub old_value: u32,
+    pub new_value: u32,
+}
+
+/// Event totals across the whole log
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryCounts {
+    pub timers_started: u32,
+    pub timers_expired: u32,
+    pub alerts_fired: u32,
+    pub phase_changes: u32,
+    pub counter_changes: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckpointSummary {
+    pub passed: u32,
+    pub total: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BossInfo {
+    pub id: String,
+    pub name: String,
+    pub area: String,
+}
+
+/// An NPC seen in the log
+#[derive(Debug, Clone, Serialize)]
+pub struct EntityRecord {
+    pub npc_id: i64,
+    pub name: String,
+    pub first_seen: Option<String>,
+    pub last_death: Option<String>,
+    pub deaths: u32,
+    pub last_hp: Option<i64>,
+    pub max_hp: Option<i64>,
+}
+
+/// One player's share of a challenge
+#[derive(Debug, Clone, Serialize)]
+pub struct ChallengePlayer {
+    pub name: String,
+    pub value: i64,
+    pub per_second: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChallengeRecord {
+    pub id: String,
+    pub name: String,
+    pub value: i64,
+    pub events: u32,
+    /// Seconds the challenge was measured over (its phases, or the fight)
+    pub duration_secs: f32,
+    pub per_second: f32,
+    pub phases: Option<Vec<String>>,
+    pub players: Vec<ChallengePlayer>,
+}
+
+/// A boss ability that no timer triggers on
+#[derive(Debug, Clone, Serialize)]
+pub struct AbilityRecord {
+    pub id: i64,
+    pub name: String,
+    pub count: u32,
+    pub sources: Vec<String>,
+}
+
+/// Full validation report
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonReport {
+    pub boss: BossInfo,
+    pub log: String,
+    pub event_count: usize,
+    pub summary: SummaryCounts,
+    pub checkpoints: Option<CheckpointSummary>,
+    pub entities: Vec<EntityRecord>,
+    pub timers: Vec<TimerRecord>,
+    pub phases: Vec<PhaseRecord>,
+    pub counters: Vec<CounterRecord>,
+    pub challenges: Vec<ChallengeRecord>,
+    pub untracked_abilities: Vec<AbilityRecord>,
+    /// Timer drift under simulated lag (None without lag simulation)
+    pub lag: Option<DriftSummary>,
+}
+
+impl JsonReport {
+    /// Write the report to stdout
+    pub fn print(&self) -> Result<(), serde_json::Error> {
+        println!("{}", serde_json::to_string_pretty(self)?);
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
-//! Output formatting for validation results
-//!
-//! Provides colored CLI output and report generation.
-
-pub mod cli;
-
-pub use cli::{CliOutput, OutputLevel};
+//! Output formatting for validation results
+//!
+//! Provides colored CLI output and report generation.
+
+pub mod cli;
+pub mod json;
+
+pub use cli::{CliOutput, FiredEvent, FiredKind, OutputLevel};
+pub use json::JsonReport;
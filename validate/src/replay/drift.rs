@@ -0,0 +1,169 @@
+//! Timer drift under simulated processing lag
+//!
+//! With lag simulation on, every event is treated as read some milliseconds
+//! after it happened in game. For each timer start, the lag compensation the
+//! live app applies is computed against that delayed processing time; drift
+//! is how far the compensated start still lands from the game timestamp.
+//! Correct compensation leaves zero drift whatever the injected lag.
+
+use std::time::Duration;
+
+use baras_core::timers::lag_compensation;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/// How many of the worst-drifting starts the text report lists
+const WORST_SAMPLES: usize = 10;
+
+/// One timer start processed under simulated lag
+#[derive(Debug, Clone, Serialize)]
+pub struct DriftSample {
+    pub timer_id: String,
+    /// Combat time of the triggering event, formatted as MM:SS.ss
+    pub at: String,
+    /// Injected delay between the event and its processing
+    pub lag_ms: u64,
+    /// Compensated start minus the event time (positive = countdown runs late)
+    pub drift_ms: i64,
+}
+
+/// Aggregate drift across all timer starts
+#[derive(Debug, Clone, Serialize)]
+pub struct DriftSummary {
+    pub timers_started: usize,
+    pub mean_lag_ms: f32,
+    pub max_lag_ms: u64,
+    pub mean_drift_ms: f32,
+    pub max_drift_ms: i64,
+    /// Starts whose compensated time missed the event time
+    pub drifted: usize,
+    pub worst: Vec<DriftSample>,
+}
+
+/// Collects drift samples during a replay
+#[derive(Debug, Default)]
+pub struct DriftTracker {
+    samples: Vec<DriftSample>,
+}
+
+impl DriftTracker {
+    /// Record a timer started by an event processed `lag` after it happened
+    pub fn record(
+        &mut self,
+        timer_id: &str,
+        at: String,
+        event_timestamp: NaiveDateTime,
+        lag: Duration,
+    ) {
+        let processed_at = event_timestamp + chrono::Duration::milliseconds(lag.as_millis() as i64);
+        let compensation = lag_compensation(processed_at, event_timestamp);
+
+        self.samples.push(DriftSample {
+            timer_id: timer_id.to_string(),
+            at,
+            lag_ms: lag.as_millis() as u64,
+            drift_ms: lag.as_millis() as i64 - compensation.as_millis() as i64,
+        });
+    }
+
+    pub fn summary(&self) -> DriftSummary {
+        let count = self.samples.len();
+        let mean = |total: i64| {
+            if count > 0 {
+                total as f32 / count as f32
+            } else {
+                0.0
+            }
+        };
+
+        let mut worst: Vec<DriftSample> = self
+            .samples
+            .iter()
+            .filter(|s| s.drift_ms != 0)
+            .cloned()
+            .collect();
+        worst.sort_by(|a, b| b.drift_ms.abs().cmp(&a.drift_ms.abs()));
+        let drifted = worst.len();
+        worst.truncate(WORST_SAMPLES);
+
+        DriftSummary {
+            timers_started: count,
+            mean_lag_ms: mean(self.samples.iter().map(|s| s.lag_ms as i64).sum()),
+            max_lag_ms: self.samples.iter().map(|s| s.lag_ms).max().unwrap_or(0),
+            mean_drift_ms: mean(self.samples.iter().map(|s| s.drift_ms).sum()),
+            max_drift_ms: self
+                .samples
+                .iter()
+                .map(|s| s.drift_ms)
+                .max_by_key(|d| d.abs())
+                .unwrap_or(0),
+            drifted,
+            worst,
+        }
+    }
+
+    /// Print the drift report
+    pub fn print(&self) {
+        let summary = self.summary();
+        let line = "═".repeat(51);
+        println!();
+        println!("{}", line);
+        println!("  TIMER DRIFT (SIMULATED LAG)");
+        println!("{}", line);
+        println!("Timers Started:  {}", summary.timers_started);
+        println!(
+            "Injected Lag:    {:.1}ms mean, {}ms max",
+            summary.mean_lag_ms, summary.max_lag_ms
+        );
+        println!(
+            "Timer Drift:     {:.1}ms mean, {}ms max",
+            summary.mean_drift_ms, summary.max_drift_ms
+        );
+        println!(
+            "Drifted Timers:  {}/{}",
+            summary.drifted, summary.timers_started
+        );
+
+        if !summary.worst.is_empty() {
+            println!();
+            println!("  {:10} {:35} {:>8} {:>8}", "Time", "Timer", "Lag", "Drift");
+            println!("  {}", "─".repeat(65));
+            for sample in &summary.worst {
+                println!(
+                    "  {:10} {:35} {:>6}ms {:>6}ms",
+                    sample.at, sample.timer_id, sample.lag_ms, sample.drift_ms
+                );
+            }
+        }
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compensation_removes_injected_lag() {
+        let event = chrono::NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_milli_opt(20, 0, 0, 0)
+            .unwrap();
+
+        let mut tracker = DriftTracker::default();
+        tracker.record("missiles", "00:10.00".to_string(), event, Duration::ZERO);
+        tracker.record(
+            "missiles",
+            "00:20.00".to_string(),
+            event,
+            Duration::from_millis(250),
+        );
+
+        let summary = tracker.summary();
+        assert_eq!(summary.timers_started, 2);
+        assert_eq!(summary.max_lag_ms, 250);
+        assert_eq!(summary.max_drift_ms, 0);
+        assert_eq!(summary.drifted, 0);
+        assert!(summary.worst.is_empty());
+    }
+}
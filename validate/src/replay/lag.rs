@@ -73,6 +73,12 @@ impl LagSimulator {
         }
     }
 
+    /// Replace the base lag, keeping jitter and spikes
+    pub fn with_base_lag(mut self, base_lag_ms: u64) -> Self {
+        self.base_lag_ms = base_lag_ms;
+        self
+    }
+
     /// Seed the RNG for deterministic behavior in tests
     pub fn seed(&mut self, seed: u64) {
         self.rng_state = seed;
@@ -0,0 +1,164 @@
+//! Watch mode: re-run validation when inputs change
+//!
+//! Polls the boss TOML and the combat log for modification, re-runs the
+//! replay, and prints only which timer starts and phase changes appeared or
+//! disappeared since the previous run.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::output::{FiredEvent, FiredKind};
+
+/// How often watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Files to watch and their last seen modification times
+pub struct FileWatcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl FileWatcher {
+    pub fn new() -> Self {
+        Self { files: Vec::new() }
+    }
+
+    /// Start watching `path` (no-op if already watched)
+    pub fn watch(&mut self, path: &Path) {
+        if self.files.iter().any(|(p, _)| p == path) {
+            return;
+        }
+        self.files.push((path.to_path_buf(), modified(path)));
+    }
+
+    /// Block until a watched file changes, returning the changed paths
+    pub fn wait_for_change(&mut self) -> Vec<PathBuf> {
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+
+            let mut changed = Vec::new();
+            for (path, last) in &mut self.files {
+                let current = modified(path);
+                if current != *last {
+                    *last = current;
+                    changed.push(path.clone());
+                }
+            }
+            if !changed.is_empty() {
+                return changed;
+            }
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// One line of a run-to-run diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FiredChange {
+    Added(FiredEvent),
+    Removed(FiredEvent),
+}
+
+impl FiredChange {
+    fn event(&self) -> &FiredEvent {
+        match self {
+            FiredChange::Added(e) | FiredChange::Removed(e) => e,
+        }
+    }
+}
+
+/// Events fired in only one of two runs, ordered by pull and combat time
+///
+/// Events are compared as a multiset, so a timer that fires one extra time at
+/// the same moment still shows up as added.
+pub fn diff_fired(previous: &[FiredEvent], current: &[FiredEvent]) -> Vec<FiredChange> {
+    let mut counts: HashMap<&FiredEvent, i32> = HashMap::new();
+    for event in previous {
+        *counts.entry(event).or_default() -= 1;
+    }
+    for event in current {
+        *counts.entry(event).or_default() += 1;
+    }
+
+    let mut changes: Vec<FiredChange> = counts
+        .into_iter()
+        .flat_map(|(event, count)| {
+            let change = if count > 0 {
+                FiredChange::Added(event.clone())
+            } else {
+                FiredChange::Removed(event.clone())
+            };
+            std::iter::repeat_n(change, count.unsigned_abs() as usize)
+        })
+        .collect();
+
+    changes.sort_by(|a, b| {
+        let (ea, eb) = (a.event(), b.event());
+        (ea.pull, &ea.at, ea.kind, &ea.id).cmp(&(eb.pull, &eb.at, eb.kind, &eb.id))
+    });
+    changes
+}
+
+/// Print a diff as `+`/`-` lines, or a note when nothing changed
+pub fn print_diff(changes: &[FiredChange]) {
+    if changes.is_empty() {
+        println!("  (no change in fired timers or phases)");
+        return;
+    }
+
+    for change in changes {
+        let (sign, event) = match change {
+            FiredChange::Added(e) => ('+', e),
+            FiredChange::Removed(e) => ('-', e),
+        };
+        let kind = match event.kind {
+            FiredKind::Timer => "timer",
+            FiredKind::Phase => "phase",
+        };
+        println!(
+            "{} pull {} [{}] {} {}",
+            sign, event.pull, event.at, kind, event.id
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timer(pull: u32, at: &str, id: &str) -> FiredEvent {
+        FiredEvent {
+            pull,
+            at: at.to_string(),
+            kind: FiredKind::Timer,
+            id: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diff_reports_added_and_removed() {
+        let previous = vec![
+            timer(1, "00:10.00", "missiles"),
+            timer(1, "00:20.00", "missiles"),
+        ];
+        let current = vec![
+            timer(1, "00:10.00", "missiles"),
+            timer(1, "00:15.00", "missiles"),
+            timer(1, "00:15.00", "missiles"),
+        ];
+
+        let changes = diff_fired(&previous, &current);
+        assert_eq!(
+            changes,
+            vec![
+                FiredChange::Added(timer(1, "00:15.00", "missiles")),
+                FiredChange::Added(timer(1, "00:15.00", "missiles")),
+                FiredChange::Removed(timer(1, "00:20.00", "missiles")),
+            ]
+        );
+        assert!(diff_fired(&current, &current).is_empty());
+    }
+}